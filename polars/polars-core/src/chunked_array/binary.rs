@@ -0,0 +1,163 @@
+//! Arbitrary byte sequences backed by Arrow's `LargeBinaryArray`.
+//!
+//! Unlike `Utf8Chunked` the values don't have to be valid UTF-8. Use [BinaryChunked::encode]
+//! and [Utf8Chunked::decode] to go from bytes to (hex or base64) strings and back.
+use crate::prelude::*;
+use arrow::array::Array;
+
+/// The text encodings of binary data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BinaryEncoding {
+    /// Lowercase hexadecimal, two characters per byte.
+    Hex,
+    /// Standard base64 with padding.
+    Base64,
+}
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+fn hex_encode(v: &[u8]) -> String {
+    let mut out = String::with_capacity(v.len() * 2);
+    for b in v {
+        out.push(HEX_CHARS[(b >> 4) as usize] as char);
+        out.push(HEX_CHARS[(b & 0xf) as usize] as char);
+    }
+    out
+}
+
+fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    fn nibble(c: u8) -> Option<u8> {
+        match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        }
+    }
+    let s = s.as_bytes();
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    s.chunks(2)
+        .map(|pair| Some(nibble(pair[0])? << 4 | nibble(pair[1])?))
+        .collect()
+}
+
+impl BinaryEncoding {
+    fn encode(self, v: &[u8]) -> String {
+        match self {
+            BinaryEncoding::Hex => hex_encode(v),
+            BinaryEncoding::Base64 => base64::encode(v),
+        }
+    }
+
+    fn decode(self, s: &str) -> Option<Vec<u8>> {
+        match self {
+            BinaryEncoding::Hex => hex_decode(s),
+            BinaryEncoding::Base64 => base64::decode(s).ok(),
+        }
+    }
+}
+
+impl BinaryChunked {
+    /// Get a single value. Returns `None` if the value is null or `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&[u8]> {
+        if index >= self.len() {
+            return None;
+        }
+        let (chunk_idx, idx) = self.index_to_chunked_index(index);
+        let arr = self.downcast_chunks().get(chunk_idx).unwrap();
+        if arr.is_null(idx) {
+            None
+        } else {
+            Some(arr.value(idx))
+        }
+    }
+
+    /// Encode the bytes to strings.
+    pub fn encode(&self, encoding: BinaryEncoding) -> Utf8Chunked {
+        let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len(), self.len() * 16);
+        for opt_v in self {
+            builder.append_option(opt_v.map(|v| encoding.encode(v)));
+        }
+        builder.finish()
+    }
+
+    /// Interpret the bytes as UTF-8 strings. Values that are not valid UTF-8 become null.
+    pub fn to_utf8(&self) -> Utf8Chunked {
+        let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len(), self.get_values_size());
+        for opt_v in self {
+            builder.append_option(opt_v.and_then(|v| std::str::from_utf8(v).ok()));
+        }
+        builder.finish()
+    }
+}
+
+impl Utf8Chunked {
+    /// The UTF-8 bytes of the strings.
+    pub fn as_binary(&self) -> BinaryChunked {
+        let mut builder = BinaryChunkedBuilder::new(self.name(), self.len());
+        for opt_v in self {
+            builder.append_option(opt_v);
+        }
+        builder.finish()
+    }
+
+    /// Decode hex or base64 strings to bytes.
+    ///
+    /// # Errors
+    /// If a string is not valid in the given encoding.
+    pub fn decode(&self, encoding: BinaryEncoding) -> Result<BinaryChunked> {
+        let mut builder = BinaryChunkedBuilder::new(self.name(), self.len());
+        for opt_s in self {
+            match opt_s {
+                Some(s) => {
+                    let v = encoding.decode(s).ok_or_else(|| {
+                        PolarsError::ValueError(
+                            format!("could not decode {:?} as {:?}", s, encoding).into(),
+                        )
+                    })?;
+                    builder.append_value(v)
+                }
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_binary_encode_decode() -> Result<()> {
+        let ca = BinaryChunked::new_from_opt_slice(
+            "a",
+            &[Some(&b"\x00\xffpolars"[..]), None, Some(&b""[..])],
+        );
+        assert_eq!(ca.get(0), Some(&b"\x00\xffpolars"[..]));
+        assert_eq!(ca.get(1), None);
+
+        let hex = ca.encode(BinaryEncoding::Hex);
+        assert_eq!(Vec::from(&hex), &[Some("00ff706f6c617273"), None, Some("")]);
+        let b64 = ca.encode(BinaryEncoding::Base64);
+        assert_eq!(Vec::from(&b64), &[Some("AP9wb2xhcnM="), None, Some("")]);
+
+        for (s, encoding) in &[(hex, BinaryEncoding::Hex), (b64, BinaryEncoding::Base64)] {
+            let out = s.decode(*encoding)?;
+            assert_eq!(
+                out.into_iter().collect::<Vec<_>>(),
+                ca.into_iter().collect::<Vec<_>>()
+            );
+        }
+
+        let invalid = Utf8Chunked::new_from_slice("a", &["not hex!"]);
+        assert!(invalid.decode(BinaryEncoding::Hex).is_err());
+        assert!(invalid.decode(BinaryEncoding::Base64).is_err());
+
+        // invalid utf8 becomes null
+        assert_eq!(Vec::from(&ca.to_utf8()), &[None, None, Some("")]);
+        Ok(())
+    }
+}
@@ -194,4 +194,29 @@ mod test {
         assert_eq!(out.categorical_map.unwrap().len(), 2);
         Ok(())
     }
+
+    #[test]
+    fn test_categorical_from_dictionary_array() -> Result<()> {
+        use arrow::array::{ArrayRef, DictionaryArray};
+        use arrow::datatypes::Int32Type;
+        use std::convert::TryFrom;
+        use std::iter::FromIterator;
+
+        let _lock = SINGLE_LOCK.lock();
+        reset_string_cache();
+        let arr: DictionaryArray<Int32Type> =
+            DictionaryArray::from_iter(vec![Some("a"), None, Some("b"), Some("a")]);
+        let s = Series::try_from(("cat", vec![Arc::new(arr) as ArrayRef]))?;
+        assert_eq!(s.dtype(), &DataType::Categorical);
+        assert_eq!(s.categorical()?.categorical_map.as_ref().unwrap().len(), 2);
+
+        // categoricals are compared by their string values
+        let other = Series::new("other", &["a", "a", "c", "a"]).cast::<CategoricalType>()?;
+        let mask = s.eq(&other);
+        assert_eq!(
+            Vec::from(&mask),
+            &[Some(true), None, Some(false), Some(true)]
+        );
+        Ok(())
+    }
 }
@@ -5,6 +5,8 @@ use crate::{
     utils::{get_iter_capacity, NoNull},
 };
 pub use arrow::alloc;
+#[cfg(feature = "dtype-binary")]
+use arrow::array::LargeBinaryBuilder;
 use arrow::array::{ArrayRef, LargeListBuilder};
 use arrow::{array::Array, buffer::Buffer};
 use num::Num;
@@ -167,6 +169,59 @@ impl Utf8ChunkedBuilder {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+pub struct BinaryChunkedBuilder {
+    pub builder: LargeBinaryBuilder,
+    pub capacity: usize,
+    field: Field,
+}
+
+#[cfg(feature = "dtype-binary")]
+impl BinaryChunkedBuilder {
+    /// Create a new BinaryChunkedBuilder
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of byte sequences in the final array.
+    pub fn new(name: &str, capacity: usize) -> Self {
+        BinaryChunkedBuilder {
+            builder: LargeBinaryBuilder::new(capacity),
+            capacity,
+            field: Field::new(name, DataType::Binary),
+        }
+    }
+
+    /// Appends a value into the builder
+    #[inline]
+    pub fn append_value<B: AsRef<[u8]>>(&mut self, v: B) {
+        self.builder.append_value(v.as_ref()).unwrap();
+    }
+
+    /// Appends a null slot into the builder
+    #[inline]
+    pub fn append_null(&mut self) {
+        self.builder.append_null().unwrap();
+    }
+
+    #[inline]
+    pub fn append_option<B: AsRef<[u8]>>(&mut self, opt: Option<B>) {
+        match opt {
+            Some(v) => self.append_value(v),
+            None => self.append_null(),
+        }
+    }
+
+    pub fn finish(mut self) -> BinaryChunked {
+        let arr = Arc::new(self.builder.finish());
+        ChunkedArray {
+            field: Arc::new(self.field),
+            chunks: vec![arr],
+            phantom: PhantomData,
+            categorical_map: None,
+        }
+    }
+}
+
 pub struct Utf8ChunkedBuilderCow {
     builder: Utf8ChunkedBuilder,
 }
@@ -342,6 +397,39 @@ where
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl<B> NewChunkedArray<BinaryType, B> for BinaryChunked
+where
+    B: AsRef<[u8]>,
+{
+    fn new_from_slice(name: &str, v: &[B]) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, v.len());
+        v.iter().for_each(|val| builder.append_value(val));
+        builder.finish()
+    }
+
+    fn new_from_opt_slice(name: &str, opt_v: &[Option<B>]) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, opt_v.len());
+        opt_v
+            .iter()
+            .for_each(|opt| builder.append_option(opt.as_ref()));
+        builder.finish()
+    }
+
+    fn new_from_opt_iter(name: &str, it: impl Iterator<Item = Option<B>>) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, get_iter_capacity(&it));
+        it.for_each(|opt| builder.append_option(opt));
+        builder.finish()
+    }
+
+    /// Create a new ChunkedArray from an iterator.
+    fn new_from_iter(name: &str, it: impl Iterator<Item = B>) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, get_iter_capacity(&it));
+        it.for_each(|v| builder.append_value(v));
+        builder.finish()
+    }
+}
+
 pub trait ListBuilderTrait {
     fn append_opt_series(&mut self, opt_s: Option<&Series>);
     fn append_series(&mut self, s: &Series);
@@ -5,6 +5,8 @@ use crate::prelude::*;
 use arrow::array::{make_array, Array, ArrayDataBuilder};
 use arrow::compute::cast;
 use num::NumCast;
+#[cfg(any(feature = "dtype-decimal", feature = "timezones"))]
+use std::convert::TryFrom;
 
 fn cast_ca<N, T>(ca: &ChunkedArray<T>) -> Result<ChunkedArray<N>>
 where
@@ -39,6 +41,17 @@ macro_rules! cast_from_dtype {
     }};
 }
 
+/// Wrap a ChunkedArray of any type in a Series, for casts that are implemented on Series.
+#[cfg(any(feature = "dtype-decimal", feature = "timezones"))]
+fn to_series<T>(ca: &ChunkedArray<T>) -> Series {
+    if let DataType::Categorical = ca.dtype() {
+        // the arrays don't know the categorical map
+        let ca: &CategoricalChunked = unsafe { &*(ca as *const ChunkedArray<T> as *const _) };
+        return ca.clone().into_series();
+    }
+    Series::try_from((ca.name(), ca.chunks.clone())).unwrap()
+}
+
 macro_rules! cast_with_dtype {
     ($self:expr, $data_type:expr) => {{
         use DataType::*;
@@ -78,6 +91,18 @@ macro_rules! cast_with_dtype {
             }
             List(_) => ChunkCast::cast::<ListType>($self).map(|ca| ca.into_series()),
             Categorical => ChunkCast::cast::<CategoricalType>($self).map(|ca| ca.into_series()),
+            #[cfg(feature = "dtype-binary")]
+            Binary => ChunkCast::cast::<BinaryType>($self).map(|ca| ca.into_series()),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(precision, scale) => {
+                DecimalChunked::cast_from_series(&to_series($self), *precision, *scale)
+                    .map(|ca| ca.into_series())
+            }
+            #[cfg(feature = "timezones")]
+            Datetime(tz) => {
+                crate::chunked_array::temporal::timezone::cast_to_datetime(&to_series($self), tz)
+                    .map(|ca| ca.into_series())
+            }
             dt => Err(PolarsError::Other(
                 format!("Casting to {:?} is not supported", dt).into(),
             )),
@@ -124,6 +149,55 @@ impl ChunkCast for CategoricalChunked {
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+impl ChunkCast for DecimalChunked {
+    fn cast<N>(&self) -> Result<ChunkedArray<N>>
+    where
+        N: PolarsDataType,
+    {
+        match N::get_dtype() {
+            DataType::Decimal(_, _) => Ok(unsafe { std::mem::transmute(self.clone()) }),
+            DataType::Utf8 => self.to_utf8().cast::<N>(),
+            DataType::Float32 | DataType::Float64 => self.to_f64().cast::<N>(),
+            _ => {
+                // truncate the fractional part
+                let div = 10i128.pow(self.scale() as u32);
+                let ca: Int64Chunked = self
+                    .iter_unscaled()
+                    .map(|opt_v| opt_v.map(|v| (v / div) as i64))
+                    .collect();
+                let mut ca = ca.cast::<N>()?;
+                ca.rename(self.name());
+                Ok(ca)
+            }
+        }
+    }
+
+    fn cast_with_dtype(&self, data_type: &DataType) -> Result<Series> {
+        cast_with_dtype!(self, data_type)
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl ChunkCast for BinaryChunked {
+    fn cast<N>(&self) -> Result<ChunkedArray<N>>
+    where
+        N: PolarsDataType,
+    {
+        match N::get_dtype() {
+            DataType::Binary => Ok(unsafe { std::mem::transmute(self.clone()) }),
+            DataType::Utf8 => Ok(unsafe { std::mem::transmute(self.to_utf8()) }),
+            dt => Err(PolarsError::InvalidOperation(
+                format!("cannot cast binary to {:?}", dt).into(),
+            )),
+        }
+    }
+
+    fn cast_with_dtype(&self, data_type: &DataType) -> Result<Series> {
+        cast_with_dtype!(self, data_type)
+    }
+}
+
 impl<T> ChunkCast for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -141,7 +215,7 @@ where
                 return Ok(ca);
             }
             // the underlying datatype is i64 so we transmute array
-            (Duration(_), Int64) => unsafe {
+            (Duration(_), Int64) | (Int64, Duration(_)) => unsafe {
                 cast_from_dtype!(self, transmute_array_from_dtype, Int64.to_arrow())
             },
             // paths not supported by arrow kernel
@@ -191,6 +265,8 @@ impl ChunkCast for Utf8Chunked {
                 let ca = unsafe { std::mem::transmute(ca) };
                 Ok(ca)
             }
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => Ok(unsafe { std::mem::transmute(self.as_binary()) }),
             _ => cast_ca(self),
         }
     }
@@ -415,6 +415,65 @@ impl ChunkCompare<&Utf8Chunked> for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+macro_rules! impl_cmp_binary {
+    ($self:ident, $rhs:ident, $operand:tt) => {{
+        // broadcast
+        if $rhs.len() == 1 {
+            match $rhs.get(0) {
+                Some(right) => $self
+                    .into_iter()
+                    .map(|opt_left| opt_left.map(|left| left $operand right))
+                    .collect(),
+                None => BooleanChunked::full("", false, $self.len()),
+            }
+        } else {
+            $self
+                .into_iter()
+                .zip($rhs)
+                .map(|(opt_left, opt_right)| match (opt_left, opt_right) {
+                    (Some(left), Some(right)) => Some(left $operand right),
+                    _ => None,
+                })
+                .collect()
+        }
+    }};
+}
+
+#[cfg(feature = "dtype-binary")]
+impl ChunkCompare<&BinaryChunked> for BinaryChunked {
+    fn eq_missing(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        self.into_iter()
+            .zip(rhs)
+            .map(|(opt_a, opt_b)| opt_a == opt_b)
+            .collect()
+    }
+
+    fn eq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_cmp_binary!(self, rhs, ==)
+    }
+
+    fn neq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_cmp_binary!(self, rhs, !=)
+    }
+
+    fn gt(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_cmp_binary!(self, rhs, >)
+    }
+
+    fn gt_eq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_cmp_binary!(self, rhs, >=)
+    }
+
+    fn lt(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_cmp_binary!(self, rhs, <)
+    }
+
+    fn lt_eq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_cmp_binary!(self, rhs, <=)
+    }
+}
+
 pub trait NumComp: Num + NumCast + PartialOrd {}
 
 impl NumComp for f32 {}
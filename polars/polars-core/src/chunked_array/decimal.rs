@@ -0,0 +1,385 @@
+//! Fixed point decimals backed by Arrow's `DecimalArray`.
+//!
+//! Arrow stores the unscaled values as 128 bit integers. Arithmetic is done on those values
+//! directly, but most other operations (sorting, grouping, joining, etc.) use the 64 bit
+//! [physical](DecimalChunked::physical) representation, which is why [DECIMAL_MAX_PRECISION] is 18.
+//! Creating decimals with a larger precision, or with values that have more digits than the
+//! precision, is an error.
+use crate::prelude::*;
+use arrow::array::{Array, DecimalBuilder};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+fn overflow_err() -> PolarsError {
+    PolarsError::ValueError("decimal value out of range".into())
+}
+
+fn pow10(exp: usize) -> Result<i128> {
+    10i128.checked_pow(exp as u32).ok_or_else(overflow_err)
+}
+
+fn check_precision(precision: usize, scale: usize) -> Result<()> {
+    if precision > DECIMAL_MAX_PRECISION {
+        return Err(PolarsError::ValueError(
+            format!(
+                "the precision {} of a decimal cannot exceed {}",
+                precision, DECIMAL_MAX_PRECISION
+            )
+            .into(),
+        ));
+    }
+    if scale > precision {
+        return Err(PolarsError::ValueError(
+            format!(
+                "the scale {} of a decimal cannot exceed its precision {}",
+                scale, precision
+            )
+            .into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Integer division that rounds half away from zero.
+pub(crate) fn div_round(v: i128, div: i128) -> i128 {
+    let q = v / div;
+    let r = v % div;
+    if r.abs() * 2 >= div.abs() {
+        if (v < 0) == (div < 0) {
+            q + 1
+        } else {
+            q - 1
+        }
+    } else {
+        q
+    }
+}
+
+/// Format an unscaled decimal value, e.g. `-12345` with a scale of 2 is formatted as `-123.45`.
+pub(crate) fn fmt_decimal(v: i128, scale: usize) -> String {
+    let sign = if v < 0 { "-" } else { "" };
+    let abs = v.unsigned_abs();
+    if scale == 0 {
+        return format!("{}{}", sign, abs);
+    }
+    let div = 10u128.pow(scale as u32);
+    format!("{}{}.{:0width$}", sign, abs / div, abs % div, width = scale)
+}
+
+/// Parse a string like `-123.45` to its unscaled value at the given scale.
+/// Digits beyond the scale are truncated.
+fn parse_decimal(s: &str, scale: usize) -> Option<i128> {
+    let s = s.trim();
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (int, frac) = match s.find('.') {
+        Some(idx) => (&s[..idx], &s[idx + 1..]),
+        None => (s, ""),
+    };
+    if (int.is_empty() && frac.is_empty())
+        || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let mut frac_digits = frac.bytes();
+    let v = int
+        .bytes()
+        .chain((0..scale).map(|_| frac_digits.next().unwrap_or(b'0')))
+        .try_fold(0i128, |acc, b| {
+            acc.checked_mul(10)?.checked_add((b - b'0') as i128)
+        })?;
+    Some(if negative { -v } else { v })
+}
+
+/// Multiply unscaled values by `10^exp` and convert them to 64 bit integers. Values that don't fit
+/// saturate.
+pub(crate) fn saturating_rescale<I>(name: &str, iter: I, exp: usize) -> Int64Chunked
+where
+    I: Iterator<Item = Option<i128>>,
+{
+    let m = 10i128.pow(exp as u32);
+    let mut ca: Int64Chunked = iter
+        .map(|opt_v| {
+            opt_v.map(|v| {
+                let v = v.saturating_mul(m);
+                i64::try_from(v).unwrap_or(if v < 0 { i64::MIN } else { i64::MAX })
+            })
+        })
+        .collect();
+    ca.rename(name);
+    ca
+}
+
+impl DecimalChunked {
+    /// The total number of digits.
+    pub fn precision(&self) -> usize {
+        match self.dtype() {
+            DataType::Decimal(precision, _) => *precision,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The number of digits after the decimal point.
+    pub fn scale(&self) -> usize {
+        match self.dtype() {
+            DataType::Decimal(_, scale) => *scale,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Iterate over the unscaled values.
+    pub fn iter_unscaled(&self) -> impl Iterator<Item = Option<i128>> + '_ {
+        self.downcast_iter().flat_map(|arr| {
+            (0..arr.len()).map(move |idx| {
+                if arr.is_null(idx) {
+                    None
+                } else {
+                    Some(arr.value(idx))
+                }
+            })
+        })
+    }
+
+    /// Create a decimal ChunkedArray from unscaled values, i.e. `12345` with a scale of 2 is `123.45`.
+    ///
+    /// # Errors
+    /// If the precision exceeds [DECIMAL_MAX_PRECISION] or a value has more digits than the
+    /// precision.
+    pub fn from_unscaled<I>(name: &str, iter: I, precision: usize, scale: usize) -> Result<Self>
+    where
+        I: IntoIterator<Item = Option<i128>>,
+    {
+        check_precision(precision, scale)?;
+        let max = pow10(precision)?;
+        let iter = iter.into_iter();
+        let mut builder = DecimalBuilder::new(iter.size_hint().0, precision, scale);
+        for opt_v in iter {
+            match opt_v {
+                Some(v) if v.abs() >= max => return Err(overflow_err()),
+                Some(v) => builder.append_value(v)?,
+                None => builder.append_null()?,
+            }
+        }
+        // always create a chunk so that the precision and scale are known
+        let arr = Arc::new(builder.finish()) as ArrayRef;
+        Ok(Self::new_from_chunks(name, vec![arr]))
+    }
+
+    /// Create a decimal ChunkedArray from Arrow decimal arrays.
+    ///
+    /// # Errors
+    /// If the precision exceeds [DECIMAL_MAX_PRECISION] or a value has more digits than the
+    /// precision.
+    pub fn try_from_chunks(name: &str, chunks: Vec<ArrayRef>) -> Result<Self> {
+        let ca = Self::new_from_chunks(name, chunks);
+        check_precision(ca.precision(), ca.scale())?;
+        let max = pow10(ca.precision())?;
+        if ca.iter_unscaled().flatten().any(|v| v.abs() >= max) {
+            return Err(overflow_err());
+        }
+        Ok(ca)
+    }
+
+    /// Create a decimal ChunkedArray from the 64 bit unscaled values.
+    pub fn new_from_physical(ca: &Int64Chunked, precision: usize, scale: usize) -> Result<Self> {
+        Self::from_unscaled(
+            ca.name(),
+            ca.into_iter().map(|opt_v| opt_v.map(|v| v as i128)),
+            precision,
+            scale,
+        )
+    }
+
+    /// The unscaled values as 64 bit integers. These always fit, because the precision of a
+    /// decimal is at most [DECIMAL_MAX_PRECISION].
+    pub fn physical(&self) -> Int64Chunked {
+        let mut ca: Int64Chunked = self
+            .iter_unscaled()
+            .map(|opt_v| opt_v.map(|v| v as i64))
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// The unscaled values at a scale that is at least the scale of `self`.
+    /// Values of which the digits don't fit in 64 bits saturate.
+    pub(crate) fn physical_at_scale(&self, scale: usize) -> Int64Chunked {
+        debug_assert!(scale >= self.scale());
+        saturating_rescale(self.name(), self.iter_unscaled(), scale - self.scale())
+    }
+
+    /// The unscaled values of `self` and `other` at the larger scale of both, so that equal
+    /// decimals have equal keys and the order of the keys is the order of the decimals. Integers
+    /// are decimals with a scale of 0. Only the side with the smaller scale is scaled up, and its
+    /// values that saturate are larger in magnitude than any value of the other side. Returns
+    /// `None` if `other` is not a decimal or an integer.
+    pub(crate) fn unscaled_keys(&self, other: &Series) -> Option<(Int64Chunked, Int64Chunked)> {
+        use DataType::*;
+        let (scale, other) = match other.dtype() {
+            Decimal(_, other_scale) => {
+                let other = other.decimal().ok()?;
+                let scale = std::cmp::max(self.scale(), *other_scale);
+                (scale, other.physical_at_scale(scale))
+            }
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 => {
+                let other = other.cast::<Int64Type>().ok()?;
+                let other = other.i64().ok()?;
+                let iter = other.into_iter().map(|opt_v| opt_v.map(|v| v as i128));
+                (
+                    self.scale(),
+                    saturating_rescale(other.name(), iter, self.scale()),
+                )
+            }
+            _ => return None,
+        };
+        Some((self.physical_at_scale(scale), other))
+    }
+
+    /// Apply a fallible function to the unscaled values of `self` and `rhs`.
+    /// Both arrays should have the same length and scale.
+    pub(crate) fn binary_unscaled<F>(&self, rhs: &DecimalChunked, f: F) -> Result<Self>
+    where
+        F: Fn(i128, i128) -> Result<i128>,
+    {
+        let values = self
+            .iter_unscaled()
+            .zip(rhs.iter_unscaled())
+            .map(|(l, r)| match (l, r) {
+                (Some(l), Some(r)) => f(l, r).map(Some),
+                _ => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_unscaled(self.name(), values, self.precision(), self.scale())
+    }
+
+    /// Change the scale of the decimals. The number of digits before the decimal point is kept.
+    /// Digits that don't fit the new scale are rounded half away from zero.
+    pub fn rescale(&self, scale: usize) -> Result<Self> {
+        let precision = self.precision() - self.scale() + scale;
+        self.rescale_with_precision(precision, scale)
+    }
+
+    fn rescale_with_precision(&self, precision: usize, scale: usize) -> Result<Self> {
+        let values = match scale.cmp(&self.scale()) {
+            Ordering::Equal => self.iter_unscaled().collect::<Vec<_>>(),
+            Ordering::Greater => {
+                let m = pow10(scale - self.scale())?;
+                self.iter_unscaled()
+                    .map(|opt_v| match opt_v {
+                        Some(v) => v.checked_mul(m).map(Some).ok_or_else(overflow_err),
+                        None => Ok(None),
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            }
+            Ordering::Less => {
+                let div = pow10(self.scale() - scale)?;
+                self.iter_unscaled()
+                    .map(|opt_v| opt_v.map(|v| div_round(v, div)))
+                    .collect()
+            }
+        };
+        Self::from_unscaled(self.name(), values, precision, scale)
+    }
+
+    /// Convert to floating point numbers.
+    pub fn to_f64(&self) -> Float64Chunked {
+        let div = 10f64.powi(self.scale() as i32);
+        let mut ca: Float64Chunked = self
+            .iter_unscaled()
+            .map(|opt_v| opt_v.map(|v| v as f64 / div))
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Format the decimals as strings.
+    pub fn to_utf8(&self) -> Utf8Chunked {
+        let scale = self.scale();
+        let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len(), self.len() * 8);
+        for opt_v in self.iter_unscaled() {
+            builder.append_option(opt_v.map(|v| fmt_decimal(v, scale)));
+        }
+        builder.finish()
+    }
+
+    /// Cast a Series of a numeric, utf8 or decimal type to decimals.
+    pub(crate) fn cast_from_series(s: &Series, precision: usize, scale: usize) -> Result<Self> {
+        let values = match s.dtype() {
+            DataType::Decimal(_, _) => {
+                return s.decimal()?.rescale_with_precision(precision, scale);
+            }
+            DataType::Utf8 | DataType::Categorical => {
+                let s = s.cast::<Utf8Type>()?;
+                // values that cannot be parsed become null
+                s.utf8()?
+                    .into_iter()
+                    .map(|opt_v| opt_v.and_then(|v| parse_decimal(v, scale)))
+                    .collect::<Vec<_>>()
+            }
+            DataType::Float32 | DataType::Float64 => {
+                let m = 10f64.powi(scale as i32);
+                let s = s.cast::<Float64Type>()?;
+                s.f64()?
+                    .into_iter()
+                    .map(|opt_v| opt_v.map(|v| (v * m).round() as i128))
+                    .collect::<Vec<_>>()
+            }
+            _ => {
+                let m = pow10(scale)?;
+                let s = s.cast::<Int64Type>()?;
+                s.i64()?
+                    .into_iter()
+                    .map(|opt_v| match opt_v {
+                        Some(v) => (v as i128)
+                            .checked_mul(m)
+                            .map(Some)
+                            .ok_or_else(overflow_err),
+                        None => Ok(None),
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            }
+        };
+        Self::from_unscaled(s.name(), values, precision, scale)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decimal_fmt_parse() {
+        assert_eq!(fmt_decimal(-12345, 2), "-123.45");
+        assert_eq!(fmt_decimal(5, 3), "0.005");
+        assert_eq!(fmt_decimal(42, 0), "42");
+        assert_eq!(parse_decimal("-123.456", 2), Some(-12345));
+        assert_eq!(parse_decimal("7", 2), Some(700));
+        assert_eq!(parse_decimal("foo", 2), None);
+    }
+
+    #[test]
+    fn test_decimal_cast() -> Result<()> {
+        let s = Series::new("a", &["1.25", "-0.5", "foo"]);
+        let s = s.cast_with_dtype(&DataType::Decimal(10, 2))?;
+        assert_eq!(s.dtype(), &DataType::Decimal(10, 2));
+        assert_eq!(s.null_count(), 1);
+
+        let ca = s.decimal()?;
+        assert_eq!(Vec::from(&ca.physical()), &[Some(125), Some(-50), None]);
+        let rescaled = ca.rescale(1)?;
+        assert_eq!(rescaled.dtype(), &DataType::Decimal(9, 1));
+        assert_eq!(Vec::from(&rescaled.physical()), &[Some(13), Some(-5), None]);
+        assert_eq!(Vec::from(&ca.to_f64()), &[Some(1.25), Some(-0.5), None]);
+
+        // values with more digits than the precision
+        assert!(Series::new("a", &["123.4"])
+            .cast_with_dtype(&DataType::Decimal(4, 2))
+            .is_err());
+        assert!(Series::new("a", &["1"])
+            .cast_with_dtype(&DataType::Decimal(20, 2))
+            .is_err());
+        Ok(())
+    }
+}
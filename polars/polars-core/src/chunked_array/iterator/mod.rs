@@ -119,6 +119,15 @@ impl<'a> IntoIterator for &'a Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl<'a> IntoIterator for &'a BinaryChunked {
+    type Item = Option<&'a [u8]>;
+    type IntoIter = Box<dyn PolarsIterator<Item = Self::Item> + 'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.downcast_iter().flatten().trust_my_length(self.len()))
+    }
+}
+
 pub struct Utf8IterNoNull<'a> {
     array: &'a LargeStringArray,
     current: usize,
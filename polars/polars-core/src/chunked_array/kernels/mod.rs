@@ -10,6 +10,12 @@ pub mod temporal;
 use polars_arrow::builder::BooleanArrayBuilder;
 
 use crate::chunked_array::builder::get_bitmap;
+#[cfg(feature = "dtype-duration-ms")]
+use crate::datatypes::DurationMillisecondType;
+#[cfg(feature = "dtype-duration-ns")]
+use crate::datatypes::DurationNanosecondType;
+#[cfg(any(feature = "dtype-duration-ns", feature = "dtype-duration-ms"))]
+use crate::datatypes::TimeUnit;
 use crate::datatypes::{
     ArrowDataType, Float64Type, PolarsFloatType, PolarsNumericType, PolarsPrimitiveType,
 };
@@ -60,6 +66,10 @@ where
         Int32 => transmute_array::<_, Int32Type>(arr),
         Int16 => transmute_array::<_, Int16Type>(arr),
         Int8 => transmute_array::<_, Int8Type>(arr),
+        #[cfg(feature = "dtype-duration-ns")]
+        Duration(TimeUnit::Nanosecond) => transmute_array::<_, DurationNanosecondType>(arr),
+        #[cfg(feature = "dtype-duration-ms")]
+        Duration(TimeUnit::Millisecond) => transmute_array::<_, DurationMillisecondType>(arr),
         _ => todo!(),
     }
 }
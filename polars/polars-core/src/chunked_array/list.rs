@@ -0,0 +1,204 @@
+use crate::prelude::*;
+
+impl ListChunked {
+    /// Select one value out of every list. `f` maps a list to a (transformed) list and the
+    /// index of the value in that list to select. Null lists and `None` indexes result in
+    /// a null value.
+    fn lst_select<F>(&self, f: F) -> Result<Series>
+    where
+        F: Fn(Series) -> (Series, Option<usize>),
+    {
+        let mut values: Option<Series> = None;
+        let mut idx = Vec::with_capacity(self.len());
+        let mut offset = 0;
+
+        for opt_s in self.into_iter() {
+            match opt_s {
+                Some(s) => {
+                    let (s, opt_idx) = f(s);
+                    idx.push(opt_idx.map(|i| i + offset));
+                    offset += s.len();
+                    match values.as_mut() {
+                        Some(values) => {
+                            values.append(&s)?;
+                        }
+                        None => values = Some(s),
+                    }
+                }
+                None => idx.push(None),
+            }
+        }
+
+        let mut out = match values {
+            Some(values) => values.rechunk().take_opt_iter(&mut idx.into_iter()),
+            None => UInt32Chunked::full_null(self.name(), self.len())
+                .into_series()
+                .cast_with_dtype(&self.inner_dtype())?,
+        };
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    /// Get the number of values in every list.
+    pub fn lst_lengths(&self) -> UInt32Chunked {
+        let mut ca: UInt32Chunked = self
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| s.len() as u32))
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Get the value at position `idx` of every list. Negative indexes count from the end
+    /// of the list. Lists that are too short result in a null value.
+    pub fn lst_get(&self, idx: i64) -> Result<Series> {
+        self.lst_select(|s| {
+            let len = s.len() as i64;
+            let idx = if idx < 0 { len + idx } else { idx };
+            let opt_idx = if idx >= 0 && idx < len {
+                Some(idx as usize)
+            } else {
+                None
+            };
+            (s, opt_idx)
+        })
+    }
+
+    /// Get the first value of every list.
+    pub fn lst_first(&self) -> Result<Series> {
+        self.lst_get(0)
+    }
+
+    /// Get the last value of every list.
+    pub fn lst_last(&self) -> Result<Series> {
+        self.lst_get(-1)
+    }
+
+    /// Check if the lists contain `value`. `value` should be a [`Series`] of length 1.
+    pub fn lst_contains(&self, value: &Series) -> Result<BooleanChunked> {
+        if value.len() != 1 {
+            return Err(PolarsError::ShapeMisMatch(
+                "can only check if the lists contain a single value".into(),
+            ));
+        }
+        let f = |s: Series| s.eq(value).into_iter().any(|opt_v| opt_v == Some(true));
+        let mut ca: BooleanChunked = self.into_iter().map(|opt_s| opt_s.map(f)).collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Get the sum of every list.
+    pub fn lst_sum(&self) -> Result<Series> {
+        self.lst_select(|s| (s.sum_as_series(), Some(0)))
+    }
+
+    /// Get the mean of every list.
+    pub fn lst_mean(&self) -> Result<Series> {
+        self.lst_select(|s| (s.mean_as_series(), Some(0)))
+    }
+
+    /// Get the maximum of every list.
+    pub fn lst_max(&self) -> Result<Series> {
+        self.lst_select(|s| (s.max_as_series(), Some(0)))
+    }
+
+    /// Get the minimum of every list.
+    pub fn lst_min(&self) -> Result<Series> {
+        self.lst_select(|s| (s.min_as_series(), Some(0)))
+    }
+
+    /// Sort the values of every list.
+    pub fn lst_sort(&self, reverse: bool) -> ListChunked {
+        let mut ca: ListChunked = self
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| s.sort(reverse)))
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Reverse the values of every list.
+    pub fn lst_reverse(&self) -> ListChunked {
+        let mut ca: ListChunked = self
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| s.reverse()))
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Get the unique values of every list.
+    pub fn lst_unique(&self) -> Result<ListChunked> {
+        let values = self
+            .into_iter()
+            .map(|opt_s| opt_s.map(|s| s.unique()).transpose())
+            .collect::<Result<Vec<_>>>()?;
+        let mut ca: ListChunked = values.into_iter().collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Join the string values of every list with a `separator`. Null values are skipped.
+    pub fn lst_join(&self, separator: &str) -> Result<Utf8Chunked> {
+        if self.inner_dtype() != DataType::Utf8 {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "cannot join the values of a list of {:?}, expected Utf8",
+                    self.inner_dtype()
+                )
+                .into(),
+            ));
+        }
+        let mut ca: Utf8Chunked = self
+            .into_iter()
+            .map(|opt_s| {
+                opt_s.map(|s| {
+                    let ca = s.utf8().unwrap();
+                    ca.into_iter().flatten().collect::<Vec<_>>().join(separator)
+                })
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_list_namespace() -> Result<()> {
+        let ca: ListChunked = vec![
+            Some(Series::new("", &[3, 1, 3])),
+            None,
+            Some(Series::new("", &[2])),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(Vec::from(&ca.lst_lengths()), &[Some(3), None, Some(1)]);
+        assert_eq!(Vec::from(ca.lst_get(1)?.i32()?), &[Some(1), None, None]);
+        assert_eq!(Vec::from(ca.lst_last()?.i32()?), &[Some(3), None, Some(2)]);
+        assert_eq!(
+            Vec::from(&ca.lst_contains(&Series::new("", &[2]))?),
+            &[Some(false), None, Some(true)]
+        );
+        assert_eq!(Vec::from(ca.lst_sum()?.i32()?), &[Some(7), None, Some(2)]);
+        assert_eq!(Vec::from(ca.lst_max()?.i32()?), &[Some(3), None, Some(2)]);
+
+        let sorted = ca.lst_sort(false);
+        assert_eq!(
+            Vec::from(sorted.get(0).unwrap().i32()?),
+            &[Some(1), Some(3), Some(3)]
+        );
+        let unique = ca.lst_unique()?;
+        assert_eq!(unique.get(0).unwrap().len(), 2);
+
+        let ca: ListChunked = vec![Some(Series::new("", &["a", "b"])), None]
+            .into_iter()
+            .collect();
+        assert_eq!(Vec::from(&ca.lst_join("-")?), &[Some("a-b"), None]);
+        Ok(())
+    }
+}
@@ -19,13 +19,22 @@ use std::sync::Arc;
 pub mod ops;
 #[macro_use]
 pub mod arithmetic;
+#[cfg(feature = "dtype-binary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dtype-binary")))]
+pub mod binary;
 pub mod boolean;
 pub mod builder;
 pub mod cast;
 pub mod comparison;
+#[cfg(feature = "dtype-decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dtype-decimal")))]
+pub mod decimal;
 pub mod float;
 pub mod iterator;
 pub mod kernels;
+#[cfg(feature = "list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "list")))]
+mod list;
 #[cfg(feature = "ndarray")]
 mod ndarray;
 
@@ -38,11 +47,20 @@ mod random;
 #[cfg(feature = "strings")]
 #[cfg_attr(docsrs, doc(cfg(feature = "strings")))]
 pub mod strings;
+#[cfg(feature = "dtype-struct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dtype-struct")))]
+pub mod struct_;
 #[cfg(feature = "temporal")]
 #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
 pub mod temporal;
 pub mod upstream_traits;
 
+#[cfg(feature = "dtype-decimal")]
+use arrow::array::DecimalArray;
+#[cfg(feature = "dtype-binary")]
+use arrow::array::LargeBinaryArray;
+#[cfg(feature = "timezones")]
+use arrow::array::TimestampMillisecondArray;
 use arrow::array::{
     Array, ArrayData, Date32Array, DurationMillisecondArray, DurationNanosecondArray,
     LargeListArray,
@@ -492,7 +510,20 @@ where
     /// Create a new ChunkedArray from existing chunks.
     pub fn new_from_chunks(name: &str, chunks: Vec<ArrayRef>) -> Self {
         // prevent List<Null> if the inner list type is known.
-        let datatype = if matches!(T::get_dtype(), DataType::List(_)) {
+        // decimals take their precision and scale from the arrays.
+        // datetimes take their time zone from the arrays.
+        let dtype_from_arrays = match T::get_dtype() {
+            DataType::List(_) => true,
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => true,
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => matches!(
+                chunks.get(0).map(|arr| arr.data_type()),
+                Some(ArrowDataType::Timestamp(_, Some(_)))
+            ),
+            _ => false,
+        };
+        let datatype = if dtype_from_arrays {
             if let Some(arr) = chunks.get(0) {
                 arr.data_type().into()
             } else {
@@ -568,6 +599,24 @@ where
                 let v = downcast!(UInt32Array);
                 AnyValue::Utf8(&self.categorical_map.as_ref().expect("should be set").get(v))
             }
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => {
+                let arr = &*(arr as *const dyn Array as *const DecimalArray);
+                AnyValue::Decimal(arr.value(idx), arr.scale())
+            }
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => {
+                let v = downcast!(TimestampMillisecondArray);
+                match self.dtype() {
+                    DataType::Datetime(tz) => AnyValue::Datetime(v, tz),
+                    _ => unreachable!(),
+                }
+            }
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => {
+                let arr = &*(arr as *const dyn Array as *const LargeBinaryArray);
+                AnyValue::Binary(arr.value(idx))
+            }
             _ => unimplemented!(),
         }
     }
@@ -625,6 +674,10 @@ impl AsSinglePtr for BooleanChunked {}
 impl AsSinglePtr for ListChunked {}
 impl AsSinglePtr for Utf8Chunked {}
 impl AsSinglePtr for CategoricalChunked {}
+#[cfg(feature = "dtype-decimal")]
+impl AsSinglePtr for DecimalChunked {}
+#[cfg(feature = "dtype-binary")]
+impl AsSinglePtr for BinaryChunked {}
 #[cfg(feature = "object")]
 impl<T> AsSinglePtr for ObjectChunked<T> {}
 
@@ -820,6 +873,15 @@ impl ValueSize for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ValueSize for BinaryChunked {
+    fn get_values_size(&self) -> usize {
+        self.chunks
+            .iter()
+            .fold(0usize, |acc, arr| acc + arr.get_values_size())
+    }
+}
+
 impl ListChunked {
     /// Get the inner data type of the list.
     pub fn inner_dtype(&self) -> DataType {
@@ -1020,4 +1082,21 @@ pub(crate) mod test {
         let v: Vec<_> = ca.into_iter().collect();
         assert_eq!(v, &[Some(0), None, Some(1), Some(2)]);
     }
+
+    #[test]
+    fn test_par_apply() {
+        let mut ca = Int32Chunked::new_from_opt_slice("a", &[Some(1), None, Some(3)]);
+        ca.append(&Int32Chunked::new_from_opt_slice("a", &[Some(4), None]));
+
+        let out = ca.par_apply_values(|v| v * 2);
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(2), None, Some(6), Some(8), None]);
+
+        let out = ca.par_apply(|opt_v| opt_v.map_or(Some(0), |v| Some(v + 1)));
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(2), Some(0), Some(4), Some(5), Some(0)]
+        );
+        assert_eq!(out.null_count(), 0);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::NoNull;
 use ndarray::prelude::*;
 
 impl<T> ChunkedArray<T>
@@ -12,6 +13,40 @@ where
         let slice = self.cont_slice()?;
         Ok(aview1(slice))
     }
+
+    /// Create a `ChunkedArray` from a 1 dimensional `ndarray`. A contiguous array is copied at
+    /// once, otherwise the values are gathered one by one.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn from_ndarray(name: &str, array: ArrayView1<T::Native>) -> Self {
+        match array.as_slice() {
+            Some(slice) => Self::new_from_slice(name, slice),
+            None => {
+                let mut ca = array.iter().copied().collect::<NoNull<Self>>().into_inner();
+                ca.rename(name);
+                ca
+            }
+        }
+    }
+}
+
+impl Series {
+    /// Create a `Series` of type `N` from a 1 dimensional `ndarray`.
+    ///
+    /// ```rust
+    /// use ndarray::arr1;
+    /// use polars_core::prelude::*;
+    ///
+    /// let s = Series::from_ndarray_1d::<Float64Type>("a", arr1(&[1.0, 2.0]).view());
+    /// assert_eq!(s.dtype(), &DataType::Float64);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn from_ndarray_1d<N>(name: &str, array: ArrayView1<N::Native>) -> Series
+    where
+        N: PolarsNumericType,
+        ChunkedArray<N>: IntoSeries,
+    {
+        ChunkedArray::<N>::from_ndarray(name, array).into_series()
+    }
 }
 
 impl ListChunked {
@@ -110,4 +145,42 @@ impl DataFrame {
         }
         Ok(ndarr)
     }
+
+    /// Create a `DataFrame` from a 2D `ndarray::Array`, with a column per column of the array.
+    /// The columns of an array in column-major (Fortran) layout are contiguous and are copied at
+    /// once; for other layouts the values are gathered one by one.
+    ///
+    /// ```rust
+    /// use ndarray::arr2;
+    /// use polars_core::prelude::*;
+    ///
+    /// let array = arr2(&[[1.0, 10.0], [2.0, 8.0], [3.0, 6.0]]);
+    /// let df = DataFrame::from_ndarray::<Float64Type>(array.view(), &["a", "b"]).unwrap();
+    /// assert_eq!(df.shape(), (3, 2));
+    /// assert_eq!(df.to_ndarray::<Float64Type>().unwrap(), array);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn from_ndarray<N>(array: ArrayView2<N::Native>, column_names: &[&str]) -> Result<Self>
+    where
+        N: PolarsNumericType,
+        ChunkedArray<N>: IntoSeries,
+    {
+        let n_columns = array.len_of(Axis(1));
+        if n_columns != column_names.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "the array has {} columns, but {} column names are given",
+                    n_columns,
+                    column_names.len()
+                )
+                .into(),
+            ));
+        }
+        let columns = array
+            .axis_iter(Axis(1))
+            .zip(column_names)
+            .map(|(column, name)| Series::from_ndarray_1d::<N>(name, column))
+            .collect();
+        DataFrame::new(columns)
+    }
 }
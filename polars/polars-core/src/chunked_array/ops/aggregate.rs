@@ -30,7 +30,11 @@ pub trait ChunkAggSeries {
         unimplemented!()
     }
     /// Get the quantile of the ChunkedArray as a new Series of length 1.
-    fn quantile_as_series(&self, _quantile: f64) -> Result<Series> {
+    fn quantile_as_series(
+        &self,
+        _quantile: f64,
+        _interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
         unimplemented!()
     }
 }
@@ -62,19 +66,35 @@ macro_rules! agg_float_with_nans {
     }};
 }
 
-macro_rules! impl_quantile {
-    ($self:expr, $quantile:expr) => {{
-        let null_count = $self.null_count();
-        let opt = ChunkSort::sort($self, false)
-            .slice(
-                ((($self.len() - null_count) as f64) * $quantile + null_count as f64) as i64,
-                1,
-            )
-            .into_iter()
-            .next()
-            .unwrap();
-        opt
-    }};
+/// Compute the quantile of values that are sorted in ascending order.
+pub(crate) fn quantile_sorted(
+    sorted: &[f64],
+    quantile: f64,
+    interpol: QuantileInterpolOptions,
+) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let pos = quantile * (sorted.len() - 1) as f64;
+    let (lower, upper) = (sorted[pos.floor() as usize], sorted[pos.ceil() as usize]);
+    let out = match interpol {
+        QuantileInterpolOptions::Nearest => sorted[pos.round() as usize],
+        QuantileInterpolOptions::Lower => lower,
+        QuantileInterpolOptions::Higher => upper,
+        QuantileInterpolOptions::Midpoint => (lower + upper) / 2.0,
+        QuantileInterpolOptions::Linear => lower + (upper - lower) * (pos - pos.floor()),
+    };
+    Some(out)
+}
+
+pub(crate) fn check_quantile(quantile: f64) -> Result<()> {
+    if (0.0..=1.0).contains(&quantile) {
+        Ok(())
+    } else {
+        Err(PolarsError::ValueError(
+            "quantile should be between 0.0 and 1.0".into(),
+        ))
+    }
 }
 
 impl<T> ChunkAgg<T::Native> for ChunkedArray<T>
@@ -141,15 +161,15 @@ where
         }
     }
 
-    fn quantile(&self, quantile: f64) -> Result<Option<T::Native>> {
-        if !(0.0..=1.0).contains(&quantile) {
-            Err(PolarsError::ValueError(
-                "quantile should be between 0.0 and 1.0".into(),
-            ))
-        } else {
-            let opt = impl_quantile!(self, quantile);
-            Ok(opt)
-        }
+    fn quantile(&self, quantile: f64, interpol: QuantileInterpolOptions) -> Result<Option<f64>> {
+        check_quantile(quantile)?;
+        let mut values = self
+            .into_iter()
+            .flatten()
+            .map(|v| v.to_f64().unwrap())
+            .collect::<Vec<_>>();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(quantile_sorted(&values, quantile, interpol))
     }
 }
 
@@ -294,11 +314,13 @@ where
         let val = [self.median()];
         Series::new(self.name(), val)
     }
-    fn quantile_as_series(&self, quantile: f64) -> Result<Series> {
-        let v = self.quantile(quantile)?;
-        let mut ca: ChunkedArray<T> = [v].iter().copied().collect();
-        ca.rename(self.name());
-        Ok(ca.into_series())
+    fn quantile_as_series(
+        &self,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
+        let val = [self.quantile(quantile, interpol)?];
+        Ok(Series::new(self.name(), val))
     }
 }
 
@@ -417,7 +439,11 @@ impl ChunkAggSeries for BooleanChunked {
     fn median_as_series(&self) -> Series {
         BooleanChunked::full_null(self.name(), 1).into_series()
     }
-    fn quantile_as_series(&self, _quantile: f64) -> Result<Series> {
+    fn quantile_as_series(
+        &self,
+        _quantile: f64,
+        _interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
         Ok(BooleanChunked::full_null(self.name(), 1).into_series())
     }
 }
@@ -446,7 +472,11 @@ impl ChunkAggSeries for Utf8Chunked {
     fn median_as_series(&self) -> Series {
         one_null_utf8!(self)
     }
-    fn quantile_as_series(&self, _quantile: f64) -> Result<Series> {
+    fn quantile_as_series(
+        &self,
+        _quantile: f64,
+        _interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
         Ok(one_null_utf8!(self))
     }
 }
@@ -477,7 +507,11 @@ impl ChunkAggSeries for ListChunked {
     fn median_as_series(&self) -> Series {
         one_null_list!(self)
     }
-    fn quantile_as_series(&self, _quantile: f64) -> Result<Series> {
+    fn quantile_as_series(
+        &self,
+        _quantile: f64,
+        _interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
         Ok(one_null_list!(self))
     }
 }
@@ -624,4 +658,24 @@ mod test {
         assert_eq!(ca.mean(), None);
         assert_eq!(ca.mean_as_series().f32().unwrap().get(0), None);
     }
+
+    #[test]
+    fn test_quantile() -> Result<()> {
+        use QuantileInterpolOptions::*;
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(4), None, Some(1), Some(3), Some(2)]);
+        assert_eq!(ca.quantile(0.5, Nearest)?, Some(3.0));
+        assert_eq!(ca.quantile(0.5, Lower)?, Some(2.0));
+        assert_eq!(ca.quantile(0.5, Higher)?, Some(3.0));
+        assert_eq!(ca.quantile(0.5, Midpoint)?, Some(2.5));
+        assert_eq!(ca.quantile(0.5, Linear)?, Some(2.5));
+        assert_eq!(ca.quantile(0.25, Linear)?, Some(1.75));
+        assert_eq!(ca.quantile(1.0, Higher)?, Some(4.0));
+        assert!(ca.quantile(1.5, Nearest).is_err());
+
+        let out = ca.quantile_as_series(0.0, Linear)?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(out.f64()?.get(0), Some(1.0));
+        assert_eq!(Int32Chunked::full_null("a", 2).quantile(0.5, Linear)?, None);
+        Ok(())
+    }
 }
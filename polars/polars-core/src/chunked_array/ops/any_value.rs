@@ -79,6 +79,30 @@ impl ChunkAnyValue for CategoricalChunked {
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+impl ChunkAnyValue for DecimalChunked {
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, index: usize) -> AnyValue {
+        get_any_value_unchecked!(self, index)
+    }
+
+    fn get_any_value(&self, index: usize) -> AnyValue {
+        get_any_value!(self, index)
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl ChunkAnyValue for BinaryChunked {
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, index: usize) -> AnyValue {
+        get_any_value_unchecked!(self, index)
+    }
+
+    fn get_any_value(&self, index: usize) -> AnyValue {
+        get_any_value!(self, index)
+    }
+}
+
 #[cfg(feature = "object")]
 impl<T: PolarsObject> ChunkAnyValue for ObjectChunked<T> {
     #[inline]
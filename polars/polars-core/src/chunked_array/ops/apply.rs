@@ -1,7 +1,9 @@
 //! Implementations of the ChunkApply Trait.
 use crate::prelude::*;
-use crate::utils::{CustomIterTools, NoNull};
+use crate::utils::{split_ca, CustomIterTools, NoNull};
+use crate::POOL;
 use arrow::array::{Array, ArrayRef, BooleanArray, LargeStringArray, PrimitiveArray};
+use rayon::prelude::*;
 use std::borrow::Cow;
 use std::convert::TryFrom;
 
@@ -116,6 +118,59 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Split the array in a slice per thread and apply `f` on the slices in parallel. The
+    /// results are appended in order, so the output has (at least) a chunk per slice.
+    fn par_apply_slices<F>(&self, f: F) -> Self
+    where
+        F: Fn(&Self) -> Self + Send + Sync,
+    {
+        let n_threads = std::cmp::max(std::cmp::min(POOL.current_num_threads(), self.len()), 1);
+        let slices = split_ca(self, n_threads).unwrap();
+        let cas: Vec<_> = POOL.install(|| slices.par_iter().map(f).collect());
+
+        let mut iter = cas.into_iter();
+        let mut out = iter.next().unwrap();
+        for ca in iter {
+            out.append(&ca);
+        }
+        out.rename(self.name());
+        out
+    }
+
+    /// Apply a closure elementwise on the values in parallel. Use this instead of
+    /// [apply](ChunkApply::apply) if the closure is expensive.
+    ///
+    /// Null values remain null.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn expensive(ca: &Float64Chunked) -> Float64Chunked {
+    ///     ca.par_apply_values(|v| (0..100).fold(v, |acc, _| acc.sin()))
+    /// }
+    /// ```
+    pub fn par_apply_values<F>(&self, f: F) -> Self
+    where
+        F: Fn(T::Native) -> T::Native + Copy + Send + Sync,
+    {
+        self.par_apply_slices(|ca| ca.apply(f))
+    }
+
+    /// Apply a closure on the optional values in parallel. The closure determines which values
+    /// of the output are null.
+    pub fn par_apply<F>(&self, f: F) -> Self
+    where
+        F: Fn(Option<T::Native>) -> Option<T::Native> + Copy + Send + Sync,
+    {
+        self.par_apply_slices(|ca| ca.into_iter().map(f).collect())
+    }
+}
+
 impl<'a> ChunkApply<'a, bool, bool> for BooleanChunked {
     fn apply_cast_numeric<F, S>(&self, f: F) -> ChunkedArray<S>
     where
@@ -54,6 +54,19 @@ impl ChunkOps for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkOps for BinaryChunked {
+    fn rechunk(&self) -> Self {
+        if self.chunks().len() == 1 {
+            self.clone()
+        } else {
+            let chunks =
+                vec![concat(&self.chunks.iter().map(|a| &**a).collect_vec().as_slice()).unwrap()];
+            ChunkedArray::new_from_chunks(self.name(), chunks)
+        }
+    }
+}
+
 impl ChunkOps for CategoricalChunked {
     fn rechunk(&self) -> Self
     where
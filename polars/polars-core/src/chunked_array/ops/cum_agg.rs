@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use itertools::__std_iter::FromIterator;
 use num::Bounded;
-use std::ops::{Add, AddAssign};
+use std::ops::{Add, AddAssign, Mul};
 
 fn det_max<T>(state: &mut T, v: Option<T>) -> Option<Option<T>>
 where
@@ -50,10 +50,27 @@ where
     }
 }
 
+fn det_prod<T>(state: &mut Option<T>, v: Option<T>) -> Option<Option<T>>
+where
+    T: Copy + PartialOrd + Mul<Output = T>,
+{
+    match (*state, v) {
+        (Some(state_inner), Some(v)) => {
+            *state = Some(state_inner * v);
+            Some(*state)
+        }
+        (None, Some(v)) => {
+            *state = Some(v);
+            Some(*state)
+        }
+        (_, None) => Some(None),
+    }
+}
+
 impl<T> ChunkCumAgg<T> for ChunkedArray<T>
 where
     T: PolarsNumericType,
-    T::Native: Bounded + PartialOrd + AddAssign + Add<Output = T::Native>,
+    T::Native: Bounded + PartialOrd + AddAssign + Add<Output = T::Native> + Mul<Output = T::Native>,
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
 {
     fn cum_max(&self, reverse: bool) -> ChunkedArray<T> {
@@ -100,6 +117,21 @@ where
             ca
         }
     }
+
+    fn cum_prod(&self, reverse: bool) -> ChunkedArray<T> {
+        let init = None;
+        let mut ca: Self = match reverse {
+            false => self.into_iter().scan(init, det_prod).collect(),
+            true => self.into_iter().rev().scan(init, det_prod).collect(),
+        };
+
+        ca.rename(self.name());
+        if reverse {
+            ca.reverse()
+        } else {
+            ca
+        }
+    }
 }
 
 impl ChunkCumAgg<CategoricalType> for CategoricalChunked {}
@@ -147,4 +179,55 @@ mod test {
         );
         let _out = ca.cum_sum(false);
     }
+
+    #[test]
+    fn test_cum_prod() {
+        let ca = Int32Chunked::new_from_opt_slice("foo", &[None, Some(2), Some(3), None, Some(4)]);
+        let out = ca.cum_prod(true);
+        assert_eq!(Vec::from(&out), &[None, Some(24), Some(12), None, Some(4)]);
+        let out = ca.cum_prod(false);
+        assert_eq!(Vec::from(&out), &[None, Some(2), Some(6), None, Some(24)]);
+    }
+
+    #[test]
+    fn test_cum_prod_no_overflow() {
+        let s = Series::new("foo", &[100_000u32, 100_000]);
+        let out = s.cum_prod(false);
+        assert_eq!(
+            Vec::from(out.i64().unwrap()),
+            &[Some(100_000), Some(10_000_000_000)]
+        );
+        let s = Series::new("foo", &[1e30f32, 1e30]);
+        assert_eq!(
+            s.cum_prod(false).f64().unwrap().get(1),
+            Some(1e30f32 as f64 * 1e30f32 as f64)
+        );
+    }
+
+    #[test]
+    fn test_cum_agg_multiple_chunks() {
+        let mut ca = Int32Chunked::new_from_slice("foo", &[3, 1]);
+        ca.append(&Int32Chunked::new_from_opt_slice(
+            "foo",
+            &[None, Some(4), Some(2)],
+        ));
+        assert_eq!(ca.chunks().len(), 2);
+
+        assert_eq!(
+            Vec::from(&ca.cum_max(false)),
+            &[Some(3), Some(3), None, Some(4), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(&ca.cum_min(true)),
+            &[Some(1), Some(1), None, Some(2), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(&ca.cum_prod(false)),
+            &[Some(3), Some(3), None, Some(12), Some(24)]
+        );
+        assert_eq!(
+            Vec::from(&ca.cum_prod(true)),
+            &[Some(24), Some(8), None, Some(8), Some(2)]
+        );
+    }
 }
@@ -2,6 +2,10 @@
 use crate::chunked_array::object::ObjectArray;
 use crate::prelude::*;
 use crate::utils::index_to_chunked_index;
+#[cfg(feature = "dtype-decimal")]
+use arrow::array::DecimalArray;
+#[cfg(feature = "dtype-binary")]
+use arrow::array::LargeBinaryArray;
 use arrow::array::{
     Array, ArrayRef, BooleanArray, LargeListArray, LargeStringArray, PrimitiveArray,
 };
@@ -122,6 +126,48 @@ impl ListChunked {
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+impl DecimalChunked {
+    pub fn downcast_iter(&self) -> impl Iterator<Item = &DecimalArray> + DoubleEndedIterator {
+        self.chunks.iter().map(|arr| {
+            let arr = &**arr;
+            unsafe { &*(arr as *const dyn Array as *const DecimalArray) }
+        })
+    }
+    pub fn downcast_chunks(&self) -> Chunks<'_, DecimalArray> {
+        Chunks::new(&self.chunks)
+    }
+
+    #[inline]
+    pub(crate) fn index_to_chunked_index(&self, index: usize) -> (usize, usize) {
+        if self.chunks.len() == 1 {
+            return (0, index);
+        }
+        index_to_chunked_index(self.downcast_iter().map(|arr| arr.len()), index)
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl BinaryChunked {
+    pub fn downcast_iter(&self) -> impl Iterator<Item = &LargeBinaryArray> + DoubleEndedIterator {
+        self.chunks.iter().map(|arr| {
+            let arr = &**arr;
+            unsafe { &*(arr as *const dyn Array as *const LargeBinaryArray) }
+        })
+    }
+    pub fn downcast_chunks(&self) -> Chunks<'_, LargeBinaryArray> {
+        Chunks::new(&self.chunks)
+    }
+
+    #[inline]
+    pub(crate) fn index_to_chunked_index(&self, index: usize) -> (usize, usize) {
+        if self.chunks.len() == 1 {
+            return (0, index);
+        }
+        index_to_chunked_index(self.downcast_iter().map(|arr| arr.len()), index)
+    }
+}
+
 #[cfg(feature = "object")]
 impl<'a, T> ObjectChunked<T>
 where
@@ -0,0 +1,255 @@
+//! Exponentially weighted moving aggregations.
+use crate::prelude::*;
+use num::NumCast;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EwmOptions {
+    /// Smoothing factor `0 < alpha <= 1`. The weight of an observation decays with `1 - alpha`
+    /// per time step.
+    pub alpha: f64,
+    /// Divide by the decaying sum of the weights of the observations, which corrects for the
+    /// imbalance of the weights at the start of the array. If `false`, the weighted
+    /// aggregations are computed recursively.
+    pub adjust: bool,
+    /// Compute the biased variance and standard deviation instead of the unbiased ones.
+    pub bias: bool,
+    /// Amount of non-null observations that should be seen before computing a result.
+    pub min_periods: usize,
+    /// Skip the null values when computing the weights. If `false`, the weights are based on
+    /// the absolute positions, so the weights of the older observations also decay at a null
+    /// value.
+    pub ignore_nulls: bool,
+}
+
+impl EwmOptions {
+    /// Options with smoothing factor `alpha`.
+    pub fn alpha(alpha: f64) -> Self {
+        EwmOptions {
+            alpha,
+            adjust: true,
+            bias: false,
+            min_periods: 1,
+            ignore_nulls: false,
+        }
+    }
+
+    /// Options with the smoothing factor given by the span: `alpha = 2 / (span + 1)`.
+    pub fn span(span: f64) -> Self {
+        Self::alpha(2.0 / (span + 1.0))
+    }
+}
+
+impl Default for EwmOptions {
+    fn default() -> Self {
+        Self::alpha(0.5)
+    }
+}
+
+fn check_options(options: &EwmOptions) -> Result<()> {
+    if options.alpha > 0.0 && options.alpha <= 1.0 {
+        Ok(())
+    } else {
+        Err(PolarsError::ValueError(
+            format!(
+                "alpha of an exponentially weighted aggregation should be in (0, 1], got {}",
+                options.alpha
+            )
+            .into(),
+        ))
+    }
+}
+
+/// Exponentially weighted mean. A null value outputs the mean of the observations before it.
+fn ewm_mean_iter<I>(values: I, options: &EwmOptions) -> Vec<Option<f64>>
+where
+    I: Iterator<Item = Option<f64>>,
+{
+    let old_wt_factor = 1.0 - options.alpha;
+    let new_wt = if options.adjust { 1.0 } else { options.alpha };
+    let mut weighted_avg: Option<f64> = None;
+    let mut old_wt = 1.0;
+    let mut nobs = 0;
+
+    values
+        .map(|opt_v| {
+            if opt_v.is_some() {
+                nobs += 1;
+            }
+            match (weighted_avg, opt_v) {
+                (Some(avg), opt_v) if opt_v.is_some() || !options.ignore_nulls => {
+                    old_wt *= old_wt_factor;
+                    if let Some(v) = opt_v {
+                        weighted_avg = Some((old_wt * avg + new_wt * v) / (old_wt + new_wt));
+                        if options.adjust {
+                            old_wt += new_wt;
+                        } else {
+                            old_wt = 1.0;
+                        }
+                    }
+                }
+                (None, Some(v)) => weighted_avg = Some(v),
+                _ => {}
+            }
+            if nobs >= options.min_periods {
+                weighted_avg
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Exponentially weighted variance.
+fn ewm_var_iter<I>(values: I, options: &EwmOptions) -> Vec<Option<f64>>
+where
+    I: Iterator<Item = Option<f64>>,
+{
+    let old_wt_factor = 1.0 - options.alpha;
+    let new_wt = if options.adjust { 1.0 } else { options.alpha };
+    let mut mean: Option<f64> = None;
+    let mut var = 0.0;
+    let mut sum_wt = 1.0;
+    let mut sum_wt2 = 1.0;
+    let mut old_wt = 1.0;
+    let mut nobs = 0;
+
+    values
+        .map(|opt_v| {
+            if opt_v.is_some() {
+                nobs += 1;
+            }
+            match (mean, opt_v) {
+                (Some(old_mean), opt_v) if opt_v.is_some() || !options.ignore_nulls => {
+                    sum_wt *= old_wt_factor;
+                    sum_wt2 *= old_wt_factor * old_wt_factor;
+                    old_wt *= old_wt_factor;
+                    if let Some(v) = opt_v {
+                        let new_mean = (old_wt * old_mean + new_wt * v) / (old_wt + new_wt);
+                        let old_delta = old_mean - new_mean;
+                        let new_delta = v - new_mean;
+                        var = (old_wt * (var + old_delta * old_delta)
+                            + new_wt * new_delta * new_delta)
+                            / (old_wt + new_wt);
+                        mean = Some(new_mean);
+                        sum_wt += new_wt;
+                        sum_wt2 += new_wt * new_wt;
+                        old_wt += new_wt;
+                        if !options.adjust {
+                            sum_wt /= old_wt;
+                            sum_wt2 /= old_wt * old_wt;
+                            old_wt = 1.0;
+                        }
+                    }
+                }
+                (None, Some(v)) => mean = Some(v),
+                _ => {}
+            }
+            if nobs < options.min_periods || mean.is_none() {
+                None
+            } else if options.bias {
+                Some(var)
+            } else {
+                // correct the bias with the effective number of observations
+                let numerator = sum_wt * sum_wt;
+                let denominator = numerator - sum_wt2;
+                if denominator > 0.0 {
+                    Some(numerator / denominator * var)
+                } else {
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: NumCast,
+{
+    fn ewm_values(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        self.into_iter().map(|opt_v| opt_v.and_then(NumCast::from))
+    }
+
+    /// Compute the exponentially weighted moving mean.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ewma")))]
+    pub fn ewm_mean(&self, options: &EwmOptions) -> Result<Float64Chunked> {
+        check_options(options)?;
+        let mut out: Float64Chunked = ewm_mean_iter(self.ewm_values(), options)
+            .into_iter()
+            .collect();
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    /// Compute the exponentially weighted moving variance.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ewma")))]
+    pub fn ewm_var(&self, options: &EwmOptions) -> Result<Float64Chunked> {
+        check_options(options)?;
+        let mut out: Float64Chunked = ewm_var_iter(self.ewm_values(), options)
+            .into_iter()
+            .collect();
+        out.rename(self.name());
+        Ok(out)
+    }
+
+    /// Compute the exponentially weighted moving standard deviation.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ewma")))]
+    pub fn ewm_std(&self, options: &EwmOptions) -> Result<Float64Chunked> {
+        self.ewm_var(options).map(|ca| ca.apply(|v| v.sqrt()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ewm_mean() -> Result<()> {
+        let ca = Float64Chunked::new_from_opt_slice("a", &[Some(1.0), None, Some(3.0)]);
+
+        // the weight of the first observation decays twice, as the null value takes a step
+        let out = ca.ewm_mean(&EwmOptions::alpha(0.5))?;
+        assert_eq!(Vec::from(&out), &[Some(1.0), Some(1.0), Some(2.6)]);
+
+        let options = EwmOptions {
+            ignore_nulls: true,
+            ..EwmOptions::alpha(0.5)
+        };
+        let out = ca.ewm_mean(&options)?;
+        assert_eq!(Vec::from(&out), &[Some(1.0), Some(1.0), Some(7.0 / 3.0)]);
+
+        let options = EwmOptions {
+            adjust: false,
+            ..EwmOptions::alpha(0.5)
+        };
+        let ca = Int32Chunked::new_from_slice("a", &[1, 3, 5]);
+        let out = ca.ewm_mean(&options)?;
+        assert_eq!(Vec::from(&out), &[Some(1.0), Some(2.0), Some(3.5)]);
+
+        assert!(ca.ewm_mean(&EwmOptions::alpha(0.0)).is_err());
+        assert_eq!(EwmOptions::span(3.0), EwmOptions::alpha(0.5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ewm_var() -> Result<()> {
+        let ca = Float64Chunked::new_from_slice("a", &[1.0, 3.0]);
+        let options = EwmOptions {
+            adjust: false,
+            ..EwmOptions::alpha(0.5)
+        };
+        let out = ca.ewm_var(&options)?;
+        assert_eq!(Vec::from(&out), &[None, Some(2.0)]);
+
+        let options = EwmOptions {
+            bias: true,
+            ..options
+        };
+        let out = ca.ewm_var(&options)?;
+        assert_eq!(Vec::from(&out), &[Some(0.0), Some(1.0)]);
+        let out = ca.ewm_std(&options)?;
+        assert_eq!(Vec::from(&out), &[Some(0.0), Some(1.0)]);
+        Ok(())
+    }
+}
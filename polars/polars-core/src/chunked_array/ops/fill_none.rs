@@ -2,78 +2,55 @@ use crate::prelude::*;
 use num::{Bounded, Num, NumCast, One, Zero};
 use std::ops::{Add, Div};
 
-fn fill_forward<T>(ca: &ChunkedArray<T>) -> ChunkedArray<T>
+/// Replace the null values with the previous non-null value. At most `limit` consecutive
+/// null values are filled.
+fn fill_forward<T, I>(iter: I, limit: Option<u32>) -> impl Iterator<Item = Option<T>>
 where
-    T: PolarsNumericType,
+    T: Copy,
+    I: Iterator<Item = Option<T>>,
 {
-    ca.into_iter()
-        .scan(None, |previous, opt_v| {
-            let val = match opt_v {
-                Some(_) => Some(opt_v),
-                None => Some(*previous),
-            };
-            *previous = opt_v;
-            val
-        })
-        .collect()
-}
-
-macro_rules! impl_fill_forward {
-    ($ca:ident) => {{
-        let ca = $ca
-            .into_iter()
-            .scan(None, |previous, opt_v| {
-                let val = match opt_v {
-                    Some(_) => Some(opt_v),
-                    None => Some(*previous),
-                };
-                *previous = opt_v;
-                val
-            })
-            .collect();
-        Ok(ca)
-    }};
+    let limit = limit.unwrap_or(u32::MAX);
+    let mut previous = None;
+    let mut count = 0;
+    iter.map(move |opt_v| match opt_v {
+        Some(_) => {
+            previous = opt_v;
+            count = 0;
+            opt_v
+        }
+        None if count < limit => {
+            count += 1;
+            previous
+        }
+        None => None,
+    })
 }
 
-fn fill_backward<T>(ca: &ChunkedArray<T>) -> ChunkedArray<T>
+/// Replace the null values with the next non-null value. At most `limit` consecutive
+/// null values are filled.
+fn fill_backward<T, I>(iter: I, limit: Option<u32>) -> impl Iterator<Item = Option<T>>
 where
-    T: PolarsNumericType,
+    T: Copy,
+    I: Iterator<Item = Option<T>>,
 {
-    let mut iter = ca.into_iter().peekable();
+    let values: Vec<_> = iter.collect();
+    let filled: Vec<_> = fill_forward(values.into_iter().rev(), limit).collect();
+    filled.into_iter().rev()
+}
 
-    let mut builder = PrimitiveChunkedBuilder::<T>::new(ca.name(), ca.len());
-    while let Some(opt_v) = iter.next() {
-        match opt_v {
-            Some(v) => builder.append_value(v),
-            None => {
-                match iter.peek() {
-                    // end of iterator
-                    None => builder.append_null(),
-                    Some(opt_v) => builder.append_option(*opt_v),
-                }
-            }
-        }
-    }
-    builder.finish()
+macro_rules! impl_fill_forward {
+    ($ca:ident, $limit:expr) => {{
+        let mut ca: Self = fill_forward($ca.into_iter(), $limit).collect();
+        ca.rename($ca.name());
+        ca
+    }};
 }
 
 macro_rules! impl_fill_backward {
-    ($ca:ident, $builder:ident) => {{
-        let mut iter = $ca.into_iter().peekable();
-
-        while let Some(opt_v) = iter.next() {
-            match opt_v {
-                Some(v) => $builder.append_value(v),
-                None => {
-                    match iter.peek() {
-                        // end of iterator
-                        None => $builder.append_null(),
-                        Some(opt_v) => $builder.append_option(*opt_v),
-                    }
-                }
-            }
-        }
-        Ok($builder.finish())
+    ($ca:ident, $limit:expr) => {{
+        let mut ca: Self = fill_backward($ca.into_iter(), $limit).collect();
+        ca.rename($ca.name());
+        ca
     }};
 }
 
@@ -95,8 +72,8 @@ where
             return Ok(self.clone());
         }
         let ca = match strategy {
-            FillNoneStrategy::Forward => fill_forward(self),
-            FillNoneStrategy::Backward => fill_backward(self),
+            FillNoneStrategy::Forward(limit) => impl_fill_forward!(self, limit),
+            FillNoneStrategy::Backward(limit) => impl_fill_backward!(self, limit),
             FillNoneStrategy::Min => self
                 .fill_none_with_value(self.min().ok_or_else(|| {
                     PolarsError::Other("Could not determine fill value".into())
@@ -135,10 +112,9 @@ impl ChunkFillNone for BooleanChunked {
         if self.null_count() == 0 {
             return Ok(self.clone());
         }
-        let mut builder = BooleanChunkedBuilder::new(self.name(), self.len());
         match strategy {
-            FillNoneStrategy::Forward => impl_fill_forward!(self),
-            FillNoneStrategy::Backward => impl_fill_backward!(self, builder),
+            FillNoneStrategy::Forward(limit) => Ok(impl_fill_forward!(self, limit)),
+            FillNoneStrategy::Backward(limit) => Ok(impl_fill_backward!(self, limit)),
             FillNoneStrategy::Min => self.fill_none_with_value(
                 1 == self
                     .min()
@@ -170,12 +146,9 @@ impl ChunkFillNone for Utf8Chunked {
         if self.null_count() == 0 {
             return Ok(self.clone());
         }
-        let factor = self.len() as f32 / (self.len() - self.null_count()) as f32;
-        let value_cap = (self.get_values_size() as f32 * 1.25 * factor) as usize;
-        let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len(), value_cap);
         match strategy {
-            FillNoneStrategy::Forward => impl_fill_forward!(self),
-            FillNoneStrategy::Backward => impl_fill_backward!(self, builder),
+            FillNoneStrategy::Forward(limit) => Ok(impl_fill_forward!(self, limit)),
+            FillNoneStrategy::Backward(limit) => Ok(impl_fill_backward!(self, limit)),
             strat => Err(PolarsError::InvalidOperation(
                 format!("Strategy {:?} not supported", strat).into(),
             )),
@@ -238,12 +211,12 @@ mod test {
     fn test_fill_none() {
         let ca =
             Int32Chunked::new_from_opt_slice("", &[None, Some(2), Some(3), None, Some(4), None]);
-        let filled = ca.fill_none(FillNoneStrategy::Forward).unwrap();
+        let filled = ca.fill_none(FillNoneStrategy::Forward(None)).unwrap();
         assert_eq!(
             Vec::from(&filled),
             &[None, Some(2), Some(3), Some(3), Some(4), Some(4)]
         );
-        let filled = ca.fill_none(FillNoneStrategy::Backward).unwrap();
+        let filled = ca.fill_none(FillNoneStrategy::Backward(None)).unwrap();
         assert_eq!(
             Vec::from(&filled),
             &[Some(2), Some(2), Some(3), Some(4), Some(4), None]
@@ -265,4 +238,25 @@ mod test {
         );
         println!("{:?}", filled);
     }
+
+    #[test]
+    fn test_fill_none_limit() -> Result<()> {
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(1), None, None, None, Some(5), None]);
+        let filled = ca.fill_none(FillNoneStrategy::Forward(Some(2)))?;
+        assert_eq!(filled.name(), "a");
+        assert_eq!(
+            Vec::from(&filled),
+            &[Some(1), Some(1), Some(1), None, Some(5), Some(5)]
+        );
+        let filled = ca.fill_none(FillNoneStrategy::Backward(Some(1)))?;
+        assert_eq!(
+            Vec::from(&filled),
+            &[Some(1), None, None, Some(5), Some(5), None]
+        );
+
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[None, Some("a"), None, None]);
+        let filled = ca.fill_none(FillNoneStrategy::Forward(None))?;
+        assert_eq!(Vec::from(&filled), &[None, Some("a"), Some("a"), Some("a")]);
+        Ok(())
+    }
 }
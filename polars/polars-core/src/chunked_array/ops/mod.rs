@@ -6,6 +6,8 @@ use crate::prelude::*;
 use crate::series::implementations::SeriesWrap;
 use crate::utils::NoNull;
 use arrow::array::{ArrayRef, UInt32Array};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::marker::Sized;
 
 pub(crate) mod aggregate;
@@ -15,12 +17,16 @@ pub(crate) mod bit_repr;
 pub(crate) mod chunkops;
 pub(crate) mod cum_agg;
 pub(crate) mod downcast;
+#[cfg(feature = "ewma")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ewma")))]
+pub(crate) mod ewm;
 pub(crate) mod explode;
 pub(crate) mod fill_none;
 pub(crate) mod filter;
 #[cfg(feature = "is_in")]
 #[cfg_attr(docsrs, doc(cfg(feature = "is_in")))]
 pub(crate) mod is_in;
+pub(crate) mod moment;
 pub(crate) mod peaks;
 pub(crate) mod set;
 pub(crate) mod shift;
@@ -67,6 +73,10 @@ pub trait ChunkCumAgg<T> {
     fn cum_sum(&self, _reverse: bool) -> ChunkedArray<T> {
         panic!("operation cum_sum not supported for this dtype")
     }
+    /// Get an array with the cumulative product computed at every element
+    fn cum_prod(&self, _reverse: bool) -> ChunkedArray<T> {
+        panic!("operation cum_prod not supported for this dtype")
+    }
 }
 
 /// Traverse and collect every nth element
@@ -202,6 +212,52 @@ pub trait ChunkWindow {
             "rolling mean not supported for this datatype".into(),
         ))
     }
+
+    /// Apply a rolling variance (moving variance) over the values in this array.
+    /// A window of length `window_size` will traverse the array. The values that fill this window
+    /// will (optionally) be multiplied with the weights given by the `weight` vector. The resulting
+    /// values will be aggregated to their sample variance (`ddof = 1`).
+    ///
+    /// # Arguments
+    ///
+    /// * `window_size` - The length of the window.
+    /// * `weight` - An optional slice with the same length of the window that will be multiplied
+    ///              elementwise with the values in the window.
+    /// * `ignore_null` - Toggle behavior of aggregation regarding null values in the window.
+    ///                     `true` -> Null values will be ignored.
+    ///                     `false` -> Any Null in the window leads to a Null in the aggregation result.
+    /// * `min_periods` -  Amount of elements in the window that should be filled before computing a result.
+    fn rolling_var(
+        &self,
+        _window_size: u32,
+        _weight: Option<&[f64]>,
+        _ignore_null: bool,
+        _min_periods: u32,
+    ) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        Err(PolarsError::InvalidOperation(
+            "rolling var not supported for this datatype".into(),
+        ))
+    }
+
+    /// Apply a rolling standard deviation (moving standard deviation) over the values in this
+    /// array. See [rolling_var](ChunkWindow::rolling_var).
+    fn rolling_std(
+        &self,
+        _window_size: u32,
+        _weight: Option<&[f64]>,
+        _ignore_null: bool,
+        _min_periods: u32,
+    ) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        Err(PolarsError::InvalidOperation(
+            "rolling std not supported for this datatype".into(),
+        ))
+    }
 }
 
 /// Custom rolling window functions
@@ -468,6 +524,29 @@ pub trait ChunkApply<'a, A, B> {
         F: Fn((usize, Option<A>)) -> Option<B> + Copy;
 }
 
+/// How to compute a quantile that lies between two values `i < j` of the data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QuantileInterpolOptions {
+    /// `i` or `j`, whichever is nearest.
+    Nearest,
+    /// `i`
+    Lower,
+    /// `j`
+    Higher,
+    /// `(i + j) / 2`
+    Midpoint,
+    /// `i + (j - i) * fraction`, where `fraction` is the fractional part of the position
+    /// between `i` and `j`.
+    Linear,
+}
+
+impl Default for QuantileInterpolOptions {
+    fn default() -> Self {
+        QuantileInterpolOptions::Nearest
+    }
+}
+
 /// Aggregation operations
 pub trait ChunkAgg<T> {
     /// Aggregate the sum of the ChunkedArray.
@@ -499,7 +578,7 @@ pub trait ChunkAgg<T> {
 
     /// Aggregate a given quantile of the ChunkedArray.
     /// Returns `None` if the array is empty or only contains null values.
-    fn quantile(&self, _quantile: f64) -> Result<Option<T>> {
+    fn quantile(&self, _quantile: f64, _interpol: QuantileInterpolOptions) -> Result<Option<f64>> {
         Ok(None)
     }
 }
@@ -585,10 +664,10 @@ pub trait ChunkUnique<T> {
         ))
     }
 
-    /// Count the unique values.
-    fn value_counts(&self) -> Result<DataFrame> {
+    /// Count the unique values. The output is sorted by the counts in descending order.
+    fn value_counts(&self, _include_nulls: bool) -> Result<DataFrame> {
         Err(PolarsError::InvalidOperation(
-            "is_duplicated is not implemented for this dtype".into(),
+            "value_counts is not implemented for this dtype".into(),
         ))
     }
 }
@@ -622,10 +701,12 @@ pub trait ChunkSort<T> {
 
 #[derive(Copy, Clone, Debug)]
 pub enum FillNoneStrategy {
-    /// previous value in array
-    Backward,
-    /// next value in array
-    Forward,
+    /// Next non-null value in the array. Fills at most the given number of consecutive
+    /// null values, or all of them if `None`.
+    Backward(Option<u32>),
+    /// Previous non-null value in the array. Fills at most the given number of consecutive
+    /// null values, or all of them if `None`.
+    Forward(Option<u32>),
     /// mean value of array
     Mean,
     /// minimal value in array
@@ -642,6 +723,25 @@ pub enum FillNoneStrategy {
     MinBound,
 }
 
+/// Determines which boundaries of an interval are included.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ClosedWindow {
+    /// Include both boundaries.
+    Both,
+    /// Include the lower boundary.
+    Left,
+    /// Include the upper boundary.
+    Right,
+    /// Include neither boundary.
+    None,
+}
+
+impl Default for ClosedWindow {
+    fn default() -> Self {
+        ClosedWindow::Both
+    }
+}
+
 /// Replace None values with various strategies
 pub trait ChunkFillNone {
     /// Replace None values with one of the following strategies:
@@ -740,6 +840,30 @@ impl ChunkFullNull for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl<'a> ChunkFull<&'a [u8]> for BinaryChunked {
+    fn full(name: &str, value: &'a [u8], length: usize) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, length);
+
+        for _ in 0..length {
+            builder.append_value(value);
+        }
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl ChunkFullNull for BinaryChunked {
+    fn full_null(name: &str, length: usize) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, length);
+
+        for _ in 0..length {
+            builder.append_null();
+        }
+        builder.finish()
+    }
+}
+
 impl ChunkFull<&Series> for ListChunked {
     fn full(name: &str, value: &Series, length: usize) -> ListChunked {
         let mut builder = get_list_builder(value.dtype(), value.len() * length, length, name);
@@ -811,6 +935,8 @@ macro_rules! impl_reverse {
 impl_reverse!(BooleanType, BooleanChunked);
 impl_reverse!(Utf8Type, Utf8Chunked);
 impl_reverse!(ListType, ListChunked);
+#[cfg(feature = "dtype-binary")]
+impl_reverse!(BinaryType, BinaryChunked);
 #[cfg(feature = "object")]
 impl<T: PolarsObject> ChunkReverse<ObjectType<T>> for ObjectChunked<T> {
     fn reverse(&self) -> Self {
@@ -869,6 +995,13 @@ impl ChunkExpandAtIndex<BooleanType> for BooleanChunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkExpandAtIndex<BinaryType> for BinaryChunked {
+    fn expand_at_index(&self, index: usize, length: usize) -> BinaryChunked {
+        impl_chunk_expand!(self, length, index)
+    }
+}
+
 impl ChunkExpandAtIndex<Utf8Type> for Utf8Chunked {
     fn expand_at_index(&self, index: usize, length: usize) -> Utf8Chunked {
         impl_chunk_expand!(self, length, index)
@@ -0,0 +1,94 @@
+//! Higher order moments of numeric ChunkedArrays.
+use crate::prelude::*;
+use num::ToPrimitive;
+
+/// Returns the number of valid values and the 2nd, 3rd and 4th central moments.
+fn central_moments<T>(ca: &ChunkedArray<T>) -> Option<(f64, f64, f64, f64)>
+where
+    T: PolarsNumericType,
+    T::Native: ToPrimitive,
+{
+    let n = (ca.len() - ca.null_count()) as f64;
+    if n == 0.0 {
+        return None;
+    }
+    let mean = ca
+        .into_iter()
+        .flatten()
+        .map(|v| v.to_f64().unwrap())
+        .sum::<f64>()
+        / n;
+    let (m2, m3, m4) = ca
+        .into_iter()
+        .flatten()
+        .fold((0.0, 0.0, 0.0), |(m2, m3, m4), v| {
+            let d = v.to_f64().unwrap() - mean;
+            let d2 = d * d;
+            (m2 + d2, m3 + d2 * d, m4 + d2 * d2)
+        });
+    Some((n, m2 / n, m3 / n, m4 / n))
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: ToPrimitive,
+{
+    /// Compute the sample skewness of the valid values.
+    ///
+    /// If `bias` is false, the result is corrected for statistical bias. This correction needs
+    /// at least 3 values.
+    pub fn skew(&self, bias: bool) -> Option<f64> {
+        let (n, m2, m3, _) = central_moments(self)?;
+        let skew = m3 / m2.powf(1.5);
+        if !bias && n > 2.0 {
+            Some((n * (n - 1.0)).sqrt() / (n - 2.0) * skew)
+        } else {
+            Some(skew)
+        }
+    }
+
+    /// Compute the kurtosis of the valid values.
+    ///
+    /// If `fisher` is true, 3.0 is subtracted so that a normal distribution has a kurtosis of 0.0
+    /// (Fisher's definition), otherwise Pearson's definition is used.
+    /// If `bias` is false, the result is corrected for statistical bias. This correction needs
+    /// at least 4 values.
+    pub fn kurtosis(&self, fisher: bool, bias: bool) -> Option<f64> {
+        let (n, m2, _, m4) = central_moments(self)?;
+        let mut kurt = m4 / (m2 * m2);
+        if !bias && n > 3.0 {
+            kurt = ((n * n - 1.0) * kurt - 3.0 * (n - 1.0) * (n - 1.0)) / ((n - 2.0) * (n - 3.0))
+                + 3.0;
+        }
+        if fisher {
+            kurt -= 3.0;
+        }
+        Some(kurt)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_skew_kurtosis() {
+        let ca = Int32Chunked::new_from_opt_slice(
+            "a",
+            &[Some(1), None, Some(2), Some(3), Some(4), Some(10)],
+        );
+        let skew = ca.skew(true).unwrap();
+        assert!((skew - 1.1384199576606167).abs() < 1e-12);
+        let skew = ca.skew(false).unwrap();
+        assert!((skew - 1.1384199576606167 * 20f64.sqrt() / 3.0).abs() < 1e-12);
+
+        assert!((ca.kurtosis(false, true).unwrap() - 2.788).abs() < 1e-12);
+        assert!((ca.kurtosis(true, true).unwrap() + 0.212).abs() < 1e-12);
+        assert!((ca.kurtosis(true, false).unwrap() - 3.152).abs() < 1e-12);
+
+        let ca = Float64Chunked::new_from_opt_slice("a", &[None]);
+        assert_eq!(ca.skew(true), None);
+        assert_eq!(ca.kurtosis(true, true), None);
+    }
+}
@@ -470,6 +470,53 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkSort<BinaryType> for BinaryChunked {
+    fn sort(&self, reverse: bool) -> BinaryChunked {
+        let sort_parallel = sort_parallel(self);
+
+        let mut v = Vec::from_iter(self);
+        sort_branch(
+            v.as_mut_slice(),
+            sort_parallel,
+            reverse,
+            order_default_null,
+            order_reverse_null,
+        );
+
+        let mut builder = BinaryChunkedBuilder::new(self.name(), self.len());
+        v.into_iter().for_each(|opt_v| builder.append_option(opt_v));
+        builder.finish()
+    }
+
+    fn sort_in_place(&mut self, reverse: bool) {
+        let sorted = self.sort(reverse);
+        self.chunks = sorted.chunks;
+    }
+
+    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+        let sort_parallel = sort_parallel(self);
+
+        let mut vals: Vec<_> = self
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| (i as u32, v))
+            .collect();
+        argsort_branch(
+            vals.as_mut_slice(),
+            sort_parallel,
+            reverse,
+            |(_, a), (_, b)| order_default_null(a, b),
+            |(_, a), (_, b)| order_reverse_null(a, b),
+        );
+
+        let ca: NoNull<UInt32Chunked> = vals.into_iter().map(|(idx, _v)| idx).collect();
+        let mut ca = ca.into_inner();
+        ca.rename(self.name());
+        ca
+    }
+}
+
 impl ChunkSort<CategoricalType> for CategoricalChunked {
     fn sort(&self, reverse: bool) -> Self {
         self.as_ref().sort(reverse).cast().unwrap()
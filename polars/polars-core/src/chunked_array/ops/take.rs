@@ -517,6 +517,38 @@ impl ChunkTake for CategoricalChunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkTake for BinaryChunked {
+    unsafe fn take_unchecked<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Self
+    where
+        Self: std::marker::Sized,
+        I: Iterator<Item = usize>,
+        INulls: Iterator<Item = Option<usize>>,
+    {
+        self.take(indices)
+    }
+
+    fn take<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Self
+    where
+        Self: std::marker::Sized,
+        I: Iterator<Item = usize>,
+        INulls: Iterator<Item = Option<usize>>,
+    {
+        let mut ca: BinaryChunked = match indices {
+            TakeIdx::Array(array) => array
+                .into_iter()
+                .map(|opt_idx| opt_idx.and_then(|idx| self.get(idx as usize)))
+                .collect(),
+            TakeIdx::Iter(iter) => iter.map(|idx| self.get(idx)).collect(),
+            TakeIdx::IterNulls(iter) => iter
+                .map(|opt_idx| opt_idx.and_then(|idx| self.get(idx)))
+                .collect(),
+        };
+        ca.rename(self.name());
+        ca
+    }
+}
+
 #[cfg(feature = "object")]
 impl<T: PolarsObject> ChunkTake for ObjectChunked<T> {
     // TODO! implement unsafe unchecked
@@ -648,6 +680,15 @@ impl ChunkTakeEvery<Utf8Type> for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkTakeEvery<BinaryType> for BinaryChunked {
+    fn take_every(&self, n: usize) -> BinaryChunked {
+        let mut ca: BinaryChunked = self.into_iter().step_by(n).collect();
+        ca.rename(self.name());
+        ca
+    }
+}
+
 impl ChunkTakeEvery<ListType> for ListChunked {
     fn take_every(&self, n: usize) -> ListChunked {
         if self.null_count() == 0 {
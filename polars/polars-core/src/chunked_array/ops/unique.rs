@@ -130,7 +130,7 @@ macro_rules! arg_unique_ca {
 }
 
 macro_rules! impl_value_counts {
-    ($self:expr) => {{
+    ($self:expr, $include_nulls:expr) => {{
         let group_tuples = $self.group_tuples(true);
         let values =
             unsafe { $self.take_unchecked(group_tuples.iter().map(|t| t.0 as usize).into()) };
@@ -139,8 +139,14 @@ macro_rules! impl_value_counts {
             .map(|(_, groups)| groups.len() as u32)
             .collect();
         counts.rename("counts");
-        let cols = vec![values.into_series(), counts.into_inner().into_series()];
-        let df = DataFrame::new_no_checks(cols);
+        let values = values.into_series();
+        let not_null = values.is_not_null();
+        let has_nulls = values.null_count() > 0;
+        let cols = vec![values, counts.into_inner().into_series()];
+        let mut df = DataFrame::new_no_checks(cols);
+        if !$include_nulls && has_nulls {
+            df = df.filter(&not_null)?;
+        }
         df.sort("counts", true)
     }};
 }
@@ -171,8 +177,8 @@ where
         Ok(is_duplicated(self))
     }
 
-    fn value_counts(&self) -> Result<DataFrame> {
-        impl_value_counts!(self)
+    fn value_counts(&self, include_nulls: bool) -> Result<DataFrame> {
+        impl_value_counts!(self, include_nulls)
     }
 }
 
@@ -199,8 +205,36 @@ impl ChunkUnique<Utf8Type> for Utf8Chunked {
         Ok(is_duplicated(self))
     }
 
-    fn value_counts(&self) -> Result<DataFrame> {
-        impl_value_counts!(self)
+    fn value_counts(&self, include_nulls: bool) -> Result<DataFrame> {
+        impl_value_counts!(self, include_nulls)
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl ChunkUnique<BinaryType> for BinaryChunked {
+    fn unique(&self) -> Result<Self> {
+        let set = fill_set(self.into_iter());
+        let mut ca: Self = set.iter().copied().collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    fn arg_unique(&self) -> Result<UInt32Chunked> {
+        Ok(UInt32Chunked::new_from_aligned_vec(
+            self.name(),
+            arg_unique(self.into_iter(), self.len()),
+        ))
+    }
+
+    fn is_unique(&self) -> Result<BooleanChunked> {
+        Ok(is_unique(self))
+    }
+    fn is_duplicated(&self) -> Result<BooleanChunked> {
+        Ok(is_duplicated(self))
+    }
+
+    fn value_counts(&self, include_nulls: bool) -> Result<DataFrame> {
+        impl_value_counts!(self, include_nulls)
     }
 }
 
@@ -226,8 +260,8 @@ impl ChunkUnique<CategoricalType> for CategoricalChunked {
         Ok(is_duplicated(self))
     }
 
-    fn value_counts(&self) -> Result<DataFrame> {
-        impl_value_counts!(self)
+    fn value_counts(&self, include_nulls: bool) -> Result<DataFrame> {
+        impl_value_counts!(self, include_nulls)
     }
 }
 
@@ -352,6 +386,10 @@ impl ChunkUnique<BooleanType> for BooleanChunked {
     fn is_duplicated(&self) -> Result<BooleanChunked> {
         Ok(is_duplicated(self))
     }
+
+    fn value_counts(&self, include_nulls: bool) -> Result<DataFrame> {
+        impl_value_counts!(self, include_nulls)
+    }
 }
 
 fn float_unique<T>(ca: &ChunkedArray<T>) -> ChunkedArray<T>
@@ -415,8 +453,8 @@ impl ChunkUnique<Float32Type> for Float32Chunked {
     fn is_duplicated(&self) -> Result<BooleanChunked> {
         Ok(is_duplicated(self))
     }
-    fn value_counts(&self) -> Result<DataFrame> {
-        impl_value_counts!(self)
+    fn value_counts(&self, include_nulls: bool) -> Result<DataFrame> {
+        impl_value_counts!(self, include_nulls)
     }
 }
 
@@ -438,8 +476,8 @@ impl ChunkUnique<Float64Type> for Float64Chunked {
     fn is_duplicated(&self) -> Result<BooleanChunked> {
         Ok(is_duplicated(self))
     }
-    fn value_counts(&self) -> Result<DataFrame> {
-        impl_value_counts!(self)
+    fn value_counts(&self, include_nulls: bool) -> Result<DataFrame> {
+        impl_value_counts!(self, include_nulls)
     }
 }
 
@@ -478,6 +516,36 @@ mod test {
         );
     }
 
+    #[test]
+    fn value_counts() -> Result<()> {
+        let ca = Utf8Chunked::new_from_opt_slice(
+            "a",
+            &[Some("a"), None, Some("b"), Some("a"), None, None],
+        );
+        let df = ca.value_counts(true)?;
+        assert_eq!(df.get_column_names(), &["a", "counts"]);
+        assert_eq!(
+            Vec::from(df.column("a")?.utf8()?),
+            &[None, Some("a"), Some("b")]
+        );
+        assert_eq!(
+            Vec::from(df.column("counts")?.u32()?),
+            &[Some(3), Some(2), Some(1)]
+        );
+
+        let df = ca.value_counts(false)?;
+        assert_eq!(Vec::from(df.column("a")?.utf8()?), &[Some("a"), Some("b")]);
+        assert_eq!(Vec::from(df.column("counts")?.u32()?), &[Some(2), Some(1)]);
+
+        let ca = BooleanChunked::new_from_slice("a", &[true, false, true]);
+        let df = ca.value_counts(false)?;
+        assert_eq!(
+            Vec::from(df.column("a")?.bool()?),
+            &[Some(true), Some(false)]
+        );
+        Ok(())
+    }
+
     #[test]
     fn is_unique() {
         let ca = Float32Chunked::new_from_slice("a", &[1., 2., 1., 1., 3.]);
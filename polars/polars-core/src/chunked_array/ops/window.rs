@@ -90,14 +90,20 @@ where
     }
 }
 
-fn rescale_window<T>(window: &[Option<T>], weight: &[T]) -> Vec<Option<T>>
+/// Multiply the values in the ring buffer `window` with the `weight` that belongs to their
+/// position. The first weight belongs to the oldest value, which is stored at `oldest_idx`.
+fn rescale_window<T>(window: &[Option<T>], weight: &[T], oldest_idx: usize) -> Vec<Option<T>>
 where
     T: Mul<Output = T> + Copy,
 {
+    let window_size = window.len();
     window
         .iter()
-        .zip(weight)
-        .map(|(opt_a, &b)| opt_a.map(|a| a * b))
+        .enumerate()
+        .map(|(i, opt_a)| {
+            let b = weight[(i + window_size - oldest_idx) % window_size];
+            opt_a.map(|a| a * b)
+        })
         .collect()
 }
 
@@ -142,6 +148,7 @@ fn update_state<T>(
 fn apply_window<T, F>(
     weight: Option<&[T]>,
     window: &[Option<T>],
+    oldest_idx: u32,
     fold_fn: F,
     init_fold: InitFold,
 ) -> Option<T>
@@ -157,7 +164,7 @@ where
 
     match weight {
         None => window.iter().copied().fold(Some(init), fold_fn),
-        Some(weight) => rescale_window(window, weight)
+        Some(weight) => rescale_window(window, weight, oldest_idx as usize)
             .into_iter()
             .fold(Some(init), fold_fn),
     }
@@ -197,11 +204,12 @@ where
         ca.into_no_null_iter()
             .scan((window, 0u32, 0u32), |state, v| {
                 idx_count = update_state(state, idx_count, Some(v), window_size);
-                let (window, _, some_count) = state;
+                let (window, oldest_idx, some_count) = state;
                 if *some_count < min_periods {
                     Some(None)
                 } else {
-                    let sum = apply_window(weight.as_deref(), window, fold_fn, init_fold);
+                    let sum =
+                        apply_window(weight.as_deref(), window, *oldest_idx, fold_fn, init_fold);
                     Some(sum)
                 }
             })
@@ -210,17 +218,110 @@ where
         ca.into_iter()
             .scan((window, 0u32, 0u32), |state, opt_v| {
                 idx_count = update_state(state, idx_count, opt_v, window_size);
-                let (window, _, some_count) = state;
+                let (window, oldest_idx, some_count) = state;
                 if *some_count < min_periods {
                     Some(None)
                 } else {
-                    Some(apply_window(weight.as_deref(), window, fold_fn, init_fold))
+                    Some(apply_window(
+                        weight.as_deref(),
+                        window,
+                        *oldest_idx,
+                        fold_fn,
+                        init_fold,
+                    ))
                 }
             })
             .collect()
     }
 }
 
+/// Compute the sample variance of every window. The variance cannot be computed with a single
+/// fold, so the windows are traversed explicitly.
+fn rolling_var_f64<T>(
+    ca: &ChunkedArray<T>,
+    window_size: u32,
+    weight: Option<&[f64]>,
+    ignore_null: bool,
+    min_periods: u32,
+) -> Vec<Option<f64>>
+where
+    T: PolarsNumericType,
+    T::Native: NumCast,
+{
+    let window_size = window_size as usize;
+    let values: Vec<Option<f64>> = ca
+        .into_iter()
+        .map(|opt_v| opt_v.and_then(NumCast::from))
+        .collect();
+    let mut window_values = Vec::with_capacity(window_size);
+    (0..values.len())
+        .map(|i| {
+            let window = &values[(i + 1).saturating_sub(window_size)..=i];
+            // like the folds, a window that is not filled yet counts as having null values
+            if !ignore_null && window.len() < window_size {
+                return None;
+            }
+            // the weights are aligned with the end of the window
+            let weight_offset = window_size - window.len();
+            window_values.clear();
+            for (j, opt_v) in window.iter().enumerate() {
+                match (opt_v, weight) {
+                    (Some(v), Some(weight)) => window_values.push(v * weight[weight_offset + j]),
+                    (Some(v), None) => window_values.push(*v),
+                    (None, _) if !ignore_null => return None,
+                    (None, _) => {}
+                }
+            }
+            let n = window_values.len();
+            if n < min_periods as usize || n < 2 {
+                return None;
+            }
+            let mean = window_values.iter().sum::<f64>() / n as f64;
+            let squared = window_values
+                .iter()
+                .map(|v| (v - mean) * (v - mean))
+                .sum::<f64>();
+            Some(squared / (n - 1) as f64)
+        })
+        .collect()
+}
+
+/// The aggregation of [Series::rolling](crate::series::Series::rolling).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RollingFunction {
+    Mean,
+    Sum,
+    Min,
+    Max,
+    Var,
+    Std,
+}
+
+/// Options of [Series::rolling](crate::series::Series::rolling).
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollingOptions {
+    /// The length of the window.
+    pub window_size: u32,
+    /// Amount of non-null elements in the window that should be filled before computing a result.
+    pub min_periods: u32,
+    /// An optional slice with the same length of the window that will be multiplied
+    /// elementwise with the values in the window.
+    pub weights: Option<Vec<f64>>,
+    /// Set the result at the center of the window instead of at the end of the window.
+    pub center: bool,
+}
+
+impl Default for RollingOptions {
+    fn default() -> Self {
+        RollingOptions {
+            window_size: 3,
+            min_periods: 1,
+            weights: None,
+            center: false,
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum InitFold {
     Zero,
@@ -249,7 +350,7 @@ where
         ignore_null: bool,
         min_periods: u32,
     ) -> Result<Self> {
-        check_input(window_size, min_periods)?;
+        check_input(window_size, weight, min_periods)?;
         let fold_fn = if ignore_null {
             sum_fold_ignore_null::<T::Native>
         } else {
@@ -273,7 +374,7 @@ where
         ignore_null: bool,
         min_periods: u32,
     ) -> Result<Self> {
-        check_input(window_size, min_periods)?;
+        check_input(window_size, weight, min_periods)?;
         let rolling_window_size = self.window_size(window_size, None, min_periods);
         let ca = self.rolling_sum(window_size, weight, ignore_null, min_periods)?;
         Ok((&ca).div(&rolling_window_size))
@@ -286,7 +387,7 @@ where
         ignore_null: bool,
         min_periods: u32,
     ) -> Result<Self> {
-        check_input(window_size, min_periods)?;
+        check_input(window_size, weight, min_periods)?;
         let fold_fn = if ignore_null {
             min_fold_ignore_null::<T::Native>
         } else {
@@ -310,7 +411,7 @@ where
         ignore_null: bool,
         min_periods: u32,
     ) -> Result<Self> {
-        check_input(window_size, min_periods)?;
+        check_input(window_size, weight, min_periods)?;
         let fold_fn = if ignore_null {
             max_fold_ignore_null::<T::Native>
         } else {
@@ -326,6 +427,38 @@ where
             min_periods,
         ))
     }
+
+    fn rolling_var(
+        &self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Result<Self> {
+        check_input(window_size, weight, min_periods)?;
+        Ok(
+            rolling_var_f64(self, window_size, weight, ignore_null, min_periods)
+                .into_iter()
+                .map(|opt_v| opt_v.and_then(NumCast::from))
+                .collect(),
+        )
+    }
+
+    fn rolling_std(
+        &self,
+        window_size: u32,
+        weight: Option<&[f64]>,
+        ignore_null: bool,
+        min_periods: u32,
+    ) -> Result<Self> {
+        check_input(window_size, weight, min_periods)?;
+        Ok(
+            rolling_var_f64(self, window_size, weight, ignore_null, min_periods)
+                .into_iter()
+                .map(|opt_v| opt_v.and_then(|v| NumCast::from(v.sqrt())))
+                .collect(),
+        )
+    }
 }
 
 impl<T> ChunkWindowCustom<T::Native> for ChunkedArray<T>
@@ -363,11 +496,15 @@ where
 }
 
 /// utility
-fn check_input(window_size: u32, min_periods: u32) -> Result<()> {
+fn check_input(window_size: u32, weight: Option<&[f64]>, min_periods: u32) -> Result<()> {
     if min_periods > window_size {
         Err(PolarsError::ValueError(
             "`windows_size` should be >= `min_periods`".into(),
         ))
+    } else if matches!(weight, Some(weight) if weight.len() < window_size as usize) {
+        Err(PolarsError::ValueError(
+            "`weight` should have a value for every element in the window".into(),
+        ))
     } else {
         Ok(())
     }
@@ -480,4 +617,58 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_rolling_weights() {
+        // the first weight belongs to the oldest value in the window
+        let ca = Int32Chunked::new_from_slice("foo", &[1, 2, 3, 4]);
+        let a = ca.rolling_sum(2, Some(&[0., 1.]), true, 0).unwrap();
+        assert_eq!(Vec::from(&a), &[Some(1), Some(2), Some(3), Some(4)]);
+        assert!(ca.rolling_sum(2, Some(&[1.]), true, 0).is_err());
+    }
+
+    #[test]
+    fn test_rolling_var_std() {
+        let ca = Float64Chunked::new_from_slice("foo", &[1.0, 3.0, 7.0, 7.0]);
+        let a = ca.rolling_var(2, None, true, 1).unwrap();
+        assert_eq!(Vec::from(&a), &[None, Some(2.0), Some(8.0), Some(0.0)]);
+        let a = ca.rolling_std(2, None, true, 1).unwrap();
+        assert_eq!(
+            Vec::from(&a),
+            &[None, Some(2.0f64.sqrt()), Some(8.0f64.sqrt()), Some(0.0)]
+        );
+        // a null value in the window leads to a null without `ignore_null`
+        let ca = Float64Chunked::new_from_opt_slice("foo", &[Some(1.0), None, Some(3.0)]);
+        let a = ca.rolling_var(3, None, false, 1).unwrap();
+        assert_eq!(Vec::from(&a), &[None, None, None]);
+
+        // the variance of integers is computed in Float64
+        let s = Series::new("foo", &[1, 2, 4]);
+        let out = s.rolling_var(2, None, true, 1).unwrap();
+        assert_eq!(Vec::from(out.f64().unwrap()), &[None, Some(0.5), Some(2.0)]);
+        let out = s.rolling_std(2, None, true, 1).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[None, Some(0.5f64.sqrt()), Some(2.0f64.sqrt())]
+        );
+        assert!(Series::new("foo", &["a", "b"])
+            .rolling_var(2, None, true, 1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_rolling_center() {
+        let s = Series::new("foo", &[1.0, 2.0, 3.0, 4.0, 5.0]);
+        let options = RollingOptions {
+            window_size: 4,
+            center: true,
+            ..Default::default()
+        };
+        let out = s.rolling(RollingFunction::Max, &options).unwrap();
+        assert_eq!(out.name(), "foo");
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(2.0), Some(3.0), Some(4.0), Some(5.0), Some(5.0)]
+        );
+    }
 }
@@ -115,6 +115,26 @@ impl ChunkZip<Utf8Type> for Utf8Chunked {
         }
     }
 }
+#[cfg(feature = "dtype-binary")]
+impl ChunkZip<BinaryType> for BinaryChunked {
+    fn zip_with(&self, mask: &BooleanChunked, other: &BinaryChunked) -> Result<BinaryChunked> {
+        if self.len() != mask.len() || other.len() != mask.len() {
+            impl_ternary_broadcast!(self, self.len(), other.len(), other, mask, BinaryType)
+        } else {
+            let (left, right, mask) = align_chunks_ternary(self, other, mask);
+            let chunks = left
+                .downcast_iter()
+                .zip(right.downcast_iter())
+                .zip(mask.downcast_iter())
+                .map(|((left_c, right_c), mask_c)| {
+                    let arr = zip(mask_c, left_c, right_c)?;
+                    Ok(arr)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
+        }
+    }
+}
 impl ChunkZip<ListType> for ListChunked {
     fn zip_with(
         &self,
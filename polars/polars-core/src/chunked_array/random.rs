@@ -1,7 +1,8 @@
+use crate::frame::select::Selection;
 use crate::prelude::*;
 use crate::utils::NoNull;
 use num::{Float, NumCast};
-use rand::distributions::Bernoulli;
+use rand::distributions::{Bernoulli, WeightedIndex};
 use rand::prelude::*;
 use rand::seq::IteratorRandom;
 use rand_distr::{Distribution, Normal, StandardNormal, Uniform};
@@ -26,70 +27,197 @@ fn create_rand_index_no_replacement(
     (rng, (0..len).choose_multiple(&mut rng, n).into_iter())
 }
 
+/// Create the indices of a sample of `n` out of `len` values.
+///
+/// If `weights` are given, every index is sampled with a probability proportional to its weight.
+/// Null weights count as 0.0 and indices with a weight of 0.0 are never sampled. If `shuffle` is
+/// false, the indices are sorted so that the sample keeps the original order.
+fn create_sample_idx(
+    n: usize,
+    len: usize,
+    with_replacement: bool,
+    shuffle: bool,
+    weights: Option<&Series>,
+) -> Result<UInt32Chunked> {
+    if !with_replacement && n > len {
+        return Err(PolarsError::ShapeMisMatch(
+            "n is larger than the number of elements in this array".into(),
+        ));
+    }
+    let mut idx: Vec<u32> = match weights {
+        None => match with_replacement {
+            true => create_rand_index_with_replacement(n, len)
+                .1
+                .map(|i| i as u32)
+                .collect(),
+            false => create_rand_index_no_replacement(n, len)
+                .1
+                .map(|i| i as u32)
+                .collect(),
+        },
+        Some(weights) => {
+            if weights.len() != len {
+                return Err(PolarsError::ShapeMisMatch(
+                    "the weights should have the same length as the sampled data".into(),
+                ));
+            }
+            let weights = weights.cast::<Float64Type>()?;
+            let weights: Vec<f64> = weights
+                .f64()?
+                .into_iter()
+                .map(|opt_w| opt_w.unwrap_or(0.0))
+                .collect();
+            if weights.iter().any(|w| w.is_nan() || *w < 0.0) {
+                return Err(PolarsError::ValueError(
+                    "the sample weights should not be negative".into(),
+                ));
+            }
+            let mut rng = rand::thread_rng();
+            if with_replacement {
+                let dist = WeightedIndex::new(&weights)
+                    .map_err(|e| PolarsError::RandError(format!("{:?}", e)))?;
+                (0..n).map(|_| dist.sample(&mut rng) as u32).collect()
+            } else {
+                // Efraimidis-Spirakis: take the n values with the largest u^(1/w) keys
+                let mut keys: Vec<(f64, u32)> = weights
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, w)| **w > 0.0)
+                    .map(|(i, w)| (rng.gen::<f64>().powf(1.0 / w), i as u32))
+                    .collect();
+                if n > keys.len() {
+                    return Err(PolarsError::ShapeMisMatch(
+                        "n is larger than the number of elements with a positive weight".into(),
+                    ));
+                }
+                keys.sort_unstable_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+                keys.into_iter().take(n).map(|(_, i)| i).collect()
+            }
+        }
+    };
+    if shuffle {
+        idx.shuffle(&mut rand::thread_rng());
+    } else {
+        idx.sort_unstable();
+    }
+    let idx: NoNull<UInt32Chunked> = idx.into_iter().collect();
+    Ok(idx.into_inner())
+}
+
 impl<T> ChunkedArray<T>
 where
     ChunkedArray<T>: ChunkTake,
 {
     /// Sample n datapoints from this ChunkedArray.
     pub fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Self> {
-        if !with_replacement && n > self.len() {
-            return Err(PolarsError::ShapeMisMatch(
-                "n is larger than the number of elements in this array".into(),
-            ));
-        }
-        let len = self.len();
-
-        match with_replacement {
-            true => {
-                let (_, iter) = create_rand_index_with_replacement(n, len);
-                // Safety we know that we never go out of bounds
-                debug_assert_eq!(len, self.len());
-                unsafe { Ok(self.take_unchecked(iter.into())) }
-            }
-            false => {
-                let (_, iter) = create_rand_index_no_replacement(n, len);
-                // Safety we know that we never go out of bounds
-                debug_assert_eq!(len, self.len());
-                unsafe { Ok(self.take_unchecked(iter.into())) }
-            }
-        }
+        self.sample_n_weighted(n, with_replacement, true, None)
     }
 
     /// Sample a fraction between 0.0-1.0 of this ChunkedArray.
     pub fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Self> {
+        self.sample_frac_weighted(frac, with_replacement, true, None)
+    }
+
+    /// Sample n datapoints from this ChunkedArray.
+    ///
+    /// * `shuffle` - Shuffle the order of the sampled values. Otherwise the original order is kept.
+    /// * `weights` - Sample every value with a probability proportional to its weight. Values
+    ///   with a weight of 0.0 are never sampled.
+    pub fn sample_n_weighted(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        weights: Option<&Series>,
+    ) -> Result<Self> {
+        let idx = create_sample_idx(n, self.len(), with_replacement, shuffle, weights)?;
+        // Safety we know that we never go out of bounds
+        unsafe { Ok(self.take_unchecked((&idx).into())) }
+    }
+
+    /// Sample a fraction between 0.0-1.0 of this ChunkedArray.
+    /// See [ChunkedArray::sample_n_weighted].
+    pub fn sample_frac_weighted(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        weights: Option<&Series>,
+    ) -> Result<Self> {
         let n = (self.len() as f64 * frac) as usize;
-        self.sample_n(n, with_replacement)
+        self.sample_n_weighted(n, with_replacement, shuffle, weights)
     }
 }
 
 impl DataFrame {
     /// Sample n datapoints from this DataFrame.
     pub fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Self> {
-        if !with_replacement && n > self.height() {
-            return Err(PolarsError::ShapeMisMatch(
-                "n is larger than the number of elements in this array".into(),
-            ));
-        }
+        self.sample_n_weighted(n, with_replacement, true, None)
+    }
+
+    /// Sample a fraction between 0.0-1.0 of this DataFrame.
+    pub fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Self> {
+        self.sample_frac_weighted(frac, with_replacement, true, None)
+    }
+
+    /// Sample n datapoints from this DataFrame.
+    ///
+    /// * `shuffle` - Shuffle the order of the sampled rows. Otherwise the original order is kept.
+    /// * `weights` - Name of a column with weights. Every row is sampled with a probability
+    ///   proportional to its weight. Rows with a weight of 0.0 are never sampled.
+    pub fn sample_n_weighted(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        weights: Option<&str>,
+    ) -> Result<Self> {
+        let weights = weights.map(|name| self.column(name)).transpose()?;
         // all columns should used the same indices. So we first create the indices.
-        let idx: NoNull<UInt32Chunked> = match with_replacement {
-            true => create_rand_index_with_replacement(n, self.height())
-                .1
-                .map(|i| i as u32)
-                .collect(),
-            false => create_rand_index_no_replacement(n, self.height())
-                .1
-                .map(|i| i as u32)
-                .collect(),
-        };
+        let idx = create_sample_idx(n, self.height(), with_replacement, shuffle, weights)?;
         // Safety:
         // indices are withing bounds
-        Ok(unsafe { self.take_unchecked(&idx.into_inner()) })
+        Ok(unsafe { self.take_unchecked(&idx) })
     }
 
-    /// Sample a fraction between 0.0-1.0 of this DataFrame.
-    pub fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Self> {
+    /// Sample a fraction between 0.0-1.0 of this DataFrame. See [DataFrame::sample_n_weighted].
+    pub fn sample_frac_weighted(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        weights: Option<&str>,
+    ) -> Result<Self> {
         let n = (self.height() as f64 * frac) as usize;
-        self.sample_n(n, with_replacement)
+        self.sample_n_weighted(n, with_replacement, shuffle, weights)
+    }
+
+    /// Sample a fraction between 0.0-1.0 of every group of the `by` columns, so that the
+    /// proportions of the groups are preserved in the sample. The number of rows sampled per
+    /// group is rounded to the nearest integer.
+    pub fn sample_stratified<'a, J, S: Selection<'a, J>>(
+        &self,
+        by: S,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+    ) -> Result<Self> {
+        let gb = self.groupby(by)?;
+        let mut idx = Vec::with_capacity((self.height() as f64 * frac) as usize);
+        for (_first, group) in gb.get_groups() {
+            let n = (group.len() as f64 * frac).round() as usize;
+            let group_idx = create_sample_idx(n, group.len(), with_replacement, false, None)?;
+            idx.extend(group_idx.into_no_null_iter().map(|i| group[i as usize]));
+        }
+        if shuffle {
+            idx.shuffle(&mut rand::thread_rng());
+        } else {
+            idx.sort_unstable();
+        }
+        let idx: NoNull<UInt32Chunked> = idx.into_iter().collect();
+        // Safety:
+        // indices are withing bounds
+        Ok(unsafe { self.take_unchecked(&idx.into_inner()) })
     }
 }
 
@@ -176,5 +304,61 @@ mod test {
         assert!(df.sample_frac(0.4, true).is_ok());
         // with replacement can sample more than 100%
         assert!(df.sample_frac(2.0, true).is_ok());
+        assert!(df.sample_n_weighted(3, false, true, None).is_ok());
+
+        // without shuffling the original order is kept
+        let out = df.sample_n_weighted(3, false, false, None).unwrap();
+        let values: Vec<_> = out
+            .column("foo")
+            .unwrap()
+            .i32()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_sample_weighted() -> Result<()> {
+        let df = df![
+            "foo" => &[1, 2, 3, 4, 5],
+            "weight" => &[Some(0.0), Some(1.0), None, Some(0.0), Some(2.0)]
+        ]?;
+        let out = df.sample_n_weighted(2, false, false, Some("weight"))?;
+        assert_eq!(Vec::from(out.column("foo")?.i32()?), &[Some(2), Some(5)]);
+        // rows with a weight of 0.0 are never sampled
+        assert!(df
+            .sample_n_weighted(3, false, false, Some("weight"))
+            .is_err());
+
+        let out = df.sample_n_weighted(20, true, false, Some("weight"))?;
+        assert!(out
+            .column("foo")?
+            .i32()?
+            .into_no_null_iter()
+            .all(|v| v == 2 || v == 5));
+
+        let s = df.column("foo")?;
+        let weights = df.column("weight")?;
+        let out = s.sample_n_weighted(2, false, true, Some(weights))?;
+        assert_eq!(out.len(), 2);
+        assert!(s
+            .sample_n_weighted(2, false, false, Some(&weights.head(Some(2))))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_stratified() -> Result<()> {
+        let df = df![
+            "class" => &["a", "a", "a", "a", "b", "b", "b", "b", "b", "b", "b", "b"],
+            "foo" => &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]
+        ]?;
+        let out = df.sample_stratified("class", 0.5, false, false)?;
+        assert_eq!(out.height(), 6);
+        let class = out.column("class")?.utf8()?;
+        assert_eq!(class.into_no_null_iter().filter(|c| *c == "a").count(), 2);
+        assert_eq!(class.into_no_null_iter().filter(|c| *c == "b").count(), 4);
+        Ok(())
     }
 }
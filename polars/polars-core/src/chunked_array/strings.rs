@@ -1,6 +1,7 @@
 use crate::chunked_array::kernels::strings::string_lengths;
 use crate::prelude::*;
 use arrow::compute::kernels::substring::substring;
+use polars_arrow::array::ValueSize;
 use regex::Regex;
 
 impl Utf8Chunked {
@@ -22,6 +23,40 @@ impl Utf8Chunked {
         Ok(ca)
     }
 
+    /// Extract the capture group `group_index` of the first match of a regex pattern.
+    /// Group 0 is the whole match. Strings that don't match result in a null value.
+    pub fn extract(&self, pat: &str, group_index: usize) -> Result<Utf8Chunked> {
+        let reg = Regex::new(pat)?;
+        let mut ca: Utf8Chunked = self
+            .into_iter()
+            .map(|opt_s| {
+                opt_s.and_then(|s| {
+                    reg.captures(s)
+                        .and_then(|caps| caps.get(group_index))
+                        .map(|m| m.as_str())
+                })
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Extract all the non-overlapping matches of a regex pattern in a list.
+    pub fn extract_all(&self, pat: &str) -> Result<ListChunked> {
+        let reg = Regex::new(pat)?;
+        let mut ca: ListChunked = self
+            .into_iter()
+            .map(|opt_s| {
+                opt_s.map(|s| {
+                    let matches: Vec<_> = reg.find_iter(s).map(|m| m.as_str()).collect();
+                    Series::new("", matches.as_slice())
+                })
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
     /// Replace the leftmost (sub)string by a regex pattern
     pub fn replace(&self, pat: &str, val: &str) -> Result<Utf8Chunked> {
         let reg = Regex::new(pat)?;
@@ -36,6 +71,52 @@ impl Utf8Chunked {
         Ok(self.apply(f))
     }
 
+    /// Split the strings by a literal `pattern` into a list of substrings.
+    pub fn str_split(&self, pattern: &str) -> ListChunked {
+        let mut ca: ListChunked = self
+            .into_iter()
+            .map(|opt_s| {
+                opt_s.map(|s| {
+                    let parts: Vec<_> = s.split(pattern).collect();
+                    Series::new("", parts.as_slice())
+                })
+            })
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Split the strings by a literal `pattern` into exactly `n` new columns, named
+    /// `{name}_0`, `{name}_1`, etc.
+    /// Strings with fewer parts are padded with null values and parts after the first `n` are
+    /// dropped.
+    pub fn split_exact(&self, pattern: &str, n: usize) -> Vec<Utf8Chunked> {
+        let mut builders: Vec<_> = (0..n)
+            .map(|i| {
+                Utf8ChunkedBuilder::new(
+                    &format!("{}_{}", self.name(), i),
+                    self.len(),
+                    self.get_values_size() / n.max(1),
+                )
+            })
+            .collect();
+
+        for opt_s in self.into_iter() {
+            match opt_s {
+                Some(s) => {
+                    let mut parts = s.split(pattern);
+                    for builder in builders.iter_mut() {
+                        builder.append_option(parts.next());
+                    }
+                }
+                None => builders
+                    .iter_mut()
+                    .for_each(|builder| builder.append_null()),
+            }
+        }
+        builders.into_iter().map(|b| b.finish()).collect()
+    }
+
     /// Modify the strings to their lowercase equivalent
     pub fn to_lowercase(&self) -> Utf8Chunked {
         self.apply(|s| str::to_lowercase(s).into())
@@ -63,3 +144,44 @@ impl Utf8Chunked {
         Ok(Self::new_from_chunks(self.name(), chunks))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_extract() -> Result<()> {
+        let ca =
+            Utf8Chunked::new_from_opt_slice("a", &[Some("id: 12, 34"), Some("no digits"), None]);
+        let out = ca.extract(r"id: (\d+)", 1)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some("12"), None, None]);
+
+        let out = ca.extract_all(r"\d+")?;
+        let matches = out.get(0).unwrap();
+        assert_eq!(Vec::from(matches.utf8()?), &[Some("12"), Some("34")]);
+        assert_eq!(out.get(1).unwrap().len(), 0);
+        assert!(out.get(2).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_str_split() -> Result<()> {
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[Some("a_b_c"), Some("d"), None]);
+        let out = ca.str_split("_");
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.get(0).unwrap().utf8()?),
+            &[Some("a"), Some("b"), Some("c")]
+        );
+        assert_eq!(Vec::from(out.get(1).unwrap().utf8()?), &[Some("d")]);
+        assert!(out.get(2).is_none());
+
+        let out = ca.split_exact("_", 2);
+        assert_eq!(out.len(), 2);
+        assert_eq!(out[0].name(), "a_0");
+        assert_eq!(Vec::from(&out[0]), &[Some("a"), Some("d"), None]);
+        assert_eq!(Vec::from(&out[1]), &[Some("b"), None, None]);
+        Ok(())
+    }
+}
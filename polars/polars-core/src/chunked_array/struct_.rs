@@ -0,0 +1,313 @@
+//! Nested records of named fields.
+//!
+//! A [StructChunked] is not backed by a single Arrow array. Every field is a `Series` of the
+//! same length, so that accessing a field is free. An Arrow `StructArray` is only created when
+//! the data leaves polars (e.g. when writing IPC or Parquet).
+use crate::prelude::*;
+use arrow::array::{Array, StructArray};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+#[derive(Clone)]
+pub struct StructChunked {
+    fields: Vec<Series>,
+    field: Field,
+    // a boolean Series that is `true` where the struct itself is not null. `None` if no struct
+    // is null. It is transformed by the same operations as the fields.
+    validity: Option<Series>,
+    // a single StructArray over the (rechunked) fields.
+    chunks: Vec<ArrayRef>,
+}
+
+fn is_valid_at(validity: &Series, index: usize) -> bool {
+    matches!(validity.get(index), AnyValue::Boolean(true))
+}
+
+fn fields_to_struct_array(fields: &[Series], validity: Option<&Series>) -> ArrayRef {
+    let values = fields
+        .iter()
+        .map(|s| {
+            let s = s.rechunk();
+            let arr = s.chunks()[0].clone();
+            (s.field().to_arrow(), arr)
+        })
+        .collect::<Vec<_>>();
+    match validity {
+        Some(validity) => {
+            let mask: BooleanChunked = (0..validity.len())
+                .map(|i| is_valid_at(validity, i))
+                .collect();
+            let bitmap = mask.downcast_iter().next().unwrap().values().clone();
+            Arc::new(StructArray::from((values, bitmap)))
+        }
+        None => Arc::new(StructArray::from(values)),
+    }
+}
+
+impl StructChunked {
+    /// Create a struct from multiple `Series`. The `Series` become the fields of the struct
+    /// and must have the same length and unique names.
+    pub fn new(name: &str, fields: &[Series]) -> Result<Self> {
+        if fields.is_empty() {
+            return Err(PolarsError::NoData(
+                "a struct needs at least one field".into(),
+            ));
+        }
+        let len = fields[0].len();
+        if fields.iter().any(|s| s.len() != len) {
+            return Err(PolarsError::ShapeMisMatch(
+                "the fields of a struct must have the same length".into(),
+            ));
+        }
+        let mut names = HashSet::with_capacity(fields.len());
+        if !fields.iter().all(|s| names.insert(s.name())) {
+            return Err(PolarsError::Duplicate(
+                "the fields of a struct must have unique names".into(),
+            ));
+        }
+        Ok(Self::new_unchecked(name, fields.to_vec(), None))
+    }
+
+    fn new_unchecked(name: &str, fields: Vec<Series>, validity: Option<Series>) -> Self {
+        let dtype = DataType::Struct(fields.iter().map(|s| s.field().clone()).collect());
+        let chunks = vec![fields_to_struct_array(&fields, validity.as_ref())];
+        StructChunked {
+            fields,
+            field: Field::new(name, dtype),
+            validity,
+            chunks,
+        }
+    }
+
+    /// Create a struct from Arrow `StructArray`s.
+    pub fn new_from_chunks(name: &str, chunks: &[ArrayRef]) -> Result<Self> {
+        let arrays = chunks
+            .iter()
+            .map(|arr| {
+                arr.as_any().downcast_ref::<StructArray>().ok_or_else(|| {
+                    PolarsError::DataTypeMisMatch(
+                        format!("expected a struct array, got {:?}", arr.data_type()).into(),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let arrow_fields = match chunks.get(0).map(|arr| arr.data_type()) {
+            Some(ArrowDataType::Struct(fields)) if !fields.is_empty() => fields.clone(),
+            _ => {
+                return Err(PolarsError::NoData(
+                    "cannot create a struct without arrays".into(),
+                ))
+            }
+        };
+
+        let fields = arrow_fields
+            .iter()
+            .enumerate()
+            .map(|(i, fld)| {
+                let field_chunks = arrays.iter().map(|arr| arr.column(i).clone()).collect();
+                Series::try_from((fld.name().as_str(), field_chunks))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let validity = if arrays.iter().any(|arr| arr.null_count() > 0) {
+            let validity: BooleanChunked = arrays
+                .iter()
+                .flat_map(|arr| (0..arr.len()).map(move |i| arr.is_valid(i)))
+                .collect();
+            Some(validity.into_series())
+        } else {
+            None
+        };
+        Ok(Self::new_unchecked(name, fields, validity))
+    }
+
+    /// Get the fields of the struct.
+    pub fn fields(&self) -> &[Series] {
+        &self.fields
+    }
+
+    /// Get a field of the struct by name.
+    pub fn field(&self, name: &str) -> Result<&Series> {
+        self.fields
+            .iter()
+            .find(|s| s.name() == name)
+            .ok_or_else(|| PolarsError::NotFound(name.into()))
+    }
+
+    /// Get the validity of the structs themselves, `None` if no struct is null.
+    pub fn validity(&self) -> Option<&Series> {
+        self.validity.as_ref()
+    }
+
+    /// Whether the struct at `index` is null. The fields may have values there.
+    pub fn is_null_at(&self, index: usize) -> bool {
+        match &self.validity {
+            Some(validity) => !is_valid_at(validity, index),
+            None => false,
+        }
+    }
+
+    /// Get the number of null structs.
+    pub fn null_count(&self) -> usize {
+        match &self.validity {
+            Some(_) => (0..self.len()).filter(|&i| self.is_null_at(i)).count(),
+            None => 0,
+        }
+    }
+
+    /// Turn the fields of the struct into the columns of a DataFrame.
+    pub fn unnest(self) -> DataFrame {
+        DataFrame::new_no_checks(self.fields)
+    }
+
+    pub fn name(&self) -> &str {
+        self.field.name()
+    }
+
+    pub fn rename(&mut self, name: &str) {
+        self.field = Field::new(name, self.field.data_type().clone())
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        self.field.data_type()
+    }
+
+    pub fn ref_field(&self) -> &Field {
+        &self.field
+    }
+
+    pub fn chunks(&self) -> &Vec<ArrayRef> {
+        &self.chunks
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Apply an operation to every field and create a new struct from the results.
+    /// The operation should keep the names of the fields.
+    pub fn apply_fields<F>(&self, f: F) -> Self
+    where
+        F: Fn(&Series) -> Series,
+    {
+        let validity = self.validity.as_ref().map(&f);
+        Self::new_unchecked(self.name(), self.fields.iter().map(f).collect(), validity)
+    }
+
+    /// Apply a fallible operation to every field and create a new struct from the results.
+    pub fn try_apply_fields<F>(&self, f: F) -> Result<Self>
+    where
+        F: Fn(&Series) -> Result<Series>,
+    {
+        let validity = self.validity.as_ref().map(&f).transpose()?;
+        let fields = self.fields.iter().map(f).collect::<Result<Vec<_>>>()?;
+        Ok(Self::new_unchecked(self.name(), fields, validity))
+    }
+
+    /// Combine the fields of `self` and `other` pairwise into a new struct. A missing
+    /// validity is treated as all valid.
+    pub(crate) fn try_combine_fields<F>(&self, other: &StructChunked, f: F) -> Result<Self>
+    where
+        F: Fn(&Series, &Series) -> Result<Series>,
+    {
+        let validity = match (&self.validity, &other.validity) {
+            (None, None) => None,
+            (l, r) => {
+                let all_valid = |ca: &StructChunked| {
+                    BooleanChunked::full(ca.name(), true, ca.len()).into_series()
+                };
+                let l = l.clone().unwrap_or_else(|| all_valid(self));
+                let r = r.clone().unwrap_or_else(|| all_valid(other));
+                Some(f(&l, &r)?)
+            }
+        };
+        let fields = self
+            .fields
+            .iter()
+            .zip(other.fields())
+            .map(|(l, r)| f(l, r))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new_unchecked(self.name(), fields, validity))
+    }
+
+    /// Get the values of all fields at `index`, or `Null` if the struct is null there.
+    pub fn get_any_value(&self, index: usize) -> AnyValue {
+        if self.is_null_at(index) {
+            AnyValue::Null
+        } else {
+            AnyValue::Struct(self.fields.iter().map(|s| s.get(index)).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_struct_fields() -> Result<()> {
+        let a = Series::new("a", &[1, 2, 3]);
+        let b = Series::new("b", &["foo", "bar", "ham"]);
+        let ca = StructChunked::new("s", &[a.clone(), b])?;
+
+        assert_eq!(ca.len(), 3);
+        assert!(ca.field("a")?.series_equal(&a));
+        assert!(ca.field("c").is_err());
+        assert!(matches!(ca.dtype(), DataType::Struct(fields) if fields.len() == 2));
+
+        // round trip through arrow
+        let out = StructChunked::new_from_chunks("s", ca.chunks())?;
+        assert_eq!(out.dtype(), ca.dtype());
+        assert!(out.field("b")?.series_equal(ca.field("b")?));
+
+        assert!(StructChunked::new("s", &[a.clone(), a]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_validity() -> Result<()> {
+        let a = Series::new("a", &[1, 2, 3]);
+        let b = Series::new("b", &[Some("foo"), None, Some("ham")]);
+        let arr = StructArray::from((
+            vec![
+                (a.field().to_arrow(), a.chunks()[0].clone()),
+                (b.field().to_arrow(), b.chunks()[0].clone()),
+            ],
+            arrow::buffer::Buffer::from(&[0b101u8]),
+        ));
+        let ca = StructChunked::new_from_chunks("s", &[Arc::new(arr)])?;
+        assert_eq!(ca.null_count(), 1);
+        assert!(ca.is_null_at(1));
+        assert_eq!(ca.get_any_value(1), AnyValue::Null);
+        // the fields keep their own validity
+        assert_eq!(ca.field("a")?.null_count(), 0);
+        assert_eq!(ca.chunks()[0].null_count(), 1);
+
+        // the validity follows the operations on the fields
+        let s = ca.into_series();
+        let out = s.slice(1, 2);
+        assert_eq!(out.null_count(), 1);
+        assert!(out.struct_()?.is_null_at(0));
+        let out = s.reverse();
+        assert!(out.struct_()?.is_null_at(1));
+        assert!(!out.struct_()?.is_null_at(0));
+
+        let mut appended = StructChunked::new("s", &[a, b])?.into_series();
+        appended.append(&s)?;
+        assert_eq!(
+            Vec::from(&appended.is_null()),
+            &[
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(false)
+            ]
+        );
+        Ok(())
+    }
+}
@@ -49,8 +49,13 @@ pub trait AsNaiveTime {
     fn as_naive_time(&self) -> Vec<Option<NaiveTime>>;
 }
 
+/// Parse a datetime. If `fmt` only describes a date, the time is set to midnight.
 pub fn parse_naive_datetime_from_str(s: &str, fmt: &str) -> Option<NaiveDateTime> {
-    NaiveDateTime::parse_from_str(s, fmt).ok()
+    NaiveDateTime::parse_from_str(s, fmt).ok().or_else(|| {
+        NaiveDate::parse_from_str(s, fmt)
+            .ok()
+            .map(|d| d.and_hms(0, 0, 0))
+    })
 }
 
 pub trait FromNaiveDateTime<T, N> {
@@ -164,6 +169,18 @@ impl AsNaiveTime for Time64NanosecondChunked {
     }
 }
 
+impl Time64NanosecondChunked {
+    /// Format Time64 with a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    pub fn strftime(&self, fmt: &str) -> Utf8Chunked {
+        let mut ca: Utf8Chunked = self
+            .into_iter()
+            .map(|opt_t| opt_t.map(|t| format!("{}", time64_nanosecond_as_time(t).format(fmt))))
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+}
+
 const N_PATTERNS: usize = 7;
 fn date_pattern(val: &str, id: usize) -> Option<&'static str> {
     match id {
@@ -186,11 +203,11 @@ fn date_pattern(val: &str, id: usize) -> Option<&'static str> {
             }
         }
         2 => {
-            // 2021/12/31 12:54:98
+            // 2021/12/31
             let pat = r"^\d{4}/\d{1,2}/\d{1,2}\s*$";
             let reg = Regex::new(pat).expect("wrong regex");
             if reg.is_match(val) {
-                return Some("%Y/%m/%d %H:%M:%S");
+                return Some("%Y/%m/%d");
             }
         }
         3 => {
@@ -274,6 +291,9 @@ impl Utf8Chunked {
         ))
     }
 
+    /// Parse the strings to dates with a [chrono format string](chrono::format::strftime).
+    /// If no `fmt` is given, the format is inferred from the first non-null value.
+    /// Values that cannot be parsed become null.
     pub fn as_date32(&self, fmt: Option<&str>) -> Result<Date32Chunked> {
         let fmt = match fmt {
             Some(fmt) => fmt,
@@ -302,6 +322,9 @@ impl Utf8Chunked {
         Ok(ca)
     }
 
+    /// Parse the strings to datetimes with a [chrono format string](chrono::format::strftime).
+    /// If no `fmt` is given, the format is inferred from the first non-null value.
+    /// Values that cannot be parsed become null and date-only values are set to midnight.
     pub fn as_date64(&self, fmt: Option<&str>) -> Result<Date64Chunked> {
         let fmt = match fmt {
             Some(fmt) => fmt,
@@ -403,9 +426,12 @@ impl Date64Chunked {
 
     /// Format Date64 with a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn strftime(&self, fmt: &str) -> Utf8Chunked {
-        self.as_naive_datetime_iter()
+        let mut ca: Utf8Chunked = self
+            .as_naive_datetime_iter()
             .map(|opt_dt| opt_dt.map(|dt| format!("{}", dt.format(fmt))))
-            .collect()
+            .collect();
+        ca.rename(self.name());
+        ca
     }
 }
 
@@ -453,8 +479,11 @@ impl Date32Chunked {
 
     /// Format Date32 with a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn strftime(&self, fmt: &str) -> Utf8Chunked {
-        self.as_naive_datetime_iter()
+        let mut ca: Utf8Chunked = self
+            .as_naive_datetime_iter()
             .map(|opt_dt| opt_dt.map(|dt| format!("{}", dt.format(fmt))))
-            .collect()
+            .collect();
+        ca.rename(self.name());
+        ca
     }
 }
@@ -43,7 +43,7 @@ pub(crate) fn naive_time_to_time64_nanoseconds(v: &NaiveTime) -> i64 {
         + v.nanosecond() as i64
 }
 
-pub(crate) fn time64_nanosecond_as_time(v: i64) -> NaiveTime {
+pub fn time64_nanosecond_as_time(v: i64) -> NaiveTime {
     NaiveTime::from_num_seconds_from_midnight(
         // extract seconds from nanoseconds
         (v / NANOSECONDS_IN_SECOND) as u32,
@@ -0,0 +1,92 @@
+//! Construct a range of datetimes.
+use super::conversions_utils::naive_datetime_to_date64;
+use crate::prelude::*;
+use chrono::{Duration, NaiveDateTime};
+
+/// Create a `Date64` array with the datetimes from `start` to `stop`, with steps of `every`.
+/// Whether `start` and `stop` are included is determined by `closed`. `stop` is only included
+/// if it lies on a step.
+///
+/// Cast the output to [`Date32Type`] to get a range of dates.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_core::utils::chrono::{Duration, NaiveDate};
+///
+/// let start = NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0);
+/// let stop = NaiveDate::from_ymd(2021, 1, 3).and_hms(0, 0, 0);
+/// let days = date_range("day", start, stop, Duration::days(1), ClosedWindow::Both).unwrap();
+/// assert_eq!(days.len(), 3);
+/// ```
+pub fn date_range(
+    name: &str,
+    start: NaiveDateTime,
+    stop: NaiveDateTime,
+    every: Duration,
+    closed: ClosedWindow,
+) -> Result<Date64Chunked> {
+    let every = every.num_milliseconds();
+    if every <= 0 {
+        return Err(PolarsError::ValueError(
+            "the step of a date_range should be a positive duration".into(),
+        ));
+    }
+    let start = naive_datetime_to_date64(&start);
+    let stop = naive_datetime_to_date64(&stop);
+    if stop < start {
+        return Err(PolarsError::ValueError(
+            "the stop of a date_range should not be before its start".into(),
+        ));
+    }
+
+    let mut values = AlignedVec::with_capacity_aligned(((stop - start) / every + 1) as usize);
+    let mut t = start;
+    while t <= stop {
+        let include = match closed {
+            ClosedWindow::Both => true,
+            ClosedWindow::Left => t != stop,
+            ClosedWindow::Right => t != start,
+            ClosedWindow::None => t != start && t != stop,
+        };
+        if include {
+            values.push(t);
+        }
+        t += every;
+    }
+    Ok(ChunkedArray::new_from_aligned_vec(name, values))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_date_range() -> Result<()> {
+        let start = NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0);
+        let stop = NaiveDate::from_ymd(2021, 1, 1).and_hms(2, 0, 0);
+        let hour = 3_600_000;
+        let t0 = naive_datetime_to_date64(&start);
+
+        let out = date_range("t", start, stop, Duration::hours(1), ClosedWindow::Both)?;
+        assert_eq!(out.name(), "t");
+        assert_eq!(out.cont_slice()?, &[t0, t0 + hour, t0 + 2 * hour]);
+
+        let out = date_range("t", start, stop, Duration::hours(1), ClosedWindow::Left)?;
+        assert_eq!(out.cont_slice()?, &[t0, t0 + hour]);
+        let out = date_range("t", start, stop, Duration::hours(1), ClosedWindow::Right)?;
+        assert_eq!(out.cont_slice()?, &[t0 + hour, t0 + 2 * hour]);
+        let out = date_range("t", start, stop, Duration::hours(1), ClosedWindow::None)?;
+        assert_eq!(out.cont_slice()?, &[t0 + hour]);
+
+        // stop is not on a step
+        let out = date_range("t", start, stop, Duration::minutes(90), ClosedWindow::Both)?;
+        assert_eq!(out.cont_slice()?, &[t0, t0 + 90 * 60_000]);
+
+        let days = out.cast::<Date32Type>()?;
+        assert_eq!(days.len(), 2);
+        Ok(())
+    }
+}
@@ -1,11 +1,16 @@
 //! Traits and utilities for temporal data.
 pub mod conversion;
 pub(crate) mod conversions_utils;
+mod date_range;
+#[cfg(feature = "timezones")]
+#[cfg_attr(docsrs, doc(cfg(feature = "timezones")))]
+pub mod timezone;
 pub use self::conversion::{
     AsDuration, AsNaiveDate, AsNaiveDateTime, AsNaiveTime, FromNaiveDate, FromNaiveDateTime,
     FromNaiveTime,
 };
 pub(crate) use self::conversions_utils::*;
+pub use self::date_range::date_range;
 use chrono::NaiveDateTime;
 
 pub fn unix_time() -> NaiveDateTime {
@@ -63,4 +68,39 @@ mod test {
             ca.cont_slice().unwrap()
         );
     }
+
+    #[test]
+    fn parse_utf8() -> Result<()> {
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[Some("2021/03/19"), None, Some("foo")]);
+        let out = ca.as_date32(None)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(18705), None, None]);
+
+        let out = ca.as_date64(None)?;
+        assert_eq!(Vec::from(&out), &[Some(18705 * 86_400_000), None, None]);
+
+        let ca = Utf8Chunked::new_from_slice("a", &["19-03-2021 12:00"]);
+        let out = ca.as_date64(Some("%d-%m-%Y %H:%M"))?;
+        assert_eq!(Vec::from(&out), &[Some(18705 * 86_400_000 + 43_200_000)]);
+        Ok(())
+    }
+
+    #[test]
+    fn strftime() -> Result<()> {
+        let ca = Date32Chunked::new_from_opt_slice("d", &[Some(18705), None]);
+        let out = ca.into_series().strftime("%d/%m/%Y")?;
+        assert_eq!(out.name(), "d");
+        assert_eq!(Vec::from(out.utf8()?), &[Some("19/03/2021"), None]);
+
+        let ca = Date64Chunked::new_from_slice("d", &[18705 * 86_400_000 + 43_200_000]);
+        assert_eq!(
+            Vec::from(&ca.strftime("%Y-%m-%d %H:%M")),
+            &[Some("2021-03-19 12:00")]
+        );
+
+        let time = NaiveTime::parse_from_str("23:56:04", "%H:%M:%S").unwrap();
+        let ca = Time64NanosecondChunked::new_from_naive_time("t", &[time]);
+        assert_eq!(Vec::from(&ca.strftime("%H.%M")), &[Some("23.56")]);
+        Ok(())
+    }
 }
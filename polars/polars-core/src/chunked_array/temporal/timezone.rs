@@ -0,0 +1,301 @@
+//! Time zone aware datetimes.
+//!
+//! A [DatetimeChunked] stores the milliseconds since the UNIX epoch in UTC, just like a
+//! `Date64Chunked`. The time zone is metadata on the dtype and only determines how the values
+//! are shown, formatted and how the calendar fields (year, hour, etc.) are computed.
+use super::*;
+use crate::prelude::*;
+use arrow::compute::cast;
+use chrono::{DateTime, FixedOffset, LocalResult, NaiveDateTime, TimeZone as _};
+use chrono_tz::Tz;
+
+/// A time zone from the tz database, e.g. `Europe/Amsterdam`, or a fixed UTC offset such as
+/// `+01:00`, as Arrow allows both.
+#[derive(Clone, Copy)]
+pub(crate) enum ParsedTimeZone {
+    Named(Tz),
+    Offset(FixedOffset),
+}
+
+/// Parse an offset of the form `+HH:MM`, `-HH:MM`, `+HHMM` or `+HH`.
+fn parse_offset(tz: &str) -> Option<FixedOffset> {
+    let sign = match tz.get(..1)? {
+        "+" => 1,
+        "-" => -1,
+        _ => return None,
+    };
+    let digits = tz[1..].replacen(':', "", 1);
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (
+            digits[..2].parse::<i32>().ok()?,
+            digits[2..].parse::<i32>().ok()?,
+        ),
+        _ => return None,
+    };
+    if minutes >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+pub(crate) fn parse_time_zone(tz: &str) -> Result<ParsedTimeZone> {
+    if let Ok(tz) = tz.parse::<Tz>() {
+        return Ok(ParsedTimeZone::Named(tz));
+    }
+    parse_offset(tz)
+        .map(ParsedTimeZone::Offset)
+        .ok_or_else(|| PolarsError::ValueError(format!("unknown time zone: {}", tz).into()))
+}
+
+impl ParsedTimeZone {
+    /// Get the UTC timestamp of a wall clock time in this time zone.
+    /// Ambiguous times (when the clock is set back) resolve to the earliest instant.
+    /// Times that don't exist (when the clock is set forward) are `None`.
+    fn localize(&self, ndt: &NaiveDateTime) -> Option<i64> {
+        fn earliest<T: chrono::TimeZone>(res: LocalResult<DateTime<T>>) -> Option<i64> {
+            match res {
+                LocalResult::None => None,
+                res => res.earliest().map(|dt| dt.timestamp_millis()),
+            }
+        }
+        match self {
+            ParsedTimeZone::Named(tz) => earliest(tz.from_local_datetime(ndt)),
+            ParsedTimeZone::Offset(offset) => earliest(offset.from_local_datetime(ndt)),
+        }
+    }
+
+    /// Get the wall clock time in this time zone of a UTC timestamp.
+    fn to_local(&self, v: i64) -> NaiveDateTime {
+        match self {
+            ParsedTimeZone::Named(tz) => tz.timestamp_millis(v).naive_local(),
+            ParsedTimeZone::Offset(offset) => offset.timestamp_millis(v).naive_local(),
+        }
+    }
+
+    /// Format a UTC timestamp in this time zone.
+    fn format(&self, v: i64, fmt: &str) -> String {
+        match self {
+            ParsedTimeZone::Named(tz) => format!("{}", tz.timestamp_millis(v).format(fmt)),
+            ParsedTimeZone::Offset(offset) => format!("{}", offset.timestamp_millis(v).format(fmt)),
+        }
+    }
+}
+
+/// Format a UTC timestamp in the time zone `tz`.
+pub(crate) fn fmt_datetime(v: i64, tz: &str) -> String {
+    match parse_time_zone(tz) {
+        Ok(tz) => tz.format(v, "%Y-%m-%d %H:%M:%S%.3f %Z"),
+        Err(_) => format!("{} {}", date64_as_datetime(v), tz),
+    }
+}
+
+/// Cast a Series to datetimes in `tz`. Strings are parsed, other types are interpreted as
+/// milliseconds since the UNIX epoch.
+pub(crate) fn cast_to_datetime(s: &Series, tz: &str) -> Result<DatetimeChunked> {
+    match s.dtype() {
+        DataType::Datetime(_) => s.datetime()?.convert_time_zone(tz),
+        DataType::Date64 => s.date64()?.convert_time_zone(tz),
+        DataType::Date32 => s.cast::<Date64Type>()?.date64()?.convert_time_zone(tz),
+        DataType::Utf8 => s.utf8()?.as_datetime(None, tz),
+        _ => {
+            let s = s.cast::<Int64Type>()?;
+            DatetimeChunked::new_from_timestamps(s.i64()?, tz)
+        }
+    }
+}
+
+impl DatetimeChunked {
+    /// The time zone the datetimes are shown in.
+    pub fn time_zone(&self) -> &str {
+        match self.dtype() {
+            DataType::Datetime(tz) => tz,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Create datetimes in `tz` from milliseconds since the UNIX epoch (in UTC).
+    pub fn new_from_timestamps(ca: &Int64Chunked, tz: &str) -> Result<Self> {
+        parse_time_zone(tz)?;
+        let dtype = DataType::Datetime(tz.to_string()).to_arrow();
+        let chunks = ca
+            .chunks()
+            .iter()
+            .map(|arr| cast(arr, &dtype))
+            .collect::<arrow::error::Result<Vec<_>>>()?;
+        let mut out = Self::new_from_chunks(ca.name(), chunks);
+        // set the time zone explicitly, there may be no chunks to take it from
+        out.field = Arc::new(Field::new(ca.name(), DataType::Datetime(tz.to_string())));
+        Ok(out)
+    }
+
+    /// The milliseconds since the UNIX epoch (in UTC).
+    pub fn timestamps(&self) -> Int64Chunked {
+        self.cast::<Int64Type>().unwrap()
+    }
+
+    /// Show the same instants in another time zone.
+    pub fn convert_time_zone(&self, tz: &str) -> Result<Self> {
+        Self::new_from_timestamps(&self.timestamps(), tz)
+    }
+
+    /// Keep the wall clock times, but change the time zone they are in.
+    /// If `tz` is `None`, the time zone is dropped and the wall clock times are returned as
+    /// `Date64`.
+    pub fn replace_time_zone(&self, tz: Option<&str>) -> Result<Series> {
+        self.local_datetime()?.replace_time_zone(tz)
+    }
+
+    /// The wall clock times in the time zone of the datetimes.
+    /// Fails if the time zone is not known, e.g. when it was read from a file.
+    pub fn local_datetime(&self) -> Result<Date64Chunked> {
+        let tz = parse_time_zone(self.time_zone())?;
+        let mut ca: Date64Chunked = self
+            .timestamps()
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| naive_datetime_to_date64(&tz.to_local(v))))
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
+    /// Format the datetimes in their time zone with a `fmt` rule.
+    /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    pub fn strftime(&self, fmt: &str) -> Result<Utf8Chunked> {
+        let tz = parse_time_zone(self.time_zone())?;
+        let mut ca: Utf8Chunked = self
+            .timestamps()
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| tz.format(v, fmt)))
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+}
+
+impl Date64Chunked {
+    /// Interpret the values as wall clock times in `tz`. Wall clock times that don't exist in
+    /// `tz` become null. If `tz` is `None` the values are returned unchanged.
+    pub fn replace_time_zone(&self, tz: Option<&str>) -> Result<Series> {
+        let tz_name = match tz {
+            Some(tz) => tz,
+            None => return Ok(self.clone().into_series()),
+        };
+        let tz = parse_time_zone(tz_name)?;
+        let mut ca: Int64Chunked = self
+            .as_naive_datetime_iter()
+            .map(|opt_dt| opt_dt.and_then(|dt| tz.localize(&dt)))
+            .collect();
+        ca.rename(self.name());
+        DatetimeChunked::new_from_timestamps(&ca, tz_name).map(|ca| ca.into_series())
+    }
+
+    /// Interpret the values as UTC and show them in `tz`.
+    pub fn convert_time_zone(&self, tz: &str) -> Result<DatetimeChunked> {
+        DatetimeChunked::new_from_timestamps(&self.cast::<Int64Type>()?, tz)
+    }
+}
+
+impl Utf8Chunked {
+    /// Parse strings to datetimes in `tz`.
+    ///
+    /// Strings with a UTC offset (`%z` in `fmt`, or RFC 3339 if no `fmt` is given) are parsed
+    /// to the instant they describe. Other strings are interpreted as wall clock times in `tz`.
+    pub fn as_datetime(&self, fmt: Option<&str>, tz: &str) -> Result<DatetimeChunked> {
+        let parse_offset = |s: &str| match fmt {
+            Some(fmt) => DateTime::parse_from_str(s, fmt).ok(),
+            None => DateTime::parse_from_rfc3339(s).ok(),
+        };
+        let with_offset = match fmt {
+            Some(fmt) => fmt.contains("%z"),
+            None => self
+                .first_non_null()
+                .map_or(false, |idx| parse_offset(self.get(idx).unwrap()).is_some()),
+        };
+        if with_offset {
+            parse_time_zone(tz)?;
+            let mut ca: Int64Chunked = self
+                .into_iter()
+                .map(|opt_s| opt_s.and_then(parse_offset).map(|dt| dt.timestamp_millis()))
+                .collect();
+            ca.rename(self.name());
+            DatetimeChunked::new_from_timestamps(&ca, tz)
+        } else {
+            let s = self.as_date64(fmt)?.replace_time_zone(Some(tz))?;
+            Ok(s.datetime()?.clone())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_convert_and_replace_time_zone() -> Result<()> {
+        // 2021-03-28 00:30:00 UTC
+        let ca = Int64Chunked::new_from_slice("dt", &[1616891400000]);
+        let dt = DatetimeChunked::new_from_timestamps(&ca, "UTC")?;
+        assert_eq!(dt.dtype(), &DataType::Datetime("UTC".into()));
+
+        let ams = dt.convert_time_zone("Europe/Amsterdam")?;
+        assert_eq!(ams.time_zone(), "Europe/Amsterdam");
+        assert_eq!(Vec::from(&ams.timestamps()), &[Some(1616891400000)]);
+        assert_eq!(
+            Vec::from(&ams.strftime("%Y-%m-%d %H:%M %Z")?),
+            &[Some("2021-03-28 01:30 CET")]
+        );
+
+        // same wall clock time, one hour earlier in UTC
+        let s = dt.replace_time_zone(Some("Europe/Amsterdam"))?;
+        assert_eq!(
+            Vec::from(&s.datetime()?.timestamps()),
+            &[Some(1616891400000 - 3_600_000)]
+        );
+        // dropping the time zone gives the wall clock time
+        let s = ams.replace_time_zone(None)?;
+        assert_eq!(s.dtype(), &DataType::Date64);
+        assert_eq!(Vec::from(&s.date64()?.strftime("%H:%M")), &[Some("01:30")]);
+
+        assert!(dt.convert_time_zone("Mars/Olympus_Mons").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_datetime_parse_and_compare() -> Result<()> {
+        let ca = Utf8Chunked::new_from_slice(
+            "dt",
+            &["2021-01-01T12:00:00+01:00", "2021-01-01T11:00:00Z"],
+        );
+        let dt = ca.as_datetime(None, "Europe/Amsterdam")?;
+        let utc = dt.convert_time_zone("UTC")?.into_series();
+        let dt = dt.into_series();
+        assert_eq!(Vec::from(&dt.eq(&utc)), &[Some(true), Some(true)]);
+        assert_eq!(dt.get(0), dt.get(1));
+
+        // wall clock times without an offset are local to the time zone
+        let ca = Utf8Chunked::new_from_slice("dt", &["2021-01-01 12:00:00"]);
+        let dt = ca.as_datetime(None, "Asia/Tokyo")?;
+        assert_eq!(Vec::from(&dt.timestamps()), &[Some(1609470000000)]);
+        assert_eq!(Vec::from(&dt.local_datetime()?.hour()), &[Some(12)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_offset_time_zone() -> Result<()> {
+        // 2021-01-01 11:00:00 UTC
+        let ca = Int64Chunked::new_from_slice("dt", &[1609498800000]);
+        let dt = DatetimeChunked::new_from_timestamps(&ca, "+01:00")?;
+        assert_eq!(Vec::from(&dt.local_datetime()?.hour()), &[Some(12)]);
+        assert_eq!(Vec::from(&dt.strftime("%H:%M %z")?), &[Some("12:00 +0100")]);
+        let dt = DatetimeChunked::new_from_timestamps(&ca, "-0530")?;
+        assert_eq!(Vec::from(&dt.strftime("%H:%M")?), &[Some("05:30")]);
+
+        assert!(DatetimeChunked::new_from_timestamps(&ca, "+25:00").is_err());
+        assert!(DatetimeChunked::new_from_timestamps(&ca, "+01:60").is_err());
+        Ok(())
+    }
+}
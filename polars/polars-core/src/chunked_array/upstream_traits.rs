@@ -114,6 +114,19 @@ where
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl<Ptr> FromIterator<Option<Ptr>> for BinaryChunked
+where
+    Ptr: AsRef<[u8]>,
+{
+    fn from_iter<I: IntoIterator<Item = Option<Ptr>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut builder = BinaryChunkedBuilder::new("", get_iter_capacity(&iter));
+        iter.for_each(|opt_v| builder.append_option(opt_v));
+        builder.finish()
+    }
+}
+
 /// Local AsRef<T> trait to circumvent the orphan rule.
 pub trait PolarsAsRef<T: ?Sized>: AsRef<T> {}
 
@@ -29,6 +29,12 @@ pub struct ListType {}
 
 pub struct CategoricalType {}
 
+#[cfg(feature = "dtype-decimal")]
+pub struct DecimalType {}
+
+#[cfg(feature = "dtype-binary")]
+pub struct BinaryType {}
+
 pub trait PolarsDataType: Send + Sync {
     fn get_dtype() -> DataType;
 }
@@ -63,6 +69,14 @@ impl PolarsDataType for Time64NanosecondType {
     }
 }
 
+#[cfg(feature = "timezones")]
+impl PolarsDataType for TimestampMillisecondType {
+    fn get_dtype() -> DataType {
+        // the time zone is set on the field of the ChunkedArray
+        DataType::Datetime("UTC".to_string())
+    }
+}
+
 impl PolarsDataType for DurationNanosecondType {
     fn get_dtype() -> DataType {
         DataType::Duration(TimeUnit::Nanosecond)
@@ -81,6 +95,13 @@ impl PolarsDataType for Utf8Type {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl PolarsDataType for BinaryType {
+    fn get_dtype() -> DataType {
+        DataType::Binary
+    }
+}
+
 impl PolarsDataType for ListType {
     fn get_dtype() -> DataType {
         // null as we cannot no anything without self.
@@ -94,6 +115,14 @@ impl PolarsDataType for CategoricalType {
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+impl PolarsDataType for DecimalType {
+    fn get_dtype() -> DataType {
+        // the precision and scale are set on the field of the ChunkedArray
+        DataType::Decimal(DECIMAL_MAX_PRECISION, 0)
+    }
+}
+
 #[cfg(feature = "object")]
 #[cfg_attr(docsrs, doc(cfg(feature = "object")))]
 pub struct ObjectType<T>(T);
@@ -115,6 +144,9 @@ impl<T> PolarsSingleType for T where T: ArrowPrimitiveType + PolarsDataType {}
 
 impl PolarsSingleType for Utf8Type {}
 
+#[cfg(feature = "dtype-binary")]
+impl PolarsSingleType for BinaryType {}
+
 pub type ListChunked = ChunkedArray<ListType>;
 pub type BooleanChunked = ChunkedArray<BooleanType>;
 pub type UInt8Chunked = ChunkedArray<UInt8Type>;
@@ -134,6 +166,20 @@ pub type DurationNanosecondChunked = ChunkedArray<DurationNanosecondType>;
 pub type DurationMillisecondChunked = ChunkedArray<DurationMillisecondType>;
 pub type Time64NanosecondChunked = ChunkedArray<Time64NanosecondType>;
 pub type CategoricalChunked = ChunkedArray<CategoricalType>;
+#[cfg(feature = "dtype-decimal")]
+pub type DecimalChunked = ChunkedArray<DecimalType>;
+#[cfg(feature = "dtype-binary")]
+pub type BinaryChunked = ChunkedArray<BinaryType>;
+#[cfg(feature = "timezones")]
+pub type DatetimeChunked = ChunkedArray<TimestampMillisecondType>;
+
+/// An IANA time zone name, e.g. `"Europe/Amsterdam"`.
+#[cfg(feature = "timezones")]
+pub type TimeZone = String;
+
+/// The maximum number of digits of a decimal. The values are computed with 64 bit integers.
+#[cfg(feature = "dtype-decimal")]
+pub const DECIMAL_MAX_PRECISION: usize = 18;
 
 pub trait PolarsPrimitiveType: ArrowPrimitiveType + Send + Sync + PolarsDataType {}
 // impl PolarsPrimitiveType for BooleanType {}
@@ -152,6 +198,8 @@ impl PolarsPrimitiveType for Date64Type {}
 impl PolarsPrimitiveType for Time64NanosecondType {}
 impl PolarsPrimitiveType for DurationNanosecondType {}
 impl PolarsPrimitiveType for DurationMillisecondType {}
+#[cfg(feature = "timezones")]
+impl PolarsPrimitiveType for TimestampMillisecondType {}
 
 pub trait PolarsNumericType: PolarsPrimitiveType + ArrowNumericType {}
 impl PolarsNumericType for UInt8Type {}
@@ -169,6 +217,8 @@ impl PolarsNumericType for Date64Type {}
 impl PolarsNumericType for Time64NanosecondType {}
 impl PolarsNumericType for DurationNanosecondType {}
 impl PolarsNumericType for DurationMillisecondType {}
+#[cfg(feature = "timezones")]
+impl PolarsNumericType for TimestampMillisecondType {}
 
 pub trait PolarsIntegerType: PolarsNumericType {}
 impl PolarsIntegerType for UInt8Type {}
@@ -184,6 +234,8 @@ impl PolarsIntegerType for Date64Type {}
 impl PolarsIntegerType for Time64NanosecondType {}
 impl PolarsIntegerType for DurationNanosecondType {}
 impl PolarsIntegerType for DurationMillisecondType {}
+#[cfg(feature = "timezones")]
+impl PolarsIntegerType for TimestampMillisecondType {}
 
 pub trait PolarsFloatType: PolarsNumericType {}
 impl PolarsFloatType for Float32Type {}
@@ -232,6 +284,18 @@ pub enum AnyValue<'a> {
     #[cfg(feature = "object")]
     /// Use as_any to get a dyn Any
     Object(&'a str),
+    #[cfg(feature = "dtype-decimal")]
+    /// A fixed point decimal number represented by its unscaled value and its scale.
+    Decimal(i128, usize),
+    #[cfg(feature = "dtype-struct")]
+    /// The values of the fields of a struct.
+    Struct(Vec<AnyValue<'a>>),
+    #[cfg(feature = "timezones")]
+    /// The milliseconds since the UNIX epoch (in UTC) and the time zone the value is shown in.
+    Datetime(i64, &'a str),
+    #[cfg(feature = "dtype-binary")]
+    /// A sequence of arbitrary bytes.
+    Binary(&'a [u8]),
 }
 
 impl From<f64> for AnyValue<'_> {
@@ -353,6 +417,22 @@ impl Display for DataType {
             #[cfg(feature = "object")]
             DataType::Object(s) => s,
             DataType::Categorical => "cat",
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(precision, scale) => {
+                return write!(f, "decimal({}, {})", precision, scale)
+            }
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(tz) => return write!(f, "datetime(ms, {})", tz),
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => "binary",
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|fld| format!("{}: {}", fld.name(), fld.data_type()))
+                    .collect::<Vec<_>>();
+                return write!(f, "struct {{{}}}", fields.join(", "));
+            }
             _ => panic!("{:?} not implemented", self),
         };
         f.write_str(s)
@@ -380,6 +460,15 @@ impl PartialEq for AnyValue<'_> {
             (Time64(l, _), Time64(r, _)) => l == r,
             (Duration(l, _), Duration(r, _)) => l == r,
             (Boolean(l), Boolean(r)) => l == r,
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(l, scale_l), Decimal(r, scale_r)) => l == r && scale_l == scale_r,
+            #[cfg(feature = "dtype-struct")]
+            (Struct(l), Struct(r)) => l == r,
+            // the same instant in different time zones
+            #[cfg(feature = "timezones")]
+            (Datetime(l, _), Datetime(r, _)) => l == r,
+            #[cfg(feature = "dtype-binary")]
+            (Binary(l), Binary(r)) => l == r,
             (List(_), List(_)) => panic!("eq between list series not supported"),
             #[cfg(feature = "object")]
             (Object(_), Object(_)) => panic!("eq between object not supported"),
@@ -405,12 +494,19 @@ impl PartialOrd for AnyValue<'_> {
             (Int64(l), Int64(r)) => l.partial_cmp(r),
             (Float32(l), Float32(r)) => l.partial_cmp(r),
             (Float64(l), Float64(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(l, scale_l), Decimal(r, scale_r)) if scale_l == scale_r => l.partial_cmp(r),
+            #[cfg(feature = "timezones")]
+            (Datetime(l, _), Datetime(r, _)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-binary")]
+            (Binary(l), Binary(r)) => l.partial_cmp(r),
             _ => None,
         }
     }
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DataType {
     Boolean,
     UInt8,
@@ -436,9 +532,23 @@ pub enum DataType {
     #[cfg(feature = "object")]
     /// A generic type that can be used in a `Series`
     /// &'static str can be used to determine/set inner type
+    #[cfg_attr(feature = "serde", serde(skip))]
     Object(&'static str),
     Null,
     Categorical,
+    #[cfg(feature = "dtype-decimal")]
+    /// A fixed point decimal number with a precision (the total number of digits) and a scale
+    /// (the number of digits after the decimal point).
+    Decimal(usize, usize),
+    #[cfg(feature = "dtype-struct")]
+    /// A nested record of named fields.
+    Struct(Vec<Field>),
+    #[cfg(feature = "timezones")]
+    /// Milliseconds since the UNIX epoch (in UTC) that are shown in a time zone.
+    Datetime(TimeZone),
+    #[cfg(feature = "dtype-binary")]
+    /// Sequences of arbitrary bytes.
+    Binary,
 }
 
 impl DataType {
@@ -468,6 +578,14 @@ impl DataType {
             #[cfg(feature = "object")]
             Object(_) => unimplemented!(),
             Categorical => ArrowDataType::UInt32,
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(precision, scale) => ArrowDataType::Decimal(*precision, *scale),
+            #[cfg(feature = "dtype-struct")]
+            Struct(fields) => ArrowDataType::Struct(fields.iter().map(|f| f.to_arrow()).collect()),
+            #[cfg(feature = "timezones")]
+            Datetime(tz) => ArrowDataType::Timestamp(TimeUnit::Millisecond, Some(tz.clone())),
+            #[cfg(feature = "dtype-binary")]
+            Binary => ArrowDataType::LargeBinary,
         }
     }
 }
@@ -479,7 +597,8 @@ impl PartialEq<ArrowDataType> for DataType {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Field {
     name: String,
     data_type: DataType,
@@ -510,6 +629,7 @@ impl Field {
 }
 
 #[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Schema {
     fields: Vec<Field>,
 }
@@ -648,6 +768,23 @@ impl From<&ArrowDataType> for DataType {
                 DataType::Duration(TimeUnit::Millisecond)
             }
             ArrowDataType::Utf8 => DataType::Utf8,
+            #[cfg(feature = "dtype-binary")]
+            ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
+            #[cfg(feature = "timezones")]
+            ArrowDataType::Timestamp(TimeUnit::Millisecond, Some(tz)) => {
+                DataType::Datetime(tz.clone())
+            }
+            ArrowDataType::Dictionary(_, value_type)
+                if matches!(**value_type, ArrowDataType::Utf8 | ArrowDataType::LargeUtf8) =>
+            {
+                DataType::Categorical
+            }
+            #[cfg(feature = "dtype-decimal")]
+            ArrowDataType::Decimal(precision, scale) => DataType::Decimal(*precision, *scale),
+            #[cfg(feature = "dtype-struct")]
+            ArrowDataType::Struct(fields) => {
+                DataType::Struct(fields.iter().map(|f| f.into()).collect())
+            }
             dt => panic!("Arrow datatype {:?} not supported by Polars", dt),
         }
     }
@@ -39,6 +39,8 @@ pub enum PolarsError {
     HasNullValues(ErrString),
     #[error("{0}")]
     UnknownSchema(ErrString),
+    #[error("Schema doesn't match: {0}")]
+    SchemaMisMatch(ErrString),
     #[error(transparent)]
     Various(#[from] anyhow::Error),
     #[error(transparent)]
@@ -1,5 +1,9 @@
 use crate::prelude::*;
 
+#[cfg(feature = "dtype-decimal")]
+use crate::chunked_array::decimal::fmt_decimal;
+#[cfg(feature = "timezones")]
+use crate::chunked_array::temporal::timezone::fmt_datetime;
 #[cfg(feature = "temporal")]
 use crate::chunked_array::temporal::{
     date32_as_datetime, date64_as_datetime, time64_nanosecond_as_time,
@@ -180,6 +184,14 @@ impl Debug for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl Debug for BinaryChunked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let limit = set_limit!(self);
+        format_array!(limit, f, self, "binary", self.name(), "ChunkedArray")
+    }
+}
+
 impl Debug for ListChunked {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let limit = set_limit!(self);
@@ -194,6 +206,14 @@ impl Debug for CategoricalChunked {
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+impl Debug for DecimalChunked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let limit = set_limit!(self);
+        format_array!(limit, f, self, "decimal", self.name(), "ChunkedArray")
+    }
+}
+
 #[cfg(feature = "object")]
 impl<T> Debug for ObjectChunked<T>
 where
@@ -343,6 +363,42 @@ impl Debug for Series {
                 self.name(),
                 "Series"
             ),
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => format_array!(
+                limit,
+                f,
+                self.struct_().unwrap(),
+                "struct",
+                self.name(),
+                "Series"
+            ),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => format_array!(
+                limit,
+                f,
+                self.decimal().unwrap(),
+                "decimal",
+                self.name(),
+                "Series"
+            ),
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => format_array!(
+                limit,
+                f,
+                self.binary().unwrap(),
+                "binary",
+                self.name(),
+                "Series"
+            ),
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => format_array!(
+                limit,
+                f,
+                self.datetime().unwrap(),
+                "datetime",
+                self.name(),
+                "Series"
+            ),
             _ => unimplemented!(),
         }
     }
@@ -562,6 +618,24 @@ impl Display for AnyValue<'_> {
             AnyValue::Duration(v, TimeUnit::Nanosecond) => write!(f, "{}", v),
             AnyValue::Duration(v, TimeUnit::Millisecond) => write!(f, "{}", v),
             AnyValue::List(s) => write!(f, "{}", s.fmt_list()),
+            #[cfg(feature = "dtype-decimal")]
+            AnyValue::Decimal(v, scale) => write!(f, "{}", fmt_decimal(*v, *scale)),
+            #[cfg(feature = "dtype-struct")]
+            AnyValue::Struct(values) => {
+                let values = values.iter().map(|v| format!("{}", v)).collect::<Vec<_>>();
+                write!(f, "{{{}}}", values.join(", "))
+            }
+            #[cfg(feature = "timezones")]
+            AnyValue::Datetime(v, tz) => write!(f, "{}", fmt_datetime(*v, tz)),
+            #[cfg(feature = "dtype-binary")]
+            AnyValue::Binary(v) => {
+                let v = v
+                    .iter()
+                    .flat_map(|b| std::ascii::escape_default(*b))
+                    .map(char::from)
+                    .collect::<String>();
+                write!(f, "b\"{}\"", v)
+            }
             #[cfg(feature = "object")]
             AnyValue::Object(_) => write!(f, "object"),
             _ => unimplemented!(),
@@ -642,6 +716,34 @@ impl FmtList for CategoricalChunked {
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+impl FmtList for DecimalChunked {
+    fn fmt_list(&self) -> String {
+        let fmt = |i| format!("{}", self.get_any_value(i));
+        match self.len() {
+            0 => "[]".to_string(),
+            1 => format!("[{}]", fmt(0)),
+            2 => format!("[{}, {}]", fmt(0), fmt(1)),
+            3 => format!("[{}, {}, {}]", fmt(0), fmt(1), fmt(2)),
+            _ => format!("[{}, {}, ... {}]", fmt(0), fmt(1), fmt(self.len() - 1)),
+        }
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl FmtList for BinaryChunked {
+    fn fmt_list(&self) -> String {
+        let fmt = |i| format!("{}", self.get_any_value(i));
+        match self.len() {
+            0 => "[]".to_string(),
+            1 => format!("[{}]", fmt(0)),
+            2 => format!("[{}, {}]", fmt(0), fmt(1)),
+            3 => format!("[{}, {}, {}]", fmt(0), fmt(1), fmt(2)),
+            _ => format!("[{}, {}, ... {}]", fmt(0), fmt(1), fmt(self.len() - 1)),
+        }
+    }
+}
+
 #[cfg(feature = "object")]
 impl<T> FmtList for ObjectChunked<T> {
     fn fmt_list(&self) -> String {
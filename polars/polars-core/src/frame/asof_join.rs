@@ -0,0 +1,368 @@
+//! As-of joins match every row of the left DataFrame with the row of the right DataFrame that has
+//! the nearest key, instead of an equal key.
+use crate::frame::hash_join::check_categorical_src;
+use crate::prelude::*;
+use num::NumCast;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::ops::Sub;
+
+/// Which key of the right DataFrame is considered the nearest.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AsofStrategy {
+    /// The last row with a key less than or equal to the left key.
+    Backward,
+    /// The first row with a key greater than or equal to the left key.
+    Forward,
+    /// The row with the smallest absolute distance to the left key. Ties go to the backward match.
+    Nearest,
+}
+
+impl Default for AsofStrategy {
+    fn default() -> Self {
+        AsofStrategy::Backward
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AsOfOptions {
+    pub strategy: AsofStrategy,
+    /// Don't match keys that are further apart than this distance. For temporal keys the distance
+    /// is in the physical unit, e.g. milliseconds for `Date64`.
+    pub tolerance: Option<f64>,
+}
+
+/// Cast a join key to `Int64`, or to `Float64` if `float`.
+fn to_asof_key(s: &Series, float: bool) -> Result<Series> {
+    match s.dtype() {
+        DataType::Utf8 | DataType::Boolean | DataType::List(_) | DataType::Categorical => {
+            Err(PolarsError::InvalidOperation(
+                format!("cannot do an asof join on a key of type {:?}", s.dtype()).into(),
+            ))
+        }
+        DataType::Date32 => to_asof_key(&s.cast::<Int32Type>()?, float),
+        _ if float => s.cast::<Float64Type>(),
+        _ => s.cast::<Int64Type>(),
+    }
+}
+
+/// Find the matching index in `right`, which is sorted by key.
+fn asof_search<T>(
+    right: &[(T, u32)],
+    value: T,
+    strategy: AsofStrategy,
+    tolerance: Option<T>,
+) -> Option<u32>
+where
+    T: PartialOrd + Copy + Sub<Output = T>,
+{
+    let backward = || {
+        let n_le = right.partition_point(|(v, _)| *v <= value);
+        if n_le == 0 {
+            None
+        } else {
+            let (v, idx) = right[n_le - 1];
+            Some((value - v, idx))
+        }
+    };
+    let forward = || {
+        let n_lt = right.partition_point(|(v, _)| *v < value);
+        right.get(n_lt).map(|&(v, idx)| (v - value, idx))
+    };
+
+    let (distance, idx) = match strategy {
+        AsofStrategy::Backward => backward()?,
+        AsofStrategy::Forward => forward()?,
+        AsofStrategy::Nearest => match (backward(), forward()) {
+            (Some(b), Some(f)) => {
+                if f.0 < b.0 {
+                    f
+                } else {
+                    b
+                }
+            }
+            (b, f) => b.or(f)?,
+        },
+    };
+    match tolerance {
+        Some(tolerance) if distance > tolerance => None,
+        _ => Some(idx),
+    }
+}
+
+/// For every row of `left`, get the index of the matching row in `right`. Rows only match within
+/// the same group.
+fn join_asof_indexes<T>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    groups: &[(Vec<u32>, Vec<u32>)],
+    options: AsOfOptions,
+) -> Result<Vec<Option<u32>>>
+where
+    T: PolarsNumericType,
+    T::Native: NumCast + PartialOrd,
+{
+    let tolerance = match options.tolerance {
+        Some(tolerance) => Some(NumCast::from(tolerance).ok_or_else(|| {
+            PolarsError::ValueError(format!("invalid asof tolerance {}", tolerance).into())
+        })?),
+        None => None,
+    };
+    let left = left.into_iter().collect::<Vec<_>>();
+    let right = right.into_iter().collect::<Vec<_>>();
+
+    let mut out = vec![None; left.len()];
+    for (left_idx, right_idx) in groups {
+        let mut right_keys = right_idx
+            .iter()
+            .filter_map(|&idx| right[idx as usize].map(|v| (v, idx)))
+            .collect::<Vec<_>>();
+        right_keys.sort_by(|a, b| {
+            a.0.partial_cmp(&b.0)
+                .unwrap_or(Ordering::Equal)
+                .then(a.1.cmp(&b.1))
+        });
+
+        for &idx in left_idx {
+            out[idx as usize] = left[idx as usize]
+                .and_then(|v| asof_search(&right_keys, v, options.strategy, tolerance));
+        }
+    }
+    Ok(out)
+}
+
+/// Split the rows of both DataFrames in groups with equal `by` values.
+fn asof_groups(
+    left_height: usize,
+    right_height: usize,
+    left_by: &[Series],
+    right_by: &[Series],
+) -> Result<Vec<(Vec<u32>, Vec<u32>)>> {
+    if left_by.is_empty() {
+        return Ok(vec![(
+            (0..left_height as u32).collect(),
+            (0..right_height as u32).collect(),
+        )]);
+    }
+    if left_by.len() != right_by.len() {
+        return Err(PolarsError::ValueError(
+            "the number of left and right 'by' columns should be equal".into(),
+        ));
+    }
+
+    // group both tables at once; indexes >= left_height belong to the right table
+    let keys = left_by
+        .iter()
+        .zip(right_by)
+        .map(|(l, r)| {
+            check_categorical_src(l, r)?;
+            let mut s = l.clone();
+            s.append(&r.cast_with_dtype(l.dtype())?)?;
+            Ok(s)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let df = DataFrame::new_no_checks(keys.clone());
+    let gb = df.groupby_with_series(keys, true)?;
+
+    Ok(gb
+        .get_groups()
+        .iter()
+        .map(|(_, idx)| {
+            let (l, r): (Vec<u32>, Vec<u32>) =
+                idx.iter().partition(|&&idx| (idx as usize) < left_height);
+            let r = r.into_iter().map(|idx| idx - left_height as u32).collect();
+            (l, r)
+        })
+        .collect())
+}
+
+impl DataFrame {
+    /// Perform an as-of join on two DataFrames. Every row of the left DataFrame is matched with
+    /// the row of the right DataFrame that has the nearest key (see [AsofStrategy]), so the join
+    /// keys must be numeric or temporal. The row order of the left DataFrame is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn join_dfs(quotes: &DataFrame, trades: &DataFrame) -> Result<DataFrame> {
+    ///     let options = AsOfOptions {
+    ///         strategy: AsofStrategy::Backward,
+    ///         tolerance: Some(10.0),
+    ///     };
+    ///     quotes.join_asof(trades, "time", "time", options)
+    /// }
+    /// ```
+    pub fn join_asof(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        options: AsOfOptions,
+    ) -> Result<DataFrame> {
+        let s_left = self.column(left_on)?;
+        let s_right = other.column(right_on)?;
+        self.join_asof_from_series(
+            other,
+            s_left,
+            s_right,
+            &[],
+            &[],
+            options,
+            &DuplicateColumns::default(),
+        )
+    }
+
+    /// Perform an as-of join on two DataFrames, where rows only match if the `by` columns
+    /// are equal.
+    pub fn join_asof_by(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        left_by: &[&str],
+        right_by: &[&str],
+        options: AsOfOptions,
+    ) -> Result<DataFrame> {
+        let s_left = self.column(left_on)?;
+        let s_right = other.column(right_on)?;
+        let left_by = self.select_series(left_by.to_vec())?;
+        let right_by = other.select_series(right_by.to_vec())?;
+        self.join_asof_from_series(
+            other,
+            s_left,
+            s_right,
+            &left_by,
+            &right_by,
+            options,
+            &DuplicateColumns::default(),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn join_asof_from_series(
+        &self,
+        other: &DataFrame,
+        s_left: &Series,
+        s_right: &Series,
+        left_by: &[Series],
+        right_by: &[Series],
+        options: AsOfOptions,
+        duplicates: &DuplicateColumns,
+    ) -> Result<DataFrame> {
+        let is_float = |dt: &DataType| matches!(dt, DataType::Float32 | DataType::Float64);
+        let float = is_float(s_left.dtype()) || is_float(s_right.dtype());
+        let left_key = to_asof_key(s_left, float)?;
+        let right_key = to_asof_key(s_right, float)?;
+
+        let groups = asof_groups(self.height(), other.height(), left_by, right_by)?;
+        let opt_join_idx = if float {
+            join_asof_indexes(left_key.f64()?, right_key.f64()?, &groups, options)?
+        } else {
+            join_asof_indexes(left_key.i64()?, right_key.i64()?, &groups, options)?
+        };
+
+        // remove the join columns of the right table
+        let columns = other
+            .get_columns()
+            .iter()
+            .filter(|s| {
+                s.name() != s_right.name() && right_by.iter().all(|by| by.name() != s.name())
+            })
+            .cloned()
+            .collect();
+        let df_right = unsafe {
+            DataFrame::new_no_checks(columns).take_opt_iter_unchecked(
+                opt_join_idx
+                    .iter()
+                    .map(|opt_idx| opt_idx.map(|idx| idx as usize)),
+            )
+        };
+        self.finish_join(self.clone(), df_right, duplicates)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_join_asof() -> Result<()> {
+        let quotes = df![
+            "time" => [1, 5, 10, 21],
+            "bid" => [1.0, 2.0, 3.0, 4.0]
+        ]?;
+        let trades = df![
+            "time" => [2, 3, 10, 15],
+            "price" => [10, 20, 30, 40]
+        ]?;
+
+        let out = quotes.join_asof(&trades, "time", "time", AsOfOptions::default())?;
+        assert_eq!(out.get_column_names(), &["time", "bid", "price"]);
+        assert_eq!(
+            Vec::from(out.column("price")?.i32()?),
+            &[None, Some(20), Some(30), Some(40)]
+        );
+
+        let options = AsOfOptions {
+            strategy: AsofStrategy::Forward,
+            tolerance: None,
+        };
+        let out = quotes.join_asof(&trades, "time", "time", options)?;
+        assert_eq!(
+            Vec::from(out.column("price")?.i32()?),
+            &[Some(10), Some(30), Some(30), None]
+        );
+
+        let options = AsOfOptions {
+            strategy: AsofStrategy::Nearest,
+            tolerance: Some(3.0),
+        };
+        let out = quotes.join_asof(&trades, "time", "time", options)?;
+        assert_eq!(
+            Vec::from(out.column("price")?.i32()?),
+            &[Some(10), Some(20), Some(30), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_asof_by() -> Result<()> {
+        let quotes = df![
+            "time" => [1.0, 2.0, 3.0, 4.0],
+            "ticker" => ["a", "b", "a", "b"]
+        ]?;
+        // not sorted on purpose
+        let trades = df![
+            "ticker" => ["a", "b", "a", "c"],
+            "t" => [2.5, 0.5, 0.5, 1.0],
+            "price" => [1, 2, 3, 4]
+        ]?;
+
+        let out = quotes.join_asof_by(
+            &trades,
+            "time",
+            "t",
+            &["ticker"],
+            &["ticker"],
+            AsOfOptions::default(),
+        )?;
+        assert_eq!(out.get_column_names(), &["time", "ticker", "price"]);
+        assert_eq!(
+            Vec::from(out.column("price")?.i32()?),
+            &[Some(3), Some(2), Some(1), Some(2)]
+        );
+
+        let out = quotes.join(
+            &trades,
+            vec!["time", "ticker"],
+            vec!["t", "ticker"],
+            JoinType::AsOf(AsOfOptions::default()),
+        );
+        assert!(out.is_ok());
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+//! Summary statistics of a DataFrame.
+use crate::prelude::*;
+
+const STATISTICS: [&str; 11] = [
+    "count",
+    "null_count",
+    "mean",
+    "std",
+    "min",
+    "25%",
+    "50%",
+    "75%",
+    "max",
+    "unique",
+    "top",
+];
+
+/// Get the value of a Series of length 1 as `f64`.
+fn first_f64(s: &Series) -> Result<Option<f64>> {
+    let s = s.cast::<Float64Type>()?;
+    Ok(s.f64()?.get(0))
+}
+
+fn describe_numeric(s: &Series) -> Result<Series> {
+    let quantile = |q| {
+        s.quantile_as_series(q, QuantileInterpolOptions::default())
+            .and_then(|s| first_f64(&s))
+    };
+    let values = [
+        Some((s.len() - s.null_count()) as f64),
+        Some(s.null_count() as f64),
+        s.mean(),
+        first_f64(&s.std_as_series())?,
+        first_f64(&s.min_as_series())?,
+        quantile(0.25)?,
+        quantile(0.5)?,
+        quantile(0.75)?,
+        first_f64(&s.max_as_series())?,
+        None,
+        None,
+    ];
+    Ok(Series::new(s.name(), &values))
+}
+
+fn describe_utf8(s: &Series) -> Result<Series> {
+    let counts = s.value_counts(false)?;
+    let top = match counts.height() {
+        0 => None,
+        _ => counts.select_at_idx(0).unwrap().utf8()?.get(0),
+    };
+    let count = (s.len() - s.null_count()).to_string();
+    let null_count = s.null_count().to_string();
+    let unique = counts.height().to_string();
+
+    let values = [
+        Some(count.as_str()),
+        Some(null_count.as_str()),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(unique.as_str()),
+        top,
+    ];
+    Ok(Series::new(s.name(), &values))
+}
+
+impl DataFrame {
+    /// Summary statistics of the `DataFrame`. The first column, `"describe"`, names the
+    /// statistics. Numeric columns get the `count`, `null_count`, `mean`, `std`, `min`,
+    /// quartiles and `max` as `Float64`. `Utf8` columns get the `count`, `null_count`,
+    /// number of `unique` values and the most frequent value (`top`). Columns of other
+    /// data types are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    ///
+    /// fn example(df: &DataFrame) -> Result<()> {
+    ///     let summary = df.describe()?;
+    ///     println!("{}", summary);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn describe(&self) -> Result<DataFrame> {
+        let mut columns = Vec::with_capacity(self.width() + 1);
+        columns.push(Series::new("describe", &STATISTICS));
+
+        for s in &self.columns {
+            use DataType::*;
+            match s.dtype() {
+                UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32
+                | Float64 => columns.push(describe_numeric(s)?),
+                Utf8 => columns.push(describe_utf8(s)?),
+                _ => {}
+            }
+        }
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_describe() -> Result<()> {
+        let df = df![
+            "a" => [Some(1.0), Some(2.0), None, Some(3.0)],
+            "b" => [Some("x"), Some("y"), Some("x"), None],
+            "c" => [true, false, true, false]
+        ]?;
+
+        let out = df.describe()?;
+        assert_eq!(out.get_column_names(), &["describe", "a", "b"]);
+        assert_eq!(out.height(), 11);
+
+        let a = out.column("a")?.f64()?;
+        assert_eq!(a.get(0), Some(3.0));
+        assert_eq!(a.get(1), Some(1.0));
+        assert_eq!(a.get(2), Some(2.0));
+        assert_eq!(a.get(8), Some(3.0));
+
+        let b = out.column("b")?.utf8()?;
+        assert_eq!(b.get(0), Some("3"));
+        assert_eq!(b.get(9), Some("2"));
+        assert_eq!(b.get(10), Some("x"));
+        Ok(())
+    }
+}
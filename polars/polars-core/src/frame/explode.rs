@@ -1,6 +1,7 @@
 use crate::chunked_array::ops::explode::offsets_to_indexes;
 use crate::frame::select::Selection;
 use crate::prelude::*;
+use crate::utils::get_supertype;
 use std::collections::VecDeque;
 
 fn get_exploded(series: &Series) -> Result<(Series, &[i64])> {
@@ -13,6 +14,7 @@ fn get_exploded(series: &Series) -> Result<(Series, &[i64])> {
 
 impl DataFrame {
     /// Explode `DataFrame` to long format by exploding a column with Lists.
+    /// Multiple columns can be exploded at once, if their lists have the same lengths in every row.
     ///
     /// # Example
     ///
@@ -87,6 +89,9 @@ impl DataFrame {
             df = df.drop(s.name())?;
         }
 
+        // offsets of the first exploded column, relative to its first offset
+        let mut first_offsets: Vec<i64> = vec![];
+
         for (i, s) in columns.iter().enumerate() {
             if let Ok((exploded, offsets)) = get_exploded(s) {
                 let col_idx = self.name_to_idx(s.name())?;
@@ -95,6 +100,20 @@ impl DataFrame {
                 if i == 0 {
                     let row_idx = offsets_to_indexes(offsets, exploded.len());
                     df = unsafe { df.take_iter_unchecked(row_idx.into_iter()) };
+                    first_offsets = offsets.iter().map(|o| o - offsets[0]).collect();
+                } else if offsets
+                    .iter()
+                    .zip(&first_offsets)
+                    .any(|(o, first)| o - offsets[0] != *first)
+                {
+                    return Err(PolarsError::ShapeMisMatch(
+                        format!(
+                            "The lists in the exploded columns don't have the same lengths in every row. Column {} differs from column {}",
+                            s.name(),
+                            columns[0].name()
+                        )
+                        .into(),
+                    ));
                 }
                 if exploded.len() == df.height() {
                     df.columns.insert(col_idx, exploded);
@@ -121,6 +140,7 @@ impl DataFrame {
     ///
     /// * `id_vars` - String slice that represent the columns to use as id variables.
     /// * `value_vars` - String slice that represent the columns to use as value variables.
+    ///   If this is empty, all the columns that are not in `id_vars` are used.
     ///
     /// ```rust
     ///
@@ -174,15 +194,39 @@ impl DataFrame {
         id_vars: SelId,
         value_vars: SelValue,
     ) -> Result<Self> {
-        let ids = self.select(id_vars)?;
-        let value_vars = value_vars.to_selection_vec();
+        let id_vars = id_vars.to_selection_vec();
+        let ids = self.select(id_vars.clone())?;
+        let mut value_vars = value_vars.to_selection_vec();
+        // if no value columns are given, all the columns that are not an id are melted
+        if value_vars.is_empty() {
+            value_vars = self
+                .get_column_names()
+                .into_iter()
+                .filter(|name| !id_vars.contains(name))
+                .collect();
+        }
         let len = self.height();
 
+        // all values end up in a single column, so they need a common dtype
+        let mut value_dtype: Option<DataType> = None;
+        for name in &value_vars {
+            let dtype = self.column(name)?.dtype();
+            value_dtype = Some(match value_dtype {
+                Some(st) => get_supertype(&st, dtype)?,
+                None => dtype.clone(),
+            });
+        }
+
         let mut dataframe_chunks = VecDeque::with_capacity(value_vars.len());
 
         for value_column_name in value_vars {
             let variable_col = Utf8Chunked::full("variable", value_column_name, len).into_series();
             let mut value_col = self.column(value_column_name)?.clone();
+            if let Some(dtype) = &value_dtype {
+                if value_col.dtype() != dtype {
+                    value_col = value_col.cast_with_dtype(dtype)?;
+                }
+            }
             value_col.rename("value");
 
             let mut df_chunk = ids.clone();
@@ -240,6 +284,41 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_explode_multiple_columns() -> Result<()> {
+        let a = Series::new("a", &[Series::new("", &[1, 2]), Series::new("", &[3])]);
+        let b = Series::new(
+            "b",
+            &[Series::new("", &["x", "y"]), Series::new("", &["z"])],
+        );
+        let c = Series::new("c", &[10, 20]);
+        let df = DataFrame::new(vec![a, b, c])?;
+
+        let exploded = df.explode(&["a", "b"])?;
+        assert_eq!(exploded.get_column_names(), &["a", "b", "c"]);
+        assert_eq!(
+            Vec::from(exploded.column("a")?.i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(exploded.column("b")?.utf8()?),
+            &[Some("x"), Some("y"), Some("z")]
+        );
+        assert_eq!(
+            Vec::from(exploded.column("c")?.i32()?),
+            &[Some(10), Some(10), Some(20)]
+        );
+
+        // same total length, but different lengths per row
+        let b = Series::new(
+            "b",
+            &[Series::new("", &["x"]), Series::new("", &["y", "z"])],
+        );
+        let df = DataFrame::new(vec![df.column("a")?.clone(), b])?;
+        assert!(df.explode(&["a", "b"]).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_melt() {
         let df = df!("A" => &["a", "b", "a"],
@@ -255,4 +334,24 @@ mod test {
             &[Some(10), Some(11), Some(12), Some(2), Some(4), Some(6)]
         )
     }
+
+    #[test]
+    fn test_melt_defaults_and_supertype() -> Result<()> {
+        let df = df!("A" => &["a", "b"],
+         "B" => &[1, 3],
+         "C" => &[0.5, 1.5]
+        )?;
+
+        let melted = df.melt(&["A"], Vec::<&str>::new())?;
+        assert_eq!(melted.get_column_names(), &["A", "variable", "value"]);
+        assert_eq!(
+            Vec::from(melted.column("variable")?.utf8()?),
+            &[Some("B"), Some("B"), Some("C"), Some("C")]
+        );
+        assert_eq!(
+            Vec::from(melted.column("value")?.f64()?),
+            &[Some(1.0), Some(3.0), Some(0.5), Some(1.5)]
+        );
+        Ok(())
+    }
 }
@@ -300,6 +300,13 @@ impl AggFirst for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl AggFirst for BinaryChunked {
+    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        impl_agg_first!(self, groups, BinaryChunked)
+    }
+}
+
 impl AggFirst for ListChunked {
     fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
         impl_agg_first!(self, groups, ListChunked)
@@ -381,6 +388,13 @@ impl AggLast for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl AggLast for BinaryChunked {
+    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        impl_agg_last!(self, groups, BinaryChunked)
+    }
+}
+
 impl AggLast for CategoricalChunked {
     fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
         self.cast::<UInt32Type>()
@@ -475,6 +489,22 @@ impl AggNUnique for CategoricalChunked {
             })
     }
 }
+#[cfg(feature = "dtype-binary")]
+impl AggNUnique for BinaryChunked {
+    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+        let ca: NoNull<UInt32Chunked> = groups
+            .iter()
+            .map(|(_first, idx)| {
+                let mut set = HashSet::with_hasher(RandomState::new());
+                for i in idx {
+                    set.insert(self.get(*i as usize));
+                }
+                set.len() as u32
+            })
+            .collect();
+        Some(ca.into_inner())
+    }
+}
 #[cfg(feature = "object")]
 impl<T> AggNUnique for ObjectChunked<T> {}
 
@@ -565,7 +595,12 @@ where
 }
 
 pub(crate) trait AggQuantile {
-    fn agg_quantile(&self, _groups: &[(u32, Vec<u32>)], _quantile: f64) -> Option<Series> {
+    fn agg_quantile(
+        &self,
+        _groups: &[(u32, Vec<u32>)],
+        _quantile: f64,
+        _interpol: QuantileInterpolOptions,
+    ) -> Option<Series> {
         None
     }
 
@@ -580,18 +615,19 @@ where
     T::Native: PartialOrd + Num + NumCast + Zero,
     ChunkedArray<T>: IntoSeries,
 {
-    fn agg_quantile(&self, groups: &[(u32, Vec<u32>)], quantile: f64) -> Option<Series> {
-        agg_helper::<T, _>(groups, |(_first, idx)| {
+    fn agg_quantile(
+        &self,
+        groups: &[(u32, Vec<u32>)],
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Option<Series> {
+        agg_helper::<Float64Type, _>(groups, |(_first, idx)| {
             if idx.is_empty() {
                 return None;
             }
 
             let group_vals = unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
-            let sorted_idx_ca = group_vals.argsort(false);
-            let sorted_idx = sorted_idx_ca.downcast_iter().next().unwrap().values();
-            let quant_idx = (quantile * (sorted_idx.len() - 1) as f64) as usize;
-            let value_idx = sorted_idx[quant_idx];
-            group_vals.get(value_idx as usize)
+            group_vals.quantile(quantile, interpol).unwrap()
         })
     }
 
@@ -613,3 +649,46 @@ impl AggQuantile for ListChunked {}
 impl AggQuantile for CategoricalChunked {}
 #[cfg(feature = "object")]
 impl<T> AggQuantile for ObjectChunked<T> {}
+
+pub(crate) trait AggMoment {
+    fn agg_skew(&self, _groups: &[(u32, Vec<u32>)], _bias: bool) -> Option<Series> {
+        None
+    }
+
+    fn agg_kurtosis(
+        &self,
+        _groups: &[(u32, Vec<u32>)],
+        _fisher: bool,
+        _bias: bool,
+    ) -> Option<Series> {
+        None
+    }
+}
+
+impl<T> AggMoment for ChunkedArray<T>
+where
+    T: PolarsNumericType + Sync,
+    T::Native: ToPrimitive,
+    ChunkedArray<T>: IntoSeries,
+{
+    fn agg_skew(&self, groups: &[(u32, Vec<u32>)], bias: bool) -> Option<Series> {
+        agg_helper::<Float64Type, _>(groups, |(_first, idx)| {
+            let group_vals = unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
+            group_vals.skew(bias)
+        })
+    }
+
+    fn agg_kurtosis(&self, groups: &[(u32, Vec<u32>)], fisher: bool, bias: bool) -> Option<Series> {
+        agg_helper::<Float64Type, _>(groups, |(_first, idx)| {
+            let group_vals = unsafe { self.take_unchecked(idx.iter().map(|i| *i as usize).into()) };
+            group_vals.kurtosis(fisher, bias)
+        })
+    }
+}
+
+impl AggMoment for Utf8Chunked {}
+impl AggMoment for BooleanChunked {}
+impl AggMoment for ListChunked {}
+impl AggMoment for CategoricalChunked {}
+#[cfg(feature = "object")]
+impl<T> AggMoment for ObjectChunked<T> {}
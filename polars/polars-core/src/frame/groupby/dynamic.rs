@@ -0,0 +1,242 @@
+//! Groupby operations over (possibly overlapping) time windows.
+use crate::prelude::*;
+use crate::utils::chrono::Duration;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DynamicGroupOptions {
+    /// `Date64` column that determines the windows. It should be sorted in ascending order.
+    pub index_column: String,
+    /// Interval between the starts of two windows.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::duration"))]
+    pub every: Duration,
+    /// Length of a window. A window contains the rows in `[start, start + period)`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::duration"))]
+    pub period: Duration,
+    /// Shift of the window starts. By default they are multiples of `every` since the epoch.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::duration"))]
+    pub offset: Duration,
+    /// Add the `_lower_boundary` and `_upper_boundary` columns of the windows to the output.
+    pub include_boundaries: bool,
+}
+
+impl DynamicGroupOptions {
+    /// Windows of length `every` that don't overlap.
+    pub fn new(index_column: &str, every: Duration) -> Self {
+        DynamicGroupOptions {
+            index_column: index_column.to_string(),
+            every,
+            period: every,
+            offset: Duration::zero(),
+            include_boundaries: false,
+        }
+    }
+}
+
+/// Compute the non-empty windows of a sorted slice of timestamps.
+/// Returns the lower boundary, upper boundary and the offsets of the rows in every window.
+fn time_windows(
+    time: &[i64],
+    every: i64,
+    period: i64,
+    offset: i64,
+) -> Vec<(i64, i64, std::ops::Range<usize>)> {
+    let (first, last) = match (time.first(), time.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return vec![],
+    };
+    // the first window that contains the first timestamp
+    let mut start = (first - offset).div_euclid(every) * every + offset;
+    while start - every + period > first {
+        start -= every;
+    }
+
+    let mut windows = vec![];
+    while start <= last {
+        let end = start + period;
+        let lower = time.partition_point(|t| *t < start);
+        let upper = time.partition_point(|t| *t < end);
+        if lower < upper {
+            windows.push((start, end, lower..upper));
+        }
+        start += every;
+    }
+    windows
+}
+
+impl DataFrame {
+    /// Group the DataFrame in time windows over the `index_column` and optionally per `by` group.
+    /// A row can be part of multiple windows if the `period` is larger than `every`.
+    ///
+    /// Returns the key columns: the `by` columns followed by the lower boundaries of the windows
+    /// (named after the `index_column`) and the groups, that can be used to aggregate the
+    /// other columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// use polars_core::utils::chrono::Duration;
+    ///
+    /// fn hourly_sum(df: &DataFrame) -> Result<DataFrame> {
+    ///     let options = DynamicGroupOptions::new("time", Duration::hours(1));
+    ///     let (mut keys, groups) = df.groupby_dynamic(vec![], &options)?;
+    ///     let mut sum = df.column("value")?.agg_sum(&groups).unwrap();
+    ///     sum.rename("value_sum");
+    ///     keys.push(sum);
+    ///     DataFrame::new(keys)
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "dynamic_groupby")))]
+    pub fn groupby_dynamic(
+        &self,
+        by: Vec<Series>,
+        options: &DynamicGroupOptions,
+    ) -> Result<(Vec<Series>, GroupTuples)> {
+        let every = options.every.num_milliseconds();
+        if every <= 0 || options.period.num_milliseconds() <= 0 {
+            return Err(PolarsError::ValueError(
+                "every and period should be positive durations".into(),
+            ));
+        }
+        let index = self.column(&options.index_column)?;
+        if index.dtype() != &DataType::Date64 {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "the index column of a dynamic groupby should be of type date64, got {:?}",
+                    index.dtype()
+                )
+                .into(),
+            ));
+        }
+        if index.null_count() > 0 {
+            return Err(PolarsError::HasNullValues(
+                "the index column of a dynamic groupby has null values".into(),
+            ));
+        }
+        let time = index.date64()?.into_no_null_iter().collect::<Vec<_>>();
+        if time.windows(2).any(|w| w[0] > w[1]) {
+            return Err(PolarsError::InvalidOperation(
+                "the index column of a dynamic groupby should be sorted in ascending order".into(),
+            ));
+        }
+
+        let by_groups = if by.is_empty() {
+            vec![(0, (0..self.height() as u32).collect())]
+        } else {
+            let mut groups = self.groupby_with_series(by.clone(), true)?.groups;
+            groups.sort();
+            groups
+        };
+
+        let mut key_idx = vec![];
+        let mut lower_boundaries = vec![];
+        let mut upper_boundaries = vec![];
+        let mut groups = vec![];
+        for (first, idx) in by_groups {
+            let group_time = idx.iter().map(|i| time[*i as usize]).collect::<Vec<_>>();
+            for (lower, upper, range) in time_windows(
+                &group_time,
+                every,
+                options.period.num_milliseconds(),
+                options.offset.num_milliseconds(),
+            ) {
+                let window = idx[range].to_vec();
+                key_idx.push(first as usize);
+                lower_boundaries.push(lower);
+                upper_boundaries.push(upper);
+                groups.push((window[0], window));
+            }
+        }
+
+        let mut keys = by
+            .iter()
+            // Safety:
+            // the group indexes are in bounds.
+            .map(|s| unsafe { s.take_iter_unchecked(&mut key_idx.iter().copied()) })
+            .collect::<Vec<_>>();
+        if options.include_boundaries {
+            keys.push(
+                Date64Chunked::new_from_slice("_lower_boundary", &lower_boundaries).into_series(),
+            );
+            keys.push(
+                Date64Chunked::new_from_slice("_upper_boundary", &upper_boundaries).into_series(),
+            );
+        }
+        keys.push(
+            Date64Chunked::new_from_slice(&options.index_column, &lower_boundaries).into_series(),
+        );
+        Ok((keys, groups))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_groupby_dynamic() -> Result<()> {
+        let minute = 60 * 1000;
+        let time =
+            Date64Chunked::new_from_slice("time", &[0, minute, 3 * minute, 4 * minute, 7 * minute]);
+        let df = DataFrame::new(vec![
+            time.into_series(),
+            Series::new("value", &[1, 2, 3, 4, 5]),
+            Series::new("id", &["a", "a", "b", "a", "b"]),
+        ])?;
+
+        let options = DynamicGroupOptions::new("time", Duration::minutes(2));
+        let (keys, groups) = df.groupby_dynamic(vec![], &options)?;
+        assert_eq!(
+            Vec::from(keys[0].date64()?),
+            &[
+                Some(0),
+                Some(2 * minute),
+                Some(4 * minute),
+                Some(6 * minute)
+            ]
+        );
+        let sum = df.column("value")?.agg_sum(&groups).unwrap();
+        assert_eq!(Vec::from(sum.i32()?), &[Some(3), Some(3), Some(4), Some(5)]);
+
+        // overlapping windows per id
+        let options = DynamicGroupOptions {
+            period: Duration::minutes(4),
+            include_boundaries: true,
+            ..options
+        };
+        let (keys, groups) = df.groupby_dynamic(vec![df.column("id")?.clone()], &options)?;
+        assert_eq!(keys[0].name(), "id");
+        assert_eq!(keys[1].name(), "_lower_boundary");
+        assert_eq!(
+            Vec::from(keys[3].date64()?),
+            &[
+                Some(-2 * minute),
+                Some(0),
+                Some(2 * minute),
+                Some(4 * minute),
+                Some(0),
+                Some(2 * minute),
+                Some(4 * minute),
+                Some(6 * minute)
+            ]
+        );
+        let sum = df.column("value")?.agg_sum(&groups).unwrap();
+        assert_eq!(
+            Vec::from(sum.i32()?),
+            &[
+                Some(3),
+                Some(3),
+                Some(4),
+                Some(4),
+                Some(3),
+                Some(3),
+                Some(5),
+                Some(5)
+            ]
+        );
+        Ok(())
+    }
+}
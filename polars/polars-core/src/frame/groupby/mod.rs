@@ -13,11 +13,15 @@ use std::fmt::Debug;
 use std::hash::{BuildHasher, Hash, Hasher};
 
 pub mod aggregations;
+#[cfg(feature = "dynamic_groupby")]
+pub mod dynamic;
 pub(crate) mod hashing;
 #[cfg(feature = "pivot")]
 pub(crate) mod pivot;
 #[cfg(feature = "downsample")]
 pub mod resample;
+#[cfg(feature = "rolling_groupby")]
+pub mod rolling;
 
 pub type GroupTuples = Vec<(u32, Vec<u32>)>;
 pub type GroupedMap<T> = HashMap<T, Vec<u32>, RandomState>;
@@ -172,6 +176,13 @@ impl IntoGroupTuples for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl IntoGroupTuples for BinaryChunked {
+    fn group_tuples(&self, _multithreaded: bool) -> GroupTuples {
+        groupby(self.into_iter())
+    }
+}
+
 impl IntoGroupTuples for CategoricalChunked {
     fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
         self.cast::<UInt32Type>()
@@ -652,10 +663,12 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     /// ```rust
     /// # use polars_core::prelude::*;
     /// fn example(df: DataFrame) -> Result<DataFrame> {
-    ///     df.groupby("date")?.select("temp").quantile(0.2)
+    ///     df.groupby("date")?
+    ///         .select("temp")
+    ///         .quantile(0.2, QuantileInterpolOptions::Linear)
     /// }
     /// ```
-    pub fn quantile(&self, quantile: f64) -> Result<DataFrame> {
+    pub fn quantile(&self, quantile: f64, interpol: QuantileInterpolOptions) -> Result<DataFrame> {
         if !(0.0..=1.0).contains(&quantile) {
             return Err(PolarsError::Other(
                 "quantile should be within 0.0 and 1.0".into(),
@@ -664,7 +677,7 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         let (mut cols, agg_cols) = self.prepare_agg()?;
         for agg_col in agg_cols {
             let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Quantile(quantile));
-            let opt_agg = agg_col.agg_quantile(&self.groups, quantile);
+            let opt_agg = agg_col.agg_quantile(&self.groups, quantile, interpol);
             if let Some(mut agg) = opt_agg {
                 agg.rename(&new_name);
                 cols.push(agg.into_series());
@@ -696,6 +709,46 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         DataFrame::new(cols)
     }
 
+    /// Aggregate grouped `Series` and determine the skewness per group.
+    /// See [ChunkedArray::skew](crate::chunked_array::ChunkedArray::skew).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("temp").skew(false)
+    /// }
+    /// ```
+    pub fn skew(&self, bias: bool) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Skew(bias));
+            let opt_agg = agg_col.agg_skew(&self.groups, bias);
+            if let Some(mut agg) = opt_agg {
+                agg.rename(&new_name);
+                cols.push(agg.into_series());
+            }
+        }
+        DataFrame::new(cols)
+    }
+
+    /// Aggregate grouped `Series` and determine the kurtosis per group.
+    /// See [ChunkedArray::kurtosis](crate::chunked_array::ChunkedArray::kurtosis).
+    pub fn kurtosis(&self, fisher: bool, bias: bool) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name =
+                fmt_groupby_column(agg_col.name(), GroupByMethod::Kurtosis(fisher, bias));
+            let opt_agg = agg_col.agg_kurtosis(&self.groups, fisher, bias);
+            if let Some(mut agg) = opt_agg {
+                agg.rename(&new_name);
+                cols.push(agg.into_series());
+            }
+        }
+        DataFrame::new(cols)
+    }
+
     /// Aggregate grouped `Series` and determine the variance per group.
     pub fn var(&self) -> Result<DataFrame> {
         let (mut cols, agg_cols) = self.prepare_agg()?;
@@ -724,6 +777,36 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         DataFrame::new(cols)
     }
 
+    /// Aggregate grouped `Series` and determine the most frequent value(s) per group. As a group
+    /// can have multiple most frequent values, the output columns are lists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("date")?.select("temp").mode()
+    /// }
+    /// ```
+    #[cfg(feature = "mode")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mode")))]
+    pub fn mode(&self) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        for agg_col in agg_cols {
+            let new_name = format!["{}_mode", agg_col.name()];
+            if let Some(agg) = agg_col.agg_list(&self.groups) {
+                let mut ca: ListChunked = agg
+                    .list()?
+                    .into_iter()
+                    .map(|opt_s| opt_s.and_then(|s| s.mode().ok()))
+                    .collect();
+                ca.rename(&new_name);
+                cols.push(ca.into_series());
+            }
+        }
+        DataFrame::new(cols)
+    }
+
     /// Aggregate grouped series and compute the number of values per group.
     ///
     /// # Example
@@ -1000,6 +1083,8 @@ pub enum GroupByMethod {
     List,
     Std,
     Var,
+    Skew(bool),
+    Kurtosis(bool, bool),
 }
 
 // Formatting functions used in eager and lazy code for renaming grouped columns
@@ -1020,6 +1105,8 @@ pub fn fmt_groupby_column(name: &str, method: GroupByMethod) -> String {
         Quantile(quantile) => format!["{}_quantile_{:.2}", name, quantile],
         Std => format!["{}_agg_std", name],
         Var => format!["{}_agg_var", name],
+        Skew(_) => format!["{}_skew", name],
+        Kurtosis(_, _) => format!["{}_kurtosis", name],
     }
 }
 
@@ -1115,7 +1202,7 @@ mod test {
             df.groupby("date")
                 .unwrap()
                 .select("temp")
-                .quantile(0.2)
+                .quantile(0.2, QuantileInterpolOptions::default())
                 .unwrap()
         );
         println!(
@@ -1356,4 +1443,65 @@ mod test {
         dbg!(out);
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "mode")]
+    fn test_groupby_mode() -> Result<()> {
+        let df = df![
+            "g" => ["foo", "foo", "foo", "bar", "bar"],
+            "int" => [1, 2, 2, 3, 4]
+        ]?;
+
+        let out = df.groupby_stable("g")?.select("int").mode()?;
+        let modes = out.column("int_mode")?.list()?;
+        assert_eq!(Vec::from(modes.get(0).unwrap().i32()?), &[Some(2)]);
+        assert_eq!(Vec::from(modes.get(1).unwrap().i32()?), &[Some(3), Some(4)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_quantile() -> Result<()> {
+        let df = df![
+            "g" => ["foo", "foo", "foo", "foo", "bar"],
+            "int" => [1, 2, 3, 4, 5]
+        ]?;
+        let quantile = |interpol| {
+            df.groupby_stable("g")?
+                .select("int")
+                .quantile(0.5, interpol)?
+                .column("int_quantile_0.50")
+                .map(|s| Vec::from(s.f64().unwrap()))
+        };
+
+        assert_eq!(
+            quantile(QuantileInterpolOptions::Midpoint)?,
+            &[Some(2.5), Some(5.0)]
+        );
+        assert_eq!(
+            quantile(QuantileInterpolOptions::Lower)?,
+            &[Some(2.0), Some(5.0)]
+        );
+        assert!(df
+            .groupby("g")?
+            .quantile(1.5, QuantileInterpolOptions::Lower)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_skew_kurtosis() -> Result<()> {
+        let df = df![
+            "g" => ["foo", "foo", "foo", "bar", "bar", "bar", "bar"],
+            "int" => [1, 2, 3, 1, 1, 1, 5]
+        ]?;
+        let out = df.groupby_stable("g")?.select("int").skew(true)?;
+        let skew = out.column("int_skew")?.f64()?;
+        assert_eq!(skew.get(0), Some(0.0));
+        assert!((skew.get(1).unwrap() - 2.0 / 3f64.sqrt()).abs() < 1e-12);
+
+        let out = df.groupby_stable("g")?.select("int").kurtosis(true, true)?;
+        let kurt = out.column("int_kurtosis")?.f64()?;
+        assert!((kurt.get(1).unwrap() + 2.0 / 3.0).abs() < 1e-12);
+        Ok(())
+    }
 }
@@ -200,6 +200,48 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
             values_column,
         }
     }
+
+    /// Pivot multiple value columns at once, each with its own aggregation.
+    ///
+    /// The pivoted columns are named `{values_column}_{aggregation}_{pivot_value}`, e.g. `N_sum_k`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_core::df;
+    ///
+    /// fn example() -> Result<DataFrame> {
+    ///     let df = df!("foo" => &["A", "A", "B", "B", "C"],
+    ///         "N" => &[1, 2, 2, 4, 2],
+    ///         "M" => &[1.0, 2.0, 3.0, 4.0, 5.0],
+    ///         "bar" => &["k", "l", "m", "n", "0"]
+    ///         )?;
+    ///
+    ///     df.groupby("foo")?
+    ///     .pivot_multiple("bar", &[("N", PivotAgg::Sum), ("M", PivotAgg::List)])
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "pivot")))]
+    pub fn pivot_multiple(
+        &self,
+        pivot_column: &str,
+        values: &[(&str, PivotAgg)],
+    ) -> Result<DataFrame> {
+        let pivot_series = self.df.column(pivot_column)?;
+        let mut out = DataFrame::new(self.keys())?;
+        for (values_column, agg_type) in values {
+            let values_series = self.df.column(values_column)?;
+            let pivoted = pivot_impl(values_series, pivot_series, vec![], &self.groups, *agg_type)?;
+            let mut cols = pivoted.columns;
+            for s in &mut cols {
+                let name = format!("{}_{}_{}", values_column, agg_type.name(), s.name());
+                s.rename(&name);
+            }
+            out.hstack_mut(&cols)?;
+        }
+        Ok(out)
+    }
 }
 
 /// Intermediate structure when a `pivot` operation is applied.
@@ -325,6 +367,9 @@ where
                         PivotAgg::Max => pivot_agg_max(main_builder, v),
                         PivotAgg::Mean => pivot_agg_mean(main_builder, v),
                         PivotAgg::Median => pivot_agg_median(main_builder, v),
+                        PivotAgg::Count | PivotAgg::List => {
+                            unreachable!("dispatched in pivot_impl")
+                        }
                     },
                 }
             }
@@ -436,6 +481,8 @@ impl ChunkPivot for ListChunked {}
 #[cfg(feature = "object")]
 impl<T> ChunkPivot for ObjectChunked<T> {}
 
+/// The aggregation that is applied to the values that end up in the same cell of a pivot.
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum PivotAgg {
     First,
     Sum,
@@ -443,6 +490,70 @@ pub enum PivotAgg {
     Max,
     Mean,
     Median,
+    /// The number of values.
+    Count,
+    /// All the values, collected in a list.
+    List,
+}
+
+impl PivotAgg {
+    fn name(&self) -> &'static str {
+        use PivotAgg::*;
+        match self {
+            First => "first",
+            Sum => "sum",
+            Min => "min",
+            Max => "max",
+            Mean => "mean",
+            Median => "median",
+            Count => "count",
+            List => "list",
+        }
+    }
+}
+
+/// Pivot by collecting the values of every cell in a list. This works for all dtypes.
+fn pivot_list(
+    values_series: &Series,
+    pivot_series: &Series,
+    keys: Vec<Series>,
+    groups: &[(u32, Vec<u32>)],
+) -> Result<DataFrame> {
+    let pivot_unique = pivot_series.unique()?;
+    let pivot_vec_unique: Vec<_> = pivot_unique.as_groupable_iter()?.collect();
+    let pivot_vec: Vec<_> = pivot_series.as_groupable_iter()?.collect();
+
+    let mut cols = keys;
+    cols.reserve_exact(pivot_vec_unique.len());
+    for pivot_val in pivot_vec_unique.iter().flatten() {
+        let mut ca: ListChunked = groups
+            .iter()
+            .map(|(_first, idx)| {
+                let mut iter = idx
+                    .iter()
+                    .map(|i| *i as usize)
+                    .filter(|i| pivot_vec[*i].as_ref() == Some(pivot_val));
+                values_series.take_iter(&mut iter)
+            })
+            .collect();
+        ca.rename(&format!("{:?}", pivot_val));
+        cols.push(ca.into_series());
+    }
+    DataFrame::new(cols)
+}
+
+fn pivot_impl(
+    values_series: &Series,
+    pivot_series: &Series,
+    keys: Vec<Series>,
+    groups: &[(u32, Vec<u32>)],
+    agg_type: PivotAgg,
+) -> Result<DataFrame> {
+    match agg_type {
+        PivotAgg::Count => values_series.pivot_count(&**pivot_series, keys, groups),
+        PivotAgg::List => pivot_list(values_series, pivot_series, keys, groups),
+        _ => values_series.pivot(&**pivot_series, keys, groups, agg_type),
+    }
 }
 
 fn pivot_agg_first<T>(builder: &mut PrimitiveChunkedBuilder<T>, v: &[Option<T::Native>])
@@ -527,15 +638,22 @@ impl<'df, 'sel_str> Pivot<'df, 'sel_str> {
     pub fn count(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot_count(&**pivot_series, self.gb.keys(), &self.gb.groups)
+        pivot_impl(
+            values_series,
+            pivot_series,
+            self.gb.keys(),
+            &self.gb.groups,
+            PivotAgg::Count,
+        )
     }
 
     /// Aggregate the pivot results by taking the first occurring value.
     pub fn first(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot(
-            &**pivot_series,
+        pivot_impl(
+            values_series,
+            pivot_series,
             self.gb.keys(),
             &self.gb.groups,
             PivotAgg::First,
@@ -546,8 +664,9 @@ impl<'df, 'sel_str> Pivot<'df, 'sel_str> {
     pub fn sum(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot(
-            &**pivot_series,
+        pivot_impl(
+            values_series,
+            pivot_series,
             self.gb.keys(),
             &self.gb.groups,
             PivotAgg::Sum,
@@ -558,8 +677,9 @@ impl<'df, 'sel_str> Pivot<'df, 'sel_str> {
     pub fn min(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot(
-            &**pivot_series,
+        pivot_impl(
+            values_series,
+            pivot_series,
             self.gb.keys(),
             &self.gb.groups,
             PivotAgg::Min,
@@ -570,8 +690,9 @@ impl<'df, 'sel_str> Pivot<'df, 'sel_str> {
     pub fn max(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot(
-            &**pivot_series,
+        pivot_impl(
+            values_series,
+            pivot_series,
             self.gb.keys(),
             &self.gb.groups,
             PivotAgg::Max,
@@ -582,8 +703,9 @@ impl<'df, 'sel_str> Pivot<'df, 'sel_str> {
     pub fn mean(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot(
-            &**pivot_series,
+        pivot_impl(
+            values_series,
+            pivot_series,
             self.gb.keys(),
             &self.gb.groups,
             PivotAgg::Mean,
@@ -593,13 +715,27 @@ impl<'df, 'sel_str> Pivot<'df, 'sel_str> {
     pub fn median(&self) -> Result<DataFrame> {
         let pivot_series = self.gb.df.column(self.pivot_column)?;
         let values_series = self.gb.df.column(self.values_column)?;
-        values_series.pivot(
-            &**pivot_series,
+        pivot_impl(
+            values_series,
+            pivot_series,
             self.gb.keys(),
             &self.gb.groups,
             PivotAgg::Median,
         )
     }
+
+    /// Aggregate the pivot results by collecting all duplicates in a list.
+    pub fn list(&self) -> Result<DataFrame> {
+        let pivot_series = self.gb.df.column(self.pivot_column)?;
+        let values_series = self.gb.df.column(self.values_column)?;
+        pivot_impl(
+            values_series,
+            pivot_series,
+            self.gb.keys(),
+            &self.gb.groups,
+            PivotAgg::List,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -644,4 +780,32 @@ mod test {
             &[Some(0), Some(0), Some(2)]
         );
     }
+
+    #[test]
+    fn test_pivot_multiple() -> Result<()> {
+        let df = df!("foo" => &["A", "A", "B", "B", "C"],
+            "N" => &[1, 2, 2, 4, 2],
+            "M" => &[1.0, 2.0, 3.0, 4.0, 5.0],
+            "bar" => &["k", "l", "m", "m", "l"]
+        )?;
+        let pvt = df
+            .groupby("foo")?
+            .pivot_multiple("bar", &[("N", PivotAgg::Sum), ("M", PivotAgg::List)])?
+            .sort("foo", false)?;
+        assert_eq!(pvt.width(), 7);
+        assert_eq!(
+            Vec::from(pvt.column("N_sum_m")?.i32()?),
+            &[None, Some(6), None]
+        );
+        let m_list = pvt.column("M_list_m")?.list()?.get(1).unwrap();
+        assert_eq!(Vec::from(m_list.f64()?), &[Some(3.0), Some(4.0)]);
+        assert_eq!(pvt.column("M_list_m")?.list()?.get(0).unwrap().len(), 0);
+
+        let pvt = df.groupby("foo")?.pivot("bar", "N").list()?;
+        assert_eq!(
+            pvt.column("m")?.dtype(),
+            &DataType::List(ArrowDataType::Int32)
+        );
+        Ok(())
+    }
 }
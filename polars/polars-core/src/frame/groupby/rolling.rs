@@ -0,0 +1,187 @@
+//! Groupby operations where every row defines a window that looks back over the index column.
+use crate::prelude::*;
+use crate::utils::chrono::Duration;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How far a rolling window looks back.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RollingPeriod {
+    /// A duration on a `Date64` index column.
+    Duration(#[cfg_attr(feature = "serde", serde(with = "crate::serde::duration"))] Duration),
+    /// A range of values on an integer index column. With a row count as index column this is a
+    /// number of rows.
+    Index(i64),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RollingGroupOptions {
+    /// Column that determines the windows. It should be sorted in ascending order.
+    pub index_column: String,
+    /// The window of a row with index value `t` contains the rows in `(t - period, t]`.
+    pub period: RollingPeriod,
+}
+
+impl RollingGroupOptions {
+    pub fn new(index_column: &str, period: RollingPeriod) -> Self {
+        RollingGroupOptions {
+            index_column: index_column.to_string(),
+            period,
+        }
+    }
+}
+
+impl DataFrame {
+    /// Group the DataFrame in a window per row that looks back over the `index_column`, and
+    /// optionally per `by` group. Every row is the last row of its window.
+    ///
+    /// Returns the key columns: the `by` columns followed by the `index_column` and the groups,
+    /// that can be used to aggregate the other columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// use polars_core::utils::chrono::Duration;
+    ///
+    /// /// Sum of the last 3 days as of every row.
+    /// fn rolling_sum(df: &DataFrame) -> Result<Series> {
+    ///     let period = RollingPeriod::Duration(Duration::days(3));
+    ///     let options = RollingGroupOptions::new("date", period);
+    ///     let (_keys, groups) = df.groupby_rolling(vec![], &options)?;
+    ///     Ok(df.column("value")?.agg_sum(&groups).unwrap())
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rolling_groupby")))]
+    pub fn groupby_rolling(
+        &self,
+        by: Vec<Series>,
+        options: &RollingGroupOptions,
+    ) -> Result<(Vec<Series>, GroupTuples)> {
+        let index = self.column(&options.index_column)?;
+        let period = match (&options.period, index.dtype()) {
+            (RollingPeriod::Duration(duration), DataType::Date64) => duration.num_milliseconds(),
+            (
+                RollingPeriod::Index(period),
+                DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64,
+            ) => *period,
+            (period, dtype) => {
+                return Err(PolarsError::DataTypeMisMatch(
+                    format!(
+                        "a rolling period {:?} cannot be used on an index column of type {:?}",
+                        period, dtype
+                    )
+                    .into(),
+                ))
+            }
+        };
+        if period <= 0 {
+            return Err(PolarsError::ValueError(
+                "the period of a rolling groupby should be positive".into(),
+            ));
+        }
+        if index.null_count() > 0 {
+            return Err(PolarsError::HasNullValues(
+                "the index column of a rolling groupby has null values".into(),
+            ));
+        }
+        let index_i64 = index.cast::<Int64Type>()?;
+        let time = index_i64.i64()?.into_no_null_iter().collect::<Vec<_>>();
+        if time.windows(2).any(|w| w[0] > w[1]) {
+            return Err(PolarsError::InvalidOperation(
+                "the index column of a rolling groupby should be sorted in ascending order".into(),
+            ));
+        }
+
+        let by_groups = if by.is_empty() {
+            vec![(0, (0..self.height() as u32).collect())]
+        } else {
+            let mut groups = self.groupby_with_series(by.clone(), true)?.groups;
+            groups.sort();
+            groups
+        };
+
+        let mut key_idx = Vec::with_capacity(self.height());
+        let mut groups = Vec::with_capacity(self.height());
+        for (_, idx) in by_groups {
+            let group_time = idx.iter().map(|i| time[*i as usize]).collect::<Vec<_>>();
+            for (i, t) in group_time.iter().enumerate() {
+                let lower = group_time.partition_point(|v| *v <= t - period);
+                // rows with an equal index value belong to the same window
+                let upper = i + group_time[i..].partition_point(|v| v <= t);
+                let window = idx[lower..upper].to_vec();
+                key_idx.push(idx[i] as usize);
+                groups.push((window[0], window));
+            }
+        }
+
+        let keys = by
+            .iter()
+            .chain(std::iter::once(index))
+            // Safety:
+            // the row indexes are in bounds.
+            .map(|s| unsafe { s.take_iter_unchecked(&mut key_idx.iter().copied()) })
+            .collect();
+        Ok((keys, groups))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_groupby_rolling() -> Result<()> {
+        let day = 24 * 3600 * 1000;
+        let date = Date64Chunked::new_from_slice("date", &[0, day, day, 3 * day, 5 * day]);
+        let df = DataFrame::new(vec![
+            date.into_series(),
+            Series::new("value", &[1, 2, 3, 4, 5]),
+            Series::new("id", &["a", "b", "a", "a", "b"]),
+            Series::new("row", &[0u32, 1, 2, 3, 4]),
+        ])?;
+
+        let period = RollingPeriod::Duration(Duration::days(3));
+        let options = RollingGroupOptions::new("date", period);
+        let (keys, groups) = df.groupby_rolling(vec![], &options)?;
+        assert_eq!(keys[0].name(), "date");
+        let sum = df.column("value")?.agg_sum(&groups).unwrap();
+        assert_eq!(
+            Vec::from(sum.i32()?),
+            &[Some(1), Some(6), Some(6), Some(9), Some(9)]
+        );
+
+        let (keys, groups) = df.groupby_rolling(vec![df.column("id")?.clone()], &options)?;
+        assert_eq!(
+            Vec::from(keys[0].utf8()?),
+            &[Some("a"), Some("a"), Some("a"), Some("b"), Some("b")]
+        );
+        let sum = df.column("value")?.agg_sum(&groups).unwrap();
+        assert_eq!(
+            Vec::from(sum.i32()?),
+            &[Some(1), Some(4), Some(7), Some(2), Some(5)]
+        );
+
+        // the last two rows
+        let options = RollingGroupOptions::new("row", RollingPeriod::Index(2));
+        let (_, groups) = df.groupby_rolling(vec![], &options)?;
+        let sum = df.column("value")?.agg_sum(&groups).unwrap();
+        assert_eq!(
+            Vec::from(sum.i32()?),
+            &[Some(1), Some(3), Some(5), Some(7), Some(9)]
+        );
+
+        let options = RollingGroupOptions::new("date", RollingPeriod::Index(2));
+        assert!(df.groupby_rolling(vec![], &options).is_err());
+        Ok(())
+    }
+}
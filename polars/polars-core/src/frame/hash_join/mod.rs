@@ -1,5 +1,7 @@
 mod multiple_keys;
 
+#[cfg(feature = "asof_join")]
+use crate::frame::asof_join::AsOfOptions;
 use crate::frame::hash_join::multiple_keys::{
     inner_join_multiple_keys, left_join_multiple_keys, outer_join_multiple_keys,
 };
@@ -16,6 +18,8 @@ use hashbrown::hash_map::{Entry, RawEntryMut};
 use hashbrown::HashMap;
 use itertools::Itertools;
 use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::hash::{BuildHasher, Hash, Hasher};
@@ -39,6 +43,19 @@ pub(crate) fn check_categorical_src(l: &Series, r: &Series) -> Result<()> {
     Ok(())
 }
 
+/// Cast decimal join keys of a different precision or scale to their supertype, so that the
+/// joined key column can hold the values of both sides.
+fn coerce_join_keys(l: &Series, r: &Series) -> Result<(Series, Series)> {
+    #[cfg(feature = "dtype-decimal")]
+    if let (DataType::Decimal(_, _), DataType::Decimal(_, _)) = (l.dtype(), r.dtype()) {
+        if l.dtype() != r.dtype() {
+            let dtype = crate::utils::get_supertype(l.dtype(), r.dtype())?;
+            return Ok((l.cast_with_dtype(&dtype)?, r.cast_with_dtype(&dtype)?));
+        }
+    }
+    Ok((l.clone(), r.clone()))
+}
+
 macro_rules! det_hash_prone_order {
     ($self:expr, $other:expr) => {{
         // The shortest relation will be used to create a hash table.
@@ -58,10 +75,40 @@ macro_rules! det_hash_prone_order {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum JoinType {
     Left,
     Inner,
     Outer,
+    /// Join on the nearest key. Any join columns after the first one are used as `by` columns.
+    #[cfg(feature = "asof_join")]
+    AsOf(AsOfOptions),
+    /// The cartesian product of both DataFrames. No join columns are used.
+    #[cfg(feature = "cross_join")]
+    Cross,
+    /// Keep the rows of the left DataFrame that have a match in the right DataFrame.
+    #[cfg(feature = "semi_anti_join")]
+    Semi,
+    /// Keep the rows of the left DataFrame that don't have a match in the right DataFrame.
+    #[cfg(feature = "semi_anti_join")]
+    Anti,
+}
+
+/// What to do with the columns of the right DataFrame that have the same name as a column of the
+/// left DataFrame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DuplicateColumns {
+    /// Append a suffix to the names of the right columns.
+    Suffix(String),
+    /// Return an error.
+    Error,
+}
+
+impl Default for DuplicateColumns {
+    fn default() -> Self {
+        DuplicateColumns::Suffix("_right".to_string())
+    }
 }
 
 unsafe fn get_hash_tbl_threaded_join<T, H>(
@@ -171,6 +218,13 @@ where
     .collect()
 }
 
+/// If the left relation was used to build the hash table, the join tuples are ordered by the
+/// right relation. A stable sort on the left index restores the order of the left relation, while
+/// keeping multiple matches of a single left row contiguous and in right order.
+pub(crate) fn restore_left_order(join_tuples: &mut [(u32, u32)]) {
+    POOL.install(|| join_tuples.par_sort_by_key(|(left, _right)| *left))
+}
+
 fn hash_join_tuples_inner<T, IntoSlice>(
     probe: Vec<IntoSlice>,
     build: Vec<IntoSlice>,
@@ -198,7 +252,7 @@ where
         .collect::<Vec<_>>();
     // next we probe the other relation
     // code duplication is because we want to only do the swap check once
-    POOL.install(|| {
+    let mut join_tuples: Vec<(u32, u32)> = POOL.install(|| {
         probe
             .into_par_iter()
             .zip(offsets)
@@ -234,7 +288,11 @@ where
             })
             .flatten()
             .collect()
-    })
+    });
+    if swap {
+        restore_left_order(&mut join_tuples);
+    }
+    join_tuples
 }
 
 fn hash_join_tuples_left<T, IntoSlice>(
@@ -940,7 +998,12 @@ impl_zip_outer_join!(Utf8Chunked);
 
 impl DataFrame {
     /// Utility method to finish a join.
-    fn finish_join(&self, mut df_left: DataFrame, mut df_right: DataFrame) -> Result<DataFrame> {
+    pub(crate) fn finish_join(
+        &self,
+        mut df_left: DataFrame,
+        mut df_right: DataFrame,
+        duplicates: &DuplicateColumns,
+    ) -> Result<DataFrame> {
         let mut left_names = HashSet::with_capacity_and_hasher(df_left.width(), RandomState::new());
 
         df_left.columns.iter().for_each(|series| {
@@ -955,8 +1018,18 @@ impl DataFrame {
             }
         });
 
-        for name in rename_strs {
-            df_right.rename(&name, &format!("{}_right", name))?;
+        match duplicates {
+            DuplicateColumns::Suffix(suffix) => {
+                for name in rename_strs {
+                    df_right.rename(&name, &format!("{}{}", name, suffix))?;
+                }
+            }
+            DuplicateColumns::Error if !rename_strs.is_empty() => {
+                return Err(PolarsError::Duplicate(
+                    format!("columns {:?} exist in both DataFrames", rename_strs).into(),
+                ))
+            }
+            DuplicateColumns::Error => {}
         }
 
         df_left.hstack_mut(&df_right.columns)?;
@@ -981,6 +1054,33 @@ impl DataFrame {
         right_on: S2,
         how: JoinType,
     ) -> Result<DataFrame> {
+        self.join_with(other, left_on, right_on, how, &DuplicateColumns::default())
+    }
+
+    /// Generic join method where the naming of duplicate column names can be set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> Result<DataFrame> {
+    ///     let suffix = DuplicateColumns::Suffix("_other".into());
+    ///     left.join_with(right, "key", "key", JoinType::Left, &suffix)
+    /// }
+    /// ```
+    pub fn join_with<'a, J, S1: Selection<'a, J>, S2: Selection<'a, J>>(
+        &self,
+        other: &DataFrame,
+        left_on: S1,
+        right_on: S2,
+        how: JoinType,
+        duplicates: &DuplicateColumns,
+    ) -> Result<DataFrame> {
+        #[cfg(feature = "cross_join")]
+        if let JoinType::Cross = how {
+            return self.cross_join_from(other, duplicates);
+        }
+
         let selected_left = self.select_series(left_on)?;
         let selected_right = other.select_series(right_on)?;
         assert_eq!(selected_right.len(), selected_left.len());
@@ -988,18 +1088,62 @@ impl DataFrame {
         for (l, r) in selected_left.iter().zip(&selected_right) {
             check_categorical_src(l, r)?
         }
+        let (selected_left, selected_right): (Vec<_>, Vec<_>) = selected_left
+            .iter()
+            .zip(&selected_right)
+            .map(|(l, r)| coerce_join_keys(l, r))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        #[cfg(feature = "semi_anti_join")]
+        if matches!(how, JoinType::Semi | JoinType::Anti) {
+            return self.semi_anti_join_from_series(
+                &selected_left,
+                &selected_right,
+                how == JoinType::Anti,
+            );
+        }
+
+        #[cfg(feature = "asof_join")]
+        if let JoinType::AsOf(options) = how {
+            return self.join_asof_from_series(
+                other,
+                &selected_left[0],
+                &selected_right[0],
+                &selected_left[1..],
+                &selected_right[1..],
+                options,
+                duplicates,
+            );
+        }
 
         if selected_left.len() == 1 {
             return match how {
-                JoinType::Inner => {
-                    self.inner_join(other, selected_left[0].name(), selected_right[0].name())
-                }
-                JoinType::Left => {
-                    self.left_join(other, selected_left[0].name(), selected_right[0].name())
-                }
-                JoinType::Outer => {
-                    self.outer_join(other, selected_left[0].name(), selected_right[0].name())
-                }
+                JoinType::Inner => self.inner_join_from_series(
+                    other,
+                    &selected_left[0],
+                    &selected_right[0],
+                    duplicates,
+                ),
+                JoinType::Left => self.left_join_from_series(
+                    other,
+                    &selected_left[0],
+                    &selected_right[0],
+                    duplicates,
+                ),
+                JoinType::Outer => self.outer_join_from_series(
+                    other,
+                    &selected_left[0],
+                    &selected_right[0],
+                    duplicates,
+                ),
+                #[cfg(feature = "asof_join")]
+                JoinType::AsOf(_) => unreachable!(),
+                #[cfg(feature = "cross_join")]
+                JoinType::Cross => unreachable!(),
+                #[cfg(feature = "semi_anti_join")]
+                JoinType::Semi | JoinType::Anti => unreachable!(),
             };
         }
 
@@ -1038,7 +1182,7 @@ impl DataFrame {
                         )
                     },
                 );
-                self.finish_join(df_left, df_right)
+                self.finish_join(df_left, df_right, duplicates)
             }
             JoinType::Left => {
                 let left = DataFrame::new_no_checks(selected_left);
@@ -1056,7 +1200,7 @@ impl DataFrame {
                         )
                     },
                 );
-                self.finish_join(df_left, df_right)
+                self.finish_join(df_left, df_right, duplicates)
             }
             JoinType::Outer => {
                 let left = DataFrame::new_no_checks(selected_left.clone());
@@ -1087,13 +1231,23 @@ impl DataFrame {
                     s.rename(s_left.name());
                     df_left.hstack_mut(&[s])?;
                 }
-                self.finish_join(df_left, df_right)
+                self.finish_join(df_left, df_right, duplicates)
             }
+            #[cfg(feature = "asof_join")]
+            JoinType::AsOf(_) => unreachable!(),
+            #[cfg(feature = "cross_join")]
+            JoinType::Cross => unreachable!(),
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi | JoinType::Anti => unreachable!(),
         }
     }
 
     /// Perform an inner join on two DataFrames.
     ///
+    /// The row order of the left DataFrame is preserved. If a row matches multiple rows of the
+    /// right DataFrame, the duplicated left rows are contiguous and follow the order of the right
+    /// DataFrame.
+    ///
     /// # Example
     ///
     /// ```
@@ -1110,7 +1264,7 @@ impl DataFrame {
     ) -> Result<DataFrame> {
         let s_left = self.column(left_on)?;
         let s_right = other.column(right_on)?;
-        self.inner_join_from_series(other, s_left, s_right)
+        self.inner_join_from_series(other, s_left, s_right, &DuplicateColumns::default())
     }
 
     pub(crate) fn inner_join_from_series(
@@ -1118,6 +1272,7 @@ impl DataFrame {
         other: &DataFrame,
         s_left: &Series,
         s_right: &Series,
+        duplicates: &DuplicateColumns,
     ) -> Result<DataFrame> {
         check_categorical_src(s_left, s_right)?;
         let join_tuples = s_left.hash_join_inner(s_right);
@@ -1131,10 +1286,15 @@ impl DataFrame {
                     .take_iter_unchecked(join_tuples.iter().map(|(_left, right)| *right as usize))
             },
         );
-        self.finish_join(df_left, df_right)
+        self.finish_join(df_left, df_right, duplicates)
     }
 
     /// Perform a left join on two DataFrames
+    ///
+    /// The row order of the left DataFrame is preserved. If a row matches multiple rows of the
+    /// right DataFrame, the duplicated left rows are contiguous and follow the order of the right
+    /// DataFrame.
+    ///
     /// # Example
     ///
     /// ```
@@ -1146,7 +1306,7 @@ impl DataFrame {
     pub fn left_join(&self, other: &DataFrame, left_on: &str, right_on: &str) -> Result<DataFrame> {
         let s_left = self.column(left_on)?;
         let s_right = other.column(right_on)?;
-        self.left_join_from_series(other, s_left, s_right)
+        self.left_join_from_series(other, s_left, s_right, &DuplicateColumns::default())
     }
 
     pub(crate) fn left_join_from_series(
@@ -1154,6 +1314,7 @@ impl DataFrame {
         other: &DataFrame,
         s_left: &Series,
         s_right: &Series,
+        duplicates: &DuplicateColumns,
     ) -> Result<DataFrame> {
         check_categorical_src(s_left, s_right)?;
         let opt_join_tuples = s_left.hash_join_left(s_right);
@@ -1168,7 +1329,7 @@ impl DataFrame {
                 )
             },
         );
-        self.finish_join(df_left, df_right)
+        self.finish_join(df_left, df_right, duplicates)
     }
 
     /// Perform an outer join on two DataFrames
@@ -1188,15 +1349,18 @@ impl DataFrame {
     ) -> Result<DataFrame> {
         let s_left = self.column(left_on)?;
         let s_right = other.column(right_on)?;
-        self.outer_join_from_series(other, s_left, s_right)
+        self.outer_join_from_series(other, s_left, s_right, &DuplicateColumns::default())
     }
     pub(crate) fn outer_join_from_series(
         &self,
         other: &DataFrame,
         s_left: &Series,
         s_right: &Series,
+        duplicates: &DuplicateColumns,
     ) -> Result<DataFrame> {
         check_categorical_src(s_left, s_right)?;
+        let (s_left, s_right) = coerce_join_keys(s_left, s_right)?;
+        let (s_left, s_right) = (&s_left, &s_right);
         // Get the indexes of the joined relations
         let opt_join_tuples = s_left.hash_join_outer(s_right);
 
@@ -1220,7 +1384,98 @@ impl DataFrame {
         let mut s = s_left.zip_outer_join_column(s_right, &opt_join_tuples);
         s.rename(s_left.name());
         df_left.hstack_mut(&[s])?;
-        self.finish_join(df_left, df_right)
+        self.finish_join(df_left, df_right, duplicates)
+    }
+
+    /// Filter the DataFrame on the rows that have a match in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn known_customers(orders: &DataFrame, customers: &DataFrame) -> Result<DataFrame> {
+    ///     orders.semi_join(customers, "customer_id", "id")
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "semi_anti_join")))]
+    pub fn semi_join(&self, other: &DataFrame, left_on: &str, right_on: &str) -> Result<DataFrame> {
+        self.join(other, left_on, right_on, JoinType::Semi)
+    }
+
+    /// Filter the DataFrame on the rows that don't have a match in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn unknown_customers(orders: &DataFrame, customers: &DataFrame) -> Result<DataFrame> {
+    ///     orders.anti_join(customers, "customer_id", "id")
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "semi_anti_join")))]
+    pub fn anti_join(&self, other: &DataFrame, left_on: &str, right_on: &str) -> Result<DataFrame> {
+        self.join(other, left_on, right_on, JoinType::Anti)
+    }
+
+    #[cfg(feature = "semi_anti_join")]
+    fn semi_anti_join_from_series(
+        &self,
+        selected_left: &[Series],
+        selected_right: &[Series],
+        anti: bool,
+    ) -> Result<DataFrame> {
+        let join_tuples = if selected_left.len() == 1 {
+            selected_left[0].hash_join_left(&selected_right[0])
+        } else {
+            let left = DataFrame::new_no_checks(selected_left.to_vec());
+            let right = DataFrame::new_no_checks(selected_right.to_vec());
+            left_join_multiple_keys(&left, &right)
+        };
+
+        // only the existence of a match is needed; the right table is never taken
+        let mut has_match = vec![false; self.height()];
+        for (left, right) in join_tuples {
+            if right.is_some() {
+                has_match[left as usize] = true;
+            }
+        }
+        let mask: BooleanChunked = has_match.into_iter().map(|m| m != anti).collect();
+        self.filter(&mask)
+    }
+
+    /// Create the cartesian product of two DataFrames. Every row of the left DataFrame is
+    /// repeated for every row of the right DataFrame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn all_combinations(sizes: &DataFrame, colors: &DataFrame) -> Result<DataFrame> {
+    ///     sizes.cross_join(colors)
+    /// }
+    /// ```
+    #[cfg(feature = "cross_join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cross_join")))]
+    pub fn cross_join(&self, other: &DataFrame) -> Result<DataFrame> {
+        self.cross_join_from(other, &DuplicateColumns::default())
+    }
+
+    #[cfg(feature = "cross_join")]
+    fn cross_join_from(
+        &self,
+        other: &DataFrame,
+        duplicates: &DuplicateColumns,
+    ) -> Result<DataFrame> {
+        let n_rows_right = other.height();
+        let n_rows = self.height() * n_rows_right;
+
+        let (df_left, df_right) = POOL.join(
+            || unsafe { self.take_iter_unchecked((0..n_rows).map(|i| i / n_rows_right)) },
+            || unsafe { other.take_iter_unchecked((0..n_rows).map(|i| i % n_rows_right)) },
+        );
+        self.finish_join(df_left, df_right, duplicates)
     }
 }
 
@@ -1249,10 +1504,10 @@ mod test {
             std::env::set_var("POLARS_MAX_THREADS", format!("{}", i));
             let joined = temp.inner_join(&rain, "days", "days").unwrap();
 
-            let join_col_days = Series::new("days", &[1, 2, 1]);
-            let join_col_temp = Series::new("temp", &[19.9, 7., 19.9]);
-            let join_col_rain = Series::new("rain", &[0.1, 0.3, 0.1]);
-            let join_col_rain_right = Series::new("rain_right", [0.1, 0.2, 0.4].as_ref());
+            let join_col_days = Series::new("days", &[1, 1, 2]);
+            let join_col_temp = Series::new("temp", &[19.9, 19.9, 7.]);
+            let join_col_rain = Series::new("rain", &[0.1, 0.1, 0.3]);
+            let join_col_rain_right = Series::new("rain_right", [0.1, 0.4, 0.2].as_ref());
             let true_df = DataFrame::new(vec![
                 join_col_days,
                 join_col_temp,
@@ -1304,6 +1559,105 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_join_maintains_left_order() {
+        for i in 1..8 {
+            std::env::set_var("POLARS_MAX_THREADS", format!("{}", i));
+            let left = df![
+                "key" => [3, 1, 2, 1, 4],
+                "row" => [0, 1, 2, 3, 4]
+            ]
+            .unwrap();
+            // key 1 matches multiple rows of the right frame
+            let right = df![
+                "key" => [1, 2, 1, 3, 1, 5],
+                "val" => [10, 20, 11, 30, 12, 50]
+            ]
+            .unwrap();
+
+            let joined = left.left_join(&right, "key", "key").unwrap();
+            assert_eq!(
+                Vec::from(joined.column("row").unwrap().i32().unwrap()),
+                &[
+                    Some(0),
+                    Some(1),
+                    Some(1),
+                    Some(1),
+                    Some(2),
+                    Some(3),
+                    Some(3),
+                    Some(3),
+                    Some(4)
+                ]
+            );
+            assert_eq!(
+                Vec::from(joined.column("val").unwrap().i32().unwrap()),
+                &[
+                    Some(30),
+                    Some(10),
+                    Some(11),
+                    Some(12),
+                    Some(20),
+                    Some(10),
+                    Some(11),
+                    Some(12),
+                    None
+                ]
+            );
+
+            // the left frame is the shorter relation, so it is used to build the hash table.
+            let joined = left.inner_join(&right, "key", "key").unwrap();
+            assert_eq!(
+                Vec::from(joined.column("row").unwrap().i32().unwrap()),
+                &[
+                    Some(0),
+                    Some(1),
+                    Some(1),
+                    Some(1),
+                    Some(2),
+                    Some(3),
+                    Some(3),
+                    Some(3)
+                ]
+            );
+            assert_eq!(
+                Vec::from(joined.column("val").unwrap().i32().unwrap()),
+                &[
+                    Some(30),
+                    Some(10),
+                    Some(11),
+                    Some(12),
+                    Some(20),
+                    Some(10),
+                    Some(11),
+                    Some(12)
+                ]
+            );
+
+            // multiple keys
+            let mut left = left;
+            let mut right = right;
+            left.with_column(Series::new("key2", &[0; 5])).unwrap();
+            right.with_column(Series::new("key2", &[0; 6])).unwrap();
+            let joined = left
+                .join(&right, &["key", "key2"], &["key", "key2"], JoinType::Inner)
+                .unwrap();
+            assert_eq!(
+                Vec::from(joined.column("row").unwrap().i32().unwrap()),
+                &[
+                    Some(0),
+                    Some(1),
+                    Some(1),
+                    Some(1),
+                    Some(2),
+                    Some(3),
+                    Some(3),
+                    Some(3)
+                ]
+            );
+        }
+    }
+
     #[test]
     fn test_outer_join() {
         let (temp, rain) = create_frames();
@@ -1491,4 +1845,94 @@ mod test {
         right.inner_join(&left, "key", "key").unwrap();
         right.outer_join(&left, "key", "key").unwrap();
     }
+
+    #[test]
+    #[cfg(feature = "cross_join")]
+    fn test_cross_join() -> Result<()> {
+        let sizes = df!["size" => ["s", "m", "l"], "id" => [1, 2, 3]]?;
+        let colors = df!["color" => ["red", "blue"], "id" => [4, 5]]?;
+
+        let out = sizes.join(
+            &colors,
+            Vec::<&str>::new(),
+            Vec::<&str>::new(),
+            JoinType::Cross,
+        )?;
+        assert_eq!(out.shape(), (6, 4));
+        assert_eq!(out.get_column_names(), &["size", "id", "color", "id_right"]);
+        assert_eq!(
+            Vec::from(out.column("size")?.utf8()?),
+            &[
+                Some("s"),
+                Some("s"),
+                Some("m"),
+                Some("m"),
+                Some("l"),
+                Some("l")
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("id_right")?.i32()?),
+            &[Some(4), Some(5), Some(4), Some(5), Some(4), Some(5)]
+        );
+
+        let empty = colors.head(Some(0));
+        assert_eq!(sizes.cross_join(&empty)?.height(), 0);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "semi_anti_join")]
+    fn test_semi_anti_join() -> Result<()> {
+        let orders = df![
+            "customer_id" => [1, 2, 3, 2, 4],
+            "amount" => [10, 20, 30, 40, 50]
+        ]?;
+        let customers = df![
+            "id" => [2, 1, 2],
+            "amount" => [0, 0, 0]
+        ]?;
+
+        let out = orders.semi_join(&customers, "customer_id", "id")?;
+        assert_eq!(out.get_column_names(), &["customer_id", "amount"]);
+        assert_eq!(
+            Vec::from(out.column("amount")?.i32()?),
+            &[Some(10), Some(20), Some(40)]
+        );
+        let out = orders.anti_join(&customers, "customer_id", "id")?;
+        assert_eq!(
+            Vec::from(out.column("amount")?.i32()?),
+            &[Some(30), Some(50)]
+        );
+
+        // multiple keys
+        let out = orders.join(
+            &customers,
+            vec!["customer_id", "amount"],
+            vec!["id", "amount"],
+            JoinType::Semi,
+        )?;
+        assert_eq!(out.height(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_suffix() -> Result<()> {
+        let left = df![
+            "id" => [1, 2],
+            "value" => [1, 2]
+        ]?;
+        let right = df![
+            "id" => [2, 1],
+            "value" => [20, 10]
+        ]?;
+
+        let duplicates = DuplicateColumns::Suffix("_other".into());
+        let out = left.join_with(&right, "id", "id", JoinType::Inner, &duplicates)?;
+        assert_eq!(out.get_column_names(), &["id", "value", "value_other"]);
+
+        let out = left.join_with(&right, "id", "id", JoinType::Left, &DuplicateColumns::Error);
+        assert!(out.is_err());
+        Ok(())
+    }
 }
@@ -1,5 +1,7 @@
 use crate::frame::groupby::hashing::{populate_multiple_key_hashmap, HASHMAP_INIT_SIZE};
-use crate::frame::hash_join::{get_hash_tbl_threaded_join, get_hash_tbl_threaded_join_mut};
+use crate::frame::hash_join::{
+    get_hash_tbl_threaded_join, get_hash_tbl_threaded_join_mut, restore_left_order,
+};
 use crate::prelude::*;
 use crate::utils::split_df;
 use crate::vector_hasher::{df_rows_to_hashes_threaded, this_thread, IdBuildHasher, IdxHash};
@@ -141,7 +143,7 @@ pub(crate) fn inner_join_multiple_keys(
     let offsets = get_offsets(&probe_hashes);
     // next we probe the other relation
     // code duplication is because we want to only do the swap check once
-    POOL.install(|| {
+    let mut join_tuples: Vec<(u32, u32)> = POOL.install(|| {
         probe_hashes
             .into_par_iter()
             .zip(offsets)
@@ -180,7 +182,11 @@ pub(crate) fn inner_join_multiple_keys(
             })
             .flatten()
             .collect()
-    })
+    });
+    if swap {
+        restore_left_order(&mut join_tuples);
+    }
+    join_tuples
 }
 
 #[cfg(feature = "private")]
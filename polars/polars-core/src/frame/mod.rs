@@ -1,6 +1,7 @@
 //! DataFrame module.
 use std::borrow::Cow;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::hash::BuildHasher;
 use std::iter::Iterator;
 use std::mem;
 use std::sync::Arc;
@@ -16,19 +17,53 @@ use crate::prelude::*;
 use crate::utils::{
     accumulate_dataframes_horizontal, accumulate_dataframes_vertical, split_ca, split_df, NoNull,
 };
+use crate::vector_hasher::boost_hash_combine;
 
 mod arithmetic;
+#[cfg(feature = "asof_join")]
+#[cfg_attr(docsrs, doc(cfg(feature = "asof_join")))]
+pub mod asof_join;
+#[cfg(feature = "describe")]
+#[cfg_attr(docsrs, doc(cfg(feature = "describe")))]
+mod describe;
 pub mod explode;
 pub mod groupby;
 pub mod hash_join;
+#[cfg(feature = "partition_by")]
+#[cfg_attr(docsrs, doc(cfg(feature = "partition_by")))]
+mod partition;
 pub mod row;
 pub mod select;
+#[cfg(feature = "transpose")]
+#[cfg_attr(docsrs, doc(cfg(feature = "transpose")))]
+mod transpose;
+#[cfg(feature = "upsample")]
+#[cfg_attr(docsrs, doc(cfg(feature = "upsample")))]
+pub mod upsample;
 mod upstream_traits;
 use crate::prelude::sort::prepare_argsort;
 use crate::POOL;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Which row of a group of duplicates to keep in [drop_duplicates](DataFrame::drop_duplicates).
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UniqueKeepStrategy {
+    /// Keep the first occurrence.
+    First,
+    /// Keep the last occurrence.
+    Last,
+    /// Drop all rows that occur more than once.
+    None,
+}
+
+impl Default for UniqueKeepStrategy {
+    fn default() -> Self {
+        UniqueKeepStrategy::First
+    }
+}
+
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DataFrame {
@@ -163,6 +198,72 @@ impl DataFrame {
         Schema::new(fields)
     }
 
+    /// Select and cast the columns of this DataFrame, so that it matches the given schema. The
+    /// columns of the output are in the order of the schema.
+    ///
+    /// Every field of the schema must be present in the DataFrame. If `strict` is true, the
+    /// DataFrame may not have columns that are not in the schema and casts may not turn valid
+    /// values into nulls. Otherwise, the columns that are not in the schema are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let df = df! {
+    ///     "price" => ["1.5", "2.0"],
+    ///     "id" => [1, 2]
+    /// }?;
+    /// let schema = Schema::new(vec![
+    ///     Field::new("id", DataType::Int64),
+    ///     Field::new("price", DataType::Float64),
+    /// ]);
+    ///
+    /// let df = df.cast_to_schema(&schema, true)?;
+    /// assert_eq!(df.schema(), schema);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cast_to_schema(&self, schema: &Schema, strict: bool) -> Result<DataFrame> {
+        if strict {
+            if let Some(s) = self
+                .columns
+                .iter()
+                .find(|s| schema.index_of(s.name()).is_err())
+            {
+                return Err(PolarsError::SchemaMisMatch(
+                    format!("column {} is not in the schema", s.name()).into(),
+                ));
+            }
+        }
+
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let s = self.column(field.name())?;
+                if s.dtype() == field.data_type() {
+                    return Ok(s.clone());
+                }
+                let out = s.cast_with_dtype(field.data_type())?;
+                if strict && out.null_count() != s.null_count() {
+                    return Err(PolarsError::SchemaMisMatch(
+                        format!(
+                            "column {} could not be cast from {:?} to {:?} without losing values",
+                            field.name(),
+                            s.dtype(),
+                            field.data_type()
+                        )
+                        .into(),
+                    ));
+                }
+                Ok(out)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataFrame::new_no_checks(columns))
+    }
+
     /// Get a reference to the DataFrame columns.
     #[inline]
     pub fn get_columns(&self) -> &Vec<Series> {
@@ -335,8 +436,7 @@ impl DataFrame {
         Ok(df)
     }
 
-    /// Concatenate a DataFrame to this DataFrame
-    pub fn vstack_mut(&mut self, df: &DataFrame) -> Result<&mut Self> {
+    fn check_vstack(&self, df: &DataFrame) -> Result<()> {
         if self.width() != df.width() {
             return Err(PolarsError::ShapeMisMatch(
                 format!("Could not vertically stack DataFrame. The DataFrames appended width {} differs from the parent DataFrames width {}", self.width(), df.width()).into()
@@ -344,7 +444,7 @@ impl DataFrame {
         }
 
         self.columns
-            .iter_mut()
+            .iter()
             .zip(df.columns.iter())
             .try_for_each(|(left, right)| {
                 if left.dtype() != right.dtype() {
@@ -356,14 +456,54 @@ impl DataFrame {
                         .into(),
                     ));
                 }
+                Ok(())
+            })
+    }
 
+    /// Concatenate a DataFrame to this DataFrame
+    pub fn vstack_mut(&mut self, df: &DataFrame) -> Result<&mut Self> {
+        self.check_vstack(df)?;
+
+        self.columns
+            .iter_mut()
+            .zip(df.columns.iter())
+            .for_each(|(left, right)| {
                 left.append(right).expect("should not fail");
-                Ok(())
-            })?;
+            });
         // don't rechunk here. Chunks in columns always match.
         Ok(self)
     }
 
+    /// Extend the memory backed by this DataFrame with the values from `other`.
+    ///
+    /// Different from `vstack_mut`, which adds the chunks of `other` to the chunks of this
+    /// DataFrame, `extend` keeps every column in a single chunk (see [Series::extend]). This is
+    /// useful when DataFrames are appended one by one and queried in between, e.g. when reading
+    /// from a stream, as operations on a DataFrame with many chunks are slow.
+    ///
+    /// Prefer `vstack_mut` followed by a single `rechunk` if the DataFrames are combined at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn append_batch(df: &mut DataFrame, batch: &DataFrame) -> Result<()> {
+    ///     df.extend(batch)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn extend(&mut self, other: &DataFrame) -> Result<&mut Self> {
+        self.check_vstack(other)?;
+
+        self.columns
+            .iter_mut()
+            .zip(other.columns.iter())
+            .for_each(|(left, right)| {
+                left.extend(right).expect("should not fail");
+            });
+        Ok(self)
+    }
+
     /// Remove column by name
     ///
     /// # Example
@@ -381,13 +521,30 @@ impl DataFrame {
         result
     }
 
-    /// Return a new DataFrame where all null values are dropped
-    pub fn drop_nulls(&self, subset: Option<&[String]>) -> Result<Self> {
+    /// Return a new DataFrame where the rows with null values are dropped. If a `subset` of
+    /// column names is given, only the nulls in those columns are considered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    ///
+    /// fn example(df: &DataFrame) -> Result<DataFrame> {
+    ///     // drop the rows that have a null in column "a" or "b"
+    ///     df.drop_nulls(Some(&["a", "b"]))
+    /// }
+    ///
+    /// fn example_all_columns(df: &DataFrame) -> Result<DataFrame> {
+    ///     df.drop_nulls::<String>(None)
+    /// }
+    /// ```
+    pub fn drop_nulls<S: AsRef<str>>(&self, subset: Option<&[S]>) -> Result<Self> {
         let selected_series;
 
         let mut iter = match subset {
             Some(cols) => {
-                selected_series = self.select_series(&cols)?;
+                let cols = cols.iter().map(|s| s.as_ref()).collect::<Vec<_>>();
+                selected_series = self.select_series(cols)?;
                 selected_series.iter()
             }
             None => self.columns.iter(),
@@ -444,6 +601,50 @@ impl DataFrame {
         self.insert_at_idx_no_name_check(index, series)
     }
 
+    /// Add a `UInt32` column named `name` at index 0 that counts the rows, starting at `offset`
+    /// (default 0).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    ///
+    /// # fn main() -> Result<()> {
+    /// let df = df!("a" => ["x", "y"])?.with_row_count("row_nr", Some(10))?;
+    /// assert_eq!(Vec::from(df.column("row_nr")?.u32()?), &[Some(10), Some(11)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_row_count(&self, name: &str, offset: Option<u32>) -> Result<Self> {
+        let mut df = self.clone();
+        df.with_row_count_mut(name, offset)?;
+        Ok(df)
+    }
+
+    /// Add a `UInt32` column named `name` at index 0 that counts the rows, starting at `offset`
+    /// (default 0). Returns an error if the row count does not fit in a `u32`.
+    pub fn with_row_count_mut(&mut self, name: &str, offset: Option<u32>) -> Result<&mut Self> {
+        use std::convert::TryFrom;
+        let offset = offset.unwrap_or(0);
+        let end = u32::try_from(self.height())
+            .ok()
+            .and_then(|height| offset.checked_add(height))
+            .ok_or_else(|| {
+                PolarsError::OutOfBounds(
+                    format!(
+                        "a row count starting at {} overflows a u32 for {} rows",
+                        offset,
+                        self.height()
+                    )
+                    .into(),
+                )
+            })?;
+        let ca: NoNull<UInt32Chunked> = (offset..end).collect();
+        let mut ca = ca.into_inner();
+        ca.rename(name);
+        self.insert_at_idx(0, ca)
+    }
+
     /// Add a new column to this `DataFrame` or replace an existing one.
     pub fn with_column<S: IntoSeries>(&mut self, column: S) -> Result<&mut Self> {
         let series = column.into_series();
@@ -832,7 +1033,9 @@ impl DataFrame {
         })
     }
 
-    /// Rename a column in the DataFrame
+    /// Rename a column in the DataFrame. The position of the column is not changed.
+    ///
+    /// This errors if `column` does not exist or if another column is already named `name`.
     ///
     /// # Example
     ///
@@ -845,9 +1048,71 @@ impl DataFrame {
     /// }
     /// ```
     pub fn rename(&mut self, column: &str, name: &str) -> Result<&mut Self> {
-        self.select_mut(column)
-            .ok_or_else(|| PolarsError::NotFound(name.into()))
-            .map(|s| s.rename(name))?;
+        let idx = self.name_to_idx(column)?;
+        if column != name {
+            self.has_column(name)?;
+        }
+        self.columns[idx].rename(name);
+        Ok(self)
+    }
+
+    /// Rename multiple columns in the DataFrame with a mapping from existing to new names.
+    /// The position of the columns is not changed.
+    ///
+    /// The whole mapping is validated before any column is renamed, so on error the DataFrame
+    /// is left untouched. This errors if a column in the mapping does not exist or if the
+    /// renaming would lead to duplicate column names.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// fn example(df: &mut DataFrame) -> Result<&mut DataFrame> {
+    ///     let mut mapping = HashMap::new();
+    ///     mapping.insert("foo".to_string(), "bar".to_string());
+    ///     mapping.insert("bar".to_string(), "foo".to_string());
+    ///     df.rename_many(&mapping)
+    /// }
+    /// ```
+    pub fn rename_many<H: BuildHasher>(
+        &mut self,
+        mapping: &HashMap<String, String, H>,
+    ) -> Result<&mut Self> {
+        for column in mapping.keys() {
+            self.name_to_idx(column)?;
+        }
+        let new_names = self
+            .columns
+            .iter()
+            .map(|s| {
+                mapping
+                    .get(s.name())
+                    .map(|s| s.as_str())
+                    .unwrap_or(s.name())
+            })
+            .collect::<Vec<_>>();
+
+        let mut names = HashSet::with_capacity_and_hasher(new_names.len(), RandomState::default());
+        for name in &new_names {
+            if !names.insert(*name) {
+                return Err(PolarsError::Duplicate(
+                    format!("column with name: '{}' already present in DataFrame", name).into(),
+                ));
+            }
+        }
+        let new_names = new_names
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+
+        self.columns
+            .iter_mut()
+            .zip(new_names)
+            .for_each(|(s, name)| {
+                s.rename(&name);
+            });
         Ok(self)
     }
 
@@ -857,17 +1122,56 @@ impl DataFrame {
         Ok(self)
     }
 
-    /// This is the dispatch of Self::sort, and exists to reduce compile bloat by monomorphization.
-    fn sort_impl(&self, by_column: Vec<&str>, reverse: Vec<bool>) -> Result<Self> {
+    /// Compute the indexes that sort the DataFrame by `by_column`.
+    fn argsort_impl(
+        &self,
+        by_column: Vec<&str>,
+        reverse: Vec<bool>,
+        nulls_last: bool,
+    ) -> Result<UInt32Chunked> {
+        if reverse.is_empty() {
+            return Err(PolarsError::ValueError(
+                "at least one ordering boolean is needed to sort".into(),
+            ));
+        }
         let take = match by_column.len() {
             1 => {
                 let s = self.column(by_column[0])?;
-                s.argsort(reverse[0])
+                let take = s.argsort(reverse[0]);
+                let null_count = s.null_count();
+                // ascending sorts put the nulls first, so we move them to the end
+                if nulls_last && !reverse[0] && null_count > 0 {
+                    let mut out = take.slice(null_count as i64, take.len() - null_count);
+                    out.append(&take.slice(0, null_count));
+                    out
+                } else {
+                    take
+                }
             }
             _ => {
                 #[cfg(feature = "sort_multiple")]
                 {
-                    let columns = self.select_series(by_column)?;
+                    let mut columns = self.select_series(by_column)?;
+                    let mut reverse = reverse;
+                    if nulls_last {
+                        if reverse.len() == 1 {
+                            reverse = vec![reverse[0]; columns.len()];
+                        }
+                        // ascending columns put their nulls first, so we first sort those
+                        // columns by their validity.
+                        let mut sort_columns = Vec::with_capacity(columns.len());
+                        let mut sort_reverse = Vec::with_capacity(columns.len());
+                        for (s, rev) in columns.into_iter().zip(reverse) {
+                            if !rev && s.null_count() > 0 {
+                                sort_columns.push(s.is_null().into_series());
+                                sort_reverse.push(false);
+                            }
+                            sort_columns.push(s);
+                            sort_reverse.push(rev);
+                        }
+                        columns = sort_columns;
+                        reverse = sort_reverse;
+                    }
 
                     let (first, columns, reverse) = prepare_argsort(columns, reverse)?;
                     first.argsort_multiple(&columns, &reverse)?
@@ -878,12 +1182,50 @@ impl DataFrame {
                 }
             }
         };
+        Ok(take)
+    }
+
+    /// This is the dispatch of Self::sort, and exists to reduce compile bloat by monomorphization.
+    fn sort_impl(
+        &self,
+        by_column: Vec<&str>,
+        reverse: Vec<bool>,
+        nulls_last: bool,
+    ) -> Result<Self> {
+        let take = self.argsort_impl(by_column, reverse, nulls_last)?;
         if std::env::var("POLARS_VERT_PAR").is_ok() {
             return Ok(unsafe { self.take_unchecked_vectical(&take) });
         }
         Ok(self.take(&take))
     }
 
+    /// Get the indexes that would sort the DataFrame by `by_column`, without sorting the
+    /// DataFrame itself. The indexes can be used with [`DataFrame::take`] to apply the same
+    /// ordering to this or another DataFrame of the same height.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    ///
+    /// fn sort_like(df: &DataFrame, other: &DataFrame) -> Result<DataFrame> {
+    ///     let idx = df.argsort_by(&["a", "b"], vec![false, true])?;
+    ///     Ok(other.take(&idx))
+    /// }
+    /// ```
+    pub fn argsort_by<'a, S, J>(
+        &self,
+        by_column: S,
+        reverse: impl IntoVec<bool>,
+    ) -> Result<UInt32Chunked>
+    where
+        S: Selection<'a, J>,
+    {
+        let by_column = by_column.to_selection_vec();
+        let reverse = reverse.into_vec();
+        self.argsort_impl(by_column, reverse, false)
+    }
+
     /// Return a sorted clone of this DataFrame.
     ///
     /// # Example
@@ -900,13 +1242,38 @@ impl DataFrame {
     /// }
     /// ```
     pub fn sort<'a, S, J>(&self, by_column: S, reverse: impl IntoVec<bool>) -> Result<Self>
+    where
+        S: Selection<'a, J>,
+    {
+        self.sort_with_options(by_column, reverse, false)
+    }
+
+    /// Return a sorted clone of this DataFrame. Every column in `by_column` gets its own
+    /// `reverse` flag. If `nulls_last` is set, null values are placed at the end regardless
+    /// of the sort direction. Otherwise they come first in ascending and last in descending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    ///
+    /// fn sort_example(df: &DataFrame) -> Result<DataFrame> {
+    ///     df.sort_with_options(&["a", "b"], vec![true, false], true)
+    /// }
+    /// ```
+    pub fn sort_with_options<'a, S, J>(
+        &self,
+        by_column: S,
+        reverse: impl IntoVec<bool>,
+        nulls_last: bool,
+    ) -> Result<Self>
     where
         S: Selection<'a, J>,
     {
         // we do this heap allocation and dispatch to reduce monomorphization bloat
         let by_column = by_column.to_selection_vec();
         let reverse = reverse.into_vec();
-        self.sort_impl(by_column, reverse)
+        self.sort_impl(by_column, reverse, nulls_last)
     }
 
     /// Replace a column with a series.
@@ -1332,11 +1699,11 @@ impl DataFrame {
     }
 
     /// Aggregate the columns to their quantile values.
-    pub fn quantile(&self, quantile: f64) -> Result<Self> {
+    pub fn quantile(&self, quantile: f64, interpol: QuantileInterpolOptions) -> Result<Self> {
         let columns = self
             .columns
             .par_iter()
-            .map(|s| s.quantile_as_series(quantile))
+            .map(|s| s.quantile_as_series(quantile, interpol))
             .collect::<Result<Vec<_>>>()?;
         Ok(DataFrame::new_no_checks(columns))
     }
@@ -1410,6 +1777,34 @@ impl DataFrame {
         }
     }
 
+    /// Hash every row of the DataFrame to a `u64`, by combining the [hashes](Series::hash) of
+    /// the values in the row.
+    ///
+    /// Like the hashes of the values, the row hashes are the same on every platform and in
+    /// every run for a given `seed`.
+    pub fn hash_rows(&self, seed: u64) -> Result<UInt64Chunked> {
+        let mut iter = self.columns.iter().map(|s| s.hash(seed));
+        let first = match iter.next() {
+            Some(hashes) => hashes?,
+            None => {
+                return Err(PolarsError::NoData(
+                    "cannot hash the rows of an empty DataFrame".into(),
+                ))
+            }
+        };
+        let mut ca = iter.try_fold(first, |acc, hashes| {
+            let hashes = hashes?;
+            let ca: NoNull<UInt64Chunked> = acc
+                .into_no_null_iter()
+                .zip(hashes.into_no_null_iter())
+                .map(|(l, r)| boost_hash_combine(l, r))
+                .collect();
+            Ok(ca.into_inner())
+        })?;
+        ca.rename("hash");
+        Ok(ca)
+    }
+
     /// Aggregate the column horizontally to their mean values
     pub fn hmean(&self) -> Result<Option<Series>> {
         match self.columns.len() {
@@ -1533,6 +1928,9 @@ impl DataFrame {
     /// Drop duplicate rows from a DataFrame.
     /// *This fails when there is a column of type List in DataFrame*
     ///
+    /// If a `subset` of column names is given, only those columns are used to determine if rows
+    /// are duplicates. `keep` determines which row of the duplicates is kept.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -1547,7 +1945,7 @@ impl DataFrame {
     ///                    "int" => [1, 1, 2, 2, 3, 3, ],
     ///                    "str" => ["a", "a", "b", "b", "c", "c"]
     ///                }?;
-    ///      df.drop_duplicates(true, None)
+    ///      df.drop_duplicates(true, None, UniqueKeepStrategy::First)
     ///  }
     /// # }
     /// ```
@@ -1566,37 +1964,86 @@ impl DataFrame {
     /// | 3   | 3   | "c" |
     /// +-----+-----+-----+
     /// ```
-    pub fn drop_duplicates(&self, maintain_order: bool, subset: Option<&[String]>) -> Result<Self> {
+    pub fn drop_duplicates(
+        &self,
+        maintain_order: bool,
+        subset: Option<&[String]>,
+        keep: UniqueKeepStrategy,
+    ) -> Result<Self> {
         let names = match &subset {
             Some(s) => s.iter().map(|s| &**s).collect(),
             None => self.get_column_names(),
         };
         let gb = self.groupby(names)?;
-        let groups = gb.get_groups().iter().map(|v| v.0);
-
-        let df = if maintain_order {
-            let mut groups = groups.collect::<Vec<_>>();
-            groups.sort_unstable();
-            unsafe { self.take_iter_unchecked(groups.into_iter().map(|i| i as usize)) }
-        } else {
-            unsafe { self.take_iter_unchecked(groups.into_iter().map(|i| i as usize)) }
+        let groups = gb.get_groups().iter();
+        let mut idx: Vec<u32> = match keep {
+            UniqueKeepStrategy::First => groups.map(|(first, _)| *first).collect(),
+            UniqueKeepStrategy::Last => groups.map(|(_, g)| *g.iter().max().unwrap()).collect(),
+            UniqueKeepStrategy::None => groups
+                .filter(|(_, g)| g.len() == 1)
+                .map(|(first, _)| *first)
+                .collect(),
         };
+        if maintain_order {
+            idx.sort_unstable();
+        }
+        let df = unsafe { self.take_iter_unchecked(idx.into_iter().map(|i| i as usize)) };
 
         Ok(df)
     }
 
-    /// Get a mask of all the unique rows in the DataFrame.
-    pub fn is_unique(&self) -> Result<BooleanChunked> {
-        let mut gb = self.groupby(self.get_column_names())?;
+    fn is_unique_impl(
+        &self,
+        subset: Option<&[String]>,
+        unique_val: bool,
+        duplicated_val: bool,
+    ) -> Result<BooleanChunked> {
+        let names = match &subset {
+            Some(s) => s.iter().map(|s| &**s).collect(),
+            None => self.get_column_names(),
+        };
+        let mut gb = self.groupby(names)?;
         let groups = std::mem::take(&mut gb.groups);
-        Ok(is_unique_helper(groups, self.height() as u32, true, false))
+        Ok(is_unique_helper(
+            groups,
+            self.height() as u32,
+            unique_val,
+            duplicated_val,
+        ))
+    }
+
+    /// Get a mask of all the unique rows in the DataFrame.
+    /// If a `subset` of column names is given, only those columns are used to compare the rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// // keep the rows of which the key occurs only once
+    /// fn example(df: &DataFrame) -> Result<DataFrame> {
+    ///     let mask = df.is_unique(Some(&["key".to_string()]))?;
+    ///     df.filter(&mask)
+    /// }
+    /// ```
+    pub fn is_unique(&self, subset: Option<&[String]>) -> Result<BooleanChunked> {
+        self.is_unique_impl(subset, true, false)
     }
 
     /// Get a mask of all the duplicated rows in the DataFrame.
-    pub fn is_duplicated(&self) -> Result<BooleanChunked> {
-        let mut gb = self.groupby(self.get_column_names())?;
-        let groups = std::mem::take(&mut gb.groups);
-        Ok(is_unique_helper(groups, self.height() as u32, false, true))
+    /// If a `subset` of column names is given, only those columns are used to compare the rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// // keep all rows that appear more than once
+    /// fn example(df: &DataFrame) -> Result<DataFrame> {
+    ///     let mask = df.is_duplicated(None)?;
+    ///     df.filter(&mask)
+    /// }
+    /// ```
+    pub fn is_duplicated(&self, subset: Option<&[String]>) -> Result<BooleanChunked> {
+        self.is_unique_impl(subset, false, true)
     }
 
     /// Create a new DataFrame that shows the null counts per column.
@@ -1697,6 +2144,7 @@ impl std::convert::TryFrom<Vec<RecordBatch>> for DataFrame {
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashMap;
     use std::convert::TryFrom;
 
     use arrow::array::{Float64Array, Int64Array};
@@ -1818,6 +2266,133 @@ mod test {
         println!("{:?}", df);
     }
 
+    #[test]
+    #[cfg(feature = "sort_multiple")]
+    fn test_argsort_by() -> Result<()> {
+        let df = df![
+            "a" => [1, 2, 1, 2],
+            "b" => [1, 2, 3, 4]
+        ]?;
+        let idx = df.argsort_by(&["a", "b"], vec![false, true])?;
+        assert_eq!(Vec::from(&idx), &[Some(2), Some(0), Some(3), Some(1)]);
+        assert!(df
+            .take(&idx)
+            .frame_equal(&df.sort(&["a", "b"], vec![false, true])?));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "sort_multiple")]
+    fn test_sort_nulls_last() -> Result<()> {
+        let df = df![
+            "a" => [Some(1), None, Some(2), Some(1)],
+            "b" => [Some(1), Some(2), Some(3), None]
+        ]?;
+
+        let out = df.sort_with_options("a", false, true)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(1), Some(1), Some(2), None]
+        );
+
+        let out = df.sort_with_options(&["a", "b"], vec![true, false], true)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(2), Some(1), Some(1), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(3), Some(1), None, Some(2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_extend() -> Result<()> {
+        let mut df = df! {
+            "a" => [1, 2],
+            "b" => ["a", "b"]
+        }?;
+        let batch = df.clone();
+        for _ in 0..3 {
+            df.extend(&batch)?;
+        }
+        assert_eq!(df.height(), 8);
+        assert_eq!(df.n_chunks()?, 1);
+        assert!(df.extend(&df!("a" => [1.0], "b" => ["c"])?).is_err());
+
+        // many small batches stay in a single chunk
+        df.extend(&df!("a" => [3], "b" => ["c"])?)?;
+        assert_eq!(df.n_chunks()?, 1);
+        assert_eq!(df.column("a")?.i32()?.get(8), Some(3));
+        for _ in 0..1000 {
+            df.extend(&batch)?;
+        }
+        assert_eq!(df.height(), 2009);
+        assert_eq!(df.n_chunks()?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_to_schema() -> Result<()> {
+        let df = df! {
+            "b" => ["1", "x"],
+            "a" => [1, 2],
+            "c" => [true, false]
+        }?;
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Float64),
+            Field::new("b", DataType::Int32),
+        ]);
+
+        let out = df.cast_to_schema(&schema, false)?;
+        assert_eq!(out.schema(), schema);
+        assert_eq!(Vec::from(out.column("b")?.i32()?), &[Some(1), None]);
+
+        // the extra column and the lossy cast are rejected
+        assert!(df.cast_to_schema(&schema, true).is_err());
+        assert!(df.drop("c")?.cast_to_schema(&schema, true).is_err());
+        let schema = Schema::new(vec![Field::new("a", DataType::Float64)]);
+        assert!(df.select("a")?.cast_to_schema(&schema, true).is_ok());
+        assert!(df
+            .cast_to_schema(&Schema::new(vec![Field::new("d", DataType::Int32)]), false)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_row_count() -> Result<()> {
+        let df = create_frame().with_row_count("row_nr", None)?;
+        assert_eq!(df.get_column_names()[0], "row_nr");
+        assert_eq!(
+            Vec::from(df.column("row_nr")?.u32()?),
+            &[Some(0), Some(1), Some(2)]
+        );
+        assert!(df.with_row_count("row_nr", None).is_err());
+        // the last row count is u32::MAX - 1
+        let df = create_frame().with_row_count("row_nr", Some(u32::MAX - 3))?;
+        assert_eq!(df.column("row_nr")?.u32()?.get(2), Some(u32::MAX - 1));
+        assert!(create_frame()
+            .with_row_count("row_nr", Some(u32::MAX - 1))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_drop_nulls_subset() -> Result<()> {
+        let df = df![
+            "a" => [Some(1), None, Some(3), Some(4)],
+            "b" => [Some("x"), Some("y"), None, Some("z")],
+            "c" => [None, Some(1.0), Some(2.0), Some(3.0)]
+        ]?;
+        let out = df.drop_nulls(Some(&["a", "b"]))?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(4)]);
+
+        let out = df.drop_nulls::<String>(None)?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(4)]);
+        Ok(())
+    }
+
     #[test]
     fn slice() {
         let df = create_frame();
@@ -1865,6 +2440,39 @@ mod test {
         assert!(df.column("bar").is_ok())
     }
 
+    #[test]
+    fn test_rename() {
+        let mut df = df! {
+            "foo" => &[1, 2, 3],
+            "bar" => &[1, 2, 3],
+            "ham" => &[1, 2, 3]
+        }
+        .unwrap();
+        assert!(df.rename("foo", "bar").is_err());
+        assert!(df.rename("spam", "eggs").is_err());
+        df.rename("foo", "foo").unwrap();
+        df.rename("bar", "spam").unwrap();
+        assert_eq!(df.get_column_names(), &["foo", "spam", "ham"]);
+
+        // swapping names is allowed
+        let mut mapping = HashMap::new();
+        mapping.insert("foo".to_string(), "spam".to_string());
+        mapping.insert("spam".to_string(), "foo".to_string());
+        df.rename_many(&mapping).unwrap();
+        assert_eq!(df.get_column_names(), &["spam", "foo", "ham"]);
+
+        // a collision does not lead to a partial rename
+        let mut mapping = HashMap::new();
+        mapping.insert("spam".to_string(), "eggs".to_string());
+        mapping.insert("foo".to_string(), "ham".to_string());
+        assert!(df.rename_many(&mapping).is_err());
+        assert_eq!(df.get_column_names(), &["spam", "foo", "ham"]);
+
+        let mut mapping = HashMap::new();
+        mapping.insert("eggs".to_string(), "bacon".to_string());
+        assert!(df.rename_many(&mapping).is_err());
+    }
+
     #[test]
     fn drop_duplicates() {
         let df = df! {
@@ -1875,7 +2483,7 @@ mod test {
         .unwrap();
         dbg!(&df);
         let df = df
-            .drop_duplicates(true, None)
+            .drop_duplicates(true, None, UniqueKeepStrategy::First)
             .unwrap()
             .sort("flt", false)
             .unwrap();
@@ -1889,6 +2497,52 @@ mod test {
         assert!(df.frame_equal(&valid));
     }
 
+    #[test]
+    fn test_drop_duplicates_keep() -> Result<()> {
+        let df = df! {
+            "key" => [1, 2, 1, 3, 1],
+            "val" => [1, 2, 3, 4, 5]
+        }?;
+        let subset = ["key".to_string()];
+        let out = df.drop_duplicates(true, Some(&subset), UniqueKeepStrategy::First)?;
+        assert_eq!(
+            Vec::from(out.column("val")?.i32()?),
+            &[Some(1), Some(2), Some(4)]
+        );
+        let out = df.drop_duplicates(true, Some(&subset), UniqueKeepStrategy::Last)?;
+        assert_eq!(
+            Vec::from(out.column("val")?.i32()?),
+            &[Some(2), Some(4), Some(5)]
+        );
+        let out = df.drop_duplicates(true, Some(&subset), UniqueKeepStrategy::None)?;
+        assert_eq!(Vec::from(out.column("val")?.i32()?), &[Some(2), Some(4)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_unique_is_duplicated() -> Result<()> {
+        let df = df! {
+            "key" => [1, 2, 1, 3],
+            "val" => ["a", "b", "a", "c"],
+            "other" => [1, 2, 3, 4]
+        }?;
+        assert_eq!(
+            Vec::from(&df.is_duplicated(None)?),
+            &[Some(false), Some(false), Some(false), Some(false)]
+        );
+        let subset = ["key".to_string(), "val".to_string()];
+        assert_eq!(
+            Vec::from(&df.is_duplicated(Some(&subset))?),
+            &[Some(true), Some(false), Some(true), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(&df.is_unique(Some(&subset))?),
+            &[Some(false), Some(true), Some(false), Some(true)]
+        );
+        assert!(df.is_unique(Some(&["foo".to_string()])).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_vstack() {
         // check that it does not accidentally rechunks
@@ -1927,4 +2581,59 @@ mod test {
             &[Some(4), Some(2), Some(6)]
         );
     }
+
+    #[test]
+    fn test_hash_rows() -> Result<()> {
+        let df = df!("a" => &[1, 2, 1],
+                     "b" => &[Some("x"), None, Some("x")]
+        )?;
+        let hashes = df.hash_rows(0)?;
+        assert_eq!(
+            Vec::from(&hashes),
+            &[
+                Some(6435990380648176825),
+                Some(12083528762186352680),
+                Some(6435990380648176825)
+            ]
+        );
+
+        // the hashes are fixed, whatever the platform
+        assert_eq!(
+            Vec::from(&df.column("a")?.hash(0)?),
+            &[
+                Some(5764286377952901305),
+                Some(15937264073637691849),
+                Some(5764286377952901305)
+            ]
+        );
+        let s = df.column("b")?;
+        assert_eq!(
+            Vec::from(&s.hash(0)?),
+            &[
+                Some(1324260516288016390),
+                Some(2737183428366584608),
+                Some(1324260516288016390)
+            ]
+        );
+        let hashes = s.hash(42)?;
+        assert_eq!(hashes.name(), "b");
+        assert_eq!(
+            Vec::from(&hashes),
+            &[
+                Some(6990644295923851785),
+                Some(8426676591153850246),
+                Some(6990644295923851785)
+            ]
+        );
+        let s = Series::new("c", &[1.5, 0.0, -0.0]);
+        assert_eq!(
+            Vec::from(&s.hash(0)?),
+            &[
+                Some(3275210157283113109),
+                Some(3157854246557864315),
+                Some(3157854246557864315)
+            ]
+        );
+        Ok(())
+    }
 }
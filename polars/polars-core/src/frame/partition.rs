@@ -0,0 +1,82 @@
+//! Split a DataFrame into one DataFrame per group.
+use crate::frame::select::Selection;
+use crate::prelude::*;
+use crate::POOL;
+use rayon::prelude::*;
+
+impl DataFrame {
+    /// Split the `DataFrame` into multiple `DataFrame`s, one for every unique combination of
+    /// the values in the `by` columns.
+    ///
+    /// If `maintain_order` is true, the partitions are returned in the order in which their keys
+    /// first occur and the rows within every partition keep their original order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate polars_core;
+    /// # fn main() {
+    /// use polars_core::prelude::*;
+    /// let df = df!("key" => &["a", "b", "a"],
+    ///              "value" => &[1, 2, 3]
+    ///     )
+    /// .unwrap();
+    ///
+    /// let partitions = df.partition_by(&["key"], true).unwrap();
+    /// assert_eq!(partitions.len(), 2);
+    /// assert_eq!(partitions[0].height(), 2);
+    /// # }
+    /// ```
+    pub fn partition_by<'a, J, S: Selection<'a, J>>(
+        &self,
+        by: S,
+        maintain_order: bool,
+    ) -> Result<Vec<DataFrame>> {
+        let mut gb = if maintain_order {
+            self.groupby_stable(by)?
+        } else {
+            self.groupby(by)?
+        };
+        let groups = std::mem::take(&mut gb.groups);
+
+        Ok(POOL.install(|| {
+            groups
+                .into_par_iter()
+                .map(|(_first, idx)| {
+                    // Safety:
+                    // the group indices are in bounds
+                    unsafe { self.take_iter_unchecked(idx.into_iter().map(|i| i as usize)) }
+                })
+                .collect()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_partition_by() -> Result<()> {
+        let df = df!("key" => &["b", "a", "b", "c", "a"],
+                     "value" => &[1, 2, 3, 4, 5]
+        )?;
+
+        let partitions = df.partition_by(&["key"], true)?;
+        assert_eq!(partitions.len(), 3);
+        assert_eq!(
+            Vec::from(partitions[0].column("value")?.i32()?),
+            &[Some(1), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(partitions[1].column("value")?.i32()?),
+            &[Some(2), Some(5)]
+        );
+        assert_eq!(Vec::from(partitions[2].column("value")?.i32()?), &[Some(4)]);
+
+        let partitions = df.partition_by(&["key"], false)?;
+        assert_eq!(partitions.len(), 3);
+        assert_eq!(partitions.iter().map(|df| df.height()).sum::<usize>(), 5);
+        Ok(())
+    }
+}
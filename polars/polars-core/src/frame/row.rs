@@ -1,5 +1,7 @@
 use crate::prelude::*;
+use crate::utils::get_supertype;
 use itertools::Itertools;
+use num::NumCast;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Row<'a>(pub Vec<AnyValue<'a>>);
@@ -38,4 +40,431 @@ impl DataFrame {
                 *any_val = s.get_unchecked(idx);
             });
     }
+
+    /// Iterate over the rows of the DataFrame. Use of this is discouraged as it will likely be
+    /// slow.
+    pub fn iter_rows(&self) -> impl Iterator<Item = Row> + '_ {
+        (0..self.height()).map(move |idx| self.get_row(idx))
+    }
+
+    /// Create a DataFrame from rows with the given schema. The values are converted to the data
+    /// types of the schema.
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_core::frame::row::Row;
+    ///
+    /// let rows = vec![
+    ///     Row(vec![AnyValue::Utf8("a"), AnyValue::Int32(1)]),
+    ///     Row(vec![AnyValue::Null, AnyValue::Int64(2)]),
+    /// ];
+    /// let schema = Schema::new(vec![
+    ///     Field::new("key", DataType::Utf8),
+    ///     Field::new("value", DataType::Float64),
+    /// ]);
+    /// let df = DataFrame::from_rows_and_schema(&rows, &schema).unwrap();
+    /// assert_eq!(df.schema(), schema);
+    /// ```
+    pub fn from_rows_and_schema(rows: &[Row], schema: &Schema) -> Result<Self> {
+        let width = schema.len();
+        if let Some(row) = rows.iter().find(|row| row.0.len() != width) {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "a row has {} values, but the schema has {} fields",
+                    row.0.len(),
+                    width
+                )
+                .into(),
+            ));
+        }
+        let columns = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| {
+                let values = rows.iter().map(|row| &row.0[idx]).collect_vec();
+                series_from_any_values(field.name(), &values, field.data_type())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+
+    /// Create a DataFrame from rows. The data type of a column is the supertype of its values,
+    /// columns with only null values get data type `Utf8`. The columns are named `column_0`,
+    /// `column_1`, etc.
+    pub fn from_rows(rows: &[Row]) -> Result<Self> {
+        let schema = infer_schema(rows)?;
+        Self::from_rows_and_schema(rows, &schema)
+    }
+}
+
+/// The data type of a value, `None` for a null value.
+fn any_value_dtype(value: &AnyValue) -> Result<Option<DataType>> {
+    use AnyValue::*;
+    let dtype = match value {
+        Null => return Ok(None),
+        Boolean(_) => DataType::Boolean,
+        Utf8(_) => DataType::Utf8,
+        UInt8(_) => DataType::UInt8,
+        UInt16(_) => DataType::UInt16,
+        UInt32(_) => DataType::UInt32,
+        UInt64(_) => DataType::UInt64,
+        Int8(_) => DataType::Int8,
+        Int16(_) => DataType::Int16,
+        Int32(_) => DataType::Int32,
+        Int64(_) => DataType::Int64,
+        Float32(_) => DataType::Float32,
+        Float64(_) => DataType::Float64,
+        Date32(_) => DataType::Date32,
+        Date64(_) => DataType::Date64,
+        Time64(_, tu) => DataType::Time64(*tu),
+        Duration(_, tu) => DataType::Duration(*tu),
+        List(s) => DataType::List(s.dtype().to_arrow()),
+        #[allow(unreachable_patterns)]
+        value => {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot create a column from the value {:?}", value).into(),
+            ))
+        }
+    };
+    Ok(Some(dtype))
+}
+
+fn infer_schema(rows: &[Row]) -> Result<Schema> {
+    let width = rows.first().map_or(0, |row| row.0.len());
+    let mut dtypes: Vec<Option<DataType>> = vec![None; width];
+    for row in rows {
+        if row.0.len() != width {
+            return Err(PolarsError::ShapeMisMatch(
+                "all rows should have the same number of values".into(),
+            ));
+        }
+        for (dtype, value) in dtypes.iter_mut().zip(&row.0) {
+            if let Some(value_dtype) = any_value_dtype(value)? {
+                *dtype = Some(match dtype.take() {
+                    Some(dtype) => get_supertype(&dtype, &value_dtype)?,
+                    None => value_dtype,
+                });
+            }
+        }
+    }
+    let fields = dtypes
+        .into_iter()
+        .enumerate()
+        .map(|(idx, dtype)| Field::new(&format!("column_{}", idx), dtype.unwrap_or(DataType::Utf8)))
+        .collect();
+    Ok(Schema::new(fields))
+}
+
+fn conversion_error(value: &AnyValue, dtype: &DataType) -> PolarsError {
+    PolarsError::ValueError(format!("cannot convert {:?} to {:?}", value, dtype).into())
+}
+
+/// Number of nanoseconds in a unit of `tu`.
+fn nanoseconds(tu: &TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    }
+}
+
+/// Convert `v` from a `from` time unit to a `to` time unit. Returns `None` if the value cannot
+/// be represented exactly.
+fn convert_time_unit(v: i64, from: &TimeUnit, to: &TimeUnit) -> Option<i64> {
+    let (from, to) = (nanoseconds(from), nanoseconds(to));
+    if from >= to {
+        v.checked_mul(from / to)
+    } else if v % (to / from) == 0 {
+        Some(v / (to / from))
+    } else {
+        None
+    }
+}
+
+/// Extract a numeric value, also from the physical value of a temporal type. Returns an error
+/// if the value cannot be represented exactly in `dtype`, e.g. a float with a fractional part in
+/// an integer column.
+fn extract_num<T: NumCast>(value: &AnyValue, dtype: &DataType) -> Result<Option<T>> {
+    use AnyValue::*;
+    let is_float = matches!(dtype, DataType::Float32 | DataType::Float64);
+    let out: Option<T> = match (value, dtype) {
+        (Null, _) => return Ok(None),
+        // days to milliseconds and back
+        (Date32(v), DataType::Date64) => NumCast::from(*v as i64 * 86_400_000),
+        (Date64(v), DataType::Date32) if v % 86_400_000 == 0 => NumCast::from(*v / 86_400_000),
+        (Date64(_), DataType::Date32) => None,
+        (Time64(v, from), DataType::Time64(to)) | (Duration(v, from), DataType::Duration(to)) => {
+            convert_time_unit(*v, from, to).and_then(NumCast::from)
+        }
+        (Float32(v), _) if !is_float && v.fract() != 0.0 => None,
+        (Float64(v), _) if !is_float && v.fract() != 0.0 => None,
+        (Boolean(v), _) => NumCast::from(*v as u8),
+        (UInt8(v), _) => NumCast::from(*v),
+        (UInt16(v), _) => NumCast::from(*v),
+        (UInt32(v), _) => NumCast::from(*v),
+        (UInt64(v), _) => NumCast::from(*v),
+        (Int8(v), _) => NumCast::from(*v),
+        (Int16(v), _) => NumCast::from(*v),
+        (Int32(v), _) | (Date32(v), _) => NumCast::from(*v),
+        (Int64(v), _) | (Date64(v), _) | (Time64(v, _), _) | (Duration(v, _), _) => {
+            NumCast::from(*v)
+        }
+        (Float32(v), _) => NumCast::from(*v),
+        (Float64(v), _) => NumCast::from(*v),
+        _ => None,
+    };
+    out.map(Some).ok_or_else(|| conversion_error(value, dtype))
+}
+
+/// Create a Series of `dtype` from values.
+fn series_from_any_values(name: &str, values: &[&AnyValue], dtype: &DataType) -> Result<Series> {
+    macro_rules! numeric {
+        ($type:ty) => {{
+            let ca: ChunkedArray<$type> = values
+                .iter()
+                .map(|value| extract_num(value, dtype))
+                .collect::<Result<_>>()?;
+            ca.into_series()
+        }};
+    }
+
+    let mut s = match dtype {
+        DataType::Boolean => values
+            .iter()
+            .map(|value| match value {
+                AnyValue::Null => Ok(None),
+                AnyValue::Boolean(v) => Ok(Some(*v)),
+                value => Err(conversion_error(value, dtype)),
+            })
+            .collect::<Result<BooleanChunked>>()?
+            .into_series(),
+        DataType::Utf8 | DataType::Categorical => values
+            .iter()
+            .map(|value| match value {
+                AnyValue::Null => Ok(None),
+                AnyValue::Utf8(v) => Ok(Some(*v)),
+                value => Err(conversion_error(value, dtype)),
+            })
+            .collect::<Result<Utf8Chunked>>()?
+            .into_series()
+            .cast_with_dtype(dtype)?,
+        DataType::List(inner) => {
+            let inner = DataType::from(inner);
+            values
+                .iter()
+                .map(|value| match value {
+                    AnyValue::Null => Ok(None),
+                    AnyValue::List(s) => s.cast_with_dtype(&inner).map(Some),
+                    value => Err(conversion_error(value, dtype)),
+                })
+                .collect::<Result<ListChunked>>()?
+                .into_series()
+        }
+        #[cfg(feature = "dtype-u8")]
+        DataType::UInt8 => numeric!(UInt8Type),
+        #[cfg(feature = "dtype-u16")]
+        DataType::UInt16 => numeric!(UInt16Type),
+        DataType::UInt32 => numeric!(UInt32Type),
+        #[cfg(feature = "dtype-u64")]
+        DataType::UInt64 => numeric!(UInt64Type),
+        #[cfg(feature = "dtype-i8")]
+        DataType::Int8 => numeric!(Int8Type),
+        #[cfg(feature = "dtype-i16")]
+        DataType::Int16 => numeric!(Int16Type),
+        DataType::Int32 => numeric!(Int32Type),
+        DataType::Int64 => numeric!(Int64Type),
+        DataType::Float32 => numeric!(Float32Type),
+        DataType::Float64 => numeric!(Float64Type),
+        DataType::Date32 => numeric!(Int32Type).cast_with_dtype(dtype)?,
+        DataType::Date64 | DataType::Time64(_) | DataType::Duration(_) => {
+            numeric!(Int64Type).cast_with_dtype(dtype)?
+        }
+        dtype => {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot create a column of dtype {:?} from rows", dtype).into(),
+            ))
+        }
+    };
+    s.rename(name);
+    Ok(s)
+}
+
+/// Create a `DataFrame` from rows of a struct, with a column per field.
+///
+/// This is usually derived with `#[derive(IntoDataFrame)]` from the `derive` feature of polars.
+pub trait IntoDataFrame: Sized {
+    fn into_df<I: IntoIterator<Item = Self>>(rows: I) -> Result<DataFrame>;
+}
+
+/// Extract rows of a struct from a `DataFrame`, with a column per field.
+///
+/// This is usually derived with `#[derive(FromDataFrame)]` from the `derive` feature of polars.
+pub trait FromDataFrame: Sized {
+    fn from_df(df: &DataFrame) -> Result<Vec<Self>>;
+}
+
+/// Types that can be the field of a struct that is converted from or into a `DataFrame`.
+/// `Option<T>` fields map to columns with null values.
+pub trait DataFrameField: Sized {
+    fn into_series(name: &str, values: Vec<Self>) -> Series;
+
+    /// Extract the values of the column. The column is cast to the type of the field.
+    fn from_series(s: &Series) -> Result<Vec<Self>>;
+}
+
+fn no_nulls(s: &Series) -> Result<()> {
+    if s.null_count() > 0 {
+        Err(PolarsError::ValueError(
+            format!(
+                "column {} has null values, use an Option field to extract it",
+                s.name()
+            )
+            .into(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+macro_rules! impl_dataframe_field {
+    ($native:ty, $dtype:expr, $getter:ident) => {
+        impl DataFrameField for $native {
+            fn into_series(name: &str, values: Vec<Self>) -> Series {
+                Series::new(name, values)
+            }
+
+            fn from_series(s: &Series) -> Result<Vec<Self>> {
+                no_nulls(s)?;
+                let s = s.cast_with_dtype(&$dtype)?;
+                Ok(s.$getter()?.into_no_null_iter().collect())
+            }
+        }
+
+        impl DataFrameField for Option<$native> {
+            fn into_series(name: &str, values: Vec<Self>) -> Series {
+                Series::new(name, values)
+            }
+
+            fn from_series(s: &Series) -> Result<Vec<Self>> {
+                let s = s.cast_with_dtype(&$dtype)?;
+                Ok(s.$getter()?.into_iter().collect())
+            }
+        }
+    };
+}
+
+impl_dataframe_field!(bool, DataType::Boolean, bool);
+#[cfg(feature = "dtype-u8")]
+impl_dataframe_field!(u8, DataType::UInt8, u8);
+#[cfg(feature = "dtype-u16")]
+impl_dataframe_field!(u16, DataType::UInt16, u16);
+impl_dataframe_field!(u32, DataType::UInt32, u32);
+#[cfg(feature = "dtype-u64")]
+impl_dataframe_field!(u64, DataType::UInt64, u64);
+#[cfg(feature = "dtype-i8")]
+impl_dataframe_field!(i8, DataType::Int8, i8);
+#[cfg(feature = "dtype-i16")]
+impl_dataframe_field!(i16, DataType::Int16, i16);
+impl_dataframe_field!(i32, DataType::Int32, i32);
+impl_dataframe_field!(i64, DataType::Int64, i64);
+impl_dataframe_field!(f32, DataType::Float32, f32);
+impl_dataframe_field!(f64, DataType::Float64, f64);
+
+impl DataFrameField for String {
+    fn into_series(name: &str, values: Vec<Self>) -> Series {
+        Series::new(name, values)
+    }
+
+    fn from_series(s: &Series) -> Result<Vec<Self>> {
+        no_nulls(s)?;
+        let s = s.cast_with_dtype(&DataType::Utf8)?;
+        Ok(s.utf8()?.into_no_null_iter().map(str::to_string).collect())
+    }
+}
+
+impl DataFrameField for Option<String> {
+    fn into_series(name: &str, values: Vec<Self>) -> Series {
+        Series::new(name, values)
+    }
+
+    fn from_series(s: &Series) -> Result<Vec<Self>> {
+        let s = s.cast_with_dtype(&DataType::Utf8)?;
+        Ok(s.utf8()?
+            .into_iter()
+            .map(|opt_v| opt_v.map(str::to_string))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_rows() -> Result<()> {
+        let df = df![
+            "a" => [Some(1i64), None, Some(3)],
+            "b" => [Some("x"), Some("y"), None],
+            "c" => [1.0, 2.5, 3.0]
+        ]?;
+
+        let rows = df.iter_rows().collect_vec();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1], df.get_row(1));
+
+        let out = DataFrame::from_rows_and_schema(&rows, &df.schema())?;
+        assert!(out.frame_equal_missing(&df));
+
+        // the dtypes are inferred from the values
+        let rows = vec![
+            Row(vec![AnyValue::Int32(1), AnyValue::Null, AnyValue::Null]),
+            Row(vec![
+                AnyValue::Float64(2.5),
+                AnyValue::Boolean(true),
+                AnyValue::Null,
+            ]),
+        ];
+        let out = DataFrame::from_rows(&rows)?;
+        assert_eq!(
+            out.get_column_names(),
+            &["column_0", "column_1", "column_2"]
+        );
+        assert_eq!(
+            out.dtypes(),
+            &[DataType::Float64, DataType::Boolean, DataType::Utf8]
+        );
+        assert_eq!(out.column("column_0")?.null_count(), 0);
+
+        let rows = vec![Row(vec![AnyValue::Utf8("x")])];
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32)]);
+        assert!(DataFrame::from_rows_and_schema(&rows, &schema).is_err());
+
+        // floats are not truncated into integer columns
+        let rows = vec![Row(vec![AnyValue::Float64(2.0)])];
+        let out = DataFrame::from_rows_and_schema(&rows, &schema)?;
+        assert_eq!(out.column("a")?.i32()?.get(0), Some(2));
+        let rows = vec![Row(vec![AnyValue::Float64(2.5)])];
+        assert!(DataFrame::from_rows_and_schema(&rows, &schema).is_err());
+
+        // time units are converted
+        let schema = Schema::new(vec![Field::new(
+            "a",
+            DataType::Duration(TimeUnit::Nanosecond),
+        )]);
+        let rows = vec![Row(vec![AnyValue::Duration(3, TimeUnit::Millisecond)])];
+        let out = DataFrame::from_rows_and_schema(&rows, &schema)?;
+        assert_eq!(
+            out.column("a")?.duration_nanosecond()?.get(0),
+            Some(3_000_000)
+        );
+        let schema = Schema::new(vec![Field::new(
+            "a",
+            DataType::Duration(TimeUnit::Millisecond),
+        )]);
+        let rows = vec![Row(vec![AnyValue::Duration(1, TimeUnit::Nanosecond)])];
+        assert!(DataFrame::from_rows_and_schema(&rows, &schema).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,125 @@
+//! Transpose a DataFrame.
+use crate::prelude::*;
+use crate::utils::get_supertype;
+
+impl DataFrame {
+    /// Transpose the `DataFrame`, so that every row becomes a column and every column a row.
+    /// All the values are cast to their supertype.
+    ///
+    /// # Arguments
+    ///
+    /// * `header_name` - If given, the first column of the output has this name and contains the
+    ///   names of the original columns.
+    /// * `names_column` - If given, the values of this column are used as names of the new
+    ///   columns, and the column itself is not transposed. Otherwise the new columns are named
+    ///   `column_0`, `column_1`, etc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate polars_core;
+    /// # fn main() {
+    /// use polars_core::prelude::*;
+    /// let df = df!("metric" => &["min", "max"],
+    ///              "a" => &[1, 5],
+    ///              "b" => &[2, 8]
+    ///     )
+    /// .unwrap();
+    ///
+    /// let out = df.transpose(Some("column"), Some("metric")).unwrap();
+    /// assert_eq!(out.get_column_names(), &["column", "min", "max"]);
+    /// assert_eq!(Vec::from(out.column("max").unwrap().i32().unwrap()), &[Some(5), Some(8)]);
+    /// # }
+    /// ```
+    pub fn transpose(
+        &self,
+        header_name: Option<&str>,
+        names_column: Option<&str>,
+    ) -> Result<DataFrame> {
+        let new_names: Vec<String> = match names_column {
+            Some(name) => {
+                let s = self.column(name)?.cast::<Utf8Type>()?;
+                let ca = s.utf8()?;
+                if ca.null_count() > 0 {
+                    return Err(PolarsError::ValueError(
+                        "the column with the new column names should not contain null values"
+                            .into(),
+                    ));
+                }
+                ca.into_no_null_iter()
+                    .map(|name| name.to_string())
+                    .collect()
+            }
+            None => (0..self.height())
+                .map(|i| format!("column_{}", i))
+                .collect(),
+        };
+        let columns = self
+            .columns
+            .iter()
+            .filter(|s| Some(s.name()) != names_column)
+            .collect::<Vec<_>>();
+
+        let mut out = Vec::with_capacity(new_names.len() + 1);
+        if let Some(header_name) = header_name {
+            let names = columns.iter().map(|s| s.name()).collect::<Vec<_>>();
+            out.push(Series::new(header_name, &names));
+        }
+
+        if let Some((first, rest)) = columns.split_first() {
+            let mut dtype = first.dtype().clone();
+            for s in rest {
+                dtype = get_supertype(&dtype, s.dtype())?;
+            }
+            // all values in a single Series; the values of column j start at j * height
+            let mut values = first.cast_with_dtype(&dtype)?;
+            for s in rest {
+                values.append(&s.cast_with_dtype(&dtype)?)?;
+            }
+            let values = values.rechunk();
+
+            let height = self.height();
+            let width = columns.len();
+            for (i, name) in new_names.iter().enumerate() {
+                let mut s = values.take_iter(&mut (0..width).map(|j| j * height + i));
+                s.rename(name);
+                out.push(s);
+            }
+        }
+        DataFrame::new(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_transpose() -> Result<()> {
+        let df = df!("a" => &[1, 2, 3],
+         "b" => &[Some(0.5), None, Some(1.5)]
+        )?;
+
+        let out = df.transpose(None, None)?;
+        assert_eq!(out.shape(), (2, 3));
+        assert_eq!(
+            out.get_column_names(),
+            &["column_0", "column_1", "column_2"]
+        );
+        assert_eq!(
+            Vec::from(out.column("column_1")?.f64()?),
+            &[Some(2.0), None]
+        );
+
+        let out = df.transpose(Some("name"), None)?;
+        assert_eq!(
+            Vec::from(out.column("name")?.utf8()?),
+            &[Some("a"), Some("b")]
+        );
+
+        let out = df.transpose(Some("name"), Some("a"))?;
+        assert_eq!(out.get_column_names(), &["name", "1", "2", "3"]);
+        assert_eq!(Vec::from(out.column("3")?.f64()?), &[Some(1.5)]);
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+//! Insert the missing time steps in a DataFrame.
+use crate::prelude::*;
+use crate::utils::chrono::Duration;
+
+impl DataFrame {
+    /// Upsample a `Date64` column, which should be sorted in ascending order, to the frequency
+    /// `every`. Starting at the first time step, rows are inserted for every missing time step.
+    /// The other columns of the inserted rows are null, so that they can be filled with
+    /// `fill_none` or interpolation. The existing rows are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// use polars_core::utils::chrono::Duration;
+    ///
+    /// fn hourly(df: &DataFrame) -> Result<DataFrame> {
+    ///     df.upsample("time", Duration::hours(1))?
+    ///         .fill_none(FillNoneStrategy::Forward(None))
+    /// }
+    /// ```
+    pub fn upsample(&self, time_column: &str, every: Duration) -> Result<DataFrame> {
+        self.upsample_by(&[], time_column, every)
+    }
+
+    /// Upsample a `Date64` column per group of the `by` columns.
+    /// See [upsample](crate::frame::DataFrame::upsample).
+    pub fn upsample_by(
+        &self,
+        by: &[&str],
+        time_column: &str,
+        every: Duration,
+    ) -> Result<DataFrame> {
+        let every = every.num_milliseconds();
+        if every <= 0 {
+            return Err(PolarsError::ValueError(
+                "the upsample frequency should be a positive duration".into(),
+            ));
+        }
+        let time_s = self.column(time_column)?;
+        if time_s.dtype() != &DataType::Date64 {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "the time column of an upsample should be of type date64, got {:?}",
+                    time_s.dtype()
+                )
+                .into(),
+            ));
+        }
+        if time_s.null_count() > 0 {
+            return Err(PolarsError::HasNullValues(
+                "the time column of an upsample has null values".into(),
+            ));
+        }
+        // there is no first time step to start from
+        if self.height() == 0 {
+            return Ok(self.clone());
+        }
+        let time = time_s.date64()?.into_no_null_iter().collect::<Vec<_>>();
+
+        let groups = if by.is_empty() {
+            vec![(0, (0..self.height() as u32).collect())]
+        } else {
+            self.groupby_stable(by)?.groups
+        };
+
+        // the row of the original DataFrame, or None for an inserted row
+        let mut rows = Vec::with_capacity(self.height());
+        // the group of every row, to fill the `by` columns of the inserted rows
+        let mut group_rows = Vec::with_capacity(self.height());
+        let mut new_time = Vec::with_capacity(self.height());
+        for (first, idx) in groups {
+            let mut next = time[first as usize];
+            let mut prev = next;
+            for i in idx {
+                let t = time[i as usize];
+                if t < prev {
+                    return Err(PolarsError::InvalidOperation(
+                        "the time column of an upsample should be sorted in ascending order".into(),
+                    ));
+                }
+                while next < t {
+                    rows.push(None);
+                    group_rows.push(first as usize);
+                    new_time.push(next);
+                    next += every;
+                }
+                if next == t {
+                    next += every;
+                }
+                rows.push(Some(i as usize));
+                group_rows.push(first as usize);
+                new_time.push(t);
+                prev = t;
+            }
+        }
+
+        // Safety:
+        // the row indexes are in bounds.
+        let mut df = unsafe { self.take_opt_iter_unchecked(rows.into_iter()) };
+        df.replace(
+            time_column,
+            Date64Chunked::new_from_slice(time_column, &new_time),
+        )?;
+        for name in by {
+            let s = self.column(name)?;
+            // Safety:
+            // the group indexes are in bounds.
+            let s = unsafe { s.take_iter_unchecked(&mut group_rows.iter().copied()) };
+            df.replace(name, s)?;
+        }
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_upsample() -> Result<()> {
+        let hour = 3600 * 1000;
+        let time = Date64Chunked::new_from_slice("time", &[0, 3 * hour, hour, 2 * hour]);
+        let df = DataFrame::new(vec![
+            time.into_series(),
+            Series::new("id", &["a", "a", "b", "b"]),
+            Series::new("value", &[1, 2, 3, 4]),
+        ])?;
+
+        assert!(df.upsample("time", Duration::hours(1)).is_err());
+
+        let out = df.upsample_by(&["id"], "time", Duration::hours(1))?;
+        assert_eq!(
+            Vec::from(out.column("time")?.date64()?),
+            &[
+                Some(0),
+                Some(hour),
+                Some(2 * hour),
+                Some(3 * hour),
+                Some(hour),
+                Some(2 * hour)
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("id")?.utf8()?),
+            &[
+                Some("a"),
+                Some("a"),
+                Some("a"),
+                Some("a"),
+                Some("b"),
+                Some("b")
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("value")?.i32()?),
+            &[Some(1), None, None, Some(2), Some(3), Some(4)]
+        );
+
+        let empty = df.head(Some(0));
+        let out = empty.upsample_by(&["id"], "time", Duration::hours(1))?;
+        assert!(out.frame_equal(&empty));
+        assert_eq!(empty.upsample("time", Duration::hours(1))?.height(), 0);
+        Ok(())
+    }
+}
@@ -4,7 +4,10 @@
 //!
 use crate::chunked_array::ops::sort::prepare_argsort;
 use crate::prelude::*;
+use crate::utils::{accumulate_dataframes_horizontal, get_supertype};
+use arrow::array::new_null_array;
 use num::{Float, NumCast};
+use std::convert::TryFrom;
 use std::ops::Div;
 
 /// Compute the covariance between two columns.
@@ -53,6 +56,328 @@ pub fn argsort_by(by: &[Series], reverse: &[bool]) -> Result<UInt32Chunked> {
     first.argsort_multiple(&by, &reverse)
 }
 
+/// Horizontally concatenate the string values of multiple `Series`, separated by `delimiter`.
+/// `Series` that are not of type `Utf8` are cast first and `Series` of length 1 are broadcast.
+///
+/// If `ignore_nulls` is true, null values are skipped and a row is only null if all its values
+/// are null. Otherwise a row is null if any of its values is null.
+pub fn concat_str(s: &[Series], delimiter: &str, ignore_nulls: bool) -> Result<Utf8Chunked> {
+    if s.is_empty() {
+        return Err(PolarsError::NoData(
+            "expected at least one Series in concat_str".into(),
+        ));
+    }
+    let len = s.iter().map(|s| s.len()).max().unwrap();
+
+    let cas = s
+        .iter()
+        .map(|s| {
+            if s.len() != len && s.len() != 1 {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "cannot concatenate a Series of length {} with a Series of length {}",
+                        s.len(),
+                        len
+                    )
+                    .into(),
+                ));
+            }
+            s.cast::<Utf8Type>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut iters = cas
+        .iter()
+        .map(|s| {
+            let ca = s.utf8().unwrap();
+            if ca.len() == 1 {
+                Box::new(std::iter::repeat(ca.get(0)).take(len))
+                    as Box<dyn Iterator<Item = Option<&str>>>
+            } else {
+                Box::new(ca.into_iter())
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut ca: Utf8Chunked = (0..len)
+        .map(|_| {
+            let mut buf = String::new();
+            let mut n_valid = 0;
+            let mut has_nulls = false;
+            for iter in iters.iter_mut() {
+                match iter.next().unwrap() {
+                    Some(val) => {
+                        if n_valid > 0 {
+                            buf.push_str(delimiter);
+                        }
+                        buf.push_str(val);
+                        n_valid += 1;
+                    }
+                    None => has_nulls = true,
+                }
+            }
+            if n_valid == 0 || (has_nulls && !ignore_nulls) {
+                None
+            } else {
+                Some(buf)
+            }
+        })
+        .collect();
+    ca.rename(s[0].name());
+    Ok(ca)
+}
+
+/// Start an eager `when(..).then(..).otherwise(..)` chain on `Series`.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_core::functions::when;
+///
+/// fn example(s: &Series) -> Result<Series> {
+///     let small = s.lt(10);
+///     let medium = s.lt(100);
+///     when(&small)
+///         .then(&Series::new("", &["small"]))
+///         .when(&medium)
+///         .then(&Series::new("", &["medium"]))
+///         .otherwise(&Series::new("", &["large"]))
+/// }
+/// ```
+pub fn when(predicate: &BooleanChunked) -> When {
+    When {
+        branches: vec![],
+        predicate,
+    }
+}
+
+/// Intermediate state of an eager `when(..).then(..)` chain, waiting for the values of the
+/// last predicate.
+pub struct When<'a> {
+    branches: Vec<(&'a BooleanChunked, &'a Series)>,
+    predicate: &'a BooleanChunked,
+}
+
+/// Intermediate state of an eager `when(..).then(..)` chain, waiting for another predicate or
+/// the `otherwise` values.
+pub struct WhenThen<'a> {
+    branches: Vec<(&'a BooleanChunked, &'a Series)>,
+}
+
+impl<'a> When<'a> {
+    /// Take the values of `then` where the predicate is `true`. `then` may have length 1.
+    pub fn then(mut self, then: &'a Series) -> WhenThen<'a> {
+        self.branches.push((self.predicate, then));
+        WhenThen {
+            branches: self.branches,
+        }
+    }
+}
+
+impl<'a> WhenThen<'a> {
+    /// Add a branch that is only taken if none of the previous predicates are `true`.
+    pub fn when(self, predicate: &'a BooleanChunked) -> When<'a> {
+        When {
+            branches: self.branches,
+            predicate,
+        }
+    }
+
+    /// Take the values of `otherwise` where none of the predicates are `true`. Null values
+    /// in the predicates count as `false`.
+    pub fn otherwise(self, otherwise: &Series) -> Result<Series> {
+        // the branches are nested last in first out, so the first true predicate wins
+        self.branches
+            .iter()
+            .rev()
+            .try_fold(otherwise.clone(), |falsy, (predicate, truthy)| {
+                let mask = if predicate.null_count() > 0 {
+                    predicate.fill_none_with_value(false)?
+                } else {
+                    (*predicate).clone()
+                };
+                truthy.zip_with(&mask, &falsy)
+            })
+    }
+}
+
+/// The way [concat_df](fn.concat_df.html) combines DataFrames.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConcatMethod {
+    /// Stack the DataFrames on top of each other. The DataFrames must have the same column
+    /// names in the same order and the columns must have the same data types.
+    Vertical,
+    /// Stack the DataFrames on top of each other. The DataFrames must have the same column
+    /// names in the same order; columns with different data types are cast to their supertype.
+    VerticalRelaxed,
+    /// Stack the DataFrames on top of each other. The columns are matched by name and the
+    /// output has the union of all columns, in order of appearance. Missing columns are filled
+    /// with nulls and columns with different data types are cast to their supertype.
+    Diagonal,
+    /// Add the columns of all DataFrames next to each other. The DataFrames must have the same
+    /// height and unique column names.
+    Horizontal,
+}
+
+/// Create a `Series` of `len` null values of the given data type.
+fn full_null_series(name: &str, dtype: &DataType, len: usize) -> Result<Series> {
+    match dtype {
+        DataType::Categorical => {
+            full_null_series(name, &DataType::Utf8, len)?.cast_with_dtype(dtype)
+        }
+        DataType::Object(_) => Err(PolarsError::InvalidOperation(
+            format!("cannot create null values of dtype {:?}", dtype).into(),
+        )),
+        _ => Series::try_from((name, new_null_array(&dtype.to_arrow(), len))),
+    }
+}
+
+/// Determine the supertype of all data types.
+fn supertype<'a, I: IntoIterator<Item = &'a DataType>>(dtypes: I) -> Result<DataType> {
+    let mut iter = dtypes.into_iter();
+    let first = iter.next().unwrap().clone();
+    iter.try_fold(first, |acc, dtype| get_supertype(&acc, dtype))
+}
+
+/// Check that all DataFrames have the same column names in the same order.
+fn check_column_names(dfs: &[DataFrame]) -> Result<()> {
+    let names = dfs[0].get_column_names();
+    match dfs.iter().find(|df| df.get_column_names() != names) {
+        Some(df) => Err(PolarsError::SchemaMisMatch(
+            format!(
+                "cannot vertically concatenate a DataFrame with columns {:?} and a DataFrame with columns {:?}",
+                names,
+                df.get_column_names()
+            )
+            .into(),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Vertically stack DataFrames with the same column names and the given data types. Categorical
+/// columns are stacked as `Utf8`, as the categories of different DataFrames do not match.
+fn stack_with_dtypes(dfs: Vec<Vec<Series>>, dtypes: &[DataType]) -> Result<DataFrame> {
+    let mut stacked: Option<DataFrame> = None;
+    for columns in dfs {
+        let columns = columns
+            .iter()
+            .zip(dtypes)
+            .map(|(s, dtype)| match dtype {
+                DataType::Categorical => s.cast::<Utf8Type>(),
+                _ if s.dtype() == dtype => Ok(s.clone()),
+                _ => s.cast_with_dtype(dtype),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let df = DataFrame::new_no_checks(columns);
+        match stacked.as_mut() {
+            Some(acc) => {
+                acc.vstack_mut(&df)?;
+            }
+            None => stacked = Some(df),
+        }
+    }
+    let mut out = stacked.unwrap();
+    for (idx, dtype) in dtypes.iter().enumerate() {
+        if matches!(dtype, DataType::Categorical) {
+            let s = out.get_columns()[idx].cast_with_dtype(dtype)?;
+            out.replace_at_idx(idx, s)?;
+        }
+    }
+    Ok(out)
+}
+
+/// Combine multiple DataFrames into a single DataFrame. See [ConcatMethod](enum.ConcatMethod.html)
+/// for the ways the DataFrames can be combined.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_core::functions::{concat_df, ConcatMethod};
+///
+/// # fn main() -> Result<()> {
+/// let january = df! {
+///     "id" => [1, 2],
+///     "price" => [1.5, 2.0]
+/// }?;
+/// let february = df! {
+///     "id" => [3],
+///     "discount" => [0.1]
+/// }?;
+///
+/// let df = concat_df(&[january, february], ConcatMethod::Diagonal)?;
+/// assert_eq!(df.get_column_names(), &["id", "price", "discount"]);
+/// assert_eq!(df.column("price")?.null_count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn concat_df(dfs: &[DataFrame], how: ConcatMethod) -> Result<DataFrame> {
+    if dfs.is_empty() {
+        return Err(PolarsError::NoData(
+            "expected at least one DataFrame to concatenate".into(),
+        ));
+    }
+
+    match how {
+        ConcatMethod::Vertical => {
+            check_column_names(dfs)?;
+            let mut out = dfs[0].clone();
+            for df in &dfs[1..] {
+                out.vstack_mut(df)?;
+            }
+            Ok(out)
+        }
+        ConcatMethod::VerticalRelaxed => {
+            check_column_names(dfs)?;
+            let width = dfs[0].width();
+            let dtypes = (0..width)
+                .map(|idx| supertype(dfs.iter().map(|df| df.get_columns()[idx].dtype())))
+                .collect::<Result<Vec<_>>>()?;
+            stack_with_dtypes(
+                dfs.iter().map(|df| df.get_columns().clone()).collect(),
+                &dtypes,
+            )
+        }
+        ConcatMethod::Diagonal => {
+            let mut names: Vec<&str> = vec![];
+            for df in dfs {
+                for name in df.get_column_names() {
+                    if !names.contains(&name) {
+                        names.push(name)
+                    }
+                }
+            }
+            let dtypes = names
+                .iter()
+                .map(|name| {
+                    supertype(
+                        dfs.iter()
+                            .filter_map(|df| df.column(name).ok())
+                            .map(|s| s.dtype()),
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let columns = dfs
+                .iter()
+                .map(|df| {
+                    names
+                        .iter()
+                        .zip(&dtypes)
+                        .map(|(name, dtype)| match df.column(name) {
+                            Ok(s) => Ok(s.clone()),
+                            Err(_) => full_null_series(name, dtype, df.height()),
+                        })
+                        .collect::<Result<Vec<_>>>()
+                })
+                .collect::<Result<Vec<_>>>()?;
+            stack_with_dtypes(columns, &dtypes)
+        }
+        ConcatMethod::Horizontal => accumulate_dataframes_horizontal(dfs.to_vec()),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -64,4 +389,82 @@ mod test {
         assert!((cov(&a.f32().unwrap(), &b.f32().unwrap()).unwrap() - 0.5).abs() < 0.001);
         assert!((pearson_corr(&a.f32().unwrap(), &b.f32().unwrap()).unwrap() - 1.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_concat_str() {
+        let a = Series::new("a", &[Some("foo"), None, Some("bar")]);
+        let b = Series::new("b", &[Some(1), Some(2), None]);
+        let c = Series::new("c", &["-"]);
+
+        let out = concat_str(&[a.clone(), b.clone(), c], "_", false).unwrap();
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some("foo_1_-"), None, None]);
+
+        let out = concat_str(&[a, b], "_", true).unwrap();
+        assert_eq!(Vec::from(&out), &[Some("foo_1"), Some("2"), Some("bar")]);
+    }
+
+    #[test]
+    fn test_when_then_otherwise() -> Result<()> {
+        let s = Series::new("a", &[Some(1), Some(50), Some(500), None]);
+        let out = when(&s.lt(10))
+            .then(&Series::new("", &[1]))
+            .when(&s.lt(100))
+            .then(&Series::new("", &[2]))
+            .otherwise(&Series::new("", &[3]))?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(2), Some(3), Some(3)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_df() -> Result<()> {
+        let a = df! {
+            "id" => [1, 2],
+            "price" => [1.5, 2.0]
+        }?;
+        let b = df! {
+            "id" => [3i64],
+            "price" => [Some(4.0f32)]
+        }?;
+        let c = df! {
+            "discount" => [0.1],
+            "id" => [4]
+        }?;
+
+        assert!(concat_df(&[a.clone(), b.clone()], ConcatMethod::Vertical).is_err());
+        let out = concat_df(&[a.clone(), b], ConcatMethod::VerticalRelaxed)?;
+        assert_eq!(out.dtypes(), &[DataType::Int64, DataType::Float64]);
+        assert_eq!(out.height(), 3);
+        // the columns are matched by name and order, not only by position
+        let swapped = df! {
+            "price" => [3.0],
+            "id" => [3]
+        }?;
+        let renamed = df! {
+            "id" => [3],
+            "cost" => [3.0]
+        }?;
+        for df in [swapped, renamed].iter() {
+            for how in [ConcatMethod::Vertical, ConcatMethod::VerticalRelaxed].iter() {
+                assert!(concat_df(&[a.clone(), df.clone()], *how).is_err());
+            }
+        }
+
+        let out = concat_df(&[a.clone(), c.clone()], ConcatMethod::Diagonal)?;
+        assert_eq!(out.get_column_names(), &["id", "price", "discount"]);
+        assert_eq!(
+            Vec::from(out.column("id")?.i32()?),
+            &[Some(1), Some(2), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(out.column("discount")?.f64()?),
+            &[None, None, Some(0.1)]
+        );
+
+        assert!(concat_df(&[a.clone(), c], ConcatMethod::Horizontal).is_err());
+        let d = df!("discount" => [0.1, 0.2])?;
+        let out = concat_df(&[a, d], ConcatMethod::Horizontal)?;
+        assert_eq!(out.shape(), (2, 3));
+        Ok(())
+    }
 }
@@ -15,7 +15,7 @@ pub use crate::{
             chunkops::ChunkOps,
             take::AsTakeIndex,
             take_random::{IntoTakeRandom, NumTakeRandomChunked, NumTakeRandomCont},
-            window::InitFold,
+            window::{InitFold, RollingFunction, RollingOptions},
             *,
         },
         ChunkedArray,
@@ -23,7 +23,11 @@ pub use crate::{
     datatypes,
     datatypes::*,
     error::{PolarsError, Result},
-    frame::{hash_join::JoinType, DataFrame},
+    frame::{
+        hash_join::{DuplicateColumns, JoinType},
+        row::{DataFrameField, FromDataFrame, IntoDataFrame},
+        DataFrame, UniqueKeepStrategy,
+    },
     series::{
         arithmetic::{LhsNumOps, NumOpsDispatch},
         IntoSeries, NamedFrom, Series, SeriesTrait,
@@ -39,6 +43,36 @@ pub use std::sync::Arc;
 
 #[cfg(feature = "temporal")]
 pub use crate::chunked_array::temporal::conversion::*;
+#[cfg(feature = "temporal")]
+pub use crate::chunked_array::temporal::date_range;
 
 #[cfg(feature = "object")]
 pub use crate::chunked_array::object::PolarsObject;
+
+#[cfg(feature = "dtype-struct")]
+pub use crate::chunked_array::struct_::StructChunked;
+
+#[cfg(feature = "dtype-binary")]
+pub use crate::chunked_array::{binary::BinaryEncoding, builder::BinaryChunkedBuilder};
+
+#[cfg(feature = "asof_join")]
+pub use crate::frame::asof_join::{AsOfOptions, AsofStrategy};
+
+#[cfg(feature = "ewma")]
+pub use crate::chunked_array::ops::ewm::EwmOptions;
+
+#[cfg(feature = "dynamic_groupby")]
+pub use crate::frame::groupby::dynamic::DynamicGroupOptions;
+#[cfg(feature = "pivot")]
+pub use crate::frame::groupby::pivot::PivotAgg;
+#[cfg(feature = "rolling_groupby")]
+pub use crate::frame::groupby::rolling::{RollingGroupOptions, RollingPeriod};
+
+#[cfg(feature = "diff")]
+pub use crate::series::diff::NullBehavior;
+#[cfg(feature = "interpolate")]
+pub use crate::series::interpolate::InterpolationMethod;
+#[cfg(feature = "rank")]
+pub use crate::series::rank::{RankMethod, RankOptions};
+#[cfg(feature = "search_sorted")]
+pub use crate::series::search_sorted::SearchSortedSide;
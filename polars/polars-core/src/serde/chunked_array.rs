@@ -1,8 +1,9 @@
 use super::DeDataType;
 use crate::prelude::*;
-use serde::ser::SerializeStruct;
+use serde::ser::{self, SerializeStruct};
 use serde::{Serialize, Serializer};
 use std::cell::RefCell;
+use std::convert::TryFrom;
 
 pub struct IterSer<I>
 where
@@ -55,7 +56,7 @@ where
     {
         let mut state = serializer.serialize_struct("series", 3)?;
         state.serialize_field("name", self.name())?;
-        let dtype: DeDataType = self.dtype().into();
+        let dtype = DeDataType::try_from(self.dtype()).map_err(ser::Error::custom)?;
         state.serialize_field("datatype", &dtype)?;
         state.serialize_field("values", &IterSer::new(self.into_iter()))?;
         state.end()
@@ -74,7 +75,7 @@ macro_rules! impl_serialize {
             {
                 let mut state = serializer.serialize_struct("series", 3)?;
                 state.serialize_field("name", self.name())?;
-                let dtype: DeDataType = self.dtype().into();
+                let dtype = DeDataType::try_from(self.dtype()).map_err(ser::Error::custom)?;
                 state.serialize_field("datatype", &dtype)?;
                 state.serialize_field("values", &IterSer::new(self.into_iter()))?;
                 state.end()
@@ -95,7 +96,12 @@ impl Serialize for CategoricalChunked {
     where
         S: Serializer,
     {
+        // the categories are serialized as strings
         let ca = self.cast::<Utf8Type>().unwrap();
-        ca.serialize(serializer)
+        let mut state = serializer.serialize_struct("series", 3)?;
+        state.serialize_field("name", self.name())?;
+        state.serialize_field("datatype", &DeDataType::Categorical)?;
+        state.serialize_field("values", &IterSer::new(ca.into_iter()))?;
+        state.end()
     }
 }
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 pub mod chunked_array;
 pub mod series;
 use crate::prelude::*;
+use std::convert::TryFrom;
 
 /// Intermediate enum. Needed because [crate::datatypes::DataType] has
 /// a &static str and thus requires Deserialize<&static>
@@ -22,15 +23,22 @@ enum DeDataType<'a> {
     Date32,
     Date64,
     Time64(TimeUnit),
+    Duration(TimeUnit),
     List,
     Object(&'a str),
     Null,
     Categorical,
 }
 
-impl From<&DataType> for DeDataType<'_> {
-    fn from(dt: &DataType) -> Self {
-        match dt {
+impl TryFrom<&DataType> for DeDataType<'_> {
+    type Error = PolarsError;
+
+    fn try_from(dt: &DataType) -> Result<Self> {
+        let dt = match dt {
+            DataType::Int8 => DeDataType::Int8,
+            DataType::Int16 => DeDataType::Int16,
+            DataType::UInt8 => DeDataType::UInt8,
+            DataType::UInt16 => DeDataType::UInt16,
             DataType::Int32 => DeDataType::Int32,
             DataType::UInt32 => DeDataType::UInt32,
             DataType::Int64 => DeDataType::Int64,
@@ -42,11 +50,40 @@ impl From<&DataType> for DeDataType<'_> {
             DataType::Utf8 => DeDataType::Utf8,
             DataType::Boolean => DeDataType::Boolean,
             DataType::Null => DeDataType::Null,
+            DataType::Time64(tu) => DeDataType::Time64(*tu),
+            DataType::Duration(tu) => DeDataType::Duration(*tu),
             DataType::List(_) => DeDataType::List,
+            DataType::Categorical => DeDataType::Categorical,
             #[cfg(feature = "object")]
             DataType::Object(s) => DeDataType::Object(s),
-            _ => unimplemented!(),
-        }
+            dt => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("serialization of dtype {:?} is not supported", dt).into(),
+                ))
+            }
+        };
+        Ok(dt)
+    }
+}
+
+/// (De)serialize a [Duration](crate::utils::chrono::Duration) as a number of milliseconds.
+#[cfg(feature = "temporal")]
+pub(crate) mod duration {
+    use crate::utils::chrono::Duration;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(duration.num_milliseconds())
+    }
+
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        i64::deserialize(deserializer).map(Duration::milliseconds)
     }
 }
 
@@ -54,6 +91,7 @@ impl From<&DataType> for DeDataType<'_> {
 mod test {
     use super::*;
     use crate::prelude::*;
+    use std::convert::TryFrom;
 
     #[test]
     fn test_serde() -> Result<()> {
@@ -88,4 +126,49 @@ mod test {
         let out = serde_json::from_str::<DataFrame>(&json).unwrap();
         assert!(df.frame_equal_missing(&out));
     }
+
+    #[test]
+    fn test_serde_dtypes() {
+        let df = df![
+            "i64" => [Some(1i64), None, Some(3)],
+            "f32" => [Some(1.0f32), Some(2.5), None],
+            "utf8" => [Some("a \"quoted\" value"), None, Some("c")],
+            "bool" => [None, Some(true), Some(false)]
+        ]
+        .unwrap();
+        let mut cat = df
+            .column("utf8")
+            .unwrap()
+            .cast::<CategoricalType>()
+            .unwrap();
+        cat.rename("cat");
+        let list = Series::new(
+            "list",
+            &[
+                Series::new("", &[1, 2]),
+                Series::new("", &[3]),
+                Series::new("", &[4, 5]),
+            ],
+        );
+        let list = list
+            .list()
+            .unwrap()
+            .into_iter()
+            .enumerate()
+            .map(|(i, opt_s)| if i == 1 { None } else { opt_s })
+            .collect::<ListChunked>()
+            .into_series();
+        let df = df.hstack(&[cat, list]).unwrap();
+
+        let json = serde_json::to_string(&df).unwrap();
+        let out = serde_json::from_str::<DataFrame>(&json).unwrap();
+        assert_eq!(out.dtypes(), df.dtypes());
+        assert!(df.frame_equal_missing(&out));
+    }
+
+    #[test]
+    fn test_serde_unsupported_dtype() {
+        assert!(DeDataType::try_from(&DataType::Decimal(10, 2)).is_err());
+        assert!(DeDataType::try_from(&DataType::Struct(vec![])).is_err());
+    }
 }
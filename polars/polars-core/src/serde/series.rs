@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use crate::serde::DeDataType;
 use serde::de::{MapAccess, Visitor};
-use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 use std::borrow::Cow;
 use std::fmt::Formatter;
 
@@ -13,37 +13,36 @@ impl Serialize for Series {
     where
         S: Serializer,
     {
-        if let Ok(ca) = self.i32() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.u32() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.i64() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.u64() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.f32() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.f64() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.date32() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.date64() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.time64_nanosecond() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.utf8() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.bool() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.categorical() {
-            ca.serialize(serializer)
-        } else if let Ok(ca) = self.list() {
-            ca.serialize(serializer)
-        } else {
-            // cast small integers to i32
-            self.cast_with_dtype(&DataType::Int32)
-                .unwrap()
-                .serialize(serializer)
+        match self.dtype() {
+            DataType::Int8 => self.i8().unwrap().serialize(serializer),
+            DataType::Int16 => self.i16().unwrap().serialize(serializer),
+            DataType::Int32 => self.i32().unwrap().serialize(serializer),
+            DataType::Int64 => self.i64().unwrap().serialize(serializer),
+            DataType::UInt8 => self.u8().unwrap().serialize(serializer),
+            DataType::UInt16 => self.u16().unwrap().serialize(serializer),
+            DataType::UInt32 => self.u32().unwrap().serialize(serializer),
+            DataType::UInt64 => self.u64().unwrap().serialize(serializer),
+            DataType::Float32 => self.f32().unwrap().serialize(serializer),
+            DataType::Float64 => self.f64().unwrap().serialize(serializer),
+            DataType::Date32 => self.date32().unwrap().serialize(serializer),
+            DataType::Date64 => self.date64().unwrap().serialize(serializer),
+            DataType::Time64(TimeUnit::Nanosecond) => {
+                self.time64_nanosecond().unwrap().serialize(serializer)
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                self.duration_nanosecond().unwrap().serialize(serializer)
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                self.duration_millisecond().unwrap().serialize(serializer)
+            }
+            DataType::Utf8 => self.utf8().unwrap().serialize(serializer),
+            DataType::Boolean => self.bool().unwrap().serialize(serializer),
+            DataType::Categorical => self.categorical().unwrap().serialize(serializer),
+            DataType::List(_) => self.list().unwrap().serialize(serializer),
+            dt => Err(ser::Error::custom(format!(
+                "serialization of a Series of dtype {:?} is not supported",
+                dt
+            ))),
         }
     }
 }
@@ -120,10 +119,7 @@ impl<'de> Deserialize<'de> for Series {
                     count += 1;
                     match key {
                         Field::Name => {
-                            name = match map.next_value::<&str>() {
-                                Ok(s) => Some(Cow::Borrowed(s)),
-                                Err(_) => Some(Cow::Owned(map.next_value::<String>()?)),
-                            };
+                            name = Some(map.next_value::<Cow<'de, str>>()?);
                         }
                         Field::DataType => {
                             dtype = Some(map.next_value()?);
@@ -157,6 +153,16 @@ impl<'de> Deserialize<'de> for Series {
                         let values: Vec<Option<u8>> = map.next_value()?;
                         Ok(Series::new(&name, values))
                     }
+                    #[cfg(feature = "dtype-i16")]
+                    DeDataType::Int16 => {
+                        let values: Vec<Option<i16>> = map.next_value()?;
+                        Ok(Series::new(&name, values))
+                    }
+                    #[cfg(feature = "dtype-u16")]
+                    DeDataType::UInt16 => {
+                        let values: Vec<Option<u16>> = map.next_value()?;
+                        Ok(Series::new(&name, values))
+                    }
                     DeDataType::Int32 => {
                         let values: Vec<Option<i32>> = map.next_value()?;
                         Ok(Series::new(&name, values))
@@ -203,17 +209,40 @@ impl<'de> Deserialize<'de> for Series {
                             .cast::<Time64NanosecondType>()
                             .unwrap())
                     }
-                    DeDataType::Utf8 => {
-                        let values: Vec<Option<&str>> = map.next_value()?;
-                        Ok(Series::new(&name, values))
+                    #[cfg(feature = "dtype-duration-ns")]
+                    DeDataType::Duration(TimeUnit::Nanosecond) => {
+                        let values: Vec<Option<i64>> = map.next_value()?;
+                        Ok(Series::new(&name, values)
+                            .cast::<DurationNanosecondType>()
+                            .unwrap())
                     }
-                    DeDataType::List => {
-                        let values: Vec<Series> = map.next_value()?;
+                    #[cfg(feature = "dtype-duration-ms")]
+                    DeDataType::Duration(TimeUnit::Millisecond) => {
+                        let values: Vec<Option<i64>> = map.next_value()?;
+                        Ok(Series::new(&name, values)
+                            .cast::<DurationMillisecondType>()
+                            .unwrap())
+                    }
+                    DeDataType::Utf8 => {
+                        let values: Vec<Option<String>> = map.next_value()?;
                         Ok(Series::new(&name, values))
                     }
-                    dt => {
-                        panic!("{:?} dtype deserialization not yet implemented", dt)
+                    DeDataType::Categorical => {
+                        let values: Vec<Option<String>> = map.next_value()?;
+                        Series::new(&name, values)
+                            .cast::<CategoricalType>()
+                            .map_err(de::Error::custom)
                     }
+                    DeDataType::List => {
+                        let values: Vec<Option<Series>> = map.next_value()?;
+                        let mut ca: ListChunked = values.into_iter().collect();
+                        ca.rename(&name);
+                        Ok(ca.into_series())
+                    }
+                    dt => Err(de::Error::custom(format!(
+                        "deserialization of a Series of dtype {:?} is not supported",
+                        dt
+                    ))),
                 }
             }
         }
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use crate::utils::get_supertype;
+use crate::utils::{get_supertype, get_temporal_arithmetic_type, ArithmeticOp};
 use num::{Num, NumCast};
 use std::borrow::Cow;
 use std::fmt::Debug;
@@ -123,12 +123,80 @@ pub(crate) fn coerce_lhs_rhs<'a>(
     Ok((left, right))
 }
 
+const MS_IN_DAY: i64 = 86_400_000;
+const NS_IN_MS: i64 = 1_000_000;
+
+/// The values of a temporal Series as `Int64` in the given unit.
+fn to_physical_in_unit(s: &Series, unit: &TimeUnit) -> Result<Series> {
+    let (phys, in_ns) = match s.dtype() {
+        DataType::Date32 => (
+            &s.cast::<Int32Type>()?.cast::<Int64Type>()? * MS_IN_DAY,
+            false,
+        ),
+        DataType::Duration(TimeUnit::Nanosecond) => (s.cast::<Int64Type>()?, true),
+        _ => (s.cast::<Int64Type>()?, false),
+    };
+    Ok(match (in_ns, unit) {
+        (false, TimeUnit::Nanosecond) => &phys * NS_IN_MS,
+        (true, TimeUnit::Millisecond) => &phys / NS_IN_MS,
+        _ => phys,
+    })
+}
+
+/// Arithmetic between temporal types that doesn't follow the supertype rules.
+/// See [get_temporal_arithmetic_type]. Fractional results are truncated toward zero.
+fn temporal_arithmetic(
+    lhs: &Series,
+    op: ArithmeticOp,
+    rhs: &Series,
+    dtype: &DataType,
+) -> Result<Series> {
+    let unit = match dtype {
+        DataType::Duration(unit) => unit.clone(),
+        _ => TimeUnit::Millisecond,
+    };
+    let physical = |s: &Series| match s.dtype() {
+        DataType::Float32 | DataType::Float64 => s.cast::<Float64Type>(),
+        DataType::Duration(_) | DataType::Date32 | DataType::Date64 => {
+            to_physical_in_unit(s, &unit)
+        }
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_) => to_physical_in_unit(s, &unit),
+        _ => s.cast::<Int64Type>(),
+    };
+    let (l, r) = (physical(lhs)?, physical(rhs)?);
+    let (l, r) = coerce_lhs_rhs(&l, &r)?;
+    let out = match op {
+        ArithmeticOp::Add => l.add_to(&r)?,
+        ArithmeticOp::Sub => l.subtract(&r)?,
+        ArithmeticOp::Mul => l.multiply(&r)?,
+        ArithmeticOp::Div => l.divide(&r)?,
+    };
+    let mut out = out.cast::<Int64Type>()?.cast_with_dtype(dtype)?;
+    out.rename(lhs.name());
+    Ok(out)
+}
+
+/// Apply `op` to `lhs` and `rhs` after casting them to their supertype, or with the temporal
+/// rules if both are temporal.
+fn arithmetic(lhs: &Series, op: ArithmeticOp, rhs: &Series) -> Result<Series> {
+    if let Some(dtype) = get_temporal_arithmetic_type(lhs.dtype(), op, rhs.dtype()) {
+        return temporal_arithmetic(lhs, op, rhs, &dtype);
+    }
+    let (lhs, rhs) = coerce_lhs_rhs(lhs, rhs)?;
+    match op {
+        ArithmeticOp::Add => lhs.add_to(rhs.as_ref()),
+        ArithmeticOp::Sub => lhs.subtract(rhs.as_ref()),
+        ArithmeticOp::Mul => lhs.multiply(rhs.as_ref()),
+        ArithmeticOp::Div => lhs.divide(rhs.as_ref()),
+    }
+}
+
 impl ops::Sub for &Series {
     type Output = Series;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-        lhs.subtract(rhs.as_ref()).expect("data types don't match")
+        arithmetic(self, ArithmeticOp::Sub, rhs).expect("data types don't match")
     }
 }
 
@@ -136,8 +204,7 @@ impl ops::Add for &Series {
     type Output = Series;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-        lhs.add_to(rhs.as_ref()).expect("data types don't match")
+        arithmetic(self, ArithmeticOp::Add, rhs).expect("data types don't match")
     }
 }
 
@@ -150,8 +217,7 @@ impl ops::Mul for &Series {
     /// let out = &s * &s;
     /// ```
     fn mul(self, rhs: Self) -> Self::Output {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-        lhs.multiply(rhs.as_ref()).expect("data types don't match")
+        arithmetic(self, ArithmeticOp::Mul, rhs).expect("data types don't match")
     }
 }
 
@@ -164,8 +230,7 @@ impl ops::Div for &Series {
     /// let out = &s / &s;
     /// ```
     fn div(self, rhs: Self) -> Self::Output {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-        lhs.divide(rhs.as_ref()).expect("data types don't match")
+        arithmetic(self, ArithmeticOp::Div, rhs).expect("data types don't match")
     }
 }
 
@@ -529,4 +594,103 @@ mod test {
         let _ = s.minute().map(|m| m / 5);
         let _ = s.minute().map(|m| m.into_series() / 5);
     }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-date32",
+        feature = "dtype-date64",
+        feature = "dtype-duration-ms",
+        feature = "dtype-duration-ns"
+    ))]
+    fn test_arithmetic_temporal() -> Result<()> {
+        let ms = DataType::Duration(TimeUnit::Millisecond);
+        let a = Date64Chunked::new_from_slice("a", &[86_400_000, 1000]).into_series();
+        let b = Date64Chunked::new_from_slice("b", &[0, 3000]).into_series();
+
+        // date - date = duration
+        let diff = &a - &b;
+        assert_eq!(diff.dtype(), &ms);
+        assert_eq!(diff.name(), "a");
+        assert_eq!(
+            Vec::from(diff.cast::<Int64Type>()?.i64()?),
+            &[Some(86_400_000), Some(-2000)]
+        );
+
+        // date ± duration = date
+        let out = &b + &diff;
+        assert_eq!(out.dtype(), &DataType::Date64);
+        assert!(out
+            .cast::<Int64Type>()?
+            .series_equal(&a.cast::<Int64Type>()?));
+        assert_eq!((&diff + &b).dtype(), &DataType::Date64);
+        let out = &a - &diff;
+        assert_eq!(
+            Vec::from(out.cast::<Int64Type>()?.i64()?),
+            &[Some(0), Some(3000)]
+        );
+
+        // date32 is in days
+        let days = Date32Chunked::new_from_slice("days", &[1, 2]).into_series();
+        let out = &days - &days;
+        assert_eq!(out.dtype(), &ms);
+        let out = &days + &diff;
+        assert_eq!(out.dtype(), &DataType::Date64);
+        assert_eq!(
+            Vec::from(out.cast::<Int64Type>()?.i64()?),
+            &[Some(2 * 86_400_000), Some(2 * 86_400_000 - 2000)]
+        );
+
+        // duration ± duration = duration in the finest unit
+        let ns = Series::new("ns", &[1_000_000i64, 2_000_000])
+            .cast_with_dtype(&DataType::Duration(TimeUnit::Nanosecond))?;
+        let out = &diff + &ns;
+        assert_eq!(out.dtype(), &DataType::Duration(TimeUnit::Nanosecond));
+        assert_eq!(
+            Vec::from(out.cast::<Int64Type>()?.i64()?),
+            &[Some(86_400_001_000_000), Some(-1_998_000_000)]
+        );
+        assert_eq!((&diff - &diff).dtype(), &ms);
+
+        // duration * number = duration
+        let factor = Series::new("factor", &[2i32, 3]);
+        let out = &diff * &factor;
+        assert_eq!(out.dtype(), &ms);
+        assert_eq!(
+            Vec::from(out.cast::<Int64Type>()?.i64()?),
+            &[Some(2 * 86_400_000), Some(-6000)]
+        );
+        assert_eq!((&factor * &diff).dtype(), &ms);
+        let out = &diff / &Series::new("", &[2.0f64, 4.0]);
+        assert_eq!(out.dtype(), &ms);
+        assert_eq!(
+            Vec::from(out.cast::<Int64Type>()?.i64()?),
+            &[Some(43_200_000), Some(-500)]
+        );
+
+        // fractional results are truncated toward zero
+        let out = &diff * &Series::new("", &[1.5e-8f64, 0.00075]);
+        assert_eq!(
+            Vec::from(out.cast::<Int64Type>()?.i64()?),
+            &[Some(1), Some(-1)]
+        );
+        let out = &diff / &Series::new("", &[7i64, 3]);
+        assert_eq!(
+            Vec::from(out.cast::<Int64Type>()?.i64()?),
+            &[Some(12_342_857), Some(-666)]
+        );
+        let out = &b + &ns;
+        assert_eq!(out.dtype(), &DataType::Date64);
+        assert_eq!(
+            Vec::from(out.cast::<Int64Type>()?.i64()?),
+            &[Some(1), Some(3002)]
+        );
+        let sub_ms = Series::new("ns", &[1_500_000i64, -1_500_000])
+            .cast_with_dtype(&DataType::Duration(TimeUnit::Nanosecond))?;
+        let out = &b + &sub_ms;
+        assert_eq!(
+            Vec::from(out.cast::<Int64Type>()?.i64()?),
+            &[Some(1), Some(2999)]
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,127 @@
+//! Clip the values of a Series to a range.
+use crate::prelude::*;
+use num::NumCast;
+
+fn cast_bound<T>(bound: f64) -> Result<T>
+where
+    T: NumCast,
+{
+    NumCast::from(bound).ok_or_else(|| {
+        PolarsError::ValueError(
+            format!(
+                "clip bound {} does not fit in the dtype of the Series",
+                bound
+            )
+            .into(),
+        )
+    })
+}
+
+fn clip_ca<T>(ca: &ChunkedArray<T>, min: Option<f64>, max: Option<f64>) -> Result<Series>
+where
+    T: PolarsNumericType,
+    T::Native: NumCast + PartialOrd,
+    ChunkedArray<T>: IntoSeries,
+{
+    let min = min.map(cast_bound::<T::Native>).transpose()?;
+    let max = max.map(cast_bound::<T::Native>).transpose()?;
+    let out = ca.apply(|v| match (min, max) {
+        (Some(min), _) if v < min => min,
+        (_, Some(max)) if v > max => max,
+        _ => v,
+    });
+    Ok(out.into_series())
+}
+
+impl Series {
+    fn clip_impl(&self, min: Option<f64>, max: Option<f64>) -> Result<Series> {
+        use DataType::*;
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                return Err(PolarsError::ValueError(
+                    format!(
+                        "clip lower bound {} is larger than upper bound {}",
+                        min, max
+                    )
+                    .into(),
+                ));
+            }
+        }
+        match self.dtype() {
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64 => {
+                macro_rules! clip {
+                    ($ca:expr) => {{
+                        clip_ca($ca, min, max)
+                    }};
+                }
+                macro_rules! noop {
+                    ($ca:expr) => {{
+                        unreachable!()
+                    }};
+                }
+                match_arrow_data_type_apply_macro_ca!(self, clip, noop, noop)
+            }
+            dt => Err(PolarsError::InvalidOperation(
+                format!("clip not supported for Series with dtype {:?}", dt).into(),
+            )),
+        }
+    }
+
+    /// Clip the values to the range `[min, max]`. Values below `min` are set to `min` and values
+    /// above `max` are set to `max`. Null values stay null.
+    ///
+    /// The bounds are cast to the dtype of the Series, which fails if they are not representable.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[Some(-5), Some(2), None, Some(8)]);
+    /// let out = s.clip(0.0, 4.0).unwrap();
+    /// assert_eq!(Vec::from(out.i32().unwrap()), &[Some(0), Some(2), None, Some(4)]);
+    /// ```
+    pub fn clip(&self, min: f64, max: f64) -> Result<Series> {
+        self.clip_impl(Some(min), Some(max))
+    }
+
+    /// Clip the values that are below `min` to `min`.
+    pub fn clip_min(&self, min: f64) -> Result<Series> {
+        self.clip_impl(Some(min), None)
+    }
+
+    /// Clip the values that are above `max` to `max`.
+    pub fn clip_max(&self, max: f64) -> Result<Series> {
+        self.clip_impl(None, Some(max))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clip() -> Result<()> {
+        let s = Series::new("a", &[Some(-1.5), None, Some(0.5), Some(3.0)]);
+        let out = s.clip(0.0, 1.0)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(0.0), None, Some(0.5), Some(1.0)]
+        );
+        let out = s.clip_min(0.0)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(0.0), None, Some(0.5), Some(3.0)]
+        );
+        let out = s.clip_max(0.0)?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(-1.5), None, Some(0.0), Some(0.0)]
+        );
+
+        assert!(s.clip(1.0, 0.0).is_err());
+        let s = Series::new("a", &[1u32, 2]);
+        assert!(s.clip_min(-1.0).is_err());
+        let s = Series::new("a", &["a", "b"]);
+        assert!(s.clip(0.0, 1.0).is_err());
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ use super::Series;
 use crate::apply_method_numeric_series;
 use crate::prelude::*;
 use crate::series::arithmetic::coerce_lhs_rhs;
+use std::borrow::Cow;
 
 macro_rules! impl_compare {
     ($self:expr, $rhs:expr, $method:ident) => {{
@@ -35,50 +36,85 @@ macro_rules! impl_compare {
                 .unwrap()
                 .$method($rhs.duration_millisecond().unwrap()),
             DataType::List(_) => $self.list().unwrap().$method($rhs.list().unwrap()),
+            // the categories may be encoded by different mappings, so we compare the strings
+            DataType::Categorical => $self
+                .cast::<Utf8Type>()
+                .unwrap()
+                .utf8()
+                .unwrap()
+                .$method($rhs.cast::<Utf8Type>().unwrap().utf8().unwrap()),
+            // after coercion both sides have the same scale, so the unscaled values can be compared
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => $self
+                .decimal()
+                .unwrap()
+                .physical()
+                .$method(&$rhs.decimal().unwrap().physical()),
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => $self.binary().unwrap().$method($rhs.binary().unwrap()),
+            // the values are UTC timestamps, so the time zones don't matter
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => $self.datetime().unwrap().$method($rhs.datetime().unwrap()),
             _ => unimplemented!(),
         }
     }};
 }
 
+/// Coerce both sides of a comparison to the same dtype. Decimals are compared by their unscaled
+/// values at the larger scale of both sides, so that values that don't fit a common decimal type
+/// can still be compared.
+fn coerce_compare<'a>(lhs: &'a Series, rhs: &'a Series) -> (Cow<'a, Series>, Cow<'a, Series>) {
+    #[cfg(feature = "dtype-decimal")]
+    {
+        if let Some((l, r)) = lhs.decimal().ok().and_then(|l| l.unscaled_keys(rhs)) {
+            return (Cow::Owned(l.into_series()), Cow::Owned(r.into_series()));
+        }
+        if let Some((r, l)) = rhs.decimal().ok().and_then(|r| r.unscaled_keys(lhs)) {
+            return (Cow::Owned(l.into_series()), Cow::Owned(r.into_series()));
+        }
+    }
+    coerce_lhs_rhs(lhs, rhs).expect("cannot coerce datatypes")
+}
+
 impl ChunkCompare<&Series> for Series {
     fn eq_missing(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_compare(self, rhs);
         impl_compare!(lhs.as_ref(), rhs.as_ref(), eq_missing)
     }
 
     /// Create a boolean mask by checking for equality.
     fn eq(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_compare(self, rhs);
         impl_compare!(lhs.as_ref(), rhs.as_ref(), eq)
     }
 
     /// Create a boolean mask by checking for inequality.
     fn neq(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_compare(self, rhs);
         impl_compare!(lhs.as_ref(), rhs.as_ref(), neq)
     }
 
     /// Create a boolean mask by checking if lhs > rhs.
     fn gt(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_compare(self, rhs);
         impl_compare!(lhs.as_ref(), rhs.as_ref(), gt)
     }
 
     /// Create a boolean mask by checking if lhs >= rhs.
     fn gt_eq(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_compare(self, rhs);
         impl_compare!(lhs.as_ref(), rhs.as_ref(), gt_eq)
     }
 
     /// Create a boolean mask by checking if lhs < rhs.
     fn lt(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_compare(self, rhs);
         impl_compare!(lhs.as_ref(), rhs.as_ref(), lt)
     }
 
     /// Create a boolean mask by checking if lhs <= rhs.
     fn lt_eq(&self, rhs: &Series) -> BooleanChunked {
-        let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_compare(self, rhs);
         impl_compare!(lhs.as_ref(), rhs.as_ref(), lt_eq)
     }
 }
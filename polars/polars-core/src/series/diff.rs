@@ -0,0 +1,108 @@
+//! Differences between the values of a Series and the values `n` rows before them.
+use crate::prelude::*;
+use std::borrow::Cow;
+
+/// What to do with the first rows of a [diff](crate::series::Series::diff), that don't have a
+/// value `n` rows before them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NullBehavior {
+    /// Keep the rows with a null value, so that the output has the length of the input.
+    Ignore,
+    /// Drop the rows.
+    Drop,
+}
+
+fn check_numeric_or_temporal(s: &Series, operation: &str) -> Result<()> {
+    use DataType::*;
+    match s.dtype() {
+        UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64
+        | Date32 | Date64 | Time64(_) | Duration(_) => Ok(()),
+        dt => Err(PolarsError::InvalidOperation(
+            format!("{} not supported for Series with dtype {:?}", operation, dt).into(),
+        )),
+    }
+}
+
+impl Series {
+    /// Compute the difference between every value and the value `n` rows before it.
+    ///
+    /// Unsigned integers are subtracted in a signed type that can hold negative differences:
+    /// `UInt8` and `UInt16` in `Int32`, `UInt32` and `UInt64` in `Int64`.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1, 4, 9, 16]);
+    /// let out = s.diff(1, NullBehavior::Ignore).unwrap();
+    /// assert_eq!(Vec::from(out.i32().unwrap()), &[None, Some(3), Some(5), Some(7)]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "diff")))]
+    pub fn diff(&self, n: usize, null_behavior: NullBehavior) -> Result<Series> {
+        check_numeric_or_temporal(self, "diff")?;
+        let s = match self.dtype() {
+            DataType::UInt8 | DataType::UInt16 => Cow::Owned(self.cast::<Int32Type>()?),
+            DataType::UInt32 | DataType::UInt64 => Cow::Owned(self.cast::<Int64Type>()?),
+            _ => Cow::Borrowed(self),
+        };
+        let mut out = s.as_ref() - &s.shift(n as i64);
+        out.rename(self.name());
+        match null_behavior {
+            NullBehavior::Ignore => Ok(out),
+            NullBehavior::Drop => Ok(out.slice(n as i64, self.len().saturating_sub(n))),
+        }
+    }
+
+    /// Compute the fractional change between every value and the value `n` rows before it.
+    /// The first `n` rows are null.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1, 2, 3, 6]);
+    /// let out = s.pct_change(1).unwrap();
+    /// assert_eq!(Vec::from(out.f64().unwrap()), &[None, Some(1.0), Some(0.5), Some(1.0)]);
+    /// ```
+    #[cfg(feature = "pct_change")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pct_change")))]
+    pub fn pct_change(&self, n: usize) -> Result<Series> {
+        check_numeric_or_temporal(self, "pct_change")?;
+        let s = self.to_physical_repr().cast::<Float64Type>()?;
+        let shifted = s.shift(n as i64);
+        let mut out = &(&s - &shifted) / &shifted;
+        out.rename(self.name());
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diff() -> Result<()> {
+        let s = Series::new("a", &[Some(1.0), Some(3.0), None, Some(10.0), Some(12.0)]);
+        let out = s.diff(2, NullBehavior::Ignore)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.f64()?), &[None, None, None, Some(7.0), None]);
+        let out = s.diff(1, NullBehavior::Drop)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(2.0), None, None, Some(2.0)]);
+
+        // unsigned integers don't underflow
+        let s = Series::new("a", &[3u32, 1, 4]);
+        let out = s.diff(1, NullBehavior::Drop)?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(-2), Some(3)]);
+
+        let s = Series::new("a", &["a", "b"]);
+        assert!(s.diff(1, NullBehavior::Ignore).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "pct_change")]
+    fn test_pct_change() -> Result<()> {
+        let s = Series::new("a", &[Some(2), Some(3), None, Some(6)]);
+        let out = s.pct_change(1)?;
+        assert_eq!(Vec::from(out.f64()?), &[None, Some(0.5), None, None]);
+        let out = s.pct_change(3)?;
+        assert_eq!(Vec::from(out.f64()?), &[None, None, None, Some(2.0)]);
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+//! Exchange Series with other Arrow implementations in the same process through the
+//! [Arrow C data interface](https://arrow.apache.org/docs/format/CDataInterface.html).
+use crate::prelude::*;
+use arrow::array::{make_array_from_raw, Array};
+pub use arrow::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+use std::convert::TryFrom;
+
+impl Series {
+    /// Export the Series as an Arrow array through the C data interface. The buffers are shared
+    /// with the consumer, not copied. A Series with multiple chunks is rechunked first, and a
+    /// `Categorical` Series is exported as `Utf8`.
+    ///
+    /// The consumer owns the returned structs and must call their `release` callback once it no
+    /// longer needs the data.
+    pub fn to_arrow_ffi(&self) -> Result<(*const FFI_ArrowArray, *const FFI_ArrowSchema)> {
+        let s = match self.dtype() {
+            DataType::Categorical => self.cast::<Utf8Type>()?,
+            #[cfg(feature = "object")]
+            DataType::Object(_) => {
+                return Err(PolarsError::InvalidOperation(
+                    "a Series of dtype object cannot be exported to arrow".into(),
+                ))
+            }
+            _ => self.rechunk(),
+        };
+        let arr = s
+            .chunks()
+            .first()
+            .ok_or_else(|| PolarsError::NoData("cannot export a Series without chunks".into()))?;
+        Ok(arr.to_raw()?)
+    }
+
+    /// Import an Arrow array through the C data interface. The buffers are shared with the
+    /// producer if the Arrow type is the same as the physical type of the Series, otherwise the
+    /// array is cast, e.g. `Utf8` to `LargeUtf8`.
+    ///
+    /// # Safety
+    /// `array` and `schema` must point to valid structs of the C data interface. The Series takes
+    /// ownership of them and calls their `release` callback when the data is dropped.
+    pub unsafe fn from_arrow_ffi(
+        name: &str,
+        array: *const FFI_ArrowArray,
+        schema: *const FFI_ArrowSchema,
+    ) -> Result<Series> {
+        let arr = make_array_from_raw(array, schema)?;
+        Series::try_from((name, arr))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_arrow_ffi_roundtrip() -> Result<()> {
+        let mut s = Series::new("a", &[Some(1i64), None, Some(3)]);
+        s.append(&Series::new("a", &[4i64]))?;
+        let (array, schema) = s.to_arrow_ffi()?;
+        let out = unsafe { Series::from_arrow_ffi("a", array, schema)? };
+        assert!(out.series_equal_missing(&s));
+
+        let s = Series::new("b", &[Some("foo"), None, Some("bar")]);
+        let (array, schema) = s.to_arrow_ffi()?;
+        let out = unsafe { Series::from_arrow_ffi("b", array, schema)? };
+        assert!(out.series_equal_missing(&s));
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+use super::private;
+use super::IntoSeries;
+use super::SeriesTrait;
+use super::SeriesWrap;
+use crate::chunked_array::{AsSinglePtr, ChunkIdIter};
+use crate::fmt::FmtList;
+use crate::frame::groupby::*;
+use crate::prelude::*;
+use ahash::RandomState;
+use arrow::array::{ArrayData, ArrayRef};
+use arrow::buffer::Buffer;
+use std::borrow::Cow;
+
+impl IntoSeries for BinaryChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<BinaryChunked> {
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        self.0.get_any_value_unchecked(idx_self) == other.get_unchecked(idx_other)
+    }
+
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> Result<Series> {
+        ChunkZip::zip_with(&self.0, mask, other.binary()?).map(|ca| ca.into_series())
+    }
+
+    fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
+        self.0.vec_hash(random_state)
+    }
+
+    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        self.0.agg_first(groups)
+    }
+
+    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        self.0.agg_last(groups)
+    }
+
+    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+        self.0.agg_n_unique(groups)
+    }
+
+    fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
+        IntoGroupTuples::group_tuples(&self.0, multithreaded)
+    }
+
+    fn str_value(&self, index: usize) -> Cow<str> {
+        Cow::Owned(format!("{}", self.get(index)))
+    }
+}
+
+impl SeriesTrait for SeriesWrap<BinaryChunked> {
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name);
+    }
+
+    fn array_data(&self) -> Vec<&ArrayData> {
+        self.0.array_data()
+    }
+
+    fn chunk_lengths(&self) -> ChunkIdIter {
+        self.0.chunk_id()
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn field(&self) -> &Field {
+        self.0.ref_field()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+
+    fn binary(&self) -> Result<&BinaryChunked> {
+        unsafe { Ok(&*(self as *const dyn SeriesTrait as *const BinaryChunked)) }
+    }
+
+    fn append_array(&mut self, other: ArrayRef) -> Result<()> {
+        self.0.append_array(other)
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+
+    fn append(&mut self, other: &Series) -> Result<()> {
+        if self.0.dtype() == other.dtype() {
+            self.0.append(other.binary()?);
+            Ok(())
+        } else {
+            Err(PolarsError::DataTypeMisMatch(
+                "cannot append Series; data types don't match".into(),
+            ))
+        }
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
+        ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
+    }
+
+    fn take(&self, indices: &UInt32Chunked) -> Series {
+        let indices = if indices.chunks.len() > 1 {
+            Cow::Owned(indices.rechunk())
+        } else {
+            Cow::Borrowed(indices)
+        };
+        ChunkTake::take(&self.0, (&*indices).into()).into_series()
+    }
+
+    fn take_iter(&self, iter: &mut dyn Iterator<Item = usize>) -> Series {
+        ChunkTake::take(&self.0, iter.into()).into_series()
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        self.0.take_every(n).into_series()
+    }
+
+    unsafe fn take_iter_unchecked(&self, iter: &mut dyn Iterator<Item = usize>) -> Series {
+        ChunkTake::take_unchecked(&self.0, iter.into()).into_series()
+    }
+
+    unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Result<Series> {
+        let idx = if idx.chunks.len() > 1 {
+            Cow::Owned(idx.rechunk())
+        } else {
+            Cow::Borrowed(idx)
+        };
+        Ok(ChunkTake::take_unchecked(&self.0, (&*idx).into()).into_series())
+    }
+
+    unsafe fn take_opt_iter_unchecked(
+        &self,
+        iter: &mut dyn Iterator<Item = Option<usize>>,
+    ) -> Series {
+        ChunkTake::take_unchecked(&self.0, SeriesWrap(iter).into()).into_series()
+    }
+
+    fn take_opt_iter(&self, iter: &mut dyn Iterator<Item = Option<usize>>) -> Series {
+        ChunkTake::take(&self.0, SeriesWrap(iter).into()).into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        ChunkOps::rechunk(&self.0).into_series()
+    }
+
+    fn head(&self, length: Option<usize>) -> Series {
+        self.0.head(length).into_series()
+    }
+
+    fn tail(&self, length: Option<usize>) -> Series {
+        self.0.tail(length).into_series()
+    }
+
+    fn expand_at_index(&self, index: usize, length: usize) -> Series {
+        ChunkExpandAtIndex::expand_at_index(&self.0, index, length).into_series()
+    }
+
+    fn cast_with_dtype(&self, data_type: &DataType) -> Result<Series> {
+        self.0.cast_with_dtype(data_type)
+    }
+
+    fn value_counts(&self, include_nulls: bool) -> Result<DataFrame> {
+        ChunkUnique::value_counts(&self.0, include_nulls)
+    }
+
+    fn get(&self, index: usize) -> AnyValue {
+        self.0.get_any_value(index)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort(&self, reverse: bool) -> Series {
+        ChunkSort::sort(&self.0, reverse).into_series()
+    }
+
+    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+        ChunkSort::argsort(&self.0, reverse)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn unique(&self) -> Result<Series> {
+        ChunkUnique::unique(&self.0).map(|ca| ca.into_series())
+    }
+
+    fn n_unique(&self) -> Result<usize> {
+        ChunkUnique::n_unique(&self.0)
+    }
+
+    fn arg_unique(&self) -> Result<UInt32Chunked> {
+        ChunkUnique::arg_unique(&self.0)
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn is_unique(&self) -> Result<BooleanChunked> {
+        ChunkUnique::is_unique(&self.0)
+    }
+
+    fn is_duplicated(&self) -> Result<BooleanChunked> {
+        ChunkUnique::is_duplicated(&self.0)
+    }
+
+    fn null_bits(&self) -> Vec<(usize, Option<Buffer>)> {
+        self.0.null_bits().collect()
+    }
+
+    fn reverse(&self) -> Series {
+        ChunkReverse::reverse(&self.0).into_series()
+    }
+
+    fn as_single_ptr(&mut self) -> Result<usize> {
+        self.0.as_single_ptr()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        ChunkShift::shift(&self.0, periods).into_series()
+    }
+
+    fn fmt_list(&self) -> String {
+        FmtList::fmt_list(&self.0)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_binary_series() -> Result<()> {
+        let ca = BinaryChunked::new_from_opt_slice(
+            "bytes",
+            &[
+                Some(&b"\xff"[..]),
+                Some(&b"ab"[..]),
+                None,
+                Some(&b"\xff"[..]),
+            ],
+        );
+        let df = DataFrame::new(vec![ca.into_series(), Series::new("idx", &[1, 2, 3, 4])])?;
+
+        let mask = BooleanChunked::new_from_slice("", &[true, false, true, true]);
+        let out = df.filter(&mask)?;
+        let bytes = out.column("bytes")?.binary()?;
+        assert_eq!(bytes.get(0), Some(&b"\xff"[..]));
+        assert_eq!(bytes.get(1), None);
+
+        let out = df.groupby("bytes")?.select("idx").sum()?;
+        let out = out.sort("idx_sum", false)?;
+        assert_eq!(out.column("bytes")?.dtype(), &DataType::Binary);
+        assert_eq!(
+            Vec::from(out.column("idx_sum")?.i32()?),
+            &[Some(2), Some(3), Some(5)]
+        );
+        assert_eq!(out.column("bytes")?.binary()?.get(2), Some(&b"\xff"[..]));
+
+        let sorted = df.column("bytes")?.sort(false);
+        assert_eq!(sorted.binary()?.get(1), Some(&b"ab"[..]));
+        Ok(())
+    }
+}
@@ -27,6 +27,8 @@ impl<T> ChunkedArray<T> {
         match self.dtype() {
             DataType::Duration(_) | DataType::Date64 | DataType::Time64(_) => DataType::Int64,
             DataType::Date32 => DataType::Int32,
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => DataType::Int64,
             dt => panic!("already a physical type: {:?}", dt),
         }
     }
@@ -163,13 +165,31 @@ macro_rules! impl_dyn_series {
                 self.0.agg_list(groups)
             }
 
-            fn agg_quantile(&self, groups: &[(u32, Vec<u32>)], quantile: f64) -> Option<Series> {
-                opt_physical_dispatch!(self, agg_quantile, groups, quantile)
+            fn agg_quantile(
+                &self,
+                groups: &[(u32, Vec<u32>)],
+                quantile: f64,
+                interpol: QuantileInterpolOptions,
+            ) -> Option<Series> {
+                opt_physical_dispatch!(self, agg_quantile, groups, quantile, interpol)
             }
 
             fn agg_median(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_median, groups)
             }
+
+            fn agg_skew(&self, groups: &[(u32, Vec<u32>)], bias: bool) -> Option<Series> {
+                opt_physical_dispatch!(self, agg_skew, groups, bias)
+            }
+
+            fn agg_kurtosis(
+                &self,
+                groups: &[(u32, Vec<u32>)],
+                fisher: bool,
+                bias: bool,
+            ) -> Option<Series> {
+                opt_physical_dispatch!(self, agg_kurtosis, groups, fisher, bias)
+            }
             #[cfg(feature = "lazy")]
             fn agg_valid_count(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
                 opt_physical_dispatch!(self, agg_valid_count, groups)
@@ -315,6 +335,22 @@ macro_rules! impl_dyn_series {
                 }
             }
 
+            #[cfg(feature = "timezones")]
+            fn datetime(&self) -> Result<&DatetimeChunked> {
+                if matches!(self.0.dtype(), DataType::Datetime(_)) {
+                    unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DatetimeChunked)) }
+                } else {
+                    Err(PolarsError::DataTypeMisMatch(
+                        format!(
+                            "cannot unpack Series: {:?} of type {:?} into datetime",
+                            self.name(),
+                            self.dtype(),
+                        )
+                        .into(),
+                    ))
+                }
+            }
+
             fn time64_nanosecond(&self) -> Result<&Time64NanosecondChunked> {
                 if matches!(self.0.dtype(), DataType::Time64(TimeUnit::Nanosecond)) {
                     unsafe {
@@ -457,8 +493,12 @@ macro_rules! impl_dyn_series {
                 cast_and_apply!(self, to_dummies,)
             }
 
-            fn value_counts(&self) -> Result<DataFrame> {
-                cast_and_apply!(self, value_counts,)
+            fn value_counts(&self, include_nulls: bool) -> Result<DataFrame> {
+                let mut df = cast_and_apply!(self, value_counts, include_nulls)?;
+                let name = self.name().to_string();
+                let values = df.column(&name)?.cast_with_dtype(self.dtype())?;
+                df.replace(&name, values)?;
+                Ok(df)
             }
 
             fn get(&self, index: usize) -> AnyValue {
@@ -568,8 +608,12 @@ macro_rules! impl_dyn_series {
             fn std_as_series(&self) -> Series {
                 physical_dispatch!(self, std_as_series,)
             }
-            fn quantile_as_series(&self, quantile: f64) -> Result<Series> {
-                try_physical_dispatch!(self, quantile_as_series, quantile)
+            fn quantile_as_series(
+                &self,
+                quantile: f64,
+                interpol: QuantileInterpolOptions,
+            ) -> Result<Series> {
+                try_physical_dispatch!(self, quantile_as_series, quantile, interpol)
             }
             fn rolling_mean(
                 &self,
@@ -635,6 +679,38 @@ macro_rules! impl_dyn_series {
                     min_periods
                 )
             }
+            fn rolling_var(
+                &self,
+                window_size: u32,
+                weight: Option<&[f64]>,
+                ignore_null: bool,
+                min_periods: u32,
+            ) -> Result<Series> {
+                try_physical_dispatch!(
+                    self,
+                    rolling_var,
+                    window_size,
+                    weight,
+                    ignore_null,
+                    min_periods
+                )
+            }
+            fn rolling_std(
+                &self,
+                window_size: u32,
+                weight: Option<&[f64]>,
+                ignore_null: bool,
+                min_periods: u32,
+            ) -> Result<Series> {
+                try_physical_dispatch!(
+                    self,
+                    rolling_std,
+                    window_size,
+                    weight,
+                    ignore_null,
+                    min_periods
+                )
+            }
 
             fn fmt_list(&self) -> String {
                 FmtList::fmt_list(&self.0)
@@ -646,14 +722,40 @@ macro_rules! impl_dyn_series {
 
             #[cfg(feature = "random")]
             #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-            fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
-                try_physical_dispatch!(self, sample_n, n, with_replacement)
+            fn sample_n_weighted(
+                &self,
+                n: usize,
+                with_replacement: bool,
+                shuffle: bool,
+                weights: Option<&Series>,
+            ) -> Result<Series> {
+                try_physical_dispatch!(
+                    self,
+                    sample_n_weighted,
+                    n,
+                    with_replacement,
+                    shuffle,
+                    weights
+                )
             }
 
             #[cfg(feature = "random")]
             #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-            fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
-                try_physical_dispatch!(self, sample_frac, frac, with_replacement)
+            fn sample_frac_weighted(
+                &self,
+                frac: f64,
+                with_replacement: bool,
+                shuffle: bool,
+                weights: Option<&Series>,
+            ) -> Result<Series> {
+                try_physical_dispatch!(
+                    self,
+                    sample_frac_weighted,
+                    frac,
+                    with_replacement,
+                    shuffle,
+                    weights
+                )
             }
 
             fn pow(&self, exponent: f64) -> Result<Series> {
@@ -685,6 +787,8 @@ impl_dyn_series!(Date32Chunked);
 impl_dyn_series!(Date64Chunked);
 #[cfg(feature = "dtype-time64-ns")]
 impl_dyn_series!(Time64NanosecondChunked);
+#[cfg(feature = "timezones")]
+impl_dyn_series!(DatetimeChunked);
 
 #[cfg(test)]
 mod test {
@@ -738,4 +842,20 @@ mod test {
         assert!(matches!(out.dtype(), DataType::Date64));
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn test_datetime_keeps_time_zone() -> Result<()> {
+        let s = Series::new("foo", &[3i64, 1, 2]);
+        let s = s.cast_with_dtype(&DataType::Datetime("Europe/Amsterdam".into()))?;
+        let dtype = s.dtype().clone();
+
+        assert_eq!(s.sort(false).dtype(), &dtype);
+        let mask = BooleanChunked::new_from_slice("", &[true, false, true]);
+        assert_eq!(s.filter(&mask)?.dtype(), &dtype);
+        let df = DataFrame::new(vec![s])?;
+        let out = df.left_join(&df.clone(), "foo", "foo")?;
+        assert_eq!(out.column("foo")?.dtype(), &dtype);
+        Ok(())
+    }
 }
@@ -0,0 +1,690 @@
+//! Decimals are backed by Arrow's `DecimalArray`. Arithmetic and most aggregations use the
+//! unscaled values directly. The other operations cast to the 64 bit unscaled values,
+//! apply the operation and (depending on the result) cast back to decimals.
+use super::private;
+use super::IntoSeries;
+use super::SeriesTrait;
+use super::SeriesWrap;
+use crate::chunked_array::decimal::div_round;
+use crate::chunked_array::{AsSinglePtr, ChunkIdIter};
+use crate::fmt::FmtList;
+use crate::frame::groupby::*;
+use crate::prelude::*;
+use ahash::RandomState;
+use arrow::array::{ArrayData, ArrayRef};
+use arrow::buffer::Buffer;
+use std::borrow::Cow;
+
+impl SeriesWrap<DecimalChunked> {
+    fn physical_series(&self) -> Series {
+        self.0.physical().into_series()
+    }
+
+    /// Cast a result of an operation on the physical values back to decimals.
+    fn from_physical(&self, s: Series) -> Series {
+        match s.dtype() {
+            DataType::Int64 => DecimalChunked::new_from_physical(
+                s.i64().unwrap(),
+                self.0.precision(),
+                self.0.scale(),
+            )
+            .unwrap()
+            .into_series(),
+            // the change of type is part of the operation.
+            _ => s,
+        }
+    }
+
+    /// Operations that return floats are done on the float representation of the decimals.
+    fn float_series(&self) -> Series {
+        self.0.to_f64().into_series()
+    }
+
+    fn unpack_rhs<'a>(&self, rhs: &'a Series) -> Result<&'a DecimalChunked> {
+        if self.0.dtype() == rhs.dtype() {
+            rhs.decimal()
+        } else {
+            Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "cannot apply an operation on decimals {:?} and {:?}",
+                    self.0.dtype(),
+                    rhs.dtype()
+                )
+                .into(),
+            ))
+        }
+    }
+}
+
+/// Dispatch the method call to the physical type and coerce back to decimals
+macro_rules! physical_dispatch {
+    ($s: expr, $method: ident, $($args:expr),*) => {{
+        let s = $s.physical_series().$method($($args),*);
+        $s.from_physical(s)
+    }}
+}
+
+macro_rules! try_physical_dispatch {
+    ($s: expr, $method: ident, $($args:expr),*) => {{
+        let s = $s.physical_series().$method($($args),*)?;
+        Ok($s.from_physical(s))
+    }}
+}
+
+macro_rules! opt_physical_dispatch {
+    ($s: expr, $method: ident, $($args:expr),*) => {{
+        let s = $s.physical_series().$method($($args),*)?;
+        Some($s.from_physical(s))
+    }}
+}
+
+/// Same as physical dispatch, but doesnt care about return type
+macro_rules! cast_and_apply {
+    ($s: expr, $method: ident, $($args:expr),*) => {{
+        $s.physical_series().$method($($args),*)
+    }}
+}
+
+impl IntoSeries for DecimalChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        self.0.get_any_value_unchecked(idx_self) == other.get_unchecked(idx_other)
+    }
+
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> Result<Series> {
+        let other = self.unpack_rhs(other)?.physical().into_series();
+        try_physical_dispatch!(self, zip_with_same_type, mask, &other)
+    }
+
+    fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
+        cast_and_apply!(self, vec_hash, random_state)
+    }
+
+    fn agg_mean(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        self.float_series().agg_mean(groups)
+    }
+
+    fn agg_min(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        opt_physical_dispatch!(self, agg_min, groups)
+    }
+
+    fn agg_max(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        opt_physical_dispatch!(self, agg_max, groups)
+    }
+
+    fn agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        opt_physical_dispatch!(self, agg_sum, groups)
+    }
+
+    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        physical_dispatch!(self, agg_first, groups)
+    }
+
+    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        physical_dispatch!(self, agg_last, groups)
+    }
+
+    fn agg_std(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        self.float_series().agg_std(groups)
+    }
+
+    fn agg_var(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        self.float_series().agg_var(groups)
+    }
+
+    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+        cast_and_apply!(self, agg_n_unique, groups)
+    }
+
+    fn agg_quantile(
+        &self,
+        groups: &[(u32, Vec<u32>)],
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Option<Series> {
+        self.float_series().agg_quantile(groups, quantile, interpol)
+    }
+
+    fn agg_median(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        self.float_series().agg_median(groups)
+    }
+
+    fn agg_skew(&self, groups: &[(u32, Vec<u32>)], bias: bool) -> Option<Series> {
+        self.float_series().agg_skew(groups, bias)
+    }
+
+    fn agg_kurtosis(&self, groups: &[(u32, Vec<u32>)], fisher: bool, bias: bool) -> Option<Series> {
+        self.float_series().agg_kurtosis(groups, fisher, bias)
+    }
+
+    #[cfg(feature = "lazy")]
+    fn agg_valid_count(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        cast_and_apply!(self, agg_valid_count, groups)
+    }
+
+    fn hash_join_inner(&self, other: &Series) -> Vec<(u32, u32)> {
+        match self.0.unscaled_keys(other) {
+            Some((l, r)) => l.hash_join_inner(&r),
+            None => vec![],
+        }
+    }
+    fn hash_join_left(&self, other: &Series) -> Vec<(u32, Option<u32>)> {
+        match self.0.unscaled_keys(other) {
+            Some((l, r)) => l.hash_join_left(&r),
+            None => (0..self.len() as u32).map(|idx| (idx, None)).collect(),
+        }
+    }
+    fn hash_join_outer(&self, other: &Series) -> Vec<(Option<u32>, Option<u32>)> {
+        match self.0.unscaled_keys(other) {
+            Some((l, r)) => l.hash_join_outer(&r),
+            None => (0..self.len() as u32)
+                .map(|idx| (Some(idx), None))
+                .chain((0..other.len() as u32).map(|idx| (None, Some(idx))))
+                .collect(),
+        }
+    }
+    fn zip_outer_join_column(
+        &self,
+        right_column: &Series,
+        opt_join_tuples: &[(Option<u32>, Option<u32>)],
+    ) -> Series {
+        // the join casts decimal keys to the same dtype, values of another dtype are missing
+        let right_column = match self.unpack_rhs(right_column) {
+            Ok(ca) => ca.physical().into_series(),
+            Err(_) => {
+                Int64Chunked::full_null(right_column.name(), right_column.len()).into_series()
+            }
+        };
+        physical_dispatch!(self, zip_outer_join_column, &right_column, opt_join_tuples)
+    }
+    fn subtract(&self, rhs: &Series) -> Result<Series> {
+        let rhs = self.unpack_rhs(rhs)?;
+        self.0
+            .binary_unscaled(rhs, |l, r| {
+                l.checked_sub(r)
+                    .ok_or_else(|| PolarsError::ValueError("decimal overflow".into()))
+            })
+            .map(|ca| ca.into_series())
+    }
+    fn add_to(&self, rhs: &Series) -> Result<Series> {
+        let rhs = self.unpack_rhs(rhs)?;
+        self.0
+            .binary_unscaled(rhs, |l, r| {
+                l.checked_add(r)
+                    .ok_or_else(|| PolarsError::ValueError("decimal overflow".into()))
+            })
+            .map(|ca| ca.into_series())
+    }
+    fn multiply(&self, rhs: &Series) -> Result<Series> {
+        let rhs = self.unpack_rhs(rhs)?;
+        // the product has twice the scale, round it back to the scale of the inputs.
+        let div = 10i128.pow(self.0.scale() as u32);
+        self.0
+            .binary_unscaled(rhs, |l, r| {
+                l.checked_mul(r)
+                    .map(|v| div_round(v, div))
+                    .ok_or_else(|| PolarsError::ValueError("decimal overflow".into()))
+            })
+            .map(|ca| ca.into_series())
+    }
+    fn divide(&self, rhs: &Series) -> Result<Series> {
+        let rhs = self.unpack_rhs(rhs)?;
+        let m = 10i128.pow(self.0.scale() as u32);
+        self.0
+            .binary_unscaled(rhs, |l, r| {
+                if r == 0 {
+                    return Err(PolarsError::ValueError("decimal division by zero".into()));
+                }
+                l.checked_mul(m)
+                    .map(|v| div_round(v, r))
+                    .ok_or_else(|| PolarsError::ValueError("decimal overflow".into()))
+            })
+            .map(|ca| ca.into_series())
+    }
+    fn remainder(&self, rhs: &Series) -> Result<Series> {
+        let rhs = self.unpack_rhs(rhs)?;
+        self.0
+            .binary_unscaled(rhs, |l, r| {
+                l.checked_rem(r)
+                    .ok_or_else(|| PolarsError::ValueError("decimal division by zero".into()))
+            })
+            .map(|ca| ca.into_series())
+    }
+    fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
+        cast_and_apply!(self, group_tuples, multithreaded)
+    }
+    #[cfg(feature = "sort_multiple")]
+    fn argsort_multiple(&self, by: &[Series], reverse: &[bool]) -> Result<UInt32Chunked> {
+        let s = self.physical_series();
+        s.i64()?.argsort_multiple(by, reverse)
+    }
+
+    fn str_value(&self, index: usize) -> Cow<str> {
+        Cow::Owned(format!("{}", self.get(index)))
+    }
+}
+
+impl SeriesTrait for SeriesWrap<DecimalChunked> {
+    fn cum_max(&self, reverse: bool) -> Series {
+        physical_dispatch!(self, cum_max, reverse)
+    }
+
+    fn cum_min(&self, reverse: bool) -> Series {
+        physical_dispatch!(self, cum_min, reverse)
+    }
+
+    fn cum_sum(&self, reverse: bool) -> Series {
+        physical_dispatch!(self, cum_sum, reverse)
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name);
+    }
+
+    fn array_data(&self) -> Vec<&ArrayData> {
+        self.0.array_data()
+    }
+
+    fn chunk_lengths(&self) -> ChunkIdIter {
+        self.0.chunk_id()
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn field(&self) -> &Field {
+        self.0.ref_field()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+
+    fn decimal(&self) -> Result<&DecimalChunked> {
+        unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DecimalChunked)) }
+    }
+
+    fn append_array(&mut self, other: ArrayRef) -> Result<()> {
+        self.0.append_array(other)
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.0.to_f64().mean()
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.0.to_f64().median()
+    }
+
+    fn append(&mut self, other: &Series) -> Result<()> {
+        if self.0.dtype() == other.dtype() {
+            self.0.append(other.decimal()?);
+            Ok(())
+        } else {
+            Err(PolarsError::DataTypeMisMatch(
+                "cannot append Series; data types don't match".into(),
+            ))
+        }
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
+        try_physical_dispatch!(self, filter, filter)
+    }
+
+    fn take(&self, indices: &UInt32Chunked) -> Series {
+        physical_dispatch!(self, take, indices)
+    }
+
+    fn take_iter(&self, iter: &mut dyn Iterator<Item = usize>) -> Series {
+        physical_dispatch!(self, take_iter, iter)
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        physical_dispatch!(self, take_every, n)
+    }
+
+    unsafe fn take_iter_unchecked(&self, iter: &mut dyn Iterator<Item = usize>) -> Series {
+        physical_dispatch!(self, take_iter_unchecked, iter)
+    }
+
+    unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Result<Series> {
+        try_physical_dispatch!(self, take_unchecked, idx)
+    }
+
+    unsafe fn take_opt_iter_unchecked(
+        &self,
+        iter: &mut dyn Iterator<Item = Option<usize>>,
+    ) -> Series {
+        physical_dispatch!(self, take_opt_iter_unchecked, iter)
+    }
+
+    fn take_opt_iter(&self, iter: &mut dyn Iterator<Item = Option<usize>>) -> Series {
+        physical_dispatch!(self, take_opt_iter, iter)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        physical_dispatch!(self, rechunk,)
+    }
+
+    fn head(&self, length: Option<usize>) -> Series {
+        self.0.head(length).into_series()
+    }
+
+    fn tail(&self, length: Option<usize>) -> Series {
+        self.0.tail(length).into_series()
+    }
+
+    fn expand_at_index(&self, index: usize, length: usize) -> Series {
+        physical_dispatch!(self, expand_at_index, index, length)
+    }
+
+    fn cast_with_dtype(&self, data_type: &DataType) -> Result<Series> {
+        self.0.cast_with_dtype(data_type)
+    }
+
+    fn value_counts(&self, include_nulls: bool) -> Result<DataFrame> {
+        let mut df = cast_and_apply!(self, value_counts, include_nulls)?;
+        let name = self.name().to_string();
+        let values = self.from_physical(df.column(&name)?.clone());
+        df.replace(&name, values)?;
+        Ok(df)
+    }
+
+    fn get(&self, index: usize) -> AnyValue {
+        self.0.get_any_value(index)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort(&self, reverse: bool) -> Series {
+        physical_dispatch!(self, sort, reverse)
+    }
+
+    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+        cast_and_apply!(self, argsort, reverse)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn unique(&self) -> Result<Series> {
+        try_physical_dispatch!(self, unique,)
+    }
+
+    fn n_unique(&self) -> Result<usize> {
+        cast_and_apply!(self, n_unique,)
+    }
+
+    fn arg_unique(&self) -> Result<UInt32Chunked> {
+        cast_and_apply!(self, arg_unique,)
+    }
+
+    fn arg_min(&self) -> Option<usize> {
+        cast_and_apply!(self, arg_min,)
+    }
+
+    fn arg_max(&self) -> Option<usize> {
+        cast_and_apply!(self, arg_max,)
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        cast_and_apply!(self, is_null,)
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        cast_and_apply!(self, is_not_null,)
+    }
+
+    fn is_unique(&self) -> Result<BooleanChunked> {
+        cast_and_apply!(self, is_unique,)
+    }
+
+    fn is_duplicated(&self) -> Result<BooleanChunked> {
+        cast_and_apply!(self, is_duplicated,)
+    }
+
+    fn null_bits(&self) -> Vec<(usize, Option<Buffer>)> {
+        self.0.null_bits().collect()
+    }
+
+    fn reverse(&self) -> Series {
+        physical_dispatch!(self, reverse,)
+    }
+
+    fn as_single_ptr(&mut self) -> Result<usize> {
+        self.0.as_single_ptr()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        physical_dispatch!(self, shift, periods)
+    }
+
+    fn fill_none(&self, strategy: FillNoneStrategy) -> Result<Series> {
+        try_physical_dispatch!(self, fill_none, strategy)
+    }
+
+    fn sum_as_series(&self) -> Series {
+        // sum the unscaled values in 128 bits so that the sum cannot overflow
+        let sum = match self.0.null_count() == self.0.len() {
+            true => None,
+            false => Some(self.0.iter_unscaled().flatten().sum::<i128>()),
+        };
+        DecimalChunked::from_unscaled(
+            self.name(),
+            std::iter::once(sum),
+            self.0.precision(),
+            self.0.scale(),
+        )
+        .unwrap()
+        .into_series()
+    }
+    fn max_as_series(&self) -> Series {
+        let max = self.0.iter_unscaled().flatten().max();
+        DecimalChunked::from_unscaled(
+            self.name(),
+            std::iter::once(max),
+            self.0.precision(),
+            self.0.scale(),
+        )
+        .unwrap()
+        .into_series()
+    }
+    fn min_as_series(&self) -> Series {
+        let min = self.0.iter_unscaled().flatten().min();
+        DecimalChunked::from_unscaled(
+            self.name(),
+            std::iter::once(min),
+            self.0.precision(),
+            self.0.scale(),
+        )
+        .unwrap()
+        .into_series()
+    }
+    fn mean_as_series(&self) -> Series {
+        self.float_series().mean_as_series()
+    }
+    fn median_as_series(&self) -> Series {
+        self.float_series().median_as_series()
+    }
+    fn var_as_series(&self) -> Series {
+        self.float_series().var_as_series()
+    }
+    fn std_as_series(&self) -> Series {
+        self.float_series().std_as_series()
+    }
+    fn quantile_as_series(
+        &self,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
+        self.float_series().quantile_as_series(quantile, interpol)
+    }
+
+    fn fmt_list(&self) -> String {
+        FmtList::fmt_list(&self.0)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    fn sample_n_weighted(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        weights: Option<&Series>,
+    ) -> Result<Series> {
+        try_physical_dispatch!(
+            self,
+            sample_n_weighted,
+            n,
+            with_replacement,
+            shuffle,
+            weights
+        )
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    fn sample_frac_weighted(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        weights: Option<&Series>,
+    ) -> Result<Series> {
+        try_physical_dispatch!(
+            self,
+            sample_frac_weighted,
+            frac,
+            with_replacement,
+            shuffle,
+            weights
+        )
+    }
+
+    fn peak_max(&self) -> BooleanChunked {
+        cast_and_apply!(self, peak_max,)
+    }
+
+    fn peak_min(&self) -> BooleanChunked {
+        cast_and_apply!(self, peak_min,)
+    }
+
+    #[cfg(feature = "is_in")]
+    fn is_in(&self, other: &Series) -> Result<BooleanChunked> {
+        let other = self.unpack_rhs(other)?.physical().into_series();
+        cast_and_apply!(self, is_in, &other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_decimal_arithmetic() -> Result<()> {
+        let a =
+            Series::new("a", &["1.50", "-2.25", "3"]).cast_with_dtype(&DataType::Decimal(10, 2))?;
+        let b =
+            Series::new("b", &["2", "0.5", "1.1"]).cast_with_dtype(&DataType::Decimal(10, 1))?;
+
+        let out = &a + &b;
+        assert_eq!(out.dtype(), &DataType::Decimal(11, 2));
+        assert_eq!(
+            Vec::from(&out.decimal()?.physical()),
+            &[Some(350), Some(-175), Some(410)]
+        );
+
+        let out = &a * &b;
+        assert_eq!(
+            Vec::from(&out.decimal()?.physical()),
+            &[Some(300), Some(-113), Some(330)]
+        );
+
+        let out = &a / &b;
+        assert_eq!(
+            Vec::from(&out.decimal()?.physical()),
+            &[Some(75), Some(-450), Some(273)]
+        );
+
+        let mask = a.gt(&b);
+        assert_eq!(Vec::from(&mask), &[Some(false), Some(false), Some(true)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_aggregations() -> Result<()> {
+        let s =
+            Series::new("a", &["1.50", "-2.25", "3"]).cast_with_dtype(&DataType::Decimal(10, 2))?;
+        assert_eq!(format!("{}", s.sum_as_series().get(0)), "2.25");
+        assert_eq!(format!("{}", s.max_as_series().get(0)), "3.00");
+        assert_eq!(format!("{}", s.min_as_series().get(0)), "-2.25");
+        assert_eq!(s.mean(), Some(0.75));
+
+        let sorted = s.sort(false);
+        assert_eq!(sorted.dtype(), s.dtype());
+        assert_eq!(format!("{}", sorted.get(0)), "-2.25");
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_join_scales() -> Result<()> {
+        let a =
+            Series::new("key", &["1.0", "2.5", "3.0"]).cast_with_dtype(&DataType::Decimal(5, 1))?;
+        let b = Series::new("key", &["1.00", "2.50", "4.00"])
+            .cast_with_dtype(&DataType::Decimal(6, 2))?;
+        let left = DataFrame::new(vec![a, Series::new("l", &[1, 2, 3])])?;
+        let right = DataFrame::new(vec![b, Series::new("r", &[4, 5, 6])])?;
+
+        let out = left.inner_join(&right, "key", "key")?;
+        assert_eq!(Vec::from(out.column("r")?.i32()?), &[Some(4), Some(5)]);
+
+        let out = left.outer_join(&right, "key", "key")?;
+        assert_eq!(out.column("key")?.dtype(), &DataType::Decimal(6, 2));
+        assert_eq!(out.height(), 4);
+
+        // integers are decimals with a scale of 0
+        let ints = Series::new("key", &[1i64, 3]);
+        let joined = left.column("key")?.hash_join_inner(&ints);
+        assert_eq!(joined.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_precision_limit() -> Result<()> {
+        let dtype =
+            crate::utils::get_supertype(&DataType::Decimal(18, 0), &DataType::Decimal(18, 10))?;
+        assert_eq!(dtype, DataType::Decimal(18, 10));
+
+        // the values don't fit a common decimal type, but can still be compared
+        let a =
+            Series::new("a", &["100000000000000000"]).cast_with_dtype(&DataType::Decimal(18, 0))?;
+        let b = Series::new("b", &["1.5"]).cast_with_dtype(&DataType::Decimal(18, 10))?;
+        assert_eq!(Vec::from(&a.gt(&b)), &[Some(true)]);
+        assert_eq!(Vec::from(&a.eq(&b)), &[Some(false)]);
+        assert!(crate::series::arithmetic::coerce_lhs_rhs(&a, &b).is_err());
+        Ok(())
+    }
+}
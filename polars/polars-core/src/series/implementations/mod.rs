@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-binary")]
+pub mod binary;
 #[cfg(any(
     feature = "dtype-date64",
     feature = "dtype-date32",
@@ -6,8 +8,12 @@
     feature = "dtype-time64-ns"
 ))]
 pub mod dates;
+#[cfg(feature = "dtype-decimal")]
+pub mod decimal;
 #[cfg(feature = "object")]
 pub mod object;
+#[cfg(feature = "dtype-struct")]
+pub mod struct_;
 
 use super::private;
 use super::IntoSeries;
@@ -113,13 +119,31 @@ macro_rules! impl_dyn_series {
                 self.0.agg_list(groups)
             }
 
-            fn agg_quantile(&self, groups: &[(u32, Vec<u32>)], quantile: f64) -> Option<Series> {
-                self.0.agg_quantile(groups, quantile)
+            fn agg_quantile(
+                &self,
+                groups: &[(u32, Vec<u32>)],
+                quantile: f64,
+                interpol: QuantileInterpolOptions,
+            ) -> Option<Series> {
+                self.0.agg_quantile(groups, quantile, interpol)
             }
 
             fn agg_median(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
                 self.0.agg_median(groups)
             }
+
+            fn agg_skew(&self, groups: &[(u32, Vec<u32>)], bias: bool) -> Option<Series> {
+                self.0.agg_skew(groups, bias)
+            }
+
+            fn agg_kurtosis(
+                &self,
+                groups: &[(u32, Vec<u32>)],
+                fisher: bool,
+                bias: bool,
+            ) -> Option<Series> {
+                self.0.agg_kurtosis(groups, fisher, bias)
+            }
             #[cfg(feature = "lazy")]
             fn agg_valid_count(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
                 self.0.agg_valid_count(groups)
@@ -204,6 +228,27 @@ macro_rules! impl_dyn_series {
                 self.0.cum_sum(reverse).into_series()
             }
 
+            fn cum_prod(&self, reverse: bool) -> Series {
+                use DataType::*;
+                // like `sum`, accumulate in a 64 bit type so that the product does not overflow
+                // the (small) native type
+                match self.dtype() {
+                    Int8 | Int16 | Int32 | UInt8 | UInt16 | UInt32 | UInt64 => self
+                        .0
+                        .cast::<Int64Type>()
+                        .unwrap()
+                        .cum_prod(reverse)
+                        .into_series(),
+                    Float32 => self
+                        .0
+                        .cast::<Float64Type>()
+                        .unwrap()
+                        .cum_prod(reverse)
+                        .into_series(),
+                    _ => self.0.cum_prod(reverse).into_series(),
+                }
+            }
+
             fn rename(&mut self, name: &str) {
                 self.0.rename(name);
             }
@@ -620,8 +665,8 @@ macro_rules! impl_dyn_series {
                 ToDummies::to_dummies(&self.0)
             }
 
-            fn value_counts(&self) -> Result<DataFrame> {
-                ChunkUnique::value_counts(&self.0)
+            fn value_counts(&self, include_nulls: bool) -> Result<DataFrame> {
+                ChunkUnique::value_counts(&self.0, include_nulls)
             }
 
             fn get(&self, index: usize) -> AnyValue {
@@ -731,8 +776,12 @@ macro_rules! impl_dyn_series {
             fn std_as_series(&self) -> Series {
                 VarAggSeries::std_as_series(&self.0)
             }
-            fn quantile_as_series(&self, quantile: f64) -> Result<Series> {
-                ChunkAggSeries::quantile_as_series(&self.0, quantile)
+            fn quantile_as_series(
+                &self,
+                quantile: f64,
+                interpol: QuantileInterpolOptions,
+            ) -> Result<Series> {
+                ChunkAggSeries::quantile_as_series(&self.0, quantile, interpol)
             }
             fn rolling_mean(
                 &self,
@@ -774,6 +823,51 @@ macro_rules! impl_dyn_series {
                 ChunkWindow::rolling_max(&self.0, window_size, weight, ignore_null, min_periods)
                     .map(|ca| ca.into_series())
             }
+            fn rolling_var(
+                &self,
+                window_size: u32,
+                weight: Option<&[f64]>,
+                ignore_null: bool,
+                min_periods: u32,
+            ) -> Result<Series> {
+                use DataType::*;
+                match self.0.dtype() {
+                    // the variance of integers is fractional, so it is computed in Float64
+                    UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 => self
+                        .cast_with_dtype(&Float64)?
+                        .rolling_var(window_size, weight, ignore_null, min_periods),
+                    _ => ChunkWindow::rolling_var(
+                        &self.0,
+                        window_size,
+                        weight,
+                        ignore_null,
+                        min_periods,
+                    )
+                    .map(|ca| ca.into_series()),
+                }
+            }
+            fn rolling_std(
+                &self,
+                window_size: u32,
+                weight: Option<&[f64]>,
+                ignore_null: bool,
+                min_periods: u32,
+            ) -> Result<Series> {
+                use DataType::*;
+                match self.0.dtype() {
+                    UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 => self
+                        .cast_with_dtype(&Float64)?
+                        .rolling_std(window_size, weight, ignore_null, min_periods),
+                    _ => ChunkWindow::rolling_std(
+                        &self.0,
+                        window_size,
+                        weight,
+                        ignore_null,
+                        min_periods,
+                    )
+                    .map(|ca| ca.into_series()),
+                }
+            }
 
             fn fmt_list(&self) -> String {
                 FmtList::fmt_list(&self.0)
@@ -784,17 +878,29 @@ macro_rules! impl_dyn_series {
 
             #[cfg(feature = "random")]
             #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-            fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
+            fn sample_n_weighted(
+                &self,
+                n: usize,
+                with_replacement: bool,
+                shuffle: bool,
+                weights: Option<&Series>,
+            ) -> Result<Series> {
                 self.0
-                    .sample_n(n, with_replacement)
+                    .sample_n_weighted(n, with_replacement, shuffle, weights)
                     .map(|ca| ca.into_series())
             }
 
             #[cfg(feature = "random")]
             #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-            fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
+            fn sample_frac_weighted(
+                &self,
+                frac: f64,
+                with_replacement: bool,
+                shuffle: bool,
+                weights: Option<&Series>,
+            ) -> Result<Series> {
                 self.0
-                    .sample_frac(frac, with_replacement)
+                    .sample_frac_weighted(frac, with_replacement, shuffle, weights)
                     .map(|ca| ca.into_series())
             }
 
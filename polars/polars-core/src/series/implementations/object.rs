@@ -150,8 +150,8 @@ where
         ToDummies::to_dummies(&self.0)
     }
 
-    fn value_counts(&self) -> Result<DataFrame> {
-        ChunkUnique::value_counts(&self.0)
+    fn value_counts(&self, include_nulls: bool) -> Result<DataFrame> {
+        ChunkUnique::value_counts(&self.0, include_nulls)
     }
 
     fn get(&self, index: usize) -> AnyValue {
@@ -228,14 +228,28 @@ where
 
     #[cfg(feature = "random")]
     #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
-        ObjectChunked::sample_n(&self.0, n, with_replacement).map(|ca| ca.into_series())
+    fn sample_n_weighted(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        weights: Option<&Series>,
+    ) -> Result<Series> {
+        ObjectChunked::sample_n_weighted(&self.0, n, with_replacement, shuffle, weights)
+            .map(|ca| ca.into_series())
     }
 
     #[cfg(feature = "random")]
     #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
-        ObjectChunked::sample_frac(&self.0, frac, with_replacement).map(|ca| ca.into_series())
+    fn sample_frac_weighted(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        weights: Option<&Series>,
+    ) -> Result<Series> {
+        ObjectChunked::sample_frac_weighted(&self.0, frac, with_replacement, shuffle, weights)
+            .map(|ca| ca.into_series())
     }
 
     fn get_as_any(&self, index: usize) -> &dyn Any {
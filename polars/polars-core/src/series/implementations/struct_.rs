@@ -0,0 +1,245 @@
+//! Structs are not backed by a ChunkedArray. Every operation is applied on the fields and a new
+//! struct is created from the results.
+use super::private;
+use super::IntoSeries;
+use super::SeriesTrait;
+use super::SeriesWrap;
+use crate::prelude::*;
+use arrow::array::{ArrayData, ArrayRef};
+use std::borrow::Cow;
+
+impl IntoSeries for StructChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl SeriesWrap<StructChunked> {
+    fn unpack_other<'a>(&self, other: &'a Series) -> Result<&'a StructChunked> {
+        if self.0.dtype() == other.dtype() {
+            other.struct_()
+        } else {
+            Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "cannot combine struct {:?} with {:?}",
+                    self.0.dtype(),
+                    other.dtype()
+                )
+                .into(),
+            ))
+        }
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<StructChunked> {
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        self.0.get_any_value(idx_self) == other.get_unchecked(idx_other)
+    }
+
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> Result<Series> {
+        let other = self.unpack_other(other)?;
+        self.0
+            .try_combine_fields(other, |l, r| l.zip_with(mask, r))
+            .map(|ca| ca.into_series())
+    }
+
+    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        self.0.apply_fields(|s| s.agg_first(groups)).into_series()
+    }
+
+    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        self.0.apply_fields(|s| s.agg_last(groups)).into_series()
+    }
+
+    fn str_value(&self, index: usize) -> Cow<str> {
+        Cow::Owned(format!("{}", self.get(index)))
+    }
+}
+
+impl SeriesTrait for SeriesWrap<StructChunked> {
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name);
+    }
+
+    fn array_data(&self) -> Vec<&ArrayData> {
+        self.0.chunks().iter().map(|arr| arr.data()).collect()
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn field(&self) -> &Field {
+        self.0.ref_field()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+
+    fn struct_(&self) -> Result<&StructChunked> {
+        unsafe { Ok(&*(self as *const dyn SeriesTrait as *const StructChunked)) }
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0
+            .apply_fields(|s| s.slice(offset, length))
+            .into_series()
+    }
+
+    fn append(&mut self, other: &Series) -> Result<()> {
+        let other = self.unpack_other(other)?;
+        self.0 = self.0.try_combine_fields(other, |l, r| {
+            let mut l = l.clone();
+            l.append(r)?;
+            Ok(l)
+        })?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
+        self.0
+            .try_apply_fields(|s| s.filter(filter))
+            .map(|ca| ca.into_series())
+    }
+
+    fn take(&self, indices: &UInt32Chunked) -> Series {
+        self.0.apply_fields(|s| s.take(indices)).into_series()
+    }
+
+    fn take_iter(&self, iter: &mut dyn Iterator<Item = usize>) -> Series {
+        // the iterator can only be consumed once
+        let idx: UInt32Chunked = iter.map(|i| i as u32).collect();
+        self.take(&idx)
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        self.0.apply_fields(|s| s.take_every(n)).into_series()
+    }
+
+    unsafe fn take_iter_unchecked(&self, iter: &mut dyn Iterator<Item = usize>) -> Series {
+        let idx: UInt32Chunked = iter.map(|i| i as u32).collect();
+        self.0
+            .apply_fields(|s| s.take_unchecked(&idx).unwrap())
+            .into_series()
+    }
+
+    unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Result<Series> {
+        self.0
+            .try_apply_fields(|s| s.take_unchecked(idx))
+            .map(|ca| ca.into_series())
+    }
+
+    unsafe fn take_opt_iter_unchecked(
+        &self,
+        iter: &mut dyn Iterator<Item = Option<usize>>,
+    ) -> Series {
+        self.take_opt_iter(iter)
+    }
+
+    fn take_opt_iter(&self, iter: &mut dyn Iterator<Item = Option<usize>>) -> Series {
+        let idx: UInt32Chunked = iter.map(|opt_i| opt_i.map(|i| i as u32)).collect();
+        self.0.apply_fields(|s| s.take(&idx)).into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        // the struct array is always a single chunk
+        self.0.clone().into_series()
+    }
+
+    fn head(&self, length: Option<usize>) -> Series {
+        self.0.apply_fields(|s| s.head(length)).into_series()
+    }
+
+    fn tail(&self, length: Option<usize>) -> Series {
+        self.0.apply_fields(|s| s.tail(length)).into_series()
+    }
+
+    fn expand_at_index(&self, index: usize, length: usize) -> Series {
+        self.0
+            .apply_fields(|s| s.expand_at_index(index, length))
+            .into_series()
+    }
+
+    fn cast_with_dtype(&self, data_type: &DataType) -> Result<Series> {
+        if data_type == self.0.dtype() {
+            Ok(self.0.clone().into_series())
+        } else {
+            Err(PolarsError::InvalidOperation(
+                format!("cannot cast a struct to {:?}", data_type).into(),
+            ))
+        }
+    }
+
+    fn get(&self, index: usize) -> AnyValue {
+        self.0.get_any_value(index)
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value(index)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        let mut ca: BooleanChunked = (0..self.len()).map(|i| self.0.is_null_at(i)).collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        let mut ca: BooleanChunked = (0..self.len()).map(|i| !self.0.is_null_at(i)).collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    fn reverse(&self) -> Series {
+        self.0.apply_fields(|s| s.reverse()).into_series()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.0.apply_fields(|s| s.shift(periods)).into_series()
+    }
+
+    fn fmt_list(&self) -> String {
+        format!("struct [{}]", self.len())
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_struct_series() -> Result<()> {
+        let a = Series::new("a", &[1, 2, 3]);
+        let b = Series::new("b", &["foo", "bar", "ham"]);
+        let s = StructChunked::new("s", &[a, b])?.into_series();
+
+        let df = DataFrame::new(vec![Series::new("idx", &[0, 1, 2]), s])?;
+        let out = df.filter(&df.column("idx")?.gt(0))?;
+        let s = out.column("s")?.struct_()?;
+        assert_eq!(s.len(), 2);
+        assert_eq!(
+            Vec::from(s.field("b")?.utf8()?),
+            &[Some("bar"), Some("ham")]
+        );
+
+        let out = df.sort("idx", true)?;
+        assert_eq!(
+            Vec::from(out.column("s")?.struct_()?.field("a")?.i32()?),
+            &[Some(3), Some(2), Some(1)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+//! Fill the null values of a Series by interpolation.
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InterpolationMethod {
+    /// Interpolate linearly between the surrounding values.
+    Linear,
+    /// Take the nearest surrounding value. On a tie the previous value is taken.
+    Nearest,
+}
+
+impl InterpolationMethod {
+    /// The dtype that a Series of `dtype` has after the interpolation.
+    pub fn output_dtype(&self, dtype: &DataType) -> Result<DataType> {
+        use DataType::*;
+        match self {
+            InterpolationMethod::Nearest => Ok(dtype.clone()),
+            InterpolationMethod::Linear => match dtype {
+                Float32 => Ok(Float32),
+                UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float64 => {
+                    Ok(Float64)
+                }
+                Date32 | Date64 | Time64(_) | Duration(_) => Ok(dtype.clone()),
+                dt => Err(PolarsError::InvalidOperation(
+                    format!("linear interpolation not supported for dtype {:?}", dt).into(),
+                )),
+            },
+        }
+    }
+}
+
+/// The surrounding non-null values of every interior null value: `(row, previous, next)`.
+fn interior_nulls(s: &Series) -> Vec<(usize, usize, usize)> {
+    let is_valid = s.is_not_null();
+    let valid_rows = is_valid
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, opt_v)| if opt_v == Some(true) { Some(i) } else { None })
+        .collect::<Vec<_>>();
+    valid_rows
+        .windows(2)
+        .flat_map(|w| ((w[0] + 1)..w[1]).map(move |i| (i, w[0], w[1])))
+        .collect()
+}
+
+impl Series {
+    /// Fill the null values that lie between two non-null values by interpolation. The null
+    /// values at the start and the end of the Series are not filled.
+    ///
+    /// Linear interpolation is supported for numeric and temporal Series. Integer Series are
+    /// interpolated to `Float64`, temporal Series keep their type and are rounded to the
+    /// nearest time unit.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[Some(1), None, None, Some(4), None]);
+    /// let out = s.interpolate(InterpolationMethod::Linear).unwrap();
+    /// assert_eq!(
+    ///     Vec::from(out.f64().unwrap()),
+    ///     &[Some(1.0), Some(2.0), Some(3.0), Some(4.0), None]
+    /// );
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "interpolate")))]
+    pub fn interpolate(&self, method: InterpolationMethod) -> Result<Series> {
+        match method {
+            InterpolationMethod::Nearest if self.null_count() == 0 => Ok(self.clone()),
+            InterpolationMethod::Nearest => {
+                let mut idx = (0..self.len()).map(Some).collect::<Vec<_>>();
+                for (i, prev, next) in interior_nulls(self) {
+                    idx[i] = Some(if i - prev <= next - i { prev } else { next });
+                }
+                Ok(self.take_opt_iter(&mut idx.into_iter()))
+            }
+            InterpolationMethod::Linear => self.interpolate_linear(),
+        }
+    }
+
+    fn interpolate_linear(&self) -> Result<Series> {
+        let physical = self.to_physical_repr();
+        let out_dtype = InterpolationMethod::Linear.output_dtype(self.dtype())?;
+        let is_temporal = physical.dtype() != self.dtype();
+
+        let s = physical.cast::<Float64Type>()?;
+        let ca = s.f64()?;
+        let mut values = ca.into_iter().collect::<Vec<_>>();
+        for (i, prev, next) in interior_nulls(self) {
+            let (start, end) = (values[prev].unwrap(), values[next].unwrap());
+            let v = start + (end - start) * (i - prev) as f64 / (next - prev) as f64;
+            values[i] = Some(if is_temporal { v.round() } else { v });
+        }
+        let mut out = values.into_iter().collect::<Float64Chunked>().into_series();
+        out.rename(self.name());
+
+        if is_temporal {
+            out.cast_with_dtype(physical.dtype())?
+                .cast_with_dtype(&out_dtype)
+        } else {
+            out.cast_with_dtype(&out_dtype)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interpolate() -> Result<()> {
+        let s = Series::new("a", &[None, Some(1.0f32), None, None, Some(2.5), None]);
+        let out = s.interpolate(InterpolationMethod::Linear)?;
+        assert_eq!(
+            Vec::from(out.f32()?),
+            &[None, Some(1.0), Some(1.5), Some(2.0), Some(2.5), None]
+        );
+
+        let s = Series::new("a", &[Some("a"), None, None, None, Some("b")]);
+        assert!(s.interpolate(InterpolationMethod::Linear).is_err());
+        let out = s.interpolate(InterpolationMethod::Nearest)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("a"), Some("a"), Some("a"), Some("b"), Some("b")]
+        );
+
+        #[cfg(feature = "dtype-date64")]
+        {
+            let ca = Date64Chunked::new_from_opt_slice("t", &[Some(0), None, Some(3)]);
+            let out = ca.into_series().interpolate(InterpolationMethod::Linear)?;
+            assert_eq!(out.dtype(), &DataType::Date64);
+            assert_eq!(Vec::from(out.date64()?), &[Some(0), Some(2), Some(3)]);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+//! Check if the values of a Series lie in an interval.
+use crate::prelude::*;
+
+impl Series {
+    /// Check if the values lie between `low` and `high`. `closed` determines if the boundaries
+    /// are part of the interval. `low` and `high` should have the same length as this Series or
+    /// length 1.
+    ///
+    /// Null values in this Series result in null values in the mask.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[Some(1), Some(2), None, Some(3)]);
+    /// let low = Series::new("low", &[1]);
+    /// let high = Series::new("high", &[3]);
+    /// let mask = s.is_between(&low, &high, ClosedWindow::Left).unwrap();
+    /// assert_eq!(Vec::from(&mask), &[Some(true), Some(true), None, Some(false)]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "is_between")))]
+    pub fn is_between(
+        &self,
+        low: &Series,
+        high: &Series,
+        closed: ClosedWindow,
+    ) -> Result<BooleanChunked> {
+        for bound in &[low, high] {
+            if bound.len() != self.len() && bound.len() != 1 {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "the bounds of is_between should have length {} or 1, got {}",
+                        self.len(),
+                        bound.len()
+                    )
+                    .into(),
+                ));
+            }
+        }
+        let lower = match closed {
+            ClosedWindow::Both | ClosedWindow::Left => self.gt_eq(low),
+            ClosedWindow::Right | ClosedWindow::None => self.gt(low),
+        };
+        let upper = match closed {
+            ClosedWindow::Both | ClosedWindow::Right => self.lt_eq(high),
+            ClosedWindow::Left | ClosedWindow::None => self.lt(high),
+        };
+        let mut mask = &lower & &upper;
+        if self.null_count() > 0 {
+            mask = mask.set(&self.is_null(), None)?;
+        }
+        mask.rename(self.name());
+        Ok(mask)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_between() -> Result<()> {
+        let s = Series::new("a", &[Some(1.0), Some(1.5), Some(2.0), None]);
+        let low = Series::new("low", &[1.0]);
+        let high = Series::new("high", &[2.0]);
+
+        let out = s.is_between(&low, &high, ClosedWindow::Both)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(true), Some(true), Some(true), None]);
+        let out = s.is_between(&low, &high, ClosedWindow::Right)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(false), Some(true), Some(true), None]
+        );
+        let out = s.is_between(&low, &high, ClosedWindow::None)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(false), Some(true), Some(false), None]
+        );
+
+        let high = Series::new("high", &[2, 1, 3, 4]);
+        let out = s.is_between(&low, &high, ClosedWindow::Left)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(false), Some(true), None]
+        );
+
+        assert!(s
+            .is_between(&low, &high.head(Some(2)), ClosedWindow::Both)
+            .is_err());
+        Ok(())
+    }
+}
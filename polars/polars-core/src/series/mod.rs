@@ -3,12 +3,28 @@ pub use crate::prelude::ChunkCompare;
 use crate::prelude::*;
 use arrow::{array::ArrayRef, buffer::Buffer};
 pub(crate) mod arithmetic;
+mod clip;
 mod comparison;
+#[cfg(feature = "diff")]
+mod diff;
+pub mod ffi;
 pub mod implementations;
+#[cfg(feature = "interpolate")]
+mod interpolate;
+#[cfg(feature = "is_between")]
+mod is_between;
 pub(crate) mod iterator;
+#[cfg(feature = "mode")]
+mod mode;
+mod moment;
+#[cfg(feature = "rank")]
+mod rank;
+#[cfg(feature = "search_sorted")]
+mod search_sorted;
 
 use crate::chunked_array::{builder::get_list_builder, float::IsNan, ChunkIdIter};
-use crate::utils::{split_ca, split_series};
+use crate::utils::{split_ca, split_series, NoNull};
+use crate::vector_hasher::stable_hash;
 use crate::{series::arithmetic::coerce_lhs_rhs, POOL};
 use arrow::array::ArrayData;
 use arrow::compute::cast;
@@ -77,12 +93,28 @@ pub(crate) mod private {
         fn agg_list(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
             unimplemented!()
         }
-        fn agg_quantile(&self, _groups: &[(u32, Vec<u32>)], _quantile: f64) -> Option<Series> {
+        fn agg_quantile(
+            &self,
+            _groups: &[(u32, Vec<u32>)],
+            _quantile: f64,
+            _interpol: QuantileInterpolOptions,
+        ) -> Option<Series> {
             unimplemented!()
         }
         fn agg_median(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
             unimplemented!()
         }
+        fn agg_skew(&self, _groups: &[(u32, Vec<u32>)], _bias: bool) -> Option<Series> {
+            unimplemented!()
+        }
+        fn agg_kurtosis(
+            &self,
+            _groups: &[(u32, Vec<u32>)],
+            _fisher: bool,
+            _bias: bool,
+        ) -> Option<Series> {
+            unimplemented!()
+        }
         #[cfg(feature = "lazy")]
         fn agg_valid_count(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
             unimplemented!()
@@ -175,6 +207,12 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         panic!("operation cum_sum not supported for this dtype")
     }
 
+    /// Get an array with the cumulative product computed at every element.
+    /// Integers are accumulated in `Int64` and `Float32` in `Float64`.
+    fn cum_prod(&self, _reverse: bool) -> Series {
+        panic!("operation cum_prod not supported for this dtype")
+    }
+
     /// Rename the Series.
     fn rename(&mut self, name: &str);
 
@@ -358,6 +396,38 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         ))
     }
 
+    /// Unpack to ChunkedArray of dtype decimal
+    #[cfg(feature = "dtype-decimal")]
+    fn decimal(&self) -> Result<&DecimalChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != decimal", self.dtype()).into(),
+        ))
+    }
+
+    /// Unpack to ChunkedArray of dtype datetime
+    #[cfg(feature = "timezones")]
+    fn datetime(&self) -> Result<&DatetimeChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != datetime", self.dtype()).into(),
+        ))
+    }
+
+    /// Unpack to ChunkedArray of dtype binary
+    #[cfg(feature = "dtype-binary")]
+    fn binary(&self) -> Result<&BinaryChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != binary", self.dtype()).into(),
+        ))
+    }
+
+    /// Unpack to StructChunked
+    #[cfg(feature = "dtype-struct")]
+    fn struct_(&self) -> Result<&StructChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("{:?} != struct", self.dtype()).into(),
+        ))
+    }
+
     /// Append Arrow array of same dtype to this Series.
     fn append_array(&mut self, _other: ArrayRef) -> Result<()> {
         unimplemented!()
@@ -514,7 +584,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         unimplemented!()
     }
 
-    fn value_counts(&self) -> Result<DataFrame> {
+    /// Count the unique values. Returns a DataFrame with the unique values and a `"counts"`
+    /// column, sorted by the counts in descending order. If `include_nulls` is true, the
+    /// null values are counted as well.
+    fn value_counts(&self, _include_nulls: bool) -> Result<DataFrame> {
         unimplemented!()
     }
 
@@ -669,10 +742,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     /// fn example() -> Result<()> {
     ///     let s = Series::new("some_missing", &[Some(1), None, Some(2)]);
     ///
-    ///     let filled = s.fill_none(FillNoneStrategy::Forward)?;
+    ///     let filled = s.fill_none(FillNoneStrategy::Forward(None))?;
     ///     assert_eq!(Vec::from(filled.i32()?), &[Some(1), Some(1), Some(2)]);
     ///
-    ///     let filled = s.fill_none(FillNoneStrategy::Backward)?;
+    ///     let filled = s.fill_none(FillNoneStrategy::Backward(None))?;
     ///     assert_eq!(Vec::from(filled.i32()?), &[Some(1), Some(2), Some(2)]);
     ///
     ///     let filled = s.fill_none(FillNoneStrategy::Min)?;
@@ -721,7 +794,11 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         unimplemented!()
     }
     /// Get the quantile of the ChunkedArray as a new Series of length 1.
-    fn quantile_as_series(&self, _quantile: f64) -> Result<Series> {
+    fn quantile_as_series(
+        &self,
+        _quantile: f64,
+        _interpol: QuantileInterpolOptions,
+    ) -> Result<Series> {
         unimplemented!()
     }
     /// Apply a rolling mean to a Series. See:
@@ -768,6 +845,32 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     ) -> Result<Series> {
         unimplemented!()
     }
+    /// Apply a rolling variance to a Series. See:
+    /// [ChunkedArray::rolling_var](crate::prelude::ChunkWindow::rolling_var).
+    fn rolling_var(
+        &self,
+        _window_size: u32,
+        _weight: Option<&[f64]>,
+        _ignore_null: bool,
+        _min_periods: u32,
+    ) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling var not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
+    /// Apply a rolling standard deviation to a Series. See:
+    /// [ChunkedArray::rolling_std](crate::prelude::ChunkWindow::rolling_std).
+    fn rolling_std(
+        &self,
+        _window_size: u32,
+        _weight: Option<&[f64]>,
+        _ignore_null: bool,
+        _min_periods: u32,
+    ) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            format!("rolling std not supported for dtype {:?}", self.dtype()).into(),
+        ))
+    }
 
     fn fmt_list(&self) -> String {
         "fmt implemented".into()
@@ -778,6 +881,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     /// Extract hour from underlying NaiveDateTime representation.
     /// Returns the hour number from 0 to 23.
     fn hour(&self) -> Result<UInt32Chunked> {
+        #[cfg(feature = "timezones")]
+        if let Ok(ca) = self.datetime() {
+            return Ok(ca.local_datetime()?.hour());
+        }
         self.date64().map(|ca| ca.hour())
     }
 
@@ -786,6 +893,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     /// Extract minute from underlying NaiveDateTime representation.
     /// Returns the minute number from 0 to 59.
     fn minute(&self) -> Result<UInt32Chunked> {
+        #[cfg(feature = "timezones")]
+        if let Ok(ca) = self.datetime() {
+            return Ok(ca.local_datetime()?.minute());
+        }
         self.date64().map(|ca| ca.minute())
     }
 
@@ -794,6 +905,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     /// Extract second from underlying NaiveDateTime representation.
     /// Returns the second number from 0 to 59.
     fn second(&self) -> Result<UInt32Chunked> {
+        #[cfg(feature = "timezones")]
+        if let Ok(ca) = self.datetime() {
+            return Ok(ca.local_datetime()?.second());
+        }
         self.date64().map(|ca| ca.second())
     }
 
@@ -802,6 +917,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     /// Returns the number of nanoseconds since the whole non-leap second.
     /// The range from 1,000,000,000 to 1,999,999,999 represents the leap second.
     fn nanosecond(&self) -> Result<UInt32Chunked> {
+        #[cfg(feature = "timezones")]
+        if let Ok(ca) = self.datetime() {
+            return Ok(ca.local_datetime()?.nanosecond());
+        }
         self.date64().map(|ca| ca.nanosecond())
     }
 
@@ -815,6 +934,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         match self.dtype() {
             DataType::Date32 => self.date32().map(|ca| ca.day()),
             DataType::Date64 => self.date64().map(|ca| ca.day()),
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => self
+                .datetime()
+                .and_then(|ca| Ok(ca.local_datetime()?.day())),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -827,6 +950,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         match self.dtype() {
             DataType::Date32 => self.date32().map(|ca| ca.weekday()),
             DataType::Date64 => self.date64().map(|ca| ca.weekday()),
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => self
+                .datetime()
+                .and_then(|ca| Ok(ca.local_datetime()?.weekday())),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -841,6 +968,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         match self.dtype() {
             DataType::Date32 => self.date32().map(|ca| ca.week()),
             DataType::Date64 => self.date64().map(|ca| ca.week()),
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => self
+                .datetime()
+                .and_then(|ca| Ok(ca.local_datetime()?.week())),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -856,6 +987,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         match self.dtype() {
             DataType::Date32 => self.date32().map(|ca| ca.ordinal()),
             DataType::Date64 => self.date64().map(|ca| ca.ordinal()),
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => self
+                .datetime()
+                .and_then(|ca| Ok(ca.local_datetime()?.ordinal())),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -872,6 +1007,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         match self.dtype() {
             DataType::Date32 => self.date32().map(|ca| ca.month()),
             DataType::Date64 => self.date64().map(|ca| ca.month()),
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => self
+                .datetime()
+                .and_then(|ca| Ok(ca.local_datetime()?.month())),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -886,6 +1025,10 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         match self.dtype() {
             DataType::Date32 => self.date32().map(|ca| ca.year()),
             DataType::Date64 => self.date64().map(|ca| ca.year()),
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => self
+                .datetime()
+                .and_then(|ca| Ok(ca.local_datetime()?.year())),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -894,11 +1037,51 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
 
     #[cfg(feature = "temporal")]
     #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
-    /// Format Date32/Date64 with a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    /// Format Date32/Date64/Time64 with a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     fn strftime(&self, fmt: &str) -> Result<Series> {
         match self.dtype() {
             DataType::Date32 => self.date32().map(|ca| ca.strftime(fmt).into_series()),
             DataType::Date64 => self.date64().map(|ca| ca.strftime(fmt).into_series()),
+            DataType::Time64(TimeUnit::Nanosecond) => self
+                .time64_nanosecond()
+                .map(|ca| ca.strftime(fmt).into_series()),
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => self
+                .datetime()
+                .and_then(|ca| Ok(ca.strftime(fmt)?.into_series())),
+            _ => Err(PolarsError::InvalidOperation(
+                format!("operation not supported on dtype {:?}", self.dtype()).into(),
+            )),
+        }
+    }
+
+    #[cfg(feature = "timezones")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timezones")))]
+    /// Show the same instants in the time zone `tz`. Date64 values are interpreted as UTC.
+    fn convert_time_zone(&self, tz: &str) -> Result<Series> {
+        match self.dtype() {
+            DataType::Date64 => self
+                .date64()
+                .and_then(|ca| ca.convert_time_zone(tz))
+                .map(|ca| ca.into_series()),
+            DataType::Datetime(_) => self
+                .datetime()
+                .and_then(|ca| ca.convert_time_zone(tz))
+                .map(|ca| ca.into_series()),
+            _ => Err(PolarsError::InvalidOperation(
+                format!("operation not supported on dtype {:?}", self.dtype()).into(),
+            )),
+        }
+    }
+
+    #[cfg(feature = "timezones")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timezones")))]
+    /// Keep the wall clock times, but set their time zone to `tz`.
+    /// If `tz` is `None` the time zone is dropped and the wall clock times are returned as Date64.
+    fn replace_time_zone(&self, tz: Option<&str>) -> Result<Series> {
+        match self.dtype() {
+            DataType::Date64 => self.date64().and_then(|ca| ca.replace_time_zone(tz)),
+            DataType::Datetime(_) => self.datetime().and_then(|ca| ca.replace_time_zone(tz)),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -914,6 +1097,8 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
                 .date32()
                 .map(|ca| (ca.cast::<Int64Type>().unwrap() * 1000)),
             DataType::Date64 => self.date64().map(|ca| ca.cast::<Int64Type>().unwrap()),
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => self.datetime().map(|ca| ca.timestamps()),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -928,12 +1113,43 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     #[cfg(feature = "random")]
     #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
     /// Sample n datapoints from this Series.
-    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series>;
+    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
+        self.sample_n_weighted(n, with_replacement, true, None)
+    }
 
     #[cfg(feature = "random")]
     #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
     /// Sample a fraction between 0.0-1.0 of this ChunkedArray.
-    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series>;
+    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
+        self.sample_frac_weighted(frac, with_replacement, true, None)
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    /// Sample n datapoints from this Series.
+    ///
+    /// * `shuffle` - Shuffle the order of the sampled values. Otherwise the original order is kept.
+    /// * `weights` - Sample every value with a probability proportional to its weight. Values
+    ///   with a weight of 0.0 are never sampled.
+    fn sample_n_weighted(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        weights: Option<&Series>,
+    ) -> Result<Series>;
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    /// Sample a fraction between 0.0-1.0 of this Series.
+    /// See [sample_n_weighted](SeriesTrait::sample_n_weighted).
+    fn sample_frac_weighted(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        weights: Option<&Series>,
+    ) -> Result<Series>;
 
     /// Get the value at this index as a downcastable Any trait ref.
     fn get_as_any(&self, _index: usize) -> &dyn Any {
@@ -1102,6 +1318,20 @@ impl Series {
         Ok(self)
     }
 
+    /// Extend the values of this Series with the values of `other`. Different from `append`, the
+    /// result is always a single chunk.
+    ///
+    /// Arrow arrays are immutable, so the existing values can't grow in place: every call copies
+    /// the values of this Series once. Prefer `append` followed by a single `rechunk` when many
+    /// Series are combined at once.
+    pub fn extend(&mut self, other: &Series) -> Result<&mut Self> {
+        self.append(other)?;
+        if self.n_chunks() > 1 {
+            *self = self.rechunk();
+        }
+        Ok(self)
+    }
+
     /// Sort in place.
     pub fn sort_in_place(&mut self, reverse: bool) -> &mut Self {
         self.get_inner_mut().sort_in_place(reverse);
@@ -1121,6 +1351,80 @@ impl Series {
     {
         self.0.cast_with_dtype(&N::get_dtype())
     }
+
+    /// Hash every value of the Series to a `u64`. Null values also get a hash.
+    ///
+    /// The hashes only depend on the values and the `seed`: they are the same on every platform
+    /// and in every run, so they can be persisted. Equal values of different integer types,
+    /// e.g. `1i32` and `1i64`, get different hashes.
+    pub fn hash(&self, seed: u64) -> Result<UInt64Chunked> {
+        macro_rules! hash_values {
+            ($iter:expr, $to_bytes:expr) => {{
+                let ca: NoNull<UInt64Chunked> = $iter
+                    .map(|opt_v| match opt_v {
+                        Some(v) => stable_hash(Some(AsRef::<[u8]>::as_ref(&$to_bytes(v))), seed),
+                        None => stable_hash(None, seed),
+                    })
+                    .collect();
+                ca.into_inner()
+            }};
+        }
+        // -0.0 equals 0.0 and all NaNs are the same value
+        fn canonical_f64(v: f64) -> [u8; 8] {
+            let v = if v == 0.0 {
+                0.0
+            } else if v.is_nan() {
+                f64::NAN
+            } else {
+                v
+            };
+            v.to_le_bytes()
+        }
+
+        let physical = self.to_physical_repr();
+        let mut ca = match physical.dtype() {
+            DataType::Boolean => hash_values!(physical.bool()?.into_iter(), |v: bool| [v as u8]),
+            DataType::UInt8 => hash_values!(physical.u8()?.into_iter(), u8::to_le_bytes),
+            DataType::UInt16 => hash_values!(physical.u16()?.into_iter(), u16::to_le_bytes),
+            DataType::UInt32 => hash_values!(physical.u32()?.into_iter(), u32::to_le_bytes),
+            DataType::UInt64 => hash_values!(physical.u64()?.into_iter(), u64::to_le_bytes),
+            DataType::Int8 => hash_values!(physical.i8()?.into_iter(), i8::to_le_bytes),
+            DataType::Int16 => hash_values!(physical.i16()?.into_iter(), i16::to_le_bytes),
+            DataType::Int32 => hash_values!(physical.i32()?.into_iter(), i32::to_le_bytes),
+            DataType::Int64 => hash_values!(physical.i64()?.into_iter(), i64::to_le_bytes),
+            DataType::Float32 => hash_values!(physical.f32()?.into_iter(), |v: f32| {
+                canonical_f64(v as f64)
+            }),
+            DataType::Float64 => hash_values!(physical.f64()?.into_iter(), canonical_f64),
+            DataType::Utf8 => hash_values!(physical.utf8()?.into_iter(), str::as_bytes),
+            // hash the strings, the categories depend on the global string cache
+            DataType::Categorical => {
+                let s = physical.cast::<Utf8Type>()?;
+                hash_values!(s.utf8()?.into_iter(), str::as_bytes)
+            }
+            #[cfg(feature = "timezones")]
+            DataType::Datetime(_) => {
+                let ca = physical.datetime()?.timestamps();
+                hash_values!(ca.into_iter(), i64::to_le_bytes)
+            }
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => {
+                hash_values!(physical.decimal()?.iter_unscaled(), i128::to_le_bytes)
+            }
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => {
+                hash_values!(physical.binary()?.into_iter(), std::convert::identity)
+            }
+            dt => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("cannot hash a Series of dtype {:?}", dt).into(),
+                ))
+            }
+        };
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
     /// Returns `None` if the array is empty or only contains null values.
     /// ```
     /// # use polars_core::prelude::*;
@@ -1171,6 +1475,109 @@ impl Series {
             .and_then(|s| s.f64().unwrap().get(0).and_then(T::from))
     }
 
+    /// Apply a rolling aggregation over the values of this Series. The null values in a window
+    /// are ignored.
+    ///
+    /// If `center` is set, the result of a window is set at its center instead of at its end. The
+    /// windows at the end of the Series are then filled with less than `window_size` values.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1.0, 2.0, 3.0, 4.0]);
+    /// let options = RollingOptions {
+    ///     window_size: 3,
+    ///     center: true,
+    ///     ..Default::default()
+    /// };
+    /// let out = s.rolling(RollingFunction::Sum, &options).unwrap();
+    /// assert_eq!(Vec::from(out.f64().unwrap()), &[Some(3.0), Some(6.0), Some(9.0), Some(7.0)]);
+    /// ```
+    pub fn rolling(&self, function: RollingFunction, options: &RollingOptions) -> Result<Series> {
+        let offset = if options.center {
+            options.window_size.saturating_sub(1) as usize / 2
+        } else {
+            0
+        };
+        // pad the end with null values, so that the windows at the end can be centered
+        let s = if offset > 0 {
+            let mut idx = (0..self.len())
+                .map(Some)
+                .chain(std::iter::repeat(None).take(offset));
+            self.take_opt_iter(&mut idx)
+        } else {
+            self.clone()
+        };
+        let window_size = options.window_size;
+        let weight = options.weights.as_deref();
+        let min_periods = options.min_periods;
+        let mut out = match function {
+            RollingFunction::Mean => s.rolling_mean(window_size, weight, true, min_periods),
+            RollingFunction::Sum => s.rolling_sum(window_size, weight, true, min_periods),
+            RollingFunction::Min => s.rolling_min(window_size, weight, true, min_periods),
+            RollingFunction::Max => s.rolling_max(window_size, weight, true, min_periods),
+            RollingFunction::Var => s.rolling_var(window_size, weight, true, min_periods),
+            RollingFunction::Std => s.rolling_std(window_size, weight, true, min_periods),
+        }?;
+        out.rename(self.name());
+        Ok(out.slice(offset as i64, self.len()))
+    }
+
+    #[cfg(feature = "ewma")]
+    fn ewm_f64(&self) -> Result<Series> {
+        use DataType::*;
+        match self.dtype() {
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64 => {
+                self.cast::<Float64Type>()
+            }
+            dt => Err(PolarsError::InvalidOperation(
+                format!(
+                    "exponentially weighted aggregations not supported for Series with dtype {:?}",
+                    dt
+                )
+                .into(),
+            )),
+        }
+    }
+
+    /// Compute the exponentially weighted moving mean. See
+    /// [EwmOptions](crate::prelude::EwmOptions) for the parameters.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1, 3, 5]);
+    /// let options = EwmOptions {
+    ///     adjust: false,
+    ///     ..EwmOptions::alpha(0.5)
+    /// };
+    /// let out = s.ewm_mean(&options).unwrap();
+    /// assert_eq!(Vec::from(out.f64().unwrap()), &[Some(1.0), Some(2.0), Some(3.5)]);
+    /// ```
+    #[cfg(feature = "ewma")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ewma")))]
+    pub fn ewm_mean(&self, options: &EwmOptions) -> Result<Series> {
+        let s = self.ewm_f64()?;
+        s.f64()
+            .unwrap()
+            .ewm_mean(options)
+            .map(|ca| ca.into_series())
+    }
+
+    /// Compute the exponentially weighted moving standard deviation.
+    #[cfg(feature = "ewma")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ewma")))]
+    pub fn ewm_std(&self, options: &EwmOptions) -> Result<Series> {
+        let s = self.ewm_f64()?;
+        s.f64().unwrap().ewm_std(options).map(|ca| ca.into_series())
+    }
+
+    /// Compute the exponentially weighted moving variance.
+    #[cfg(feature = "ewma")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ewma")))]
+    pub fn ewm_var(&self, options: &EwmOptions) -> Result<Series> {
+        let s = self.ewm_f64()?;
+        s.f64().unwrap().ewm_var(options).map(|ca| ca.into_series())
+    }
+
     /// Explode a list or utf8 Series. This expands every item to a new row..
     pub fn explode(&self) -> Result<Series> {
         match self.dtype() {
@@ -1201,14 +1608,14 @@ impl Series {
         }
     }
 
-    /// Check if float value is NaN (note this is different than missing/ null)
+    /// Check if float value is not NaN (note this is different than missing/ null)
     pub fn is_not_nan(&self) -> Result<BooleanChunked> {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_not_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_not_nan()),
             _ => Err(PolarsError::InvalidOperation(
                 format!(
-                    "is_nan not supported for series with dtype {:?}",
+                    "is_not_nan not supported for series with dtype {:?}",
                     self.dtype()
                 )
                 .into(),
@@ -1223,7 +1630,7 @@ impl Series {
             DataType::Float64 => Ok(self.f64().unwrap().is_finite()),
             _ => Err(PolarsError::InvalidOperation(
                 format!(
-                    "is_nan not supported for series with dtype {:?}",
+                    "is_finite not supported for series with dtype {:?}",
                     self.dtype()
                 )
                 .into(),
@@ -1231,14 +1638,14 @@ impl Series {
         }
     }
 
-    /// Check if float value is finite
+    /// Check if float value is infinite
     pub fn is_infinite(&self) -> Result<BooleanChunked> {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_infinite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_infinite()),
             _ => Err(PolarsError::InvalidOperation(
                 format!(
-                    "is_nan not supported for series with dtype {:?}",
+                    "is_infinite not supported for series with dtype {:?}",
                     self.dtype()
                 )
                 .into(),
@@ -1246,6 +1653,138 @@ impl Series {
         }
     }
 
+    /// Get the unique values in the Series in the order of their first occurrence.
+    /// This is more expensive than [`unique`](SeriesTrait::unique), which doesn't maintain order.
+    pub fn unique_stable(&self) -> Result<Series> {
+        let idx = self.arg_unique()?;
+        // Safety:
+        // arg_unique returns indexes that are in bounds
+        unsafe { self.take_unchecked(&idx) }
+    }
+
+    /// Bin the values of a numeric Series into discrete intervals.
+    ///
+    /// The `breaks` are the edges of the bins and must be strictly increasing. Bins are closed on
+    /// the right, e.g. `(0.0, 1.0]`. Values outside of the range of `breaks` and null values are
+    /// mapped to null.
+    ///
+    /// If `labels` are given, there should be exactly `breaks.len() - 1` of them. Otherwise the
+    /// intervals are formatted as labels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    ///
+    /// let s = Series::new("a", &[1.0, 2.5, 4.0]);
+    /// let binned = s.cut(&[0.0, 2.0, 4.0], Some(&["low", "high"])).unwrap();
+    /// assert_eq!(Vec::from(binned.utf8().unwrap()), &[Some("low"), Some("high"), Some("high")]);
+    /// ```
+    pub fn cut(&self, breaks: &[f64], labels: Option<&[&str]>) -> Result<Series> {
+        self.cut_impl(breaks, labels, false)
+    }
+
+    /// Bin the values of a numeric Series into discrete intervals, where the break edges are
+    /// computed from the given `quantiles` of the data. The lowest bin is closed on both sides,
+    /// so that the minimum value falls into the first bin.
+    ///
+    /// See [cut](Series::cut) for more info.
+    pub fn qcut(&self, quantiles: &[f64]) -> Result<Series> {
+        let s = self.cast_with_dtype(&DataType::Float64)?;
+        let ca = s.f64().unwrap();
+        let breaks = quantiles
+            .iter()
+            .map(|&q| {
+                ca.quantile(q, QuantileInterpolOptions::Linear)?
+                    .ok_or_else(|| {
+                        PolarsError::NoData("cannot compute quantiles of an empty Series".into())
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.cut_impl(&breaks, None, true)
+    }
+
+    fn cut_impl(
+        &self,
+        breaks: &[f64],
+        labels: Option<&[&str]>,
+        include_lowest: bool,
+    ) -> Result<Series> {
+        use DataType::*;
+        match self.dtype() {
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64 => {
+            }
+            dt => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("cut not supported for Series with dtype {:?}", dt).into(),
+                ))
+            }
+        }
+        if breaks.len() < 2 {
+            return Err(PolarsError::ValueError(
+                "cut needs at least two breaks".into(),
+            ));
+        }
+        if breaks
+            .windows(2)
+            .any(|w| w[0].partial_cmp(&w[1]) != Some(std::cmp::Ordering::Less))
+        {
+            return Err(PolarsError::ValueError(
+                "breaks of cut should be strictly increasing".into(),
+            ));
+        }
+        let labels: Vec<String> = match labels {
+            Some(labels) => {
+                if labels.len() != breaks.len() - 1 {
+                    return Err(PolarsError::ShapeMisMatch(
+                        format!(
+                            "expected {} labels for {} breaks, got {}",
+                            breaks.len() - 1,
+                            breaks.len(),
+                            labels.len()
+                        )
+                        .into(),
+                    ));
+                }
+                labels.iter().map(|s| s.to_string()).collect()
+            }
+            None => breaks
+                .windows(2)
+                .enumerate()
+                .map(|(i, w)| {
+                    if include_lowest && i == 0 {
+                        format!("[{}, {}]", w[0], w[1])
+                    } else {
+                        format!("({}, {}]", w[0], w[1])
+                    }
+                })
+                .collect(),
+        };
+
+        let s = self.cast_with_dtype(&Float64)?;
+        let ca = s.f64().unwrap();
+        let lowest = breaks[0];
+        let mut out: Utf8Chunked = ca
+            .into_iter()
+            .map(|opt_v| {
+                opt_v.and_then(|v| {
+                    if include_lowest && v == lowest {
+                        return Some(labels[0].as_str());
+                    }
+                    // the first break that is larger or equal to the value closes the bin
+                    let idx = breaks.iter().position(|&b| v <= b)?;
+                    if idx == 0 {
+                        None
+                    } else {
+                        Some(labels[idx - 1].as_str())
+                    }
+                })
+            })
+            .collect();
+        out.rename(self.name());
+        Ok(out.into_series())
+    }
+
     /// Create a new ChunkedArray with values from self where the mask evaluates `true` and values
     /// from `other` where the mask evaluates `false`
     pub fn zip_with(&self, mask: &BooleanChunked, other: &Series) -> Result<Series> {
@@ -1535,6 +2074,51 @@ impl std::convert::TryFrom<(&str, Vec<ArrayRef>)> for Series {
             ArrowDataType::LargeList(_) => {
                 Ok(ListChunked::new_from_chunks(name, chunks).into_series())
             }
+            ArrowDataType::Dictionary(_, value_type)
+                if matches!(**value_type, ArrowDataType::Utf8 | ArrowDataType::LargeUtf8) =>
+            {
+                // decode to strings and encode again, so that the categories are registered
+                // in the global string cache if it is used.
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = cast(arr, &ArrowDataType::Utf8)?;
+                        cast(&arr, &ArrowDataType::LargeUtf8)
+                    })
+                    .collect::<arrow::error::Result<Vec<_>>>()?;
+                let ca = Utf8Chunked::new_from_chunks(name, chunks);
+                Ok(ca.cast::<CategoricalType>()?.into_series())
+            }
+            #[cfg(feature = "dtype-struct")]
+            ArrowDataType::Struct(_) => {
+                Ok(StructChunked::new_from_chunks(name, &chunks)?.into_series())
+            }
+            #[cfg(feature = "dtype-decimal")]
+            ArrowDataType::Decimal(_, _) => {
+                Ok(DecimalChunked::try_from_chunks(name, chunks)?.into_series())
+            }
+            #[cfg(feature = "dtype-binary")]
+            ArrowDataType::LargeBinary => {
+                Ok(BinaryChunked::new_from_chunks(name, chunks).into_series())
+            }
+            #[cfg(feature = "dtype-binary")]
+            ArrowDataType::Binary => {
+                use arrow::array::{Array, BinaryArray};
+                // rebuild with 64 bit offsets
+                let len = chunks.iter().fold(0, |acc, array| acc + array.len());
+                let mut builder = BinaryChunkedBuilder::new(name, len);
+                for arr in &chunks {
+                    let arr = arr.as_any().downcast_ref::<BinaryArray>().unwrap();
+                    for idx in 0..arr.len() {
+                        if arr.is_null(idx) {
+                            builder.append_null()
+                        } else {
+                            builder.append_value(arr.value(idx))
+                        }
+                    }
+                }
+                Ok(builder.finish().into_series())
+            }
             ArrowDataType::Null => {
                 // we don't support null types yet so we use a small digit type filled with nulls
                 let len = chunks.iter().fold(0, |acc, array| acc + array.len());
@@ -1543,6 +2127,15 @@ impl std::convert::TryFrom<(&str, Vec<ArrayRef>)> for Series {
                 #[cfg(not(feature = "dtype-i8"))]
                 Ok(UInt32Chunked::full_null(name, len).into_series())
             }
+            #[cfg(feature = "timezones")]
+            ArrowDataType::Timestamp(_, Some(tz)) => {
+                let dtype = ArrowDataType::Timestamp(TimeUnit::Millisecond, Some(tz.clone()));
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| cast(arr, &dtype))
+                    .collect::<arrow::error::Result<Vec<_>>>()?;
+                Ok(DatetimeChunked::new_from_chunks(name, chunks).into_series())
+            }
             #[cfg(feature = "dtype-date64")]
             ArrowDataType::Timestamp(TimeUnit::Millisecond, None) => {
                 let chunks = chunks
@@ -1648,6 +2241,14 @@ mod test {
         Series::try_from(("foo", array_ref)).unwrap();
     }
 
+    #[test]
+    fn unique_stable() -> Result<()> {
+        let s = Series::new("a", &[Some(3), None, Some(1), Some(3), None, Some(2)]);
+        let out = s.unique_stable()?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(3), None, Some(1), Some(2)]);
+        Ok(())
+    }
+
     #[test]
     fn series_append() {
         let mut s1 = Series::new("a", &[1, 2]);
@@ -1673,6 +2274,90 @@ mod test {
         assert_eq!(slice_3.get(0), AnyValue::Int64(1));
     }
 
+    #[test]
+    fn series_float_masks() {
+        let s = Series::new(
+            "a",
+            &[
+                Some(1.0f32),
+                None,
+                Some(f32::NAN),
+                Some(f32::INFINITY),
+                Some(f32::NEG_INFINITY),
+            ],
+        );
+        assert_eq!(
+            Vec::from(&s.is_nan().unwrap()),
+            &[Some(false), None, Some(true), Some(false), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(&s.is_not_nan().unwrap()),
+            &[Some(true), None, Some(false), Some(true), Some(true)]
+        );
+        assert_eq!(
+            Vec::from(&s.is_finite().unwrap()),
+            &[Some(true), None, Some(false), Some(false), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(&s.cast::<Float64Type>().unwrap().is_infinite().unwrap()),
+            &[Some(false), None, Some(false), Some(true), Some(true)]
+        );
+
+        let s = Series::new("a", &[1, 2]);
+        assert!(s.is_nan().is_err());
+        assert!(s.is_not_nan().is_err());
+        assert!(s.is_finite().is_err());
+        assert!(s.is_infinite().is_err());
+    }
+
+    #[test]
+    fn series_cut() {
+        let s = Series::new(
+            "a",
+            &[Some(0.5), Some(1.0), None, Some(2.5), Some(5.0), Some(-1.0)],
+        );
+        let out = s.cut(&[0.0, 1.0, 3.0], None).unwrap();
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.utf8().unwrap()),
+            &[
+                Some("(0, 1]"),
+                Some("(0, 1]"),
+                None,
+                Some("(1, 3]"),
+                None,
+                None
+            ]
+        );
+
+        let out = s.cut(&[0.0, 1.0, 3.0], Some(&["low", "high"])).unwrap();
+        assert_eq!(
+            Vec::from(out.utf8().unwrap()),
+            &[Some("low"), Some("low"), None, Some("high"), None, None]
+        );
+
+        // non monotonic breaks, wrong number of labels and non numeric data
+        assert!(s.cut(&[0.0, 3.0, 1.0], None).is_err());
+        assert!(s.cut(&[0.0, 1.0, 3.0], Some(&["low"])).is_err());
+        assert!(Series::new("a", &["a"]).cut(&[0.0, 1.0], None).is_err());
+    }
+
+    #[test]
+    fn series_qcut() {
+        let s = Series::new("a", &[1, 2, 3, 4, 5]);
+        let out = s.qcut(&[0.0, 0.5, 1.0]).unwrap();
+        assert_eq!(
+            Vec::from(out.utf8().unwrap()),
+            &[
+                Some("[1, 3]"),
+                Some("[1, 3]"),
+                Some("[1, 3]"),
+                Some("(3, 5]"),
+                Some("(3, 5]")
+            ]
+        );
+    }
+
     #[test]
     fn out_of_range_slice_does_not_panic() {
         let series = Series::new("a", &[1i64, 2, 3, 4, 5]);
@@ -0,0 +1,48 @@
+//! The most frequent values of a Series.
+use crate::prelude::*;
+
+impl Series {
+    /// Get the most frequent value(s). If multiple values occur the maximum number of times,
+    /// all of them are returned, sorted in ascending order. Null values are ignored.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[Some(3), Some(1), None, Some(3), Some(1), None, None]);
+    /// let out = s.mode().unwrap();
+    /// assert_eq!(Vec::from(out.i32().unwrap()), &[Some(1), Some(3)]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "mode")))]
+    pub fn mode(&self) -> Result<Series> {
+        let df = self.value_counts(false)?;
+        // the output of value_counts is sorted by the counts in descending order
+        let (values, counts) = (&df.get_columns()[0], &df.get_columns()[1]);
+        let counts = counts.u32()?;
+        let mut out = match counts.get(0) {
+            Some(max_count) => values.filter(&counts.eq(max_count))?.sort(false),
+            None => self.slice(0, 0),
+        };
+        out.rename(self.name());
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mode() -> Result<()> {
+        let s = Series::new("a", &["b", "a", "b", "c"]);
+        let out = s.mode()?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.utf8()?), &[Some("b")]);
+
+        // a Series named like the count column of value_counts
+        let s = Series::new("counts", &[Some(1.5), None, None]);
+        assert_eq!(Vec::from(s.mode()?.f64()?), &[Some(1.5)]);
+
+        let s = Series::new("a", &[None::<i32>, None]);
+        assert_eq!(s.mode()?.len(), 0);
+        Ok(())
+    }
+}
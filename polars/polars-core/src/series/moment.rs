@@ -0,0 +1,30 @@
+//! Skewness and kurtosis of a Series.
+use crate::prelude::*;
+
+impl Series {
+    fn moment_ca(&self, operation: &str) -> Result<Float64Chunked> {
+        use DataType::*;
+        match self.dtype() {
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64 => {
+                Ok(self.cast::<Float64Type>()?.f64()?.clone())
+            }
+            dt => Err(PolarsError::InvalidOperation(
+                format!("{} not supported for Series with dtype {:?}", operation, dt).into(),
+            )),
+        }
+    }
+
+    /// Compute the sample skewness of the values. Null values are ignored.
+    ///
+    /// See [ChunkedArray::skew](crate::chunked_array::ChunkedArray::skew).
+    pub fn skew(&self, bias: bool) -> Result<Option<f64>> {
+        Ok(self.moment_ca("skew")?.skew(bias))
+    }
+
+    /// Compute the kurtosis of the values. Null values are ignored.
+    ///
+    /// See [ChunkedArray::kurtosis](crate::chunked_array::ChunkedArray::kurtosis).
+    pub fn kurtosis(&self, fisher: bool, bias: bool) -> Result<Option<f64>> {
+        Ok(self.moment_ca("kurtosis")?.kurtosis(fisher, bias))
+    }
+}
@@ -0,0 +1,150 @@
+//! Rank the values of a Series.
+use crate::prelude::*;
+
+/// How to rank the values that are equal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RankMethod {
+    /// The mean of the ranks the equal values would get with `Ordinal`.
+    Average,
+    /// The lowest rank of the equal values.
+    Min,
+    /// The highest rank of the equal values.
+    Max,
+    /// Like `Min`, but the next value that is not equal gets the next rank, so there are no gaps
+    /// between the ranks.
+    Dense,
+    /// A distinct rank for every value, in the order in which the values occur.
+    Ordinal,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RankOptions {
+    pub method: RankMethod,
+    /// Give the largest value rank 1.
+    pub descending: bool,
+}
+
+impl Default for RankOptions {
+    fn default() -> Self {
+        RankOptions {
+            method: RankMethod::Average,
+            descending: false,
+        }
+    }
+}
+
+impl Series {
+    /// Assign a rank to every value, starting at 1. Null values get a null rank.
+    ///
+    /// The ranks are of type `Float64` for [RankMethod::Average] and of type `UInt32` otherwise.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[3, 1, 3, 2]);
+    /// let options = RankOptions {
+    ///     method: RankMethod::Min,
+    ///     ..Default::default()
+    /// };
+    /// let ranks = s.rank(options);
+    /// assert_eq!(Vec::from(ranks.u32().unwrap()), &[Some(3), Some(1), Some(3), Some(2)]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rank")))]
+    pub fn rank(&self, options: RankOptions) -> Series {
+        let is_valid = self.is_not_null();
+        let is_valid = is_valid.into_iter().map(|opt_v| opt_v == Some(true));
+        let is_valid = is_valid.collect::<Vec<_>>();
+        // argsort is stable, so equal values keep the order in which they occur
+        let sort_idx = self
+            .argsort(options.descending)
+            .into_no_null_iter()
+            .filter(|i| is_valid[*i as usize])
+            .collect::<Vec<_>>();
+        let n = sort_idx.len();
+
+        // `is_new[i]` is true if the i-th sorted value is not equal to the one before it
+        let mut is_new = vec![true; n];
+        if n > 1 {
+            let sorted = self.take_iter(&mut sort_idx.iter().map(|i| *i as usize));
+            let neq = sorted.slice(1, n - 1).neq(&sorted.slice(0, n - 1));
+            for (flag, opt_v) in is_new[1..].iter_mut().zip(neq.into_iter()) {
+                *flag = opt_v.unwrap_or(true);
+            }
+        }
+
+        let mut ranks: Vec<Option<f64>> = vec![None; self.len()];
+        let mut start = 0;
+        let mut dense_rank = 0;
+        // ranks are assigned per run of equal values in the sorted order
+        while start < n {
+            let mut end = start + 1;
+            while end < n && !is_new[end] {
+                end += 1;
+            }
+            dense_rank += 1;
+            for (j, idx) in sort_idx[start..end].iter().enumerate() {
+                let rank = match options.method {
+                    RankMethod::Average => (start + end + 1) as f64 / 2.0,
+                    RankMethod::Min => (start + 1) as f64,
+                    RankMethod::Max => end as f64,
+                    RankMethod::Dense => dense_rank as f64,
+                    RankMethod::Ordinal => (start + j + 1) as f64,
+                };
+                ranks[*idx as usize] = Some(rank);
+            }
+            start = end;
+        }
+
+        let mut out = match options.method {
+            RankMethod::Average => ranks.into_iter().collect::<Float64Chunked>().into_series(),
+            _ => ranks
+                .into_iter()
+                .map(|opt_v| opt_v.map(|v| v as u32))
+                .collect::<UInt32Chunked>()
+                .into_series(),
+        };
+        out.rename(self.name());
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rank() {
+        let s = Series::new("a", &[Some(2), None, Some(1), Some(2), Some(3), Some(2)]);
+        let rank = |method, descending| s.rank(RankOptions { method, descending });
+
+        let out = rank(RankMethod::Average, false);
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(3.0), None, Some(1.0), Some(3.0), Some(5.0), Some(3.0)]
+        );
+        let out = rank(RankMethod::Min, false);
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[Some(2), None, Some(1), Some(2), Some(5), Some(2)]
+        );
+        let out = rank(RankMethod::Max, false);
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[Some(4), None, Some(1), Some(4), Some(5), Some(4)]
+        );
+        let out = rank(RankMethod::Dense, false);
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[Some(2), None, Some(1), Some(2), Some(3), Some(2)]
+        );
+        let out = rank(RankMethod::Ordinal, false);
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[Some(2), None, Some(1), Some(3), Some(5), Some(4)]
+        );
+        let out = rank(RankMethod::Dense, true);
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[Some(2), None, Some(3), Some(2), Some(1), Some(2)]
+        );
+    }
+}
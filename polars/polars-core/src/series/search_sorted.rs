@@ -0,0 +1,136 @@
+//! Binary search in a sorted Series.
+use crate::prelude::*;
+use crate::utils::get_supertype;
+
+/// Determines the insertion index of a value that is equal to values in the searched Series.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SearchSortedSide {
+    /// The index of the first equal value.
+    Left,
+    /// The index after the last equal value.
+    Right,
+}
+
+impl Default for SearchSortedSide {
+    fn default() -> Self {
+        SearchSortedSide::Left
+    }
+}
+
+fn search_sorted_iter<T, I, J>(
+    sorted: I,
+    values: J,
+    side: SearchSortedSide,
+    name: &str,
+) -> UInt32Chunked
+where
+    T: PartialOrd,
+    I: Iterator<Item = Option<T>>,
+    J: Iterator<Item = Option<T>>,
+{
+    let sorted: Vec<_> = sorted.collect();
+    let mut ca: UInt32Chunked = values
+        .map(|v| {
+            let idx = match side {
+                SearchSortedSide::Left => sorted.partition_point(|x| *x < v),
+                SearchSortedSide::Right => sorted.partition_point(|x| *x <= v),
+            };
+            Some(idx as u32)
+        })
+        .collect();
+    ca.rename(name);
+    ca
+}
+
+impl Series {
+    /// Find the indices where `values` should be inserted to keep this Series sorted. The Series
+    /// should be sorted in ascending order, with the null values first.
+    ///
+    /// `side` determines the index of values that are equal to values in the Series.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1, 2, 2, 5]);
+    /// let values = Series::new("values", &[0, 2, 6]);
+    /// let idx = s.search_sorted(&values, SearchSortedSide::Left).unwrap();
+    /// assert_eq!(Vec::from(&idx), &[Some(0), Some(1), Some(4)]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "search_sorted")))]
+    pub fn search_sorted(&self, values: &Series, side: SearchSortedSide) -> Result<UInt32Chunked> {
+        use DataType::*;
+        // compare in the supertype, so that a float is not truncated to search an integer Series
+        let dtype = get_supertype(self.dtype(), values.dtype())?;
+        let values = values.cast_with_dtype(&dtype)?.to_physical_repr();
+        let s = self.cast_with_dtype(&dtype)?;
+        let s = s.to_physical_repr();
+        let name = values.name();
+
+        match s.dtype() {
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64 => {
+                macro_rules! numeric {
+                    ($ca:expr) => {{
+                        let other = $ca.unpack_series_matching_type(&values)?;
+                        Ok(search_sorted_iter(
+                            $ca.into_iter(),
+                            other.into_iter(),
+                            side,
+                            name,
+                        ))
+                    }};
+                }
+                macro_rules! noop {
+                    ($ca:expr) => {{
+                        unreachable!()
+                    }};
+                }
+                match_arrow_data_type_apply_macro_ca!(s, numeric, noop, noop)
+            }
+            Utf8 => Ok(search_sorted_iter(
+                s.utf8()?.into_iter(),
+                values.utf8()?.into_iter(),
+                side,
+                name,
+            )),
+            Boolean => Ok(search_sorted_iter(
+                s.bool()?.into_iter(),
+                values.bool()?.into_iter(),
+                side,
+                name,
+            )),
+            dt => Err(PolarsError::InvalidOperation(
+                format!("search_sorted not supported for Series with dtype {:?}", dt).into(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_search_sorted() -> Result<()> {
+        let s = Series::new("a", &[None, Some(1.0), Some(2.0), Some(2.0), Some(5.5)]);
+        let values = Series::new("values", &[Some(2), Some(6), Some(-1), None]);
+
+        let out = s.search_sorted(&values, SearchSortedSide::Left)?;
+        assert_eq!(out.name(), "values");
+        assert_eq!(Vec::from(&out), &[Some(2), Some(5), Some(1), Some(0)]);
+        let out = s.search_sorted(&values, SearchSortedSide::Right)?;
+        assert_eq!(Vec::from(&out), &[Some(4), Some(5), Some(1), Some(1)]);
+
+        // the values are not truncated to the dtype of the Series
+        let s = Series::new("a", &[1, 2, 3]);
+        let values = Series::new("values", &[1.5, 2.0]);
+        let out = s.search_sorted(&values, SearchSortedSide::Left)?;
+        assert_eq!(Vec::from(&out), &[Some(1), Some(1)]);
+        let out = s.search_sorted(&values, SearchSortedSide::Right)?;
+        assert_eq!(Vec::from(&out), &[Some(1), Some(2)]);
+
+        let s = Series::new("a", &["a", "c", "e"]);
+        let values = Series::new("values", &["d", "a"]);
+        let out = s.search_sorted(&values, SearchSortedSide::Right)?;
+        assert_eq!(Vec::from(&out), &[Some(2), Some(1)]);
+        Ok(())
+    }
+}
@@ -544,6 +544,74 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Result<DataType> {
     }
 }
 
+/// Arithmetic operations that have dedicated rules for temporal data types.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ArithmeticOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// Determine the output type of an arithmetic operation on temporal data types that doesn't follow
+/// the supertype rules, e.g. `Date64 - Date64 = Duration`.
+///
+/// * date - date = duration (milliseconds)
+/// * date ± duration = date. `Date32` becomes `Date64` so that sub-day durations aren't truncated.
+/// * duration ± duration = duration (in the finest unit of the two)
+/// * duration * number = duration and duration / number = duration
+///
+/// The results are whole numbers of their unit. A fractional result, e.g. of a duration
+/// multiplied by a float or divided by an integer, is truncated toward zero. Likewise, a
+/// nanosecond duration added to a date is truncated to milliseconds.
+///
+/// Returns `None` if the regular supertype rules apply.
+pub fn get_temporal_arithmetic_type(
+    l: &DataType,
+    op: ArithmeticOp,
+    r: &DataType,
+) -> Option<DataType> {
+    use ArithmeticOp::*;
+    use DataType::*;
+
+    fn is_date(dt: &DataType) -> bool {
+        matches!(dt, Date32 | Date64)
+    }
+    fn is_number(dt: &DataType) -> bool {
+        matches!(
+            dt,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64
+        )
+    }
+
+    match (l, op, r) {
+        (l, Sub, r) if is_date(l) && is_date(r) => Some(Duration(TimeUnit::Millisecond)),
+        #[cfg(feature = "timezones")]
+        (Datetime(_), Sub, Datetime(_)) => Some(Duration(TimeUnit::Millisecond)),
+
+        (l, Add, Duration(_)) | (l, Sub, Duration(_)) if is_date(l) => Some(Date64),
+        (Duration(_), Add, r) if is_date(r) => Some(Date64),
+        #[cfg(feature = "timezones")]
+        (Datetime(tz), Add, Duration(_))
+        | (Datetime(tz), Sub, Duration(_))
+        | (Duration(_), Add, Datetime(tz)) => Some(Datetime(tz.clone())),
+
+        (Duration(lu), Add, Duration(ru)) | (Duration(lu), Sub, Duration(ru)) => {
+            if lu == &TimeUnit::Nanosecond || ru == &TimeUnit::Nanosecond {
+                Some(Duration(TimeUnit::Nanosecond))
+            } else {
+                Some(Duration(TimeUnit::Millisecond))
+            }
+        }
+
+        (Duration(unit), Mul, r) | (Duration(unit), Div, r) if is_number(r) => {
+            Some(Duration(unit.clone()))
+        }
+        (l, Mul, Duration(unit)) if is_number(l) => Some(Duration(unit.clone())),
+        _ => None,
+    }
+}
+
 /// Given two datatypes, determine the supertype that both types can safely be cast to
 fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
     use DataType::*;
@@ -693,6 +761,40 @@ fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
         (Date64, Float64) => Some(Float64),
         (Date64, Date32) => Some(Date64),
 
+        #[cfg(feature = "dtype-decimal")]
+        (Decimal(p_l, s_l), Decimal(p_r, s_r)) => {
+            // keep enough digits on both sides of the decimal point, as far as the maximum
+            // precision allows. Values that don't fit fail when they are cast.
+            let scale = std::cmp::max(*s_l, *s_r);
+            let int_digits = std::cmp::max(p_l - s_l, p_r - s_r);
+            Some(Decimal(
+                std::cmp::min(int_digits + scale, DECIMAL_MAX_PRECISION),
+                scale,
+            ))
+        }
+        #[cfg(feature = "dtype-decimal")]
+        (Decimal(p, s), Int8)
+        | (Decimal(p, s), Int16)
+        | (Decimal(p, s), Int32)
+        | (Decimal(p, s), Int64)
+        | (Decimal(p, s), UInt8)
+        | (Decimal(p, s), UInt16)
+        | (Decimal(p, s), UInt32)
+        | (Decimal(p, s), UInt64) => Some(Decimal(*p, *s)),
+        #[cfg(feature = "dtype-decimal")]
+        (Decimal(_, _), Float32) | (Decimal(_, _), Float64) => Some(Float64),
+
+        // the values are UTC timestamps, only the time zone in which they are shown differs
+        #[cfg(feature = "timezones")]
+        (Datetime(_), Datetime(_)) => Some(Datetime("UTC".to_string())),
+        #[cfg(feature = "timezones")]
+        (Datetime(tz), Date64) | (Datetime(tz), Date32) => Some(Datetime(tz.clone())),
+        // strings are parsed to datetimes
+        #[cfg(feature = "timezones")]
+        (Datetime(tz), Utf8) | (Utf8, Datetime(tz)) => Some(Datetime(tz.clone())),
+        #[cfg(feature = "timezones")]
+        (Datetime(_), Int64) => Some(Int64),
+
         (Utf8, _) => Some(Utf8),
         (_, Utf8) => Some(Utf8),
 
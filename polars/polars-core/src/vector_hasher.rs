@@ -1,5 +1,7 @@
 use crate::datatypes::UInt64Chunked;
 use crate::prelude::*;
+#[cfg(feature = "dtype-binary")]
+use crate::utils::NoNull;
 use crate::POOL;
 use ahash::RandomState;
 use arrow::array::ArrayRef;
@@ -51,6 +53,23 @@ impl VecHash for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl VecHash for BinaryChunked {
+    fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
+        let ca: NoNull<UInt64Chunked> = self
+            .into_iter()
+            .map(|opt_v| {
+                let mut hasher = random_state.build_hasher();
+                opt_v.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect();
+        let mut ca = ca.into_inner();
+        ca.rename(self.name());
+        ca
+    }
+}
+
 impl VecHash for BooleanChunked {
     fn vec_hash(&self, random_state: RandomState) -> UInt64Chunked {
         self.branch_apply_cast_numeric_no_null(|opt_v| {
@@ -370,8 +389,31 @@ where
     (hashes, build_hasher)
 }
 
+/// A hash that only depends on `bytes` and the `seed`, so that it is the same on every platform
+/// and in every run: FNV-1a followed by the finalizer of splitmix64. A null value (`None`) has
+/// its own hash.
+pub(crate) fn stable_hash(bytes: Option<&[u8]>, seed: u64) -> u64 {
+    const FNV_PRIME: u64 = 0x100000001b3;
+    let mut h = 0xcbf29ce484222325u64 ^ seed;
+    let mut write = |b: u8| {
+        h ^= b as u64;
+        h = h.wrapping_mul(FNV_PRIME);
+    };
+    match bytes {
+        None => write(0),
+        Some(bytes) => {
+            write(1);
+            bytes.iter().for_each(|&b| write(b));
+        }
+    }
+    let mut z = h;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
 // hash combine from c++' boost lib
-fn boost_hash_combine(l: u64, r: u64) -> u64 {
+pub(crate) fn boost_hash_combine(l: u64, r: u64) -> u64 {
     l ^ r.wrapping_add(0x9e3779b9u64.wrapping_add(l << 6).wrapping_add(r >> 2))
 }
 
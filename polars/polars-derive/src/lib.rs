@@ -0,0 +1,158 @@
+//! Derive macros that convert between a `Vec` of structs and a Polars `DataFrame`.
+//!
+//! Every field of the struct maps to a column with the same name. Fields of type `Option<T>`
+//! map to columns with null values. The field types must implement
+//! [DataFrameField](https://docs.rs/polars/latest/polars/frame/row/trait.DataFrameField.html).
+//!
+//! Use these macros through the `derive` feature of polars.
+//!
+//! # Example
+//!
+//! ```rust
+//! use polars::prelude::*;
+//!
+//! #[derive(Debug, PartialEq, FromDataFrame, IntoDataFrame)]
+//! struct Person {
+//!     name: String,
+//!     age: i32,
+//!     email: Option<String>,
+//! }
+//!
+//! # fn main() -> Result<()> {
+//! let people = vec![
+//!     Person { name: "Alice".into(), age: 30, email: Some("alice@example.com".into()) },
+//!     Person { name: "Bob".into(), age: 42, email: None },
+//! ];
+//!
+//! let df = Person::into_df(people)?;
+//! assert_eq!(df.get_column_names(), &["name", "age", "email"]);
+//! assert_eq!(df.column("email")?.null_count(), 1);
+//!
+//! let people = Person::from_df(&df)?;
+//! assert_eq!(people[1], Person { name: "Bob".into(), age: 42, email: None });
+//! # Ok(())
+//! # }
+//! ```
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, Type};
+
+/// The fields of the struct with their column name and type.
+fn named_fields(input: &DeriveInput) -> syn::Result<Vec<(&Ident, String, &Type)>> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields
+                .named
+                .iter()
+                .map(|field| {
+                    let ident = field.ident.as_ref().unwrap();
+                    (ident, ident.unraw().to_string(), &field.ty)
+                })
+                .collect()),
+            _ => Err(syn::Error::new_spanned(
+                &input.ident,
+                "only structs with named fields can be converted to and from a DataFrame",
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "only structs can be converted to and from a DataFrame",
+        )),
+    }
+}
+
+fn expand_into_dataframe(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = named_fields(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let idents = fields.iter().map(|(ident, _, _)| ident).collect::<Vec<_>>();
+    let columns = fields.iter().map(|(_, column, _)| column);
+    let types = fields.iter().map(|(_, _, ty)| ty).collect::<Vec<_>>();
+    let values = idents
+        .iter()
+        .map(|ident| format_ident!("__{}", ident.unraw()))
+        .collect::<Vec<_>>();
+
+    Ok(quote! {
+        impl #impl_generics ::polars::frame::row::IntoDataFrame for #name #ty_generics #where_clause {
+            fn into_df<I: ::std::iter::IntoIterator<Item = Self>>(
+                rows: I,
+            ) -> ::polars::error::Result<::polars::frame::DataFrame> {
+                let rows = rows.into_iter();
+                let (capacity, _) = rows.size_hint();
+                #(
+                    let mut #values: ::std::vec::Vec<#types> =
+                        ::std::vec::Vec::with_capacity(capacity);
+                )*
+                for row in rows {
+                    #( #values.push(row.#idents); )*
+                }
+                ::polars::frame::DataFrame::new(::std::vec![
+                    #(
+                        <#types as ::polars::frame::row::DataFrameField>::into_series(
+                            #columns,
+                            #values,
+                        ),
+                    )*
+                ])
+            }
+        }
+    })
+}
+
+fn expand_from_dataframe(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = named_fields(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let idents = fields.iter().map(|(ident, _, _)| ident).collect::<Vec<_>>();
+    let columns = fields.iter().map(|(_, column, _)| column);
+    let types = fields.iter().map(|(_, _, ty)| ty);
+    let values = idents
+        .iter()
+        .map(|ident| format_ident!("__{}", ident.unraw()))
+        .collect::<Vec<_>>();
+
+    Ok(quote! {
+        impl #impl_generics ::polars::frame::row::FromDataFrame for #name #ty_generics #where_clause {
+            fn from_df(
+                df: &::polars::frame::DataFrame,
+            ) -> ::polars::error::Result<::std::vec::Vec<Self>> {
+                #(
+                    let mut #values = <#types as ::polars::frame::row::DataFrameField>::from_series(
+                        df.column(#columns)?,
+                    )?
+                    .into_iter();
+                )*
+                ::std::result::Result::Ok((0..df.height())
+                    .map(|_| Self {
+                        #( #idents: #values.next().unwrap(), )*
+                    })
+                    .collect())
+            }
+        }
+    })
+}
+
+/// Implement `IntoDataFrame`, which creates a `DataFrame` with a column per field from rows of
+/// the struct.
+#[proc_macro_derive(IntoDataFrame)]
+pub fn derive_into_dataframe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_into_dataframe(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Implement `FromDataFrame`, which extracts rows of the struct from a `DataFrame` with a column
+/// per field.
+#[proc_macro_derive(FromDataFrame)]
+pub fn derive_from_dataframe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_dataframe(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
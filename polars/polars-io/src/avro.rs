@@ -0,0 +1,391 @@
+//! # (De)serializing Apache Avro container files.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use std::io::Cursor;
+//!
+//! let s0 = Series::new("days", &[0, 1, 2, 3, 4]);
+//! let s1 = Series::new("temp", &[22.1, 19.9, 7., 2., 3.]);
+//! let df = DataFrame::new(vec![s0, s1]).unwrap();
+//!
+//! let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+//! AvroWriter::new(&mut buf).finish(&df).expect("avro writer");
+//!
+//! buf.set_position(0);
+//! let df_read = AvroReader::new(buf).finish().unwrap();
+//! assert!(df.frame_equal(&df_read));
+//! ```
+use crate::prelude::*;
+use crate::utils::to_arrow_compatible_df;
+use avro_rs::{
+    types::Value, Reader as AvroFileReader, Schema as AvroSchema, Writer as AvroFileWriter,
+};
+use polars_core::prelude::*;
+use serde_json::json;
+use std::convert::TryFrom;
+use std::io::{Read, Seek, Write};
+
+fn to_polars_err(e: avro_rs::Error) -> PolarsError {
+    PolarsError::Various(e.into())
+}
+
+/// Map an avro schema to the polars type it is read as.
+fn avro_to_dtype(schema: &AvroSchema) -> Result<DataType> {
+    use AvroSchema::*;
+    let dtype = match schema {
+        Boolean => DataType::Boolean,
+        Int => DataType::Int32,
+        Long => DataType::Int64,
+        Float => DataType::Float32,
+        Double => DataType::Float64,
+        String | Enum { .. } => DataType::Utf8,
+        Date => DataType::Date32,
+        TimestampMillis => DataType::Date64,
+        Union(union) => match union.variants() {
+            // nullable types are written as a union with null
+            [Null, schema] | [schema, Null] => return avro_to_dtype(schema),
+            _ => {
+                return Err(PolarsError::UnknownSchema(
+                    format!("avro union {:?} is not supported", union).into(),
+                ))
+            }
+        },
+        _ => {
+            return Err(PolarsError::UnknownSchema(
+                format!("avro type {:?} is not supported", schema).into(),
+            ))
+        }
+    };
+    Ok(dtype)
+}
+
+/// Map a polars type to the avro schema it is written as.
+fn dtype_to_avro_json(dtype: &DataType) -> Result<serde_json::Value> {
+    use DataType::*;
+    let json = match dtype {
+        Boolean => json!("boolean"),
+        Int8 | Int16 | Int32 | UInt8 | UInt16 => json!("int"),
+        Int64 | UInt32 | UInt64 => json!("long"),
+        Float32 => json!("float"),
+        Float64 => json!("double"),
+        Utf8 => json!("string"),
+        Date32 => json!({"type": "int", "logicalType": "date"}),
+        Date64 => json!({"type": "long", "logicalType": "timestamp-millis"}),
+        dt => {
+            return Err(PolarsError::InvalidOperation(
+                format!("writing dtype {:?} to avro is not supported", dt).into(),
+            ))
+        }
+    };
+    Ok(json)
+}
+
+/// Column buffer that collects avro values of a single field.
+enum Buffer {
+    Boolean(Vec<Option<bool>>),
+    Int32(Vec<Option<i32>>),
+    Int64(Vec<Option<i64>>),
+    Float32(Vec<Option<f32>>),
+    Float64(Vec<Option<f64>>),
+    Utf8(Vec<Option<String>>),
+}
+
+impl Buffer {
+    fn new(dtype: &DataType) -> Self {
+        match dtype {
+            DataType::Boolean => Buffer::Boolean(vec![]),
+            DataType::Int32 | DataType::Date32 => Buffer::Int32(vec![]),
+            DataType::Int64 | DataType::Date64 => Buffer::Int64(vec![]),
+            DataType::Float32 => Buffer::Float32(vec![]),
+            DataType::Float64 => Buffer::Float64(vec![]),
+            _ => Buffer::Utf8(vec![]),
+        }
+    }
+
+    fn push(&mut self, value: Value) -> Result<()> {
+        match (self, value) {
+            (buf, Value::Union(value)) => buf.push(*value)?,
+            (Buffer::Boolean(buf), Value::Null) => buf.push(None),
+            (Buffer::Int32(buf), Value::Null) => buf.push(None),
+            (Buffer::Int64(buf), Value::Null) => buf.push(None),
+            (Buffer::Float32(buf), Value::Null) => buf.push(None),
+            (Buffer::Float64(buf), Value::Null) => buf.push(None),
+            (Buffer::Utf8(buf), Value::Null) => buf.push(None),
+            (Buffer::Boolean(buf), Value::Boolean(v)) => buf.push(Some(v)),
+            (Buffer::Int32(buf), Value::Int(v)) | (Buffer::Int32(buf), Value::Date(v)) => {
+                buf.push(Some(v))
+            }
+            (Buffer::Int64(buf), Value::Long(v))
+            | (Buffer::Int64(buf), Value::TimestampMillis(v)) => buf.push(Some(v)),
+            (Buffer::Float32(buf), Value::Float(v)) => buf.push(Some(v)),
+            (Buffer::Float64(buf), Value::Double(v)) => buf.push(Some(v)),
+            (Buffer::Utf8(buf), Value::String(v)) | (Buffer::Utf8(buf), Value::Enum(_, v)) => {
+                buf.push(Some(v))
+            }
+            (_, value) => {
+                return Err(PolarsError::DataTypeMisMatch(
+                    format!("unexpected avro value {:?}", value).into(),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    fn into_series(self, name: &str, dtype: &DataType) -> Result<Series> {
+        let s = match self {
+            Buffer::Boolean(buf) => Series::new(name, buf),
+            Buffer::Int32(buf) => Series::new(name, buf),
+            Buffer::Int64(buf) => Series::new(name, buf),
+            Buffer::Float32(buf) => Series::new(name, buf),
+            Buffer::Float64(buf) => Series::new(name, buf),
+            Buffer::Utf8(buf) => Series::new(name, buf),
+        };
+        match dtype {
+            DataType::Date32 | DataType::Date64 => s.cast_with_dtype(dtype),
+            _ => Ok(s),
+        }
+    }
+}
+
+/// Read Apache Avro container files into a DataFrame.
+///
+/// # Example
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::fs::File;
+///
+/// fn example() -> Result<DataFrame> {
+///     let file = File::open("file.avro").expect("file not found");
+///     AvroReader::new(file).finish()
+/// }
+/// ```
+pub struct AvroReader<R> {
+    reader: R,
+    rechunk: bool,
+    stop_after_n_rows: Option<usize>,
+}
+
+impl<R> AvroReader<R>
+where
+    R: Read + Seek,
+{
+    /// Stop reading when `n` rows are read.
+    pub fn with_stop_after_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.stop_after_n_rows = num_rows;
+        self
+    }
+}
+
+impl<R> SerReader<R> for AvroReader<R>
+where
+    R: Read + Seek,
+{
+    fn new(reader: R) -> Self {
+        AvroReader {
+            reader,
+            rechunk: true,
+            stop_after_n_rows: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(self) -> Result<DataFrame> {
+        let reader = AvroFileReader::new(self.reader).map_err(to_polars_err)?;
+        let fields = match reader.writer_schema() {
+            AvroSchema::Record { fields, .. } => fields
+                .iter()
+                .map(|f| Ok((f.name.clone(), avro_to_dtype(&f.schema)?)))
+                .collect::<Result<Vec<_>>>()?,
+            schema => {
+                return Err(PolarsError::UnknownSchema(
+                    format!("expected an avro record, got {:?}", schema).into(),
+                ))
+            }
+        };
+        let mut buffers = fields
+            .iter()
+            .map(|(_, dtype)| Buffer::new(dtype))
+            .collect::<Vec<_>>();
+
+        let n_rows = self.stop_after_n_rows.unwrap_or(usize::MAX);
+        for value in reader.take(n_rows) {
+            match value.map_err(to_polars_err)? {
+                Value::Record(values) => {
+                    for (buf, (_, value)) in buffers.iter_mut().zip(values) {
+                        buf.push(value)?
+                    }
+                }
+                value => {
+                    return Err(PolarsError::DataTypeMisMatch(
+                        format!("expected an avro record, got {:?}", value).into(),
+                    ))
+                }
+            }
+        }
+
+        let columns = buffers
+            .into_iter()
+            .zip(&fields)
+            .map(|(buf, (name, dtype))| buf.into_series(name, dtype))
+            .collect::<Result<Vec<_>>>()?;
+        let df = DataFrame::new(columns)?;
+        match self.rechunk {
+            true => Ok(df.agg_chunks()),
+            false => Ok(df),
+        }
+    }
+}
+
+/// Write a DataFrame to an Apache Avro container file.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::fs::File;
+///
+/// fn example(df: &DataFrame) -> Result<()> {
+///     let mut file = File::create("file.avro").expect("could not create file");
+///
+///     AvroWriter::new(&mut file)
+///         .finish(df)
+/// }
+/// ```
+pub struct AvroWriter<'a, W> {
+    writer: &'a mut W,
+}
+
+impl<'a, W> SerWriter<'a, W> for AvroWriter<'a, W>
+where
+    W: Write,
+{
+    fn new(writer: &'a mut W) -> Self {
+        AvroWriter { writer }
+    }
+
+    fn finish(self, df: &DataFrame) -> Result<()> {
+        let df = to_arrow_compatible_df(df);
+        let fields = df
+            .get_columns()
+            .iter()
+            .map(|s| {
+                Ok(json!({
+                    "name": s.name(),
+                    "type": ["null", dtype_to_avro_json(s.dtype())?]
+                }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let schema = json!({"type": "record", "name": "polars", "fields": fields}).to_string();
+        let schema = AvroSchema::parse_str(&schema).map_err(to_polars_err)?;
+
+        let mut writer = AvroFileWriter::new(&schema, &mut *self.writer);
+        for idx in 0..df.height() {
+            let record = df
+                .get_columns()
+                .iter()
+                .map(|s| {
+                    let value = match s.get(idx) {
+                        AnyValue::Null => Value::Null,
+                        AnyValue::Boolean(v) => Value::Boolean(v),
+                        AnyValue::Utf8(v) => Value::String(v.to_string()),
+                        AnyValue::UInt8(v) => Value::Int(v as i32),
+                        AnyValue::UInt16(v) => Value::Int(v as i32),
+                        AnyValue::UInt32(v) => Value::Long(v as i64),
+                        AnyValue::UInt64(v) => match i64::try_from(v) {
+                            Ok(v) => Value::Long(v),
+                            Err(_) => {
+                                return Err(PolarsError::ValueError(
+                                    format!(
+                                        "value {} of column {} does not fit in an avro long",
+                                        v,
+                                        s.name()
+                                    )
+                                    .into(),
+                                ))
+                            }
+                        },
+                        AnyValue::Int8(v) => Value::Int(v as i32),
+                        AnyValue::Int16(v) => Value::Int(v as i32),
+                        AnyValue::Int32(v) => Value::Int(v),
+                        AnyValue::Int64(v) => Value::Long(v),
+                        AnyValue::Float32(v) => Value::Float(v),
+                        AnyValue::Float64(v) => Value::Double(v),
+                        AnyValue::Date32(v) => Value::Date(v),
+                        AnyValue::Date64(v) => Value::TimestampMillis(v),
+                        av => {
+                            return Err(PolarsError::InvalidOperation(
+                                format!("writing {:?} to avro is not supported", av).into(),
+                            ))
+                        }
+                    };
+                    Ok((s.name().to_string(), Value::Union(Box::new(value))))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            writer
+                .append(Value::Record(record))
+                .map_err(to_polars_err)?;
+        }
+        writer.flush().map_err(to_polars_err)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use polars_core::df;
+    use polars_core::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn write_and_read_avro() -> Result<()> {
+        let df = df![
+            "int" => [Some(1i64), None, Some(3)],
+            "float" => [Some(1.0f64), Some(2.5), None],
+            "str" => [None, Some("b"), Some("c")],
+            "bool" => [true, false, true]
+        ]?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        AvroWriter::new(&mut buf).finish(&df)?;
+        buf.set_position(0);
+
+        let read = AvroReader::new(buf).finish()?;
+        assert!(read.frame_equal_missing(&df));
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        AvroWriter::new(&mut buf).finish(&df)?;
+        buf.set_position(0);
+        let read = AvroReader::new(buf)
+            .with_stop_after_n_rows(Some(2))
+            .finish()?;
+        assert_eq!(read.shape(), (2, 4));
+        Ok(())
+    }
+    #[test]
+    #[cfg(feature = "dtype-u64")]
+    fn write_avro_u64() -> Result<()> {
+        let df = df!["a" => [1u64, i64::MAX as u64]]?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        AvroWriter::new(&mut buf).finish(&df)?;
+        buf.set_position(0);
+        let read = AvroReader::new(buf).finish()?;
+        assert_eq!(
+            Vec::from(read.column("a")?.i64()?),
+            &[Some(1), Some(i64::MAX)]
+        );
+
+        let df = df!["a" => [1u64, i64::MAX as u64 + 1]]?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        assert!(AvroWriter::new(&mut buf).finish(&df).is_err());
+        Ok(())
+    }
+}
@@ -55,21 +55,54 @@
 //! ```
 //!
 use crate::csv_core::csv::{build_csv_reader, SequentialReader};
-use crate::utils::to_arrow_compatible_df;
+use crate::csv_core::utils::infer_file_schema;
 use crate::{SerReader, SerWriter};
 pub use arrow::csv::WriterBuilder;
 use polars_core::prelude::*;
+use std::borrow::Cow;
 use std::fs::File;
-use std::io::{Read, Seek, Write};
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 use std::path::PathBuf;
 use std::sync::Arc;
 
+/// Quoting policy of the fields written by the [CsvWriter].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Quote every field.
+    Always,
+    /// Only quote fields that contain a delimiter, quote or line terminator.
+    Necessary,
+    /// Never quote fields, even if that produces invalid csv.
+    Never,
+}
+
+impl From<QuoteStyle> for csv::QuoteStyle {
+    fn from(style: QuoteStyle) -> Self {
+        match style {
+            QuoteStyle::Always => csv::QuoteStyle::Always,
+            QuoteStyle::Necessary => csv::QuoteStyle::Necessary,
+            QuoteStyle::Never => csv::QuoteStyle::Never,
+        }
+    }
+}
+
+fn to_polars_err(e: csv::Error) -> PolarsError {
+    PolarsError::Various(e.into())
+}
+
 /// Write a DataFrame to csv.
 pub struct CsvWriter<'a, W: Write> {
     /// File or Stream handler
     buffer: &'a mut W,
-    /// Builds an Arrow CSV Writer
-    writer_builder: WriterBuilder,
+    has_headers: bool,
+    delimiter: u8,
+    quote: u8,
+    quote_style: QuoteStyle,
+    line_terminator: String,
+    null_value: String,
+    date_format: Option<String>,
+    time_format: Option<String>,
+    timestamp_format: Option<String>,
 }
 
 impl<'a, W> SerWriter<'a, W> for CsvWriter<'a, W>
@@ -79,19 +112,22 @@ where
     fn new(buffer: &'a mut W) -> Self {
         CsvWriter {
             buffer,
-            writer_builder: WriterBuilder::new(),
+            has_headers: true,
+            delimiter: b',',
+            quote: b'"',
+            quote_style: QuoteStyle::Necessary,
+            line_terminator: "\n".to_string(),
+            null_value: String::new(),
+            date_format: None,
+            time_format: None,
+            timestamp_format: None,
         }
     }
 
     fn finish(self, df: &DataFrame) -> Result<()> {
-        let df = to_arrow_compatible_df(df);
-        let mut csv_writer = self.writer_builder.build(self.buffer);
-
-        let iter = df.iter_record_batches();
-        for batch in iter {
-            csv_writer.write(&batch)?
-        }
-        Ok(())
+        let mut batched = self.batched(&df.schema())?;
+        batched.write_batch(df)?;
+        batched.finish()
     }
 }
 
@@ -101,31 +137,58 @@ where
 {
     /// Set whether to write headers
     pub fn has_headers(mut self, has_headers: bool) -> Self {
-        self.writer_builder = self.writer_builder.has_headers(has_headers);
+        self.has_headers = has_headers;
         self
     }
 
     /// Set the CSV file's column delimiter as a byte character
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.writer_builder = self.writer_builder.with_delimiter(delimiter);
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set the quote character. Defaults to `"`.
+    pub fn with_quote_char(mut self, quote: u8) -> Self {
+        self.quote = quote;
         self
     }
 
-    /// Set the CSV file's date format
+    /// Set the quoting policy. Defaults to [QuoteStyle::Necessary].
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Set the line terminator. This must be a single byte or `"\r\n"`. Defaults to `"\n"`.
+    pub fn with_line_terminator(mut self, line_terminator: String) -> Self {
+        self.line_terminator = line_terminator;
+        self
+    }
+
+    /// Set the string that is written for missing values. Defaults to an empty string.
+    pub fn with_null_value(mut self, null_value: String) -> Self {
+        self.null_value = null_value;
+        self
+    }
+
+    /// Set the CSV file's date format. See [chrono strftime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    /// Defaults to `%Y-%m-%d`.
     pub fn with_date_format(mut self, format: String) -> Self {
-        self.writer_builder = self.writer_builder.with_date_format(format);
+        self.date_format = Some(format);
         self
     }
 
-    /// Set the CSV file's time format
+    /// Set the CSV file's time (`Time64`) format. See [chrono strftime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    /// Defaults to `%H:%M:%S%.f`.
     pub fn with_time_format(mut self, format: String) -> Self {
-        self.writer_builder = self.writer_builder.with_time_format(format);
+        self.time_format = Some(format);
         self
     }
 
-    /// Set the CSV file's timestamp format array in
+    /// Set the CSV file's timestamp (`Date64`) format. See [chrono strftime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    /// Defaults to `%Y-%m-%dT%H:%M:%S%.f`.
     pub fn with_timestamp_format(mut self, format: String) -> Self {
-        self.writer_builder = self.writer_builder.with_timestamp_format(format);
+        self.timestamp_format = Some(format);
         self
     }
 
@@ -134,6 +197,214 @@ where
     pub fn with_batch_size(self, _batch_size: usize) -> Self {
         self
     }
+
+    /// Write DataFrames with the given `schema` one batch at a time. The header is written
+    /// once, before the first batch.
+    pub fn batched(self, schema: &Schema) -> Result<BatchedCsvWriter<'a, W>> {
+        let terminator = match self.line_terminator.as_bytes() {
+            b"\r\n" => csv::Terminator::CRLF,
+            [byte] => csv::Terminator::Any(*byte),
+            _ => {
+                return Err(PolarsError::InvalidOperation(
+                    format!(
+                        "line terminator {:?} is not supported, use a single byte or \\r\\n",
+                        self.line_terminator
+                    )
+                    .into(),
+                ))
+            }
+        };
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .quote_style(self.quote_style.into())
+            .terminator(terminator)
+            .from_writer(self.buffer);
+        if self.has_headers {
+            writer
+                .write_record(schema.fields().iter().map(|field| field.name()))
+                .map_err(to_polars_err)?;
+        }
+
+        Ok(BatchedCsvWriter {
+            writer,
+            null_value: self.null_value,
+            date_format: self.date_format,
+            time_format: self.time_format,
+            timestamp_format: self.timestamp_format,
+        })
+    }
+}
+
+/// Writes DataFrames to csv in batches. Created by [CsvWriter::batched].
+pub struct BatchedCsvWriter<'a, W: Write> {
+    writer: csv::Writer<&'a mut W>,
+    null_value: String,
+    date_format: Option<String>,
+    time_format: Option<String>,
+    timestamp_format: Option<String>,
+}
+
+impl<'a, W> BatchedCsvWriter<'a, W>
+where
+    W: Write,
+{
+    /// Write the rows of a batch.
+    ///
+    /// The DataFrame should have the schema that was used to create this writer.
+    pub fn write_batch(&mut self, df: &DataFrame) -> Result<()> {
+        let formats = TemporalFormats {
+            date: self.date_format.as_deref().unwrap_or("%Y-%m-%d"),
+            time: self.time_format.as_deref().unwrap_or("%H:%M:%S%.f"),
+            timestamp: self
+                .timestamp_format
+                .as_deref()
+                .unwrap_or("%Y-%m-%dT%H:%M:%S%.f"),
+        };
+        // only the dtypes that can't be formatted one value at a time are cast to Utf8
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|s| {
+                if formats_by_row(s.dtype()) {
+                    Ok(Cow::Borrowed(s))
+                } else {
+                    s.cast_with_dtype(&DataType::Utf8).map(Cow::Owned)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut serializers = columns
+            .iter()
+            .map(|s| field_serializer(s, &formats))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut buf = Vec::with_capacity(64);
+        for _ in 0..df.height() {
+            for serialize in serializers.iter_mut() {
+                buf.clear();
+                if serialize(&mut buf) {
+                    self.writer.write_field(&buf).map_err(to_polars_err)?;
+                } else {
+                    self.writer
+                        .write_field(&self.null_value)
+                        .map_err(to_polars_err)?;
+                }
+            }
+            self.writer
+                .write_record(None::<&[u8]>)
+                .map_err(to_polars_err)?;
+        }
+        Ok(())
+    }
+
+    /// Flush the written rows to the underlying writer.
+    pub fn finish(mut self) -> Result<()> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+// the formats are unused if no temporal dtype is enabled
+#[allow(dead_code)]
+struct TemporalFormats<'a> {
+    date: &'a str,
+    time: &'a str,
+    timestamp: &'a str,
+}
+
+/// Writes the value of a column in the next row to the buffer. Returns `false` for a null.
+type FieldSerializer<'a> = Box<dyn FnMut(&mut Vec<u8>) -> bool + 'a>;
+
+fn serialize_with<'a, I, T, F>(mut iter: I, mut f: F) -> FieldSerializer<'a>
+where
+    I: Iterator<Item = Option<T>> + 'a,
+    F: FnMut(&mut Vec<u8>, T) + 'a,
+{
+    Box::new(move |buf| match iter.next().flatten() {
+        Some(v) => {
+            f(buf, v);
+            true
+        }
+        None => false,
+    })
+}
+
+fn write_display<T: std::fmt::Display>(buf: &mut Vec<u8>, v: T) {
+    write!(buf, "{}", v).expect("writing to a Vec can't fail")
+}
+
+/// Whether [field_serializer] formats the values of this dtype without casting the column.
+fn formats_by_row(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::Boolean
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Utf8 => true,
+        #[cfg(feature = "dtype-u64")]
+        DataType::UInt64 => true,
+        #[cfg(feature = "dtype-date32")]
+        DataType::Date32 => true,
+        #[cfg(feature = "dtype-date64")]
+        DataType::Date64 => true,
+        #[cfg(feature = "dtype-time64-ns")]
+        DataType::Time64(TimeUnit::Nanosecond) => true,
+        _ => false,
+    }
+}
+
+#[allow(unused_variables)]
+fn field_serializer<'a>(s: &'a Series, formats: &TemporalFormats) -> Result<FieldSerializer<'a>> {
+    let serializer = match s.dtype() {
+        DataType::Boolean => serialize_with(s.bool()?.into_iter(), write_display),
+        DataType::UInt8 => serialize_with(s.u8()?.into_iter(), write_display),
+        DataType::UInt16 => serialize_with(s.u16()?.into_iter(), write_display),
+        DataType::UInt32 => serialize_with(s.u32()?.into_iter(), write_display),
+        #[cfg(feature = "dtype-u64")]
+        DataType::UInt64 => serialize_with(s.u64()?.into_iter(), write_display),
+        DataType::Int8 => serialize_with(s.i8()?.into_iter(), write_display),
+        DataType::Int16 => serialize_with(s.i16()?.into_iter(), write_display),
+        DataType::Int32 => serialize_with(s.i32()?.into_iter(), write_display),
+        DataType::Int64 => serialize_with(s.i64()?.into_iter(), write_display),
+        // the debug representation keeps the decimal point of integral floats, e.g. `7.0`
+        DataType::Float32 => serialize_with(s.f32()?.into_iter(), |buf, v| {
+            write!(buf, "{:?}", v).expect("writing to a Vec can't fail")
+        }),
+        DataType::Float64 => serialize_with(s.f64()?.into_iter(), |buf, v| {
+            write!(buf, "{:?}", v).expect("writing to a Vec can't fail")
+        }),
+        #[cfg(feature = "dtype-date32")]
+        DataType::Date32 => {
+            let fmt = formats.date.to_string();
+            serialize_with(s.date32()?.as_naive_datetime_iter(), move |buf, v| {
+                write_display(buf, v.format(&fmt))
+            })
+        }
+        #[cfg(feature = "dtype-date64")]
+        DataType::Date64 => {
+            let fmt = formats.timestamp.to_string();
+            serialize_with(s.date64()?.as_naive_datetime_iter(), move |buf, v| {
+                write_display(buf, v.format(&fmt))
+            })
+        }
+        #[cfg(feature = "dtype-time64-ns")]
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            let fmt = formats.time.to_string();
+            serialize_with(s.time64_nanosecond()?.into_iter(), move |buf, v| {
+                write_display(buf, time64_nanosecond_as_time(v).format(&fmt))
+            })
+        }
+        _ => serialize_with(s.utf8()?.into_iter(), |buf, v| {
+            buf.extend_from_slice(v.as_bytes())
+        }),
+    };
+    Ok(serializer)
 }
 
 #[derive(Copy, Clone)]
@@ -144,6 +415,83 @@ pub enum CsvEncoding {
     LossyUtf8,
 }
 
+/// How to handle lines that have a different number of fields than the schema.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RaggedLines {
+    /// Return an error for lines with too few or too many fields.
+    Error,
+    /// Fill missing fields with nulls. Lines with too many fields are an error.
+    Pad,
+    /// Fill missing fields with nulls and ignore the surplus fields.
+    Truncate,
+}
+
+impl Default for RaggedLines {
+    fn default() -> Self {
+        RaggedLines::Truncate
+    }
+}
+
+/// Compression of the csv input.
+#[cfg(feature = "decompress")]
+#[cfg_attr(docsrs, doc(cfg(feature = "decompress")))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CsvCompression {
+    Uncompressed,
+    Gzip,
+    Zstd,
+}
+
+#[cfg(feature = "decompress")]
+impl CsvCompression {
+    /// Determine the compression from the magic bytes at the start of the input.
+    fn from_magic_bytes(bytes: &[u8]) -> Self {
+        match bytes {
+            [0x1f, 0x8b, ..] => CsvCompression::Gzip,
+            [0x28, 0xb5, 0x2f, 0xfd, ..] => CsvCompression::Zstd,
+            _ => CsvCompression::Uncompressed,
+        }
+    }
+}
+
+/// Split a schema overwrite in the schema the parser can handle and the fields that need to be
+/// cast after parsing.
+fn split_schema_overwrite(schema: &Schema) -> (Schema, Vec<Field>) {
+    let mut to_cast = Vec::with_capacity(schema.len());
+
+    let fields = schema
+        .fields()
+        .iter()
+        .filter_map(|fld| {
+            match fld.data_type() {
+                // For categorical we first read as utf8 and later cast to categorical
+                DataType::Categorical => {
+                    to_cast.push(fld.clone());
+                    Some(Field::new(fld.name(), DataType::Utf8))
+                }
+                DataType::Date32 | DataType::Date64 => {
+                    to_cast.push(fld.clone());
+                    // let inference decide the column type
+                    None
+                }
+                _ => Some(fld.clone()),
+            }
+        })
+        .collect();
+    (Schema::new(fields), to_cast)
+}
+
+/// Cast the parsed columns to the original dtypes in the schema overwrite.
+fn cast_to_overwrite(df: &mut DataFrame, to_cast: &[Field]) -> Result<()> {
+    for fld in to_cast {
+        // the column may not be projected
+        if df.column(fld.name()).is_ok() {
+            df.may_apply(fld.name(), |s| s.cast_with_dtype(fld.data_type()))?;
+        }
+    }
+    Ok(())
+}
+
 /// Create a new DataFrame by reading a csv file.
 ///
 /// # Example
@@ -188,6 +536,12 @@ where
     sample_size: usize,
     chunk_size: usize,
     low_memory: bool,
+    null_values: Option<Vec<String>>,
+    parse_dates: bool,
+    comment_char: Option<u8>,
+    ragged_lines: RaggedLines,
+    #[cfg(feature = "decompress")]
+    compression: Option<CsvCompression>,
 }
 
 impl<'a, R> CsvReader<'a, R>
@@ -310,6 +664,183 @@ where
         self
     }
 
+    /// Parse the fields that are equal to one of these values as null, e.g. `"NA"`.
+    /// The values are also ignored during schema inference.
+    pub fn with_null_values(mut self, null_values: Option<Vec<String>>) -> Self {
+        self.null_values = null_values;
+        self
+    }
+
+    /// Skip the lines that start with this character.
+    pub fn with_comment_char(mut self, comment_char: Option<u8>) -> Self {
+        self.comment_char = comment_char;
+        self
+    }
+
+    /// Set how lines with too few or too many fields are handled. By default missing fields
+    /// are read as null and surplus fields are ignored.
+    pub fn with_ragged_lines(mut self, ragged_lines: RaggedLines) -> Self {
+        self.ragged_lines = ragged_lines;
+        self
+    }
+
+    /// Detect ISO dates (`yyyy-mm-dd`) and datetimes (`yyyy-mm-ddThh:mm:ss`) during schema
+    /// inference and parse them to `Date32` and `Date64` columns.
+    #[cfg(any(feature = "dtype-date32", feature = "dtype-date64"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "dtype-date32", feature = "dtype-date64")))
+    )]
+    pub fn with_parse_dates(mut self, toggle: bool) -> Self {
+        self.parse_dates = toggle;
+        self
+    }
+
+    /// Set the compression of the input. If `None` (the default), gzip and zstd compressed
+    /// input is detected by its magic bytes.
+    ///
+    /// Compressed input is decompressed in a streaming fashion and parsed in batches, so the
+    /// decompressed input is never held in memory at once. The schema of compressed input is
+    /// inferred from the first 4 MiB of decompressed bytes.
+    #[cfg(feature = "decompress")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "decompress")))]
+    pub fn with_compression(mut self, compression: Option<CsvCompression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Get the compression of the input, detecting it if not set.
+    #[cfg(feature = "decompress")]
+    fn detect_compression(&mut self) -> Result<CsvCompression> {
+        let compression = match self.compression {
+            Some(compression) => compression,
+            None => {
+                let mut magic = Vec::with_capacity(4);
+                let n = (&mut self.reader).take(4).read_to_end(&mut magic)?;
+                self.reader.seek(SeekFrom::Current(-(n as i64)))?;
+                CsvCompression::from_magic_bytes(&magic)
+            }
+        };
+        Ok(compression)
+    }
+
+    /// Decompress and parse compressed input in batches, so that the decompressed input does
+    /// not have to be held in memory at once.
+    #[cfg(feature = "decompress")]
+    fn finish_compressed(self) -> Result<DataFrame> {
+        let rechunk = self.rechunk;
+        let mut batched = self.batched(DECOMPRESSED_BATCH_SIZE)?;
+        let mut df = match batched.next_batch()? {
+            Some(df) => df,
+            None => batched.parse(vec![])?,
+        };
+        while let Some(batch) = batched.next_batch()? {
+            df.vstack_mut(&batch)?;
+        }
+        if rechunk && df.n_chunks()? > 1 {
+            df.as_single_chunk();
+        }
+        Ok(df)
+    }
+
+    /// Read the csv in batches of `batch_size` rows. Only the rows of a single batch are kept
+    /// in memory, so this can be used to process files that are larger than memory.
+    ///
+    /// Compressed input is decompressed while it is read.
+    pub fn batched(mut self, batch_size: usize) -> Result<BatchedCsvReader<R>> {
+        #[cfg(feature = "decompress")]
+        let compression = self.detect_compression()?;
+        #[cfg(feature = "decompress")]
+        let mut source = BatchedSource::new(self.reader, compression)?;
+        #[cfg(not(feature = "decompress"))]
+        let mut source = BatchedSource::Plain(self.reader);
+
+        let delimiter = self.delimiter.unwrap_or(b',');
+        let (schema_overwrite, to_cast) = match self.schema_overwrite {
+            Some(schema) => {
+                let (schema, to_cast) = split_schema_overwrite(schema);
+                (Some(schema), to_cast)
+            }
+            None => (None, vec![]),
+        };
+        #[cfg_attr(not(feature = "decompress"), allow(unused_mut))]
+        let mut buf = Vec::with_capacity(READ_SIZE);
+        let schema = match self.schema.take() {
+            Some(schema) => schema,
+            None => {
+                let (schema, _) = match &mut source {
+                    BatchedSource::Plain(reader) => {
+                        let schema = infer_file_schema(
+                            reader,
+                            delimiter,
+                            self.max_records,
+                            self.has_header,
+                            schema_overwrite.as_ref(),
+                            self.skip_rows,
+                            self.null_values.as_deref(),
+                            self.parse_dates,
+                            self.comment_char,
+                        )?;
+                        reader.seek(SeekFrom::Start(0))?;
+                        schema
+                    }
+                    // decompressed input cannot be seeked, so the schema is inferred from the
+                    // first decompressed bytes, which are kept to be parsed
+                    #[cfg(feature = "decompress")]
+                    source => {
+                        source.take(READ_SIZE as u64).read_to_end(&mut buf)?;
+                        infer_file_schema(
+                            &mut Cursor::new(&buf),
+                            delimiter,
+                            self.max_records,
+                            self.has_header,
+                            schema_overwrite.as_ref(),
+                            self.skip_rows,
+                            self.null_values.as_deref(),
+                            self.parse_dates,
+                            self.comment_char,
+                        )?
+                    }
+                };
+                Arc::new(schema)
+            }
+        };
+        let projection = match self.columns.take() {
+            Some(columns) => Some(
+                columns
+                    .iter()
+                    .map(|name| schema.index_of(name))
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+            None => self.projection.take(),
+        };
+
+        let mut batched = BatchedCsvReader {
+            reader: source,
+            buf,
+            eof: false,
+            batch_size: std::cmp::max(batch_size, 1),
+            rows_remaining: self.stop_after_n_rows,
+            schema,
+            projection,
+            delimiter,
+            encoding: self.encoding,
+            ignore_parser_errors: self.ignore_parser_errors,
+            n_threads: self.n_threads,
+            sample_size: self.sample_size,
+            chunk_size: self.chunk_size,
+            low_memory: self.low_memory,
+            null_values: self.null_values,
+            parse_dates: self.parse_dates,
+            comment_char: self.comment_char,
+            ragged_lines: self.ragged_lines,
+            rechunk: self.rechunk,
+            to_cast,
+        };
+        batched.skip_start(self.skip_rows + self.has_header as usize)?;
+        Ok(batched)
+    }
+
     pub fn build_inner_reader(self) -> Result<SequentialReader<R>> {
         build_csv_reader(
             self.reader,
@@ -329,6 +860,10 @@ where
             self.sample_size,
             self.chunk_size,
             self.low_memory,
+            self.null_values,
+            self.parse_dates,
+            self.comment_char,
+            self.ragged_lines,
         )
     }
 }
@@ -367,38 +902,30 @@ where
             sample_size: 1024,
             chunk_size: 8192,
             low_memory: false,
+            null_values: None,
+            parse_dates: false,
+            comment_char: None,
+            ragged_lines: RaggedLines::default(),
+            #[cfg(feature = "decompress")]
+            compression: None,
         }
     }
 
     /// Read the file and create the DataFrame.
-    fn finish(self) -> Result<DataFrame> {
+    #[allow(unused_mut)]
+    fn finish(mut self) -> Result<DataFrame> {
+        #[cfg(feature = "decompress")]
+        {
+            if self.detect_compression()? != CsvCompression::Uncompressed {
+                return self.finish_compressed();
+            }
+        }
         let rechunk = self.rechunk;
 
         let mut df = if let Some(schema) = self.schema_overwrite {
             // This branch we check if there are dtypes we cannot parse.
             // We only support a few dtypes in the parser and later cast to the required dtype
-            let mut to_cast = Vec::with_capacity(schema.len());
-
-            let fields = schema
-                .fields()
-                .iter()
-                .filter_map(|fld| {
-                    match fld.data_type() {
-                        // For categorical we first read as utf8 and later cast to categorical
-                        DataType::Categorical => {
-                            to_cast.push(fld);
-                            Some(Field::new(fld.name(), DataType::Utf8))
-                        }
-                        DataType::Date32 | DataType::Date64 => {
-                            to_cast.push(fld);
-                            // let inference decide the column type
-                            None
-                        }
-                        _ => Some(fld.clone()),
-                    }
-                })
-                .collect();
-            let schema = Schema::new(fields);
+            let (schema, to_cast) = split_schema_overwrite(schema);
 
             // we cannot overwrite self, because the lifetime is already instantiated with `a, and
             // the lifetime that accompanies this scope is shorter.
@@ -421,13 +948,14 @@ where
                 self.sample_size,
                 self.chunk_size,
                 self.low_memory,
+                self.null_values,
+                self.parse_dates,
+                self.comment_char,
+                self.ragged_lines,
             )?;
             let mut df = csv_reader.as_df(None, None)?;
 
-            // cast to the original dtypes in the schema
-            for fld in to_cast {
-                df.may_apply(fld.name(), |s| s.cast_with_dtype(fld.data_type()))?;
-            }
+            cast_to_overwrite(&mut df, &to_cast)?;
             df
         } else {
             let mut csv_reader = self.build_inner_reader()?;
@@ -443,10 +971,236 @@ where
     }
 }
 
+/// Number of bytes that are read from the input at once by the [BatchedCsvReader].
+const READ_SIZE: usize = 4 * 1024 * 1024;
+/// Number of rows that are parsed at once when compressed input is read into a DataFrame.
+#[cfg(feature = "decompress")]
+const DECOMPRESSED_BATCH_SIZE: usize = 100_000;
+
+/// The input of a [BatchedCsvReader], which is decompressed while it is read.
+enum BatchedSource<R: Read> {
+    Plain(R),
+    #[cfg(feature = "decompress")]
+    Gzip(flate2::read::MultiGzDecoder<R>),
+    #[cfg(feature = "decompress")]
+    Zstd(zstd::stream::read::Decoder<'static, std::io::BufReader<R>>),
+}
+
+impl<R: Read> BatchedSource<R> {
+    #[cfg(feature = "decompress")]
+    fn new(reader: R, compression: CsvCompression) -> Result<Self> {
+        let source = match compression {
+            CsvCompression::Uncompressed => BatchedSource::Plain(reader),
+            CsvCompression::Gzip => BatchedSource::Gzip(flate2::read::MultiGzDecoder::new(reader)),
+            CsvCompression::Zstd => BatchedSource::Zstd(zstd::stream::read::Decoder::new(reader)?),
+        };
+        Ok(source)
+    }
+}
+
+impl<R: Read> Read for BatchedSource<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            BatchedSource::Plain(reader) => reader.read(buf),
+            #[cfg(feature = "decompress")]
+            BatchedSource::Gzip(reader) => reader.read(buf),
+            #[cfg(feature = "decompress")]
+            BatchedSource::Zstd(reader) => reader.read(buf),
+        }
+    }
+}
+
+/// Find the end of the first `n` records in `bytes`. Returns the number of records found and
+/// the byte offset right after the last of them. If `eof`, a trailing record without a line
+/// ending is complete as well. Comment lines are not counted as records.
+fn find_records_end(
+    bytes: &[u8],
+    delimiter: u8,
+    comment_char: Option<u8>,
+    n: usize,
+    eof: bool,
+) -> (usize, usize) {
+    use csv_core::ReadRecordResult;
+    let mut reader = csv_core::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .comment(comment_char)
+        .build();
+    // the parsed fields are not needed, only the record boundaries.
+    let mut output = vec![0; 4096];
+    let mut ends = vec![0; 256];
+
+    let mut count = 0;
+    let mut read = 0;
+    let mut end = 0;
+    while count < n {
+        let (result, n_in, _, _) = reader.read_record(&bytes[read..], &mut output, &mut ends);
+        read += n_in;
+        match result {
+            ReadRecordResult::Record => {
+                count += 1;
+                end = read;
+            }
+            ReadRecordResult::OutputFull | ReadRecordResult::OutputEndsFull => {}
+            // with an empty input at eof the reader returns the last record or `End`.
+            ReadRecordResult::InputEmpty if eof => {}
+            ReadRecordResult::InputEmpty | ReadRecordResult::End => break,
+        }
+    }
+    (count, end)
+}
+
+/// Reads a csv file in batches of DataFrames. Created by [CsvReader::batched].
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+///
+/// fn example() -> Result<()> {
+///     for df in CsvReader::from_path("large.csv")?.batched(100_000)? {
+///         println!("{:?}", df?.shape());
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct BatchedCsvReader<R: Read> {
+    reader: BatchedSource<R>,
+    /// Bytes read from the input that are not yet parsed.
+    buf: Vec<u8>,
+    eof: bool,
+    batch_size: usize,
+    rows_remaining: Option<usize>,
+    schema: SchemaRef,
+    projection: Option<Vec<usize>>,
+    delimiter: u8,
+    encoding: CsvEncoding,
+    ignore_parser_errors: bool,
+    n_threads: Option<usize>,
+    sample_size: usize,
+    chunk_size: usize,
+    low_memory: bool,
+    null_values: Option<Vec<String>>,
+    parse_dates: bool,
+    comment_char: Option<u8>,
+    ragged_lines: RaggedLines,
+    rechunk: bool,
+    to_cast: Vec<Field>,
+}
+
+impl<R: Read> BatchedCsvReader<R> {
+    /// Schema of the batches, before projection.
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn fill_buf(&mut self) -> Result<()> {
+        let n = (&mut self.reader)
+            .take(READ_SIZE as u64)
+            .read_to_end(&mut self.buf)?;
+        if n == 0 {
+            self.eof = true;
+        }
+        Ok(())
+    }
+
+    /// Find the end of the next `n` records, reading more input if needed.
+    fn next_records(&mut self, n: usize) -> Result<(usize, usize)> {
+        let mut count = 0;
+        let mut end = 0;
+        loop {
+            let (c, e) = find_records_end(
+                &self.buf[end..],
+                self.delimiter,
+                self.comment_char,
+                n - count,
+                self.eof,
+            );
+            count += c;
+            end += e;
+            if count == n || self.eof {
+                return Ok((count, end));
+            }
+            self.fill_buf()?;
+        }
+    }
+
+    /// Skip the utf8-bom and the first `n` records.
+    fn skip_start(&mut self, n: usize) -> Result<()> {
+        self.fill_buf()?;
+        if self.buf.starts_with(&[0xEF, 0xBB, 0xBF]) {
+            self.buf.drain(..3);
+        }
+        let (_, end) = self.next_records(n)?;
+        self.buf.drain(..end);
+        Ok(())
+    }
+
+    fn next_batch(&mut self) -> Result<Option<DataFrame>> {
+        let n = match self.rows_remaining {
+            Some(0) => return Ok(None),
+            Some(remaining) => std::cmp::min(remaining, self.batch_size),
+            None => self.batch_size,
+        };
+        let (count, end) = self.next_records(n)?;
+        if count == 0 {
+            return Ok(None);
+        }
+        if let Some(remaining) = &mut self.rows_remaining {
+            *remaining -= count;
+        }
+        let bytes = self.buf.drain(..end).collect::<Vec<_>>();
+        self.parse(bytes).map(Some)
+    }
+
+    /// Parse the records in `bytes`.
+    fn parse(&self, bytes: Vec<u8>) -> Result<DataFrame> {
+        // every batch is parsed with the options of the eager reader and the schema of the file
+        let mut reader = build_csv_reader(
+            Cursor::new(bytes),
+            None,
+            0,
+            self.projection.clone(),
+            None,
+            Some(self.delimiter),
+            false,
+            self.ignore_parser_errors,
+            Some(self.schema.clone()),
+            None,
+            self.encoding,
+            self.n_threads,
+            None,
+            None,
+            self.sample_size,
+            self.chunk_size,
+            self.low_memory,
+            self.null_values.clone(),
+            self.parse_dates,
+            self.comment_char,
+            self.ragged_lines,
+        )?;
+        let mut df = reader.as_df(None, None)?;
+        cast_to_overwrite(&mut df, &self.to_cast)?;
+        if self.rechunk && df.n_chunks()? > 1 {
+            df.as_single_chunk();
+        }
+        Ok(df)
+    }
+}
+
+impl<R: Read> Iterator for BatchedCsvReader<R> {
+    type Item = Result<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_batch().transpose()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
     use polars_core::datatypes::AnyValue;
+    use polars_core::df;
     use polars_core::prelude::*;
     use std::io::Cursor;
 
@@ -463,6 +1217,74 @@ mod test {
         assert_eq!("days,temp\n0,22.1\n1,19.9\n2,7.0\n3,2.0\n4,3.0\n", csv);
     }
 
+    #[test]
+    fn write_csv_options() -> Result<()> {
+        let df = df![
+            "a" => [Some(1), None, Some(3)],
+            "b" => [Some("x;y"), Some("z"), None]
+        ]?;
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .with_delimiter(b';')
+            .with_null_value("NA".to_string())
+            .with_line_terminator("\r\n".to_string())
+            .finish(&df)?;
+        let csv = std::str::from_utf8(&buf).unwrap();
+        assert_eq!("a;b\r\n1;\"x;y\"\r\nNA;z\r\n3;NA\r\n", csv);
+
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .has_headers(false)
+            .with_quote_char(b'\'')
+            .with_quote_style(QuoteStyle::Always)
+            .finish(&df)?;
+        let csv = std::str::from_utf8(&buf).unwrap();
+        assert_eq!("'1','x;y'\n'','z'\n'3',''\n", csv);
+
+        let mut buf: Vec<u8> = Vec::new();
+        let out = CsvWriter::new(&mut buf)
+            .with_line_terminator("--".to_string())
+            .finish(&df);
+        assert!(out.is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-date32", feature = "dtype-time64-ns"))]
+    fn write_csv_temporal_formats() -> Result<()> {
+        let date = Date32Chunked::new_from_opt_slice("date", &[Some(18705), None]).into_series();
+        let time = Time64NanosecondChunked::new_from_opt_slice(
+            "time",
+            &[Some(3_723_000_000_000), Some(0)],
+        )
+        .into_series();
+        let flag = BooleanChunked::new_from_opt_slice("flag", &[Some(true), None]).into_series();
+        let cat = Utf8Chunked::new_from_opt_slice("cat", &[Some("a"), None])
+            .into_series()
+            .cast_with_dtype(&DataType::Categorical)?;
+        let df = DataFrame::new(vec![date, time, flag, cat])?;
+
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf).finish(&df)?;
+        let csv = std::str::from_utf8(&buf).unwrap();
+        assert_eq!(
+            "date,time,flag,cat\n2021-03-19,01:02:03,true,a\n,00:00:00,,\n",
+            csv
+        );
+
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .with_date_format("%d/%m/%Y".to_string())
+            .with_time_format("%H:%M".to_string())
+            .finish(&df)?;
+        let csv = std::str::from_utf8(&buf).unwrap();
+        assert_eq!(
+            "date,time,flag,cat\n19/03/2021,01:02,true,a\n,00:00,,\n",
+            csv
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_read_csv_file() {
         let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
@@ -867,4 +1689,188 @@ AUDCAD,1616455921,0.96212,0.95666,1"#;
         assert!(out.is_err());
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "decompress")]
+    fn test_read_compressed() -> Result<()> {
+        use std::io::Write;
+
+        let csv = "a,b\n1,foo\n2,bar\n3,ham\n";
+        let expected = CsvReader::new(Cursor::new(csv)).finish()?;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(csv.as_bytes())?;
+        let gzipped = encoder.finish()?;
+        let df = CsvReader::new(Cursor::new(gzipped)).finish()?;
+        assert!(df.frame_equal(&expected));
+
+        let zstd_bytes = zstd::stream::encode_all(csv.as_bytes(), 0)?;
+        let df = CsvReader::new(Cursor::new(zstd_bytes.clone()))
+            .with_compression(Some(CsvCompression::Zstd))
+            .finish()?;
+        assert!(df.frame_equal(&expected));
+
+        // explicitly uncompressed input is parsed as is
+        let out = CsvReader::new(Cursor::new(zstd_bytes))
+            .with_compression(Some(CsvCompression::Uncompressed))
+            .finish();
+        assert!(out.map_or(true, |df| !df.frame_equal(&expected)));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "decompress")]
+    fn test_read_compressed_batched() -> Result<()> {
+        use std::io::Write;
+
+        let csv = "a,b\n1,foo\n2,bar\n3,ham\n4,spam\n5,eggs\n";
+        let expected = CsvReader::new(Cursor::new(csv)).finish()?;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(csv.as_bytes())?;
+        let gzipped = encoder.finish()?;
+        let batches = CsvReader::new(Cursor::new(gzipped))
+            .batched(2)?
+            .collect::<Result<Vec<_>>>()?;
+        let heights = batches.iter().map(|df| df.height()).collect::<Vec<_>>();
+        assert_eq!(heights, &[2, 2, 1]);
+        let mut df = batches[0].clone();
+        for batch in &batches[1..] {
+            df.vstack_mut(batch)?;
+        }
+        assert!(df.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_csv_batched() -> Result<()> {
+        let csv = "a,b\n1,\"foo\nbar\"\n2,ham\n3,spam\n4,eggs\n5,bacon";
+        let expected = CsvReader::new(Cursor::new(csv)).finish()?;
+
+        let batches = CsvReader::new(Cursor::new(csv))
+            .batched(2)?
+            .collect::<Result<Vec<_>>>()?;
+        let heights = batches.iter().map(|df| df.height()).collect::<Vec<_>>();
+        assert_eq!(heights, &[2, 2, 1]);
+        let mut df = batches[0].clone();
+        for batch in &batches[1..] {
+            df.vstack_mut(batch)?;
+        }
+        assert!(df.frame_equal(&expected));
+
+        let batches = CsvReader::new(Cursor::new(csv))
+            .with_stop_after_n_rows(Some(3))
+            .with_columns(Some(vec!["b".to_string()]))
+            .batched(2)?
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[1].get_column_names(), &["b"]);
+        assert_eq!(batches[1].column("b")?.utf8()?.get(0), Some("spam"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_values_and_dtype_override() -> Result<()> {
+        let csv = "id,value,name\n001,1,foo\n002,NA,-\n003,3,bar\n";
+        let schema = Schema::new(vec![Field::new("id", DataType::Utf8)]);
+        let df = CsvReader::new(Cursor::new(csv))
+            .with_dtypes(Some(&schema))
+            .with_null_values(Some(vec!["NA".to_string(), "-".to_string()]))
+            .finish()?;
+
+        assert_eq!(df.column("id")?.utf8()?.get(0), Some("001"));
+        assert_eq!(df.column("value")?.dtype(), &DataType::Int64);
+        assert_eq!(
+            Vec::from(df.column("value")?.i64()?),
+            &[Some(1), None, Some(3)]
+        );
+        assert_eq!(df.column("name")?.null_count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-date32", feature = "dtype-date64"))]
+    fn test_parse_dates() -> Result<()> {
+        let csv = "date,datetime,mixed,text\n\
+                   2021-01-01,2021-01-01T10:00:00,2021-01-01,foo\n\
+                   2021-01-02,2021-01-02 11:30:00.5,2021-01-02T12:00,2021-01-02\n";
+        let df = CsvReader::new(Cursor::new(csv))
+            .with_parse_dates(true)
+            .finish()?;
+        assert_eq!(df.column("date")?.dtype(), &DataType::Date32);
+        assert_eq!(df.column("datetime")?.dtype(), &DataType::Date64);
+        assert_eq!(df.column("mixed")?.dtype(), &DataType::Date64);
+        assert_eq!(df.column("text")?.dtype(), &DataType::Utf8);
+        assert_eq!(df.column("date")?.date32()?.get(1), Some(18629));
+        assert_eq!(
+            df.column("datetime")?.date64()?.get(1),
+            Some(18629 * 86_400_000 + 41_400_500)
+        );
+
+        let df = CsvReader::new(Cursor::new(csv)).finish()?;
+        assert_eq!(df.column("date")?.dtype(), &DataType::Utf8);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-date32", feature = "dtype-date64"))]
+    fn test_parse_dates_batched() -> Result<()> {
+        let csv = "date,datetime,text\n\
+                   2021-01-01,2021-01-01T10:00:00,foo\n\
+                   2021-01-02,2021-01-02 11:30:00.5,bar\n\
+                   2021-01-03,2021-01-03T12:00:00,baz\n";
+        let batches = CsvReader::new(Cursor::new(csv))
+            .with_parse_dates(true)
+            .batched(2)?
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches.len(), 2);
+        for df in &batches {
+            assert_eq!(df.column("date")?.dtype(), &DataType::Date32);
+            assert_eq!(df.column("datetime")?.dtype(), &DataType::Date64);
+            assert_eq!(df.column("text")?.dtype(), &DataType::Utf8);
+        }
+        assert_eq!(batches[0].column("date")?.date32()?.get(1), Some(18629));
+        assert_eq!(batches[1].column("date")?.date32()?.get(0), Some(18630));
+        assert_eq!(
+            batches[0].column("datetime")?.date64()?.get(1),
+            Some(18629 * 86_400_000 + 41_400_500)
+        );
+
+        let batches = CsvReader::new(Cursor::new(csv))
+            .batched(2)?
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches[0].column("date")?.dtype(), &DataType::Utf8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_comments_and_ragged_lines() -> Result<()> {
+        let csv = "# instrument: foo\n# date: today\na,b,c\n1,2,3\n# calibration\n4,5\n6,7,8,9\n";
+        let df = CsvReader::new(Cursor::new(csv))
+            .with_comment_char(Some(b'#'))
+            .finish()?;
+        assert_eq!(df.shape(), (3, 3));
+        assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+        assert_eq!(Vec::from(df.column("c")?.i64()?), &[Some(3), None, Some(8)]);
+
+        let out = CsvReader::new(Cursor::new(csv))
+            .with_comment_char(Some(b'#'))
+            .with_ragged_lines(RaggedLines::Pad)
+            .finish();
+        assert!(out.is_err());
+        let out = CsvReader::new(Cursor::new(csv))
+            .with_comment_char(Some(b'#'))
+            .with_ragged_lines(RaggedLines::Error)
+            .with_stop_after_n_rows(Some(2))
+            .finish();
+        assert!(out.is_err());
+
+        let batches = CsvReader::new(Cursor::new(csv))
+            .with_comment_char(Some(b'#'))
+            .batched(2)?
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].height(), 2);
+        Ok(())
+    }
 }
@@ -1,5 +1,9 @@
 use crate::csv::CsvEncoding;
 use crate::csv_core::csv::RunningSize;
+#[cfg(feature = "dtype-date32")]
+use crate::csv_core::parser::parse_iso_date;
+#[cfg(feature = "dtype-date64")]
+use crate::csv_core::parser::parse_iso_datetime;
 use crate::csv_core::parser::{drop_quotes, skip_whitespace};
 use polars_core::prelude::*;
 use std::fmt::Debug;
@@ -101,6 +105,35 @@ where
     }
 }
 
+/// Parse a temporal field with `parse`. Used for the date columns, as they are stored in the
+/// builders of their physical integer type.
+#[cfg(any(feature = "dtype-date32", feature = "dtype-date64"))]
+fn parse_temporal<T: PolarsNumericType>(
+    builder: &mut PrimitiveChunkedBuilder<T>,
+    bytes: &[u8],
+    ignore_errors: bool,
+    start_pos: usize,
+    parse: fn(&[u8]) -> Option<T::Native>,
+) -> Result<()> {
+    let (bytes, _) = skip_whitespace(bytes);
+    let bytes = drop_quotes(bytes);
+    match parse(bytes) {
+        Some(value) => builder.append_value(value),
+        None if ignore_errors || bytes.is_empty() => builder.append_null(),
+        None => {
+            return Err(PolarsError::Other(
+                format!(
+                    "Error while parsing value {} at byte position {} as date",
+                    String::from_utf8_lossy(bytes),
+                    start_pos
+                )
+                .into(),
+            ))
+        }
+    }
+    Ok(())
+}
+
 pub(crate) struct Utf8Field {
     builder: Utf8ChunkedBuilder,
     // buffer that is used as output buffer for csv-core
@@ -246,6 +279,14 @@ pub(crate) fn init_buffers(
                 &DataType::Float64 => {
                     Buffer::Float64(PrimitiveChunkedBuilder::new(field.name(), capacity))
                 }
+                #[cfg(feature = "dtype-date32")]
+                &DataType::Date32 => {
+                    Buffer::Date32(PrimitiveChunkedBuilder::new(field.name(), capacity))
+                }
+                #[cfg(feature = "dtype-date64")]
+                &DataType::Date64 => {
+                    Buffer::Date64(PrimitiveChunkedBuilder::new(field.name(), capacity))
+                }
                 &DataType::Utf8 => Buffer::Utf8(Utf8Field::new(
                     field.name(),
                     capacity,
@@ -273,6 +314,12 @@ pub(crate) enum Buffer {
     UInt64(PrimitiveChunkedBuilder<UInt64Type>),
     Float32(PrimitiveChunkedBuilder<Float32Type>),
     Float64(PrimitiveChunkedBuilder<Float64Type>),
+    /// Days since the unix epoch
+    #[cfg(feature = "dtype-date32")]
+    Date32(PrimitiveChunkedBuilder<Int32Type>),
+    /// Milliseconds since the unix epoch
+    #[cfg(feature = "dtype-date64")]
+    Date64(PrimitiveChunkedBuilder<Int64Type>),
     /// Stores the Utf8 fields and the total string length seen for that column
     Utf8(Utf8Field),
 }
@@ -288,10 +335,41 @@ impl Buffer {
             Buffer::UInt64(v) => v.finish().into_series(),
             Buffer::Float32(v) => v.finish().into_series(),
             Buffer::Float64(v) => v.finish().into_series(),
+            #[cfg(feature = "dtype-date32")]
+            Buffer::Date32(v) => v
+                .finish()
+                .into_series()
+                .cast_with_dtype(&DataType::Date32)
+                .unwrap(),
+            #[cfg(feature = "dtype-date64")]
+            Buffer::Date64(v) => v
+                .finish()
+                .into_series()
+                .cast_with_dtype(&DataType::Date64)
+                .unwrap(),
             Buffer::Utf8(v) => v.builder.finish().into_series(),
         }
     }
 
+    #[inline]
+    pub(crate) fn add_null(&mut self) {
+        match self {
+            Buffer::Boolean(v) => v.append_null(),
+            Buffer::Int32(v) => v.append_null(),
+            Buffer::Int64(v) => v.append_null(),
+            Buffer::UInt32(v) => v.append_null(),
+            #[cfg(feature = "dtype-u64")]
+            Buffer::UInt64(v) => v.append_null(),
+            Buffer::Float32(v) => v.append_null(),
+            Buffer::Float64(v) => v.append_null(),
+            #[cfg(feature = "dtype-date32")]
+            Buffer::Date32(v) => v.append_null(),
+            #[cfg(feature = "dtype-date64")]
+            Buffer::Date64(v) => v.append_null(),
+            Buffer::Utf8(v) => v.builder.append_null(),
+        }
+    }
+
     #[inline]
     pub(crate) fn add(
         &mut self,
@@ -364,6 +442,10 @@ impl Buffer {
                     encoding,
                 )
             }
+            #[cfg(feature = "dtype-date32")]
+            Date32(buf) => parse_temporal(buf, bytes, ignore_errors, start_pos, parse_iso_date),
+            #[cfg(feature = "dtype-date64")]
+            Date64(buf) => parse_temporal(buf, bytes, ignore_errors, start_pos, parse_iso_datetime),
             Utf8(buf) => <Utf8Field as ParsedBuffer<Utf8Type>>::parse_bytes(
                 buf,
                 bytes,
@@ -1,4 +1,4 @@
-use crate::csv::CsvEncoding;
+use crate::csv::{CsvEncoding, RaggedLines};
 use crate::csv_core::utils::*;
 use crate::csv_core::{buffer::*, parser::*};
 use crate::PhysicalIoExpr;
@@ -36,6 +36,9 @@ pub struct SequentialReader<R: Read> {
     sample_size: usize,
     chunk_size: usize,
     low_memory: bool,
+    null_values: Option<Vec<String>>,
+    comment_char: Option<u8>,
+    ragged_lines: RaggedLines,
 }
 
 impl<R> fmt::Debug for SequentialReader<R>
@@ -135,8 +138,11 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
         sample_size: usize,
         chunk_size: usize,
         low_memory: bool,
+        null_values: Option<Vec<String>>,
+        comment_char: Option<u8>,
+        ragged_lines: RaggedLines,
     ) -> Self {
-        let csv_reader = init_csv_reader(reader, has_header, delimiter);
+        let csv_reader = init_csv_reader(reader, has_header, delimiter, comment_char);
         let record_iter = Some(csv_reader.into_byte_records());
 
         Self {
@@ -155,6 +161,9 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
             sample_size,
             chunk_size,
             low_memory,
+            null_values,
+            comment_char,
+            ragged_lines,
         }
     }
 
@@ -162,6 +171,14 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
         // Skip all leading white space and the occasional utf8-bom
         bytes = skip_line_ending(skip_whitespace(skip_bom(bytes)).0).0;
 
+        // Skip the comment lines in front of the header.
+        if let Some(comment_char) = self.comment_char {
+            while bytes.first() == Some(&comment_char) {
+                let pos = next_line_position_naive(bytes).unwrap_or(bytes.len());
+                bytes = skip_line_ending(&bytes[pos..]).0;
+            }
+        }
+
         // If there is a header we skip it.
         if self.has_header {
             bytes = skip_header(bytes).0;
@@ -312,6 +329,8 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
             &POOL
         };
 
+        let null_values = self.null_values.as_deref().unwrap_or(&[]);
+
         // all the buffers returned from the threads
         // Structure:
         //      the inner vec has got buffers from all the columns.
@@ -351,6 +370,10 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
                             ignore_parser_errors,
                             self.encoding,
                             chunk_size,
+                            null_values,
+                            self.comment_char,
+                            self.ragged_lines,
+                            schema.fields().len(),
                         )?;
 
                         let mut local_df = DataFrame::new_no_checks(
@@ -470,6 +493,10 @@ pub fn build_csv_reader<R: 'static + Read + Seek + Sync + Send>(
     sample_size: usize,
     chunk_size: usize,
     low_memory: bool,
+    null_values: Option<Vec<String>>,
+    parse_dates: bool,
+    comment_char: Option<u8>,
+    ragged_lines: RaggedLines,
 ) -> Result<SequentialReader<R>> {
     // check if schema should be inferred
     let delimiter = delimiter.unwrap_or(b',');
@@ -483,6 +510,9 @@ pub fn build_csv_reader<R: 'static + Read + Seek + Sync + Send>(
                 has_header,
                 schema_overwrite,
                 skip_rows,
+                null_values.as_deref(),
+                parse_dates,
+                comment_char,
             )?;
             Arc::new(inferred_schema)
         }
@@ -512,5 +542,8 @@ pub fn build_csv_reader<R: 'static + Read + Seek + Sync + Send>(
         sample_size,
         chunk_size,
         low_memory,
+        null_values,
+        comment_char,
+        ragged_lines,
     ))
 }
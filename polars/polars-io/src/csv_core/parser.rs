@@ -1,7 +1,9 @@
 use super::buffer::*;
-use crate::csv::CsvEncoding;
+use crate::csv::{CsvEncoding, RaggedLines};
 use num::traits::Pow;
 use polars_core::prelude::*;
+#[cfg(any(feature = "dtype-date32", feature = "dtype-date64"))]
+use polars_core::utils::chrono::{Datelike, NaiveDate};
 
 /// Skip the utf-8 Byte Order Mark.
 /// credits to csv-core
@@ -309,6 +311,82 @@ impl<'a> Iterator for SplitFields<'a> {
     }
 }
 
+#[cfg(feature = "dtype-date64")]
+const MS_IN_DAY: i64 = 86_400_000;
+
+#[cfg(any(feature = "dtype-date32", feature = "dtype-date64"))]
+fn parse_digits(bytes: &[u8]) -> Option<u32> {
+    if bytes.is_empty() {
+        return None;
+    }
+    bytes.iter().try_fold(0u32, |acc, b| {
+        if b.is_ascii_digit() {
+            Some(acc * 10 + (b - b'0') as u32)
+        } else {
+            None
+        }
+    })
+}
+
+/// Number of days from the first day of the common era to the unix epoch.
+#[cfg(any(feature = "dtype-date32", feature = "dtype-date64"))]
+const EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+/// Parse a `yyyy-mm-dd` date to the number of days since the unix epoch.
+#[cfg(any(feature = "dtype-date32", feature = "dtype-date64"))]
+pub(crate) fn parse_iso_date(bytes: &[u8]) -> Option<i32> {
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year = parse_digits(&bytes[..4])?;
+    let month = parse_digits(&bytes[5..7])?;
+    let day = parse_digits(&bytes[8..10])?;
+    // rejects dates that don't exist, such as 2021-02-31
+    let date = NaiveDate::from_ymd_opt(year as i32, month, day)?;
+    Some(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+}
+
+/// Parse a `yyyy-mm-dd[(T| )hh:mm[:ss[.f+]]][Z]` datetime to the number of milliseconds since
+/// the unix epoch.
+#[cfg(feature = "dtype-date64")]
+pub(crate) fn parse_iso_datetime(bytes: &[u8]) -> Option<i64> {
+    let bytes = bytes.strip_suffix(b"Z").unwrap_or(bytes);
+    let days = parse_iso_date(bytes.get(..10)?)? as i64;
+    if bytes.len() == 10 {
+        return Some(days * MS_IN_DAY);
+    }
+    if bytes.len() < 16 || !matches!(bytes[10], b'T' | b' ') || bytes[13] != b':' {
+        return None;
+    }
+    let hour = parse_digits(&bytes[11..13])?;
+    let minute = parse_digits(&bytes[14..16])?;
+    let mut second = 0;
+    let mut millisecond = 0;
+    let rest = &bytes[16..];
+    if !rest.is_empty() {
+        if rest.len() < 3 || rest[0] != b':' {
+            return None;
+        }
+        second = parse_digits(&rest[1..3])?;
+        let fraction = &rest[3..];
+        if !fraction.is_empty() {
+            if fraction.len() < 2 || fraction[0] != b'.' {
+                return None;
+            }
+            let fraction = &fraction[1..];
+            // validate all digits, but only keep millisecond precision
+            parse_digits(fraction)?;
+            let digits = &fraction[..std::cmp::min(3, fraction.len())];
+            millisecond = parse_digits(digits)? * 10u32.pow(3 - digits.len() as u32);
+        }
+    }
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    let ms_of_day = (hour * 3600 + minute * 60 + second) as i64 * 1000 + millisecond as i64;
+    Some(days * MS_IN_DAY + ms_of_day)
+}
+
 /// Parse CSV.
 ///
 /// # Arguments
@@ -318,6 +396,9 @@ impl<'a> Iterator for SplitFields<'a> {
 /// * `projection` - Indices of the columns to project.
 /// * `buffers` - Parsed output will be written to these buffers. Except for UTF8 data. The offsets of the
 ///               fields are written to the buffers. The UTF8 data will be parsed later.
+/// * `null_values` - Fields that are equal to one of these values are parsed as null.
+/// * `comment_char` - Lines starting with this character are skipped.
+/// * `ragged_lines` - How to handle lines that don't have `n_fields` fields.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn parse_lines(
     bytes: &[u8],
@@ -328,6 +409,10 @@ pub(crate) fn parse_lines(
     ignore_parser_errors: bool,
     encoding: CsvEncoding,
     n_lines: usize,
+    null_values: &[String],
+    comment_char: Option<u8>,
+    ragged_lines: RaggedLines,
+    n_fields: usize,
 ) -> Result<usize> {
     // This variable will store the number of bytes we read. It is important to do this bookkeeping
     // to be able to correctly parse the strings later.
@@ -344,8 +429,9 @@ pub(crate) fn parse_lines(
         let len = line.len();
 
         // two adjacent '\n\n' will lead to an empty line.
-        if len == 0 {
-            read += 1;
+        // comment lines are skipped as well.
+        if len == 0 || comment_char == Some(line[0]) {
+            read += len + 1;
             continue;
         }
         // including the '\n' character
@@ -369,44 +455,71 @@ pub(crate) fn parse_lines(
             .next()
             .expect("at least one column should be projected");
         let mut processed_fields = 0;
+        let mut fields_in_line = 0;
+        let mut projection_done = false;
 
         let iter = SplitFields::new(line, delimiter);
 
         for (idx, field) in iter.enumerate() {
-            if idx == next_projected {
+            fields_in_line = idx + 1;
+            if !projection_done && idx == next_projected {
                 debug_assert!(processed_fields < buffers.len());
                 let buf = unsafe {
                     // SAFETY: processed fields index can never exceed the projection indices.
                     buffers.get_unchecked_mut(processed_fields)
                 };
                 // let buf = &mut buffers[processed_fields];
-                buf.add(field, ignore_parser_errors, read, encoding)
-                    .map_err(|e| {
-                        PolarsError::Other(
-                            format!(
-                                "{:?} on thread line {}; on input: {}",
-                                e,
-                                idx,
-                                String::from_utf8_lossy(field)
+                if null_values.iter().any(|v| v.as_bytes() == field) {
+                    buf.add_null();
+                } else {
+                    buf.add(field, ignore_parser_errors, read, encoding)
+                        .map_err(|e| {
+                            PolarsError::Other(
+                                format!(
+                                    "{:?} on thread line {}; on input: {}",
+                                    e,
+                                    idx,
+                                    String::from_utf8_lossy(field)
+                                )
+                                .into(),
                             )
-                            .into(),
-                        )
-                    })?;
+                        })?;
+                }
 
                 processed_fields += 1;
 
-                // if we have all projected columns we are done with this line
+                // if we have all projected columns we are done with this line, unless we need to
+                // count the fields of the line.
                 match projection_iter.next() {
                     Some(p) => next_projected = p,
-                    None => {
+                    None if ragged_lines == RaggedLines::Truncate => {
                         break;
                     }
+                    None => projection_done = true,
                 }
             }
             // +1 is the split character that is consumed by the iterator.
             read += field.len() + 1;
         }
 
+        // with the truncate policy the fields are not counted to the end of the line.
+        if ragged_lines != RaggedLines::Truncate {
+            let too_many = fields_in_line > n_fields;
+            let too_few = fields_in_line < n_fields && ragged_lines == RaggedLines::Error;
+            if too_many || too_few {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "line at byte position {} has {} fields, expected {}: {}",
+                        read_sol,
+                        fields_in_line,
+                        n_fields,
+                        String::from_utf8_lossy(line)
+                    )
+                    .into(),
+                ));
+            }
+        }
+
         // there can be lines that miss fields (also the comma values)
         // this means the splitter won't process them.
         // We traverse them to read them as null values.
@@ -417,7 +530,7 @@ pub(crate) fn parse_lines(
                 buffers.get_unchecked_mut(processed_fields)
             };
 
-            buf.add(&[], true, read, encoding)?;
+            buf.add_null();
             processed_fields += 1;
         }
 
@@ -442,4 +555,27 @@ mod test {
         hello";
         assert_eq!(skip_whitespace(input).0, b"hello");
     }
+
+    #[test]
+    #[cfg(all(feature = "dtype-date32", feature = "dtype-date64"))]
+    fn test_parse_iso_dates() {
+        assert_eq!(parse_iso_date(b"1970-01-01"), Some(0));
+        assert_eq!(parse_iso_date(b"2021-03-01"), Some(18687));
+        assert_eq!(parse_iso_date(b"1969-12-31"), Some(-1));
+        assert_eq!(parse_iso_date(b"2021-13-01"), None);
+        assert_eq!(parse_iso_date(b"2021-02-31"), None);
+        assert_eq!(parse_iso_date(b"2021-04-31"), None);
+        assert_eq!(parse_iso_date(b"2020-02-29"), Some(18321));
+        assert_eq!(parse_iso_date(b"2021-02-29"), None);
+        assert_eq!(parse_iso_date(b"20210301"), None);
+
+        assert_eq!(parse_iso_datetime(b"1970-01-02"), Some(86_400_000));
+        assert_eq!(parse_iso_datetime(b"1970-01-01T00:01"), Some(60_000));
+        assert_eq!(
+            parse_iso_datetime(b"1970-01-01 01:00:01.5Z"),
+            Some(3_601_500)
+        );
+        assert_eq!(parse_iso_datetime(b"1970-01-01T25:00"), None);
+        assert_eq!(parse_iso_datetime(b"foo"), None);
+    }
 }
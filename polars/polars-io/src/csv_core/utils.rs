@@ -1,5 +1,9 @@
 use crate::csv::CsvEncoding;
 use crate::csv_core::parser::next_line_position;
+#[cfg(feature = "dtype-date32")]
+use crate::csv_core::parser::parse_iso_date;
+#[cfg(feature = "dtype-date64")]
+use crate::csv_core::parser::parse_iso_datetime;
 use ahash::RandomState;
 use lazy_static::lazy_static;
 use polars_core::prelude::*;
@@ -12,10 +16,14 @@ pub(crate) fn init_csv_reader<R: Read>(
     reader: R,
     has_header: bool,
     delimiter: u8,
+    comment_char: Option<u8>,
 ) -> csv::Reader<R> {
     let mut reader_builder = csv::ReaderBuilder::new();
     reader_builder.has_headers(has_header);
     reader_builder.delimiter(delimiter);
+    reader_builder.comment(comment_char);
+    // ragged lines are handled by the parser.
+    reader_builder.flexible(true);
     reader_builder.from_reader(reader)
 }
 
@@ -59,12 +67,26 @@ lazy_static! {
 }
 
 /// Infer the data type of a record
-fn infer_field_schema(string: &str) -> DataType {
+fn infer_field_schema(string: &str, parse_dates: bool) -> DataType {
     // when quoting is enabled in the reader, these quotes aren't escaped, we default to
     // Utf8 for them
     if string.starts_with('"') {
         return DataType::Utf8;
     }
+    if parse_dates {
+        #[cfg(feature = "dtype-date32")]
+        {
+            if parse_iso_date(string.as_bytes()).is_some() {
+                return DataType::Date32;
+            }
+        }
+        #[cfg(feature = "dtype-date64")]
+        {
+            if parse_iso_datetime(string.as_bytes()).is_some() {
+                return DataType::Date64;
+            }
+        }
+    }
     // match regex in a particular order
     if BOOLEAN_RE.is_match(string) {
         DataType::Boolean
@@ -101,6 +123,9 @@ pub fn infer_file_schema<R: Read + Seek>(
     has_header: bool,
     schema_overwrite: Option<&Schema>,
     skip_rows: usize,
+    null_values: Option<&[String]>,
+    parse_dates: bool,
+    comment_char: Option<u8>,
 ) -> Result<(Schema, usize)> {
     let mut reader = BufReader::new(reader);
     let mut line = String::new();
@@ -113,7 +138,7 @@ pub fn infer_file_schema<R: Read + Seek>(
     // It may later.
     let encoding = CsvEncoding::LossyUtf8;
     // set headers to false otherwise the csv crate, skips them.
-    let csv_reader = init_csv_reader(reader, false, delimiter);
+    let csv_reader = init_csv_reader(reader, false, delimiter, comment_char);
 
     let mut records = csv_reader.into_byte_records();
     let header_length;
@@ -158,11 +183,14 @@ pub fn infer_file_schema<R: Read + Seek>(
 
         for i in 0..header_length {
             if let Some(slice) = record.get(i) {
-                if slice.is_empty() {
+                let is_null_value = null_values
+                    .map(|values| values.iter().any(|v| v.as_bytes() == slice))
+                    .unwrap_or(false);
+                if slice.is_empty() || is_null_value {
                     nulls[i] = true;
                 } else {
                     let s = parse_bytes_with_encoding(slice, encoding)?;
-                    column_types[i].insert(infer_field_schema(&s));
+                    column_types[i].insert(infer_field_schema(&s, parse_dates));
                 }
             }
         }
@@ -194,6 +222,12 @@ pub fn infer_file_schema<R: Read + Seek>(
                 {
                     // we have an integer and double, fall down to double
                     fields.push(Field::new(&field_name, DataType::Float64));
+                } else if cfg!(feature = "dtype-date64")
+                    && possibilities.contains(&DataType::Date32)
+                    && possibilities.contains(&DataType::Date64)
+                {
+                    // we have dates and datetimes, the datetime parser also accepts dates
+                    fields.push(Field::new(&field_name, DataType::Date64));
                 } else {
                     // default to Utf8 for conflicting datatypes (e.g bool and int)
                     fields.push(Field::new(&field_name, DataType::Utf8));
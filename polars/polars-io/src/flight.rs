@@ -0,0 +1,213 @@
+//! # Arrow Flight client.
+//!
+//! Fetch a `DataFrame` from an Arrow Flight service with `DoGet` and send one with `DoPut`.
+//! The record batches received from the service are moved into the `DataFrame` without
+//! copying the buffers.
+//!
+//! The functions are `async` and need to run on a tokio runtime.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::flight::{do_get, FlightServiceClient};
+//! use arrow_flight::Ticket;
+//!
+//! async fn example() -> Result<DataFrame> {
+//!     let mut client = FlightServiceClient::connect("http://localhost:50051").await.unwrap();
+//!     do_get(&mut client, Ticket { ticket: b"my_table".to_vec() }).await
+//! }
+//! ```
+use crate::utils::to_arrow_compatible_df;
+use arrow::array::ArrayRef;
+use arrow::datatypes::Schema as ArrowSchema;
+use arrow::ipc::{self, reader::read_dictionary, writer::IpcWriteOptions};
+use arrow::record_batch::RecordBatch;
+pub use arrow_flight::flight_service_client::FlightServiceClient;
+use arrow_flight::utils::{flight_data_from_arrow_batch, flight_data_to_arrow_batch};
+use arrow_flight::{FlightData, FlightDescriptor, PutResult, SchemaAsIpc, Ticket};
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use std::convert::TryFrom;
+use std::sync::Arc;
+use tonic::transport::Channel;
+
+fn to_polars_err<E: std::error::Error + Send + Sync + 'static>(e: E) -> PolarsError {
+    PolarsError::Various(e.into())
+}
+
+/// Decodes the messages of a flight stream. The dictionaries of dictionary encoded columns are
+/// sent in separate messages and are used to decode the record batches that follow them.
+struct FlightDecoder {
+    schema: Arc<ArrowSchema>,
+    dictionaries_by_field: Vec<Option<ArrayRef>>,
+    dfs: Vec<DataFrame>,
+}
+
+impl FlightDecoder {
+    /// Create a decoder from the first message of the stream, which contains the schema.
+    fn try_new(schema_data: &FlightData) -> Result<Self> {
+        let schema = Arc::new(ArrowSchema::try_from(schema_data)?);
+        let dictionaries_by_field = vec![None; schema.fields().len()];
+        Ok(FlightDecoder {
+            schema,
+            dictionaries_by_field,
+            dfs: vec![],
+        })
+    }
+
+    fn decode(&mut self, data: &FlightData) -> Result<()> {
+        let message = ipc::root_as_message(&data.data_header).map_err(|e| {
+            PolarsError::Other(format!("invalid flight data header: {:?}", e).into())
+        })?;
+        match message.header_as_dictionary_batch() {
+            Some(batch) => read_dictionary(
+                &data.data_body,
+                batch,
+                &self.schema,
+                &mut self.dictionaries_by_field,
+            )?,
+            None => {
+                let batch = flight_data_to_arrow_batch(
+                    data,
+                    self.schema.clone(),
+                    &self.dictionaries_by_field,
+                )?;
+                self.dfs.push(DataFrame::try_from(batch)?);
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<DataFrame> {
+        if self.dfs.is_empty() {
+            return DataFrame::try_from(RecordBatch::new_empty(self.schema));
+        }
+        accumulate_dataframes_vertical(self.dfs)
+    }
+}
+
+/// Encode `df` as the messages of a flight stream: the schema, followed by the dictionaries and
+/// the data of every record batch.
+fn to_flight_data(df: &DataFrame) -> Vec<FlightData> {
+    let df = to_arrow_compatible_df(df);
+    let options = IpcWriteOptions::default();
+    let schema = df.schema().to_arrow();
+
+    let mut messages = vec![FlightData::from(SchemaAsIpc::new(&schema, &options))];
+    for batch in df.iter_record_batches() {
+        let (dictionaries, data) = flight_data_from_arrow_batch(&batch, &options);
+        messages.extend(dictionaries);
+        messages.push(data);
+    }
+    messages
+}
+
+/// Issue a `DoGet` for `ticket` and collect the returned record batches into a DataFrame.
+pub async fn do_get(
+    client: &mut FlightServiceClient<Channel>,
+    ticket: Ticket,
+) -> Result<DataFrame> {
+    let mut stream = client
+        .do_get(ticket)
+        .await
+        .map_err(to_polars_err)?
+        .into_inner();
+
+    // the first message contains the schema
+    let mut decoder = match stream.message().await.map_err(to_polars_err)? {
+        Some(data) => FlightDecoder::try_new(&data)?,
+        None => return Err(PolarsError::NoData("the flight stream is empty".into())),
+    };
+    while let Some(data) = stream.message().await.map_err(to_polars_err)? {
+        decoder.decode(&data)?;
+    }
+    decoder.finish()
+}
+
+/// Send `df` to the service with a `DoPut` for `descriptor`. Returns the messages the service
+/// acknowledged the upload with.
+pub async fn do_put(
+    client: &mut FlightServiceClient<Channel>,
+    descriptor: FlightDescriptor,
+    df: &DataFrame,
+) -> Result<Vec<PutResult>> {
+    let mut messages = to_flight_data(df);
+    messages[0].flight_descriptor = Some(descriptor);
+
+    let mut stream = client
+        .do_put(futures::stream::iter(messages))
+        .await
+        .map_err(to_polars_err)?
+        .into_inner();
+    let mut results = vec![];
+    while let Some(result) = stream.message().await.map_err(to_polars_err)? {
+        results.push(result);
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::DictionaryArray;
+    use arrow::datatypes::{DataType as ArrowDataType, Field as ArrowField, Int32Type};
+    use polars_core::df;
+
+    fn decode(messages: &[FlightData]) -> Result<DataFrame> {
+        let mut decoder = FlightDecoder::try_new(&messages[0])?;
+        for data in &messages[1..] {
+            decoder.decode(data)?;
+        }
+        decoder.finish()
+    }
+
+    #[test]
+    fn test_flight_data_round_trip() -> Result<()> {
+        let mut df = df![
+            "a" => [1, 2, 3],
+            "b" => [Some("x"), None, Some("z")],
+            "c" => [1.0, 2.5, 3.0]
+        ]?;
+        // two record batches
+        df.vstack_mut(&df.clone())?;
+        let messages = to_flight_data(&df);
+        assert_eq!(messages.len(), 3);
+        assert!(decode(&messages)?.frame_equal_missing(&df));
+
+        let empty = df.head(Some(0));
+        let out = decode(&to_flight_data(&empty))?;
+        assert_eq!(out.shape(), (0, 3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_flight_data_dictionaries() -> Result<()> {
+        let dtype = ArrowDataType::Dictionary(
+            Box::new(ArrowDataType::Int32),
+            Box::new(ArrowDataType::Utf8),
+        );
+        let schema = Arc::new(ArrowSchema::new(vec![ArrowField::new("cat", dtype, true)]));
+        let options = IpcWriteOptions::default();
+
+        let mut messages = vec![FlightData::from(SchemaAsIpc::new(&schema, &options))];
+        // every batch is sent with its own dictionary
+        for values in &[vec!["a", "b", "a"], vec!["c", "a"]] {
+            let array: DictionaryArray<Int32Type> = values.iter().copied().collect();
+            let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)])?;
+            let (dictionaries, data) = flight_data_from_arrow_batch(&batch, &options);
+            assert_eq!(dictionaries.len(), 1);
+            messages.extend(dictionaries);
+            messages.push(data);
+        }
+
+        let out = decode(&messages)?;
+        assert_eq!(out.column("cat")?.dtype(), &DataType::Categorical);
+        let values = out.column("cat")?.cast::<Utf8Type>()?;
+        assert_eq!(
+            Vec::from(values.utf8()?),
+            &[Some("a"), Some("b"), Some("a"), Some("c"), Some("a")]
+        );
+        Ok(())
+    }
+}
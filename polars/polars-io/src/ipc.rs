@@ -32,14 +32,37 @@
 //! let df_read = IpcReader::new(buf).finish().unwrap();
 //! assert!(df.frame_equal(&df_read));
 //! ```
-use super::{finish_reader, ArrowReader, ArrowResult, RecordBatch};
+//!
+//! ## IPC stream format
+//!
+//! The IPC *stream* format doesn't need a seekable source or sink and is used to send DataFrames
+//! between processes, e.g. over sockets or stdin/ stdout. Use [IpcStreamReader] and
+//! [IpcStreamWriter] for this format.
+use super::{finish_reader, ArrowReader, ArrowResult, BatchedReader, RecordBatch};
 use crate::prelude::*;
 use crate::utils::to_arrow_compatible_df;
+#[cfg(feature = "lazy")]
+use crate::{PhysicalIoExpr, ScanAggregation};
+use arrow::array::{ArrayData, ArrayRef};
+use arrow::datatypes::Schema as ArrowSchema;
+use arrow::error::ArrowError;
 use arrow::ipc::{
-    reader::FileReader as ArrowIPCFileReader, writer::FileWriter as ArrowIPCFileWriter,
+    self,
+    reader::{
+        read_dictionary, read_record_batch, FileReader as ArrowIPCFileReader,
+        StreamReader as ArrowIPCStreamReader,
+    },
+    writer::{FileWriter as ArrowIPCFileWriter, StreamWriter as ArrowIPCStreamWriter},
 };
+use flatbuffers::FlatBufferBuilder;
+use memmap::Mmap;
 use polars_core::prelude::*;
-use std::io::{Read, Seek, Write};
+use std::borrow::Cow;
+use std::convert::TryInto;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+use std::path::Path;
 use std::sync::Arc;
 
 /// Read Arrows IPC format into a DataFrame
@@ -63,18 +86,619 @@ pub struct IpcReader<R> {
     reader: R,
     /// Aggregates chunks afterwards to a single chunk.
     rechunk: bool,
+    stop_after_n_rows: Option<usize>,
+    projection: Option<Vec<usize>>,
+}
+
+impl<R> IpcReader<R> {
+    /// Stop reading when `n` rows are read.
+    pub fn with_stop_after_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.stop_after_n_rows = num_rows;
+        self
+    }
+
+    /// Only read the columns at these indices. The other columns are not decoded.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
 }
 
-impl<R> ArrowReader for ArrowIPCFileReader<R>
+impl<R> IpcReader<R>
 where
     R: Read + Seek,
 {
+    /// Get the schema of the IPC file.
+    pub fn schema(self) -> Result<Schema> {
+        let ipc_reader = ArrowIPCFileReader::try_new(self.reader)?;
+        Ok((&*ipc_reader.schema()).into())
+    }
+
+    /// Get the number of rows of the IPC file. Only the footer and the headers of the record
+    /// batches are read, not their bodies.
+    pub fn num_rows(mut self) -> Result<usize> {
+        let reader = &mut self.reader;
+        let len = reader.seek(SeekFrom::End(0))? as usize;
+        let tail_len = ARROW_MAGIC.len() + 4;
+        if len < ARROW_MAGIC.len() + tail_len {
+            return Err(to_arrow_err("file too small").into());
+        }
+        let mut tail = vec![0; tail_len];
+        reader.seek(SeekFrom::Start((len - tail_len) as u64))?;
+        reader.read_exact(&mut tail)?;
+        if tail[4..] != ARROW_MAGIC {
+            return Err(to_arrow_err("missing footer").into());
+        }
+        let mut footer_len = [0; 4];
+        footer_len.copy_from_slice(&tail[..4]);
+        let footer_len = i32::from_le_bytes(footer_len) as usize;
+        let footer_start = (len - tail_len)
+            .checked_sub(footer_len)
+            .ok_or_else(|| to_arrow_err("footer length out of bounds"))?;
+        let mut footer = vec![0; footer_len];
+        reader.seek(SeekFrom::Start(footer_start as u64))?;
+        reader.read_exact(&mut footer)?;
+        let footer = ipc::root_as_footer(&footer).map_err(to_arrow_err)?;
+
+        let mut rows = 0;
+        let mut meta = vec![];
+        for block in footer.recordBatches().into_iter().flatten() {
+            meta.resize(block.metaDataLength() as usize, 0);
+            reader.seek(SeekFrom::Start(block.offset() as u64))?;
+            reader.read_exact(&mut meta)?;
+            let batch = read_message_header(&meta)?
+                .header_as_record_batch()
+                .ok_or_else(|| to_arrow_err("expected a record batch"))?;
+            rows += batch.length() as usize;
+        }
+        Ok(rows)
+    }
+
+    /// Read the file one record batch at a time. Every record batch is converted to a DataFrame
+    /// when it is requested, so the file does not have to fit in memory.
+    pub fn batched(self) -> Result<BatchedReader>
+    where
+        R: 'static,
+    {
+        let ipc_reader = IpcFileReader::try_new(SeekSource::new(self.reader)?, self.projection)?;
+        Ok(BatchedReader::new(
+            Box::new(ipc_reader),
+            self.stop_after_n_rows,
+        ))
+    }
+
+    #[cfg(feature = "lazy")]
+    // todo! hoist to lazy crate
+    pub fn finish_with_scan_ops(
+        self,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        aggregate: Option<&[ScanAggregation]>,
+    ) -> Result<DataFrame> {
+        let rechunk = self.rechunk;
+        let stop_after_n_rows = self.stop_after_n_rows;
+        let ipc_reader = IpcFileReader::try_new(SeekSource::new(self.reader)?, self.projection)?;
+        finish_reader(ipc_reader, rechunk, stop_after_n_rows, predicate, aggregate)
+    }
+}
+
+/// A source of the bytes of an IPC file.
+trait IpcSource {
+    /// The length of the file in bytes.
+    fn file_len(&self) -> usize;
+
+    /// Get `len` bytes of the file starting at `start`.
+    fn read_at(&mut self, start: usize, len: usize) -> ArrowResult<Cow<'_, [u8]>>;
+}
+
+impl IpcSource for Mmap {
+    fn file_len(&self) -> usize {
+        self.len()
+    }
+
+    fn read_at(&mut self, start: usize, len: usize) -> ArrowResult<Cow<'_, [u8]>> {
+        get_bytes(self, start, len).map(Cow::Borrowed)
+    }
+}
+
+/// Reads the blocks of an IPC file from a `Read + Seek` source into intermediate buffers.
+struct SeekSource<R> {
+    reader: R,
+    len: usize,
+}
+
+impl<R: Read + Seek> SeekSource<R> {
+    fn new(mut reader: R) -> ArrowResult<Self> {
+        let len = reader.seek(SeekFrom::End(0))? as usize;
+        Ok(SeekSource { reader, len })
+    }
+}
+
+impl<R: Read + Seek> IpcSource for SeekSource<R> {
+    fn file_len(&self) -> usize {
+        self.len
+    }
+
+    fn read_at(&mut self, start: usize, len: usize) -> ArrowResult<Cow<'_, [u8]>> {
+        // check the bounds before allocating, the lengths come from the file
+        match start.checked_add(len) {
+            Some(end) if end <= self.len => {}
+            _ => return Err(to_arrow_err("block out of bounds")),
+        }
+        let mut buf = vec![0; len];
+        self.reader.seek(SeekFrom::Start(start as u64))?;
+        self.reader.read_exact(&mut buf)?;
+        Ok(Cow::Owned(buf))
+    }
+}
+
+/// Which columns of a record batch are decoded.
+enum Projection {
+    All,
+    /// The ranges of the field nodes and buffers of the projected columns in a record batch
+    /// message. Only these are decoded.
+    Buffers(Vec<(Range<usize>, Range<usize>)>),
+    /// The layout of a column in the file is not known, so all columns are decoded and the
+    /// projected columns are selected afterwards.
+    Columns(Vec<usize>),
+}
+
+/// The number of field nodes and buffers of a column of type `dtype` in a record batch
+/// message, or `None` if the layout of the type is not known.
+fn field_layout(dtype: &ArrowDataType) -> Option<(usize, usize)> {
+    use ArrowDataType::*;
+    let layout = match dtype {
+        Boolean
+        | Int8
+        | Int16
+        | Int32
+        | Int64
+        | UInt8
+        | UInt16
+        | UInt32
+        | UInt64
+        | Float16
+        | Float32
+        | Float64
+        | Timestamp(_, _)
+        | Date32
+        | Date64
+        | Time32(_)
+        | Time64(_)
+        | Duration(_)
+        | Interval(_)
+        | Decimal(_, _)
+        | FixedSizeBinary(_)
+        | Dictionary(_, _) => (1, 2),
+        Binary | LargeBinary | Utf8 | LargeUtf8 => (1, 3),
+        List(field) | LargeList(field) => {
+            let (nodes, buffers) = field_layout(field.data_type())?;
+            (1 + nodes, 2 + buffers)
+        }
+        FixedSizeList(field, _) => {
+            let (nodes, buffers) = field_layout(field.data_type())?;
+            (1 + nodes, 1 + buffers)
+        }
+        Struct(fields) => fields.iter().try_fold((1, 1), |(nodes, buffers), field| {
+            let (n, b) = field_layout(field.data_type())?;
+            Some((nodes + n, buffers + b))
+        })?,
+        _ => return None,
+    };
+    Some(layout)
+}
+
+/// Reads the record batches of an IPC file and decodes only the projected columns.
+///
+/// Different from [ArrowIPCFileReader], the footer and the messages are decoded here, so that a
+/// record batch message can be rewritten to only contain the field nodes and buffers of the
+/// projected columns before it is passed to arrow. Only the record batches that are requested
+/// are read, so when the source is memory mapped, pages of batches that are not needed, e.g.
+/// when `stop_after_n_rows` is set, are never loaded from disk.
+struct IpcFileReader<S> {
+    source: S,
+    schema: Arc<ArrowSchema>,
+    projected_schema: Arc<ArrowSchema>,
+    projection: Projection,
+    blocks: Vec<ipc::Block>,
+    /// The dictionaries of the decoded columns.
+    dictionaries_by_field: Vec<Option<ArrayRef>>,
+    current_block: usize,
+}
+
+impl<S: IpcSource> IpcFileReader<S> {
+    fn try_new(mut source: S, projection: Option<Vec<usize>>) -> Result<Self> {
+        let len = source.file_len();
+        let tail_len = ARROW_MAGIC.len() + 4;
+        let has_magic = |source: &mut S, start| -> ArrowResult<bool> {
+            Ok(source.read_at(start, ARROW_MAGIC.len())?[..] == ARROW_MAGIC)
+        };
+        if len < ARROW_MAGIC.len() + tail_len
+            || !has_magic(&mut source, 0)?
+            || !has_magic(&mut source, len - ARROW_MAGIC.len())?
+        {
+            return Err(
+                to_arrow_err("Arrow file does not contain correct header or footer").into(),
+            );
+        }
+        let footer_end = len - tail_len;
+        let mut footer_len = [0; 4];
+        footer_len.copy_from_slice(&source.read_at(footer_end, 4)?);
+        let footer_len = i32::from_le_bytes(footer_len) as usize;
+        let footer_start = footer_end
+            .checked_sub(footer_len)
+            .ok_or_else(|| to_arrow_err("footer length out of bounds"))?;
+        let footer_bytes = source.read_at(footer_start, footer_len)?.into_owned();
+        let footer = ipc::root_as_footer(&footer_bytes).map_err(to_arrow_err)?;
+
+        let blocks = footer
+            .recordBatches()
+            .map(|blocks| blocks.to_vec())
+            .unwrap_or_default();
+        let ipc_schema = footer
+            .schema()
+            .ok_or_else(|| to_arrow_err("missing schema"))?;
+        let schema = Arc::new(ipc::convert::fb_to_schema(ipc_schema));
+
+        let mut dictionaries_by_field = vec![None; schema.fields().len()];
+        for block in footer.dictionaries().into_iter().flatten() {
+            let bytes = read_block(&mut source, block)?;
+            let (message, body) = split_block(&bytes, block)?;
+            let batch = message
+                .header_as_dictionary_batch()
+                .ok_or_else(|| to_arrow_err("expected a dictionary batch"))?;
+            if let Some(data) = batch.data() {
+                check_buffers(&data, body)?;
+            }
+            read_dictionary(body, batch, &schema, &mut dictionaries_by_field)?;
+        }
+        for values in dictionaries_by_field.iter().flatten() {
+            check_array_data(values.data())?;
+        }
+
+        let (projected_schema, projection) = match projection {
+            None => (schema.clone(), Projection::All),
+            Some(projection) => {
+                let fields = projection
+                    .iter()
+                    .map(|i| {
+                        schema.fields().get(*i).cloned().ok_or_else(|| {
+                            PolarsError::OutOfBounds(
+                                format!("projection index {} is out of bounds", i).into(),
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let layouts = schema
+                    .fields()
+                    .iter()
+                    .map(|f| field_layout(f.data_type()))
+                    .collect::<Option<Vec<_>>>();
+                let projection = match layouts {
+                    Some(layouts) => {
+                        let mut ranges = Vec::with_capacity(layouts.len());
+                        let (mut node, mut buffer) = (0, 0);
+                        for (n_nodes, n_buffers) in layouts {
+                            ranges.push((node..node + n_nodes, buffer..buffer + n_buffers));
+                            node += n_nodes;
+                            buffer += n_buffers;
+                        }
+                        dictionaries_by_field = projection
+                            .iter()
+                            .map(|i| dictionaries_by_field[*i].clone())
+                            .collect();
+                        Projection::Buffers(projection.iter().map(|i| ranges[*i].clone()).collect())
+                    }
+                    None => Projection::Columns(projection),
+                };
+                (Arc::new(ArrowSchema::new(fields)), projection)
+            }
+        };
+
+        Ok(IpcFileReader {
+            source,
+            schema,
+            projected_schema,
+            projection,
+            blocks,
+            dictionaries_by_field,
+            current_block: 0,
+        })
+    }
+}
+
+/// Magic bytes at the start and the end of an IPC file.
+const ARROW_MAGIC: [u8; 6] = *b"ARROW1";
+/// Prefix of the length of an IPC message since format version 0.15.
+const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+
+fn to_arrow_err<E: std::fmt::Debug>(e: E) -> ArrowError {
+    ArrowError::IoError(format!("could not read IPC file: {:?}", e))
+}
+
+/// Get `len` bytes of `bytes` starting at `start`.
+fn get_bytes(bytes: &[u8], start: usize, len: usize) -> ArrowResult<&[u8]> {
+    start
+        .checked_add(len)
+        .and_then(|end| bytes.get(start..end))
+        .ok_or_else(|| to_arrow_err("block out of bounds"))
+}
+
+/// Get the message of the metadata of an IPC block, which starts with the length of the message.
+fn read_message_header(meta: &[u8]) -> ArrowResult<ipc::Message> {
+    let mut meta_start = 4;
+    let mut meta_len = get_bytes(meta, 0, 4)?;
+    if meta_len == CONTINUATION_MARKER {
+        meta_len = get_bytes(meta, meta_start, 4)?;
+        meta_start += 4;
+    }
+    let mut len = [0; 4];
+    len.copy_from_slice(meta_len);
+    let message = get_bytes(meta, meta_start, i32::from_le_bytes(len) as usize)?;
+    ipc::root_as_message(message).map_err(to_arrow_err)
+}
+
+/// Read the metadata and the body of the IPC `block`.
+fn read_block<'a, S: IpcSource>(
+    source: &'a mut S,
+    block: &ipc::Block,
+) -> ArrowResult<Cow<'a, [u8]>> {
+    let len = (block.metaDataLength() as usize)
+        .checked_add(block.bodyLength() as usize)
+        .ok_or_else(|| to_arrow_err("block out of bounds"))?;
+    source.read_at(block.offset() as usize, len)
+}
+
+/// Split the `bytes` of an IPC `block` in its message and its body.
+fn split_block<'a>(
+    bytes: &'a [u8],
+    block: &ipc::Block,
+) -> ArrowResult<(ipc::Message<'a>, &'a [u8])> {
+    let meta_len = block.metaDataLength() as usize;
+    let message = read_message_header(get_bytes(bytes, 0, meta_len)?)?;
+    let body = get_bytes(bytes, meta_len, block.bodyLength() as usize)?;
+    Ok((message, body))
+}
+
+/// Check that the buffers of a record batch message are in the bounds of the `body` of the
+/// message. Arrow slices the body with these without checking them.
+fn check_buffers(batch: &ipc::RecordBatch, body: &[u8]) -> ArrowResult<()> {
+    for buffer in batch.buffers().unwrap_or(&[]) {
+        if buffer.offset() < 0
+            || buffer.length() < 0
+            || get_bytes(body, buffer.offset() as usize, buffer.length() as usize).is_err()
+        {
+            return Err(to_arrow_err("buffer out of bounds"));
+        }
+    }
+    Ok(())
+}
+
+/// The width in bytes of the values of a fixed width type.
+fn fixed_width(dtype: &ArrowDataType) -> Option<usize> {
+    use ArrowDataType::*;
+    let width = match dtype {
+        Int8 | UInt8 => 1,
+        Int16 | UInt16 | Float16 => 2,
+        Int32 | UInt32 | Float32 | Date32 | Time32(_) => 4,
+        Int64 | UInt64 | Float64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => 8,
+        _ => return None,
+    };
+    Some(width)
+}
+
+/// Read the `len + 1` offsets of a variable sized array.
+fn read_offsets(data: &ArrayData, large: bool) -> ArrowResult<Vec<usize>> {
+    let width = if large { 8 } else { 4 };
+    let bytes = data
+        .buffers()
+        .get(0)
+        .map_or(&[][..], |buffer| buffer.as_slice());
+    let bytes = get_bytes(bytes, data.offset() * width, (data.len() + 1) * width)
+        .map_err(|_| to_arrow_err("offsets out of bounds"))?;
+    bytes
+        .chunks_exact(width)
+        .map(|offset| {
+            let offset = if large {
+                i64::from_le_bytes(offset.try_into().unwrap())
+            } else {
+                i32::from_le_bytes(offset.try_into().unwrap()) as i64
+            };
+            if offset < 0 {
+                Err(to_arrow_err("negative offset"))
+            } else {
+                Ok(offset as usize)
+            }
+        })
+        .collect()
+}
+
+/// Check that the offsets are ascending and end in the bounds of the values.
+fn check_offsets(offsets: &[usize], values_len: usize) -> ArrowResult<()> {
+    let ascending = offsets.windows(2).all(|w| w[0] <= w[1]);
+    match offsets.last() {
+        Some(last) if !ascending || *last > values_len => {
+            Err(to_arrow_err("offsets out of bounds"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Validate the buffers of decoded array data. Arrow does not validate the lengths, offsets and
+/// utf8 values that are read from the file, and reading the values of an invalid array is
+/// undefined behavior.
+fn check_array_data(data: &ArrayData) -> ArrowResult<()> {
+    use ArrowDataType::*;
+    let end = data.offset() + data.len();
+    if let Some(validity) = data.null_buffer() {
+        if validity.len() * 8 < end {
+            return Err(to_arrow_err("validity out of bounds"));
+        }
+    }
+    if data.len() == 0 {
+        return Ok(());
+    }
+    let buffer_len = |i: usize| data.buffers().get(i).map_or(0, |buffer| buffer.len());
+    match data.data_type() {
+        Boolean => {
+            if buffer_len(0) * 8 < end {
+                return Err(to_arrow_err("values out of bounds"));
+            }
+        }
+        Utf8 | LargeUtf8 | Binary | LargeBinary => {
+            let large = matches!(data.data_type(), LargeUtf8 | LargeBinary);
+            let offsets = read_offsets(data, large)?;
+            check_offsets(&offsets, buffer_len(1))?;
+            if matches!(data.data_type(), Utf8 | LargeUtf8) {
+                let values = data.buffers()[1].as_slice();
+                for w in offsets.windows(2) {
+                    std::str::from_utf8(&values[w[0]..w[1]]).map_err(to_arrow_err)?;
+                }
+            }
+        }
+        List(_) | LargeList(_) => {
+            let child = data
+                .child_data()
+                .get(0)
+                .ok_or_else(|| to_arrow_err("missing list values"))?;
+            let offsets = read_offsets(data, matches!(data.data_type(), LargeList(_)))?;
+            check_offsets(&offsets, child.len())?;
+            check_array_data(child)?;
+        }
+        Dictionary(keys, _) => {
+            let width = fixed_width(keys).unwrap_or(0);
+            if buffer_len(0) < end * width {
+                return Err(to_arrow_err("dictionary keys out of bounds"));
+            }
+        }
+        dtype => {
+            if let Some(width) = fixed_width(dtype) {
+                if buffer_len(0) < end * width {
+                    return Err(to_arrow_err("values out of bounds"));
+                }
+            }
+            for child in data.child_data() {
+                check_array_data(child)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl<S: IpcSource> ArrowReader for IpcFileReader<S> {
     fn next_record_batch(&mut self) -> ArrowResult<Option<RecordBatch>> {
-        self.next().map_or(Ok(None), |v| v.map(Some))
+        let block = match self.blocks.get(self.current_block) {
+            Some(block) => *block,
+            None => return Ok(None),
+        };
+        self.current_block += 1;
+        let bytes = read_block(&mut self.source, &block)?;
+        let (message, body) = split_block(&bytes, &block)?;
+        let batch = message
+            .header_as_record_batch()
+            .ok_or_else(|| to_arrow_err("expected a record batch"))?;
+        check_buffers(&batch, body)?;
+
+        let batch = match &self.projection {
+            Projection::All => read_record_batch(
+                body,
+                batch,
+                self.schema.clone(),
+                &self.dictionaries_by_field,
+            )?,
+            Projection::Columns(columns) => {
+                let batch = read_record_batch(
+                    body,
+                    batch,
+                    self.schema.clone(),
+                    &self.dictionaries_by_field,
+                )?;
+                let columns = columns.iter().map(|i| batch.column(*i).clone()).collect();
+                RecordBatch::try_new(self.projected_schema.clone(), columns)?
+            }
+            Projection::Buffers(ranges) => {
+                // rewrite the message, so that it only refers to the buffers of the projected
+                // columns in the body
+                let all_nodes = batch.nodes().unwrap_or(&[]);
+                let all_buffers = batch.buffers().unwrap_or(&[]);
+                let mut nodes = vec![];
+                let mut buffers = vec![];
+                for (node_range, buffer_range) in ranges {
+                    nodes.extend_from_slice(
+                        all_nodes
+                            .get(node_range.clone())
+                            .ok_or_else(|| to_arrow_err("field node out of bounds"))?,
+                    );
+                    buffers.extend_from_slice(
+                        all_buffers
+                            .get(buffer_range.clone())
+                            .ok_or_else(|| to_arrow_err("buffer out of bounds"))?,
+                    );
+                }
+                let mut fbb = FlatBufferBuilder::new();
+                let nodes = fbb.create_vector(&nodes);
+                let buffers = fbb.create_vector(&buffers);
+                let mut builder = ipc::RecordBatchBuilder::new(&mut fbb);
+                builder.add_length(batch.length());
+                builder.add_nodes(nodes);
+                builder.add_buffers(buffers);
+                let projected = builder.finish();
+                fbb.finish(projected, None);
+                let projected = flatbuffers::root::<ipc::RecordBatch>(fbb.finished_data())
+                    .map_err(to_arrow_err)?;
+                read_record_batch(
+                    body,
+                    projected,
+                    self.projected_schema.clone(),
+                    &self.dictionaries_by_field,
+                )?
+            }
+        };
+        for column in batch.columns() {
+            check_array_data(column.data())?;
+        }
+        Ok(Some(batch))
     }
 
     fn schema(&self) -> Arc<Schema> {
-        Arc::new((&*self.schema()).into())
+        Arc::new((&*self.projected_schema).into())
+    }
+}
+
+impl IpcReader<Mmap> {
+    /// Create a reader that memory maps the IPC file at `path` instead of reading it through
+    /// file handle system calls. The pages of the file are loaded by the OS when they are
+    /// accessed and are shared between readers of the same file.
+    ///
+    /// Only the pages of the record batches that are read are accessed. This is not a zero copy
+    /// read: the arrow buffers of this arrow version can't borrow the mapped memory, so the
+    /// values are copied out of the mapped pages when a batch is decoded. The decoded buffers
+    /// are validated, as the file is not trusted.
+    pub fn memory_mapped<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: the mapping is read only and all reads are bounds checked against the length
+        // of the file at the time it was mapped. It is still undefined behavior if the file is
+        // modified or truncated by another process while it is mapped: a truncation makes
+        // accesses to the pages past the new end of the file raise SIGBUS. Like the other
+        // memory mapped readers, we require that the file is not changed while it is read.
+        let mmap = unsafe { Mmap::map(&file)? };
+        Ok(IpcReader {
+            reader: mmap,
+            rechunk: true,
+            stop_after_n_rows: None,
+            projection: None,
+        })
+    }
+
+    /// Rechunk to a single chunk after reading the file.
+    pub fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    /// Read the memory mapped file into a DataFrame.
+    pub fn finish(self) -> Result<DataFrame> {
+        let ipc_reader = IpcFileReader::try_new(self.reader, self.projection)?;
+        finish_reader(ipc_reader, self.rechunk, self.stop_after_n_rows, None, None)
     }
 }
 
@@ -86,6 +710,8 @@ where
         IpcReader {
             reader,
             rechunk: true,
+            stop_after_n_rows: None,
+            projection: None,
         }
     }
     fn set_rechunk(mut self, rechunk: bool) -> Self {
@@ -95,8 +721,9 @@ where
 
     fn finish(self) -> Result<DataFrame> {
         let rechunk = self.rechunk;
-        let ipc_reader = ArrowIPCFileReader::try_new(self.reader)?;
-        finish_reader(ipc_reader, rechunk, None, None, None)
+        let stop_after_n_rows = self.stop_after_n_rows;
+        let ipc_reader = IpcFileReader::try_new(SeekSource::new(self.reader)?, self.projection)?;
+        finish_reader(ipc_reader, rechunk, stop_after_n_rows, None, None)
     }
 }
 
@@ -144,6 +771,113 @@ where
     }
 }
 
+/// Read Arrows IPC stream format into a DataFrame
+///
+/// Contrary to the other readers, this only requires the source to implement `Read`,
+/// so it does not implement [SerReader].
+///
+/// # Example
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::ipc::IpcStreamReader;
+///
+/// fn example() -> Result<DataFrame> {
+///     let stdin = std::io::stdin();
+///     IpcStreamReader::new(stdin.lock()).finish()
+/// }
+/// ```
+pub struct IpcStreamReader<R> {
+    /// Stream object
+    reader: R,
+    /// Aggregates chunks afterwards to a single chunk.
+    rechunk: bool,
+    stop_after_n_rows: Option<usize>,
+}
+
+impl<R> ArrowReader for ArrowIPCStreamReader<R>
+where
+    R: Read,
+{
+    fn next_record_batch(&mut self) -> ArrowResult<Option<RecordBatch>> {
+        self.next().map_or(Ok(None), |v| v.map(Some))
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        Arc::new((&*self.schema()).into())
+    }
+}
+
+impl<R> IpcStreamReader<R>
+where
+    R: Read,
+{
+    pub fn new(reader: R) -> Self {
+        IpcStreamReader {
+            reader,
+            rechunk: true,
+            stop_after_n_rows: None,
+        }
+    }
+
+    /// Rechunk to a single chunk after reading the stream.
+    pub fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_stop_after_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.stop_after_n_rows = num_rows;
+        self
+    }
+
+    /// Read the stream until it is exhausted and create the DataFrame.
+    pub fn finish(self) -> Result<DataFrame> {
+        let rechunk = self.rechunk;
+        let stop_after_n_rows = self.stop_after_n_rows;
+        let ipc_reader = ArrowIPCStreamReader::try_new(self.reader)?;
+        finish_reader(ipc_reader, rechunk, stop_after_n_rows, None, None)
+    }
+}
+
+/// Write a DataFrame to Arrow's IPC stream format
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::ipc::IpcStreamWriter;
+/// use polars_io::SerWriter;
+///
+/// fn example(df: &DataFrame) -> Result<()> {
+///     let stdout = std::io::stdout();
+///     IpcStreamWriter::new(&mut stdout.lock()).finish(df)
+/// }
+/// ```
+pub struct IpcStreamWriter<'a, W> {
+    writer: &'a mut W,
+}
+
+impl<'a, W> SerWriter<'a, W> for IpcStreamWriter<'a, W>
+where
+    W: Write,
+{
+    fn new(writer: &'a mut W) -> Self {
+        IpcStreamWriter { writer }
+    }
+
+    fn finish(self, df: &DataFrame) -> Result<()> {
+        let df = to_arrow_compatible_df(df);
+        let mut ipc_writer = ArrowIPCStreamWriter::try_new(self.writer, &df.schema().to_arrow())?;
+
+        for batch in df.iter_record_batches() {
+            ipc_writer.write(&batch)?
+        }
+        ipc_writer.finish()?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -165,4 +899,195 @@ mod test {
         let df_read = IpcReader::new(buf).finish().unwrap();
         assert!(df.frame_equal(&df_read));
     }
+
+    #[test]
+    fn read_ipc_with_projection() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let df = create_df();
+        IpcWriter::new(&mut buf).finish(&df).expect("ipc writer");
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf)
+            .with_projection(Some(vec![1]))
+            .finish()
+            .unwrap();
+        assert_eq!(df_read.get_column_names(), &[df.get_column_names()[1]]);
+    }
+
+    #[test]
+    fn read_ipc_projected_buffers() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let df = df![
+            "name" => [Some("a"), None, Some("ccc")],
+            "values" => [1i32, 2, 3],
+            "flag" => [Some(true), Some(false), None]
+        ]?;
+        IpcWriter::new(&mut buf).finish(&df)?;
+        buf.set_position(0);
+
+        // the columns after a variable length column are found and can be reordered
+        let df_read = IpcReader::new(buf)
+            .with_projection(Some(vec![2, 0]))
+            .finish()?;
+        assert!(df_read.frame_equal_missing(&df.select(&["flag", "name"])?));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn write_and_read_ipc_decimal() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let s = Series::new("dec", &["1.25", "-3.5", "100"])
+            .cast_with_dtype(&DataType::Decimal(12, 3))
+            .unwrap();
+        let df = DataFrame::new(vec![s]).unwrap();
+        IpcWriter::new(&mut buf).finish(&df).expect("ipc writer");
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        assert_eq!(
+            df_read.column("dec").unwrap().dtype(),
+            &DataType::Decimal(12, 3)
+        );
+        assert!(df.frame_equal(&df_read));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-binary")]
+    fn write_and_read_ipc_binary() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let values = [Some(&b"\x00\xff"[..]), None, Some(&b"polars"[..])];
+        let s = BinaryChunked::new_from_opt_slice("bytes", &values).into_series();
+        let df = DataFrame::new(vec![s]).unwrap();
+        IpcWriter::new(&mut buf).finish(&df).expect("ipc writer");
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        let ca = df_read.column("bytes").unwrap().binary().unwrap();
+        assert_eq!(ca.into_iter().collect::<Vec<_>>(), values);
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn write_and_read_ipc_struct() {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let fields = [
+            Series::new("a", &[1, 2, 3]),
+            Series::new("b", &["foo", "bar", "ham"]),
+        ];
+        let s = StructChunked::new("s", &fields).unwrap().into_series();
+        let df = DataFrame::new(vec![s]).unwrap();
+        IpcWriter::new(&mut buf).finish(&df).expect("ipc writer");
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish().unwrap();
+        let s = df_read.column("s").unwrap().struct_().unwrap();
+        assert_eq!(s.dtype(), df.column("s").unwrap().dtype());
+        assert!(s.field("b").unwrap().series_equal(&fields[1]));
+    }
+
+    #[test]
+    fn read_ipc_memory_mapped() {
+        let path = std::env::temp_dir().join("polars_read_ipc_memory_mapped.ipc");
+        let df = create_df();
+        IpcWriter::new(&mut std::fs::File::create(&path).unwrap())
+            .finish(&df)
+            .expect("ipc writer");
+
+        let df_read = IpcReader::memory_mapped(&path).unwrap().finish().unwrap();
+        assert!(df.frame_equal(&df_read));
+    }
+
+    #[test]
+    fn read_ipc_memory_mapped_projected() -> Result<()> {
+        let path = std::env::temp_dir().join("polars_read_ipc_memory_mapped_projected.ipc");
+        let mut df = create_df();
+        // two record batches
+        df.vstack_mut(&create_df())?;
+        IpcWriter::new(&mut std::fs::File::create(&path)?).finish(&df)?;
+
+        let df_read = IpcReader::memory_mapped(&path)?
+            .with_projection(Some(vec![1]))
+            .with_stop_after_n_rows(Some(7))
+            .finish()?;
+        assert_eq!(df_read.get_column_names(), &["temp"]);
+        assert_eq!(df_read.height(), 7);
+        assert!(df_read
+            .column("temp")?
+            .series_equal(&df.column("temp")?.slice(0, 7)));
+
+        std::fs::write(&path, b"ARROW1 not an ipc file ARROW1")?;
+        assert!(IpcReader::memory_mapped(&path)?.finish().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn read_ipc_invalid_offsets() -> Result<()> {
+        let path = std::env::temp_dir().join("polars_read_ipc_invalid_offsets.ipc");
+        let df = df!("a" => ["abc", "de"])?;
+        let mut buf = vec![];
+        IpcWriter::new(&mut buf).finish(&df)?;
+        let find = |buf: &[u8], pattern: &[u8]| {
+            buf.windows(pattern.len())
+                .position(|w| w == pattern)
+                .unwrap()
+        };
+
+        // an offset past the end of the values
+        let offsets = [0i32, 3, 5]
+            .iter()
+            .flat_map(|v| v.to_le_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let mut corrupt = buf.clone();
+        let idx = find(&buf, &offsets) + 8;
+        corrupt[idx..idx + 4].copy_from_slice(&255i32.to_le_bytes());
+        std::fs::write(&path, &corrupt)?;
+        assert!(IpcReader::memory_mapped(&path)?.finish().is_err());
+        assert!(IpcReader::new(Cursor::new(corrupt)).finish().is_err());
+
+        // a value that is not valid utf8
+        let mut corrupt = buf.clone();
+        corrupt[find(&buf, b"abcde")] = 0xff;
+        std::fs::write(&path, &corrupt)?;
+        assert!(IpcReader::memory_mapped(&path)?.finish().is_err());
+
+        std::fs::write(&path, &buf)?;
+        assert!(IpcReader::memory_mapped(&path)?.finish()?.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn ipc_num_rows() -> Result<()> {
+        let mut df = create_df();
+        // two record batches
+        df.vstack_mut(&create_df())?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf).finish(&df)?;
+        buf.set_position(0);
+        assert_eq!(IpcReader::new(buf).num_rows()?, 10);
+
+        let buf = Cursor::new(b"ARROW1 not an ipc file ARROW1".to_vec());
+        assert!(IpcReader::new(buf).num_rows().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn write_and_read_ipc_stream() {
+        let mut buf: Vec<u8> = Vec::new();
+        let df = create_df();
+
+        IpcStreamWriter::new(&mut buf)
+            .finish(&df)
+            .expect("ipc stream writer");
+
+        // a slice is `Read` but not `Seek`
+        let df_read = IpcStreamReader::new(buf.as_slice()).finish().unwrap();
+        assert!(df.frame_equal(&df_read));
+
+        let df_read = IpcStreamReader::new(buf.as_slice())
+            .with_stop_after_n_rows(Some(2))
+            .finish()
+            .unwrap();
+        assert_eq!(df_read.height(), 2);
+    }
 }
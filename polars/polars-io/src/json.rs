@@ -2,6 +2,11 @@
 //!
 //! ## Read JSON to a DataFrame
 //!
+//! The reader expects newline delimited JSON (NDJSON / JSON Lines): one JSON object per line.
+//! The file is streamed in batches of `batch_size` lines, every batch is parsed directly into
+//! arrow arrays and becomes a chunk of the resulting `DataFrame`. The schema is inferred from
+//! the first lines of the file (100 by default, see [`JsonReader::infer_schema`]).
+//!
 //! ## Example
 //!
 //! ```
@@ -74,6 +79,7 @@ where
     reader: R,
     reader_builder: ReaderBuilder,
     rechunk: bool,
+    stop_after_n_rows: Option<usize>,
 }
 
 impl<R> SerReader<R> for JsonReader<R>
@@ -83,8 +89,9 @@ where
     fn new(reader: R) -> Self {
         JsonReader {
             reader,
-            reader_builder: ReaderBuilder::new(),
+            reader_builder: ReaderBuilder::new().infer_schema(Some(100)),
             rechunk: true,
+            stop_after_n_rows: None,
         }
     }
 
@@ -95,13 +102,19 @@ where
 
     fn finish(self) -> Result<DataFrame> {
         let rechunk = self.rechunk;
-        finish_reader(
+        let stop_after_n_rows = self.stop_after_n_rows;
+        let df = finish_reader(
             self.reader_builder.build(self.reader)?,
             rechunk,
+            stop_after_n_rows,
             None,
             None,
-            None,
-        )
+        )?;
+        // the last batch may contain more rows than requested
+        match stop_after_n_rows {
+            Some(n) if n < df.height() => Ok(df.slice(0, n)),
+            _ => Ok(df),
+        }
     }
 }
 
@@ -115,7 +128,8 @@ where
         self
     }
 
-    /// Set the JSON reader to infer the schema of the file
+    /// Set the JSON reader to infer the schema of the file from the first `max_records` lines.
+    /// If `None`, the whole file is read to infer the schema.
     pub fn infer_schema(mut self, max_records: Option<usize>) -> Self {
         self.reader_builder = self.reader_builder.infer_schema(max_records);
         self
@@ -128,6 +142,12 @@ where
         self
     }
 
+    /// Stop reading when `n` rows are read.
+    pub fn with_stop_after_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.stop_after_n_rows = num_rows;
+        self
+    }
+
     /// Set the reader's column projection
     pub fn with_projection(mut self, projection: Vec<String>) -> Self {
         self.reader_builder = self.reader_builder.with_projection(projection);
@@ -138,6 +158,7 @@ where
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
+    use polars_core::prelude::*;
     use std::io::Cursor;
 
     #[test]
@@ -166,4 +187,45 @@ mod test {
         assert_eq!("d", df.get_columns()[3].name());
         assert_eq!((12, 4), df.shape());
     }
+
+    #[test]
+    fn read_json_batched() -> Result<()> {
+        let json = r#"{"a":1, "b":"x"}
+{"a":2, "b":"y"}
+{"a":3}
+{"a":4, "b":"z"}
+{"a":5, "b":"x"}"#;
+        let df = JsonReader::new(Cursor::new(json))
+            .infer_schema(Some(2))
+            .with_batch_size(2)
+            .set_rechunk(false)
+            .finish()?;
+        assert_eq!(df.n_chunks()?, 3);
+        assert_eq!(df.column("b")?.null_count(), 1);
+
+        let df = JsonReader::new(Cursor::new(json))
+            .with_batch_size(2)
+            .with_stop_after_n_rows(Some(3))
+            .finish()?;
+        assert_eq!(df.shape(), (3, 2));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn read_json_nested() -> Result<()> {
+        let json = r#"{"a":1, "b":{"c":"x", "d":1.5}}
+{"a":2, "b":{"c":"y", "d":2.5}}
+{"a":3, "b":{"c":"z", "d":3.5}}"#;
+        let df = JsonReader::new(Cursor::new(json))
+            .with_batch_size(2)
+            .finish()?;
+        let b = df.column("b")?.struct_()?;
+        assert_eq!(b.len(), 3);
+        assert_eq!(
+            Vec::from(b.field("c")?.utf8()?),
+            &[Some("x"), Some("y"), Some("z")]
+        );
+        Ok(())
+    }
 }
@@ -1,22 +1,39 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "avro")]
+#[cfg_attr(docsrs, doc(cfg(feature = "avro")))]
+pub mod avro;
 #[cfg(feature = "csv-file")]
 #[cfg_attr(docsrs, doc(cfg(feature = "csv-file")))]
 pub mod csv;
 #[cfg(feature = "csv-file")]
 #[cfg_attr(docsrs, doc(cfg(feature = "csv-file")))]
 pub mod csv_core;
+#[cfg(feature = "flight")]
+#[cfg_attr(docsrs, doc(cfg(feature = "flight")))]
+pub mod flight;
 #[cfg(feature = "ipc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
 pub mod ipc;
 #[cfg(feature = "json")]
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
 pub mod json;
+#[cfg(feature = "object-store")]
+#[cfg_attr(docsrs, doc(cfg(feature = "object-store")))]
+pub mod object_store;
 #[cfg(feature = "parquet")]
 #[cfg_attr(docsrs, doc(cfg(feature = "feature")))]
 pub mod parquet;
+#[cfg(any(feature = "csv-file", feature = "parquet", feature = "ipc"))]
+pub mod partition;
 pub mod prelude;
+#[cfg(feature = "sql")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sql")))]
+pub mod sql;
 pub(crate) mod utils;
+#[cfg(feature = "xlsx")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xlsx")))]
+pub mod xlsx;
 
 use arrow::{
     error::Result as ArrowResult, json::Reader as ArrowJsonReader, record_batch::RecordBatch,
@@ -28,6 +45,43 @@ use std::sync::Arc;
 
 pub trait PhysicalIoExpr: Send + Sync {
     fn evaluate(&self, df: &DataFrame) -> Result<Series>;
+
+    /// Get a view on the expression that decides, based on statistics, if a batch of rows
+    /// needs to be read at all.
+    fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+        None
+    }
+}
+
+/// Decide if a batch of rows (e.g. a parquet row group) may contain rows that pass a predicate.
+pub trait StatsEvaluator {
+    /// Returns `false` only if it is certain that no row in the batch passes the predicate.
+    fn should_read(&self, stats: &BatchStats) -> Result<bool>;
+}
+
+/// Statistics of a single column in a batch of rows.
+#[derive(Debug, Clone)]
+pub struct ColumnStats {
+    pub field: Field,
+    pub null_count: Option<usize>,
+    /// Minimal value of the batch as a `Series` of length 1.
+    pub min_value: Option<Series>,
+    /// Maximal value of the batch as a `Series` of length 1.
+    pub max_value: Option<Series>,
+}
+
+/// Statistics of a batch of rows.
+#[derive(Debug, Clone)]
+pub struct BatchStats {
+    pub num_rows: usize,
+    pub column_stats: Vec<ColumnStats>,
+}
+
+impl BatchStats {
+    /// Get the statistics of the column with the given name.
+    pub fn get_stats(&self, name: &str) -> Option<&ColumnStats> {
+        self.column_stats.iter().find(|s| s.field.name() == name)
+    }
 }
 
 pub trait SerReader<R>
@@ -124,6 +178,11 @@ pub(crate) fn finish_reader<R: ArrowReader>(
             .map(|scan_agg| scan_agg.finish(&df))
             .collect::<Result<_>>()?;
         df = DataFrame::new_no_checks(cols)
+    } else if let Some(n) = stop_after_n_rows {
+        // the last batch may contain more rows than requested
+        if df.height() > n {
+            df = df.slice(0, n)
+        }
     }
 
     match rechunk {
@@ -132,6 +191,60 @@ pub(crate) fn finish_reader<R: ArrowReader>(
     }
 }
 
+/// Converts the record batches of an IPC or parquet file to DataFrames one at a time, so that
+/// the file does not have to fit in memory.
+///
+/// Created with [ipc::IpcReader::batched] or [parquet::ParquetReader::batched].
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+pub struct BatchedReader {
+    reader: Box<dyn ArrowReader>,
+    rows_remaining: Option<usize>,
+}
+
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+impl BatchedReader {
+    pub(crate) fn new(reader: Box<dyn ArrowReader>, stop_after_n_rows: Option<usize>) -> Self {
+        BatchedReader {
+            reader,
+            rows_remaining: stop_after_n_rows,
+        }
+    }
+
+    /// Schema of the batches.
+    pub fn schema(&self) -> Arc<Schema> {
+        self.reader.schema()
+    }
+}
+
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+impl Iterator for BatchedReader {
+    type Item = Result<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use std::convert::TryFrom;
+
+        if self.rows_remaining == Some(0) {
+            return None;
+        }
+        let batch = match self.reader.next_record_batch() {
+            Ok(Some(batch)) => batch,
+            Ok(None) => return None,
+            Err(e) => return Some(Err(e.into())),
+        };
+        let mut df = match DataFrame::try_from(batch) {
+            Ok(df) => df,
+            Err(e) => return Some(Err(e)),
+        };
+        if let Some(remaining) = &mut self.rows_remaining {
+            if df.height() > *remaining {
+                df = df.slice(0, *remaining);
+            }
+            *remaining -= df.height();
+        }
+        Some(Ok(df))
+    }
+}
+
 pub enum ScanAggregation {
     Sum {
         column: String,
@@ -0,0 +1,375 @@
+//! # Read from remote object stores.
+//!
+//! An [ObjectSource] gives access to a single object (e.g. a file in an S3 bucket) via range
+//! requests. Wrapped in an [ObjectReader] it can be passed to any reader that requires
+//! `Read + Seek`, such as the `CsvReader`. The `ParquetReader` only requests the byte ranges of
+//! the footer and the row groups it reads.
+//!
+//! Sources are opened by URI. The `file://` scheme is built in; clients for remote schemes
+//! (`s3://`, `gs://`, `az://`, `http(s)://`) are not shipped with polars and have to be
+//! plugged in with [register_object_source]. Opening a URI with a scheme that has no
+//! registered source returns an error that names the scheme.
+//! The lazy scans open their paths with a [ScanReader], so with this feature enabled they accept
+//! URIs as well as local paths.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use polars_io::object_store::open_uri;
+//!
+//! fn example() -> Result<DataFrame> {
+//!     let reader = open_uri("file:///tmp/data.parquet")?;
+//!     ParquetReader::new(reader).finish()
+//! }
+//! ```
+use lazy_static::lazy_static;
+use polars_core::prelude::*;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+
+/// A single object that is read with range requests.
+pub trait ObjectSource: Send + Sync {
+    /// Size of the object in bytes.
+    fn size(&self) -> Result<u64>;
+
+    /// Read `length` bytes starting at `offset`.
+    fn read_range(&self, offset: u64, length: usize) -> Result<Vec<u8>>;
+}
+
+/// Opens an [ObjectSource] from a URI.
+pub type ObjectSourceFactory = fn(uri: &str) -> Result<Arc<dyn ObjectSource>>;
+
+lazy_static! {
+    static ref OBJECT_SOURCES: RwLock<HashMap<String, ObjectSourceFactory>> = {
+        let mut sources = HashMap::new();
+        sources.insert("file".to_string(), open_local_file as ObjectSourceFactory);
+        RwLock::new(sources)
+    };
+}
+
+/// Register the factory that opens the URIs with the given scheme, e.g. `"s3"`.
+pub fn register_object_source(scheme: &str, factory: ObjectSourceFactory) {
+    OBJECT_SOURCES
+        .write()
+        .unwrap()
+        .insert(scheme.to_string(), factory);
+}
+
+/// Remote schemes that are expected to be served by a registered [ObjectSource].
+const REMOTE_SCHEMES: [&str; 8] = ["s3", "s3a", "gs", "gcs", "az", "abfs", "http", "https"];
+
+/// Open the object at `uri` with the source registered for its scheme.
+pub fn open_uri(uri: &str) -> Result<ObjectReader> {
+    let scheme = uri
+        .find("://")
+        .map(|idx| &uri[..idx])
+        .ok_or_else(|| PolarsError::ValueError(format!("{} is not a URI", uri).into()))?;
+    let factory = OBJECT_SOURCES
+        .read()
+        .unwrap()
+        .get(scheme)
+        .copied()
+        .ok_or_else(|| {
+            if REMOTE_SCHEMES.contains(&scheme) {
+                PolarsError::NotFound(format!(
+                    "cannot open {}: no object source registered for scheme {}://, \
+                    register a client for it with `register_object_source`",
+                    uri, scheme
+                ))
+            } else {
+                PolarsError::ValueError(
+                    format!("cannot open {}: unsupported URI scheme {}://", uri, scheme).into(),
+                )
+            }
+        })?;
+    ObjectReader::new(factory(uri)?)
+}
+
+/// Object source of a file on the local file system.
+pub struct LocalFileSource {
+    file: Mutex<File>,
+}
+
+impl LocalFileSource {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Result<Self> {
+        let file = File::open(path.into())?;
+        Ok(LocalFileSource {
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl ObjectSource for LocalFileSource {
+    fn size(&self) -> Result<u64> {
+        Ok(self.file.lock().unwrap().metadata()?.len())
+    }
+
+    fn read_range(&self, offset: u64, length: usize) -> Result<Vec<u8>> {
+        let mut file = self.file.lock().unwrap();
+        file.seek(SeekFrom::Start(offset))?;
+        let mut buf = Vec::with_capacity(length);
+        file.by_ref().take(length as u64).read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+fn open_local_file(uri: &str) -> Result<Arc<dyn ObjectSource>> {
+    let path = uri.trim_start_matches("file://");
+    Ok(Arc::new(LocalFileSource::new(path)?))
+}
+
+/// `Read + Seek` adapter over an [ObjectSource] that reads in blocks.
+pub struct ObjectReader {
+    source: Arc<dyn ObjectSource>,
+    size: u64,
+    position: u64,
+    block: Vec<u8>,
+    block_offset: u64,
+    block_size: usize,
+}
+
+impl ObjectReader {
+    pub fn new(source: Arc<dyn ObjectSource>) -> Result<Self> {
+        let size = source.size()?;
+        Ok(ObjectReader {
+            source,
+            size,
+            position: 0,
+            block: vec![],
+            block_offset: 0,
+            block_size: 8 * 1024 * 1024,
+        })
+    }
+
+    /// Number of bytes that are requested at once by sequential reads.
+    pub fn with_block_size(mut self, block_size: usize) -> Self {
+        self.block_size = std::cmp::max(block_size, 1);
+        self
+    }
+}
+
+fn to_io_err(e: PolarsError) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::Other, format!("{:?}", e))
+}
+
+impl Read for ObjectReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.position >= self.size {
+            return Ok(0);
+        }
+        let block_end = self.block_offset + self.block.len() as u64;
+        if self.position < self.block_offset || self.position >= block_end {
+            let length = std::cmp::min(self.block_size as u64, self.size - self.position);
+            self.block = self
+                .source
+                .read_range(self.position, length as usize)
+                .map_err(to_io_err)?;
+            self.block_offset = self.position;
+            if self.block.is_empty() {
+                return Ok(0);
+            }
+        }
+        let start = (self.position - self.block_offset) as usize;
+        let n = std::cmp::min(buf.len(), self.block.len() - start);
+        buf[..n].copy_from_slice(&self.block[start..start + n]);
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl Seek for ObjectReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::End(offset) => self.size as i64 + offset,
+            SeekFrom::Current(offset) => self.position as i64 + offset,
+        };
+        if position < 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "seek to a negative position",
+            ));
+        }
+        self.position = position as u64;
+        Ok(self.position)
+    }
+}
+
+/// Whether `path` is a URI, such as `s3://bucket/data.parquet`, rather than a local path.
+pub fn is_uri<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref().to_string_lossy().contains("://")
+}
+
+/// Reader of either a local file or an object opened by URI.
+pub enum ScanReader {
+    File(File),
+    Object(ObjectReader),
+}
+
+impl ScanReader {
+    /// Open `path` with the object source registered for its scheme if it is a URI, and from
+    /// the local file system otherwise.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if is_uri(path) {
+            Ok(ScanReader::Object(open_uri(&path.to_string_lossy())?))
+        } else {
+            Ok(ScanReader::File(File::open(path)?))
+        }
+    }
+}
+
+impl Read for ScanReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            ScanReader::File(file) => file.read(buf),
+            ScanReader::Object(reader) => reader.read(buf),
+        }
+    }
+}
+
+impl Seek for ScanReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        match self {
+            ScanReader::File(file) => file.seek(pos),
+            ScanReader::Object(reader) => reader.seek(pos),
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+mod parquet_impl {
+    use super::*;
+    use parquet_lib::errors::{ParquetError, Result as ParquetResult};
+    use parquet_lib::file::reader::{ChunkReader, Length};
+    use std::io::Cursor;
+
+    impl Length for ObjectReader {
+        fn len(&self) -> u64 {
+            self.size
+        }
+    }
+
+    impl ChunkReader for ObjectReader {
+        type T = Cursor<Vec<u8>>;
+
+        /// Every chunk (the footer, a column chunk) is fetched with a single range request.
+        fn get_read(&self, start: u64, length: usize) -> ParquetResult<Self::T> {
+            self.source
+                .read_range(start, length)
+                .map(Cursor::new)
+                .map_err(|e| ParquetError::General(format!("{:?}", e)))
+        }
+    }
+
+    impl Length for ScanReader {
+        fn len(&self) -> u64 {
+            match self {
+                ScanReader::File(file) => Length::len(file),
+                ScanReader::Object(reader) => reader.size,
+            }
+        }
+    }
+
+    impl ChunkReader for ScanReader {
+        type T = Box<dyn Read + Send>;
+
+        fn get_read(&self, start: u64, length: usize) -> ParquetResult<Self::T> {
+            match self {
+                ScanReader::File(file) => Ok(Box::new(file.get_read(start, length)?)),
+                ScanReader::Object(reader) => Ok(Box::new(reader.get_read(start, length)?)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use std::io::Write;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    #[cfg(feature = "csv-file")]
+    fn test_read_csv_from_object_source() -> Result<()> {
+        let path = std::env::temp_dir().join("polars_object_source.csv");
+        std::fs::File::create(&path)?.write_all(b"a,b\n1,2\n3,4\n")?;
+
+        let reader = open_uri(&format!("file://{}", path.to_string_lossy()))?.with_block_size(3);
+        let df = CsvReader::new(reader).finish()?;
+        assert_eq!(df.shape(), (2, 2));
+
+        assert!(matches!(
+            open_uri("unknown://bucket/file.csv"),
+            Err(PolarsError::ValueError(_))
+        ));
+        match open_uri("s3://bucket/file.csv") {
+            Err(PolarsError::NotFound(msg)) => assert!(msg.contains("s3://")),
+            _ => panic!("expected an error for an unregistered scheme"),
+        }
+        Ok(())
+    }
+
+    /// Local file source that counts the bytes that are requested.
+    struct CountingSource {
+        inner: LocalFileSource,
+        requested: Arc<AtomicUsize>,
+    }
+
+    impl ObjectSource for CountingSource {
+        fn size(&self) -> Result<u64> {
+            self.inner.size()
+        }
+
+        fn read_range(&self, offset: u64, length: usize) -> Result<Vec<u8>> {
+            self.requested.fetch_add(length, Ordering::Relaxed);
+            self.inner.read_range(offset, length)
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_read_parquet_chunks_from_object_source() -> Result<()> {
+        let n = 10_000;
+        let df = DataFrame::new(vec![
+            Series::new("a", (0..n).collect::<Vec<i64>>()),
+            Series::new(
+                "b",
+                (0..n).map(|v| format!("value-{}", v)).collect::<Vec<_>>(),
+            ),
+        ])?;
+        let path = std::env::temp_dir().join("polars_object_source.parquet");
+        ParquetWriter::new(std::fs::File::create(&path)?).finish(&df)?;
+        let size = std::fs::metadata(&path)?.len() as usize;
+
+        let requested = Arc::new(AtomicUsize::new(0));
+        let open = || -> Result<ObjectReader> {
+            ObjectReader::new(Arc::new(CountingSource {
+                inner: LocalFileSource::new(&path)?,
+                requested: requested.clone(),
+            }))
+        };
+        let out = ParquetReader::new(open()?).finish()?;
+        assert!(out.frame_equal(&df));
+
+        // only the footer and the column chunks of `a` are requested
+        requested.store(0, Ordering::Relaxed);
+        let out = ParquetReader::new(open()?)
+            .batched(Some(vec![0]), n as usize)?
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(out[0].get_column_names(), &["a"]);
+        assert!(out[0].column("a")?.series_equal(df.column("a")?));
+        assert!(requested.load(Ordering::Relaxed) < size);
+
+        let reader = ScanReader::open(format!("file://{}", path.to_string_lossy()))?;
+        assert!(matches!(reader, ScanReader::Object(_)));
+        assert!(ParquetReader::new(reader).finish()?.frame_equal(&df));
+        Ok(())
+    }
+}
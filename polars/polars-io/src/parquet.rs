@@ -14,11 +14,18 @@
 //! }
 //! ```
 //!
-use super::{finish_reader, ArrowReader, ArrowResult, RecordBatch};
+//! Decimals are written as their 64 bit unscaled values. Their precision and scale are kept in
+//! the file metadata, so that they are read as decimals again.
+//!
+use super::{finish_reader, ArrowReader, ArrowResult, BatchedReader, RecordBatch};
 use crate::prelude::*;
 use crate::utils::to_arrow_compatible_df;
+#[cfg(feature = "lazy")]
+use crate::{BatchStats, ColumnStats};
 use crate::{PhysicalIoExpr, ScanAggregation};
+use arrow::array::{Array, DecimalBuilder, Int64Array};
 use arrow::{compute::cast, record_batch::RecordBatchReader};
+use parquet_lib::file::metadata::{KeyValue, ParquetMetaData};
 use parquet_lib::file::reader::{FileReader, SerializedFileReader};
 pub use parquet_lib::file::serialized_reader::SliceableCursor;
 use parquet_lib::{
@@ -26,7 +33,13 @@ use parquet_lib::{
         arrow_reader::ParquetRecordBatchReader, arrow_writer::ArrowWriter as ParquetArrowWriter,
         ArrowReader as ParquetArrowReader, ParquetFileArrowReader,
     },
-    file::writer::TryClone,
+    basic::Compression,
+    file::{properties::WriterProperties, writer::TryClone},
+};
+#[cfg(feature = "lazy")]
+use parquet_lib::{
+    basic::ConvertedType,
+    file::{metadata::RowGroupMetaData, statistics::Statistics},
 };
 use polars_core::prelude::*;
 use std::io::{Read, Seek, Write};
@@ -42,6 +55,210 @@ fn set_batch_size(max_rows: usize, stop_after_n_rows: Option<usize>) -> usize {
     batch_size
 }
 
+/// The prefix of the file metadata keys under which the precision and scale of a decimal column
+/// are kept, e.g. `polars.decimal.price` = `"10,2"`.
+const DECIMAL_METADATA_PREFIX: &str = "polars.decimal.";
+
+/// The name, precision and scale of the columns that are written as unscaled decimal values.
+type DecimalColumns = Vec<(String, usize, usize)>;
+
+fn decimal_columns(metadata: &ParquetMetaData) -> DecimalColumns {
+    metadata
+        .file_metadata()
+        .key_value_metadata()
+        .iter()
+        .flatten()
+        .filter_map(|kv| {
+            let name = kv.key.strip_prefix(DECIMAL_METADATA_PREFIX)?;
+            let mut parts = kv.value.as_ref()?.split(',');
+            let precision = parts.next()?.parse().ok()?;
+            let scale = parts.next()?.parse().ok()?;
+            Some((name.to_string(), precision, scale))
+        })
+        .collect()
+}
+
+fn restore_decimal_schema(
+    schema: &ArrowSchema,
+    decimals: &[(String, usize, usize)],
+) -> ArrowSchema {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(
+            |field| match decimals.iter().find(|(name, _, _)| name == field.name()) {
+                Some((name, precision, scale)) => {
+                    ArrowField::new(name, ArrowDataType::Decimal(*precision, *scale), true)
+                }
+                None => field.clone(),
+            },
+        )
+        .collect();
+    ArrowSchema::new(fields)
+}
+
+/// Convert the unscaled values of the decimal columns in `batch` back to decimals.
+fn restore_decimals(
+    batch: RecordBatch,
+    decimals: &[(String, usize, usize)],
+) -> ArrowResult<RecordBatch> {
+    let schema = batch.schema();
+    let mut columns = batch.columns().to_vec();
+    for (name, precision, scale) in decimals {
+        let idx = match schema.index_of(name) {
+            Ok(idx) => idx,
+            // not projected
+            Err(_) => continue,
+        };
+        let values = match columns[idx].as_any().downcast_ref::<Int64Array>() {
+            Some(values) => values,
+            None => continue,
+        };
+        let mut builder = DecimalBuilder::new(values.len(), *precision, *scale);
+        for i in 0..values.len() {
+            if values.is_null(i) {
+                builder.append_null()?
+            } else {
+                builder.append_value(values.value(i) as i128)?
+            }
+        }
+        columns[idx] = Arc::new(builder.finish());
+    }
+    RecordBatch::try_new(Arc::new(restore_decimal_schema(&schema, decimals)), columns)
+}
+
+/// Reads the record batches of a parquet file and restores its decimal columns.
+struct ParquetBatchReader {
+    reader: ParquetRecordBatchReader,
+    decimals: DecimalColumns,
+}
+
+impl ArrowReader for ParquetBatchReader {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<RecordBatch>> {
+        match self.reader.next_record_batch()? {
+            Some(batch) if !self.decimals.is_empty() => {
+                restore_decimals(batch, &self.decimals).map(Some)
+            }
+            batch => Ok(batch),
+        }
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        let schema = <ParquetRecordBatchReader as RecordBatchReader>::schema(&self.reader);
+        Arc::new((&restore_decimal_schema(&schema, &self.decimals)).into())
+    }
+}
+
+/// Convert the min/ max statistics of a column chunk to `Series` of length 1.
+/// Only numeric and boolean statistics are used. Unsigned integers are stored as signed
+/// physical values and are converted back to the unsigned column type.
+#[cfg(feature = "lazy")]
+fn min_max_to_series(
+    name: &str,
+    stats: &Statistics,
+    converted_type: ConvertedType,
+) -> Option<(Series, Series)> {
+    if !stats.has_min_max_set() {
+        return None;
+    }
+    let min_max = match (stats, converted_type) {
+        (Statistics::Int32(s), converted_type)
+            if matches!(
+                converted_type,
+                ConvertedType::UINT_8 | ConvertedType::UINT_16 | ConvertedType::UINT_32
+            ) =>
+        {
+            let (min, max) = (*s.min() as u32, *s.max() as u32);
+            // written with a signed ordering, the statistics are meaningless for unsigned values
+            if min > max {
+                return None;
+            }
+            (Series::new(name, &[min]), Series::new(name, &[max]))
+        }
+        (Statistics::Int64(s), ConvertedType::UINT_64) => {
+            let (min, max) = (*s.min() as u64, *s.max() as u64);
+            if min > max {
+                return None;
+            }
+            (Series::new(name, &[min]), Series::new(name, &[max]))
+        }
+        (Statistics::Boolean(s), _) => (
+            Series::new(name, &[*s.min()]),
+            Series::new(name, &[*s.max()]),
+        ),
+        (Statistics::Int32(s), _) => (
+            Series::new(name, &[*s.min()]),
+            Series::new(name, &[*s.max()]),
+        ),
+        (Statistics::Int64(s), _) => (
+            Series::new(name, &[*s.min()]),
+            Series::new(name, &[*s.max()]),
+        ),
+        (Statistics::Float(s), _) => (
+            Series::new(name, &[*s.min()]),
+            Series::new(name, &[*s.max()]),
+        ),
+        (Statistics::Double(s), _) => (
+            Series::new(name, &[*s.min()]),
+            Series::new(name, &[*s.max()]),
+        ),
+        _ => return None,
+    };
+    Some(min_max)
+}
+
+#[cfg(all(feature = "lazy", feature = "dtype-decimal"))]
+fn decimal_stats(unscaled: &Series, precision: usize, scale: usize) -> Option<Series> {
+    let ca = unscaled.i64().ok()?;
+    DecimalChunked::new_from_physical(ca, precision, scale)
+        .ok()
+        .map(|ca| ca.into_series())
+}
+
+#[cfg(all(feature = "lazy", not(feature = "dtype-decimal")))]
+fn decimal_stats(_unscaled: &Series, _precision: usize, _scale: usize) -> Option<Series> {
+    None
+}
+
+/// Collect the statistics of a row group. The statistics of decimal columns are unscaled values.
+#[cfg(feature = "lazy")]
+fn row_group_stats(rg: &RowGroupMetaData, decimals: &[(String, usize, usize)]) -> BatchStats {
+    let column_stats = rg
+        .columns()
+        .iter()
+        .filter_map(|col| {
+            let stats = col.statistics()?;
+            let descr = col.column_descr();
+            let name = descr.name();
+            let mut min_max = min_max_to_series(name, stats, descr.converted_type());
+            if let Some((_, precision, scale)) = decimals.iter().find(|(n, _, _)| n == name) {
+                min_max = min_max.and_then(|(min, max)| {
+                    decimal_stats(&min, *precision, *scale)
+                        .zip(decimal_stats(&max, *precision, *scale))
+                });
+            }
+            let (min_value, max_value) = match min_max {
+                Some((min, max)) => (Some(min), Some(max)),
+                None => (None, None),
+            };
+            let dtype = min_value
+                .as_ref()
+                .map(|s| s.dtype().clone())
+                .unwrap_or(DataType::Null);
+            Some(ColumnStats {
+                field: Field::new(name, dtype),
+                null_count: Some(stats.null_count() as usize),
+                min_value,
+                max_value,
+            })
+        })
+        .collect();
+    BatchStats {
+        num_rows: rg.num_rows() as usize,
+        column_stats,
+    }
+}
+
 /// Read Apache parquet format into a DataFrame.
 pub struct ParquetReader<R> {
     reader: R,
@@ -63,8 +280,49 @@ where
     ) -> Result<DataFrame> {
         let rechunk = self.rechunk;
 
-        let file_reader = Arc::new(SerializedFileReader::new(self.reader)?);
-        let rows_in_file = file_reader.metadata().file_metadata().num_rows() as usize;
+        let mut file_reader = SerializedFileReader::new(self.reader)?;
+        let decimals = decimal_columns(file_reader.metadata());
+        // skip the row groups of which the statistics show that no row passes the predicate
+        if let Some(evaluator) = predicate.as_ref().and_then(|p| p.as_stats_evaluator()) {
+            file_reader.filter_row_groups(&|rg, _| {
+                evaluator
+                    .should_read(&row_group_stats(rg, &decimals))
+                    .unwrap_or(true)
+            });
+        }
+        let file_reader = Arc::new(file_reader);
+        let rows_in_file = file_reader
+            .metadata()
+            .row_groups()
+            .iter()
+            .map(|rg| rg.num_rows() as usize)
+            .sum::<usize>();
+
+        let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
+        if rows_in_file == 0 {
+            use std::convert::TryFrom;
+            let schema = restore_decimal_schema(&arrow_reader.get_schema()?, &decimals);
+            let df = DataFrame::try_from(RecordBatch::new_empty(Arc::new(schema)))?;
+            let df = match projection {
+                Some(projection) => DataFrame::new_no_checks(
+                    projection
+                        .iter()
+                        .filter_map(|i| df.select_at_idx(*i).cloned())
+                        .collect(),
+                ),
+                None => df,
+            };
+            return match aggregate {
+                Some(aggregate) => {
+                    let cols = aggregate
+                        .iter()
+                        .map(|scan_agg| scan_agg.finish(&df))
+                        .collect::<Result<_>>()?;
+                    Ok(DataFrame::new_no_checks(cols))
+                }
+                None => Ok(df),
+            };
+        }
 
         if let Some(stop_after_n_rows) = self.stop_after_n_rows {
             if stop_after_n_rows > rows_in_file {
@@ -78,7 +336,6 @@ where
         };
         let batch_size = set_batch_size(batch_size, self.stop_after_n_rows);
 
-        let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
         let record_reader = match projection {
             Some(projection) => {
                 arrow_reader.get_record_reader_by_columns(projection.iter().copied(), batch_size)
@@ -86,7 +343,10 @@ where
             None => arrow_reader.get_record_reader(batch_size),
         }?;
         finish_reader(
-            record_reader,
+            ParquetBatchReader {
+                reader: record_reader,
+                decimals,
+            },
             rechunk,
             self.stop_after_n_rows,
             predicate,
@@ -101,11 +361,45 @@ where
         self
     }
 
+    /// Read the file in batches of at most `batch_size` rows, which are converted to DataFrames
+    /// one at a time. Only the columns at the indices in `projection` are read.
+    pub fn batched(
+        self,
+        projection: Option<Vec<usize>>,
+        batch_size: usize,
+    ) -> Result<BatchedReader> {
+        let file_reader = Arc::new(SerializedFileReader::new(self.reader)?);
+        let decimals = decimal_columns(file_reader.metadata());
+        let mut batch_size = std::cmp::max(batch_size, 1);
+        if let Some(n) = self.stop_after_n_rows {
+            batch_size = std::cmp::max(std::cmp::min(batch_size, n), 1);
+        }
+        let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
+        let record_reader = match projection {
+            Some(projection) => arrow_reader.get_record_reader_by_columns(projection, batch_size),
+            None => arrow_reader.get_record_reader(batch_size),
+        }?;
+        Ok(BatchedReader::new(
+            Box::new(ParquetBatchReader {
+                reader: record_reader,
+                decimals,
+            }),
+            self.stop_after_n_rows,
+        ))
+    }
+
+    /// Get the number of rows of the file from its metadata.
+    pub fn num_rows(self) -> Result<usize> {
+        let file_reader = SerializedFileReader::new(self.reader)?;
+        Ok(file_reader.metadata().file_metadata().num_rows() as usize)
+    }
+
     pub fn schema(self) -> Result<Schema> {
         let file_reader = Arc::new(SerializedFileReader::new(self.reader)?);
+        let decimals = decimal_columns(file_reader.metadata());
         let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
-        let schema = arrow_reader.get_schema()?;
-        Ok(schema.into())
+        let schema = restore_decimal_schema(&arrow_reader.get_schema()?, &decimals);
+        Ok((&schema).into())
     }
 }
 
@@ -141,10 +435,14 @@ where
     fn finish(self) -> Result<DataFrame> {
         let rechunk = self.rechunk;
         let file_reader = Arc::new(SerializedFileReader::new(self.reader)?);
+        let decimals = decimal_columns(file_reader.metadata());
         let n_rows = file_reader.metadata().file_metadata().num_rows() as usize;
         let batch_size = set_batch_size(n_rows, self.stop_after_n_rows);
         let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
-        let record_reader = arrow_reader.get_record_reader(batch_size)?;
+        let record_reader = ParquetBatchReader {
+            reader: arrow_reader.get_record_reader(batch_size)?,
+            decimals,
+        };
         finish_reader(record_reader, rechunk, self.stop_after_n_rows, None, None)
     }
 }
@@ -153,9 +451,46 @@ where
 ///
 /// # Example
 ///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::fs::File;
 ///
+/// fn example(df: &DataFrame) -> Result<()> {
+///     let file = File::create("file.parquet").expect("could not create file");
+///     ParquetWriter::new(file).finish(df)
+/// }
+/// ```
 pub struct ParquetWriter<W> {
     writer: W,
+    compression: ParquetCompression,
+    row_group_size: Option<usize>,
+}
+
+/// Compression codec used to write the column chunks of a parquet file.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ParquetCompression {
+    Uncompressed,
+    Snappy,
+    Gzip,
+    Zstd,
+}
+
+impl Default for ParquetCompression {
+    fn default() -> Self {
+        ParquetCompression::Uncompressed
+    }
+}
+
+impl From<ParquetCompression> for Compression {
+    fn from(compression: ParquetCompression) -> Self {
+        match compression {
+            ParquetCompression::Uncompressed => Compression::UNCOMPRESSED,
+            ParquetCompression::Snappy => Compression::SNAPPY,
+            ParquetCompression::Gzip => Compression::GZIP,
+            ParquetCompression::Zstd => Compression::ZSTD,
+        }
+    }
 }
 
 impl<W> ParquetWriter<W>
@@ -167,63 +502,152 @@ where
     where
         W: 'static + Write + Seek + TryClone,
     {
-        ParquetWriter { writer }
+        ParquetWriter {
+            writer,
+            compression: ParquetCompression::default(),
+            row_group_size: None,
+        }
     }
 
-    /// Write the given DataFrame in the the writer `W`.
-    pub fn finish(self, df: &DataFrame) -> Result<()> {
-        let df = to_arrow_compatible_df(df);
-        let mut fields = df.schema().to_arrow().fields().clone();
+    /// Set the compression used. Defaults to `Uncompressed`.
+    pub fn with_compression(mut self, compression: ParquetCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the maximum number of rows in a row group. If `None`, every chunk of the
+    /// DataFrame is written as a single row group.
+    pub fn with_row_group_size(mut self, size: Option<usize>) -> Self {
+        self.row_group_size = size;
+        self
+    }
+
+    /// Create a writer that writes DataFrames of the given `schema` in batches. Every batch is
+    /// flushed to the writer `W` when it is written, so the DataFrames don't have to be
+    /// materialized at once.
+    pub fn batched(self, schema: &Schema) -> Result<BatchedWriter<W>> {
+        let mut fields = schema.to_arrow().fields().clone();
 
         // date64 is not supported by parquet and will be be truncated to date32
         // We coerce these to timestamp(ms)
-        let date64_columns = df
-            .get_columns()
-            .iter()
-            .enumerate()
-            .filter_map(|(i, s)| match s.dtype() {
+        let mut date64_columns = vec![];
+        let mut metadata: Vec<KeyValue> = vec![];
+        for (i, field) in schema.fields().iter().enumerate() {
+            match field.data_type() {
                 DataType::Date64 => {
                     fields[i] = ArrowField::new(
-                        s.name(),
+                        field.name(),
                         ArrowDataType::Timestamp(TimeUnit::Millisecond, None),
-                        s.null_count() > 0,
+                        true,
                     );
-                    Some(i)
+                    date64_columns.push(i)
                 }
-                _ => None,
-            })
-            .collect::<Vec<_>>();
-        let column_names = df
-            .get_columns()
-            .iter()
-            .map(|s| s.name().to_string())
-            .collect::<Vec<_>>();
-
-        let iter = df.iter_record_batches().map(|rb| {
-            if !date64_columns.is_empty() {
-                let mut columns = rb.columns().to_vec();
-                for i in &date64_columns {
-                    let array = cast(&columns[*i], &ArrowDataType::Int64).unwrap();
-                    let array = cast(
-                        &array,
-                        &ArrowDataType::Timestamp(TimeUnit::Millisecond, None),
-                    )
-                    .unwrap();
-                    columns[*i] = array;
+                // Our categorical type is not known to arrow/ parquet, so we coerce to large-utf8.
+                DataType::Categorical => {
+                    fields[i] = ArrowField::new(field.name(), ArrowDataType::LargeUtf8, true);
                 }
-                RecordBatch::try_from_iter(column_names.iter().zip(columns)).unwrap()
-            } else {
-                rb
+                // decimals are written as their unscaled values
+                #[cfg(feature = "dtype-decimal")]
+                DataType::Decimal(precision, scale) => {
+                    fields[i] = ArrowField::new(field.name(), ArrowDataType::Int64, true);
+                    metadata.push(KeyValue {
+                        key: format!("{}{}", DECIMAL_METADATA_PREFIX, field.name()),
+                        value: Some(format!("{},{}", precision, scale)),
+                    });
+                }
+                _ => {}
+            }
+        }
+        let schema = Arc::new(ArrowSchema::new(fields));
+        let mut props = WriterProperties::builder().set_compression(self.compression.into());
+        if !metadata.is_empty() {
+            props = props.set_key_value_metadata(Some(metadata));
+        }
+        let props = props.build();
+        let writer = ParquetArrowWriter::try_new(self.writer, schema.clone(), Some(props))?;
+
+        Ok(BatchedWriter {
+            writer,
+            schema,
+            date64_columns,
+            row_group_size: self.row_group_size,
+        })
+    }
+
+    /// Write the given DataFrame in the the writer `W`.
+    pub fn finish(self, df: &DataFrame) -> Result<()> {
+        let mut batched = self.batched(&df.schema())?;
+        batched.write_batch(df)?;
+        batched.finish()
+    }
+}
+
+/// Writes DataFrames to parquet in batches. Created by [ParquetWriter::batched].
+pub struct BatchedWriter<W>
+where
+    W: 'static + Write + Seek + TryClone,
+{
+    writer: ParquetArrowWriter<W>,
+    schema: Arc<ArrowSchema>,
+    date64_columns: Vec<usize>,
+    row_group_size: Option<usize>,
+}
+
+impl<W> BatchedWriter<W>
+where
+    W: 'static + Write + Seek + TryClone,
+{
+    /// Write a batch to the parquet writer.
+    ///
+    /// The DataFrame should have the schema that was used to create this writer.
+    pub fn write_batch(&mut self, df: &DataFrame) -> Result<()> {
+        if let Some(size) = self.row_group_size {
+            let size = std::cmp::max(size, 1);
+            if df.height() > size {
+                let mut offset = 0;
+                while offset < df.height() {
+                    self.write_row_group(&df.slice(offset as i64, size))?;
+                    offset += size;
+                }
+                return Ok(());
             }
-        });
+        }
+        self.write_row_group(df)
+    }
 
-        let mut parquet_writer =
-            ParquetArrowWriter::try_new(self.writer, Arc::new(ArrowSchema::new(fields)), None)?;
+    fn write_row_group(&mut self, df: &DataFrame) -> Result<()> {
+        let df = to_arrow_compatible_df(df);
+        #[cfg(feature = "dtype-decimal")]
+        let df = DataFrame::new_no_checks(
+            df.get_columns()
+                .iter()
+                .map(|s| match s.decimal() {
+                    Ok(ca) => ca.physical().into_series(),
+                    Err(_) => s.clone(),
+                })
+                .collect(),
+        );
 
-        for batch in iter {
-            parquet_writer.write(&batch)?
+        // every record batch is written as a row group
+        for rb in df.iter_record_batches() {
+            let mut columns = rb.columns().to_vec();
+            for i in &self.date64_columns {
+                let array = cast(&columns[*i], &ArrowDataType::Int64)?;
+                let array = cast(
+                    &array,
+                    &ArrowDataType::Timestamp(TimeUnit::Millisecond, None),
+                )?;
+                columns[*i] = array;
+            }
+            let batch = RecordBatch::try_new(self.schema.clone(), columns)?;
+            self.writer.write(&batch)?
         }
-        let _ = parquet_writer.close()?;
+        Ok(())
+    }
+
+    /// Write the parquet footer and close the file.
+    pub fn finish(mut self) -> Result<()> {
+        let _ = self.writer.close()?;
         Ok(())
     }
 }
@@ -231,6 +655,7 @@ where
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
+    use parquet_lib::file::reader::{FileReader, SerializedFileReader};
     use parquet_lib::file::writer::InMemoryWriteableCursor;
     use polars_core::{df, prelude::*};
     use std::fs::File;
@@ -246,6 +671,34 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_parquet_write_options() -> Result<()> {
+        let df = df![
+            "a" => (0..100).collect::<Vec<i32>>(),
+            "b" => (0..100).map(|v| format!("{}", v % 7)).collect::<Vec<_>>()
+        ]?;
+
+        for compression in &[
+            ParquetCompression::Uncompressed,
+            ParquetCompression::Snappy,
+            ParquetCompression::Zstd,
+        ] {
+            let f: InMemoryWriteableCursor = Default::default();
+            ParquetWriter::new(f.clone())
+                .with_compression(*compression)
+                .with_row_group_size(Some(30))
+                .finish(&df)?;
+            let data = f.data();
+
+            let file_reader = SerializedFileReader::new(SliceableCursor::new(data.clone()))?;
+            assert_eq!(file_reader.metadata().num_row_groups(), 4);
+
+            let read = ParquetReader::new(SliceableCursor::new(data)).finish()?;
+            assert!(read.frame_equal(&df));
+        }
+        Ok(())
+    }
+
     #[test]
     #[cfg(all(feature = "dtype-date64", feature = "parquet"))]
     fn test_parquet_date64_round_trip() -> Result<()> {
@@ -266,4 +719,51 @@ mod test {
         assert!(read.frame_equal_missing(&df));
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_parquet_decimal_round_trip() -> Result<()> {
+        let f: InMemoryWriteableCursor = Default::default();
+
+        let s = Series::new("dec", &[Some("1.25"), None, Some("-300.5")])
+            .cast_with_dtype(&DataType::Decimal(10, 2))?;
+        let df = DataFrame::new(vec![s, Series::new("a", &[1, 2, 3])])?;
+
+        ParquetWriter::new(f.clone()).finish(&df)?;
+        let data = f.data();
+
+        let read = ParquetReader::new(SliceableCursor::new(data.clone())).finish()?;
+        assert_eq!(read.column("dec")?.dtype(), &DataType::Decimal(10, 2));
+        assert!(read.frame_equal_missing(&df));
+
+        let schema = ParquetReader::new(SliceableCursor::new(data)).schema()?;
+        assert_eq!(
+            schema.field_with_name("dec")?.data_type(),
+            &DataType::Decimal(10, 2)
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-binary", feature = "parquet"))]
+    fn test_parquet_binary_round_trip() -> Result<()> {
+        let f: InMemoryWriteableCursor = Default::default();
+
+        let s = BinaryChunked::new_from_opt_slice(
+            "bytes",
+            &[Some(&b"\x00\xff"[..]), None, Some(&b"polars"[..])],
+        )
+        .into_series();
+        let df = DataFrame::new(vec![s])?;
+
+        ParquetWriter::new(f.clone()).finish(&df)?;
+        let data = f.data();
+
+        let f = SliceableCursor::new(data);
+
+        let read = ParquetReader::new(f).finish()?;
+        assert_eq!(read.column("bytes")?.dtype(), &DataType::Binary);
+        assert!(read.frame_equal_missing(&df));
+        Ok(())
+    }
 }
@@ -0,0 +1,224 @@
+//! # Write a DataFrame as a Hive partitioned dataset.
+//!
+//! Every combination of values in the partition columns is written to its own file in a
+//! `key=value` directory layout, e.g. `path/year=2021/month=1/part-0.parquet`.
+//! The partition columns themselves are not written to the files. Characters that are not
+//! allowed in a directory name, such as `/` and `=`, are percent-encoded like Hive does.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//!
+//! fn example(df: &DataFrame) -> Result<()> {
+//!     df.write_partitioned("dataset", &["year", "month"], PartitionedFormat::Parquet)
+//! }
+//! ```
+use crate::prelude::*;
+use polars_core::prelude::*;
+use std::fs::{create_dir_all, File};
+use std::path::{Path, PathBuf};
+
+/// Value used for the directory name of a null partition key.
+const NULL_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// File format of the partitions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PartitionedFormat {
+    #[cfg(feature = "csv-file")]
+    Csv,
+    #[cfg(feature = "parquet")]
+    Parquet,
+    #[cfg(feature = "ipc")]
+    Ipc,
+}
+
+impl PartitionedFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "csv-file")]
+            PartitionedFormat::Csv => "csv",
+            #[cfg(feature = "parquet")]
+            PartitionedFormat::Parquet => "parquet",
+            #[cfg(feature = "ipc")]
+            PartitionedFormat::Ipc => "ipc",
+        }
+    }
+
+    fn write(&self, df: &DataFrame, path: &Path) -> Result<()> {
+        let mut file = File::create(path)?;
+        match self {
+            #[cfg(feature = "csv-file")]
+            PartitionedFormat::Csv => CsvWriter::new(&mut file).finish(df),
+            #[cfg(feature = "parquet")]
+            PartitionedFormat::Parquet => ParquetWriter::new(file).finish(df),
+            #[cfg(feature = "ipc")]
+            PartitionedFormat::Ipc => IpcWriter::new(&mut file).finish(df),
+        }
+    }
+}
+
+/// Percent-encode the characters Hive escapes in partition names and values, so that a key
+/// can't introduce a path separator or an extra `=`. Names or values of only dots are encoded
+/// as well, so they can't be read as `.` or `..`.
+fn escape_partition(s: &str) -> String {
+    let all_dots = s.chars().all(|c| c == '.');
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\u{0}'..='\u{1f}'
+            | '\u{7f}'
+            | '"'
+            | '#'
+            | '%'
+            | '\''
+            | '*'
+            | '/'
+            | ':'
+            | '='
+            | '?'
+            | '\\'
+            | '{'
+            | '['
+            | ']'
+            | '^' => out.push_str(&format!("%{:02X}", c as u32)),
+            '.' if all_dots => out.push_str("%2E"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Directory name of a single partition key.
+fn partition_dir(name: &str, value: AnyValue) -> String {
+    let value = match value {
+        AnyValue::Null => return format!("{}={}", escape_partition(name), NULL_PARTITION),
+        AnyValue::Utf8(v) => escape_partition(v),
+        v => escape_partition(&format!("{}", v)),
+    };
+    format!("{}={}", escape_partition(name), value)
+}
+
+pub trait PartitionedWrite {
+    /// Write one file per combination of values in the `partition_by` columns to a
+    /// `key=value` directory layout under `path`.
+    fn write_partitioned<P, S>(
+        &self,
+        path: P,
+        partition_by: &[S],
+        format: PartitionedFormat,
+    ) -> Result<()>
+    where
+        P: AsRef<Path>,
+        S: AsRef<str>;
+}
+
+impl PartitionedWrite for DataFrame {
+    fn write_partitioned<P, S>(
+        &self,
+        path: P,
+        partition_by: &[S],
+        format: PartitionedFormat,
+    ) -> Result<()>
+    where
+        P: AsRef<Path>,
+        S: AsRef<str>,
+    {
+        let partition_by = partition_by.iter().map(|s| s.as_ref()).collect::<Vec<_>>();
+        if partition_by.is_empty() {
+            return Err(PolarsError::InvalidOperation(
+                "at least one partition column is required".into(),
+            ));
+        }
+        let keys = self.select_series(partition_by.clone())?;
+        let mut values = self.clone();
+        for name in &partition_by {
+            values = values.drop(name)?;
+        }
+
+        let gb = self.groupby_stable(partition_by)?;
+        for (first, idx) in gb.get_groups() {
+            let mut dir = PathBuf::from(path.as_ref());
+            for key in &keys {
+                dir.push(partition_dir(key.name(), key.get(*first as usize)));
+            }
+            create_dir_all(&dir)?;
+
+            let idx = UInt32Chunked::new_from_slice("", idx);
+            let partition = values.take(&idx);
+            format.write(
+                &partition,
+                &dir.join(format!("part-0.{}", format.extension())),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use polars_core::df;
+    use polars_core::prelude::*;
+    use std::fs::File;
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_write_partitioned() -> Result<()> {
+        let df = df![
+            "year" => [2020, 2020, 2021, 2021, 2021],
+            "month" => [Some("jan"), Some("feb"), Some("jan"), Some("jan"), None],
+            "value" => [1, 2, 3, 4, 5]
+        ]?;
+        let path = std::env::temp_dir().join("polars_write_partitioned");
+        let _ = std::fs::remove_dir_all(&path);
+        df.write_partitioned(&path, &["year", "month"], PartitionedFormat::Parquet)?;
+
+        let read = |year: &str, month: &str| -> Result<DataFrame> {
+            let file = path.join(year).join(month).join("part-0.parquet");
+            ParquetReader::new(File::open(file)?).finish()
+        };
+        let out = read("year=2021", "month=jan")?;
+        assert_eq!(out.get_column_names(), &["value"]);
+        assert_eq!(Vec::from(out.column("value")?.i32()?), &[Some(3), Some(4)]);
+        let out = read("year=2021", "month=__HIVE_DEFAULT_PARTITION__")?;
+        assert_eq!(Vec::from(out.column("value")?.i32()?), &[Some(5)]);
+        assert!(df
+            .write_partitioned(&path, &["foo"], PartitionedFormat::Parquet)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_partition_dir() {
+        assert_eq!(
+            partition_dir("a", AnyValue::Utf8("../../etc")),
+            "a=..%2F..%2Fetc"
+        );
+        assert_eq!(partition_dir("a", AnyValue::Utf8("a/b\\c")), "a=a%2Fb%5Cc");
+        assert_eq!(partition_dir("a=b", AnyValue::Utf8("100%")), "a%3Db=100%25");
+        assert_eq!(partition_dir("..", AnyValue::Utf8(".")), "%2E%2E=%2E");
+        assert_eq!(partition_dir("a", AnyValue::Int32(1)), "a=1");
+    }
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_write_partitioned_escaped() -> Result<()> {
+        let df = df![
+            "key" => ["../../etc", "a/b"],
+            "value" => [1, 2]
+        ]?;
+        let path = std::env::temp_dir().join("polars_write_partitioned_escaped");
+        let _ = std::fs::remove_dir_all(&path);
+        df.write_partitioned(&path, &["key"], PartitionedFormat::Parquet)?;
+
+        let mut dirs = std::fs::read_dir(&path)?
+            .map(|entry| Ok(entry?.file_name().into_string().unwrap()))
+            .collect::<Result<Vec<_>>>()?;
+        dirs.sort();
+        assert_eq!(dirs, &["key=..%2F..%2Fetc", "key=a%2Fb"]);
+        Ok(())
+    }
+}
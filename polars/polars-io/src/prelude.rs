@@ -1,5 +1,7 @@
 pub use crate::{SerReader, SerWriter};
 
+#[cfg(feature = "avro")]
+pub use crate::avro::*;
 #[cfg(feature = "csv-file")]
 pub use crate::csv::*;
 #[cfg(feature = "ipc")]
@@ -8,6 +10,12 @@ pub use crate::ipc::*;
 pub use crate::json::*;
 #[cfg(feature = "parquet")]
 pub use crate::parquet::*;
+#[cfg(any(feature = "csv-file", feature = "parquet", feature = "ipc"))]
+pub use crate::partition::*;
+#[cfg(feature = "xlsx")]
+pub use crate::xlsx::*;
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+pub use crate::BatchedReader;
 
 #[cfg(test)]
 use polars_core::prelude::*;
@@ -0,0 +1,739 @@
+//! # Read the result of a SQL query.
+//!
+//! A query is executed on a [SqlConnection] and the result set is materialized in batches of
+//! `batch_size` rows. Connections are implemented for SQLite (`sql-sqlite` feature),
+//! Postgres (`sql-postgres` feature) and MySQL (`sql-mysql` feature). Other databases can be
+//! supported by implementing [SqlConnection].
+//!
+//! SQL types are mapped to the following dtypes:
+//!
+//! | SQL type                          | DataType  |
+//! |-----------------------------------|-----------|
+//! | boolean                           | Boolean   |
+//! | smallint, integer, bigint         | Int64     |
+//! | real, float, double               | Float64   |
+//! | char, varchar, text               | Utf8      |
+//!
+//! Columns of other types should be cast to one of these in the query.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::sql::SqlReader;
+//!
+//! fn example() -> Result<DataFrame> {
+//!     let conn = rusqlite::Connection::open("data.db").unwrap();
+//!     SqlReader::new(conn, "SELECT * FROM sales")
+//!         .with_batch_size(10_000)
+//!         .finish()
+//! }
+//! ```
+use polars_core::prelude::*;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+/// A single value in a row of the result set.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+/// Receives the result set of a query.
+pub trait RowSink {
+    /// Called once, before the first row, with the columns of the result set.
+    fn set_schema(&mut self, fields: Vec<Field>) -> Result<()>;
+
+    /// Called for every row of the result set.
+    fn push_row(&mut self, row: Vec<SqlValue>) -> Result<()>;
+}
+
+/// A connection to a database that executes queries.
+pub trait SqlConnection {
+    /// Execute `query` and pass the result set to the `sink`.
+    fn query(&mut self, query: &str, sink: &mut dyn RowSink) -> Result<()>;
+}
+
+enum ColumnBuilder {
+    Boolean(BooleanChunkedBuilder),
+    Int64(PrimitiveChunkedBuilder<Int64Type>),
+    Float64(PrimitiveChunkedBuilder<Float64Type>),
+    Utf8(Utf8ChunkedBuilder),
+}
+
+impl ColumnBuilder {
+    fn new(field: &Field, capacity: usize) -> Result<Self> {
+        let name = field.name();
+        let builder = match field.data_type() {
+            DataType::Boolean => ColumnBuilder::Boolean(BooleanChunkedBuilder::new(name, capacity)),
+            DataType::Int64 => ColumnBuilder::Int64(PrimitiveChunkedBuilder::new(name, capacity)),
+            DataType::Float64 => {
+                ColumnBuilder::Float64(PrimitiveChunkedBuilder::new(name, capacity))
+            }
+            DataType::Utf8 => {
+                ColumnBuilder::Utf8(Utf8ChunkedBuilder::new(name, capacity, capacity * 16))
+            }
+            dt => {
+                return Err(PolarsError::DataTypeMisMatch(
+                    format!("cannot read column {} of type {:?}", name, dt).into(),
+                ))
+            }
+        };
+        Ok(builder)
+    }
+
+    fn append(&mut self, value: SqlValue) -> Result<()> {
+        use ColumnBuilder::*;
+        match (self, value) {
+            (Boolean(b), SqlValue::Null) => b.append_null(),
+            (Int64(b), SqlValue::Null) => b.append_null(),
+            (Float64(b), SqlValue::Null) => b.append_null(),
+            (Utf8(b), SqlValue::Null) => b.append_null(),
+            (Boolean(b), SqlValue::Bool(v)) => b.append_value(v),
+            (Boolean(b), SqlValue::Int(v)) => b.append_value(v != 0),
+            (Int64(b), SqlValue::Int(v)) => b.append_value(v),
+            (Int64(b), SqlValue::Bool(v)) => b.append_value(v as i64),
+            (Float64(b), SqlValue::Float(v)) => b.append_value(v),
+            (Float64(b), SqlValue::Int(v)) => b.append_value(v as f64),
+            (Utf8(b), SqlValue::Text(v)) => b.append_value(&v),
+            (Utf8(b), SqlValue::Int(v)) => b.append_value(&v.to_string()),
+            (Utf8(b), SqlValue::Float(v)) => b.append_value(&v.to_string()),
+            (Utf8(b), SqlValue::Bool(v)) => b.append_value(&v.to_string()),
+            (_, v) => {
+                return Err(PolarsError::DataTypeMisMatch(
+                    format!("unexpected value {:?} in the result set", v).into(),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Series {
+        match self {
+            ColumnBuilder::Boolean(b) => b.finish().into_series(),
+            ColumnBuilder::Int64(b) => b.finish().into_series(),
+            ColumnBuilder::Float64(b) => b.finish().into_series(),
+            ColumnBuilder::Utf8(b) => b.finish().into_series(),
+        }
+    }
+}
+
+/// Collects rows in batches of `batch_size` and stacks the batches into a single DataFrame.
+struct DataFrameSink {
+    batch_size: usize,
+    fields: Vec<Field>,
+    builders: Vec<ColumnBuilder>,
+    rows_in_batch: usize,
+    df: Option<DataFrame>,
+}
+
+impl DataFrameSink {
+    fn new(batch_size: usize) -> Self {
+        DataFrameSink {
+            batch_size,
+            fields: vec![],
+            builders: vec![],
+            rows_in_batch: 0,
+            df: None,
+        }
+    }
+
+    fn new_builders(&self) -> Result<Vec<ColumnBuilder>> {
+        self.fields
+            .iter()
+            .map(|f| ColumnBuilder::new(f, self.batch_size))
+            .collect()
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        let builders = std::mem::replace(&mut self.builders, self.new_builders()?);
+        let batch = DataFrame::new_no_checks(builders.into_iter().map(|b| b.finish()).collect());
+        self.rows_in_batch = 0;
+        match &mut self.df {
+            Some(df) => {
+                df.vstack_mut(&batch)?;
+            }
+            None => self.df = Some(batch),
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<DataFrame> {
+        if self.rows_in_batch > 0 || self.df.is_none() {
+            self.flush()?;
+        }
+        let mut df = self.df.unwrap();
+        df.rechunk();
+        Ok(df)
+    }
+}
+
+impl RowSink for DataFrameSink {
+    fn set_schema(&mut self, fields: Vec<Field>) -> Result<()> {
+        self.fields = fields;
+        self.builders = self.new_builders()?;
+        Ok(())
+    }
+
+    fn push_row(&mut self, row: Vec<SqlValue>) -> Result<()> {
+        if row.len() != self.builders.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "expected a row of {} values, got {}",
+                    self.builders.len(),
+                    row.len()
+                )
+                .into(),
+            ));
+        }
+        for (builder, value) in self.builders.iter_mut().zip(row) {
+            builder.append(value)?;
+        }
+        self.rows_in_batch += 1;
+        if self.rows_in_batch == self.batch_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Read the result of a SQL query into a DataFrame.
+pub struct SqlReader<C> {
+    conn: C,
+    query: String,
+    batch_size: usize,
+}
+
+impl<C: SqlConnection> SqlReader<C> {
+    pub fn new<S: Into<String>>(conn: C, query: S) -> Self {
+        SqlReader {
+            conn,
+            query: query.into(),
+            batch_size: 100_000,
+        }
+    }
+
+    /// Set the number of rows that are materialized at once.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = std::cmp::max(batch_size, 1);
+        self
+    }
+
+    pub fn finish(mut self) -> Result<DataFrame> {
+        read_query(&mut self.conn, &self.query, self.batch_size)
+    }
+}
+
+fn read_query<C: SqlConnection>(conn: &mut C, query: &str, batch_size: usize) -> Result<DataFrame> {
+    let mut sink = DataFrameSink::new(batch_size);
+    conn.query(query, &mut sink)?;
+    sink.finish()
+}
+
+/// Read the result of a SQL query in parallel. The result set is split in `n_partitions`
+/// ranges of the integer `partition_on` column and every range is fetched over its own
+/// connection, opened with `connect`. Rows where `partition_on` is null are fetched with the
+/// first range.
+pub fn read_sql_partitioned<C, F>(
+    connect: F,
+    query: &str,
+    partition_on: &str,
+    n_partitions: usize,
+) -> Result<DataFrame>
+where
+    C: SqlConnection,
+    F: Fn() -> Result<C> + Sync,
+{
+    let n_partitions = std::cmp::max(n_partitions, 1) as i128;
+    let bounds = read_query(
+        &mut connect()?,
+        &format!(
+            "SELECT MIN({col}), MAX({col}) FROM ({query}) AS _partitioned",
+            col = partition_on,
+            query = query
+        ),
+        1,
+    )?;
+    let bound = |idx: usize| -> Result<Option<i64>> {
+        let s = bounds.select_at_idx(idx).unwrap();
+        match s.dtype() {
+            // an empty result set or only nulls in the partition column
+            _ if s.null_count() == s.len() => Ok(None),
+            DataType::Int64 => Ok(s.i64()?.get(0)),
+            // databases without a declared type for computed columns (e.g. SQLite) return the
+            // bounds as text, so they must parse as integers without loss
+            DataType::Utf8 => {
+                let v = s.utf8()?.get(0).unwrap();
+                v.trim().parse::<i64>().map(Some).map_err(|_| {
+                    PolarsError::DataTypeMisMatch(
+                        format!(
+                            "cannot partition on column {}, bound {} is not an integer",
+                            partition_on, v
+                        )
+                        .into(),
+                    )
+                })
+            }
+            dt => Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "cannot partition on column {} of type {:?}, expected an integer column",
+                    partition_on, dt
+                )
+                .into(),
+            )),
+        }
+    };
+    let (min, max) = match (bound(0)?, bound(1)?) {
+        (Some(min), Some(max)) => (min as i128, max as i128),
+        _ => return read_query(&mut connect()?, query, 100_000),
+    };
+    // the bounds are computed in i128, so that the span of the full i64 range does not overflow
+    let step = std::cmp::max((max - min + 1) / n_partitions, 1);
+
+    let mut ranges = vec![];
+    let mut lower = min;
+    while lower <= max {
+        let upper = lower + step;
+        let last = upper > max || ranges.len() as i128 == n_partitions - 1;
+        let mut filter = if last {
+            format!("{} >= {}", partition_on, lower)
+        } else {
+            format!(
+                "{col} >= {} AND {col} < {}",
+                lower,
+                upper,
+                col = partition_on
+            )
+        };
+        if ranges.is_empty() {
+            filter = format!("({}) OR {} IS NULL", filter, partition_on);
+        }
+        ranges.push(filter);
+        if last {
+            break;
+        }
+        lower = upper;
+    }
+
+    let dfs = POOL.install(|| {
+        ranges
+            .par_iter()
+            .map(|filter| {
+                let query = format!("SELECT * FROM ({}) AS _partitioned WHERE {}", query, filter);
+                read_query(&mut connect()?, &query, 100_000)
+            })
+            .collect::<Result<Vec<_>>>()
+    })?;
+    let mut iter = dfs.into_iter();
+    let mut df = iter.next().unwrap();
+    for other in iter {
+        df.vstack_mut(&other)?;
+    }
+    df.rechunk();
+    Ok(df)
+}
+
+#[cfg(feature = "sql-sqlite")]
+mod sqlite {
+    use super::*;
+    use rusqlite::types::ValueRef;
+
+    fn to_polars_err(e: rusqlite::Error) -> PolarsError {
+        PolarsError::Various(e.into())
+    }
+
+    /// Map the declared type of a column to a dtype, following the type affinity rules of SQLite.
+    fn declared_dtype(decl_type: Option<&str>) -> DataType {
+        let decl_type = decl_type.unwrap_or("").to_uppercase();
+        if decl_type.contains("BOOL") {
+            DataType::Boolean
+        } else if decl_type.contains("INT") {
+            DataType::Int64
+        } else if decl_type.contains("REAL")
+            || decl_type.contains("FLOA")
+            || decl_type.contains("DOUB")
+        {
+            DataType::Float64
+        } else {
+            DataType::Utf8
+        }
+    }
+
+    impl SqlConnection for rusqlite::Connection {
+        fn query(&mut self, query: &str, sink: &mut dyn RowSink) -> Result<()> {
+            let mut stmt = self.prepare(query).map_err(to_polars_err)?;
+            let fields = stmt
+                .columns()
+                .iter()
+                .map(|c| Field::new(c.name(), declared_dtype(c.decl_type())))
+                .collect::<Vec<_>>();
+            let n_columns = fields.len();
+            sink.set_schema(fields)?;
+
+            let mut rows = stmt.query([]).map_err(to_polars_err)?;
+            while let Some(row) = rows.next().map_err(to_polars_err)? {
+                let values = (0..n_columns)
+                    .map(|i| {
+                        Ok(match row.get_ref(i).map_err(to_polars_err)? {
+                            ValueRef::Null => SqlValue::Null,
+                            ValueRef::Integer(v) => SqlValue::Int(v),
+                            ValueRef::Real(v) => SqlValue::Float(v),
+                            ValueRef::Text(v) | ValueRef::Blob(v) => {
+                                SqlValue::Text(String::from_utf8_lossy(v).into_owned())
+                            }
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                sink.push_row(values)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "sql-postgres")]
+mod postgres {
+    use super::*;
+    use fallible_iterator::FallibleIterator;
+    use postgres_lib::types::Type;
+    use postgres_lib::{Client, Row};
+
+    fn to_polars_err(e: postgres_lib::Error) -> PolarsError {
+        PolarsError::Various(e.into())
+    }
+
+    fn to_dtype(tp: &Type) -> Result<DataType> {
+        let dtype = match *tp {
+            Type::BOOL => DataType::Boolean,
+            Type::INT2 | Type::INT4 | Type::INT8 => DataType::Int64,
+            Type::FLOAT4 | Type::FLOAT8 => DataType::Float64,
+            Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME => DataType::Utf8,
+            ref tp => {
+                return Err(PolarsError::DataTypeMisMatch(
+                    format!("cannot read postgres type {}, cast it in the query", tp).into(),
+                ))
+            }
+        };
+        Ok(dtype)
+    }
+
+    fn get_value(row: &Row, idx: usize, tp: &Type) -> Result<SqlValue> {
+        let value = match *tp {
+            Type::BOOL => row
+                .try_get::<_, Option<bool>>(idx)
+                .map(|v| v.map(SqlValue::Bool)),
+            Type::INT2 => row
+                .try_get::<_, Option<i16>>(idx)
+                .map(|v| v.map(|v| SqlValue::Int(v as i64))),
+            Type::INT4 => row
+                .try_get::<_, Option<i32>>(idx)
+                .map(|v| v.map(|v| SqlValue::Int(v as i64))),
+            Type::INT8 => row
+                .try_get::<_, Option<i64>>(idx)
+                .map(|v| v.map(SqlValue::Int)),
+            Type::FLOAT4 => row
+                .try_get::<_, Option<f32>>(idx)
+                .map(|v| v.map(|v| SqlValue::Float(v as f64))),
+            Type::FLOAT8 => row
+                .try_get::<_, Option<f64>>(idx)
+                .map(|v| v.map(SqlValue::Float)),
+            _ => row
+                .try_get::<_, Option<String>>(idx)
+                .map(|v| v.map(SqlValue::Text)),
+        };
+        Ok(value.map_err(to_polars_err)?.unwrap_or(SqlValue::Null))
+    }
+
+    impl SqlConnection for Client {
+        fn query(&mut self, query: &str, sink: &mut dyn RowSink) -> Result<()> {
+            let stmt = self.prepare(query).map_err(to_polars_err)?;
+            let fields = stmt
+                .columns()
+                .iter()
+                .map(|c| Ok(Field::new(c.name(), to_dtype(c.type_())?)))
+                .collect::<Result<Vec<_>>>()?;
+            sink.set_schema(fields)?;
+
+            let mut rows = self
+                .query_raw(&stmt, std::iter::empty::<i32>())
+                .map_err(to_polars_err)?;
+            while let Some(row) = rows.next().map_err(to_polars_err)? {
+                let values = stmt
+                    .columns()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| get_value(&row, i, c.type_()))
+                    .collect::<Result<Vec<_>>>()?;
+                sink.push_row(values)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "sql-mysql")]
+mod mysql {
+    use super::*;
+    use mysql_lib::consts::ColumnType;
+    use mysql_lib::prelude::Queryable;
+    use mysql_lib::{from_value_opt, Conn, Value};
+
+    fn to_polars_err<E: std::error::Error + Send + Sync + 'static>(e: E) -> PolarsError {
+        PolarsError::Various(e.into())
+    }
+
+    fn to_dtype(tp: ColumnType) -> DataType {
+        use ColumnType::*;
+        match tp {
+            MYSQL_TYPE_TINY | MYSQL_TYPE_SHORT | MYSQL_TYPE_INT24 | MYSQL_TYPE_LONG
+            | MYSQL_TYPE_LONGLONG | MYSQL_TYPE_YEAR => DataType::Int64,
+            MYSQL_TYPE_FLOAT | MYSQL_TYPE_DOUBLE => DataType::Float64,
+            _ => DataType::Utf8,
+        }
+    }
+
+    fn to_sql_value(value: Value, dtype: &DataType) -> Result<SqlValue> {
+        if value == Value::NULL {
+            return Ok(SqlValue::Null);
+        }
+        let value = match dtype {
+            DataType::Int64 => SqlValue::Int(from_value_opt(value).map_err(to_polars_err)?),
+            DataType::Float64 => SqlValue::Float(from_value_opt(value).map_err(to_polars_err)?),
+            _ => SqlValue::Text(from_value_opt(value).map_err(to_polars_err)?),
+        };
+        Ok(value)
+    }
+
+    impl SqlConnection for Conn {
+        fn query(&mut self, query: &str, sink: &mut dyn RowSink) -> Result<()> {
+            let mut result = self.query_iter(query).map_err(to_polars_err)?;
+            let fields = result
+                .columns()
+                .as_ref()
+                .iter()
+                .map(|c| Field::new(&c.name_str(), to_dtype(c.column_type())))
+                .collect::<Vec<_>>();
+            let dtypes = fields
+                .iter()
+                .map(|f| f.data_type().clone())
+                .collect::<Vec<_>>();
+            sink.set_schema(fields)?;
+
+            for row in result {
+                let row = row.map_err(to_polars_err)?;
+                let values = row
+                    .unwrap()
+                    .into_iter()
+                    .zip(&dtypes)
+                    .map(|(value, dtype)| to_sql_value(value, dtype))
+                    .collect::<Result<Vec<_>>>()?;
+                sink.push_row(values)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A connection that returns a fixed result set and applies no filters.
+    struct MockConnection;
+
+    impl SqlConnection for MockConnection {
+        fn query(&mut self, _query: &str, sink: &mut dyn RowSink) -> Result<()> {
+            sink.set_schema(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("name", DataType::Utf8),
+            ])?;
+            for i in 0..5 {
+                let name = if i == 2 {
+                    SqlValue::Null
+                } else {
+                    SqlValue::Text(format!("n{}", i))
+                };
+                sink.push_row(vec![SqlValue::Int(i), name])?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_read_sql_batched() -> Result<()> {
+        let df = SqlReader::new(MockConnection, "SELECT * FROM t")
+            .with_batch_size(2)
+            .finish()?;
+        assert_eq!(df.shape(), (5, 2));
+        assert_eq!(df.n_chunks()?, 1);
+        assert_eq!(
+            Vec::from(df.column("id")?.i64()?),
+            &[Some(0), Some(1), Some(2), Some(3), Some(4)]
+        );
+        assert_eq!(df.column("name")?.null_count(), 1);
+        Ok(())
+    }
+
+    /// A connection to a table with a single column `id`, that applies the min/max and range
+    /// queries of `read_sql_partitioned`.
+    struct FilterConnection(Vec<SqlValue>);
+
+    impl FilterConnection {
+        fn matches(filter: &str, value: &SqlValue) -> bool {
+            filter.split(" OR ").any(|clause| {
+                clause.split(" AND ").all(|cond| {
+                    let cond = cond.trim_matches(|c| c == '(' || c == ')');
+                    let parts: Vec<_> = cond.split(' ').collect();
+                    match (value, parts.as_slice()) {
+                        (SqlValue::Null, [_, "IS", "NULL"]) => true,
+                        (SqlValue::Int(v), [_, ">=", bound]) => {
+                            *v as i128 >= bound.parse().unwrap()
+                        }
+                        (SqlValue::Int(v), [_, "<", bound]) => {
+                            (*v as i128) < bound.parse().unwrap()
+                        }
+                        (SqlValue::Float(v), [_, ">=", bound]) => *v >= bound.parse().unwrap(),
+                        (SqlValue::Float(v), [_, "<", bound]) => *v < bound.parse().unwrap(),
+                        _ => false,
+                    }
+                })
+            })
+        }
+    }
+
+    impl SqlConnection for FilterConnection {
+        fn query(&mut self, query: &str, sink: &mut dyn RowSink) -> Result<()> {
+            let dtype = match self.0.iter().find(|v| **v != SqlValue::Null) {
+                Some(SqlValue::Float(_)) => DataType::Float64,
+                _ => DataType::Int64,
+            };
+            if query.starts_with("SELECT MIN") {
+                sink.set_schema(vec![
+                    Field::new("min", dtype.clone()),
+                    Field::new("max", dtype),
+                ])?;
+                let mut values: Vec<_> = self
+                    .0
+                    .iter()
+                    .filter(|v| **v != SqlValue::Null)
+                    .cloned()
+                    .collect();
+                values.sort_by(|a, b| match (a, b) {
+                    (SqlValue::Int(a), SqlValue::Int(b)) => a.cmp(b),
+                    (SqlValue::Float(a), SqlValue::Float(b)) => a.partial_cmp(b).unwrap(),
+                    _ => unreachable!(),
+                });
+                let min = values.first().cloned().unwrap_or(SqlValue::Null);
+                let max = values.last().cloned().unwrap_or(SqlValue::Null);
+                return sink.push_row(vec![min, max]);
+            }
+            sink.set_schema(vec![Field::new("id", dtype)])?;
+            let filter = query.split(" WHERE ").nth(1);
+            for value in &self.0 {
+                if filter.map_or(true, |filter| Self::matches(filter, value)) {
+                    sink.push_row(vec![value.clone()])?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_read_sql_partitioned() -> Result<()> {
+        let values = vec![
+            SqlValue::Int(i64::MIN),
+            SqlValue::Null,
+            SqlValue::Int(-1),
+            SqlValue::Int(0),
+            SqlValue::Int(7),
+            SqlValue::Int(i64::MAX),
+        ];
+        let df = read_sql_partitioned(
+            || Ok(FilterConnection(values.clone())),
+            "SELECT * FROM t",
+            "id",
+            3,
+        )?;
+        let mut out = Vec::from(df.column("id")?.i64()?);
+        out.sort();
+        assert_eq!(
+            out,
+            &[
+                None,
+                Some(i64::MIN),
+                Some(-1),
+                Some(0),
+                Some(7),
+                Some(i64::MAX)
+            ]
+        );
+
+        // only nulls
+        let df = read_sql_partitioned(
+            || Ok(FilterConnection(vec![SqlValue::Null, SqlValue::Null])),
+            "SELECT * FROM t",
+            "id",
+            3,
+        )?;
+        assert_eq!(df.height(), 2);
+
+        // rows between the bounds of a truncated float column would be lost
+        let floats = vec![SqlValue::Float(-0.5), SqlValue::Float(2.5)];
+        assert!(read_sql_partitioned(
+            || Ok(FilterConnection(floats.clone())),
+            "SELECT * FROM t",
+            "id",
+            2
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "sql-sqlite")]
+    fn test_read_sqlite() -> Result<()> {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE t (id INTEGER, value REAL, name TEXT);
+             INSERT INTO t VALUES (1, 1.5, 'a'), (2, NULL, 'b'), (3, 3.5, NULL);",
+        )
+        .unwrap();
+        let df = SqlReader::new(conn, "SELECT * FROM t").finish()?;
+        assert_eq!(df.shape(), (3, 3));
+        assert_eq!(df.column("value")?.dtype(), &DataType::Float64);
+        assert_eq!(df.column("name")?.null_count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "sql-sqlite")]
+    fn test_read_sqlite_partitioned() -> Result<()> {
+        // a named in-memory database is shared by all connections, as long as one of them is open
+        let uri = "file:test_read_sqlite_partitioned?mode=memory&cache=shared";
+        let connect =
+            || rusqlite::Connection::open(uri).map_err(|e| PolarsError::Various(e.into()));
+        let conn = connect()?;
+        conn.execute_batch(
+            "CREATE TABLE t (id INTEGER, name TEXT);
+             INSERT INTO t VALUES (1, 'a'), (NULL, 'b'), (5, 'c'), (10, 'd'), (-3, 'e');
+             CREATE TABLE f (id REAL);
+             INSERT INTO f VALUES (0.5), (2.5);",
+        )
+        .unwrap();
+
+        let df = read_sql_partitioned(connect, "SELECT * FROM t", "id", 3)?;
+        assert_eq!(df.column("id")?.dtype(), &DataType::Int64);
+        let mut out = Vec::from(df.column("id")?.i64()?);
+        out.sort();
+        assert_eq!(out, &[None, Some(-3), Some(1), Some(5), Some(10)]);
+
+        // the bounds of a float column are not integers
+        assert!(read_sql_partitioned(connect, "SELECT * FROM f", "id", 2).is_err());
+        drop(conn);
+        Ok(())
+    }
+}
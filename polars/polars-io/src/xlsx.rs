@@ -0,0 +1,353 @@
+//! # Read Excel (.xlsx) workbooks.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use std::fs::File;
+//!
+//! fn example() -> Result<DataFrame> {
+//!     let file = File::open("workbook.xlsx")?;
+//!     XlsxReader::new(file)
+//!         .with_sheet_name(Some("sales".to_string()))
+//!         .finish()
+//! }
+//! ```
+use crate::prelude::*;
+use calamine::{DataType as Cell, Reader, Xlsx};
+use polars_core::prelude::*;
+use std::io::{Read, Seek};
+
+fn to_polars_err(e: calamine::XlsxError) -> PolarsError {
+    PolarsError::Various(e.into())
+}
+
+/// Days between the excel epoch (1899-12-30) and the unix epoch.
+const EXCEL_UNIX_EPOCH_DAYS: f64 = 25569.0;
+const MS_IN_DAY: f64 = 86_400_000.0;
+
+/// Convert an excel serial date (fractional days since the excel epoch) to milliseconds since
+/// the unix epoch.
+fn excel_to_ms(v: f64) -> i64 {
+    ((v - EXCEL_UNIX_EPOCH_DAYS) * MS_IN_DAY).round() as i64
+}
+
+/// Infer the dtype of a column from its cells.
+fn infer_dtype<'a, I: Iterator<Item = &'a Cell>>(cells: I) -> DataType {
+    let mut dtype = DataType::Null;
+    for cell in cells {
+        dtype = match (cell, &dtype) {
+            (Cell::Empty, dt) | (Cell::Error(_), dt) => dt.clone(),
+            (Cell::Bool(_), DataType::Null) | (Cell::Bool(_), DataType::Boolean) => {
+                DataType::Boolean
+            }
+            (Cell::Int(_), DataType::Null) | (Cell::Int(_), DataType::Int64) => DataType::Int64,
+            (Cell::Int(_), DataType::Float64)
+            | (Cell::Float(_), DataType::Null)
+            | (Cell::Float(_), DataType::Int64)
+            | (Cell::Float(_), DataType::Float64) => DataType::Float64,
+            (Cell::DateTime(_), DataType::Null) | (Cell::DateTime(_), DataType::Date64) => {
+                DataType::Date64
+            }
+            _ => return DataType::Utf8,
+        }
+    }
+    match dtype {
+        // a column without values
+        DataType::Null => DataType::Utf8,
+        dt => dt,
+    }
+}
+
+fn cell_to_string(cell: &Cell) -> Option<String> {
+    match cell {
+        Cell::Empty | Cell::Error(_) => None,
+        Cell::String(v) => Some(v.clone()),
+        cell => Some(format!("{}", cell)),
+    }
+}
+
+/// Convert the cells of a column with `f`. Empty and error cells are read as missing values.
+/// A cell that cannot be converted is an error, unless `ignore_errors` is set, in which case it
+/// is read as a missing value as well.
+fn convert_cells<'a, T, I, F>(
+    name: &str,
+    dtype: &DataType,
+    cells: I,
+    ignore_errors: bool,
+    f: F,
+) -> Result<Vec<Option<T>>>
+where
+    I: Iterator<Item = &'a Cell>,
+    F: Fn(&Cell) -> Option<T>,
+{
+    cells
+        .enumerate()
+        .map(|(row, cell)| match cell {
+            Cell::Empty | Cell::Error(_) => Ok(None),
+            cell => match f(cell) {
+                Some(v) => Ok(Some(v)),
+                None if ignore_errors => Ok(None),
+                None => Err(PolarsError::SchemaMisMatch(
+                    format!(
+                        "could not read cell {:?} in row {} of column {} as {:?}",
+                        cell, row, name, dtype
+                    )
+                    .into(),
+                )),
+            },
+        })
+        .collect()
+}
+
+fn build_series<'a, I: Iterator<Item = &'a Cell>>(
+    name: &str,
+    dtype: &DataType,
+    cells: I,
+    ignore_errors: bool,
+) -> Result<Series> {
+    let mut s = match dtype {
+        DataType::Boolean => convert_cells(name, dtype, cells, ignore_errors, |c| match c {
+            Cell::Bool(v) => Some(*v),
+            _ => None,
+        })?
+        .into_iter()
+        .collect::<BooleanChunked>()
+        .into_series(),
+        DataType::Int64 => convert_cells(name, dtype, cells, ignore_errors, |c| match c {
+            Cell::Int(v) => Some(*v),
+            _ => None,
+        })?
+        .into_iter()
+        .collect::<Int64Chunked>()
+        .into_series(),
+        DataType::Float64 => convert_cells(name, dtype, cells, ignore_errors, |c| match c {
+            Cell::Int(v) => Some(*v as f64),
+            Cell::Float(v) => Some(*v),
+            _ => None,
+        })?
+        .into_iter()
+        .collect::<Float64Chunked>()
+        .into_series(),
+        DataType::Date64 => convert_cells(name, dtype, cells, ignore_errors, |c| match c {
+            Cell::DateTime(v) => Some(excel_to_ms(*v)),
+            _ => None,
+        })?
+        .into_iter()
+        .collect::<Int64Chunked>()
+        .into_series()
+        .cast_with_dtype(&DataType::Date64)?,
+        _ => cells
+            .map(cell_to_string)
+            .collect::<Utf8Chunked>()
+            .into_series(),
+    };
+    s.rename(name);
+    Ok(s)
+}
+
+/// Read a sheet of an Excel workbook into a DataFrame.
+pub struct XlsxReader<R> {
+    reader: R,
+    sheet_name: Option<String>,
+    sheet_index: usize,
+    has_header: bool,
+    max_records: Option<usize>,
+    skip_rows: usize,
+    ignore_errors: bool,
+}
+
+impl<R> XlsxReader<R>
+where
+    R: Read + Seek,
+{
+    /// Read the sheet with this name. If not set, the sheet at the given index is read.
+    pub fn with_sheet_name(mut self, name: Option<String>) -> Self {
+        self.sheet_name = name;
+        self
+    }
+
+    /// Read the sheet at this index. Defaults to the first sheet.
+    pub fn with_sheet_index(mut self, index: usize) -> Self {
+        self.sheet_index = index;
+        self
+    }
+
+    /// Set whether the first (non skipped) row contains the column names.
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Set the number of rows used to infer the dtypes. If `None`, all rows are used.
+    pub fn infer_schema(mut self, max_records: Option<usize>) -> Self {
+        self.max_records = max_records;
+        self
+    }
+
+    /// Skip the first `n` rows of the sheet.
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Read cells that don't match the inferred dtype of their column as missing values,
+    /// instead of returning an error.
+    pub fn with_ignore_errors(mut self, ignore: bool) -> Self {
+        self.ignore_errors = ignore;
+        self
+    }
+}
+
+impl<R> SerReader<R> for XlsxReader<R>
+where
+    R: Read + Seek,
+{
+    fn new(reader: R) -> Self {
+        XlsxReader {
+            reader,
+            sheet_name: None,
+            sheet_index: 0,
+            has_header: true,
+            max_records: Some(100),
+            skip_rows: 0,
+            ignore_errors: false,
+        }
+    }
+
+    fn finish(self) -> Result<DataFrame> {
+        let mut workbook = Xlsx::new(self.reader).map_err(to_polars_err)?;
+        let sheet_name = match self.sheet_name {
+            Some(name) => name,
+            None => workbook
+                .sheet_names()
+                .get(self.sheet_index)
+                .cloned()
+                .ok_or_else(|| {
+                    PolarsError::OutOfBounds(
+                        format!("the workbook has no sheet at index {}", self.sheet_index).into(),
+                    )
+                })?,
+        };
+        let range = workbook
+            .worksheet_range(&sheet_name)
+            .ok_or_else(|| PolarsError::NotFound(format!("sheet {}", sheet_name)))?
+            .map_err(to_polars_err)?;
+
+        let mut rows = range.rows().skip(self.skip_rows).peekable();
+        let width = range.width();
+        let names = match (self.has_header, rows.peek()) {
+            (true, Some(_)) => rows
+                .next()
+                .unwrap()
+                .iter()
+                .enumerate()
+                .map(|(i, cell)| {
+                    cell_to_string(cell).unwrap_or_else(|| format!("column_{}", i + 1))
+                })
+                .collect::<Vec<_>>(),
+            _ => (0..width).map(|i| format!("column_{}", i + 1)).collect(),
+        };
+        let rows = rows.collect::<Vec<_>>();
+        let n_infer = self.max_records.unwrap_or(usize::MAX);
+
+        let columns = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let dtype = infer_dtype(rows.iter().take(n_infer).map(|row| &row[i]));
+                build_series(
+                    name,
+                    &dtype,
+                    rows.iter().map(|row| &row[i]),
+                    self.ignore_errors,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_infer_cell_dtype() {
+        let cells = [Cell::Int(1), Cell::Empty, Cell::Float(1.5)];
+        assert_eq!(infer_dtype(cells.iter()), DataType::Float64);
+        let cells = [Cell::Bool(true), Cell::Int(1)];
+        assert_eq!(infer_dtype(cells.iter()), DataType::Utf8);
+        let cells = [Cell::Empty, Cell::DateTime(25570.5)];
+        assert_eq!(infer_dtype(cells.iter()), DataType::Date64);
+        assert_eq!(excel_to_ms(25570.5), 86_400_000 + 43_200_000);
+    }
+
+    #[test]
+    fn test_build_series_mismatch() -> Result<()> {
+        // the dtype is inferred from the first cells only
+        let cells = [Cell::Int(1), Cell::Empty, Cell::String("x".into())];
+        assert!(build_series("a", &DataType::Int64, cells.iter(), false).is_err());
+
+        let s = build_series("a", &DataType::Int64, cells.iter(), true)?;
+        assert_eq!(Vec::from(s.i64()?), &[Some(1), None, None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_xlsx() -> Result<()> {
+        let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods.xlsx";
+        let df = XlsxReader::new(std::fs::File::open(path)?)
+            .with_sheet_name(Some("foods".to_string()))
+            .finish()?;
+
+        assert_eq!(
+            df.get_column_names(),
+            &["category", "calories", "fats_g", "is_fruit", "date"]
+        );
+        assert_eq!(
+            df.dtypes(),
+            &[
+                DataType::Utf8,
+                DataType::Float64,
+                DataType::Float64,
+                DataType::Boolean,
+                DataType::Date64
+            ]
+        );
+        // empty cells are read as missing values
+        assert_eq!(
+            Vec::from(df.column("category")?.utf8()?),
+            &[Some("vegetables"), Some("seafood"), Some("fruit"), None]
+        );
+        assert_eq!(
+            Vec::from(df.column("calories")?.f64()?),
+            &[Some(45.0), Some(150.0), None, Some(100.0)]
+        );
+        assert_eq!(
+            Vec::from(df.column("is_fruit")?.bool()?),
+            &[Some(false), None, Some(true), Some(false)]
+        );
+        // 2021-01-01, 2021-01-02, missing, 2021-01-04
+        let day = 86_400_000;
+        assert_eq!(
+            Vec::from(df.column("date")?.date64()?),
+            &[
+                Some(18628 * day),
+                Some(18629 * day),
+                None,
+                Some(18631 * day)
+            ]
+        );
+
+        // without a header the first row is data
+        let df = XlsxReader::new(std::fs::File::open(path)?)
+            .has_header(false)
+            .finish()?;
+        assert_eq!(df.shape(), (5, 5));
+        assert_eq!(df.get_column_names()[0], "column_1");
+        assert_eq!(df.column("column_2")?.dtype(), &DataType::Utf8);
+        Ok(())
+    }
+}
@@ -191,6 +191,7 @@ pub fn to_datafusion_lp(lp: LogicalPlan) -> Result<DLogicalPlan> {
             aggs,
             schema,
             apply,
+            ..
         } => {
             if apply.is_some() {
                 return Err(PolarsError::Other(
@@ -214,6 +215,7 @@ pub fn to_datafusion_lp(lp: LogicalPlan) -> Result<DLogicalPlan> {
             input,
             by_column,
             reverse,
+            nulls_last,
         } => {
             assert_eq!(reverse.len(), 1);
             DLogicalPlan::Sort {
@@ -222,9 +224,10 @@ pub fn to_datafusion_lp(lp: LogicalPlan) -> Result<DLogicalPlan> {
                     .into_iter()
                     .map(|e| {
                         if reverse[0] {
-                            to_datafusion_expr(e.reverse()).map(|e| e.sort(!reverse[0], true))
+                            to_datafusion_expr(e.reverse())
+                                .map(|e| e.sort(!reverse[0], !nulls_last))
                         } else {
-                            to_datafusion_expr(e).map(|e| e.sort(!reverse[0], true))
+                            to_datafusion_expr(e).map(|e| e.sort(!reverse[0], !nulls_last))
                         }
                     })
                     .collect::<Result<Vec<_>>>()?,
@@ -292,6 +295,7 @@ pub fn to_datafusion_lp(lp: LogicalPlan) -> Result<DLogicalPlan> {
             ignore_errors,
             skip_rows,
             stop_after_n_rows,
+            row_count,
             ..
         } => {
             let schema = schema.to_arrow();
@@ -299,8 +303,8 @@ pub fn to_datafusion_lp(lp: LogicalPlan) -> Result<DLogicalPlan> {
                 .has_header(has_header)
                 .delimiter(delimiter)
                 .schema(&schema);
-            if ignore_errors || skip_rows > 0 {
-                return Err(PolarsError::Other("DataFusion does not support `ignore_errors`, `skip_rows`, `stop_after_n_rows`, `with_columns`".into()));
+            if ignore_errors || skip_rows > 0 || row_count.is_some() {
+                return Err(PolarsError::Other("DataFusion does not support `ignore_errors`, `skip_rows`, `stop_after_n_rows`, `with_columns`, `row_count`".into()));
             }
             let builder =
                 LogicalPlanBuilder::scan_csv(try_path_to_str(&path)?, options, None).unwrap();
@@ -313,8 +317,14 @@ pub fn to_datafusion_lp(lp: LogicalPlan) -> Result<DLogicalPlan> {
         ParquetScan {
             path,
             stop_after_n_rows,
+            row_count,
             ..
         } => {
+            if row_count.is_some() {
+                return Err(PolarsError::Other(
+                    "DataFusion does not support `row_count`".into(),
+                ));
+            }
             let builder =
                 LogicalPlanBuilder::scan_parquet(try_path_to_str(&path)?, None, 8).unwrap();
             match stop_after_n_rows {
@@ -3,6 +3,9 @@ use crate::logical_plan::Context;
 use crate::prelude::*;
 use crate::utils::{has_expr, output_name};
 use polars_core::prelude::*;
+use polars_core::utils::{get_temporal_arithmetic_type, ArithmeticOp};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(feature = "temporal")]
 use polars_core::utils::chrono::{NaiveDate, NaiveDateTime};
@@ -16,6 +19,14 @@ use std::{
 // reexport the lazy method
 pub use crate::frame::IntoLazy;
 
+#[cfg(feature = "strings")]
+#[cfg_attr(docsrs, doc(cfg(feature = "strings")))]
+pub mod string;
+
+#[cfg(feature = "list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "list")))]
+pub mod list;
+
 /// A wrapper trait for any closure `Fn(Vec<Series>) -> Result<Series>`
 pub trait SeriesUdf: Send + Sync {
     fn call_udf(&self, s: &mut [Series]) -> Result<Series>;
@@ -78,6 +89,26 @@ impl<T> Debug for NoEq<T> {
     }
 }
 
+#[cfg(feature = "serialize")]
+impl<T: Serialize> Serialize for NoEq<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NoEq<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(NoEq)
+    }
+}
+
 impl<T> Deref for NoEq<T> {
     type Target = T;
 
@@ -86,6 +117,43 @@ impl<T> Deref for NoEq<T> {
     }
 }
 
+/// The output dtype of an [`Expr::Function`], relative to the field of its first input.
+#[derive(Clone, PartialEq, Debug)]
+pub enum GetOutput {
+    /// The dtype of the input.
+    SameType,
+    /// A fixed dtype.
+    DataType(DataType),
+    /// A dtype that is derived from the dtype of the input once the input schema is known.
+    Map(NoEq<Arc<dyn Fn(&DataType) -> Result<DataType> + Send + Sync>>),
+}
+
+impl GetOutput {
+    pub fn map_dtype<F>(f: F) -> Self
+    where
+        F: Fn(&DataType) -> Result<DataType> + Send + Sync + 'static,
+    {
+        GetOutput::Map(NoEq::new(Arc::new(f)))
+    }
+
+    pub(crate) fn get_field(&self, input_field: &Field) -> Result<Field> {
+        match self {
+            GetOutput::SameType => Ok(input_field.clone()),
+            GetOutput::DataType(dtype) => Ok(Field::new(input_field.name(), dtype.clone())),
+            GetOutput::Map(f) => Ok(Field::new(input_field.name(), f(input_field.data_type())?)),
+        }
+    }
+}
+
+impl From<Option<DataType>> for GetOutput {
+    fn from(output_type: Option<DataType>) -> Self {
+        match output_type {
+            Some(dtype) => GetOutput::DataType(dtype),
+            None => GetOutput::SameType,
+        }
+    }
+}
+
 pub trait BinaryUdfOutputField: Send + Sync {
     fn get_field(
         &self,
@@ -112,6 +180,7 @@ where
 }
 
 #[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub enum AggExpr {
     Min(Box<Expr>),
     Max(Box<Expr>),
@@ -122,11 +191,24 @@ pub enum AggExpr {
     Mean(Box<Expr>),
     List(Box<Expr>),
     Count(Box<Expr>),
-    Quantile { expr: Box<Expr>, quantile: f64 },
+    Quantile {
+        expr: Box<Expr>,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    },
     Sum(Box<Expr>),
     AggGroups(Box<Expr>),
     Std(Box<Expr>),
     Var(Box<Expr>),
+    Skew {
+        expr: Box<Expr>,
+        bias: bool,
+    },
+    Kurtosis {
+        expr: Box<Expr>,
+        fisher: bool,
+        bias: bool,
+    },
 }
 
 impl AsRef<Expr> for AggExpr {
@@ -147,6 +229,8 @@ impl AsRef<Expr> for AggExpr {
             AggGroups(e) => e,
             Std(e) => e,
             Var(e) => e,
+            Skew { expr, .. } => expr,
+            Kurtosis { expr, .. } => expr,
         }
     }
 }
@@ -157,8 +241,27 @@ impl From<AggExpr> for Expr {
     }
 }
 
+impl IntoVec<Expr> for &str {
+    fn into_vec(self) -> Vec<Expr> {
+        vec![col(self)]
+    }
+}
+
+impl IntoVec<Expr> for &[&str] {
+    fn into_vec(self) -> Vec<Expr> {
+        self.iter().map(|name| col(name)).collect()
+    }
+}
+
+impl IntoVec<Expr> for Expr {
+    fn into_vec(self) -> Vec<Expr> {
+        vec![self]
+    }
+}
+
 /// Queries consists of multiple expressions.
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub enum Expr {
     Alias(Box<Expr>, Arc<String>),
     Column(Arc<String>),
@@ -196,13 +299,16 @@ pub enum Expr {
         truthy: Box<Expr>,
         falsy: Box<Expr>,
     },
+    /// Closures can't be serialized, expressions with a `Function` fail to serialize. Note
+    /// that this includes the expressions of the `str`, `dt` and `arr` namespaces.
+    #[cfg_attr(feature = "serialize", serde(skip))]
     Function {
         /// function arguments
         input: Vec<Expr>,
         /// function to apply
         function: NoEq<Arc<dyn SeriesUdf>>,
         /// output dtype of the function
-        output_type: Option<DataType>,
+        output_type: GetOutput,
         /// if the groups should aggregated to list before
         /// execution of the function.
         collect_groups: bool,
@@ -233,6 +339,7 @@ pub enum Expr {
         offset: i64,
         length: usize,
     },
+    #[cfg_attr(feature = "serialize", serde(skip))]
     BinaryFunction {
         input_a: Box<Expr>,
         input_b: Box<Expr>,
@@ -308,7 +415,17 @@ impl fmt::Debug for Expr {
                     Count(expr) => write!(f, "AGG COUNT {:?}", expr),
                     Var(expr) => write!(f, "AGG VAR {:?}", expr),
                     Std(expr) => write!(f, "AGG STD {:?}", expr),
-                    Quantile { expr, .. } => write!(f, "AGG QUANTILE {:?}", expr),
+                    Quantile {
+                        expr,
+                        quantile,
+                        interpol,
+                    } => write!(f, "AGG QUANTILE {:?} {} {:?}", expr, quantile, interpol),
+                    Skew { expr, bias } => write!(f, "AGG SKEW {:?} bias: {}", expr, bias),
+                    Kurtosis { expr, fisher, bias } => write!(
+                        f,
+                        "AGG KURTOSIS {:?} fisher: {} bias: {}",
+                        expr, fisher, bias
+                    ),
                 }
             }
             Cast { expr, data_type } => write!(f, "CAST {:?} TO {:?}", expr, data_type),
@@ -361,6 +478,7 @@ pub fn except(name: &str) -> Expr {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub enum Operator {
     Eq,
     NotEq,
@@ -377,6 +495,25 @@ pub enum Operator {
     Or,
 }
 
+impl Operator {
+    /// The output type of this operation if it has dedicated rules for temporal data types,
+    /// e.g. `Date64 - Date64 = Duration`.
+    pub(crate) fn temporal_output_type(
+        &self,
+        left: &DataType,
+        right: &DataType,
+    ) -> Option<DataType> {
+        let op = match self {
+            Operator::Plus => ArithmeticOp::Add,
+            Operator::Minus => ArithmeticOp::Sub,
+            Operator::Multiply => ArithmeticOp::Mul,
+            Operator::Divide => ArithmeticOp::Div,
+            _ => return None,
+        };
+        get_temporal_arithmetic_type(left, op, right)
+    }
+}
+
 pub fn binary_expr(l: Expr, op: Operator, r: Expr) -> Expr {
     Expr::BinaryExpr {
         left: Box::new(l),
@@ -611,10 +748,33 @@ impl Expr {
     }
 
     /// Compute the quantile per group.
-    pub fn quantile(self, quantile: f64) -> Self {
+    pub fn quantile(self, quantile: f64, interpol: QuantileInterpolOptions) -> Self {
         AggExpr::Quantile {
             expr: Box::new(self),
             quantile,
+            interpol,
+        }
+        .into()
+    }
+
+    /// Compute the sample skewness. If `bias` is false, the result is corrected for statistical
+    /// bias.
+    pub fn skew(self, bias: bool) -> Self {
+        AggExpr::Skew {
+            expr: Box::new(self),
+            bias,
+        }
+        .into()
+    }
+
+    /// Compute the kurtosis. If `fisher` is true, Fisher's definition is used (normal ==> 0.0),
+    /// otherwise Pearson's definition (normal ==> 3.0). If `bias` is false, the result is
+    /// corrected for statistical bias.
+    pub fn kurtosis(self, fisher: bool, bias: bool) -> Self {
+        AggExpr::Kurtosis {
+            expr: Box::new(self),
+            fisher,
+            bias,
         }
         .into()
     }
@@ -687,6 +847,27 @@ impl Expr {
         }
     }
 
+    /// Get the [`string::StringNameSpace`] to apply string operations.
+    #[cfg(feature = "strings")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "strings")))]
+    pub fn str(self) -> string::StringNameSpace {
+        string::StringNameSpace(self)
+    }
+
+    /// Get the [`list::ListNameSpace`] to apply operations on the values of lists.
+    #[cfg(feature = "list")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "list")))]
+    pub fn arr(self) -> list::ListNameSpace {
+        list::ListNameSpace(self)
+    }
+
+    /// Alias for [`Expr::arr`].
+    #[cfg(feature = "list")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "list")))]
+    pub fn lst(self) -> list::ListNameSpace {
+        self.arr()
+    }
+
     /// Take the values by idx.
     pub fn take(self, idx: Expr) -> Self {
         Expr::Take {
@@ -720,6 +901,15 @@ impl Expr {
     /// It is the responsibility of the caller that the schema is correct by giving
     /// the correct output_type. If None given the output type of the input expr is used.
     pub fn map<F>(self, function: F, output_type: Option<DataType>) -> Self
+    where
+        F: Fn(Series) -> Result<Series> + 'static + Send + Sync,
+    {
+        self.map_with_output(function, output_type.into())
+    }
+
+    /// Like [map](Self::map), but the output dtype may depend on the dtype of the input,
+    /// e.g. [`GetOutput::map_dtype`].
+    pub fn map_with_output<F>(self, function: F, output_type: GetOutput) -> Self
     where
         F: Fn(Series) -> Result<Series> + 'static + Send + Sync,
     {
@@ -743,6 +933,15 @@ impl Expr {
     /// * `map` should be used for operations that are independent of groups, e.g. `multiply * 2`, or `raise to the power`
     /// * `apply` should be used for operations that work on a group of data. e.g. `sum`, `count`, etc.
     pub fn apply<F>(self, function: F, output_type: Option<DataType>) -> Self
+    where
+        F: Fn(Series) -> Result<Series> + 'static + Send + Sync,
+    {
+        self.apply_with_output(function, output_type.into())
+    }
+
+    /// Like [apply](Self::apply), but the output dtype may depend on the dtype of the input,
+    /// e.g. [`GetOutput::map_dtype`].
+    pub fn apply_with_output<F>(self, function: F, output_type: GetOutput) -> Self
     where
         F: Fn(Series) -> Result<Series> + 'static + Send + Sync,
     {
@@ -848,10 +1047,164 @@ impl Expr {
         self.apply(move |s: Series| Ok(s.cum_max(reverse)), None)
     }
 
+    /// Get an array with the cumulative product computed at every element.
+    /// Integers are accumulated in `Int64` and `Float32` in `Float64`.
+    pub fn cum_prod(self, reverse: bool) -> Self {
+        self.apply_with_output(
+            move |s: Series| Ok(s.cum_prod(reverse)),
+            GetOutput::map_dtype(|dt| {
+                use DataType::*;
+                Ok(match dt {
+                    Int8 | Int16 | Int32 | UInt8 | UInt16 | UInt32 | UInt64 => Int64,
+                    Float32 => Float64,
+                    dt => dt.clone(),
+                })
+            }),
+        )
+    }
+
+    /// Get the most frequent value(s). See [the eager implementation](polars_core::series::Series::mode).
+    ///
+    /// In a groupby context this yields a list of the most frequent values per group.
+    #[cfg(feature = "mode")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mode")))]
+    pub fn mode(self) -> Self {
+        self.apply(|s: Series| s.mode(), None)
+    }
+
+    /// Clip the values to the range `[min, max]`. See [the eager implementation](polars_core::series::Series::clip).
+    pub fn clip(self, min: f64, max: f64) -> Self {
+        self.apply(move |s: Series| s.clip(min, max), None)
+    }
+
+    /// Clip the values that are below `min` to `min`.
+    pub fn clip_min(self, min: f64) -> Self {
+        self.apply(move |s: Series| s.clip_min(min), None)
+    }
+
+    /// Clip the values that are above `max` to `max`.
+    pub fn clip_max(self, max: f64) -> Self {
+        self.apply(move |s: Series| s.clip_max(max), None)
+    }
+
+    fn rolling(self, function: RollingFunction, options: RollingOptions) -> Self {
+        let output_type = match function {
+            // the variance of integers is computed in Float64
+            RollingFunction::Var | RollingFunction::Std => GetOutput::map_dtype(|dt| {
+                Ok(match dt {
+                    DataType::Float32 => DataType::Float32,
+                    _ => DataType::Float64,
+                })
+            }),
+            _ => GetOutput::SameType,
+        };
+        self.apply_with_output(move |s: Series| s.rolling(function, &options), output_type)
+    }
+
+    /// Apply a rolling mean. See [the eager implementation](polars_core::series::Series::rolling).
+    pub fn rolling_mean(self, options: RollingOptions) -> Self {
+        self.rolling(RollingFunction::Mean, options)
+    }
+
+    /// Apply a rolling sum. See [the eager implementation](polars_core::series::Series::rolling).
+    pub fn rolling_sum(self, options: RollingOptions) -> Self {
+        self.rolling(RollingFunction::Sum, options)
+    }
+
+    /// Apply a rolling min. See [the eager implementation](polars_core::series::Series::rolling).
+    pub fn rolling_min(self, options: RollingOptions) -> Self {
+        self.rolling(RollingFunction::Min, options)
+    }
+
+    /// Apply a rolling max. See [the eager implementation](polars_core::series::Series::rolling).
+    pub fn rolling_max(self, options: RollingOptions) -> Self {
+        self.rolling(RollingFunction::Max, options)
+    }
+
+    /// Apply a rolling variance. See [the eager implementation](polars_core::series::Series::rolling).
+    pub fn rolling_var(self, options: RollingOptions) -> Self {
+        self.rolling(RollingFunction::Var, options)
+    }
+
+    /// Apply a rolling standard deviation. See [the eager implementation](polars_core::series::Series::rolling).
+    pub fn rolling_std(self, options: RollingOptions) -> Self {
+        self.rolling(RollingFunction::Std, options)
+    }
+
+    /// Exponentially weighted moving mean. See [the eager implementation](polars_core::series::Series::ewm_mean).
+    #[cfg(feature = "ewma")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ewma")))]
+    pub fn ewm_mean(self, options: EwmOptions) -> Self {
+        self.apply(
+            move |s: Series| s.ewm_mean(&options),
+            Some(DataType::Float64),
+        )
+    }
+
+    /// Exponentially weighted moving standard deviation. See [the eager implementation](polars_core::series::Series::ewm_std).
+    #[cfg(feature = "ewma")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ewma")))]
+    pub fn ewm_std(self, options: EwmOptions) -> Self {
+        self.apply(
+            move |s: Series| s.ewm_std(&options),
+            Some(DataType::Float64),
+        )
+    }
+
+    /// Exponentially weighted moving variance. See [the eager implementation](polars_core::series::Series::ewm_var).
+    #[cfg(feature = "ewma")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ewma")))]
+    pub fn ewm_var(self, options: EwmOptions) -> Self {
+        self.apply(
+            move |s: Series| s.ewm_var(&options),
+            Some(DataType::Float64),
+        )
+    }
+
+    /// Assign a rank to every value. See [the eager implementation](polars_core::series::Series::rank).
+    ///
+    /// In a window expression the values are ranked per group: `col("x").rank(options).over(vec![col("group")])`.
+    #[cfg(feature = "rank")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rank")))]
+    pub fn rank(self, options: RankOptions) -> Self {
+        let output_type = match options.method {
+            RankMethod::Average => DataType::Float64,
+            _ => DataType::UInt32,
+        };
+        self.apply(move |s: Series| Ok(s.rank(options)), Some(output_type))
+    }
+
+    /// Fill the interior null values by interpolation. See [the eager implementation](polars_core::series::Series::interpolate).
+    #[cfg(feature = "interpolate")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "interpolate")))]
+    pub fn interpolate(self, method: InterpolationMethod) -> Self {
+        self.apply_with_output(
+            move |s: Series| s.interpolate(method),
+            GetOutput::map_dtype(move |dtype| method.output_dtype(dtype)),
+        )
+    }
+
+    /// Compute the difference between every value and the value `n` rows before it. See [the eager implementation](polars_core::series::Series::diff).
+    #[cfg(feature = "diff")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "diff")))]
+    pub fn diff(self, n: usize, null_behavior: NullBehavior) -> Self {
+        self.apply(move |s: Series| s.diff(n, null_behavior), None)
+    }
+
+    /// Compute the fractional change between every value and the value `n` rows before it. See [the eager implementation](polars_core::series::Series::pct_change).
+    #[cfg(feature = "pct_change")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pct_change")))]
+    pub fn pct_change(self, n: usize) -> Self {
+        self.apply(move |s: Series| s.pct_change(n), Some(DataType::Float64))
+    }
+
     /// Apply window function over a subgroup.
     /// This is similar to a groupby + aggregation + self join.
     /// Or similar to [window functions in Postgres](https://www.postgresql.org/docs/9.1/tutorial-window.html).
     ///
+    /// `partition_by` can be a column name, a slice of column names, an expression or a `Vec` of
+    /// expressions.
+    ///
     /// # Example
     ///
     /// ``` rust
@@ -907,10 +1260,10 @@ impl Expr {
     /// │ 1      ┆ 16     │
     /// ╰────────┴────────╯
     /// ```
-    pub fn over(self, partition_by: Vec<Expr>) -> Self {
+    pub fn over<E: IntoVec<Expr>>(self, partition_by: E) -> Self {
         Expr::Window {
             function: Box::new(self),
-            partition_by,
+            partition_by: partition_by.into_vec(),
             order_by: None,
         }
     }
@@ -923,6 +1276,25 @@ impl Expr {
             .otherwise(col(&*name))
             .alias(&*name)
     }
+
+    /// Replace the null values according to a [`FillNoneStrategy`]. In a groupby context the
+    /// values are filled per group.
+    pub fn fill_none_strategy(self, strategy: FillNoneStrategy) -> Self {
+        self.apply(move |s: Series| s.fill_none(strategy), None)
+    }
+
+    /// Replace the null values with the previous non-null value. At most `limit` consecutive
+    /// null values are filled.
+    pub fn forward_fill(self, limit: Option<u32>) -> Self {
+        self.fill_none_strategy(FillNoneStrategy::Forward(limit))
+    }
+
+    /// Replace the null values with the next non-null value. At most `limit` consecutive
+    /// null values are filled.
+    pub fn backward_fill(self, limit: Option<u32>) -> Self {
+        self.fill_none_strategy(FillNoneStrategy::Backward(limit))
+    }
+
     /// Count the values of the Series
     /// or
     /// Get counts of the group by operation.
@@ -980,6 +1352,25 @@ impl Expr {
         }
     }
 
+    /// Check if the values lie between `low` and `high`. `closed` determines if the boundaries
+    /// are part of the interval. See [the eager implementation](polars_core::series::Series::is_between).
+    #[allow(clippy::wrong_self_convention)]
+    #[cfg(feature = "is_between")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "is_between")))]
+    pub fn is_between(self, low: Expr, high: Expr, closed: ClosedWindow) -> Self {
+        let function = NoEq::new(Arc::new(move |s: &mut [Series]| {
+            s[0].is_between(&s[1], &s[2], closed)
+                .map(|ca| ca.into_series())
+        }) as Arc<dyn SeriesUdf>);
+
+        Expr::Function {
+            input: vec![self, low, high],
+            function,
+            output_type: GetOutput::DataType(DataType::Boolean),
+            collect_groups: false,
+        }
+    }
+
     /// Check if the values of the left expression are in the lists of the right expr.
     #[allow(clippy::wrong_self_convention)]
     #[cfg(feature = "is_in")]
@@ -999,6 +1390,14 @@ impl Expr {
         )
     }
 
+    /// Format a Date32/Date64/Time64 with a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    #[cfg(feature = "temporal")]
+    pub fn strftime(self, fmt: &str) -> Expr {
+        let fmt = fmt.to_string();
+        let function = move |s: Series| s.strftime(&fmt);
+        self.map(function, Some(DataType::Utf8))
+    }
+
     /// Get the year of a Date32/Date64
     #[cfg(feature = "temporal")]
     pub fn year(self) -> Expr {
@@ -1050,6 +1449,26 @@ impl Expr {
         self.map(function, Some(DataType::UInt32))
     }
 
+    /// Show the same instants of a Date64/Datetime in the time zone `tz`.
+    #[cfg(feature = "timezones")]
+    pub fn convert_time_zone(self, tz: String) -> Expr {
+        let dtype = DataType::Datetime(tz.clone());
+        let function = move |s: Series| s.convert_time_zone(&tz);
+        self.map(function, Some(dtype))
+    }
+
+    /// Keep the wall clock times of a Date64/Datetime, but set their time zone to `tz`.
+    /// If `tz` is `None` the time zone is dropped.
+    #[cfg(feature = "timezones")]
+    pub fn replace_time_zone(self, tz: Option<String>) -> Expr {
+        let dtype = match &tz {
+            Some(tz) => DataType::Datetime(tz.clone()),
+            None => DataType::Date64,
+        };
+        let function = move |s: Series| s.replace_time_zone(tz.as_deref());
+        self.map(function, Some(dtype))
+    }
+
     /// Sort this column by the ordering of another column.
     /// Can also be used in a groupby context to sort the groups.
     pub fn sort_by(self, by: Expr, reverse: bool) -> Expr {
@@ -1108,8 +1527,8 @@ pub fn median(name: &str) -> Expr {
 }
 
 /// Find a specific quantile of all the values in this Expression.
-pub fn quantile(name: &str, quantile: f64) -> Expr {
-    col(name).quantile(quantile)
+pub fn quantile(name: &str, quantile: f64, interpol: QuantileInterpolOptions) -> Expr {
+    col(name).quantile(quantile, interpol)
 }
 
 /// Apply a closure on the two columns that are evaluated from `Expr` a and `Expr` b.
@@ -1157,28 +1576,85 @@ where
     acc
 }
 
-/// Get the the sum of the values per row
+/// Accumulate over multiple columns horizontally / row wise, using the first expression as
+/// the initial value.
+///
+/// If `exprs` is empty, the expression returns an error when it is evaluated.
+pub fn reduce_exprs<F: 'static>(f: F, exprs: Vec<Expr>) -> Expr
+where
+    F: Fn(Series, Series) -> Result<Series> + Send + Sync + Copy,
+{
+    let mut iter = exprs.into_iter();
+    match iter.next() {
+        Some(acc) => fold_exprs(acc, f, iter.collect()),
+        None => lit(Null {}).map(
+            |_| {
+                Err(PolarsError::NoData(
+                    "reduce_exprs needs at least one expression".into(),
+                ))
+            },
+            None,
+        ),
+    }
+}
+
+/// Get the the sum of the values per row. Null values are ignored.
 pub fn sum_exprs(exprs: Vec<Expr>) -> Expr {
-    let func = |s1, s2| Ok(&s1 + &s2);
-    fold_exprs(lit(0), func, exprs)
+    let func = |s1: Series, s2: Series| {
+        let s1 = if s1.null_count() != 0 {
+            s1.fill_none(FillNoneStrategy::Zero)?
+        } else {
+            s1
+        };
+        let s2 = if s2.null_count() != 0 {
+            s2.fill_none(FillNoneStrategy::Zero)?
+        } else {
+            s2
+        };
+        Ok(&s1 + &s2)
+    };
+    reduce_exprs(func, exprs)
 }
 
-/// Get the the minimum value per row
+/// Get the the maximum value per row. Null values are ignored.
 pub fn max_exprs(exprs: Vec<Expr>) -> Expr {
     let func = |s1: Series, s2: Series| {
-        let mask = s1.gt(&s2);
+        let mask = s1.gt(&s2) & s1.is_not_null() | s2.is_null();
         s1.zip_with(&mask, &s2)
     };
-    fold_exprs(lit(0), func, exprs)
+    reduce_exprs(func, exprs)
 }
 
-/// Get the the minimum value per row
+/// Get the the minimum value per row. Null values are ignored.
 pub fn min_exprs(exprs: Vec<Expr>) -> Expr {
     let func = |s1: Series, s2: Series| {
-        let mask = s1.lt(&s2);
+        let mask = s1.lt(&s2) & s1.is_not_null() | s2.is_null();
         s1.zip_with(&mask, &s2)
     };
-    fold_exprs(lit(0), func, exprs)
+    reduce_exprs(func, exprs)
+}
+
+/// Get the the mean value per row. Null values are ignored.
+pub fn mean_exprs(exprs: Vec<Expr>) -> Expr {
+    let counts = exprs
+        .iter()
+        .map(|e| e.clone().is_not_null().cast(DataType::UInt32))
+        .collect();
+    let count = reduce_exprs(|s1, s2| Ok(&s1 + &s2), counts);
+
+    let func = |sum: Series, count: Series| {
+        // make sure that we do not divide by zero
+        // by replacing with None
+        let count = count.u32()?;
+        let count = count
+            .set(&count.eq(0), None)?
+            .into_series()
+            .cast::<Float64Type>()?;
+        Ok(&sum.cast::<Float64Type>()? / &count)
+    };
+    map_binary_lazy_field(sum_exprs(exprs), count, func, |_, _, fld, _| {
+        Some(Field::new(fld.name(), DataType::Float64))
+    })
 }
 
 /// Evaluate all the expressions with a bitwise or
@@ -0,0 +1,120 @@
+//! Operations on the values of List expressions.
+use crate::prelude::*;
+use polars_core::prelude::*;
+
+fn list_inner_dtype(dtype: &DataType) -> Result<DataType> {
+    match dtype {
+        DataType::List(inner) => Ok(DataType::from(inner)),
+        dtype => Err(PolarsError::DataTypeMisMatch(
+            format!("expected a list, got {:?}", dtype).into(),
+        )),
+    }
+}
+
+/// The dtype of the values of a list.
+fn inner_dtype() -> GetOutput {
+    GetOutput::map_dtype(list_inner_dtype)
+}
+
+/// The dtype of an aggregation of the values of a list. Booleans are aggregated to `UInt32`.
+fn agg_dtype() -> GetOutput {
+    GetOutput::map_dtype(|dtype| match list_inner_dtype(dtype)? {
+        DataType::Boolean => Ok(DataType::UInt32),
+        dtype => Ok(dtype),
+    })
+}
+
+/// Specialized expressions for [`Series`] of [`DataType::List`].
+pub struct ListNameSpace(pub(crate) Expr);
+
+impl ListNameSpace {
+    /// Get the number of values in every list.
+    pub fn lengths(self) -> Expr {
+        let function = |s: Series| {
+            let ca = s.list()?;
+            Ok(ca.lst_lengths().into_series())
+        };
+        self.0.map(function, Some(DataType::UInt32))
+    }
+
+    /// Get the value at position `idx` of every list. Negative indexes count from the end
+    /// of the list.
+    pub fn get(self, idx: i64) -> Expr {
+        let function = move |s: Series| {
+            let ca = s.list()?;
+            ca.lst_get(idx)
+        };
+        self.0.map_with_output(function, inner_dtype())
+    }
+
+    /// Get the first value of every list.
+    pub fn first(self) -> Expr {
+        self.get(0)
+    }
+
+    /// Get the last value of every list.
+    pub fn last(self) -> Expr {
+        self.get(-1)
+    }
+
+    /// Check if the lists contain `value`. `value` should be a [`Series`] of length 1.
+    pub fn contains(self, value: Series) -> Expr {
+        let function = move |s: Series| {
+            let ca = s.list()?;
+            ca.lst_contains(&value).map(|ca| ca.into_series())
+        };
+        self.0.map(function, Some(DataType::Boolean))
+    }
+
+    /// Get the sum of every list.
+    pub fn sum(self) -> Expr {
+        self.0.map_with_output(|s| s.list()?.lst_sum(), agg_dtype())
+    }
+
+    /// Get the mean of every list.
+    pub fn mean(self) -> Expr {
+        self.0
+            .map(|s| s.list()?.lst_mean(), Some(DataType::Float64))
+    }
+
+    /// Get the maximum of every list.
+    pub fn max(self) -> Expr {
+        self.0.map_with_output(|s| s.list()?.lst_max(), agg_dtype())
+    }
+
+    /// Get the minimum of every list.
+    pub fn min(self) -> Expr {
+        self.0.map_with_output(|s| s.list()?.lst_min(), agg_dtype())
+    }
+
+    /// Sort the values of every list.
+    pub fn sort(self, reverse: bool) -> Expr {
+        let function = move |s: Series| {
+            let ca = s.list()?;
+            Ok(ca.lst_sort(reverse).into_series())
+        };
+        self.0.map(function, None)
+    }
+
+    /// Reverse the values of every list.
+    pub fn reverse(self) -> Expr {
+        self.0
+            .map(|s| Ok(s.list()?.lst_reverse().into_series()), None)
+    }
+
+    /// Get the unique values of every list.
+    pub fn unique(self) -> Expr {
+        self.0
+            .map(|s| s.list()?.lst_unique().map(|ca| ca.into_series()), None)
+    }
+
+    /// Join the string values of every list with a `separator`.
+    pub fn join(self, separator: &str) -> Expr {
+        let separator = separator.to_string();
+        let function = move |s: Series| {
+            let ca = s.list()?;
+            ca.lst_join(&separator).map(|ca| ca.into_series())
+        };
+        self.0.map(function, Some(DataType::Utf8))
+    }
+}
@@ -0,0 +1,95 @@
+//! String operations on Utf8 expressions.
+use crate::prelude::*;
+use polars_core::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Utf8`].
+pub struct StringNameSpace(pub(crate) Expr);
+
+impl StringNameSpace {
+    /// Check if the strings contain a regex pattern.
+    pub fn contains(self, pat: &str) -> Expr {
+        let pat = pat.to_string();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            ca.contains(&pat).map(|ca| ca.into_series())
+        };
+        self.0.map(function, Some(DataType::Boolean))
+    }
+
+    /// Extract the capture group `group_index` of the first match of a regex pattern.
+    /// Group 0 is the whole match.
+    pub fn extract(self, pat: &str, group_index: usize) -> Expr {
+        let pat = pat.to_string();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            ca.extract(&pat, group_index).map(|ca| ca.into_series())
+        };
+        self.0.map(function, Some(DataType::Utf8))
+    }
+
+    /// Extract all the non-overlapping matches of a regex pattern in a list.
+    pub fn extract_all(self, pat: &str) -> Expr {
+        let pat = pat.to_string();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            ca.extract_all(&pat).map(|ca| ca.into_series())
+        };
+        self.0
+            .map(function, Some(DataType::List(DataType::Utf8.to_arrow())))
+    }
+
+    /// Replace the leftmost (sub)string by a regex pattern.
+    pub fn replace(self, pat: &str, val: &str) -> Expr {
+        let pat = pat.to_string();
+        let val = val.to_string();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            ca.replace(&pat, &val).map(|ca| ca.into_series())
+        };
+        self.0.map(function, Some(DataType::Utf8))
+    }
+
+    /// Replace all (sub)strings by a regex pattern.
+    pub fn replace_all(self, pat: &str, val: &str) -> Expr {
+        let pat = pat.to_string();
+        let val = val.to_string();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            ca.replace_all(&pat, &val).map(|ca| ca.into_series())
+        };
+        self.0.map(function, Some(DataType::Utf8))
+    }
+
+    /// Split the strings by a literal `pattern` into a list of substrings.
+    pub fn split(self, pattern: &str) -> Expr {
+        let pattern = pattern.to_string();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            Ok(ca.str_split(&pattern).into_series())
+        };
+        self.0
+            .map(function, Some(DataType::List(DataType::Utf8.to_arrow())))
+    }
+
+    /// Parse the strings to [`DataType::Date32`] or [`DataType::Date64`] with a
+    /// [chrono format string](polars_core::utils::chrono::format::strftime).
+    /// If no `fmt` is given, the format is inferred from the first non-null value.
+    #[cfg(feature = "temporal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+    pub fn strptime(self, data_type: DataType, fmt: Option<&str>) -> Expr {
+        let fmt = fmt.map(|fmt| fmt.to_string());
+        let out_type = data_type.clone();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            let fmt = fmt.as_deref();
+            match data_type {
+                DataType::Date32 => ca.as_date32(fmt).map(|ca| ca.into_series()),
+                DataType::Date64 => ca.as_date64(fmt).map(|ca| ca.into_series()),
+                _ => Err(PolarsError::InvalidOperation(
+                    format!("cannot parse strings to {:?}", data_type).into(),
+                )),
+            }
+        };
+        self.0.map(function, Some(out_type))
+    }
+}
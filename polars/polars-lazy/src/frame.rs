@@ -1,25 +1,30 @@
 //! Lazy variant of a [DataFrame](polars_core::frame::DataFrame).
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 use std::collections::HashMap;
 use std::sync::Arc;
 
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 use ahash::RandomState;
 
 use polars_core::frame::hash_join::JoinType;
 use polars_core::prelude::*;
 use polars_core::toggle_string_cache;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::logical_plan::optimizer::aggregate_pushdown::AggregatePushdown;
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 use crate::logical_plan::optimizer::aggregate_scan_projections::AggScanProjection;
+use crate::logical_plan::optimizer::join_order::JoinOrder;
 use crate::logical_plan::optimizer::simplify_expr::SimplifyExprRule;
 use crate::logical_plan::optimizer::stack_opt::{OptimizationRule, StackOptimizer};
 use crate::logical_plan::optimizer::{
     predicate_pushdown::PredicatePushDown, projection_pushdown::ProjectionPushDown,
+    slice_pushdown::SlicePushDown,
 };
+use crate::physical_plan::node_timer::NodeTimer;
 use crate::physical_plan::state::ExecutionState;
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 use crate::prelude::aggregate_scan_projections::agg_projection;
 use crate::prelude::simplify_expr::SimplifyBooleanRule;
 use crate::utils::combine_predicates_expr;
@@ -38,10 +43,13 @@ pub struct LazyCsvReader<'a> {
     schema: Option<SchemaRef>,
     schema_overwrite: Option<&'a Schema>,
     low_memory: bool,
+    row_count: Option<RowCount>,
 }
 
 #[cfg(feature = "csv-file")]
 impl<'a> LazyCsvReader<'a> {
+    /// Create a new lazy csv reader. The path may be a glob pattern such as `data/part-*.csv`,
+    /// in which case all matching files are scanned with the schema of the first file.
     pub fn new(path: String) -> Self {
         LazyCsvReader {
             path,
@@ -54,6 +62,7 @@ impl<'a> LazyCsvReader<'a> {
             schema: None,
             schema_overwrite: None,
             low_memory: false,
+            row_count: None,
         }
     }
 
@@ -113,7 +122,17 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
-    pub fn finish(self) -> LazyFrame {
+    /// Add a `UInt32` column at index 0 that counts the rows of the file. The rows are counted
+    /// during the scan, before any filters of the query are applied. If the path is a glob
+    /// pattern, the rows are counted over all files.
+    pub fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.row_count = row_count;
+        self
+    }
+
+    /// Build the scan. This fails if a file can't be opened, if its schema can't be inferred,
+    /// or if the files matched by a glob pattern don't share a schema.
+    pub fn finish(self) -> Result<LazyFrame> {
         let mut lf: LazyFrame = LogicalPlanBuilder::scan_csv(
             self.path,
             self.delimiter,
@@ -125,11 +144,12 @@ impl<'a> LazyCsvReader<'a> {
             self.schema,
             self.schema_overwrite,
             self.low_memory,
-        )
+            self.row_count,
+        )?
         .build()
         .into();
         lf.opt_state.agg_scan_projection = true;
-        lf
+        Ok(lf)
     }
 }
 
@@ -186,11 +206,43 @@ impl From<LogicalPlan> for LazyFrame {
     }
 }
 
+/// A `LazyFrame` is serialized as its logical plan, so that a query can be built in one place
+/// and executed in another. The optimization state is not serialized; a deserialized
+/// `LazyFrame` uses the default optimizations.
+///
+/// Queries with closures fail to serialize. These are not only the functions passed to e.g.
+/// `map` or `apply`, but also the expressions of the `str` and `arr` namespaces and other
+/// expressions that are implemented as a function on the Series, such as `year` or `is_nan`.
+/// Caches get fresh ids when they are deserialized.
+#[cfg(feature = "serialize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+impl Serialize for LazyFrame {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.logical_plan.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+impl<'de> Deserialize<'de> for LazyFrame {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::logical_plan::with_fresh_cache_ids(|| LogicalPlan::deserialize(deserializer))
+            .map(LazyFrame::from)
+    }
+}
+
 #[derive(Copy, Clone)]
 /// State of the allowed optimizations
 pub struct OptState {
     pub projection_pushdown: bool,
     pub predicate_pushdown: bool,
+    pub slice_pushdown: bool,
     pub type_coercion: bool,
     pub simplify_expr: bool,
     /// Make sure that all needed columns are scannedn
@@ -198,6 +250,11 @@ pub struct OptState {
     pub aggregate_pushdown: bool,
     pub global_string_cache: bool,
     pub join_pruning: bool,
+    /// Reorder chains of inner joins based on the estimated sizes of their inputs. Off by
+    /// default, as the estimates can be far off for filtered or aggregated inputs.
+    pub join_order: bool,
+    /// Compute sub-expressions that occur multiple times in a projection only once
+    pub common_subexpr_elim: bool,
 }
 
 impl Default for OptState {
@@ -205,10 +262,13 @@ impl Default for OptState {
         OptState {
             projection_pushdown: true,
             predicate_pushdown: true,
+            slice_pushdown: true,
             type_coercion: true,
             simplify_expr: true,
             global_string_cache: true,
             join_pruning: true,
+            join_order: false,
+            common_subexpr_elim: true,
             // will be toggled by a scan operation such as csv scan or parquet scan
             agg_scan_projection: false,
             aggregate_pushdown: false,
@@ -221,16 +281,67 @@ pub type AllowedOptimizations = OptState;
 
 impl LazyFrame {
     /// Create a LazyFrame directly from a parquet scan.
+    ///
+    /// The path may be a glob pattern such as `data/part-*.parquet`. All matching files are
+    /// scanned and concatenated; they must have the same schema, otherwise an error naming the
+    /// first mismatching file is returned.
     #[cfg(feature = "parquet")]
-    pub fn new_from_parquet(path: String, stop_after_n_rows: Option<usize>, cache: bool) -> Self {
-        let mut lf: LazyFrame = LogicalPlanBuilder::scan_parquet(path, stop_after_n_rows, cache)
-            .build()
-            .into();
+    pub fn new_from_parquet(
+        path: String,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+    ) -> Result<Self> {
+        Self::new_from_parquet_with_row_count(path, stop_after_n_rows, cache, None)
+    }
+
+    /// Create a LazyFrame from a parquet scan that adds a row count column at index 0. With a
+    /// glob pattern the rows are counted over all matching files.
+    #[cfg(feature = "parquet")]
+    pub fn new_from_parquet_with_row_count(
+        path: String,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+        row_count: Option<RowCount>,
+    ) -> Result<Self> {
+        let mut lf: LazyFrame =
+            LogicalPlanBuilder::scan_parquet(path, stop_after_n_rows, cache, row_count)?
+                .build()
+                .into();
+        lf.opt_state.agg_scan_projection = true;
+        Ok(lf)
+    }
+
+    /// Create a LazyFrame directly from an Arrow IPC file scan.
+    ///
+    /// Only the columns needed by the query are kept when the record batches are read. As with
+    /// [new_from_parquet](LazyFrame::new_from_parquet), `path` may be a glob pattern.
+    #[cfg(feature = "ipc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
+    pub fn scan_ipc(path: String, stop_after_n_rows: Option<usize>, cache: bool) -> Result<Self> {
+        Self::scan_ipc_with_row_count(path, stop_after_n_rows, cache, None)
+    }
+
+    /// Create a LazyFrame from an Arrow IPC file scan that adds a row count column at index 0.
+    /// With a glob pattern the rows are counted over all matching files.
+    #[cfg(feature = "ipc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
+    pub fn scan_ipc_with_row_count(
+        path: String,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+        row_count: Option<RowCount>,
+    ) -> Result<Self> {
+        let mut lf: LazyFrame =
+            LogicalPlanBuilder::scan_ipc(path, stop_after_n_rows, cache, row_count)?
+                .build()
+                .into();
         lf.opt_state.agg_scan_projection = true;
-        lf
+        Ok(lf)
     }
 
-    /// Get a dot language representation of the LogicalPlan.
+    /// Get a dot language representation of the LogicalPlan. If `optimized`, the plan is
+    /// optimized first. The node labels show the projected expressions and predicates.
+    /// The output can be rendered with Graphviz, e.g. `dot -Tsvg plan.dot > plan.svg`.
     pub fn to_dot(&self, optimized: bool) -> Result<String> {
         let mut s = String::with_capacity(512);
 
@@ -284,6 +395,13 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle slice pushdown optimization. If enabled, slices and limits are pushed down to the
+    /// scans, so that file readers can stop early.
+    pub fn with_slice_pushdown(mut self, toggle: bool) -> Self {
+        self.opt_state.slice_pushdown = toggle;
+        self
+    }
+
     /// Toggle type coercion optimization.
     pub fn with_type_coercion(mut self, toggle: bool) -> Self {
         self.opt_state.type_coercion = toggle;
@@ -296,6 +414,13 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle common subexpression elimination. If enabled, sub-expressions that occur
+    /// multiple times in a `select` or `with_columns` are only computed once.
+    pub fn with_common_subexpr_elim(mut self, toggle: bool) -> Self {
+        self.opt_state.common_subexpr_elim = toggle;
+        self
+    }
+
     /// Toggle aggregate pushdown.
     pub fn with_aggregate_pushdown(mut self, toggle: bool) -> Self {
         self.opt_state.aggregate_pushdown = toggle;
@@ -314,6 +439,13 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle join order optimization, which is off by default. Chains of inner joins are
+    /// reordered so that the input that is estimated to be the smallest is joined first.
+    pub fn with_join_order(mut self, toggle: bool) -> Self {
+        self.opt_state.join_order = toggle;
+        self
+    }
+
     /// Describe the logical plan.
     pub fn describe_plan(&self) -> String {
         self.logical_plan.describe()
@@ -346,12 +478,14 @@ impl LazyFrame {
         let opt_state = self.get_opt_state();
         let lp = self
             .get_plan_builder()
-            .sort(vec![col(by_column)], vec![reverse])
+            .sort(vec![col(by_column)], vec![reverse], false)
             .build();
         Self::from_logical_plan(lp, opt_state)
     }
 
-    /// Add a sort operation to the logical plan.
+    /// Add a sort operation to the logical plan. Every expression in `by_exprs` gets its own
+    /// `reverse` flag. If `nulls_last` is set, null values are placed at the end regardless
+    /// of the sort direction.
     ///
     /// # Example
     ///
@@ -362,12 +496,15 @@ impl LazyFrame {
     /// /// Sort DataFrame by 'sepal.width' column
     /// fn example(df: DataFrame) -> LazyFrame {
     ///       df.lazy()
-    ///         .sort_by_exprs(vec![col("sepal.width")], vec![false])
+    ///         .sort_by_exprs(vec![col("sepal.width")], vec![false], false)
     /// }
     /// ```
-    pub fn sort_by_exprs(self, by_exprs: Vec<Expr>, reverse: Vec<bool>) -> Self {
+    pub fn sort_by_exprs(self, by_exprs: Vec<Expr>, reverse: Vec<bool>, nulls_last: bool) -> Self {
         let opt_state = self.get_opt_state();
-        let lp = self.get_plan_builder().sort(by_exprs, reverse).build();
+        let lp = self
+            .get_plan_builder()
+            .sort(by_exprs, reverse, nulls_last)
+            .build();
         Self::from_logical_plan(lp, opt_state)
     }
 
@@ -431,7 +568,11 @@ impl LazyFrame {
     }
 
     /// Caches the result into a new LazyFrame. This should be used to prevent computations
-    /// running multiple times
+    /// running multiple times, e.g. if a `LazyFrame` is the input of multiple joins.
+    ///
+    /// All clones of the returned `LazyFrame` share the cache, so the cached branch is
+    /// computed only once per query. Projections and predicates are not pushed down
+    /// past the cache.
     pub fn cache(self) -> Self {
         let opt_state = self.get_opt_state();
         let lp = self.get_plan_builder().cache().build();
@@ -444,6 +585,22 @@ impl LazyFrame {
     /// Note that the fetch does not guarantee the final number of rows in the DataFrame.
     /// Filter, join operations and a lower number of rows available in the scanned file influence
     /// the final number of rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// /// Check the schema of the output on the first 100 rows of the input.
+    /// fn example(df: DataFrame) -> Result<Schema> {
+    ///       let out = df.lazy()
+    ///         .groupby(vec![col("foo")])
+    ///         .agg(vec![col("bar").sum()])
+    ///         .fetch(100)?;
+    ///       Ok(out.schema())
+    /// }
+    /// ```
     pub fn fetch(self, n_rows: usize) -> Result<DataFrame> {
         FETCH_ROWS.with(|fetch_rows| fetch_rows.set(Some(n_rows)));
         let res = self.collect();
@@ -459,10 +616,12 @@ impl LazyFrame {
         // get toggle values
         let predicate_pushdown = self.opt_state.predicate_pushdown;
         let projection_pushdown = self.opt_state.projection_pushdown;
+        let slice_pushdown = self.opt_state.slice_pushdown;
         let type_coercion = self.opt_state.type_coercion;
         let simplify_expr = self.opt_state.simplify_expr;
+        let join_order = self.opt_state.join_order;
 
-        #[cfg(any(feature = "parquet", feature = "csv-file"))]
+        #[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
         let agg_scan_projection = self.opt_state.agg_scan_projection;
         let aggregate_pushdown = self.opt_state.aggregate_pushdown;
 
@@ -473,6 +632,7 @@ impl LazyFrame {
 
         let predicate_pushdown_opt = PredicatePushDown::default();
         let projection_pushdown_opt = ProjectionPushDown {};
+        let slice_pushdown_opt = SlicePushDown {};
 
         // during debug we check if the optimizations have not modified the final schema
         #[cfg(debug_assertions)]
@@ -496,6 +656,14 @@ impl LazyFrame {
             lp_arena.replace(lp_top, alp);
         }
 
+        if slice_pushdown {
+            let alp = lp_arena.take(lp_top);
+            let alp = slice_pushdown_opt
+                .optimize(alp, lp_arena, expr_arena)
+                .expect("slice pushdown failed");
+            lp_arena.replace(lp_top, alp);
+        }
+
         if type_coercion {
             rules.push(Box::new(TypeCoercionRule {}))
         }
@@ -507,8 +675,11 @@ impl LazyFrame {
         if aggregate_pushdown {
             rules.push(Box::new(AggregatePushdown::new()))
         }
+        if join_order {
+            rules.push(Box::new(JoinOrder {}))
+        }
 
-        #[cfg(any(feature = "parquet", feature = "csv-file"))]
+        #[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
         if agg_scan_projection {
             // scan the LP to aggregate all the column used in scans
             // these columns will be added to the state of the AggScanProjection rule
@@ -564,6 +735,7 @@ impl LazyFrame {
     /// ```
     pub fn collect(self) -> Result<DataFrame> {
         let use_string_cache = self.opt_state.global_string_cache;
+        let common_subexpr_elim = self.opt_state.common_subexpr_elim;
         let mut expr_arena = Arena::with_capacity(256);
         let mut lp_arena = Arena::with_capacity(128);
         let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
@@ -572,7 +744,7 @@ impl LazyFrame {
         if use_string_cache {
             toggle_string_cache(use_string_cache);
         }
-        let planner = DefaultPlanner::default();
+        let planner = DefaultPlanner::new(common_subexpr_elim);
         let mut physical_plan =
             planner.create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
 
@@ -584,6 +756,205 @@ impl LazyFrame {
         out
     }
 
+    /// Execute the query and record how long every node of the plan takes.
+    ///
+    /// Returns the result of the query and a DataFrame with a row per node. The `start` and `end`
+    /// columns are the microseconds since the start of the query at which the node started and
+    /// finished executing. A node first executes its inputs, so its interval contains theirs;
+    /// the time spent in the node itself is the time after its inputs end.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(left: DataFrame, right: DataFrame) -> Result<()> {
+    ///     let (_out, timings) = left
+    ///         .lazy()
+    ///         .inner_join(right.lazy(), col("foo"), col("foo"))
+    ///         .profile()?;
+    ///     println!("{:?}", timings);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn profile(self) -> Result<(DataFrame, DataFrame)> {
+        let use_string_cache = self.opt_state.global_string_cache;
+        let common_subexpr_elim = self.opt_state.common_subexpr_elim;
+        let mut expr_arena = Arena::with_capacity(256);
+        let mut lp_arena = Arena::with_capacity(128);
+        let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
+
+        if use_string_cache {
+            toggle_string_cache(use_string_cache);
+        }
+        let planner = DefaultPlanner::new(common_subexpr_elim).with_profile(true);
+        let mut physical_plan =
+            planner.create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
+
+        let mut state = ExecutionState::new();
+        let timer = NodeTimer::new();
+        state.node_timer = Some(timer.clone());
+        let out = physical_plan.execute(&state);
+        if use_string_cache {
+            toggle_string_cache(!use_string_cache);
+        }
+        Ok((out?, timer.finish()?))
+    }
+
+    /// Execute the query while only keeping batches of `batch_size` rows of the input in memory.
+    ///
+    /// The part of the query that scans a csv, parquet or IPC file or `DataFrame` and applies row by row
+    /// operations (e.g. filters, projections and arithmetic) is executed batch by batch. If that
+    /// part is followed by a groupby with `sum`, `min`, `max`, `mean`, `first`, `last` or `count`
+    /// aggregations on columns, the aggregation is computed per batch and the partial results are
+    /// combined, so only the groups are kept in memory. Everything that follows is executed on the
+    /// (much smaller) result as usual.
+    ///
+    /// Queries that cannot be executed in batches fall back to materializing the full input.
+    /// Set `POLARS_VERBOSE` to get notified of this fallback.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example() -> Result<DataFrame> {
+    ///     LazyCsvReader::new("larger_than_memory.csv".into())
+    ///         .finish()?
+    ///         .filter(col("calories").gt(lit(100)))
+    ///         .groupby(vec![col("category")])
+    ///         .agg(vec![col("calories").mean()])
+    ///         .collect_streaming(100_000)
+    /// }
+    /// ```
+    pub fn collect_streaming(self, batch_size: usize) -> Result<DataFrame> {
+        let use_string_cache = self.opt_state.global_string_cache;
+        let common_subexpr_elim = self.opt_state.common_subexpr_elim;
+        let mut expr_arena = Arena::with_capacity(256);
+        let mut lp_arena = Arena::with_capacity(128);
+        let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
+
+        if use_string_cache {
+            toggle_string_cache(use_string_cache);
+        }
+        let planner = DefaultPlanner::new(common_subexpr_elim);
+        let out = crate::physical_plan::streaming::collect_streaming(
+            lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+            &planner,
+            batch_size,
+        );
+        if use_string_cache {
+            toggle_string_cache(!use_string_cache);
+        }
+        out
+    }
+
+    /// Execute the query and write the result to a parquet file at `path`.
+    ///
+    /// If the query only consists of row by row operations (e.g. filters, projections and
+    /// arithmetic) on an in memory `DataFrame` or a csv, parquet or IPC file, the query is executed in batches of
+    /// `options.row_group_size` rows and every batch is written as a row group as soon as it is
+    /// produced, so the full result is never materialized. Other queries, for instance ones that
+    /// sort, aggregate or join, fall back to collecting the result before writing it.
+    /// Set `POLARS_VERBOSE` to get notified of this fallback.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> Result<()> {
+    ///       df.lazy()
+    ///         .filter(col("sepal.width").is_not_null())
+    ///         .sink_parquet("out.parquet", ParquetWriteOptions::default())
+    /// }
+    /// ```
+    #[cfg(feature = "parquet")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
+    pub fn sink_parquet<P: AsRef<std::path::Path>>(
+        self,
+        path: P,
+        options: ParquetWriteOptions,
+    ) -> Result<()> {
+        let use_string_cache = self.opt_state.global_string_cache;
+        let common_subexpr_elim = self.opt_state.common_subexpr_elim;
+        let mut expr_arena = Arena::with_capacity(256);
+        let mut lp_arena = Arena::with_capacity(128);
+        let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
+
+        if use_string_cache {
+            toggle_string_cache(use_string_cache);
+        }
+        let planner = DefaultPlanner::new(common_subexpr_elim);
+        let out = crate::physical_plan::sink::sink_parquet(
+            lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+            &planner,
+            path,
+            options,
+        );
+        if use_string_cache {
+            toggle_string_cache(!use_string_cache);
+        }
+        out
+    }
+
+    /// Execute the query and write the result to a csv file at `path`.
+    ///
+    /// Like [LazyFrame::sink_parquet], queries that only consist of row by row operations on an
+    /// in memory `DataFrame` or a csv, parquet or IPC file are executed in batches of `options.batch_size` rows
+    /// and every batch is appended to the file as soon as it is produced. Other queries fall back
+    /// to collecting the result before writing it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> Result<()> {
+    ///       df.lazy()
+    ///         .filter(col("sepal.width").is_not_null())
+    ///         .sink_csv("out.csv", CsvWriteOptions::default())
+    /// }
+    /// ```
+    #[cfg(feature = "csv-file")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "csv-file")))]
+    pub fn sink_csv<P: AsRef<std::path::Path>>(
+        self,
+        path: P,
+        options: CsvWriteOptions,
+    ) -> Result<()> {
+        let use_string_cache = self.opt_state.global_string_cache;
+        let common_subexpr_elim = self.opt_state.common_subexpr_elim;
+        let mut expr_arena = Arena::with_capacity(256);
+        let mut lp_arena = Arena::with_capacity(128);
+        let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
+
+        if use_string_cache {
+            toggle_string_cache(use_string_cache);
+        }
+        let planner = DefaultPlanner::new(common_subexpr_elim);
+        let out = crate::physical_plan::sink::sink_csv(
+            lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+            &planner,
+            path,
+            options,
+        );
+        if use_string_cache {
+            toggle_string_cache(!use_string_cache);
+        }
+        out
+    }
+
     /// Filter by some predicate expression.
     ///
     /// # Example
@@ -661,7 +1032,7 @@ impl LazyFrame {
     ///        .agg(vec![
     ///            col("rain").min(),
     ///            col("rain").sum(),
-    ///            col("rain").quantile(0.5).alias("median_rain"),
+    ///            col("rain").quantile(0.5, QuantileInterpolOptions::Nearest).alias("median_rain"),
     ///        ])
     ///        .sort("date", false)
     /// }
@@ -672,6 +1043,80 @@ impl LazyFrame {
             logical_plan: self.logical_plan,
             opt_state,
             keys: by,
+            options: GroupbyOptions::default(),
+        }
+    }
+
+    /// Group by (possibly overlapping) time windows over the `index_column` of the options and
+    /// optionally by the `by` expressions. The windows are the keys of the aggregation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_core::utils::chrono::Duration;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///     // sum of the last 3 hours, computed every hour
+    ///     let options = DynamicGroupOptions {
+    ///         period: Duration::hours(3),
+    ///         ..DynamicGroupOptions::new("time", Duration::hours(1))
+    ///     };
+    ///     df.lazy()
+    ///         .groupby_dynamic(vec![], options)
+    ///         .agg(vec![col("rain").sum()])
+    /// }
+    /// ```
+    #[cfg(feature = "dynamic_groupby")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "dynamic_groupby")))]
+    // the other options only exist with their features
+    #[allow(clippy::needless_update)]
+    pub fn groupby_dynamic(self, by: Vec<Expr>, options: DynamicGroupOptions) -> LazyGroupBy {
+        let opt_state = self.get_opt_state();
+        LazyGroupBy {
+            logical_plan: self.logical_plan,
+            opt_state,
+            keys: by,
+            options: GroupbyOptions {
+                dynamic: Some(options),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Group by a window per row that looks back over the `index_column` of the options and
+    /// optionally by the `by` expressions. The `index_column` is a key of the aggregation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_core::utils::chrono::Duration;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///     // sum of the last 3 days as of every row
+    ///     let period = RollingPeriod::Duration(Duration::days(3));
+    ///     df.lazy()
+    ///         .groupby_rolling(vec![], RollingGroupOptions::new("date", period))
+    ///         .agg(vec![col("rain").sum()])
+    /// }
+    /// ```
+    #[cfg(feature = "rolling_groupby")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rolling_groupby")))]
+    // the other options only exist with their features
+    #[allow(clippy::needless_update)]
+    pub fn groupby_rolling(self, by: Vec<Expr>, options: RollingGroupOptions) -> LazyGroupBy {
+        let opt_state = self.get_opt_state();
+        LazyGroupBy {
+            logical_plan: self.logical_plan,
+            opt_state,
+            keys: by,
+            options: GroupbyOptions {
+                rolling: Some(options),
+                ..Default::default()
+            },
         }
     }
 
@@ -723,6 +1168,105 @@ impl LazyFrame {
         self.join(other, vec![left_on], vec![right_on], JoinType::Inner)
     }
 
+    /// Keep the rows that have a match in another lazy query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn known_customers(orders: LazyFrame, customers: LazyFrame) -> LazyFrame {
+    ///         orders
+    ///         .semi_join(customers, col("customer_id"), col("id"))
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "semi_anti_join")))]
+    pub fn semi_join(self, other: LazyFrame, left_on: Expr, right_on: Expr) -> LazyFrame {
+        self.join(other, vec![left_on], vec![right_on], JoinType::Semi)
+    }
+
+    /// Keep the rows that don't have a match in another lazy query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn unknown_customers(orders: LazyFrame, customers: LazyFrame) -> LazyFrame {
+    ///         orders
+    ///         .anti_join(customers, col("customer_id"), col("id"))
+    /// }
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "semi_anti_join")))]
+    pub fn anti_join(self, other: LazyFrame, left_on: Expr, right_on: Expr) -> LazyFrame {
+        self.join(other, vec![left_on], vec![right_on], JoinType::Anti)
+    }
+
+    /// Create the cartesian product of this query and another lazy query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn all_combinations(sizes: LazyFrame, colors: LazyFrame) -> LazyFrame {
+    ///         sizes
+    ///         .cross_join(colors)
+    ///         .filter(col("size").neq(lit("xl")))
+    /// }
+    /// ```
+    #[cfg(feature = "cross_join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cross_join")))]
+    pub fn cross_join(self, other: LazyFrame) -> LazyFrame {
+        self.join(other, vec![], vec![], JoinType::Cross)
+    }
+
+    /// As-of join query with other lazy query. Every row is matched with the row of `other`
+    /// that has the nearest key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn join_dataframes(quotes: LazyFrame, trades: LazyFrame) -> LazyFrame {
+    ///         quotes
+    ///         .join_asof(trades, col("time"), col("time"), AsOfOptions::default())
+    /// }
+    /// ```
+    #[cfg(feature = "asof_join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "asof_join")))]
+    pub fn join_asof(
+        self,
+        other: LazyFrame,
+        left_on: Expr,
+        right_on: Expr,
+        options: AsOfOptions,
+    ) -> LazyFrame {
+        self.join_asof_by(other, left_on, right_on, vec![], vec![], options)
+    }
+
+    /// As-of join query with other lazy query, where rows only match if the `by` columns
+    /// are equal.
+    #[cfg(feature = "asof_join")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "asof_join")))]
+    pub fn join_asof_by(
+        self,
+        other: LazyFrame,
+        left_on: Expr,
+        right_on: Expr,
+        left_by: Vec<Expr>,
+        right_by: Vec<Expr>,
+        options: AsOfOptions,
+    ) -> LazyFrame {
+        // the by columns are passed as additional join columns
+        let left_on = std::iter::once(left_on).chain(left_by).collect();
+        let right_on = std::iter::once(right_on).chain(right_by).collect();
+        self.join(other, left_on, right_on, JoinType::AsOf(options))
+    }
+
     /// Generic join function that can join on multiple columns.
     ///
     /// # Example
@@ -824,8 +1368,8 @@ impl LazyFrame {
     }
 
     /// Aggregate all the columns as their quantile values.
-    pub fn quantile(self, quantile: f64) -> LazyFrame {
-        self.select_local(vec![col("*").quantile(quantile)])
+    pub fn quantile(self, quantile: f64, interpol: QuantileInterpolOptions) -> LazyFrame {
+        self.select_local(vec![col("*").quantile(quantile, interpol)])
     }
 
     /// Aggregate all the columns as their standard deviation values.
@@ -857,18 +1401,24 @@ impl LazyFrame {
     }
 
     /// Drop duplicate rows. [See eager](polars_core::prelude::DataFrame::drop_duplicates).
-    pub fn drop_duplicates(self, maintain_order: bool, subset: Option<Vec<String>>) -> LazyFrame {
+    pub fn drop_duplicates(
+        self,
+        maintain_order: bool,
+        subset: Option<Vec<String>>,
+        keep: UniqueKeepStrategy,
+    ) -> LazyFrame {
         let opt_state = self.get_opt_state();
         let lp = self
             .get_plan_builder()
-            .drop_duplicates(maintain_order, subset)
+            .drop_duplicates(maintain_order, subset, keep)
             .build();
         Self::from_logical_plan(lp, opt_state)
     }
 
-    /// Drop null rows.
+    /// Drop null rows. If a `subset` is given, only the nulls in those expressions are considered.
     ///
-    /// Equal to `LazyFrame::filter(col("*").is_not_null())`
+    /// Equal to `LazyFrame::filter(col("*").is_not_null())`, so the predicate is pushed down
+    /// like any other filter.
     pub fn drop_nulls(self, subset: Option<Vec<Expr>>) -> LazyFrame {
         match subset {
             None => self.filter(col("*").is_not_null()),
@@ -903,7 +1453,9 @@ impl LazyFrame {
         self.slice(neg_tail, n)
     }
 
-    /// Melt the DataFrame from wide to long format
+    /// Melt the DataFrame from wide to long format.
+    /// If `value_vars` is empty, all the columns that are not in `id_vars` are melted.
+    /// [See eager](polars_core::prelude::DataFrame::melt).
     pub fn melt(self, id_vars: Vec<String>, value_vars: Vec<String>) -> LazyFrame {
         let opt_state = self.get_opt_state();
         let lp = self
@@ -919,10 +1471,22 @@ impl LazyFrame {
         self.slice(0, n)
     }
 
-    /// Apply a function/closure once the logical plan get executed.
+    /// Add a `UInt32` column at index 0 that counts the rows, starting at `offset` (default 0).
+    /// [See eager](polars_core::prelude::DataFrame::with_row_count).
     ///
-    /// ## Warning
-    /// This can blow up in your face if the schema is changed due to the operation. The optimizer
+    /// The rows are counted before the filters that follow in the query are applied. Use the
+    /// row count option of a scan, such as `LazyCsvReader::with_row_count`, to count the rows
+    /// while the file is read.
+    pub fn with_row_count(self, name: &str, offset: Option<u32>) -> LazyFrame {
+        let opt_state = self.get_opt_state();
+        let lp = self.get_plan_builder().with_row_count(name, offset).build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
+    /// Apply a function/closure once the logical plan get executed.
+    ///
+    /// ## Warning
+    /// This can blow up in your face if the schema is changed due to the operation. The optimizer
     /// relies on a correct schema.
     ///
     /// You can toggle certain optimizations off.
@@ -953,6 +1517,7 @@ pub struct LazyGroupBy {
     pub(crate) logical_plan: LogicalPlan,
     opt_state: OptState,
     keys: Vec<Expr>,
+    options: GroupbyOptions,
 }
 
 impl LazyGroupBy {
@@ -973,27 +1538,60 @@ impl LazyGroupBy {
     ///        .agg(vec![
     ///            col("rain").min(),
     ///            col("rain").sum(),
-    ///            col("rain").quantile(0.5).alias("median_rain"),
+    ///            col("rain").quantile(0.5, QuantileInterpolOptions::Nearest).alias("median_rain"),
     ///        ])
     ///        .sort("date", false)
     /// }
     /// ```
     pub fn agg(self, aggs: Vec<Expr>) -> LazyFrame {
         let lp = LogicalPlanBuilder::from(self.logical_plan)
-            .groupby(Arc::new(self.keys), aggs, None)
+            .groupby(Arc::new(self.keys), aggs, None, self.options)
             .build();
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
 
     /// Apply a function over the groups as a new `DataFrame`. It is not recommended that you use
     /// this as materializing the `DataFrame` is quite expensive.
+    ///
+    /// Every group is passed to `f` with all columns, including the keys, and the outputs are
+    /// concatenated. The groups are processed in parallel.
+    ///
+    /// The output of `f` is assumed to have the same schema as the input. Use
+    /// [apply_with_schema](LazyGroupBy::apply_with_schema) if it does not.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// /// Keep the two largest values of every group.
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///     df.lazy()
+    ///         .groupby(vec![col("date")])
+    ///         .apply(|df| df.sort("rain", true).map(|df| df.head(Some(2))))
+    /// }
+    /// ```
     pub fn apply<F>(self, f: F) -> LazyFrame
     where
         F: 'static + Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
     {
-        let lp = LogicalPlanBuilder::from(self.logical_plan)
-            .groupby(Arc::new(self.keys), vec![], Some(Arc::new(f)))
+        let schema = self.logical_plan.schema().clone();
+        self.apply_with_schema(f, schema)
+    }
+
+    /// Apply a function over the groups as a new `DataFrame`, see [apply](LazyGroupBy::apply).
+    /// The outputs of `f` must have the given `schema`.
+    pub fn apply_with_schema<F>(self, f: F, schema: Schema) -> LazyFrame
+    where
+        F: 'static + Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
+    {
+        let mut lp = LogicalPlanBuilder::from(self.logical_plan)
+            .groupby(Arc::new(self.keys), vec![], Some(Arc::new(f)), self.options)
             .build();
+        if let LogicalPlan::Aggregate { schema: s, .. } = &mut lp {
+            *s = Arc::new(schema);
+        }
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
 }
@@ -1006,6 +1604,7 @@ pub struct JoinBuilder {
     right_on: Vec<Expr>,
     allow_parallel: bool,
     force_parallel: bool,
+    duplicates: DuplicateColumns,
 }
 impl JoinBuilder {
     fn new(lf: LazyFrame) -> Self {
@@ -1017,6 +1616,7 @@ impl JoinBuilder {
             right_on: vec![],
             allow_parallel: true,
             force_parallel: false,
+            duplicates: DuplicateColumns::default(),
         }
     }
 
@@ -1047,22 +1647,88 @@ impl JoinBuilder {
         self.allow_parallel = allow;
         self
     }
+
+    /// Suffix added to the columns of the right table that also exist in the left table.
+    /// Defaults to `"_right"`.
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.duplicates = DuplicateColumns::Suffix(suffix.to_string());
+        self
+    }
+
+    /// How to deal with columns that exist in both tables, e.g. raise an error with
+    /// `DuplicateColumns::Error`.
+    pub fn duplicate_columns(mut self, duplicates: DuplicateColumns) -> Self {
+        self.duplicates = duplicates;
+        self
+    }
+
+    /// Finish the join. Join keys that are not plain columns are computed as temporary
+    /// columns and dropped after the join.
     pub fn finish(self) -> LazyFrame {
         let opt_state = self.lf.opt_state;
+        let other = self.other.expect("with not set");
 
-        let lp = self
-            .lf
+        let (left, left_on, tmp_left) = with_join_keys(self.lf, self.left_on, "LEFT");
+        let (other, right_on, _) = with_join_keys(other, self.right_on, "RIGHT");
+
+        let lp = left
             .get_plan_builder()
             .join(
-                self.other.expect("with not set").logical_plan,
+                other.logical_plan,
                 self.how,
-                self.left_on,
-                self.right_on,
+                left_on,
+                right_on,
                 self.allow_parallel,
                 self.force_parallel,
+                self.duplicates,
             )
             .build();
-        LazyFrame::from_logical_plan(lp, opt_state)
+        let lf = LazyFrame::from_logical_plan(lp, opt_state);
+
+        // the temporary keys of the right table are removed by the join
+        if tmp_left.is_empty() {
+            lf
+        } else {
+            let projection = lf
+                .logical_plan
+                .schema()
+                .fields()
+                .iter()
+                .filter(|f| !tmp_left.contains(f.name()))
+                .map(|f| col(f.name()))
+                .collect::<Vec<_>>();
+            lf.select(projection)
+        }
+    }
+}
+
+/// Add the join keys that are not a column as temporary columns. Returns the frame, the keys
+/// as columns and the names of the temporary columns.
+fn with_join_keys(
+    lf: LazyFrame,
+    keys: Vec<Expr>,
+    side: &str,
+) -> (LazyFrame, Vec<Expr>, Vec<String>) {
+    let mut tmp_names = vec![];
+    let mut tmp_keys = vec![];
+    let keys = keys
+        .into_iter()
+        .enumerate()
+        .map(|(i, e)| match e {
+            Expr::Column(_) => e,
+            _ => {
+                let name = format!("__POLARS_JOIN_KEY_{}_{}", side, i);
+                tmp_keys.push(e.alias(&name));
+                let key = col(&name);
+                tmp_names.push(name);
+                key
+            }
+        })
+        .collect();
+    if tmp_keys.is_empty() {
+        (lf, keys, tmp_names)
+    } else {
+        (lf.with_columns(tmp_keys), keys, tmp_names)
     }
 }
 
@@ -1072,14 +1738,14 @@ mod test {
     use polars_core::utils::chrono::{NaiveDate, NaiveDateTime, NaiveTime};
     use polars_core::*;
 
-    use crate::functions::{argsort_by, pearson_corr};
+    use crate::functions::{argsort_by, concat_str, pearson_corr};
     use crate::tests::get_df;
 
     use super::*;
 
     fn scan_foods_csv() -> LazyFrame {
         let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
-        LazyCsvReader::new(path.to_string()).finish()
+        LazyCsvReader::new(path.to_string()).finish().unwrap()
     }
 
     #[test]
@@ -1181,6 +1847,368 @@ mod test {
         }
         .unwrap();
         assert!(new.frame_equal(&out));
+
+        let new = df
+            .lazy()
+            .drop_nulls(Some(vec![col("foo")]))
+            .collect()
+            .unwrap();
+        assert_eq!(
+            Vec::from(new.column("foo").unwrap().i32().unwrap()),
+            &[Some(1), Some(3)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_lazy_sink_parquet() -> Result<()> {
+        use polars_io::prelude::*;
+        use std::fs::File;
+
+        let df = df! {
+            "a" => (0..10).collect::<Vec<i32>>(),
+            "b" => (0..10).map(|v| v as f64).collect::<Vec<_>>()
+        }?;
+        let options = ParquetWriteOptions {
+            row_group_size: 3,
+            ..Default::default()
+        };
+
+        // streamable query
+        let path = std::env::temp_dir().join("polars_sink_streaming.parquet");
+        df.clone()
+            .lazy()
+            .filter(col("a").gt(lit(2)))
+            .with_column((col("b") * lit(2.0)).alias("c"))
+            .sink_parquet(&path, options)?;
+        let expected = df
+            .clone()
+            .lazy()
+            .filter(col("a").gt(lit(2)))
+            .with_column((col("b") * lit(2.0)).alias("c"))
+            .collect()?;
+        let out = ParquetReader::new(File::open(&path)?).finish()?;
+        assert!(out.frame_equal(&expected));
+
+        // a sort needs the full result
+        let path = std::env::temp_dir().join("polars_sink_sorted.parquet");
+        df.clone()
+            .lazy()
+            .sort("a", true)
+            .sink_parquet(&path, options)?;
+        let out = ParquetReader::new(File::open(&path)?).finish()?;
+        assert!(out.frame_equal(&df.sort("a", true)?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_sink_csv() -> Result<()> {
+        use polars_io::prelude::*;
+
+        let options = CsvWriteOptions {
+            batch_size: 4,
+            ..Default::default()
+        };
+        let path = std::env::temp_dir().join("polars_sink_streaming.csv");
+        scan_foods_csv()
+            .filter(col("calories").gt(lit(50)))
+            .with_column((col("fats_g") * lit(2.0)).alias("fats_2"))
+            .sink_csv(&path, options)?;
+        let expected = scan_foods_csv()
+            .filter(col("calories").gt(lit(50)))
+            .with_column((col("fats_g") * lit(2.0)).alias("fats_2"))
+            .collect()?;
+        let out = CsvReader::from_path(&path)?.finish()?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_lazy_scan_glob() -> Result<()> {
+        use polars_io::prelude::*;
+        use std::fs::{create_dir_all, File};
+
+        let dir = std::env::temp_dir().join("polars_scan_glob");
+        create_dir_all(&dir)?;
+        let mut parts = vec![];
+        for i in 0..3i64 {
+            let mut part = df! {
+                "a" => [i, i + 10],
+                "b" => ["x", "y"]
+            }?;
+            CsvWriter::new(&mut File::create(dir.join(format!("part-{}.csv", i)))?)
+                .finish(&mut part)?;
+            ParquetWriter::new(File::create(dir.join(format!("part-{}.parquet", i)))?)
+                .finish(&part)?;
+            parts.push(part);
+        }
+        let mut expected = parts[0].clone();
+        expected.vstack_mut(&parts[1])?;
+        expected.vstack_mut(&parts[2])?;
+
+        let pattern = dir.join("part-*.csv").to_string_lossy().to_string();
+        let out = LazyCsvReader::new(pattern).finish()?.collect()?;
+        assert!(out.frame_equal(&expected));
+
+        let pattern = dir.join("part-*.parquet").to_string_lossy().to_string();
+        let out = LazyFrame::new_from_parquet(pattern.clone(), None, false)?
+            .filter(col("a").gt(lit(5)))
+            .select(&[col("a")])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i64()?),
+            &[Some(10), Some(11), Some(12)]
+        );
+
+        let out = LazyFrame::new_from_parquet(pattern, Some(4), false)?.collect()?;
+        assert_eq!(out.height(), 4);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "object-store", feature = "parquet", feature = "csv-file"))]
+    fn test_lazy_scan_uri() -> Result<()> {
+        use polars_io::prelude::*;
+        use std::fs::File;
+
+        let mut df = df! {
+            "a" => [1i64, 2, 3],
+            "b" => ["x", "y", "z"]
+        }?;
+        let parquet = std::env::temp_dir().join("polars_scan_uri.parquet");
+        let csv = std::env::temp_dir().join("polars_scan_uri.csv");
+        ParquetWriter::new(File::create(&parquet)?).finish(&df)?;
+        CsvWriter::new(&mut File::create(&csv)?).finish(&mut df)?;
+
+        let uri = |path: &std::path::Path| format!("file://{}", path.to_string_lossy());
+        let scans = vec![
+            LazyFrame::new_from_parquet(uri(&parquet), None, false)?,
+            LazyCsvReader::new(uri(&csv)).finish()?,
+        ];
+        for scan in scans {
+            let out = scan
+                .filter(col("a").gt(lit(1)))
+                .select(&[col("b")])
+                .collect()?;
+            assert_eq!(Vec::from(out.column("b")?.utf8()?), &[Some("y"), Some("z")]);
+        }
+        assert!(
+            LazyFrame::new_from_parquet("unknown://bucket/file.parquet".into(), None, false)
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_lazy_scan_glob_schema_mismatch() -> Result<()> {
+        use polars_io::prelude::*;
+        use std::fs::{create_dir_all, remove_dir_all, File};
+
+        let dir = std::env::temp_dir().join("polars_scan_glob_mismatch");
+        let _ = remove_dir_all(&dir);
+        create_dir_all(&dir)?;
+        let mut a = df! { "a" => [1i64, 2] }?;
+        let mut b = df! { "a" => ["x", "y"] }?;
+        ParquetWriter::new(File::create(dir.join("part-0.parquet"))?).finish(&a)?;
+        ParquetWriter::new(File::create(dir.join("part-1.parquet"))?).finish(&b)?;
+        CsvWriter::new(&mut File::create(dir.join("part-0.csv"))?).finish(&mut a)?;
+        CsvWriter::new(&mut File::create(dir.join("part-1.csv"))?).finish(&mut b)?;
+
+        let pattern = dir.join("part-*.parquet").to_string_lossy().to_string();
+        let err = LazyFrame::new_from_parquet(pattern, None, false)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("part-1.parquet"));
+
+        // the dtypes of the csv files are unified to their supertype
+        let pattern = dir.join("part-*.csv").to_string_lossy().to_string();
+        let out = LazyCsvReader::new(pattern).finish()?.collect()?;
+        assert_eq!(
+            Vec::from(out.column("a")?.utf8()?),
+            &[Some("1"), Some("2"), Some("x"), Some("y")]
+        );
+
+        let mut c = df! { "a" => [1i64, 2], "b" => [1i64, 2] }?;
+        let mut d = df! { "a" => [0.5, 1.5], "b" => [3i64, 4] }?;
+        let mut e = df! { "b" => [1i64, 2], "a" => [3i64, 4] }?;
+        CsvWriter::new(&mut File::create(dir.join("num-0.csv"))?).finish(&mut c)?;
+        CsvWriter::new(&mut File::create(dir.join("num-1.csv"))?).finish(&mut d)?;
+        let pattern = dir.join("num-*.csv").to_string_lossy().to_string();
+        let out = LazyCsvReader::new(pattern).finish()?.collect()?;
+        assert_eq!(out.dtypes(), &[DataType::Float64, DataType::Int64]);
+        assert_eq!(
+            Vec::from(out.column("a")?.f64()?),
+            &[Some(1.0), Some(2.0), Some(0.5), Some(1.5)]
+        );
+
+        // the columns must be in the same order
+        CsvWriter::new(&mut File::create(dir.join("num-2.csv"))?).finish(&mut e)?;
+        let pattern = dir.join("num-*.csv").to_string_lossy().to_string();
+        let err = LazyCsvReader::new(pattern)
+            .finish()
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("num-2.csv"));
+
+        let missing = dir.join("missing.parquet").to_string_lossy().to_string();
+        let err = LazyFrame::new_from_parquet(missing, None, false)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("missing.parquet"));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "ipc")]
+    fn test_lazy_scan_ipc() -> Result<()> {
+        use polars_io::prelude::*;
+        use std::fs::File;
+
+        let df = df! {
+            "a" => (0..10).collect::<Vec<i32>>(),
+            "b" => (0..10).map(|v| v as f64).collect::<Vec<_>>(),
+            "c" => (0..10).map(|v| format!("{}", v)).collect::<Vec<_>>()
+        }?;
+        let path = std::env::temp_dir().join("polars_scan_ipc.ipc");
+        IpcWriter::new(&mut File::create(&path)?).finish(&df)?;
+        let path = path.to_string_lossy().to_string();
+
+        let out = LazyFrame::scan_ipc(path.clone(), None, false)?
+            .filter(col("a").gt(lit(6)))
+            .select(&[col("b")])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["b"]);
+        assert_eq!(
+            Vec::from(out.column("b")?.f64()?),
+            &[Some(7.0), Some(8.0), Some(9.0)]
+        );
+
+        let out = LazyFrame::scan_ipc(path.clone(), Some(3), false)?.collect()?;
+        assert!(out.frame_equal(&df.head(Some(3))));
+
+        // the slice is pushed down to the scan, which reads a batch of 10 rows
+        let out = LazyFrame::scan_ipc(path, None, false)?.limit(4).collect()?;
+        assert_eq!(out.height(), 4);
+        assert!(out.frame_equal(&df.head(Some(4))));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "ipc", feature = "parquet"))]
+    fn test_lazy_scan_row_count() -> Result<()> {
+        use polars_io::prelude::*;
+        use std::fs::File;
+
+        let df = df! {
+            "a" => (0..10).collect::<Vec<i32>>(),
+            "b" => (0..10).map(|v| v as f64).collect::<Vec<_>>()
+        }?;
+        let ipc = std::env::temp_dir().join("polars_scan_row_count.ipc");
+        IpcWriter::new(&mut File::create(&ipc)?).finish(&df)?;
+        let parquet = std::env::temp_dir().join("polars_scan_row_count.parquet");
+        ParquetWriter::new(File::create(&parquet)?).finish(&df)?;
+        let row_count = || {
+            Some(RowCount {
+                name: "row_nr".into(),
+                offset: 1,
+            })
+        };
+
+        for lf in vec![
+            LazyFrame::scan_ipc_with_row_count(
+                ipc.to_string_lossy().to_string(),
+                None,
+                false,
+                row_count(),
+            )?,
+            LazyFrame::new_from_parquet_with_row_count(
+                parquet.to_string_lossy().to_string(),
+                None,
+                false,
+                row_count(),
+            )?,
+        ] {
+            // the rows are counted before the filter and the projection
+            let out = lf
+                .clone()
+                .filter(col("a").gt(lit(6)))
+                .select(&[col("row_nr"), col("b")])
+                .collect()?;
+            assert_eq!(out.get_column_names(), &["row_nr", "b"]);
+            assert_eq!(
+                Vec::from(out.column("row_nr")?.u32()?),
+                &[Some(8), Some(9), Some(10)]
+            );
+
+            let out = lf.clone().select(&[col("row_nr")]).collect()?;
+            assert_eq!(out.height(), 10);
+
+            let out = lf.select(&[col("row_nr").max()]).collect()?;
+            assert_eq!(out.column("row_nr")?.u32()?.get(0), Some(10));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_lazy_parquet_row_group_pruning() -> Result<()> {
+        use polars_io::prelude::*;
+        use std::fs::File;
+
+        let df = df! {
+            "a" => (0..100).collect::<Vec<i32>>(),
+            "b" => (0..100).map(|v| v as f64).collect::<Vec<_>>()
+        }?;
+        let path = std::env::temp_dir().join("polars_row_group_pruning.parquet");
+        ParquetWriter::new(File::create(&path)?)
+            .with_row_group_size(Some(10))
+            .finish(&df)?;
+        let scan = || LazyFrame::new_from_parquet(path.to_string_lossy().to_string(), None, false);
+
+        let predicates = vec![
+            col("a").gt(lit(85)),
+            lit(15).gt_eq(col("a")),
+            col("a").eq(lit(42)),
+            col("a").gt(lit(20)).and(col("b").lt(lit(35.0))),
+            col("a").lt(lit(5)).or(col("a").gt(lit(95))),
+        ];
+        for predicate in predicates {
+            let out = scan()?.filter(predicate.clone()).collect()?;
+            let expected = df.clone().lazy().filter(predicate).collect()?;
+            assert!(out.frame_equal(&expected));
+        }
+
+        // all row groups are pruned
+        let out = scan()?.filter(col("a").gt(lit(1000))).collect()?;
+        assert_eq!(out.shape(), (0, 2));
+        let out = scan()?
+            .filter(col("a").gt(lit(1000)))
+            .select(vec![col("a").sum()])
+            .with_aggregate_pushdown(true)
+            .collect()?;
+        let expected = df
+            .clone()
+            .lazy()
+            .filter(col("a").gt(lit(1000)))
+            .select(vec![col("a").sum()])
+            .collect()?;
+        assert!(out.frame_equal_missing(&expected));
+
+        // unsigned statistics are compared as unsigned values
+        let df = df! {
+            "a" => &[1u32, 3_000_000_000]
+        }?;
+        ParquetWriter::new(File::create(&path)?).finish(&df)?;
+        let out = scan()?
+            .filter(col("a").gt(lit(2_000_000_000u32)))
+            .collect()?;
+        assert_eq!(out.column("a")?.u32()?.get(0), Some(3_000_000_000));
+        Ok(())
     }
 
     #[test]
@@ -1273,7 +2301,9 @@ mod test {
             .agg(vec![
                 col("rain").min(),
                 col("rain").sum(),
-                col("rain").quantile(0.5).alias("median_rain"),
+                col("rain")
+                    .quantile(0.5, QuantileInterpolOptions::Nearest)
+                    .alias("median_rain"),
             ])
             .sort("date", false);
 
@@ -1412,6 +2442,8 @@ mod test {
                     )
                     .alias("diff_cases"),
             ])
+            // joins maintain the order of the left frame, groupby does not
+            .sort("uid", false)
             .explode(&[col("day"), col("diff_cases")])
             .join(
                 base_df,
@@ -1651,10 +2683,13 @@ mod test {
         assert!(df
             .clone()
             .lazy()
-            .quantile(0.5)
+            .quantile(0.5, QuantileInterpolOptions::default())
             .collect()
             .unwrap()
-            .frame_equal_missing(&df.quantile(0.5).unwrap()));
+            .frame_equal_missing(
+                &df.quantile(0.5, QuantileInterpolOptions::default())
+                    .unwrap()
+            ));
     }
 
     #[test]
@@ -2216,4 +3251,1544 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_lazy_explode_schema() -> Result<()> {
+        let df = df![
+            "a" => [0, 1, 2, 0, 2],
+            "b" => [5, 4, 3, 2, 1],
+            "c" => [2, 3, 4, 1, 5]
+        ]?;
+
+        let lf = df
+            .lazy()
+            .groupby(vec![col("a")])
+            .agg(vec![col("b").list(), col("c").list()])
+            .explode(&[col("b_agg_list")]);
+
+        let schema = lf.logical_plan.schema();
+        assert_eq!(
+            schema.field_with_name("b_agg_list")?.data_type(),
+            &DataType::Int32
+        );
+        assert!(matches!(
+            schema.field_with_name("c_agg_list")?.data_type(),
+            DataType::List(_)
+        ));
+
+        // only the exploded column is needed
+        let out = lf.select(vec![col("b_agg_list")]).collect()?;
+        assert_eq!(out.shape(), (5, 1));
+        assert_eq!(out.column("b_agg_list")?.dtype(), &DataType::Int32);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-date64", feature = "dtype-duration-ms"))]
+    fn test_lazy_temporal_arithmetic() -> Result<()> {
+        let df = df![
+            "start" => [0i64, 1000],
+            "stop" => [86_400_000i64, 4000]
+        ]?;
+        let ms = DataType::Duration(TimeUnit::Millisecond);
+
+        let lf = df
+            .lazy()
+            .with_columns(vec![
+                col("start").cast(DataType::Date64),
+                col("stop").cast(DataType::Date64),
+            ])
+            .select(vec![
+                (col("stop") - col("start")).alias("elapsed"),
+                col("start"),
+            ])
+            .with_column((col("start") + col("elapsed")).alias("stop"))
+            .with_column((col("elapsed") * lit(2)).alias("twice"));
+
+        let schema = lf.logical_plan.schema();
+        assert_eq!(schema.field_with_name("elapsed")?.data_type(), &ms);
+        assert_eq!(
+            schema.field_with_name("stop")?.data_type(),
+            &DataType::Date64
+        );
+        assert_eq!(schema.field_with_name("twice")?.data_type(), &ms);
+
+        let out = lf.collect()?;
+        assert_eq!(out.column("elapsed")?.dtype(), &ms);
+        assert_eq!(out.column("stop")?.dtype(), &DataType::Date64);
+        assert_eq!(
+            Vec::from(out.column("twice")?.cast::<Int64Type>()?.i64()?),
+            &[Some(2 * 86_400_000), Some(6000)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "asof_join")]
+    fn test_lazy_join_asof() -> Result<()> {
+        let quotes = df![
+            "time" => [1, 5, 10, 21],
+            "ticker" => ["a", "a", "b", "b"]
+        ]?;
+        let trades = df![
+            "time" => [2, 3, 10, 15],
+            "ticker" => ["a", "b", "b", "a"],
+            "price" => [10, 20, 30, 40]
+        ]?;
+
+        let out = quotes
+            .clone()
+            .lazy()
+            .join_asof(
+                trades.clone().lazy(),
+                col("time"),
+                col("time"),
+                AsOfOptions::default(),
+            )
+            .filter(col("price").gt(lit(15)))
+            .select(vec![col("time"), col("price")])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("time")?.i32()?),
+            &[Some(5), Some(10), Some(21)]
+        );
+
+        let out = quotes
+            .lazy()
+            .join_asof_by(
+                trades.lazy(),
+                col("time"),
+                col("time"),
+                vec![col("ticker")],
+                vec![col("ticker")],
+                AsOfOptions::default(),
+            )
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["time", "ticker", "price"]);
+        assert_eq!(
+            Vec::from(out.column("price")?.i32()?),
+            &[None, Some(10), Some(30), Some(30)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "cross_join")]
+    fn test_lazy_cross_join() -> Result<()> {
+        let sizes = df!["size" => ["s", "m", "l"]]?;
+        let colors = df!["color" => ["red", "blue"], "price" => [1, 2]]?;
+
+        let lf = sizes
+            .lazy()
+            .cross_join(colors.lazy())
+            .filter(col("size").neq(lit("m")))
+            .filter(col("color").eq(lit("red")));
+
+        // both predicates are pushed down to the input tables of the join
+        let plan = lf.describe_optimized_plan()?;
+        assert!(!plan.contains("SELECTION: None"));
+
+        let out = lf.collect()?;
+        assert_eq!(out.shape(), (2, 3));
+        assert_eq!(
+            Vec::from(out.column("size")?.utf8()?),
+            &[Some("s"), Some("l")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "semi_anti_join")]
+    fn test_lazy_semi_anti_join() -> Result<()> {
+        let orders = df![
+            "customer_id" => [1, 2, 3, 2, 4],
+            "amount" => [10, 20, 30, 40, 50]
+        ]?;
+        let customers = df![
+            "id" => [2, 1, 3],
+            "amount" => [0, 0, 100]
+        ]?;
+
+        // the filter on amount may not be applied to the customers
+        let out = orders
+            .clone()
+            .lazy()
+            .semi_join(customers.clone().lazy(), col("customer_id"), col("id"))
+            .filter(col("amount").gt(lit(15)))
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["customer_id", "amount"]);
+        assert_eq!(
+            Vec::from(out.column("amount")?.i32()?),
+            &[Some(20), Some(30), Some(40)]
+        );
+
+        let out = orders
+            .lazy()
+            .anti_join(customers.lazy(), col("customer_id"), col("id"))
+            .collect()?;
+        assert_eq!(Vec::from(out.column("amount")?.i32()?), &[Some(50)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_join_suffix() -> Result<()> {
+        let left = df![
+            "id" => [1, 2, 3],
+            "value" => [1, 2, 3]
+        ]?;
+        let right = df![
+            "id" => [1, 2, 3],
+            "value" => [10, 20, 30]
+        ]?;
+
+        // the projection on the renamed column must be pushed down to the right table
+        let out = left
+            .clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .left_on(vec![col("id")])
+            .right_on(vec![col("id")])
+            .suffix("_r")
+            .finish()
+            .select(vec![col("id"), col("value_r")])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["id", "value_r"]);
+        assert_eq!(
+            Vec::from(out.column("value_r")?.i32()?),
+            &[Some(10), Some(20), Some(30)]
+        );
+
+        let out = left
+            .lazy()
+            .join_builder()
+            .with(right.lazy())
+            .left_on(vec![col("id")])
+            .right_on(vec![col("id")])
+            .duplicate_columns(DuplicateColumns::Error)
+            .finish()
+            .collect();
+        assert!(out.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_join_on_expressions() -> Result<()> {
+        let left = df![
+            "id" => [1, 2, 3],
+            "a" => ["x", "y", "z"]
+        ]?;
+        let right = df![
+            "id10" => [30, 10, 40],
+            "b" => [3, 1, 4]
+        ]?;
+
+        let out = left
+            .lazy()
+            .join(
+                right.lazy(),
+                vec![col("id") * lit(10)],
+                vec![col("id10")],
+                JoinType::Left,
+            )
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["id", "a", "b"]);
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(1), None, Some(3)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dynamic_groupby")]
+    fn test_lazy_groupby_dynamic() -> Result<()> {
+        use polars_core::utils::chrono::Duration;
+
+        let minute = 60 * 1000;
+        let time = Date64Chunked::new_from_slice("time", &[0, minute, 2 * minute, 5 * minute]);
+        let df = DataFrame::new(vec![
+            time.into_series(),
+            Series::new("value", &[1, 2, 3, 4]),
+            Series::new("unused", &[1, 2, 3, 4]),
+        ])?;
+
+        let options = DynamicGroupOptions {
+            period: Duration::minutes(3),
+            ..DynamicGroupOptions::new("time", Duration::minutes(2))
+        };
+        let out = df
+            .lazy()
+            .groupby_dynamic(vec![], options)
+            .agg(vec![col("value").sum()])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["time", "value_sum"]);
+        assert_eq!(
+            Vec::from(out.column("time")?.date64()?),
+            &[
+                Some(-2 * minute),
+                Some(0),
+                Some(2 * minute),
+                Some(4 * minute)
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("value_sum")?.i32()?),
+            &[Some(1), Some(6), Some(3), Some(4)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "rolling_groupby")]
+    fn test_lazy_groupby_rolling() -> Result<()> {
+        let df = df![
+            "row" => [0, 1, 2, 3],
+            "id" => ["a", "b", "a", "a"],
+            "value" => [1, 2, 3, 4]
+        ]?;
+
+        // sum per id over the current and the previous row number
+        let out = df
+            .lazy()
+            .groupby_rolling(
+                vec![col("id")],
+                RollingGroupOptions::new("row", RollingPeriod::Index(2)),
+            )
+            .agg(vec![col("value").sum()])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["id", "row", "value_sum"]);
+        assert_eq!(
+            Vec::from(out.column("value_sum")?.i32()?),
+            &[Some(1), Some(3), Some(7), Some(2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_rolling() -> Result<()> {
+        let df = df![
+            "vals" => [1.0, 2.0, 3.0, 4.0]
+        ]?;
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("vals")
+                    .rolling_mean(RollingOptions {
+                        window_size: 2,
+                        ..Default::default()
+                    })
+                    .alias("mean"),
+                col("vals")
+                    .rolling_sum(RollingOptions {
+                        window_size: 3,
+                        center: true,
+                        ..Default::default()
+                    })
+                    .alias("sum"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("mean")?.f64()?),
+            &[Some(1.0), Some(1.5), Some(2.5), Some(3.5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("sum")?.f64()?),
+            &[Some(3.0), Some(6.0), Some(9.0), Some(7.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "ewma")]
+    fn test_lazy_ewm() -> Result<()> {
+        let df = df![
+            "vals" => [1, 3, 5]
+        ]?;
+        let options = EwmOptions {
+            adjust: false,
+            ..EwmOptions::alpha(0.5)
+        };
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("vals").ewm_mean(options).alias("mean"),
+                col("vals").ewm_var(options).alias("var"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("mean")?.f64()?),
+            &[Some(1.0), Some(2.0), Some(3.5)]
+        );
+        assert_eq!(out.column("var")?.f64()?.get(1), Some(2.0));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "rank")]
+    fn test_lazy_rank_over() -> Result<()> {
+        let df = df![
+            "groups" => ["a", "b", "a", "b", "a"],
+            "vals" => [3, 1, 1, 2, 2]
+        ]?;
+        let options = RankOptions {
+            method: RankMethod::Ordinal,
+            descending: false,
+        };
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("vals")
+                    .rank(options)
+                    .over(vec![col("groups")])
+                    .alias("rank"),
+                col("vals").rank(options).alias("rank_all"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("rank")?.u32()?),
+            &[Some(3), Some(1), Some(1), Some(2), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(out.column("rank_all")?.u32()?),
+            &[Some(5), Some(1), Some(2), Some(3), Some(4)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "interpolate")]
+    fn test_lazy_interpolate() -> Result<()> {
+        let df = df![
+            "groups" => ["a", "a", "a", "b", "b", "b"],
+            "vals" => [Some(1.0), None, Some(3.0), Some(10.0), None, Some(11.0)]
+        ]?;
+
+        let out = df
+            .lazy()
+            .select(vec![col("vals")
+                .interpolate(InterpolationMethod::Linear)
+                .over(vec![col("groups")])])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("vals")?.f64()?),
+            &[
+                Some(1.0),
+                Some(2.0),
+                Some(3.0),
+                Some(10.0),
+                Some(10.5),
+                Some(11.0)
+            ]
+        );
+
+        // integers are interpolated to floats, also in the schema
+        let df = df![
+            "vals" => [Some(1), None, Some(2)]
+        ]?;
+        let lf = df.lazy().select(vec![
+            col("vals").interpolate(InterpolationMethod::Linear),
+            col("vals")
+                .interpolate(InterpolationMethod::Nearest)
+                .alias("nearest"),
+        ]);
+        let schema = lf.logical_plan.schema().clone();
+        assert_eq!(
+            schema.field_with_name("vals")?.data_type(),
+            &DataType::Float64
+        );
+        assert_eq!(
+            schema.field_with_name("nearest")?.data_type(),
+            &DataType::Int32
+        );
+        let out = lf.collect()?;
+        assert_eq!(
+            Vec::from(out.column("vals")?.f64()?),
+            &[Some(1.0), Some(1.5), Some(2.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "pct_change")]
+    fn test_lazy_diff_pct_change() -> Result<()> {
+        let df = df![
+            "vals" => [1.0, 2.0, 4.0, 5.0]
+        ]?;
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("vals").diff(1, NullBehavior::Ignore).alias("diff"),
+                col("vals").pct_change(2).alias("pct_change"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("diff")?.f64()?),
+            &[None, Some(1.0), Some(2.0), Some(1.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("pct_change")?.f64()?),
+            &[None, None, Some(3.0), Some(1.5)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_cum_prod() -> Result<()> {
+        let df = df![
+            "groups" => ["a", "a", "b", "b", "b"],
+            "vals" => [2, 3, 1, 4, 5]
+        ]?;
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("vals").cum_prod(false).alias("cum_prod"),
+                col("vals")
+                    .cum_prod(true)
+                    .over(vec![col("groups")])
+                    .alias("cum_prod_rev"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("cum_prod")?.i64()?),
+            &[Some(2), Some(6), Some(6), Some(24), Some(120)]
+        );
+        assert_eq!(
+            Vec::from(out.column("cum_prod_rev")?.i64()?),
+            &[Some(6), Some(3), Some(20), Some(20), Some(5)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_clip() -> Result<()> {
+        let df = df![
+            "vals" => [Some(-3), Some(1), None, Some(7)]
+        ]?;
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("vals").clip(0.0, 5.0).alias("clip"),
+                col("vals").clip_max(0.0).alias("clip_max"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("clip")?.i32()?),
+            &[Some(0), Some(1), None, Some(5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("clip_max")?.i32()?),
+            &[Some(-3), Some(0), None, Some(0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "mode")]
+    fn test_lazy_groupby_mode() -> Result<()> {
+        let df = df![
+            "groups" => ["a", "a", "a", "b"],
+            "vals" => ["x", "y", "y", "z"]
+        ]?;
+
+        let out = df
+            .lazy()
+            .groupby(vec![col("groups")])
+            .agg(vec![col("vals").mode().alias("mode")])
+            .sort("groups", false)
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("mode")?.explode()?.utf8()?),
+            &[Some("y"), Some("z")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_quantile_interpol() -> Result<()> {
+        let df = df![
+            "groups" => ["a", "a", "a", "a", "b"],
+            "vals" => [4, 1, 3, 2, 5]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("groups")])
+            .agg(vec![
+                col("vals")
+                    .quantile(0.5, QuantileInterpolOptions::Linear)
+                    .alias("linear"),
+                col("vals")
+                    .quantile(0.5, QuantileInterpolOptions::Higher)
+                    .alias("higher"),
+            ])
+            .sort("groups", false)
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("linear")?.f64()?),
+            &[Some(2.5), Some(5.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("higher")?.f64()?),
+            &[Some(3.0), Some(5.0)]
+        );
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("vals").quantile(0.25, QuantileInterpolOptions::Lower)
+            ])
+            .collect()?;
+        assert_eq!(Vec::from(out.column("vals")?.f64()?), &[Some(2.0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_skew_kurtosis() -> Result<()> {
+        let df = df![
+            "groups" => ["a", "a", "a", "b", "b", "b", "b"],
+            "vals" => [1, 2, 3, 1, 1, 1, 5]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("groups")])
+            .agg(vec![
+                col("vals").skew(true).alias("skew"),
+                col("vals").kurtosis(false, true).alias("kurtosis"),
+            ])
+            .sort("groups", false)
+            .collect()?;
+        let skew = out.column("skew")?.f64()?;
+        assert_eq!(skew.get(0), Some(0.0));
+        assert!((skew.get(1).unwrap() - 2.0 / 3f64.sqrt()).abs() < 1e-12);
+        let kurtosis = out.column("kurtosis")?.f64()?;
+        assert!((kurtosis.get(1).unwrap() - 7.0 / 3.0).abs() < 1e-12);
+
+        let out = df.lazy().select(vec![col("vals").skew(false)]).collect()?;
+        assert_eq!(out.column("vals")?.dtype(), &DataType::Float64);
+        assert_eq!(out.height(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_drop_duplicates_keep() -> Result<()> {
+        let df = df![
+            "key" => [1, 2, 1, 3, 1],
+            "val" => [1, 2, 3, 4, 5]
+        ]?;
+
+        let drop_duplicates = |keep| {
+            df.clone()
+                .lazy()
+                .drop_duplicates(true, Some(vec!["key".to_string()]), keep)
+                .collect()
+        };
+        let out = drop_duplicates(UniqueKeepStrategy::Last)?;
+        assert_eq!(
+            Vec::from(out.column("val")?.i32()?),
+            &[Some(2), Some(4), Some(5)]
+        );
+        let out = drop_duplicates(UniqueKeepStrategy::None)?;
+        assert_eq!(Vec::from(out.column("val")?.i32()?), &[Some(2), Some(4)]);
+
+        // a predicate on a column outside of the subset must not be pushed down
+        let filtered = |keep, predicate: Expr| {
+            df.clone()
+                .lazy()
+                .drop_duplicates(true, Some(vec!["key".to_string()]), keep)
+                .filter(predicate)
+                .collect()
+        };
+        let out = filtered(UniqueKeepStrategy::First, col("val").gt(lit(1)))?;
+        assert_eq!(Vec::from(out.column("val")?.i32()?), &[Some(2), Some(4)]);
+        let out = filtered(UniqueKeepStrategy::None, col("val").lt(lit(3)))?;
+        assert_eq!(Vec::from(out.column("val")?.i32()?), &[Some(2)]);
+        // a predicate on the subset is pushed down
+        let out = filtered(UniqueKeepStrategy::Last, col("key").eq(lit(1)))?;
+        assert_eq!(Vec::from(out.column("val")?.i32()?), &[Some(5)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_melt_schema() -> Result<()> {
+        let df = df![
+            "id" => [1, 2],
+            "unused" => ["a", "b"],
+            "int" => [1, 2],
+            "float" => [0.5, 1.5]
+        ]?;
+
+        let lf = df.lazy().melt(
+            vec!["id".to_string()],
+            vec!["int".to_string(), "float".to_string()],
+        );
+        let schema = lf.logical_plan.schema();
+        assert_eq!(
+            schema
+                .fields()
+                .iter()
+                .map(|f| f.name().as_str())
+                .collect::<Vec<_>>(),
+            &["id", "variable", "value"]
+        );
+        assert_eq!(
+            schema.field_with_name("value")?.data_type(),
+            &DataType::Float64
+        );
+
+        let out = lf.select(vec![col("value")]).collect()?;
+        assert_eq!(
+            Vec::from(out.column("value")?.f64()?),
+            &[Some(1.0), Some(2.0), Some(0.5), Some(1.5)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_horizontal_aggregations() -> Result<()> {
+        let df = df![
+            "a" => [Some(1), Some(-2), None],
+            "b" => [Some(-4), None, None],
+            "c" => [Some(3), Some(-6), None]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![
+                sum_exprs(vec![col("a"), col("b"), col("c")]).alias("sum"),
+                min_exprs(vec![col("a"), col("b"), col("c")]).alias("min"),
+                max_exprs(vec![col("a"), col("b"), col("c")]).alias("max"),
+                mean_exprs(vec![col("a"), col("b"), col("c")]).alias("mean"),
+            ])
+            .collect()?;
+
+        assert_eq!(
+            Vec::from(out.column("sum")?.i32()?),
+            &[Some(0), Some(-8), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("min")?.i32()?),
+            &[Some(-4), Some(-6), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("max")?.i32()?),
+            &[Some(3), Some(-2), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("mean")?.f64()?),
+            &[Some(0.0), Some(-4.0), None]
+        );
+
+        // reducing no expressions is an error
+        let out = df.lazy().select(vec![sum_exprs(vec![])]).collect();
+        assert!(matches!(out, Err(PolarsError::NoData(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_concat_str() -> Result<()> {
+        let df = df![
+            "a" => [Some("foo"), None, Some("bar")],
+            "b" => [1, 2, 3]
+        ]?;
+
+        let out = df
+            .lazy()
+            .select(vec![
+                concat_str(vec![col("a"), col("b")], "-", false).alias("strict"),
+                concat_str(vec![col("a"), col("b"), lit("x")], "-", true).alias("lenient"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("strict")?.utf8()?),
+            &[Some("foo-1"), None, Some("bar-3")]
+        );
+        assert_eq!(
+            Vec::from(out.column("lenient")?.utf8()?),
+            &[Some("foo-1-x"), Some("2-x"), Some("bar-3-x")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn test_lazy_str_regex() -> Result<()> {
+        let df = df![
+            "a" => [Some("foo 12"), Some("bar 3 4"), None]
+        ]?;
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("a").str().contains(r"\d{2}").alias("contains"),
+                col("a").str().extract(r"(\w+) (\d)", 2).alias("extract"),
+                col("a").str().extract_all(r"\d+").alias("extract_all"),
+                col("a").str().replace(r"\d", "x").alias("replace"),
+                col("a").str().replace_all(r"\d", "x").alias("replace_all"),
+            ])
+            .collect()?;
+
+        assert_eq!(
+            Vec::from(out.column("contains")?.bool()?),
+            &[Some(true), Some(false), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("extract")?.utf8()?),
+            &[Some("1"), Some("3"), None]
+        );
+        assert_eq!(out.column("extract_all")?.list()?.get(1).unwrap().len(), 2);
+        assert_eq!(
+            Vec::from(out.column("replace")?.utf8()?),
+            &[Some("foo x2"), Some("bar x 4"), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("replace_all")?.utf8()?),
+            &[Some("foo xx"), Some("bar x x"), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "strings", feature = "temporal"))]
+    fn test_lazy_strptime() -> Result<()> {
+        let df = df![
+            "date" => [Some("2021-03-19"), None, Some("2021-03-20")]
+        ]?;
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("date")
+                    .str()
+                    .strptime(DataType::Date32, None)
+                    .alias("date32"),
+                col("date")
+                    .str()
+                    .strptime(DataType::Date64, Some("%Y-%m-%d"))
+                    .alias("date64"),
+            ])
+            .collect()?;
+        assert_eq!(out.column("date32")?.dtype(), &DataType::Date32);
+        assert_eq!(
+            Vec::from(out.column("date32")?.date32()?),
+            &[Some(18705), None, Some(18706)]
+        );
+        assert_eq!(out.column("date64")?.dtype(), &DataType::Date64);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "is_between")]
+    fn test_lazy_is_between() -> Result<()> {
+        let df = df![
+            "a" => [Some(1), Some(5), None, Some(10)],
+            "high" => [2, 5, 5, 20]
+        ]?;
+
+        let out = df
+            .lazy()
+            .select(vec![col("a").is_between(
+                lit(1),
+                col("high"),
+                ClosedWindow::Right,
+            )])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("a")?.bool()?),
+            &[Some(false), Some(true), None, Some(true)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "list")]
+    fn test_lazy_list_namespace() -> Result<()> {
+        let mut a: ListChunked = vec![Series::new("", &[1, 2]), Series::new("", &[3, 4])]
+            .into_iter()
+            .collect();
+        a.rename("a");
+        let df = DataFrame::new(vec![a.into_series()])?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![
+                col("a").arr().lengths().alias("lengths"),
+                col("a").arr().last().alias("last"),
+                col("a").arr().sum().alias("sum"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("lengths")?.u32()?),
+            &[Some(2), Some(2)]
+        );
+        assert_eq!(Vec::from(out.column("last")?.i32()?), &[Some(2), Some(4)]);
+        assert_eq!(Vec::from(out.column("sum")?.i32()?), &[Some(3), Some(7)]);
+
+        // the schema has the dtype of the values, not of the lists
+        let lf = df.lazy().select(vec![
+            col("a").arr().first().alias("first"),
+            col("a").arr().max().alias("max"),
+            col("a").arr().lengths().alias("lengths"),
+        ]);
+        let schema = lf.logical_plan.schema();
+        assert_eq!(
+            schema.field_with_name("first")?.data_type(),
+            &DataType::Int32
+        );
+        assert_eq!(schema.field_with_name("max")?.data_type(), &DataType::Int32);
+        assert_eq!(
+            schema.field_with_name("lengths")?.data_type(),
+            &DataType::UInt32
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_sort_multiple_nulls_last() -> Result<()> {
+        let df = df![
+            "a" => [Some(1), None, Some(2), Some(1)],
+            "b" => [Some(1), Some(2), Some(3), None]
+        ]?;
+
+        let out = df
+            .lazy()
+            .sort_by_exprs(vec![col("a"), col("b")], vec![true, false], true)
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(2), Some(1), Some(1), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(3), Some(1), None, Some(2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_fill_none_strategy() -> Result<()> {
+        let df = df![
+            "g" => ["a", "a", "b", "b"],
+            "a" => [Some(1), None, None, Some(4)],
+            "b" => [None, Some(2.0), None, Some(4.0)]
+        ]?;
+
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![
+                col("a").forward_fill(None).alias("forward"),
+                col("a").backward_fill(Some(1)).alias("backward"),
+                col("b").fill_none_strategy(FillNoneStrategy::Mean),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("forward")?.i32()?),
+            &[Some(1), Some(1), Some(1), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(out.column("backward")?.i32()?),
+            &[Some(1), None, Some(4), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.f64()?),
+            &[Some(3.0), Some(2.0), Some(3.0), Some(4.0)]
+        );
+
+        let out = df
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![col("a").forward_fill(None).alias("a")])
+            .sort("g", false)
+            .collect()?;
+        let a = out.column("a")?.list()?;
+        assert_eq!(Vec::from(a.get(1).unwrap().i32()?), &[None, Some(4)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_over_multiple_keys() -> Result<()> {
+        let df = df![
+            "a" => [1, 1, 2, 1],
+            "b" => ["x", "y", "x", "x"],
+            "values" => [1, 2, 3, 4]
+        ]?;
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("values").sum().over("a").alias("sum_a"),
+                col("values").max().over(&["a", "b"][..]).alias("max_ab"),
+                col("values")
+                    .count()
+                    .over(vec![col("a"), col("b")])
+                    .alias("count_ab"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("sum_a")?.i32()?),
+            &[Some(7), Some(7), Some(3), Some(7)]
+        );
+        assert_eq!(
+            Vec::from(out.column("max_ab")?.i32()?),
+            &[Some(4), Some(2), Some(3), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(out.column("count_ab")?.u32()?),
+            &[Some(2), Some(1), Some(1), Some(2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_udf_output_type() -> Result<()> {
+        let df = df![
+            "g" => ["a", "a", "b"],
+            "values" => [1, 2, 3]
+        ]?;
+
+        let lf = df.lazy().select(vec![
+            col("g"),
+            col("values")
+                .map(|s| s.cast::<Float64Type>(), Some(DataType::Float64))
+                .alias("float"),
+        ]);
+        let out = lf.clone().collect()?;
+        assert_eq!(out.column("float")?.dtype(), &DataType::Float64);
+
+        let out = lf
+            .clone()
+            .groupby(vec![col("g")])
+            .agg(vec![col("float")
+                .apply(|s| Ok(&s * 2.0), Some(DataType::Float64))
+                .alias("doubled")])
+            .sort("g", false)
+            .collect()?;
+        let doubled = out.column("doubled")?.list()?;
+        assert_eq!(
+            Vec::from(doubled.get(0).unwrap().f64()?),
+            &[Some(2.0), Some(4.0)]
+        );
+
+        // errors in a group are propagated
+        let out = lf
+            .groupby(vec![col("g")])
+            .agg(vec![col("float").apply(
+                |s| s.utf8().map(|ca| ca.clone().into_series()),
+                Some(DataType::Utf8),
+            )])
+            .collect();
+        assert!(out.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_when_then_chain() -> Result<()> {
+        let df = df![
+            "a" => [1, 50, 500, 5000]
+        ]?;
+
+        let out = df
+            .lazy()
+            .select(vec![when(col("a").lt(lit(10)))
+                .then(lit(1))
+                .when(col("a").lt(lit(100)))
+                .then(lit(2))
+                .when(col("a").lt(lit(1000)))
+                .then(lit(3))
+                .otherwise(lit(4))
+                .alias("bucket")])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("bucket")?.i32()?),
+            &[Some(1), Some(2), Some(3), Some(4)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_common_subexpr_elim() -> Result<()> {
+        let df = df![
+            "a" => [1.0, 2.0, 3.0, 6.0],
+            "g" => [1, 1, 2, 2]
+        ]?;
+
+        let query = |cse| {
+            df.clone()
+                .lazy()
+                .with_common_subexpr_elim(cse)
+                .select(vec![
+                    col("a").mean().alias("m"),
+                    (col("a") - col("a").mean()).alias("diff"),
+                    (col("a") - col("a").mean()).pow(2.0).alias("sq"),
+                    col("a")
+                        .quantile(0.25, QuantileInterpolOptions::Nearest)
+                        .alias("q1"),
+                    col("a")
+                        .quantile(0.75, QuantileInterpolOptions::Nearest)
+                        .alias("q3"),
+                    col("a").mean().over("g").alias("group_m"),
+                ])
+                .collect()
+        };
+        let out = query(true)?;
+        assert!(out.frame_equal(&query(false)?));
+        assert_eq!(
+            Vec::from(out.column("diff")?.f64()?),
+            &[Some(-2.0), Some(-1.0), Some(0.0), Some(3.0)]
+        );
+        assert_eq!(out.column("m")?.f64()?.get(3), Some(3.0));
+        assert_eq!(out.column("q1")?.f64()?.get(0), Some(2.0));
+        assert_eq!(out.column("q3")?.f64()?.get(0), Some(3.0));
+        assert_eq!(
+            Vec::from(out.column("group_m")?.f64()?),
+            &[Some(1.5), Some(1.5), Some(4.5), Some(4.5)]
+        );
+
+        let out = df
+            .lazy()
+            .with_column((col("a") * lit(2.0)).alias("b"))
+            .with_column((col("a") * lit(2.0) + col("b")).alias("c"))
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("c")?.f64()?),
+            &[Some(4.0), Some(8.0), Some(12.0), Some(24.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_slice_pushdown() -> Result<()> {
+        use crate::logical_plan::iterator::ArenaLpIter;
+
+        let q = scan_foods_csv()
+            .select(vec![
+                col("category"),
+                (col("calories") * lit(2)).alias("calories_2"),
+            ])
+            .slice(2, 3);
+
+        let mut expr_arena = Arena::with_capacity(16);
+        let mut lp_arena = Arena::with_capacity(16);
+        let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        let n_rows = (&lp_arena).iter(root).find_map(|(_, lp)| match lp {
+            ALogicalPlan::CsvScan {
+                stop_after_n_rows, ..
+            } => Some(*stop_after_n_rows),
+            _ => None,
+        });
+        assert_eq!(n_rows, Some(Some(5)));
+
+        let out = q.clone().collect()?;
+        let expected = q.with_slice_pushdown(false).collect()?;
+        assert_eq!(out.shape(), (3, 2));
+        assert!(out.frame_equal(&expected));
+
+        let df = get_df();
+        let out = df
+            .clone()
+            .lazy()
+            .with_column((col("sepal.width") * lit(2.0)).alias("foo"))
+            .tail(2)
+            .slice(1, 5)
+            .collect()?;
+        let expected = df
+            .lazy()
+            .with_column((col("sepal.width") * lit(2.0)).alias("foo"))
+            .tail(2)
+            .slice(1, 5)
+            .with_slice_pushdown(false)
+            .collect()?;
+        assert_eq!(out.height(), 1);
+        assert!(out.frame_equal(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_cache_shared_subplan() -> Result<()> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        let df = df![
+            "a" => [1, 2, 3, 4],
+            "c" => [1.0, 2.0, 3.0, 4.0]
+        ]?;
+        let shared = df
+            .lazy()
+            .map(
+                |df: DataFrame| {
+                    COUNT.fetch_add(1, Ordering::Relaxed);
+                    Ok(df)
+                },
+                None,
+                None,
+            )
+            .filter(col("a").gt(lit(1)))
+            .cache();
+
+        // both branches project and filter differently
+        let left = shared.clone().select(vec![col("a")]);
+        let right = shared.filter(col("c").lt(lit(4.0)));
+        let out = left
+            .inner_join(right, col("a"), col("a"))
+            .sort("a", false)
+            .collect()?;
+
+        assert_eq!(COUNT.load(Ordering::Relaxed), 1);
+        assert_eq!(out.get_column_names(), &["a", "c"]);
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(2), Some(3)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_collect_streaming() -> Result<()> {
+        let df = get_df();
+        let out = df
+            .clone()
+            .lazy()
+            .filter(col("sepal.width").gt(lit(3.1)))
+            .with_column((col("sepal.length") * lit(2.0)).alias("foo"))
+            .collect_streaming(2)?;
+        let expected = df
+            .lazy()
+            .filter(col("sepal.width").gt(lit(3.1)))
+            .with_column((col("sepal.length") * lit(2.0)).alias("foo"))
+            .collect()?;
+        assert!(out.frame_equal(&expected));
+
+        // the aggregation is combined from the partial aggregations of every batch
+        let query = || {
+            scan_foods_csv()
+                .filter(col("calories").gt(lit(50)))
+                .groupby(vec![col("category")])
+                .agg(vec![
+                    col("calories").sum(),
+                    col("fats_g").mean(),
+                    col("sugars_g").max(),
+                    col("calories").count().alias("n"),
+                ])
+                .sort("category", false)
+        };
+        let out = query().collect_streaming(3)?;
+        let expected = query().collect()?;
+        assert_eq!(out.get_column_names(), expected.get_column_names());
+        assert!(out.frame_equal(&expected));
+
+        // the mean is taken in Float64 and is null for a group without valid values
+        let out = df! {
+            "g" => ["a", "a", "b"],
+            "v" => [Some(i32::MAX), Some(i32::MAX), None]
+        }?
+        .lazy()
+        .groupby(vec![col("g")])
+        .agg(vec![col("v").mean()])
+        .sort("g", false)
+        .collect_streaming(1)?;
+        assert_eq!(
+            Vec::from(out.column("v_mean")?.f64()?),
+            &[Some(i32::MAX as f64), None]
+        );
+
+        // a projection without columns is computed once, not once per batch
+        let out = get_df().lazy().select(vec![lit(1)]).collect_streaming(2)?;
+        assert_eq!(out.height(), 1);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "parquet", feature = "ipc"))]
+    fn test_lazy_streaming_file_scans() -> Result<()> {
+        use crate::physical_plan::streaming::streamable_source;
+        use polars_io::prelude::*;
+        use std::fs::File;
+
+        let df = df! {
+            "a" => (0..10).collect::<Vec<i32>>(),
+            "b" => (0..10).map(|v| v as f64).collect::<Vec<_>>()
+        }?;
+        let parquet = std::env::temp_dir().join("polars_streaming_scan.parquet");
+        ParquetWriter::new(File::create(&parquet)?)
+            .with_row_group_size(Some(3))
+            .finish(&df)?;
+        let ipc = std::env::temp_dir().join("polars_streaming_scan.ipc");
+        let mut batches = df.slice(0, 4);
+        batches.vstack_mut(&df.slice(4, 6))?;
+        IpcWriter::new(&mut File::create(&ipc)?).finish(&batches)?;
+
+        let expected = df
+            .clone()
+            .lazy()
+            .filter(col("a").gt(lit(2)))
+            .select(vec![col("b"), (col("a") * lit(2)).alias("c")])
+            .collect()?;
+        let scans = vec![
+            LazyFrame::new_from_parquet(parquet.to_string_lossy().to_string(), None, false)?,
+            LazyFrame::scan_ipc(ipc.to_string_lossy().to_string(), None, false)?,
+        ];
+        for scan in scans {
+            let query = scan
+                .filter(col("a").gt(lit(2)))
+                .select(vec![col("b"), (col("a") * lit(2)).alias("c")]);
+
+            let mut expr_arena = Arena::with_capacity(16);
+            let mut lp_arena = Arena::with_capacity(16);
+            let root = query.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+            assert!(streamable_source(root, &lp_arena, &expr_arena).is_some());
+
+            assert!(query.clone().collect_streaming(2)?.frame_equal(&expected));
+            let path = std::env::temp_dir().join("polars_streaming_scan_sink.parquet");
+            query.sink_parquet(&path, ParquetWriteOptions::default())?;
+            let out = ParquetReader::new(File::open(&path)?).finish()?;
+            assert!(out.frame_equal(&expected));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_profile() -> Result<()> {
+        let left = df![
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "z"]
+        ]?;
+        let right = df![
+            "a" => [1, 2, 4],
+            "c" => [1.0, 2.0, 3.0]
+        ]?;
+        let (out, timings) = left
+            .clone()
+            .lazy()
+            .inner_join(right.clone().lazy(), col("a"), col("a"))
+            .filter(col("c").gt(lit(1.0)))
+            .profile()?;
+        let expected = left
+            .lazy()
+            .inner_join(right.lazy(), col("a"), col("a"))
+            .filter(col("c").gt(lit(1.0)))
+            .collect()?;
+        assert!(out.frame_equal(&expected));
+
+        assert_eq!(timings.get_column_names(), &["node", "start", "end"]);
+        let nodes = timings.column("node")?.utf8()?;
+        assert!(nodes.into_iter().any(|name| name == Some("join")));
+        assert!(nodes.into_iter().any(|name| name == Some("df_scan")));
+        let start = timings.column("start")?.u64()?;
+        let end = timings.column("end")?.u64()?;
+        assert!(start
+            .into_iter()
+            .zip(end.into_iter())
+            .all(|(start, end)| start <= end));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_join_order() -> Result<()> {
+        let orders = df![
+            "order" => [1, 2, 3, 4, 5, 6],
+            "customer" => [1, 2, 1, 3, 2, 1],
+            "product" => [1, 1, 2, 2, 3, 1]
+        ]?;
+        let customers = df![
+            "customer_id" => [1, 2, 3, 4, 5, 6, 7, 8],
+            "name" => ["a", "b", "c", "d", "e", "f", "g", "h"]
+        ]?;
+        let products = df![
+            "product_id" => [1, 2],
+            "price" => [1.0, 2.0]
+        ]?;
+
+        let q = orders
+            .lazy()
+            .inner_join(customers.lazy(), col("customer"), col("customer_id"))
+            .inner_join(products.lazy(), col("product"), col("product_id"));
+        let mut expr_arena = Arena::with_capacity(16);
+        let mut lp_arena = Arena::with_capacity(16);
+        // the joins are only reordered if the optimization is enabled
+        let lp_top = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        assert!(matches!(lp_arena.get(lp_top), ALogicalPlan::Join { .. }));
+
+        let q = q.with_join_order(true);
+        let lp_top = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        // the small products table is joined first
+        let join = match lp_arena.get(lp_top) {
+            ALogicalPlan::Projection { input, .. } => lp_arena.get(*input),
+            _ => panic!("expected the joins to be reordered"),
+        };
+        match join {
+            ALogicalPlan::Join { input_left, .. } => assert!(matches!(
+                lp_arena.get(*input_left),
+                ALogicalPlan::Join { input_right, .. }
+                    if lp_arena.get(*input_right).schema(&lp_arena).index_of("price").is_ok()
+            )),
+            _ => panic!("expected a join"),
+        }
+
+        let out = q.clone().sort("order", false).collect()?;
+        let expected = q.with_join_order(false).sort("order", false).collect()?;
+        assert_eq!(
+            out.get_column_names(),
+            &["order", "customer", "product", "name", "price"]
+        );
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_fetch() -> Result<()> {
+        let df = df![
+            "a" => (0..100).collect::<Vec<i32>>(),
+            "b" => (0..100).map(|v| v % 3).collect::<Vec<i32>>()
+        ]?;
+        let q = df
+            .lazy()
+            .filter(col("a").gt(lit(5)))
+            .groupby(vec![col("b")])
+            .agg(vec![col("a").count()]);
+
+        // only the first 10 rows are scanned
+        let out = q.clone().fetch(10)?;
+        assert_eq!(out.column("a_count")?.sum::<u32>(), Some(4));
+        // the fetch does not leak into the next query
+        let out = q.collect()?;
+        assert_eq!(out.column("a_count")?.sum::<u32>(), Some(94));
+
+        let out = scan_foods_csv().fetch(5)?;
+        assert_eq!(out.height(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_groupby_apply() -> Result<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "b", "a"],
+            "v" => [1, 2, 3, 4, 5]
+        ]?;
+
+        // keep the largest value per group
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("g")])
+            .apply(|df| df.sort("v", true).map(|df| df.head(Some(1))))
+            .select(vec![col("v")])
+            .sort("v", false)
+            .collect()?;
+        assert_eq!(Vec::from(out.column("v")?.i32()?), &[Some(4), Some(5)]);
+
+        // the output has a different schema than the input
+        let schema = Schema::new(vec![
+            Field::new("g", DataType::Utf8),
+            Field::new("n", DataType::UInt32),
+        ]);
+        let out = df
+            .lazy()
+            .groupby(vec![col("g")])
+            .apply_with_schema(
+                |df| {
+                    let g = df.column("g")?.head(Some(1));
+                    let n = Series::new("n", &[df.height() as u32]);
+                    DataFrame::new(vec![g, n])
+                },
+                schema,
+            )
+            .sort("g", false)
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["g", "n"]);
+        assert_eq!(Vec::from(out.column("n")?.u32()?), &[Some(3), Some(2)]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serialize")]
+    fn test_lazy_serde() -> Result<()> {
+        let df = df![
+            "a" => [1, 2, 3, 1],
+            "b" => ["x", "y", "z", "x"]
+        ]?;
+
+        let lf = df
+            .clone()
+            .lazy()
+            .filter(col("a").gt(lit(1)).or(col("b").eq(lit("x"))))
+            .groupby(vec![col("b")])
+            .agg(vec![col("a").sum()])
+            .sort("b", false);
+
+        let json = serde_json::to_string(&lf).unwrap();
+        let deserialized: LazyFrame = serde_json::from_str(&json).unwrap();
+        assert!(deserialized.collect()?.frame_equal(&lf.collect()?));
+
+        // closures can't be serialized
+        let lf = df
+            .clone()
+            .lazy()
+            .map(|df: DataFrame| Ok(df.head(Some(1))), None, None);
+        assert!(serde_json::to_string(&lf).is_err());
+        // neither can the built-in expressions that are implemented with a closure
+        let lf = df.clone().lazy().select(vec![col("a").is_nan()]);
+        assert!(serde_json::to_string(&lf).is_err());
+
+        // a deserialized cache gets a fresh id, which is shared by its clones
+        let cached = df.lazy().cache();
+        let lf = cached.clone().inner_join(cached, col("a"), col("a"));
+        let json = serde_json::to_string(&lf).unwrap();
+        let deserialized: LazyFrame = serde_json::from_str(&json).unwrap();
+        let cache_ids = |lf: &LazyFrame| match &lf.logical_plan {
+            LogicalPlan::Join {
+                input_left,
+                input_right,
+                ..
+            } => match (&**input_left, &**input_right) {
+                (LogicalPlan::Cache { id: left, .. }, LogicalPlan::Cache { id: right, .. }) => {
+                    (*left, *right)
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+        let (left, right) = cache_ids(&deserialized);
+        assert_eq!(left, right);
+        assert_ne!(left, cache_ids(&lf).0);
+        assert!(deserialized.collect()?.frame_equal(&lf.collect()?));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "csv-file")]
+    fn test_lazy_row_count() -> Result<()> {
+        let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
+        // the rows are counted before the filter, which is not pushed down to the scan
+        let out = LazyCsvReader::new(path.to_string())
+            .with_row_count(Some(RowCount {
+                name: "row_nr".into(),
+                offset: 1,
+            }))
+            .finish()?
+            .filter(col("category").eq(lit("seafood")))
+            .select(&[col("row_nr"), col("calories")])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["row_nr", "calories"]);
+        assert_eq!(out.height(), 8);
+        assert_eq!(out.column("row_nr")?.u32()?.get(0), Some(2));
+
+        let out = df!("a" => [3, 1, 2])?
+            .lazy()
+            .with_row_count("idx", None)
+            .filter(col("a").lt(lit(3)))
+            .collect()?;
+        assert_eq!(Vec::from(out.column("idx")?.u32()?), &[Some(1), Some(2)]);
+        Ok(())
+    }
 }
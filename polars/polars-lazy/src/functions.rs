@@ -74,7 +74,26 @@ pub fn argsort_by(by: Vec<Expr>, reverse: &[bool]) -> Expr {
     Expr::Function {
         input: by,
         function,
-        output_type: Some(DataType::UInt32),
+        output_type: GetOutput::DataType(DataType::UInt32),
+        collect_groups: true,
+    }
+}
+
+/// Horizontally concatenate the string values of multiple columns, separated by `delimiter`.
+/// Columns that are not of type `Utf8` are cast first.
+///
+/// If `ignore_nulls` is true, null values are skipped. Otherwise a row is null if any of its
+/// values is null.
+pub fn concat_str(s: Vec<Expr>, delimiter: &str, ignore_nulls: bool) -> Expr {
+    let delimiter = delimiter.to_string();
+    let function = NoEq::new(Arc::new(move |s: &mut [Series]| {
+        polars_core::functions::concat_str(s, &delimiter, ignore_nulls).map(|ca| ca.into_series())
+    }) as Arc<dyn SeriesUdf>);
+
+    Expr::Function {
+        input: s,
+        function,
+        output_type: GetOutput::DataType(DataType::Utf8),
         collect_groups: true,
     }
 }
@@ -105,7 +105,7 @@
 //!     .agg(vec![
 //!         col("rain").min(),
 //!         col("rain").sum(),
-//!         col("rain").quantile(0.5).alias("median_rain"),
+//!         col("rain").quantile(0.5, QuantileInterpolOptions::Nearest).alias("median_rain"),
 //!     ])
 //!     .sort("date", false)
 //!     .collect()
@@ -194,6 +194,9 @@ pub mod functions;
 pub mod logical_plan;
 pub mod physical_plan;
 pub mod prelude;
+#[cfg(feature = "sql")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sql")))]
+pub mod sql;
 pub(crate) mod utils;
 
 #[cfg(test)]
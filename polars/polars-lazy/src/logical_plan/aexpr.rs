@@ -16,12 +16,25 @@ pub enum AAggExpr {
     Last(Node),
     Mean(Node),
     List(Node),
-    Quantile { expr: Node, quantile: f64 },
+    Quantile {
+        expr: Node,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    },
     Sum(Node),
     Count(Node),
     Std(Node),
     Var(Node),
     AggGroups(Node),
+    Skew {
+        expr: Node,
+        bias: bool,
+    },
+    Kurtosis {
+        expr: Node,
+        fisher: bool,
+        bias: bool,
+    },
 }
 
 // AExpr representation of Nodes which are allocated in an Arena
@@ -72,7 +85,7 @@ pub enum AExpr {
     Function {
         input: Vec<Node>,
         function: NoEq<Arc<dyn SeriesUdf>>,
-        output_type: Option<DataType>,
+        output_type: GetOutput,
         collect_groups: bool,
     },
     Shift {
@@ -159,7 +172,10 @@ impl AExpr {
                     | Operator::LtEq
                     | Operator::GtEq
                     | Operator::Or => DataType::Boolean,
-                    _ => get_supertype(&left_type, &right_type)?,
+                    _ => match op.temporal_output_type(&left_type, &right_type) {
+                        Some(dtype) => dtype,
+                        None => get_supertype(&left_type, &right_type)?,
+                    },
                 };
 
                 use Operator::*;
@@ -219,11 +235,12 @@ impl AExpr {
                         ctxt,
                         GroupByMethod::Last,
                     ),
-                    List(expr) => field_by_context(
-                        arena.get(*expr).to_field(schema, ctxt, arena)?,
-                        ctxt,
-                        GroupByMethod::List,
-                    ),
+                    List(expr) => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let field =
+                            Field::new(field.name(), DataType::List(field.data_type().to_arrow()));
+                        field_by_context(field, ctxt, GroupByMethod::List)
+                    }
                     Std(expr) => {
                         let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
                         let field = Field::new(field.name(), DataType::Float64);
@@ -272,7 +289,7 @@ impl AExpr {
                         let new_name = fmt_groupby_column(field.name(), GroupByMethod::Groups);
                         Field::new(&new_name, DataType::List(ArrowDataType::UInt32))
                     }
-                    Quantile { expr, quantile } => {
+                    Quantile { expr, quantile, .. } => {
                         let mut field = field_by_context(
                             arena.get(*expr).to_field(schema, ctxt, arena)?,
                             ctxt,
@@ -281,6 +298,24 @@ impl AExpr {
                         field.coerce(DataType::Float64);
                         field
                     }
+                    Skew { expr, bias } => {
+                        let mut field = field_by_context(
+                            arena.get(*expr).to_field(schema, ctxt, arena)?,
+                            ctxt,
+                            GroupByMethod::Skew(*bias),
+                        );
+                        field.coerce(DataType::Float64);
+                        field
+                    }
+                    Kurtosis { expr, fisher, bias } => {
+                        let mut field = field_by_context(
+                            arena.get(*expr).to_field(schema, ctxt, arena)?,
+                            ctxt,
+                            GroupByMethod::Kurtosis(*fisher, *bias),
+                        );
+                        field.coerce(DataType::Float64);
+                        field
+                    }
                 };
                 Ok(field)
             }
@@ -291,13 +326,10 @@ impl AExpr {
             Ternary { truthy, .. } => arena.get(*truthy).to_field(schema, ctxt, arena),
             Function {
                 output_type, input, ..
-            } => match output_type {
-                None => arena.get(input[0]).to_field(schema, ctxt, arena),
-                Some(output_type) => {
-                    let input_field = arena.get(input[0]).to_field(schema, ctxt, arena)?;
-                    Ok(Field::new(input_field.name(), output_type.clone()))
-                }
-            },
+            } => {
+                let input_field = arena.get(input[0]).to_field(schema, ctxt, arena)?;
+                output_type.get_field(&input_field)
+            }
             BinaryFunction {
                 input_a,
                 input_b,
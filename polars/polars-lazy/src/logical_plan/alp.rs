@@ -1,12 +1,14 @@
-use crate::logical_plan::{det_melt_schema, Context};
+use crate::logical_plan::{
+    det_explode_schema, det_melt_schema, det_melt_value_vars, join_has_right_columns, Context,
+};
 use crate::prelude::*;
 use crate::utils::{aexprs_to_schema, PushNode};
 use ahash::RandomState;
-use polars_core::frame::hash_join::JoinType;
+use polars_core::frame::hash_join::{DuplicateColumns, JoinType};
 use polars_core::prelude::*;
 use polars_core::utils::{Arena, Node};
 use std::collections::HashSet;
-#[cfg(any(feature = "csv-file", feature = "parquet"))]
+#[cfg(any(feature = "csv-file", feature = "parquet", feature = "ipc"))]
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -42,6 +44,7 @@ pub enum ALogicalPlan {
         aggregate: Vec<Node>,
         cache: bool,
         low_memory: bool,
+        row_count: Option<RowCount>,
     },
     #[cfg(feature = "parquet")]
     ParquetScan {
@@ -52,6 +55,20 @@ pub enum ALogicalPlan {
         aggregate: Vec<Node>,
         stop_after_n_rows: Option<usize>,
         cache: bool,
+        cache: bool,
+        row_count: Option<RowCount>,
+    },
+    #[cfg(feature = "ipc")]
+    IpcScan {
+        path: PathBuf,
+        schema: SchemaRef,
+        with_columns: Option<Vec<String>>,
+        predicate: Option<Node>,
+        aggregate: Vec<Node>,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+        cache: bool,
+        row_count: Option<RowCount>,
     },
     DataFrameScan {
         df: Arc<DataFrame>,
@@ -73,13 +90,16 @@ pub enum ALogicalPlan {
         input: Node,
         by_column: Vec<Node>,
         reverse: Vec<bool>,
+        nulls_last: bool,
     },
     Explode {
         input: Node,
         columns: Vec<String>,
+        schema: SchemaRef,
     },
     Cache {
         input: Node,
+        id: usize,
     },
     Aggregate {
         input: Node,
@@ -87,6 +107,7 @@ pub enum ALogicalPlan {
         aggs: Vec<Node>,
         schema: SchemaRef,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        options: GroupbyOptions,
     },
     Join {
         input_left: Node,
@@ -97,6 +118,7 @@ pub enum ALogicalPlan {
         right_on: Vec<Node>,
         allow_par: bool,
         force_par: bool,
+        duplicates: DuplicateColumns,
     },
     HStack {
         input: Node,
@@ -107,6 +129,7 @@ pub enum ALogicalPlan {
         input: Node,
         maintain_order: bool,
         subset: Arc<Option<Vec<String>>>,
+        keep: UniqueKeepStrategy,
     },
     Udf {
         input: Node,
@@ -117,6 +140,9 @@ pub enum ALogicalPlan {
         projection_pd: bool,
         schema: Option<SchemaRef>,
     },
+    Union {
+        inputs: Vec<Node>,
+    },
 }
 
 impl Default for ALogicalPlan {
@@ -134,11 +160,13 @@ impl ALogicalPlan {
     pub(crate) fn schema<'a>(&'a self, arena: &'a Arena<ALogicalPlan>) -> &'a Schema {
         use ALogicalPlan::*;
         match self {
-            Cache { input } => arena.get(*input).schema(arena),
+            Cache { input, .. } => arena.get(*input).schema(arena),
             Sort { input, .. } => arena.get(*input).schema(arena),
-            Explode { input, .. } => arena.get(*input).schema(arena),
+            Explode { schema, .. } => schema,
             #[cfg(feature = "parquet")]
             ParquetScan { schema, .. } => schema,
+            #[cfg(feature = "ipc")]
+            IpcScan { schema, .. } => schema,
             DataFrameScan { schema, .. } => schema,
             Selection { input, .. } => arena.get(*input).schema(arena),
             #[cfg(feature = "csv-file")]
@@ -155,6 +183,35 @@ impl ALogicalPlan {
                 Some(schema) => schema,
                 None => arena.get(*input).schema(arena),
             },
+            Union { inputs } => arena.get(inputs[0]).schema(arena),
+        }
+    }
+
+    /// Short name of the operation of this node.
+    pub(crate) fn name(&self) -> &'static str {
+        use ALogicalPlan::*;
+        match self {
+            Melt { .. } => "melt",
+            Slice { .. } => "slice",
+            Selection { .. } => "filter",
+            #[cfg(feature = "csv-file")]
+            CsvScan { .. } => "csv_scan",
+            #[cfg(feature = "parquet")]
+            ParquetScan { .. } => "parquet_scan",
+            #[cfg(feature = "ipc")]
+            IpcScan { .. } => "ipc_scan",
+            DataFrameScan { .. } => "df_scan",
+            Projection { .. } => "projection",
+            LocalProjection { .. } => "local_projection",
+            Sort { .. } => "sort",
+            Explode { .. } => "explode",
+            Cache { .. } => "cache",
+            Aggregate { .. } => "groupby",
+            Join { .. } => "join",
+            HStack { .. } => "with_columns",
+            Distinct { .. } => "distinct",
+            Udf { .. } => "udf",
+            Union { .. } => "union",
         }
     }
 
@@ -183,6 +240,10 @@ impl ALogicalPlan {
                 (ParquetScan { path: path_a, .. }, ParquetScan { path: path_b, .. }) => {
                     canonicalize(path_a).unwrap() == canonicalize(path_b).unwrap()
                 }
+                #[cfg(feature = "ipc")]
+                (IpcScan { path: path_a, .. }, IpcScan { path: path_b, .. }) => {
+                    canonicalize(path_a).unwrap() == canonicalize(path_b).unwrap()
+                }
                 (DataFrameScan { df: df_a, .. }, DataFrameScan { df: df_b, .. }) => {
                     df_a.ptr_equal(df_b)
                 }
@@ -194,27 +255,31 @@ impl ALogicalPlan {
                     Sort {
                         by_column: l,
                         reverse: r_l,
+                        nulls_last: n_l,
                         ..
                     },
                     Sort {
                         by_column: r,
                         reverse: r_r,
+                        nulls_last: n_r,
                         ..
                     },
-                ) => l == r && r_l == r_r,
+                ) => l == r && r_l == r_r && n_l == n_r,
                 (Explode { columns: l, .. }, Explode { columns: r, .. }) => l == r,
                 (
                     Distinct {
                         maintain_order: l1,
                         subset: l2,
+                        keep: l3,
                         ..
                     },
                     Distinct {
                         maintain_order: r1,
                         subset: r2,
+                        keep: r3,
                         ..
                     },
-                ) => l1 == r1 && l2 == r2,
+                ) => l1 == r1 && l2 == r2 && l3 == r3,
                 (a, b) => {
                     std::mem::discriminant(a) == std::mem::discriminant(b)
                         && a.schema(lp_arena) == b.schema(lp_arena)
@@ -270,6 +335,7 @@ impl ALogicalPlan {
                 keys,
                 schema,
                 apply,
+                options,
                 ..
             } => Aggregate {
                 input: inputs[0],
@@ -277,6 +343,7 @@ impl ALogicalPlan {
                 aggs: exprs[keys.len()..].to_vec(),
                 schema: schema.clone(),
                 apply: apply.clone(),
+                options: options.clone(),
             },
             Join {
                 schema,
@@ -284,6 +351,7 @@ impl ALogicalPlan {
                 left_on,
                 allow_par,
                 force_par,
+                duplicates,
                 ..
             } => Join {
                 input_left: inputs[0],
@@ -294,27 +362,40 @@ impl ALogicalPlan {
                 right_on: exprs[left_on.len()..].to_vec(),
                 allow_par: *allow_par,
                 force_par: *force_par,
+                duplicates: duplicates.clone(),
             },
             Sort {
-                by_column, reverse, ..
+                by_column,
+                reverse,
+                nulls_last,
+                ..
             } => Sort {
                 input: inputs[0],
                 by_column: by_column.clone(),
                 reverse: reverse.clone(),
+                nulls_last: *nulls_last,
             },
-            Explode { columns, .. } => Explode {
+            Explode {
+                columns, schema, ..
+            } => Explode {
                 input: inputs[0],
                 columns: columns.clone(),
+                schema: schema.clone(),
+            },
+            Cache { id, .. } => Cache {
+                input: inputs[0],
+                id: *id,
             },
-            Cache { .. } => Cache { input: inputs[0] },
             Distinct {
                 maintain_order,
                 subset,
+                keep,
                 ..
             } => Distinct {
                 input: inputs[0],
                 maintain_order: *maintain_order,
                 subset: subset.clone(),
+                keep: *keep,
             },
             HStack { schema, .. } => HStack {
                 input: inputs[0],
@@ -329,6 +410,7 @@ impl ALogicalPlan {
                 predicate,
                 stop_after_n_rows,
                 cache,
+                row_count,
                 ..
             } => {
                 let mut new_predicate = None;
@@ -344,6 +426,34 @@ impl ALogicalPlan {
                     aggregate: exprs,
                     stop_after_n_rows: *stop_after_n_rows,
                     cache: *cache,
+                    row_count: row_count.clone(),
+                }
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                with_columns,
+                predicate,
+                stop_after_n_rows,
+                cache,
+                row_count,
+                ..
+            } => {
+                let mut new_predicate = None;
+                if predicate.is_some() {
+                    new_predicate = exprs.pop()
+                }
+
+                IpcScan {
+                    path: path.clone(),
+                    schema: schema.clone(),
+                    with_columns: with_columns.clone(),
+                    predicate: new_predicate,
+                    aggregate: exprs,
+                    stop_after_n_rows: *stop_after_n_rows,
+                    cache: *cache,
+                    row_count: row_count.clone(),
                 }
             }
             #[cfg(feature = "csv-file")]
@@ -359,6 +469,7 @@ impl ALogicalPlan {
                 predicate,
                 cache,
                 low_memory,
+                row_count,
                 ..
             } => {
                 let mut new_predicate = None;
@@ -378,6 +489,7 @@ impl ALogicalPlan {
                     aggregate: exprs,
                     cache: *cache,
                     low_memory: *low_memory,
+                    row_count: row_count.clone(),
                 }
             }
             DataFrameScan {
@@ -415,6 +527,7 @@ impl ALogicalPlan {
                 projection_pd: *projection_pd,
                 schema: schema.clone(),
             },
+            Union { .. } => Union { inputs },
         }
     }
 
@@ -428,7 +541,8 @@ impl ALogicalPlan {
             | Explode { .. }
             | Cache { .. }
             | Distinct { .. }
-            | Udf { .. } => {}
+            | Udf { .. }
+            | Union { .. } => {}
             Selection { predicate, .. } => container.push(*predicate),
             Projection { expr, .. } => container.extend_from_slice(expr),
             LocalProjection { expr, .. } => container.extend_from_slice(expr),
@@ -454,6 +568,17 @@ impl ALogicalPlan {
                     container.push(*node)
                 }
             }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                predicate,
+                aggregate,
+                ..
+            } => {
+                container.extend_from_slice(aggregate);
+                if let Some(node) = predicate {
+                    container.push(*node)
+                }
+            }
             #[cfg(feature = "csv-file")]
             CsvScan {
                 predicate,
@@ -514,11 +639,19 @@ impl ALogicalPlan {
                 container.push_node(*input_right);
                 return;
             }
+            Union { inputs } => {
+                for node in inputs {
+                    container.push_node(*node);
+                }
+                return;
+            }
             HStack { input, .. } => *input,
             Distinct { input, .. } => *input,
             Udf { input, .. } => *input,
             #[cfg(feature = "parquet")]
             ParquetScan { .. } => return,
+            #[cfg(feature = "ipc")]
+            IpcScan { .. } => return,
             #[cfg(feature = "csv-file")]
             CsvScan { .. } => return,
             DataFrameScan { .. } => return,
@@ -553,7 +686,8 @@ impl<'a> ALogicalPlanBuilder<'a> {
     }
 
     pub fn melt(self, id_vars: Arc<Vec<String>>, value_vars: Arc<Vec<String>>) -> Self {
-        let schema = det_melt_schema(&value_vars, self.schema());
+        let value_vars = det_melt_value_vars(&id_vars, value_vars, self.schema());
+        let schema = det_melt_schema(&id_vars, &value_vars, self.schema());
 
         let lp = ALogicalPlan::Melt {
             input: self.root,
@@ -565,6 +699,18 @@ impl<'a> ALogicalPlanBuilder<'a> {
         ALogicalPlanBuilder::new(node, self.expr_arena, self.lp_arena)
     }
 
+    pub fn explode(self, columns: Vec<String>) -> Self {
+        let schema = det_explode_schema(&columns, self.schema());
+
+        let lp = ALogicalPlan::Explode {
+            input: self.root,
+            columns,
+            schema,
+        };
+        let node = self.lp_arena.add(lp);
+        ALogicalPlanBuilder::new(node, self.expr_arena, self.lp_arena)
+    }
+
     pub fn project_local(self, exprs: Vec<Node>) -> Self {
         let input_schema = self.lp_arena.get(self.root).schema(self.lp_arena);
         let schema = aexprs_to_schema(&exprs, input_schema, Context::Default, self.expr_arena);
@@ -647,8 +793,9 @@ impl<'a> ALogicalPlanBuilder<'a> {
         keys: Vec<Node>,
         aggs: Vec<Node>,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        options: GroupbyOptions,
     ) -> Self {
-        debug_assert!(!keys.is_empty());
+        debug_assert!(!keys.is_empty() || options.index_column().is_some());
         let current_schema = self.schema();
         // TODO! add this line if LogicalPlan is dropped in favor of ALogicalPlan
         // let aggs = rewrite_projections(aggs, current_schema);
@@ -657,7 +804,9 @@ impl<'a> ALogicalPlanBuilder<'a> {
         let schema2 =
             aexprs_to_schema(&aggs, current_schema, Context::Aggregation, self.expr_arena);
 
-        let schema = Schema::try_merge(&[schema1, schema2]).unwrap();
+        let index_schema = Schema::new(options.key_fields(current_schema));
+
+        let schema = Schema::try_merge(&[schema1, index_schema, schema2]).unwrap();
 
         let lp = ALogicalPlan::Aggregate {
             input: self.root,
@@ -665,11 +814,13 @@ impl<'a> ALogicalPlanBuilder<'a> {
             aggs,
             schema: Arc::new(schema),
             apply,
+            options,
         };
         let root = self.lp_arena.add(lp);
         Self::new(root, self.expr_arena, self.lp_arena)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn join(
         self,
         other: Node,
@@ -678,6 +829,7 @@ impl<'a> ALogicalPlanBuilder<'a> {
         right_on: Vec<Node>,
         allow_par: bool,
         force_par: bool,
+        duplicates: DuplicateColumns,
     ) -> Self {
         let schema_left = self.schema();
         let schema_right = self.lp_arena.get(other).schema(self.lp_arena);
@@ -706,13 +858,14 @@ impl<'a> ALogicalPlanBuilder<'a> {
 
         for f in schema_right.fields() {
             let name = f.name();
-            if !right_names.contains(name) {
-                if names.contains(name) {
-                    let new_name = format!("{}_right", name);
-                    let field = Field::new(&new_name, f.data_type().clone());
-                    fields.push(field)
-                } else {
-                    fields.push(f.clone())
+            if !right_names.contains(name) && join_has_right_columns(how) {
+                match &duplicates {
+                    DuplicateColumns::Suffix(suffix) if names.contains(name) => {
+                        let new_name = format!("{}{}", name, suffix);
+                        let field = Field::new(&new_name, f.data_type().clone());
+                        fields.push(field)
+                    }
+                    _ => fields.push(f.clone()),
                 }
             }
         }
@@ -728,6 +881,7 @@ impl<'a> ALogicalPlanBuilder<'a> {
             right_on,
             allow_par,
             force_par,
+            duplicates,
         };
         let root = self.lp_arena.add(lp);
         Self::new(root, self.expr_arena, self.lp_arena)
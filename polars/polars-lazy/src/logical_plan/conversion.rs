@@ -60,14 +60,28 @@ pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
                 AggExpr::Mean(expr) => AAggExpr::Mean(to_aexpr(*expr, arena)),
                 AggExpr::List(expr) => AAggExpr::List(to_aexpr(*expr, arena)),
                 AggExpr::Count(expr) => AAggExpr::Count(to_aexpr(*expr, arena)),
-                AggExpr::Quantile { expr, quantile } => AAggExpr::Quantile {
+                AggExpr::Quantile {
+                    expr,
+                    quantile,
+                    interpol,
+                } => AAggExpr::Quantile {
                     expr: to_aexpr(*expr, arena),
                     quantile,
+                    interpol,
                 },
                 AggExpr::Sum(expr) => AAggExpr::Sum(to_aexpr(*expr, arena)),
                 AggExpr::Std(expr) => AAggExpr::Std(to_aexpr(*expr, arena)),
                 AggExpr::Var(expr) => AAggExpr::Var(to_aexpr(*expr, arena)),
                 AggExpr::AggGroups(expr) => AAggExpr::AggGroups(to_aexpr(*expr, arena)),
+                AggExpr::Skew { expr, bias } => AAggExpr::Skew {
+                    expr: to_aexpr(*expr, arena),
+                    bias,
+                },
+                AggExpr::Kurtosis { expr, fisher, bias } => AAggExpr::Kurtosis {
+                    expr: to_aexpr(*expr, arena),
+                    fisher,
+                    bias,
+                },
             };
             AExpr::Agg(a_agg)
         }
@@ -181,6 +195,7 @@ pub(crate) fn to_alp(
             aggregate,
             cache,
             low_memory,
+            row_count,
         } => ALogicalPlan::CsvScan {
             path,
             schema,
@@ -197,6 +212,7 @@ pub(crate) fn to_alp(
                 .collect(),
             cache,
             low_memory,
+            row_count,
         },
         #[cfg(feature = "parquet")]
         LogicalPlan::ParquetScan {
@@ -207,6 +223,7 @@ pub(crate) fn to_alp(
             aggregate,
             stop_after_n_rows,
             cache,
+            row_count,
         } => ALogicalPlan::ParquetScan {
             path,
             schema,
@@ -218,6 +235,30 @@ pub(crate) fn to_alp(
                 .collect(),
             stop_after_n_rows,
             cache,
+            row_count,
+        },
+        #[cfg(feature = "ipc")]
+        LogicalPlan::IpcScan {
+            path,
+            schema,
+            with_columns,
+            predicate,
+            aggregate,
+            stop_after_n_rows,
+            cache,
+            row_count,
+        } => ALogicalPlan::IpcScan {
+            path,
+            schema,
+            with_columns,
+            predicate: predicate.map(|expr| to_aexpr(expr, expr_arena)),
+            aggregate: aggregate
+                .into_iter()
+                .map(|expr| to_aexpr(expr, expr_arena))
+                .collect(),
+            stop_after_n_rows,
+            cache,
+            row_count,
         },
         LogicalPlan::DataFrameScan {
             df,
@@ -261,6 +302,7 @@ pub(crate) fn to_alp(
             input,
             by_column,
             reverse,
+            nulls_last,
         } => {
             let input = to_alp(*input, expr_arena, lp_arena);
             let by_column = by_column
@@ -271,15 +313,24 @@ pub(crate) fn to_alp(
                 input,
                 by_column,
                 reverse,
+                nulls_last,
             }
         }
-        LogicalPlan::Explode { input, columns } => {
+        LogicalPlan::Explode {
+            input,
+            columns,
+            schema,
+        } => {
             let input = to_alp(*input, expr_arena, lp_arena);
-            ALogicalPlan::Explode { input, columns }
+            ALogicalPlan::Explode {
+                input,
+                columns,
+                schema,
+            }
         }
-        LogicalPlan::Cache { input } => {
+        LogicalPlan::Cache { input, id } => {
             let input = to_alp(*input, expr_arena, lp_arena);
-            ALogicalPlan::Cache { input }
+            ALogicalPlan::Cache { input, id }
         }
         LogicalPlan::Aggregate {
             input,
@@ -287,6 +338,7 @@ pub(crate) fn to_alp(
             aggs,
             schema,
             apply,
+            options,
         } => {
             let i = to_alp(*input, expr_arena, lp_arena);
             let aggs_new = aggs.into_iter().map(|x| to_aexpr(x, expr_arena)).collect();
@@ -301,6 +353,7 @@ pub(crate) fn to_alp(
                 aggs: aggs_new,
                 schema,
                 apply,
+                options,
             }
         }
         LogicalPlan::Join {
@@ -312,6 +365,7 @@ pub(crate) fn to_alp(
             right_on,
             allow_par,
             force_par,
+            duplicates,
         } => {
             let i_l = to_alp(*input_left, expr_arena, lp_arena);
             let i_r = to_alp(*input_right, expr_arena, lp_arena);
@@ -334,6 +388,7 @@ pub(crate) fn to_alp(
                 right_on: r_on,
                 allow_par,
                 force_par,
+                duplicates,
             }
         }
         LogicalPlan::HStack {
@@ -353,12 +408,14 @@ pub(crate) fn to_alp(
             input,
             maintain_order,
             subset,
+            keep,
         } => {
             let i = to_alp(*input, expr_arena, lp_arena);
             ALogicalPlan::Distinct {
                 input: i,
                 maintain_order,
                 subset,
+                keep,
             }
         }
         LogicalPlan::Udf {
@@ -377,6 +434,13 @@ pub(crate) fn to_alp(
                 schema,
             }
         }
+        LogicalPlan::Union { inputs } => {
+            let inputs = inputs
+                .into_iter()
+                .map(|lp| to_alp(lp, expr_arena, lp_arena))
+                .collect();
+            ALogicalPlan::Union { inputs }
+        }
     };
     lp_arena.add(v)
 }
@@ -489,11 +553,16 @@ pub(crate) fn node_to_exp(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::List(Box::new(exp)).into()
             }
-            AAggExpr::Quantile { expr, quantile } => {
+            AAggExpr::Quantile {
+                expr,
+                quantile,
+                interpol,
+            } => {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::Quantile {
                     expr: Box::new(exp),
                     quantile,
+                    interpol,
                 }
                 .into()
             }
@@ -513,6 +582,23 @@ pub(crate) fn node_to_exp(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::AggGroups(Box::new(exp)).into()
             }
+            AAggExpr::Skew { expr, bias } => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Skew {
+                    expr: Box::new(exp),
+                    bias,
+                }
+                .into()
+            }
+            AAggExpr::Kurtosis { expr, fisher, bias } => {
+                let exp = node_to_exp(expr, expr_arena);
+                AggExpr::Kurtosis {
+                    expr: Box::new(exp),
+                    fisher,
+                    bias,
+                }
+                .into()
+            }
             AAggExpr::Count(expr) => {
                 let exp = node_to_exp(expr, expr_arena);
                 AggExpr::Count(Box::new(exp)).into()
@@ -633,6 +719,7 @@ pub(crate) fn node_to_lp(
             aggregate,
             cache,
             low_memory,
+            row_count,
         } => LogicalPlan::CsvScan {
             path,
             schema,
@@ -646,6 +733,7 @@ pub(crate) fn node_to_lp(
             aggregate: nodes_to_exprs(&aggregate, expr_arena),
             cache,
             low_memory,
+            row_count,
         },
         #[cfg(feature = "parquet")]
         ALogicalPlan::ParquetScan {
@@ -656,6 +744,7 @@ pub(crate) fn node_to_lp(
             aggregate,
             stop_after_n_rows,
             cache,
+            row_count,
         } => LogicalPlan::ParquetScan {
             path,
             schema,
@@ -664,6 +753,27 @@ pub(crate) fn node_to_lp(
             aggregate: nodes_to_exprs(&aggregate, expr_arena),
             stop_after_n_rows,
             cache,
+            row_count,
+        },
+        #[cfg(feature = "ipc")]
+        ALogicalPlan::IpcScan {
+            path,
+            schema,
+            with_columns,
+            predicate,
+            aggregate,
+            stop_after_n_rows,
+            cache,
+            row_count,
+        } => LogicalPlan::IpcScan {
+            path,
+            schema,
+            with_columns,
+            predicate: predicate.map(|n| node_to_exp(n, expr_arena)),
+            aggregate: nodes_to_exprs(&aggregate, expr_arena),
+            stop_after_n_rows,
+            cache,
+            row_count,
         },
         ALogicalPlan::DataFrameScan {
             df,
@@ -708,21 +818,31 @@ pub(crate) fn node_to_lp(
             input,
             by_column,
             reverse,
+            nulls_last,
         } => {
             let input = Box::new(node_to_lp(input, expr_arena, lp_arena));
             LogicalPlan::Sort {
                 input,
                 by_column: nodes_to_exprs(&by_column, expr_arena),
                 reverse,
+                nulls_last,
             }
         }
-        ALogicalPlan::Explode { input, columns } => {
+        ALogicalPlan::Explode {
+            input,
+            columns,
+            schema,
+        } => {
             let input = Box::new(node_to_lp(input, expr_arena, lp_arena));
-            LogicalPlan::Explode { input, columns }
+            LogicalPlan::Explode {
+                input,
+                columns,
+                schema,
+            }
         }
-        ALogicalPlan::Cache { input } => {
+        ALogicalPlan::Cache { input, id } => {
             let input = Box::new(node_to_lp(input, expr_arena, lp_arena));
-            LogicalPlan::Cache { input }
+            LogicalPlan::Cache { input, id }
         }
         ALogicalPlan::Aggregate {
             input,
@@ -730,6 +850,7 @@ pub(crate) fn node_to_lp(
             aggs,
             schema,
             apply,
+            options,
         } => {
             let i = node_to_lp(input, expr_arena, lp_arena);
 
@@ -739,6 +860,7 @@ pub(crate) fn node_to_lp(
                 aggs: nodes_to_exprs(&aggs, expr_arena),
                 schema,
                 apply,
+                options,
             }
         }
         ALogicalPlan::Join {
@@ -750,6 +872,7 @@ pub(crate) fn node_to_lp(
             right_on,
             allow_par,
             force_par,
+            duplicates,
         } => {
             let i_l = node_to_lp(input_left, expr_arena, lp_arena);
             let i_r = node_to_lp(input_right, expr_arena, lp_arena);
@@ -763,6 +886,7 @@ pub(crate) fn node_to_lp(
                 right_on: nodes_to_exprs(&right_on, expr_arena),
                 allow_par,
                 force_par,
+                duplicates,
             }
         }
         ALogicalPlan::HStack {
@@ -782,12 +906,14 @@ pub(crate) fn node_to_lp(
             input,
             maintain_order,
             subset,
+            keep,
         } => {
             let i = node_to_lp(input, expr_arena, lp_arena);
             LogicalPlan::Distinct {
                 input: Box::new(i),
                 maintain_order,
                 subset,
+                keep,
             }
         }
         ALogicalPlan::Melt {
@@ -820,5 +946,12 @@ pub(crate) fn node_to_lp(
                 schema,
             }
         }
+        ALogicalPlan::Union { inputs } => {
+            let inputs = inputs
+                .into_iter()
+                .map(|node| node_to_lp(node, expr_arena, lp_arena))
+                .collect();
+            LogicalPlan::Union { inputs }
+        }
     }
 }
@@ -53,6 +53,8 @@ impl<'a> Iterator for ExprIter<'a> {
                         AggGroups(e) => push(e),
                         Std(e) => push(e),
                         Var(e) => push(e),
+                        Skew { expr, .. } => push(expr),
+                        Kurtosis { expr, .. } => push(expr),
                     }
                 }
                 Ternary {
@@ -155,6 +157,8 @@ impl AExpr {
                     AggGroups(e) => push(e),
                     Std(e) => push(e),
                     Var(e) => push(e),
+                    Skew { expr, .. } => push(expr),
+                    Kurtosis { expr, .. } => push(expr),
                 }
             }
             Ternary {
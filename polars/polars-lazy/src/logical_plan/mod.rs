@@ -1,24 +1,38 @@
 use std::collections::HashSet;
-#[cfg(any(feature = "csv-file", feature = "parquet"))]
+#[cfg(feature = "csv-file")]
+use std::path::Path;
+#[cfg(any(feature = "csv-file", feature = "parquet", feature = "ipc"))]
 use std::path::PathBuf;
 use std::{
     cell::Cell,
     fmt::{self, Debug, Formatter, Write},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
 };
 
 use ahash::RandomState;
 use itertools::Itertools;
 
-use polars_core::frame::hash_join::JoinType;
+use polars_core::frame::hash_join::{DuplicateColumns, JoinType};
 use polars_core::prelude::*;
 #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
 #[cfg(feature = "temporal")]
 use polars_core::utils::chrono::NaiveDateTime;
+use polars_core::utils::get_supertype;
 #[cfg(feature = "csv-file")]
 use polars_io::csv_core::utils::infer_file_schema;
+#[cfg(feature = "ipc")]
+use polars_io::ipc::IpcReader;
 #[cfg(feature = "parquet")]
-use polars_io::{parquet::ParquetReader, SerReader};
+use polars_io::parquet::ParquetReader;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+use polars_io::SerReader;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serialize")]
+use std::{cell::RefCell, collections::HashMap};
 
 use crate::logical_plan::LogicalPlan::DataFrameScan;
 use crate::utils::{
@@ -35,6 +49,8 @@ pub(crate) mod optimizer;
 
 // Will be set/ unset in the fetch operation to communicate overwriting the number of rows to scan.
 thread_local! {pub(crate) static FETCH_ROWS: Cell<Option<usize>> = Cell::new(None)}
+/// Source of the unique ids of the cache nodes.
+static CACHE_ID: AtomicUsize = AtomicUsize::new(0);
 
 #[derive(Clone, Copy, Debug)]
 pub enum Context {
@@ -63,7 +79,95 @@ impl Debug for dyn DataFrameUdf {
     }
 }
 
+/// Closures can't be serialized, so a groupby with an `apply` fails to serialize.
+#[cfg(feature = "serialize")]
+fn serialize_apply<S>(
+    apply: &Option<Arc<dyn DataFrameUdf>>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match apply {
+        Some(_) => Err(serde::ser::Error::custom(
+            "a groupby apply function cannot be serialized",
+        )),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(feature = "serialize")]
+fn deserialize_apply<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Arc<dyn DataFrameUdf>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<()>::deserialize(deserializer).map(|_| None)
+}
+
+#[cfg(feature = "serialize")]
+thread_local! {
+    /// Maps the cache ids of the plan that is being deserialized to fresh ids.
+    static CACHE_ID_MAP: RefCell<Option<HashMap<usize, usize>>> = RefCell::new(None);
+}
+
+/// Cache ids are only unique within the process that created them, so every deserialized cache
+/// gets a fresh id. Within [with_fresh_cache_ids], caches that shared an id in the serialized
+/// plan share their fresh id as well, so that their input is still computed once.
+#[cfg(feature = "serialize")]
+fn deserialize_cache_id<'de, D>(deserializer: D) -> std::result::Result<usize, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let id = usize::deserialize(deserializer)?;
+    let fresh_id = CACHE_ID_MAP.with(|map| match &mut *map.borrow_mut() {
+        Some(map) => *map
+            .entry(id)
+            .or_insert_with(|| CACHE_ID.fetch_add(1, Ordering::Relaxed)),
+        None => CACHE_ID.fetch_add(1, Ordering::Relaxed),
+    });
+    Ok(fresh_id)
+}
+
+/// Deserialize a plan in `f`, mapping equal cache ids to equal fresh ids.
+#[cfg(feature = "serialize")]
+pub(crate) fn with_fresh_cache_ids<T, F: FnOnce() -> T>(f: F) -> T {
+    let outer = CACHE_ID_MAP.with(|map| map.replace(Some(HashMap::new())));
+    let out = f();
+    CACHE_ID_MAP.with(|map| map.replace(outer));
+    out
+}
+
+/// (De)serialize a [NaiveDateTime] as milliseconds since the epoch, like the `Date64` values.
+#[cfg(all(feature = "serialize", feature = "temporal", feature = "dtype-date64"))]
+mod datetime_ms {
+    use super::*;
+
+    pub(super) fn serialize<S>(
+        datetime: &NaiveDateTime,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(datetime.timestamp_millis())
+    }
+
+    pub(super) fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> std::result::Result<NaiveDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ms = i64::deserialize(deserializer)?;
+        let nanos = (ms.rem_euclid(1000) * 1_000_000) as u32;
+        Ok(NaiveDateTime::from_timestamp(ms.div_euclid(1000), nanos))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub enum LiteralValue {
     Null,
     /// A binary true or false.
@@ -101,7 +205,7 @@ pub enum LiteralValue {
         data_type: DataType,
     },
     #[cfg(all(feature = "temporal", feature = "dtype-date64"))]
-    DateTime(NaiveDateTime),
+    DateTime(#[cfg_attr(feature = "serialize", serde(with = "datetime_ms"))] NaiveDateTime),
     Series(NoEq<Series>),
 }
 
@@ -135,16 +239,101 @@ impl LiteralValue {
     }
 }
 
+/// Options of a groupby that change how the groups are created.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct GroupbyOptions {
+    /// Group in (possibly overlapping) time windows.
+    #[cfg(feature = "dynamic_groupby")]
+    pub(crate) dynamic: Option<DynamicGroupOptions>,
+    /// Group in a window per row.
+    #[cfg(feature = "rolling_groupby")]
+    pub(crate) rolling: Option<RollingGroupOptions>,
+}
+
+impl GroupbyOptions {
+    /// Name of the column the groups are determined on, apart from the keys.
+    pub(crate) fn index_column(&self) -> Option<&str> {
+        #[cfg(feature = "dynamic_groupby")]
+        if let Some(dynamic) = &self.dynamic {
+            return Some(&dynamic.index_column);
+        }
+        #[cfg(feature = "rolling_groupby")]
+        if let Some(rolling) = &self.rolling {
+            return Some(&rolling.index_column);
+        }
+        None
+    }
+
+    #[cfg(feature = "dynamic_groupby")]
+    fn boundary_fields(&self) -> Vec<Field> {
+        match &self.dynamic {
+            Some(dynamic) if dynamic.include_boundaries => vec![
+                Field::new("_lower_boundary", DataType::Date64),
+                Field::new("_upper_boundary", DataType::Date64),
+            ],
+            _ => vec![],
+        }
+    }
+
+    #[cfg(not(feature = "dynamic_groupby"))]
+    fn boundary_fields(&self) -> Vec<Field> {
+        vec![]
+    }
+
+    /// Fields of the key columns that are created by the groupby, besides the keys.
+    pub(crate) fn key_fields(&self, input_schema: &Schema) -> Vec<Field> {
+        let mut fields = self.boundary_fields();
+        if let Some(field) = self
+            .index_column()
+            .and_then(|name| input_schema.field_with_name(name).ok())
+        {
+            fields.push(field.clone());
+        }
+        fields
+    }
+}
+
+/// A `UInt32` column that counts the rows of a scan, starting at `offset`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct RowCount {
+    pub name: String,
+    pub offset: u32,
+}
+
+/// Prepend the row count column to the schema of a scan.
+#[cfg(any(feature = "csv-file", feature = "parquet", feature = "ipc"))]
+fn schema_with_row_count(schema: SchemaRef, row_count: Option<&RowCount>) -> SchemaRef {
+    match row_count {
+        Some(row_count) => {
+            let mut fields = vec![Field::new(&row_count.name, DataType::UInt32)];
+            fields.extend(schema.fields().iter().cloned());
+            Arc::new(Schema::new(fields))
+        }
+        None => schema,
+    }
+}
+
 // https://stackoverflow.com/questions/1031076/what-are-projection-and-selection
 #[derive(Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub enum LogicalPlan {
     /// Filter on a boolean mask
     Selection {
         input: Box<LogicalPlan>,
         predicate: Expr,
     },
-    /// Cache the input at this point in the LP
-    Cache { input: Box<LogicalPlan> },
+    /// Cache the input at this point in the LP. Clones of the plan share the `id`, so the
+    /// input is computed once, even if it is used in multiple branches of the query.
+    Cache {
+        input: Box<LogicalPlan>,
+        #[cfg_attr(
+            feature = "serialize",
+            serde(deserialize_with = "deserialize_cache_id")
+        )]
+        id: usize,
+    },
     /// Scan a CSV file
     #[cfg(feature = "csv-file")]
     CsvScan {
@@ -162,6 +351,8 @@ pub enum LogicalPlan {
         aggregate: Vec<Expr>,
         cache: bool,
         low_memory: bool,
+        /// Add a row count column at index 0; it is part of the `schema`, but not of the file
+        row_count: Option<RowCount>,
     },
     #[cfg(feature = "parquet")]
     #[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
@@ -174,6 +365,22 @@ pub enum LogicalPlan {
         aggregate: Vec<Expr>,
         stop_after_n_rows: Option<usize>,
         cache: bool,
+        /// Add a row count column at index 0; it is part of the `schema`, but not of the file
+        row_count: Option<RowCount>,
+    },
+    #[cfg(feature = "ipc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
+    /// Scan an Arrow IPC file
+    IpcScan {
+        path: PathBuf,
+        schema: SchemaRef,
+        with_columns: Option<Vec<String>>,
+        predicate: Option<Expr>,
+        aggregate: Vec<Expr>,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+        /// Add a row count column at index 0; it is part of the `schema`, but not of the file
+        row_count: Option<RowCount>,
     },
     // we keep track of the projection and selection as it is cheaper to first project and then filter
     /// In memory DataFrame
@@ -202,7 +409,15 @@ pub enum LogicalPlan {
         keys: Arc<Vec<Expr>>,
         aggs: Vec<Expr>,
         schema: SchemaRef,
+        #[cfg_attr(
+            feature = "serialize",
+            serde(
+                serialize_with = "serialize_apply",
+                deserialize_with = "deserialize_apply"
+            )
+        )]
         apply: Option<Arc<dyn DataFrameUdf>>,
+        options: GroupbyOptions,
     },
     /// Join operation
     Join {
@@ -214,6 +429,7 @@ pub enum LogicalPlan {
         right_on: Vec<Expr>,
         allow_par: bool,
         force_par: bool,
+        duplicates: DuplicateColumns,
     },
     /// Adding columns to the table without a Join
     HStack {
@@ -226,17 +442,20 @@ pub enum LogicalPlan {
         input: Box<LogicalPlan>,
         maintain_order: bool,
         subset: Arc<Option<Vec<String>>>,
+        keep: UniqueKeepStrategy,
     },
     /// Sort the table
     Sort {
         input: Box<LogicalPlan>,
         by_column: Vec<Expr>,
         reverse: Vec<bool>,
+        nulls_last: bool,
     },
     /// An explode operation
     Explode {
         input: Box<LogicalPlan>,
         columns: Vec<String>,
+        schema: SchemaRef,
     },
     /// Slice the table
     Slice {
@@ -252,6 +471,7 @@ pub enum LogicalPlan {
         schema: SchemaRef,
     },
     /// A User Defined Function
+    #[cfg_attr(feature = "serialize", serde(skip))]
     Udf {
         input: Box<LogicalPlan>,
         function: Arc<dyn DataFrameUdf>,
@@ -261,6 +481,8 @@ pub enum LogicalPlan {
         projection_pd: bool,
         schema: Option<SchemaRef>,
     },
+    /// Vertical concatenation of plans with the same schema
+    Union { inputs: Vec<LogicalPlan> },
 }
 
 impl Default for LogicalPlan {
@@ -280,7 +502,7 @@ impl fmt::Debug for LogicalPlan {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use LogicalPlan::*;
         match self {
-            Cache { input } => write!(f, "CACHE {:?}", input),
+            Cache { input, id } => write!(f, "CACHE {}: {:?}", id, input),
             #[cfg(feature = "parquet")]
             ParquetScan {
                 path,
@@ -303,6 +525,28 @@ impl fmt::Debug for LogicalPlan {
                     predicate
                 )
             }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                with_columns,
+                predicate,
+                ..
+            } => {
+                let total_columns = schema.fields().len();
+                let mut n_columns = "*".to_string();
+                if let Some(columns) = with_columns {
+                    n_columns = format!("{}", columns.len());
+                }
+                write!(
+                    f,
+                    "IPC SCAN {}; PROJECT {}/{} COLUMNS; SELECTION: {:?}",
+                    path.to_string_lossy(),
+                    n_columns,
+                    total_columns,
+                    predicate
+                )
+            }
             Selection { predicate, input } => {
                 write!(f, "FILTER\n\t{:?}\nFROM\n\t{:?}", predicate, input)
             }
@@ -396,26 +640,41 @@ impl fmt::Debug for LogicalPlan {
                 write!(f, "SLICE {:?}, offset: {}, len: {}", input, offset, len)
             }
             Udf { input, .. } => write!(f, "UDF {:?}", input),
+            Union { inputs } => write!(f, "UNION {:?}", inputs),
         }
     }
 }
 
+/// Format an expression for a label in the dot representation. Truncated to `n` characters.
+fn fmt_dot_expr(expr: &Expr, n: usize) -> String {
+    let mut expr_fmt = format!("{:?}", expr);
+    expr_fmt = expr_fmt.replace("[", "");
+    expr_fmt = expr_fmt.replace("]", "");
+    // double quotes would end the label
+    expr_fmt = expr_fmt.replace("\"", "'");
+    if expr_fmt.len() > n {
+        let mut end = n;
+        while !expr_fmt.is_char_boundary(end) {
+            end -= 1;
+        }
+        expr_fmt.truncate(end);
+        expr_fmt.push_str("...")
+    }
+    expr_fmt
+}
+
 fn fmt_predicate(predicate: Option<&Expr>) -> String {
     if let Some(predicate) = predicate {
-        let n = 25;
-        let mut pred_fmt = format!("{:?}", predicate);
-        pred_fmt = pred_fmt.replace("[", "");
-        pred_fmt = pred_fmt.replace("]", "");
-        if pred_fmt.len() > n {
-            pred_fmt.truncate(n);
-            pred_fmt.push_str("...")
-        }
-        pred_fmt
+        fmt_dot_expr(predicate, 25)
     } else {
         "-".to_string()
     }
 }
 
+fn fmt_projection(exprs: &[Expr]) -> String {
+    exprs.iter().map(|e| fmt_dot_expr(e, 40)).join(",\n")
+}
+
 impl LogicalPlan {
     fn write_dot(
         &self,
@@ -446,8 +705,11 @@ impl LogicalPlan {
         use LogicalPlan::*;
         let (branch, id) = id;
         match self {
-            Cache { input } => {
-                let current_node = format!("CACHE [{:?}]", (branch, id));
+            Cache {
+                input,
+                id: cache_id,
+            } => {
+                let current_node = format!("CACHE {} [{:?}]", cache_id, (branch, id));
                 self.write_dot(acc_str, prev_node, &current_node, id)?;
                 input.dot(acc_str, (branch, id + 1), &current_node)
             }
@@ -516,7 +778,8 @@ impl LogicalPlan {
             }
             Projection { expr, input, .. } => {
                 let current_node = format!(
-                    "π {}/{} [{:?}]",
+                    "SELECT\n{}\nπ {}/{} [{:?}]",
+                    fmt_projection(expr),
                     expr.len(),
                     input.schema().fields().len(),
                     (branch, id)
@@ -527,13 +790,15 @@ impl LogicalPlan {
             Sort {
                 input, by_column, ..
             } => {
-                let current_node = format!("SORT BY {:?} [{}]", by_column, id);
+                let current_node =
+                    format!("SORT BY {} [{:?}]", fmt_projection(by_column), (branch, id));
                 self.write_dot(acc_str, prev_node, &current_node, id)?;
                 input.dot(acc_str, (branch, id + 1), &current_node)
             }
             LocalProjection { expr, input, .. } => {
                 let current_node = format!(
-                    "LOCAL π {}/{} [{:?}]",
+                    "LOCAL SELECT\n{}\nπ {}/{} [{:?}]",
+                    fmt_projection(expr),
                     expr.len(),
                     input.schema().fields().len(),
                     (branch, id)
@@ -554,11 +819,12 @@ impl LogicalPlan {
             Aggregate {
                 input, keys, aggs, ..
             } => {
-                let mut s_keys = String::with_capacity(128);
-                for key in keys.iter() {
-                    s_keys.push_str(&format!("{:?}", key));
-                }
-                let current_node = format!("AGG {:?} BY {} [{:?}]", aggs, s_keys, (branch, id));
+                let current_node = format!(
+                    "AGG {}\nBY {} [{:?}]",
+                    fmt_projection(aggs),
+                    fmt_projection(keys),
+                    (branch, id)
+                );
                 self.write_dot(acc_str, prev_node, &current_node, id)?;
                 input.dot(acc_str, (branch, id + 1), &current_node)
             }
@@ -632,6 +898,36 @@ impl LogicalPlan {
                     self.write_dot(acc_str, prev_node, &current_node, id)
                 }
             }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                with_columns,
+                predicate,
+                ..
+            } => {
+                let total_columns = schema.fields().len();
+                let mut n_columns = "*".to_string();
+                if let Some(columns) = with_columns {
+                    n_columns = format!("{}", columns.len());
+                }
+
+                let pred = fmt_predicate(predicate.as_ref());
+                let current_node = format!(
+                    "IPC SCAN {};\nπ {}/{};\nσ {} [{:?}]",
+                    path.to_string_lossy(),
+                    n_columns,
+                    total_columns,
+                    pred,
+                    (branch, id)
+                );
+                if id == 0 {
+                    self.write_dot(acc_str, prev_node, &current_node, id)?;
+                    write!(acc_str, "\"{}\"", current_node)
+                } else {
+                    self.write_dot(acc_str, prev_node, &current_node, id)
+                }
+            }
             Join {
                 input_left,
                 input_right,
@@ -639,8 +935,12 @@ impl LogicalPlan {
                 right_on,
                 ..
             } => {
-                let current_node =
-                    format!("JOIN left {:?}; right: {:?} [{}]", left_on, right_on, id);
+                let current_node = format!(
+                    "JOIN left: {}; right: {} [{:?}]",
+                    fmt_projection(left_on),
+                    fmt_projection(right_on),
+                    (branch, id)
+                );
                 self.write_dot(acc_str, prev_node, &current_node, id)?;
                 input_left.dot(acc_str, (branch + 10, id + 1), &current_node)?;
                 input_right.dot(acc_str, (branch + 20, id + 1), &current_node)
@@ -650,6 +950,14 @@ impl LogicalPlan {
                 self.write_dot(acc_str, prev_node, &current_node, id)?;
                 input.dot(acc_str, (branch, id + 1), &current_node)
             }
+            Union { inputs } => {
+                let current_node = format!("UNION [{:?}]", (branch, id));
+                self.write_dot(acc_str, prev_node, &current_node, id)?;
+                for (i, input) in inputs.iter().enumerate() {
+                    input.dot(acc_str, (branch + 10 * (i + 1), id + 1), &current_node)?;
+                }
+                Ok(())
+            }
         }
     }
 
@@ -775,9 +1083,14 @@ fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
             AggExpr::AggGroups(e) => {
                 AggExpr::AggGroups(Box::new(replace_wildcard_with_column(*e, column_name)))
             }
-            AggExpr::Quantile { expr, quantile } => AggExpr::Quantile {
+            AggExpr::Quantile {
+                expr,
+                quantile,
+                interpol,
+            } => AggExpr::Quantile {
                 expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
                 quantile,
+                interpol,
             },
             AggExpr::List(e) => {
                 AggExpr::List(Box::new(replace_wildcard_with_column(*e, column_name)))
@@ -788,6 +1101,15 @@ fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
             AggExpr::Std(e) => {
                 AggExpr::Std(Box::new(replace_wildcard_with_column(*e, column_name)))
             }
+            AggExpr::Skew { expr, bias } => AggExpr::Skew {
+                expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
+                bias,
+            },
+            AggExpr::Kurtosis { expr, fisher, bias } => AggExpr::Kurtosis {
+                expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
+                fisher,
+                bias,
+            },
         }
         .into(),
         Expr::Shift { input, periods } => Expr::Shift {
@@ -888,11 +1210,13 @@ impl LogicalPlan {
     pub(crate) fn schema(&self) -> &Schema {
         use LogicalPlan::*;
         match self {
-            Cache { input } => input.schema(),
+            Cache { input, .. } => input.schema(),
             Sort { input, .. } => input.schema(),
-            Explode { input, .. } => input.schema(),
+            Explode { schema, .. } => schema,
             #[cfg(feature = "parquet")]
             ParquetScan { schema, .. } => schema,
+            #[cfg(feature = "ipc")]
+            IpcScan { schema, .. } => schema,
             DataFrameScan { schema, .. } => schema,
             Selection { input, .. } => input.schema(),
             #[cfg(feature = "csv-file")]
@@ -909,6 +1233,7 @@ impl LogicalPlan {
                 Some(schema) => schema,
                 None => input.schema(),
             },
+            Union { inputs } => inputs[0].schema(),
         }
     }
     pub fn describe(&self) -> String {
@@ -935,25 +1260,93 @@ impl LogicalPlanBuilder {
         path: P,
         stop_after_n_rows: Option<usize>,
         cache: bool,
-    ) -> Self {
+        row_count: Option<RowCount>,
+    ) -> Result<Self> {
         let path = path.into();
-        let file = std::fs::File::open(&path).expect("could not open file");
-        let schema = Arc::new(
-            ParquetReader::new(file)
-                .schema()
-                .expect("could not get parquet schema"),
-        );
+        if let Some(paths) = utils::expand_glob(&path) {
+            let lp = Self::union_files(paths?, |path| {
+                Self::scan_parquet_file(path, stop_after_n_rows, cache, None)
+            })?;
+            return Ok(lp.finish_union_scan(stop_after_n_rows, row_count));
+        }
+        Self::scan_parquet_file(path, stop_after_n_rows, cache, row_count)
+    }
+
+    #[cfg(feature = "parquet")]
+    fn scan_parquet_file(
+        path: PathBuf,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+        row_count: Option<RowCount>,
+    ) -> Result<Self> {
+        let file = utils::open_scan_source(&path)?;
+        let schema = Arc::new(ParquetReader::new(file).schema().map_err(|e| {
+            PolarsError::Other(
+                format!(
+                    "could not read the parquet schema of {}: {}",
+                    path.display(),
+                    e
+                )
+                .into(),
+            )
+        })?);
 
-        LogicalPlan::ParquetScan {
+        Ok(LogicalPlan::ParquetScan {
             path,
-            schema,
+            schema: schema_with_row_count(schema, row_count.as_ref()),
             stop_after_n_rows,
             with_columns: None,
             predicate: None,
             aggregate: vec![],
             cache,
+            row_count,
         }
-        .into()
+        .into())
+    }
+
+    #[cfg(feature = "ipc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
+    pub fn scan_ipc<P: Into<PathBuf>>(
+        path: P,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+        row_count: Option<RowCount>,
+    ) -> Result<Self> {
+        let path = path.into();
+        if let Some(paths) = utils::expand_glob(&path) {
+            let lp = Self::union_files(paths?, |path| {
+                Self::scan_ipc_file(path, stop_after_n_rows, cache, None)
+            })?;
+            return Ok(lp.finish_union_scan(stop_after_n_rows, row_count));
+        }
+        Self::scan_ipc_file(path, stop_after_n_rows, cache, row_count)
+    }
+
+    #[cfg(feature = "ipc")]
+    fn scan_ipc_file(
+        path: PathBuf,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+        row_count: Option<RowCount>,
+    ) -> Result<Self> {
+        let file = utils::open_scan_source(&path)?;
+        let schema = Arc::new(IpcReader::new(file).schema().map_err(|e| {
+            PolarsError::Other(
+                format!("could not read the ipc schema of {}: {}", path.display(), e).into(),
+            )
+        })?);
+
+        Ok(LogicalPlan::IpcScan {
+            path,
+            schema: schema_with_row_count(schema, row_count.as_ref()),
+            stop_after_n_rows,
+            with_columns: None,
+            predicate: None,
+            aggregate: vec![],
+            cache,
+            row_count,
+        }
+        .into())
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -969,25 +1362,82 @@ impl LogicalPlanBuilder {
         schema: Option<Arc<Schema>>,
         schema_overwrite: Option<&Schema>,
         low_memory: bool,
-    ) -> Self {
+        row_count: Option<RowCount>,
+    ) -> Result<Self> {
         let path = path.into();
-        let mut file = std::fs::File::open(&path).expect("could not open file");
+        if let Some(paths) = utils::expand_glob(&path) {
+            let paths = paths?;
+            // all files are read with the same schema
+            let schema = match schema {
+                Some(schema) => schema,
+                None => Arc::new(Self::infer_csv_files_schema(
+                    &paths,
+                    delimiter,
+                    has_header,
+                    skip_rows,
+                    schema_overwrite,
+                )?),
+            };
+            let lp = Self::union_files(paths, |path| {
+                Self::scan_csv_file(
+                    path,
+                    delimiter,
+                    has_header,
+                    ignore_errors,
+                    skip_rows,
+                    stop_after_n_rows,
+                    cache,
+                    Some(schema.clone()),
+                    schema_overwrite,
+                    low_memory,
+                    None,
+                )
+            })?;
+            return Ok(lp.finish_union_scan(stop_after_n_rows, row_count));
+        }
+        Self::scan_csv_file(
+            path,
+            delimiter,
+            has_header,
+            ignore_errors,
+            skip_rows,
+            stop_after_n_rows,
+            cache,
+            schema,
+            schema_overwrite,
+            low_memory,
+            row_count,
+        )
+    }
 
-        let schema = schema.unwrap_or_else(|| {
-            let (schema, _) = infer_file_schema(
-                &mut file,
+    #[allow(clippy::too_many_arguments)]
+    #[cfg(feature = "csv-file")]
+    fn scan_csv_file(
+        path: PathBuf,
+        delimiter: u8,
+        has_header: bool,
+        ignore_errors: bool,
+        skip_rows: usize,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+        schema: Option<Arc<Schema>>,
+        schema_overwrite: Option<&Schema>,
+        low_memory: bool,
+        row_count: Option<RowCount>,
+    ) -> Result<Self> {
+        let schema = match schema {
+            Some(schema) => schema,
+            None => Arc::new(Self::infer_csv_schema(
+                &path,
                 delimiter,
-                Some(100),
                 has_header,
-                schema_overwrite,
                 skip_rows,
-            )
-            .expect("could not read schema");
-            Arc::new(schema)
-        });
-        LogicalPlan::CsvScan {
+                schema_overwrite,
+            )?),
+        };
+        Ok(LogicalPlan::CsvScan {
             path,
-            schema,
+            schema: schema_with_row_count(schema, row_count.as_ref()),
             has_header,
             delimiter,
             ignore_errors,
@@ -998,13 +1448,161 @@ impl LogicalPlanBuilder {
             aggregate: vec![],
             cache,
             low_memory,
+            row_count,
         }
-        .into()
+        .into())
+    }
+
+    #[cfg(feature = "csv-file")]
+    fn infer_csv_schema(
+        path: &Path,
+        delimiter: u8,
+        has_header: bool,
+        skip_rows: usize,
+        schema_overwrite: Option<&Schema>,
+    ) -> Result<Schema> {
+        let mut file = utils::open_scan_source(path)?;
+        let (schema, _) = infer_file_schema(
+            &mut file,
+            delimiter,
+            Some(100),
+            has_header,
+            schema_overwrite,
+            skip_rows,
+            None,
+            false,
+            None,
+        )
+        .map_err(|e| {
+            PolarsError::Other(
+                format!("could not infer the schema of {}: {}", path.display(), e).into(),
+            )
+        })?;
+        Ok(schema)
+    }
+
+    /// Infer the schema of every file in `paths`. The files must have the same column names in
+    /// the same order; the dtype of a column is the supertype of its dtypes in all files.
+    #[cfg(feature = "csv-file")]
+    fn infer_csv_files_schema(
+        paths: &[PathBuf],
+        delimiter: u8,
+        has_header: bool,
+        skip_rows: usize,
+        schema_overwrite: Option<&Schema>,
+    ) -> Result<Schema> {
+        let mut schema: Option<Schema> = None;
+        for path in paths {
+            let file_schema =
+                Self::infer_csv_schema(path, delimiter, has_header, skip_rows, schema_overwrite)?;
+            schema = Some(match schema {
+                None => file_schema,
+                Some(schema) => {
+                    let same_names = schema.fields().len() == file_schema.fields().len()
+                        && schema
+                            .fields()
+                            .iter()
+                            .zip(file_schema.fields())
+                            .all(|(l, r)| l.name() == r.name());
+                    if !same_names {
+                        return Err(PolarsError::SchemaMisMatch(
+                            format!(
+                                "the columns of {} differ from the columns of {}",
+                                path.display(),
+                                paths[0].display()
+                            )
+                            .into(),
+                        ));
+                    }
+                    let fields = schema
+                        .fields()
+                        .iter()
+                        .zip(file_schema.fields())
+                        .map(|(l, r)| {
+                            let dtype = get_supertype(l.data_type(), r.data_type()).map_err(|_| {
+                                PolarsError::SchemaMisMatch(
+                                    format!(
+                                        "the dtype {:?} of column {} in {} cannot be combined with {:?}",
+                                        r.data_type(),
+                                        r.name(),
+                                        path.display(),
+                                        l.data_type()
+                                    )
+                                    .into(),
+                                )
+                            })?;
+                            Ok(Field::new(l.name(), dtype))
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Schema::new(fields)
+                }
+            });
+        }
+        schema.ok_or_else(|| PolarsError::NoData("no files match the glob pattern".into()))
+    }
+
+    /// Limit the rows of the union of the scanned files and count them over all files.
+    #[cfg(any(feature = "csv-file", feature = "parquet", feature = "ipc"))]
+    fn finish_union_scan(
+        self,
+        stop_after_n_rows: Option<usize>,
+        row_count: Option<RowCount>,
+    ) -> Self {
+        let lp = match stop_after_n_rows {
+            Some(n) => self.slice(0, n),
+            None => self,
+        };
+        match row_count {
+            Some(row_count) => lp.with_row_count(&row_count.name, Some(row_count.offset)),
+            None => lp,
+        }
+    }
+
+    /// Scan every file in `paths` with `scan` and concatenate the scans. Reports the first file
+    /// of which the schema differs from the schema of the first file.
+    #[cfg(any(feature = "csv-file", feature = "parquet", feature = "ipc"))]
+    fn union_files<F>(paths: Vec<PathBuf>, mut scan: F) -> Result<Self>
+    where
+        F: FnMut(PathBuf) -> Result<Self>,
+    {
+        let mut inputs: Vec<LogicalPlan> = Vec::with_capacity(paths.len());
+        for path in &paths {
+            let lp = scan(path.clone())?.build();
+            if let Some(first) = inputs.first() {
+                if lp.schema() != first.schema() {
+                    return Err(PolarsError::SchemaMisMatch(
+                        format!(
+                            "the schema of {} differs from the schema of {}",
+                            path.display(),
+                            paths[0].display()
+                        )
+                        .into(),
+                    ));
+                }
+            }
+            inputs.push(lp);
+        }
+        Self::union(inputs)
+    }
+
+    /// Vertically concatenate the results of the given plans. All plans must have the same schema.
+    pub fn union(inputs: Vec<LogicalPlan>) -> Result<Self> {
+        let schema = inputs
+            .first()
+            .ok_or_else(|| PolarsError::NoData("cannot create a union of zero plans".into()))?
+            .schema();
+        if inputs[1..].iter().any(|lp| lp.schema() != schema) {
+            return Err(PolarsError::ShapeMisMatch(
+                "the inputs of a union must have the same schema".into(),
+            ));
+        }
+        Ok(LogicalPlan::Union { inputs }.into())
     }
 
     pub fn cache(self) -> Self {
         LogicalPlan::Cache {
             input: Box::new(self.0),
+            id: CACHE_ID.fetch_add(1, Ordering::Relaxed),
         }
         .into()
     }
@@ -1103,14 +1701,16 @@ impl LogicalPlanBuilder {
         keys: Arc<Vec<Expr>>,
         aggs: Vec<Expr>,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        options: GroupbyOptions,
     ) -> Self {
-        debug_assert!(!keys.is_empty());
+        debug_assert!(!keys.is_empty() || options.index_column().is_some());
         let current_schema = self.0.schema();
         let aggs = rewrite_projections(aggs, current_schema);
 
         let schema1 = utils::expressions_to_schema(&keys, current_schema, Context::Default);
         let schema2 = utils::expressions_to_schema(&aggs, current_schema, Context::Aggregation);
-        let schema = Schema::try_merge(&[schema1, schema2]).unwrap();
+        let index_schema = Schema::new(options.key_fields(current_schema));
+        let schema = Schema::try_merge(&[schema1, index_schema, schema2]).unwrap();
 
         LogicalPlan::Aggregate {
             input: Box::new(self.0),
@@ -1118,6 +1718,7 @@ impl LogicalPlanBuilder {
             aggs,
             schema: Arc::new(schema),
             apply,
+            options,
         }
         .into()
     }
@@ -1137,25 +1738,29 @@ impl LogicalPlanBuilder {
         .into()
     }
 
-    pub fn sort(self, by_column: Vec<Expr>, reverse: Vec<bool>) -> Self {
+    pub fn sort(self, by_column: Vec<Expr>, reverse: Vec<bool>, nulls_last: bool) -> Self {
         LogicalPlan::Sort {
             input: Box::new(self.0),
             by_column,
             reverse,
+            nulls_last,
         }
         .into()
     }
 
     pub fn explode(self, columns: Vec<String>) -> Self {
+        let schema = det_explode_schema(&columns, self.0.schema());
         LogicalPlan::Explode {
             input: Box::new(self.0),
             columns,
+            schema,
         }
         .into()
     }
 
     pub fn melt(self, id_vars: Arc<Vec<String>>, value_vars: Arc<Vec<String>>) -> Self {
-        let schema = det_melt_schema(&value_vars, self.0.schema());
+        let value_vars = det_melt_value_vars(&id_vars, value_vars, self.0.schema());
+        let schema = det_melt_schema(&id_vars, &value_vars, self.0.schema());
         LogicalPlan::Melt {
             input: Box::new(self.0),
             id_vars,
@@ -1165,11 +1770,17 @@ impl LogicalPlanBuilder {
         .into()
     }
 
-    pub fn drop_duplicates(self, maintain_order: bool, subset: Option<Vec<String>>) -> Self {
+    pub fn drop_duplicates(
+        self,
+        maintain_order: bool,
+        subset: Option<Vec<String>>,
+        keep: UniqueKeepStrategy,
+    ) -> Self {
         LogicalPlan::Distinct {
             input: Box::new(self.0),
             maintain_order,
             subset: Arc::new(subset),
+            keep,
         }
         .into()
     }
@@ -1183,6 +1794,7 @@ impl LogicalPlanBuilder {
         .into()
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn join(
         self,
         other: LogicalPlan,
@@ -1191,6 +1803,7 @@ impl LogicalPlanBuilder {
         right_on: Vec<Expr>,
         allow_par: bool,
         force_par: bool,
+        duplicates: DuplicateColumns,
     ) -> Self {
         let schema_left = self.0.schema();
         let schema_right = other.schema();
@@ -1213,13 +1826,15 @@ impl LogicalPlanBuilder {
         for f in schema_right.fields() {
             let name = f.name();
 
-            if !right_names.contains(name) {
-                if names.contains(name) {
-                    let new_name = format!("{}_right", name);
-                    let field = Field::new(&new_name, f.data_type().clone());
-                    fields.push(field)
-                } else {
-                    fields.push(f.clone())
+            if !right_names.contains(name) && join_has_right_columns(how) {
+                match &duplicates {
+                    DuplicateColumns::Suffix(suffix) if names.contains(name) => {
+                        let new_name = format!("{}{}", name, suffix);
+                        let field = Field::new(&new_name, f.data_type().clone());
+                        fields.push(field)
+                    }
+                    // with `DuplicateColumns::Error` the join fails during execution
+                    _ => fields.push(f.clone()),
                 }
             }
         }
@@ -1235,9 +1850,29 @@ impl LogicalPlanBuilder {
             right_on,
             allow_par,
             force_par,
+            duplicates,
         }
         .into()
     }
+    /// Add a `UInt32` column at index 0 that counts the rows of the input, starting at `offset`.
+    pub fn with_row_count(self, name: &str, offset: Option<u32>) -> Self {
+        let mut fields = vec![Field::new(name, DataType::UInt32)];
+        fields.extend(self.0.schema().fields().iter().cloned());
+        let name = name.to_string();
+        // the rows must be counted before they are filtered and the row count column does not
+        // exist in the input
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            ..Default::default()
+        };
+        self.map(
+            move |df: DataFrame| df.with_row_count(&name, offset),
+            optimizations,
+            Some(Arc::new(Schema::new(fields))),
+        )
+    }
+
     pub fn map<F>(
         self,
         function: F,
@@ -1258,27 +1893,89 @@ impl LogicalPlanBuilder {
     }
 }
 
-pub(crate) fn det_melt_schema(value_vars: &[String], input_schema: &Schema) -> SchemaRef {
-    let mut fields = input_schema
-        .fields()
+/// Semi and anti joins only filter the left table.
+#[cfg(feature = "semi_anti_join")]
+pub(crate) fn join_has_right_columns(how: JoinType) -> bool {
+    !matches!(how, JoinType::Semi | JoinType::Anti)
+}
+
+#[cfg(not(feature = "semi_anti_join"))]
+pub(crate) fn join_has_right_columns(_how: JoinType) -> bool {
+    true
+}
+
+/// If no value columns are given, all the columns that are not an id are melted.
+pub(crate) fn det_melt_value_vars(
+    id_vars: &[String],
+    value_vars: Arc<Vec<String>>,
+    input_schema: &Schema,
+) -> Arc<Vec<String>> {
+    if value_vars.is_empty() {
+        Arc::new(
+            input_schema
+                .fields()
+                .iter()
+                .map(|field| field.name())
+                .filter(|name| !id_vars.contains(name))
+                .cloned()
+                .collect(),
+        )
+    } else {
+        value_vars
+    }
+}
+
+pub(crate) fn det_melt_schema(
+    id_vars: &[String],
+    value_vars: &[String],
+    input_schema: &Schema,
+) -> SchemaRef {
+    let mut fields = id_vars
         .iter()
-        .filter(|field| !value_vars.contains(field.name()))
-        .cloned()
+        .map(|name| {
+            input_schema
+                .field_with_name(name)
+                .expect("field not found")
+                .clone()
+        })
         .collect_vec();
 
     fields.reserve(2);
 
-    let value_dtype = input_schema
-        .field_with_name(&value_vars[0])
-        .expect("field not found")
-        .data_type();
+    // the values are cast to their supertype
+    let mut value_dtype: Option<DataType> = None;
+    for name in value_vars {
+        let dtype = input_schema
+            .field_with_name(name)
+            .expect("field not found")
+            .data_type();
+        value_dtype = Some(match value_dtype {
+            Some(st) => get_supertype(&st, dtype).unwrap_or(st),
+            None => dtype.clone(),
+        });
+    }
 
     fields.push(Field::new("variable", DataType::Utf8));
-    fields.push(Field::new("value", value_dtype.clone()));
+    fields.push(Field::new("value", value_dtype.unwrap_or(DataType::Null)));
 
     Arc::new(Schema::new(fields))
 }
 
+/// The exploded list columns get the data type of their inner values.
+pub(crate) fn det_explode_schema(columns: &[String], input_schema: &Schema) -> SchemaRef {
+    let fields = input_schema
+        .fields()
+        .iter()
+        .map(|field| match field.data_type() {
+            DataType::List(inner) if columns.contains(field.name()) => {
+                Field::new(field.name(), DataType::from(inner))
+            }
+            _ => field.clone(),
+        })
+        .collect_vec();
+    Arc::new(Schema::new(fields))
+}
+
 #[cfg(test)]
 mod test {
     use polars_core::df;
@@ -1445,18 +2142,28 @@ mod test {
     }
 
     #[test]
-    fn test_dot() {
+    fn test_dot() -> Result<()> {
         let left = df!("days" => &[0, 1, 2, 3, 4],
         "temp" => [22.1, 19.9, 7., 2., 3.],
         "rain" => &[0.1, 0.2, 0.3, 0.4, 0.5]
         )
         .unwrap();
-        let mut s = String::new();
-        left.lazy()
-            .select(&[col("days")])
-            .logical_plan
-            .dot(&mut s, (0, 0), "")
-            .unwrap();
+        let q = left
+            .lazy()
+            .filter(col("temp").gt(lit(5.0)))
+            .select(&[col("days"), (col("rain") * lit(2.0)).alias("foo")]);
+
+        let s = q.to_dot(false)?;
         println!("{}", s);
+        assert!(s.starts_with("graph  polars_query {"));
+        assert!(s.ends_with('}'));
+        assert!(s.contains("SELECT\ndays,\n(rain) Multiply (Float64(2.0)) AS foo"));
+        assert!(s.contains("FILTER BY (temp) Gt (Float64(5.0))"));
+
+        // the predicate is pushed down to the scan
+        let s = q.to_dot(true)?;
+        assert!(s.contains("σ (temp) Gt (Float64(5.0))"));
+        assert!(!s.contains("FILTER"));
+        Ok(())
     }
 }
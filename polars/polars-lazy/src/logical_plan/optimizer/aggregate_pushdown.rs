@@ -59,6 +59,42 @@ impl AggregatePushdown {
             None
         }
     }
+
+    /// Do the accumulated projections on top of this node, as they cannot be pushed down further.
+    fn finish_at_node(
+        &mut self,
+        node: Node,
+        lp: ALogicalPlan,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Option<ALogicalPlan> {
+        if self.accumulated_projections.is_empty() {
+            lp_arena.replace(node, lp);
+            None
+        } else {
+            // we cannot pass a join or GroupBy so we do the projection here
+            let new_node = lp_arena.add(lp.clone());
+            let input_schema = lp_arena.get(new_node).schema(lp_arena);
+
+            let nodes: Vec<_> = self.process_nodes();
+            let fields = self
+                .accumulated_projections
+                .iter()
+                .map(|n| {
+                    expr_arena
+                        .get(*n)
+                        .to_field(input_schema, Context::Default, expr_arena)
+                        .unwrap()
+                })
+                .collect();
+
+            Some(ALogicalPlan::Projection {
+                expr: nodes,
+                input: new_node,
+                schema: Arc::new(Schema::new(fields)),
+            })
+        }
+    }
 }
 
 impl OptimizationRule for AggregatePushdown {
@@ -85,33 +121,13 @@ impl OptimizationRule for AggregatePushdown {
             } => self.pushdown_projection(node, expr, input, schema, lp_arena, expr_arena),
             // todo! hstack should pushown not dependent columns
             Join { .. } | Aggregate { .. } | HStack { .. } | DataFrameScan { .. } => {
-                if self.accumulated_projections.is_empty() {
-                    lp_arena.replace(node, lp);
-                    None
-                } else {
-                    // we cannot pass a join or GroupBy so we do the projection here
-                    let new_node = lp_arena.add(lp.clone());
-                    let input_schema = lp_arena.get(new_node).schema(lp_arena);
-
-                    let nodes: Vec<_> = self.process_nodes();
-                    let fields = self
-                        .accumulated_projections
-                        .iter()
-                        .map(|n| {
-                            expr_arena
-                                .get(*n)
-                                .to_field(input_schema, Context::Default, expr_arena)
-                                .unwrap()
-                        })
-                        .collect();
-
-                    Some(ALogicalPlan::Projection {
-                        expr: nodes,
-                        input: new_node,
-                        schema: Arc::new(Schema::new(fields)),
-                    })
-                }
+                self.finish_at_node(node, lp, lp_arena, expr_arena)
             }
+            // the row count is added after the scan, so we cannot aggregate at the scan level
+            #[cfg(feature = "csv-file")]
+            CsvScan {
+                row_count: Some(_), ..
+            } => self.finish_at_node(node, lp, lp_arena, expr_arena),
             #[cfg(feature = "csv-file")]
             CsvScan {
                 path,
@@ -126,6 +142,7 @@ impl OptimizationRule for AggregatePushdown {
                 aggregate,
                 cache,
                 low_memory,
+                row_count,
             } => match self.accumulated_projections.is_empty() {
                 true => {
                     lp_arena.replace(
@@ -143,6 +160,7 @@ impl OptimizationRule for AggregatePushdown {
                             aggregate,
                             cache,
                             low_memory,
+                            row_count,
                         },
                     );
                     None
@@ -162,10 +180,15 @@ impl OptimizationRule for AggregatePushdown {
                         aggregate,
                         cache,
                         low_memory,
+                        row_count,
                     })
                 }
             },
             #[cfg(feature = "parquet")]
+            ParquetScan {
+                row_count: Some(_), ..
+            } => self.finish_at_node(node, lp, lp_arena, expr_arena),
+            #[cfg(feature = "parquet")]
             ParquetScan {
                 path,
                 schema,
@@ -174,6 +197,7 @@ impl OptimizationRule for AggregatePushdown {
                 aggregate,
                 stop_after_n_rows,
                 cache,
+                row_count,
             } => match self.accumulated_projections.is_empty() {
                 true => {
                     lp_arena.replace(
@@ -186,6 +210,7 @@ impl OptimizationRule for AggregatePushdown {
                             aggregate,
                             stop_after_n_rows,
                             cache,
+                            row_count,
                         },
                     );
                     None
@@ -200,6 +225,52 @@ impl OptimizationRule for AggregatePushdown {
                         aggregate,
                         stop_after_n_rows,
                         cache,
+                        row_count,
+                    })
+                }
+            },
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                row_count: Some(_), ..
+            } => self.finish_at_node(node, lp, lp_arena, expr_arena),
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                with_columns,
+                predicate,
+                aggregate,
+                stop_after_n_rows,
+                cache,
+                row_count,
+            } => match self.accumulated_projections.is_empty() {
+                true => {
+                    lp_arena.replace(
+                        node,
+                        IpcScan {
+                            path,
+                            schema,
+                            with_columns,
+                            predicate,
+                            aggregate,
+                            stop_after_n_rows,
+                            cache,
+                            row_count,
+                        },
+                    );
+                    None
+                }
+                false => {
+                    let aggregate = self.process_nodes();
+                    Some(ALogicalPlan::IpcScan {
+                        path,
+                        schema,
+                        with_columns,
+                        predicate,
+                        aggregate,
+                        stop_after_n_rows,
+                        cache,
+                        row_count,
                     })
                 }
             },
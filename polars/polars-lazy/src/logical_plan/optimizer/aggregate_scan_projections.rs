@@ -41,6 +41,12 @@ pub(crate) fn agg_projection(
         } => {
             process_with_columns(&path, &with_columns, columns);
         }
+        #[cfg(feature = "ipc")]
+        IpcScan {
+            path, with_columns, ..
+        } => {
+            process_with_columns(&path, &with_columns, columns);
+        }
         DataFrameScan { .. } => (),
         lp => {
             for input in lp.get_inputs() {
@@ -65,6 +71,7 @@ impl AggScanProjection {
         lp_arena: &mut Arena<ALogicalPlan>,
         path: &Path,
         with_columns: Option<Vec<String>>,
+        row_count: Option<&RowCount>,
     ) -> ALogicalPlan {
         // if the original projection is less than the new one. Also project locally
         if let Some(with_columns) = with_columns {
@@ -72,8 +79,10 @@ impl AggScanProjection {
             if with_columns.len() < agg.len() {
                 let node = lp_arena.add(lp);
 
-                let projections = with_columns
+                let projections = row_count
+                    .map(|row_count| row_count.name.clone())
                     .into_iter()
+                    .chain(with_columns)
                     .map(|s| expr_arena.add(AExpr::Column(Arc::new(s))))
                     .collect();
 
@@ -106,6 +115,7 @@ impl OptimizationRule for AggScanProjection {
                     with_columns,
                     stop_after_n_rows,
                     cache,
+                    row_count,
                 } = lp
                 {
                     let new_with_columns = self
@@ -122,6 +132,7 @@ impl OptimizationRule for AggScanProjection {
                             with_columns,
                             stop_after_n_rows,
                             cache,
+                            row_count,
                         };
                         lp_arena.replace(node, lp);
                         return None;
@@ -135,8 +146,72 @@ impl OptimizationRule for AggScanProjection {
                         aggregate,
                         stop_after_n_rows,
                         cache,
+                        row_count: row_count.clone(),
+                    };
+                    Some(self.finish_rewrite(
+                        lp,
+                        expr_arena,
+                        lp_arena,
+                        &path,
+                        with_columns,
+                        row_count.as_ref(),
+                    ))
+                } else {
+                    unreachable!()
+                }
+            }
+            #[cfg(feature = "ipc")]
+            ALogicalPlan::IpcScan { .. } => {
+                let lp = std::mem::take(lp);
+                if let ALogicalPlan::IpcScan {
+                    path,
+                    schema,
+                    predicate,
+                    aggregate,
+                    with_columns,
+                    stop_after_n_rows,
+                    cache,
+                    row_count,
+                } = lp
+                {
+                    let new_with_columns = self
+                        .columns
+                        .get(&path)
+                        .map(|agg| agg.iter().cloned().collect());
+                    // prevent infinite loop
+                    if with_columns == new_with_columns {
+                        let lp = ALogicalPlan::IpcScan {
+                            path,
+                            schema,
+                            predicate,
+                            aggregate,
+                            with_columns,
+                            stop_after_n_rows,
+                            cache,
+                            row_count,
+                        };
+                        lp_arena.replace(node, lp);
+                        return None;
+                    }
+
+                    let lp = ALogicalPlan::IpcScan {
+                        path: path.clone(),
+                        schema,
+                        with_columns: new_with_columns,
+                        predicate,
+                        aggregate,
+                        stop_after_n_rows,
+                        cache,
+                        row_count: row_count.clone(),
                     };
-                    Some(self.finish_rewrite(lp, expr_arena, lp_arena, &path, with_columns))
+                    Some(self.finish_rewrite(
+                        lp,
+                        expr_arena,
+                        lp_arena,
+                        &path,
+                        with_columns,
+                        row_count.as_ref(),
+                    ))
                 } else {
                     unreachable!()
                 }
@@ -157,6 +232,7 @@ impl OptimizationRule for AggScanProjection {
                     with_columns,
                     cache,
                     low_memory,
+                    row_count,
                 } = lp
                 {
                     let new_with_columns = self
@@ -177,6 +253,7 @@ impl OptimizationRule for AggScanProjection {
                             with_columns,
                             cache,
                             low_memory,
+                            row_count,
                         };
                         lp_arena.replace(node, lp);
                         return None;
@@ -194,8 +271,16 @@ impl OptimizationRule for AggScanProjection {
                         aggregate,
                         cache,
                         low_memory,
+                        row_count: row_count.clone(),
                     };
-                    Some(self.finish_rewrite(lp, expr_arena, lp_arena, &path, with_columns))
+                    Some(self.finish_rewrite(
+                        lp,
+                        expr_arena,
+                        lp_arena,
+                        &path,
+                        with_columns,
+                        row_count.as_ref(),
+                    ))
                 } else {
                     unreachable!()
                 }
@@ -0,0 +1,335 @@
+use crate::prelude::stack_opt::OptimizationRule;
+use crate::prelude::*;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+use crate::utils::open_scan_source;
+use polars_core::frame::hash_join::JoinType;
+use polars_core::prelude::*;
+#[cfg(feature = "ipc")]
+use polars_io::ipc::IpcReader;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::ParquetReader;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+use polars_io::SerReader;
+use std::collections::HashSet;
+#[cfg(any(feature = "csv-file", feature = "parquet", feature = "ipc"))]
+use std::path::Path;
+
+/// Assumed number of bytes a single value takes in a csv file, used to estimate the number of
+/// rows of a csv scan from the file size.
+#[cfg(feature = "csv-file")]
+const BYTES_PER_VALUE: u64 = 8;
+
+/// Estimate the number of rows in a csv file with the given schema from its size.
+#[cfg(feature = "csv-file")]
+fn csv_rows(path: &Path, schema: &Schema) -> Option<usize> {
+    let n_bytes = std::fs::metadata(path).ok()?.len();
+    let row_bytes = std::cmp::max(schema.len() as u64, 1) * BYTES_PER_VALUE;
+    Some((n_bytes / row_bytes) as usize)
+}
+
+/// Number of rows in a parquet file, from its metadata.
+#[cfg(feature = "parquet")]
+fn parquet_rows(path: &Path) -> Option<usize> {
+    ParquetReader::new(open_scan_source(path).ok()?)
+        .num_rows()
+        .ok()
+}
+
+/// Number of rows in an IPC file, from the headers of its record batches.
+#[cfg(feature = "ipc")]
+fn ipc_rows(path: &Path) -> Option<usize> {
+    IpcReader::new(open_scan_source(path).ok()?).num_rows().ok()
+}
+
+/// Rows produced by a file scan of `rows` rows that stops after `stop_after_n_rows` rows.
+#[cfg(any(feature = "csv-file", feature = "parquet", feature = "ipc"))]
+fn scan_rows(rows: Option<usize>, stop_after_n_rows: Option<usize>) -> Option<f64> {
+    let rows = rows?;
+    Some(stop_after_n_rows.map_or(rows, |n| rows.min(n)) as f64)
+}
+
+/// Estimate the fraction of the rows that pass a predicate.
+pub(crate) fn selectivity(predicate: Node, expr_arena: &Arena<AExpr>) -> f64 {
+    use AExpr::*;
+    match expr_arena.get(predicate) {
+        BinaryExpr { left, op, right } => match op {
+            Operator::Eq => 0.1,
+            Operator::NotEq => 0.9,
+            Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => 1.0 / 3.0,
+            Operator::And => selectivity(*left, expr_arena) * selectivity(*right, expr_arena),
+            Operator::Or => {
+                let left = selectivity(*left, expr_arena);
+                let right = selectivity(*right, expr_arena);
+                left + right - left * right
+            }
+            _ => 0.5,
+        },
+        Not(input) => 1.0 - selectivity(*input, expr_arena),
+        IsNull(_) => 0.1,
+        IsNotNull(_) => 0.9,
+        Alias(input, _) => selectivity(*input, expr_arena),
+        _ => 0.5,
+    }
+}
+
+/// Estimate the number of rows a plan produces. Returns `None` if no reasonable estimate can be
+/// made, e.g. for user defined functions.
+pub(crate) fn estimate_rows(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<f64> {
+    use ALogicalPlan::*;
+    let input_rows = |input: &Node| estimate_rows(*input, lp_arena, expr_arena);
+    let predicate_selectivity =
+        |predicate: &Option<Node>| predicate.map_or(1.0, |p| selectivity(p, expr_arena));
+
+    match lp_arena.get(node) {
+        DataFrameScan { df, selection, .. } => {
+            Some(df.height() as f64 * predicate_selectivity(selection))
+        }
+        #[cfg(feature = "csv-file")]
+        CsvScan {
+            path,
+            schema,
+            stop_after_n_rows,
+            predicate,
+            ..
+        } => scan_rows(csv_rows(path, schema), *stop_after_n_rows)
+            .map(|rows| rows * predicate_selectivity(predicate)),
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            path,
+            stop_after_n_rows,
+            predicate,
+            ..
+        } => scan_rows(parquet_rows(path), *stop_after_n_rows)
+            .map(|rows| rows * predicate_selectivity(predicate)),
+        #[cfg(feature = "ipc")]
+        IpcScan {
+            path,
+            stop_after_n_rows,
+            predicate,
+            ..
+        } => scan_rows(ipc_rows(path), *stop_after_n_rows)
+            .map(|rows| rows * predicate_selectivity(predicate)),
+        Selection { input, predicate } => {
+            input_rows(input).map(|rows| rows * selectivity(*predicate, expr_arena))
+        }
+        Slice { input, len, .. } => input_rows(input).map(|rows| rows.min(*len as f64)),
+        Melt {
+            input, value_vars, ..
+        } => input_rows(input).map(|rows| rows * std::cmp::max(value_vars.len(), 1) as f64),
+        Join {
+            input_left,
+            input_right,
+            how,
+            ..
+        } => {
+            let left = input_rows(input_left)?;
+            let right = input_rows(input_right)?;
+            match how {
+                JoinType::Inner => Some(left.max(right)),
+                JoinType::Outer => Some(left + right),
+                #[cfg(feature = "cross_join")]
+                JoinType::Cross => Some(left * right),
+                _ => Some(left),
+            }
+        }
+        Union { inputs } => inputs.iter().map(input_rows).sum(),
+        Projection { input, .. }
+        | LocalProjection { input, .. }
+        | HStack { input, .. }
+        | Sort { input, .. }
+        | Cache { input, .. }
+        | Distinct { input, .. }
+        | Aggregate { input, .. } => input_rows(input),
+        Explode { .. } | Udf { .. } => None,
+    }
+}
+
+fn column_names(nodes: &[Node], expr_arena: &Arena<AExpr>) -> Option<Vec<Arc<String>>> {
+    nodes
+        .iter()
+        .map(|node| match expr_arena.get(*node) {
+            AExpr::Column(name) => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Reorders chains of inner joins, so that the input that is estimated to be the smallest is
+/// joined first. This keeps the intermediate results small.
+///
+/// `(a JOIN b) JOIN c` is rewritten to `(a JOIN c) JOIN b` if `c` is estimated to be smaller
+/// than `b` and both joins only use columns of `a` as left keys. The rewrite is skipped if
+/// non-key columns of `a`, `b` and `c` share names, as the suffixes of duplicate columns depend
+/// on the join order. A projection restores the original column order.
+pub struct JoinOrder {}
+
+impl OptimizationRule for JoinOrder {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        use ALogicalPlan::*;
+        let (left, c, outer_left_on, outer_right_on, outer_par, outer_force_par, outer_dup) =
+            match lp_arena.get(node) {
+                Join {
+                    input_left,
+                    input_right,
+                    how: JoinType::Inner,
+                    left_on,
+                    right_on,
+                    allow_par,
+                    force_par,
+                    duplicates,
+                    ..
+                } => (
+                    *input_left,
+                    *input_right,
+                    left_on.clone(),
+                    right_on.clone(),
+                    *allow_par,
+                    *force_par,
+                    duplicates.clone(),
+                ),
+                _ => return None,
+            };
+        let (a, b, inner_left_on, inner_right_on, inner_par, inner_force_par, inner_dup) =
+            match lp_arena.get(left) {
+                Join {
+                    input_left,
+                    input_right,
+                    how: JoinType::Inner,
+                    left_on,
+                    right_on,
+                    allow_par,
+                    force_par,
+                    duplicates,
+                    ..
+                } => (
+                    *input_left,
+                    *input_right,
+                    left_on.clone(),
+                    right_on.clone(),
+                    *allow_par,
+                    *force_par,
+                    duplicates.clone(),
+                ),
+                _ => return None,
+            };
+
+        let rows_b = estimate_rows(b, lp_arena, expr_arena)?;
+        let rows_c = estimate_rows(c, lp_arena, expr_arena)?;
+        if rows_c >= rows_b {
+            return None;
+        }
+
+        // the outer join must only depend on columns of `a`
+        let names_a: HashSet<_> = lp_arena
+            .get(a)
+            .schema(lp_arena)
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        let outer_left_names = column_names(&outer_left_on, expr_arena)?;
+        if !outer_left_names
+            .iter()
+            .all(|name| names_a.contains(name.as_str()))
+        {
+            return None;
+        }
+
+        // the columns that remain after the joins may not clash
+        let non_keys = |input: Node, keys: &[Node]| -> Option<Vec<String>> {
+            let keys = column_names(keys, expr_arena)?;
+            Some(
+                lp_arena
+                    .get(input)
+                    .schema(lp_arena)
+                    .fields()
+                    .iter()
+                    .map(|f| f.name().clone())
+                    .filter(|name| !keys.iter().any(|key| key.as_str() == name))
+                    .collect(),
+            )
+        };
+        let names_b = non_keys(b, &inner_right_on)?;
+        let names_c = non_keys(c, &outer_right_on)?;
+        let mut all_names = names_a.clone();
+        for name in names_b.into_iter().chain(names_c) {
+            if !all_names.insert(name) {
+                return None;
+            }
+        }
+
+        let projection = lp_arena
+            .get(node)
+            .schema(lp_arena)
+            .fields()
+            .iter()
+            .map(|f| expr_arena.add(AExpr::Column(Arc::new(f.name().clone()))))
+            .collect();
+
+        let lp = ALogicalPlanBuilder::new(a, expr_arena, lp_arena)
+            .join(
+                c,
+                JoinType::Inner,
+                outer_left_on,
+                outer_right_on,
+                outer_par,
+                outer_force_par,
+                outer_dup,
+            )
+            .join(
+                b,
+                JoinType::Inner,
+                inner_left_on,
+                inner_right_on,
+                inner_par,
+                inner_force_par,
+                inner_dup,
+            )
+            .project(projection)
+            .build();
+        Some(lp)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use polars_core::df;
+
+    #[test]
+    #[cfg(all(feature = "parquet", feature = "ipc"))]
+    fn test_estimate_file_rows() -> Result<()> {
+        use polars_io::prelude::*;
+        use std::fs::File;
+
+        // long strings make the file much larger than 8 bytes per value
+        let df = df! {
+            "a" => (0..100).collect::<Vec<i64>>(),
+            "b" => (0..100).map(|v| format!("{:0>100}", v)).collect::<Vec<_>>()
+        }?;
+        let parquet = std::env::temp_dir().join("polars_estimate_rows.parquet");
+        let ipc = std::env::temp_dir().join("polars_estimate_rows.ipc");
+        ParquetWriter::new(File::create(&parquet)?).finish(&df)?;
+        IpcWriter::new(&mut File::create(&ipc)?).finish(&df)?;
+
+        let scans = vec![
+            LazyFrame::new_from_parquet(parquet.to_string_lossy().to_string(), None, false)?,
+            LazyFrame::scan_ipc(ipc.to_string_lossy().to_string(), None, false)?,
+            LazyFrame::scan_ipc(ipc.to_string_lossy().to_string(), Some(10), false)?,
+        ];
+        for (scan, expected) in scans.into_iter().zip(vec![100.0, 100.0, 10.0]) {
+            let (node, expr_arena, lp_arena) = scan.into_alp();
+            assert_eq!(estimate_rows(node, &lp_arena, &expr_arena), Some(expected));
+        }
+        Ok(())
+    }
+}
@@ -59,6 +59,7 @@ fn combine_lp_nodes(
                 keys: keys_l,
                 aggs: aggs_l,
                 apply: apply_l,
+                options: options_l,
                 ..
             },
             Aggregate {
@@ -66,6 +67,7 @@ fn combine_lp_nodes(
                 keys: keys_r,
                 aggs: aggs_r,
                 apply: apply_r,
+                options: options_r,
                 ..
             },
             // skip if we have custom functions
@@ -74,10 +76,12 @@ fn combine_lp_nodes(
                 && apply_r.is_none()
                 // check if aggregation keys can be combined.
                 && equal_aexprs(keys_l, keys_r, expr_arena)
+                && options_l == options_r
         }
         =>
             {
                 let keys = keys_l.clone();
+                let options = options_l.clone();
                 let aggs = aggs_l
                     .iter()
                     .copied()
@@ -88,7 +92,7 @@ fn combine_lp_nodes(
                     .map(|input| {
                         let node = lp_arena.add(input);
                         ALogicalPlanBuilder::new(node, expr_arena, lp_arena)
-                            .groupby(keys, aggs, None)
+                            .groupby(keys, aggs, None, options)
                             .build()
 
                     })
@@ -123,10 +127,11 @@ fn combine_lp_nodes(
             predicate,
             aggregate,
             cache,
-            low_memory
+            low_memory,
+            row_count
         },
-            CsvScan {path: path_r, with_columns: with_columns_r, ..})
-        if canonicalize(path_l).unwrap() == canonicalize(path_r).unwrap()
+            CsvScan {path: path_r, with_columns: with_columns_r, row_count: row_count_r, ..})
+        if canonicalize(path_l).unwrap() == canonicalize(path_r).unwrap() && row_count == row_count_r
         => {
             let path = path_l.clone();
             let with_columns = match (with_columns_l, with_columns_r) {
@@ -148,7 +153,8 @@ fn combine_lp_nodes(
                 with_columns,
                 aggregate: aggregate.clone(),
                 cache: *cache,
-                low_memory: *low_memory
+                low_memory: *low_memory,
+                row_count: row_count.clone()
             })
 
 
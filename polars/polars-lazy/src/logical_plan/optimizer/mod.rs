@@ -4,13 +4,15 @@ use polars_core::prelude::*;
 use std::collections::HashMap;
 
 pub(crate) mod aggregate_pushdown;
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 pub(crate) mod aggregate_scan_projections;
+pub(crate) mod join_order;
 #[cfg(feature = "private")]
 pub(crate) mod join_pruning;
 pub(crate) mod predicate_pushdown;
 pub(crate) mod projection_pushdown;
 pub(crate) mod simplify_expr;
+pub(crate) mod slice_pushdown;
 pub(crate) mod stack_opt;
 pub(crate) mod type_coercion;
 
@@ -1,5 +1,5 @@
 use crate::logical_plan::optimizer::ALogicalPlanBuilder;
-use crate::logical_plan::{optimizer, Context};
+use crate::logical_plan::{join_has_right_columns, optimizer, Context};
 use crate::prelude::*;
 use crate::utils::rename_aexpr_root_name;
 use crate::utils::{
@@ -353,7 +353,22 @@ impl PredicatePushDown {
                 aggregate,
                 stop_after_n_rows,
                 cache,
+                row_count,
             } => {
+                // the row count depends on all the rows in the file, so we filter after the scan
+                if row_count.is_some() {
+                    let lp = ParquetScan {
+                        path,
+                        schema,
+                        with_columns,
+                        predicate,
+                        aggregate,
+                        stop_after_n_rows,
+                        cache,
+                        row_count,
+                    };
+                    return Ok(self.finish_at_leaf(lp, acc_predicates, lp_arena, expr_arena));
+                }
                 let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
 
                 let lp = ParquetScan {
@@ -364,6 +379,46 @@ impl PredicatePushDown {
                     aggregate,
                     stop_after_n_rows,
                     cache,
+                    row_count,
+                };
+                Ok(lp)
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                with_columns,
+                predicate,
+                aggregate,
+                stop_after_n_rows,
+                cache,
+                row_count,
+            } => {
+                // the row count depends on all the rows in the file, so we filter after the scan
+                if row_count.is_some() {
+                    let lp = IpcScan {
+                        path,
+                        schema,
+                        with_columns,
+                        predicate,
+                        aggregate,
+                        stop_after_n_rows,
+                        cache,
+                        row_count,
+                    };
+                    return Ok(self.finish_at_leaf(lp, acc_predicates, lp_arena, expr_arena));
+                }
+                let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
+
+                let lp = IpcScan {
+                    path,
+                    schema,
+                    with_columns,
+                    predicate,
+                    aggregate,
+                    stop_after_n_rows,
+                    cache,
+                    row_count,
                 };
                 Ok(lp)
             }
@@ -381,7 +436,27 @@ impl PredicatePushDown {
                 aggregate,
                 cache,
                 low_memory,
+                row_count,
             } => {
+                // the row count depends on all the rows in the file, so we filter after the scan
+                if row_count.is_some() {
+                    let lp = CsvScan {
+                        path,
+                        schema,
+                        has_header,
+                        delimiter,
+                        ignore_errors,
+                        skip_rows,
+                        stop_after_n_rows,
+                        with_columns,
+                        predicate,
+                        aggregate,
+                        cache,
+                        low_memory,
+                        row_count,
+                    };
+                    return Ok(self.finish_at_leaf(lp, acc_predicates, lp_arena, expr_arena));
+                }
                 let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
 
                 let lp = CsvScan {
@@ -397,38 +472,41 @@ impl PredicatePushDown {
                     aggregate,
                     cache,
                     low_memory,
+                    row_count,
                 };
                 Ok(lp)
             }
-            Explode { input, columns } => {
+            Explode {
+                input,
+                columns,
+                schema,
+            } => {
                 let condition = |name: Arc<String>| columns.contains(&*name);
                 let local_predicates =
                     transfer_to_local(expr_arena, &mut acc_predicates, condition);
 
                 self.pushdown_and_assign(input, acc_predicates, lp_arena, expr_arena)?;
-                let lp = Explode { input, columns };
+                let lp = Explode {
+                    input,
+                    columns,
+                    schema,
+                };
                 Ok(self.apply_predicate(lp, local_predicates, lp_arena, expr_arena))
             }
             Distinct {
                 input,
                 subset,
                 maintain_order,
+                keep,
             } => {
-                // currently the distinct operation only keeps the first occurrences.
-                // this may have influence on the pushed down predicates. If the pushed down predicates
-                // contain a binary expression (thus depending on values in multiple columns)
-                // the final result may differ if it is pushed down.
-
-                let mut root_count = 0;
-
-                // if this condition is called more than once, its a binary or ternary operation.
-                let condition = |_| {
-                    if root_count == 0 {
-                        root_count += 1;
-                        false
-                    } else {
-                        true
-                    }
+                // the distinct operation keeps one (or none) of the rows with the same values
+                // in the subset columns. A predicate on the subset columns filters all rows of
+                // such a group or none of them, so it can be pushed down. A predicate on the
+                // other columns could filter the row that would be kept, and keep a row that
+                // would have been dropped, so it is applied after the distinct operation.
+                let condition = |name: Arc<String>| match &*subset {
+                    Some(subset) => !subset.contains(&*name),
+                    None => false,
                 };
                 let local_predicates =
                     transfer_to_local(expr_arena, &mut acc_predicates, condition);
@@ -438,6 +516,7 @@ impl PredicatePushDown {
                     input,
                     maintain_order,
                     subset,
+                    keep,
                 };
                 Ok(self.apply_predicate(lp, local_predicates, lp_arena, expr_arena))
             }
@@ -447,6 +526,7 @@ impl PredicatePushDown {
                 aggs,
                 schema,
                 apply,
+                options,
             } => {
                 self.pushdown_and_assign(input, optimizer::init_hashmap(), lp_arena, expr_arena)?;
 
@@ -457,6 +537,7 @@ impl PredicatePushDown {
                     aggs,
                     schema,
                     apply,
+                    options,
                 };
                 Ok(self.finish_at_leaf(lp, acc_predicates, lp_arena, expr_arena))
             }
@@ -469,6 +550,7 @@ impl PredicatePushDown {
                 allow_par,
                 force_par,
                 schema,
+                duplicates,
             } => {
                 let schema_left = lp_arena.get(input_left).schema(lp_arena);
                 let schema_right = lp_arena.get(input_right).schema(lp_arena);
@@ -499,7 +581,9 @@ impl PredicatePushDown {
                         );
                         filter_left = true;
                     }
-                    if check_down_node(predicate, schema_right, expr_arena) {
+                    if check_down_node(predicate, schema_right, expr_arena)
+                        && can_pushdown_right(&how)
+                    {
                         let name = get_insertion_name(expr_arena, predicate, schema_right);
                         insert_and_combine_predicate(
                             &mut pushdown_right,
@@ -536,6 +620,7 @@ impl PredicatePushDown {
                     allow_par,
                     force_par,
                     schema,
+                    duplicates,
                 };
                 Ok(self.apply_predicate(lp, local_predicates, lp_arena, expr_arena))
             }
@@ -623,13 +708,23 @@ impl PredicatePushDown {
 
                     return Ok(self.apply_predicate(lp, local_predicates, lp_arena, expr_arena));
                 }
-                Ok(Udf {
+                // the predicates are applied on top of the udf
+                self.pushdown_and_assign(input, optimizer::init_hashmap(), lp_arena, expr_arena)?;
+                let lp = Udf {
                     input,
                     function,
                     predicate_pd,
                     projection_pd,
                     schema,
-                })
+                };
+                Ok(self.finish_at_leaf(lp, acc_predicates, lp_arena, expr_arena))
+            }
+            // the cached result is shared by multiple branches of the query,
+            // so we apply the predicates on top of the cache.
+            Cache { input, id } => {
+                self.pushdown_and_assign(input, optimizer::init_hashmap(), lp_arena, expr_arena)?;
+                let lp = Cache { input, id };
+                Ok(self.finish_at_leaf(lp, acc_predicates, lp_arena, expr_arena))
             }
             lp => {
                 let inputs = lp.get_inputs();
@@ -670,6 +765,16 @@ impl PredicatePushDown {
     }
 }
 
+/// Filtering the right table of an as-of, semi or anti join changes which rows of the left table
+/// are kept.
+fn can_pushdown_right(how: &JoinType) -> bool {
+    #[cfg(feature = "asof_join")]
+    if matches!(how, JoinType::AsOf(_)) {
+        return false;
+    }
+    join_has_right_columns(*how)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -13,7 +13,7 @@ fn init_set() -> HashSet<Arc<String>, RandomState> {
 }
 
 /// utility function to get names of the columns needed in projection at scan level
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 fn get_scan_columns(
     acc_projections: &mut Vec<Node>,
     expr_arena: &Arena<AExpr>,
@@ -308,9 +308,14 @@ impl ProjectionPushDown {
                 aggregate,
                 stop_after_n_rows,
                 cache,
+                row_count,
                 ..
             } => {
-                let with_columns = get_scan_columns(&mut acc_projections, expr_arena);
+                let mut with_columns = get_scan_columns(&mut acc_projections, expr_arena);
+                // the row count is not in the file, it is added after reading
+                if let (Some(columns), Some(row_count)) = (&mut with_columns, &row_count) {
+                    columns.retain(|name| name != &row_count.name);
+                }
                 let lp = ParquetScan {
                     path,
                     schema,
@@ -319,6 +324,35 @@ impl ProjectionPushDown {
                     aggregate,
                     stop_after_n_rows,
                     cache,
+                    row_count,
+                };
+                Ok(lp)
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                predicate,
+                aggregate,
+                stop_after_n_rows,
+                cache,
+                row_count,
+                ..
+            } => {
+                let mut with_columns = get_scan_columns(&mut acc_projections, expr_arena);
+                // the row count is not in the file, it is added after reading
+                if let (Some(columns), Some(row_count)) = (&mut with_columns, &row_count) {
+                    columns.retain(|name| name != &row_count.name);
+                }
+                let lp = IpcScan {
+                    path,
+                    schema,
+                    with_columns,
+                    predicate,
+                    aggregate,
+                    stop_after_n_rows,
+                    cache,
+                    row_count,
                 };
                 Ok(lp)
             }
@@ -335,9 +369,14 @@ impl ProjectionPushDown {
                 aggregate,
                 cache,
                 low_memory,
+                row_count,
                 ..
             } => {
-                let with_columns = get_scan_columns(&mut acc_projections, expr_arena);
+                let mut with_columns = get_scan_columns(&mut acc_projections, expr_arena);
+                // the row count is not in the file, it is added after reading
+                if let (Some(columns), Some(row_count)) = (&mut with_columns, &row_count) {
+                    columns.retain(|name| name != &row_count.name);
+                }
                 let lp = CsvScan {
                     path,
                     schema,
@@ -351,6 +390,7 @@ impl ProjectionPushDown {
                     aggregate,
                     cache,
                     low_memory,
+                    row_count,
                 };
                 Ok(lp)
             }
@@ -358,6 +398,7 @@ impl ProjectionPushDown {
                 input,
                 by_column,
                 reverse,
+                nulls_last,
             } => {
                 if !acc_projections.is_empty() {
                     // Make sure that the column(s) used for the sort is projected
@@ -387,9 +428,10 @@ impl ProjectionPushDown {
                     input,
                     by_column,
                     reverse,
+                    nulls_last,
                 })
             }
-            Explode { input, columns } => {
+            Explode { input, columns, .. } => {
                 columns.iter().for_each(|name| {
                     add_str_to_accumulated(
                         name,
@@ -406,12 +448,16 @@ impl ProjectionPushDown {
                     lp_arena,
                     expr_arena,
                 )?;
-                Ok(Explode { input, columns })
+                // the input may have lost columns, so the schema is determined again
+                let builder =
+                    ALogicalPlanBuilder::new(input, expr_arena, lp_arena).explode(columns);
+                Ok(builder.build())
             }
             Distinct {
                 input,
                 maintain_order,
                 subset,
+                keep,
             } => {
                 // make sure that the set of unique columns is projected
                 if let Some(subset) = (&*subset).as_ref() {
@@ -437,6 +483,7 @@ impl ProjectionPushDown {
                     input,
                     maintain_order,
                     subset,
+                    keep,
                 })
             }
             Selection { predicate, input } => {
@@ -512,6 +559,7 @@ impl ProjectionPushDown {
                 aggs,
                 apply,
                 schema,
+                options,
             } => {
                 // the custom function may need all columns so we do the projections here.
                 if let Some(f) = apply {
@@ -521,6 +569,7 @@ impl ProjectionPushDown {
                         aggs,
                         schema,
                         apply: Some(f),
+                        options,
                     };
                     let input = lp_arena.add(lp);
 
@@ -544,6 +593,10 @@ impl ProjectionPushDown {
                     for key in &*keys {
                         add_expr_to_accumulated(*key, &mut acc_projections, &mut names, expr_arena);
                     }
+                    if let Some(name) = options.index_column() {
+                        let node = expr_arena.add(AExpr::Column(Arc::new(name.to_string())));
+                        add_expr_to_accumulated(node, &mut acc_projections, &mut names, expr_arena);
+                    }
 
                     self.pushdown_and_assign(
                         input,
@@ -555,7 +608,7 @@ impl ProjectionPushDown {
                     )?;
 
                     let builder = ALogicalPlanBuilder::new(input, expr_arena, lp_arena)
-                        .groupby(keys, aggs, apply);
+                        .groupby(keys, aggs, apply, options);
                     Ok(builder.build())
                 }
             }
@@ -567,6 +620,7 @@ impl ProjectionPushDown {
                 how,
                 allow_par,
                 force_par,
+                duplicates,
                 ..
             } => {
                 let mut pushdown_left = init_vec();
@@ -606,6 +660,12 @@ impl ProjectionPushDown {
                         }
                     }
 
+                    // with `DuplicateColumns::Error` no columns are renamed
+                    let suffix = match &duplicates {
+                        DuplicateColumns::Suffix(suffix) => suffix.as_str(),
+                        DuplicateColumns::Error => "",
+                    };
+
                     for proj in acc_projections {
                         let mut add_local = true;
 
@@ -623,7 +683,7 @@ impl ProjectionPushDown {
                         }
 
                         // Path for renamed columns due to the join. The column name of the left table
-                        // stays as is, the column of the right will have the "_right" suffix.
+                        // stays as is, the column of the right will have the suffix, "_right" by default.
                         // Thus joining two tables with both a foo column leads to ["foo", "foo_right"]
                         if !self.join_push_down(
                             schema_left,
@@ -639,12 +699,12 @@ impl ProjectionPushDown {
                             let root_column_name =
                                 aexpr_to_root_names(proj, expr_arena).pop().unwrap();
 
-                            // If _right suffix exists we need to push a projection down without this
+                            // If the suffix exists we need to push a projection down without this
                             // suffix.
-                            if root_column_name.ends_with("_right") {
-                                // downwards name is the name without the _right i.e. "foo".
+                            if !suffix.is_empty() && root_column_name.ends_with(suffix) {
+                                // downwards name is the name without the suffix i.e. "foo".
                                 let (downwards_name, _) = root_column_name
-                                    .split_at(root_column_name.len() - "_right".len());
+                                    .split_at(root_column_name.len() - suffix.len());
 
                                 let downwards_name_column =
                                     expr_arena.add(AExpr::Column(Arc::new(downwards_name.into())));
@@ -656,7 +716,7 @@ impl ProjectionPushDown {
                                 // locally we project and alias
                                 let projection = expr_arena.add(AExpr::Alias(
                                     downwards_name_column,
-                                    Arc::new(format!("{}_right", downwards_name)),
+                                    Arc::new(format!("{}{}", downwards_name, suffix)),
                                 ));
                                 local_projection.push(projection);
                             }
@@ -699,6 +759,7 @@ impl ProjectionPushDown {
                     right_on,
                     allow_par,
                     force_par,
+                    duplicates,
                 );
                 Ok(self.finish_node(local_projection, builder))
             }
@@ -751,6 +812,16 @@ impl ProjectionPushDown {
                         lp_arena,
                         expr_arena,
                     )?;
+                } else {
+                    // the udf may need all columns
+                    self.pushdown_and_assign(
+                        input,
+                        init_vec(),
+                        init_set(),
+                        projections_seen,
+                        lp_arena,
+                        expr_arena,
+                    )?;
                 }
                 Ok(Udf {
                     input,
@@ -760,7 +831,22 @@ impl ProjectionPushDown {
                     schema,
                 })
             }
-            lp @ Slice { .. } | lp @ Cache { .. } => {
+            // the cached result is shared by multiple branches of the query,
+            // so we project on top of the cache.
+            Cache { input, id } => {
+                self.pushdown_and_assign(
+                    input,
+                    init_vec(),
+                    init_set(),
+                    projections_seen,
+                    lp_arena,
+                    expr_arena,
+                )?;
+                let node = lp_arena.add(Cache { input, id });
+                let builder = ALogicalPlanBuilder::new(node, expr_arena, lp_arena);
+                Ok(self.finish_node(acc_projections, builder))
+            }
+            lp @ Slice { .. } | lp @ Union { .. } => {
                 let inputs = lp.get_inputs();
                 let exprs = lp.get_exprs();
 
@@ -0,0 +1,215 @@
+use crate::prelude::*;
+use crate::utils::is_elementwise;
+use polars_core::prelude::*;
+use std::cmp::min;
+
+pub(crate) struct SlicePushDown {}
+
+/// The slice that is pushed down.
+#[derive(Copy, Clone)]
+struct State {
+    offset: i64,
+    len: usize,
+}
+
+/// Get the maximum number of rows a scan reads, if a slice may be pushed down to it.
+/// Predicates and aggregations are applied during the scan, so those block the pushdown.
+fn scan_n_rows(lp: &mut ALogicalPlan) -> Option<&mut Option<usize>> {
+    use ALogicalPlan::*;
+    match lp {
+        #[cfg(feature = "csv-file")]
+        CsvScan {
+            stop_after_n_rows,
+            predicate: None,
+            aggregate,
+            ..
+        } if aggregate.is_empty() => Some(stop_after_n_rows),
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            stop_after_n_rows,
+            predicate: None,
+            aggregate,
+            ..
+        } if aggregate.is_empty() => Some(stop_after_n_rows),
+        #[cfg(feature = "ipc")]
+        IpcScan {
+            stop_after_n_rows,
+            predicate: None,
+            aggregate,
+            ..
+        } if aggregate.is_empty() => Some(stop_after_n_rows),
+        _ => None,
+    }
+}
+
+impl SlicePushDown {
+    /// Apply the slice on top of this node.
+    fn no_pushdown_finish(
+        &self,
+        lp: ALogicalPlan,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+    ) -> ALogicalPlan {
+        match state {
+            Some(state) => {
+                let input = lp_arena.add(lp);
+                ALogicalPlan::Slice {
+                    input,
+                    offset: state.offset,
+                    len: state.len,
+                }
+            }
+            None => lp,
+        }
+    }
+
+    /// The slice cannot be pushed down past this node. We apply it on top of this node and
+    /// restart the optimization for the inputs of this node.
+    fn no_pushdown_restart(
+        &self,
+        lp: ALogicalPlan,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<ALogicalPlan> {
+        let inputs = lp.get_inputs();
+        let exprs = lp.get_exprs();
+
+        for &node in &inputs {
+            self.pushdown_and_assign(node, None, lp_arena, expr_arena)?;
+        }
+        let lp = lp.from_exprs_and_input(exprs, inputs);
+        Ok(self.no_pushdown_finish(lp, state, lp_arena))
+    }
+
+    fn pushdown_and_assign(
+        &self,
+        input: Node,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<()> {
+        let alp = lp_arena.take(input);
+        let lp = self.pushdown(alp, state, lp_arena, expr_arena)?;
+        lp_arena.replace(input, lp);
+        Ok(())
+    }
+
+    fn pushdown(
+        &self,
+        mut logical_plan: ALogicalPlan,
+        state: Option<State>,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<ALogicalPlan> {
+        use ALogicalPlan::*;
+
+        // a scan can stop reading once the end of the slice is reached.
+        if let Some(state) = state.filter(|state| state.offset >= 0) {
+            if let Some(n_rows) = scan_n_rows(&mut logical_plan) {
+                let end = state.offset as usize + state.len;
+                *n_rows = Some(n_rows.map_or(end, |n| min(n, end)));
+                return Ok(if state.offset == 0 {
+                    logical_plan
+                } else {
+                    self.no_pushdown_finish(logical_plan, Some(state), lp_arena)
+                });
+            }
+        }
+
+        match (logical_plan, state) {
+            (Slice { input, offset, len }, None) => {
+                let state = Some(State { offset, len });
+                let alp = lp_arena.take(input);
+                self.pushdown(alp, state, lp_arena, expr_arena)
+            }
+            // combine the slices if both start counting from the beginning
+            (Slice { input, offset, len }, Some(outer)) if offset >= 0 && outer.offset >= 0 => {
+                let state = Some(State {
+                    offset: offset + outer.offset,
+                    len: min(outer.len, len.saturating_sub(outer.offset as usize)),
+                });
+                let alp = lp_arena.take(input);
+                self.pushdown(alp, state, lp_arena, expr_arena)
+            }
+            // push down the inner slice and apply the outer slice on top of it
+            (lp @ Slice { .. }, Some(outer)) => {
+                let lp = self.pushdown(lp, None, lp_arena, expr_arena)?;
+                Ok(self.no_pushdown_finish(lp, Some(outer), lp_arena))
+            }
+            (
+                DataFrameScan {
+                    df,
+                    schema,
+                    projection,
+                    selection: None,
+                },
+                Some(state),
+            ) => {
+                let df = Arc::new(df.slice(state.offset, state.len));
+                Ok(DataFrameScan {
+                    df,
+                    schema,
+                    projection,
+                    selection: None,
+                })
+            }
+            // row by row operations don't change which rows are selected by the slice
+            (
+                Projection {
+                    input,
+                    expr,
+                    schema,
+                },
+                Some(state),
+            ) if expr.iter().all(|e| is_elementwise(*e, expr_arena)) => {
+                self.pushdown_and_assign(input, Some(state), lp_arena, expr_arena)?;
+                Ok(Projection {
+                    input,
+                    expr,
+                    schema,
+                })
+            }
+            (
+                LocalProjection {
+                    expr,
+                    input,
+                    schema,
+                },
+                Some(state),
+            ) if expr.iter().all(|e| is_elementwise(*e, expr_arena)) => {
+                self.pushdown_and_assign(input, Some(state), lp_arena, expr_arena)?;
+                Ok(LocalProjection {
+                    expr,
+                    input,
+                    schema,
+                })
+            }
+            (
+                HStack {
+                    input,
+                    exprs,
+                    schema,
+                },
+                Some(state),
+            ) if exprs.iter().all(|e| is_elementwise(*e, expr_arena)) => {
+                self.pushdown_and_assign(input, Some(state), lp_arena, expr_arena)?;
+                Ok(HStack {
+                    input,
+                    exprs,
+                    schema,
+                })
+            }
+            (lp, state) => self.no_pushdown_restart(lp, state, lp_arena, expr_arena),
+        }
+    }
+
+    pub fn optimize(
+        &self,
+        logical_plan: ALogicalPlan,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<ALogicalPlan> {
+        self.pushdown(logical_plan, None, lp_arena, expr_arena)
+    }
+}
@@ -79,7 +79,10 @@ impl OptimizationRule for TypeCoercionRule {
                     let type_right = right
                         .get_type(input_schema, Context::Default, expr_arena)
                         .expect("could not get dtype");
-                    if type_left == type_right {
+                    // temporal arithmetic is dispatched on the original types
+                    if type_left == type_right
+                        || op.temporal_output_type(&type_left, &type_right).is_some()
+                    {
                         None
                     } else {
                         let st = get_supertype(&type_left, &type_right)
@@ -16,10 +16,10 @@ impl Executor for CacheExec {
 
         // cache miss
         let df = self.input.execute(state)?;
-        state.store_cache(std::mem::take(&mut self.key), df.clone());
         if std::env::var(POLARS_VERBOSE).is_ok() {
             println!("cache set {:?}", self.key);
         }
+        state.store_cache(std::mem::take(&mut self.key), df.clone());
         Ok(df)
     }
 }
@@ -6,6 +6,7 @@ pub(crate) struct DropDuplicatesExec {
     pub(crate) input: Box<dyn Executor>,
     pub(crate) maintain_order: bool,
     pub(crate) subset: Option<Vec<String>>,
+    pub(crate) keep: UniqueKeepStrategy,
 }
 
 impl Executor for DropDuplicatesExec {
@@ -14,6 +15,7 @@ impl Executor for DropDuplicatesExec {
         df.drop_duplicates(
             self.maintain_order,
             self.subset.as_ref().map(|v| v.as_ref()),
+            self.keep,
         )
     }
 }
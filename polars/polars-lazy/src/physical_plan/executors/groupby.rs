@@ -94,6 +94,8 @@ pub struct PartitionGroupByExec {
     key: Arc<dyn PhysicalExpr>,
     phys_aggs: Vec<Arc<dyn PhysicalExpr>>,
     aggs: Vec<Expr>,
+    /// Passed to the planner of the outer aggregations.
+    common_subexpr_elim: bool,
 }
 
 impl PartitionGroupByExec {
@@ -102,12 +104,14 @@ impl PartitionGroupByExec {
         key: Arc<dyn PhysicalExpr>,
         phys_aggs: Vec<Arc<dyn PhysicalExpr>>,
         aggs: Vec<Expr>,
+        common_subexpr_elim: bool,
     ) -> Self {
         Self {
             input,
             key,
             phys_aggs,
             aggs,
+            common_subexpr_elim,
         }
     }
 }
@@ -180,7 +184,7 @@ fn get_outer_agg_exprs(
         })
         .collect::<Result<Vec<_>>>()?;
 
-    let planner = DefaultPlanner {};
+    let planner = DefaultPlanner::new(exec.common_subexpr_elim);
 
     let outer_phys_aggs = aggs_and_names
         .iter()
@@ -0,0 +1,38 @@
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use polars_core::prelude::*;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+/// Groupby over (possibly overlapping) time windows.
+pub(crate) struct GroupByDynamicExec {
+    pub(crate) input: Box<dyn Executor>,
+    pub(crate) keys: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) aggs: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) options: DynamicGroupOptions,
+}
+
+impl Executor for GroupByDynamicExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let df = self.input.execute(state)?;
+        let keys = self
+            .keys
+            .iter()
+            .map(|e| e.evaluate(&df, state))
+            .collect::<Result<_>>()?;
+        let (mut columns, groups) = df.groupby_dynamic(keys, &self.options)?;
+
+        let agg_columns = POOL.install(|| {
+            self.aggs
+                .par_iter()
+                .map(|expr| {
+                    let agg_expr = expr.as_agg_expr()?;
+                    agg_expr.aggregate(&df, &groups, state)
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        columns.extend(agg_columns.into_iter().flatten());
+        Ok(DataFrame::new_no_checks(columns))
+    }
+}
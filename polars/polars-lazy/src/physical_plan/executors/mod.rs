@@ -3,13 +3,19 @@ pub mod drop_duplicates;
 pub mod explode;
 pub mod filter;
 pub mod groupby;
+#[cfg(feature = "dynamic_groupby")]
+pub(crate) mod groupby_dynamic;
+#[cfg(feature = "rolling_groupby")]
+pub(crate) mod groupby_rolling;
 pub mod join;
 pub mod melt;
+pub mod profile;
 pub mod scan;
 pub mod slice;
 pub mod sort;
 pub mod stack;
 pub mod udf;
+pub mod union;
 pub mod various;
 
 use super::*;
@@ -0,0 +1,24 @@
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use polars_core::prelude::*;
+use std::time::Instant;
+
+/// Records the start and end of the execution of its input.
+pub struct ProfileExec {
+    pub input: Box<dyn Executor>,
+    pub name: &'static str,
+}
+
+impl Executor for ProfileExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        match &state.node_timer {
+            Some(timer) => {
+                let start = Instant::now();
+                let out = self.input.execute(state)?;
+                timer.store(self.name.to_string(), start, Instant::now());
+                Ok(out)
+            }
+            None => self.input.execute(state),
+        }
+    }
+}
@@ -1,4 +1,8 @@
 use super::*;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+use crate::utils::open_scan_source;
+#[cfg(feature = "csv-file")]
+use crate::utils::scan_csv_reader;
 use crate::utils::try_path_to_str;
 use polars_io::prelude::*;
 use polars_io::{csv::CsvEncoding, ScanAggregation};
@@ -41,6 +45,7 @@ pub struct ParquetExec {
     aggregate: Vec<ScanAggregation>,
     stop_after_n_rows: Option<usize>,
     cache: bool,
+    row_count: Option<RowCount>,
 }
 
 #[cfg(feature = "parquet")]
@@ -53,6 +58,7 @@ impl ParquetExec {
         aggregate: Vec<ScanAggregation>,
         stop_after_n_rows: Option<usize>,
         cache: bool,
+        row_count: Option<RowCount>,
     ) -> Self {
         ParquetExec {
             path,
@@ -62,6 +68,43 @@ impl ParquetExec {
             aggregate,
             stop_after_n_rows,
             cache,
+            row_count,
+        }
+    }
+}
+#[cfg(feature = "ipc")]
+pub struct IpcExec {
+    path: PathBuf,
+    schema: SchemaRef,
+    with_columns: Option<Vec<String>>,
+    predicate: Option<Arc<dyn PhysicalExpr>>,
+    aggregate: Vec<ScanAggregation>,
+    stop_after_n_rows: Option<usize>,
+    cache: bool,
+    row_count: Option<RowCount>,
+}
+
+#[cfg(feature = "ipc")]
+impl IpcExec {
+    pub(crate) fn new(
+        path: PathBuf,
+        schema: SchemaRef,
+        with_columns: Option<Vec<String>>,
+        predicate: Option<Arc<dyn PhysicalExpr>>,
+        aggregate: Vec<ScanAggregation>,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+        row_count: Option<RowCount>,
+    ) -> Self {
+        IpcExec {
+            path,
+            schema,
+            with_columns,
+            predicate,
+            aggregate,
+            stop_after_n_rows,
+            cache,
+            row_count,
         }
     }
 }
@@ -70,18 +113,30 @@ impl ParquetExec {
 impl Executor for ParquetExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let path_str = try_path_to_str(&self.path)?;
-        let cache_key = match &self.predicate {
+        let mut cache_key = match &self.predicate {
             Some(predicate) => format!("{}{:?}", path_str, predicate.as_expression()),
             None => path_str.to_string(),
         };
+        // a slice may be pushed down to this scan
+        if let Some(n_rows) = self.stop_after_n_rows {
+            cache_key.push_str(&format!("n_rows: {}", n_rows));
+        }
+        if let Some(row_count) = &self.row_count {
+            cache_key.push_str(&format!("row_count: {:?}", row_count));
+        }
         if let Some(df) = state.cache_hit(&cache_key) {
             return Ok(df);
         }
         // cache miss
-        let file = std::fs::File::open(&self.path).unwrap();
+        let file = open_scan_source(&self.path)?;
 
-        let with_columns = mem::take(&mut self.with_columns);
-        let schema = mem::take(&mut self.schema);
+        // if only the row count is projected, all columns are read to get the number of rows
+        let with_columns = mem::take(&mut self.with_columns).filter(|columns| !columns.is_empty());
+        // the row count is the first field of the schema, but is not in the file
+        let schema = match self.row_count {
+            Some(_) => Arc::new(Schema::new(self.schema.fields()[1..].to_vec())),
+            None => mem::take(&mut self.schema),
+        };
 
         let projection: Option<Vec<_>> = with_columns.map(|with_columns| {
             with_columns
@@ -101,7 +156,7 @@ impl Executor for ParquetExec {
             .clone()
             .map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>);
 
-        let df = ParquetReader::new(file)
+        let mut df = ParquetReader::new(file)
             .with_stop_after_n_rows(stop_after_n_rows)
             .finish_with_scan_ops(
                 predicate,
@@ -109,6 +164,10 @@ impl Executor for ParquetExec {
                 projection.as_ref().map(|v| v.as_ref()),
             )?;
 
+        if let Some(row_count) = &self.row_count {
+            df.with_row_count_mut(&row_count.name, Some(row_count.offset))?;
+        }
+
         if self.cache {
             state.store_cache(cache_key, df.clone())
         }
@@ -120,6 +179,77 @@ impl Executor for ParquetExec {
     }
 }
 
+#[cfg(feature = "ipc")]
+impl Executor for IpcExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let path_str = try_path_to_str(&self.path)?;
+        let mut cache_key = match &self.predicate {
+            Some(predicate) => format!("{}{:?}", path_str, predicate.as_expression()),
+            None => path_str.to_string(),
+        };
+        // a slice may be pushed down to this scan
+        if let Some(n_rows) = self.stop_after_n_rows {
+            cache_key.push_str(&format!("n_rows: {}", n_rows));
+        }
+        // the projected columns are decoded, the other columns are not read
+        if let Some(with_columns) = &self.with_columns {
+            cache_key.push_str(&format!("with_columns: {:?}", with_columns));
+        }
+        if let Some(row_count) = &self.row_count {
+            cache_key.push_str(&format!("row_count: {:?}", row_count));
+        }
+        if let Some(df) = state.cache_hit(&cache_key) {
+            return Ok(df);
+        }
+        // cache miss
+        let file = open_scan_source(&self.path)?;
+
+        // if only the row count is projected, all columns are read to get the number of rows
+        let with_columns = mem::take(&mut self.with_columns).filter(|columns| !columns.is_empty());
+        // the row count is the first field of the schema, but is not in the file
+        let schema = match self.row_count {
+            Some(_) => Arc::new(Schema::new(self.schema.fields()[1..].to_vec())),
+            None => mem::take(&mut self.schema),
+        };
+
+        let projection: Option<Vec<_>> = with_columns.map(|with_columns| {
+            with_columns
+                .iter()
+                .map(|name| schema.column_with_name(name).unwrap().0)
+                .collect()
+        });
+
+        let stop_after_n_rows = set_n_rows(self.stop_after_n_rows);
+        let aggregate = if self.aggregate.is_empty() {
+            None
+        } else {
+            Some(self.aggregate.as_slice())
+        };
+        let predicate = self
+            .predicate
+            .clone()
+            .map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>);
+
+        let mut df = IpcReader::new(file)
+            .with_stop_after_n_rows(stop_after_n_rows)
+            .with_projection(projection)
+            .finish_with_scan_ops(predicate, aggregate)?;
+
+        if let Some(row_count) = &self.row_count {
+            df.with_row_count_mut(&row_count.name, Some(row_count.offset))?;
+        }
+
+        if self.cache {
+            state.store_cache(cache_key, df.clone())
+        }
+        if std::env::var(POLARS_VERBOSE).is_ok() {
+            println!("ipc {:?} read", self.path);
+        }
+
+        Ok(df)
+    }
+}
+
 #[cfg(feature = "csv-file")]
 pub struct CsvExec {
     pub path: PathBuf,
@@ -134,16 +264,24 @@ pub struct CsvExec {
     pub aggregate: Vec<ScanAggregation>,
     pub cache: bool,
     pub low_memory: bool,
+    pub row_count: Option<RowCount>,
 }
 
 #[cfg(feature = "csv-file")]
 impl Executor for CsvExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let path_str = try_path_to_str(&self.path)?;
-        let state_key = match &self.predicate {
+        let mut state_key = match &self.predicate {
             Some(predicate) => format!("{}{:?}", path_str, predicate.as_expression()),
             None => path_str.to_string(),
         };
+        // a slice may be pushed down to this scan
+        if let Some(n_rows) = self.stop_after_n_rows {
+            state_key.push_str(&format!("n_rows: {}", n_rows));
+        }
+        if let Some(row_count) = &self.row_count {
+            state_key.push_str(&format!("row_count: {:?}", row_count));
+        }
         if self.cache {
             if let Some(df) = state.cache_hit(&state_key) {
                 return Ok(df);
@@ -163,11 +301,15 @@ impl Executor for CsvExec {
             with_columns = None;
         }
         let stop_after_n_rows = set_n_rows(self.stop_after_n_rows);
+        // the row count is the first field of the schema, but is not in the file
+        let schema = match self.row_count {
+            Some(_) => Arc::new(Schema::new(self.schema.fields()[1..].to_vec())),
+            None => self.schema.clone(),
+        };
 
-        let reader = CsvReader::from_path(&self.path)
-            .unwrap()
+        let reader = scan_csv_reader(&self.path)?
             .has_header(self.has_header)
-            .with_schema(self.schema.clone())
+            .with_schema(schema)
             .with_delimiter(self.delimiter)
             .with_ignore_parser_errors(self.ignore_errors)
             .with_skip_rows(self.skip_rows)
@@ -182,7 +324,10 @@ impl Executor for CsvExec {
             Some(self.aggregate.as_slice())
         };
 
-        let df = reader.finish_with_scan_ops(self.predicate.clone(), aggregate)?;
+        let mut df = reader.finish_with_scan_ops(self.predicate.clone(), aggregate)?;
+        if let Some(row_count) = &self.row_count {
+            df.with_row_count_mut(&row_count.name, Some(row_count.offset))?;
+        }
 
         if self.cache {
             state.store_cache(state_key, df.clone());
@@ -221,6 +366,11 @@ impl Executor for DataFrameExec {
         let df = mem::take(&mut self.df);
         let mut df = Arc::try_unwrap(df).unwrap_or_else(|df| (*df).clone());
 
+        // like the file scans, only the first rows are scanned during a fetch
+        if let Some(limit) = set_n_rows(None) {
+            df = df.head(Some(limit));
+        }
+
         // projection should be before selection as those are free
         if let Some(projection) = &self.projection {
             df = evaluate_physical_expressions(&df, projection, state)?;
@@ -233,11 +383,6 @@ impl Executor for DataFrameExec {
             })?;
             df = df.filter(mask)?;
         }
-
-        if let Some(limit) = set_n_rows(None) {
-            Ok(df.head(Some(limit)))
-        } else {
-            Ok(df)
-        }
+        Ok(df)
     }
 }
@@ -6,6 +6,7 @@ pub(crate) struct SortExec {
     pub(crate) input: Box<dyn Executor>,
     pub(crate) by_column: Vec<Arc<dyn PhysicalExpr>>,
     pub(crate) reverse: Vec<bool>,
+    pub(crate) nulls_last: bool,
 }
 
 impl Executor for SortExec {
@@ -31,6 +32,10 @@ impl Executor for SortExec {
             }
         }
 
-        df.sort(&column_names, std::mem::take(&mut self.reverse))
+        df.sort_with_options(
+            &column_names,
+            std::mem::take(&mut self.reverse),
+            self.nulls_last,
+        )
     }
 }
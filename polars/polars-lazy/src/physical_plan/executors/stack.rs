@@ -1,3 +1,6 @@
+use crate::physical_plan::expressions::cache::{
+    cache_common_subexprs, clear_common_subexprs, CommonSubExprs,
+};
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::{prelude::*, POOL};
@@ -6,11 +9,20 @@ use rayon::prelude::*;
 pub struct StackExec {
     input: Box<dyn Executor>,
     expr: Vec<Arc<dyn PhysicalExpr>>,
+    common_subexprs: CommonSubExprs,
 }
 
 impl StackExec {
-    pub(crate) fn new(input: Box<dyn Executor>, expr: Vec<Arc<dyn PhysicalExpr>>) -> Self {
-        Self { input, expr }
+    pub(crate) fn new(
+        input: Box<dyn Executor>,
+        expr: Vec<Arc<dyn PhysicalExpr>>,
+        common_subexprs: CommonSubExprs,
+    ) -> Self {
+        Self {
+            input,
+            expr,
+            common_subexprs,
+        }
     }
 }
 
@@ -19,21 +31,25 @@ impl Executor for StackExec {
         let mut df = self.input.execute(state)?;
         let height = df.height();
 
-        let res = POOL.install(|| {
-            self.expr
-                .par_iter()
-                .map(|expr| {
-                    expr.evaluate(&df, state).map(|series| {
-                        // literal series. Should be whole column size
-                        if series.len() == 1 && height > 1 {
-                            series.expand_at_index(0, height)
-                        } else {
-                            series
-                        }
+        let res = cache_common_subexprs(&df, &self.common_subexprs, state).and_then(|_| {
+            POOL.install(|| {
+                self.expr
+                    .par_iter()
+                    .map(|expr| {
+                        expr.evaluate(&df, state).map(|series| {
+                            // literal series. Should be whole column size
+                            if series.len() == 1 && height > 1 {
+                                series.expand_at_index(0, height)
+                            } else {
+                                series
+                            }
+                        })
                     })
-                })
-                .collect::<Result<Vec<_>>>()
-        })?;
+                    .collect::<Result<Vec<_>>>()
+            })
+        });
+        clear_common_subexprs(&self.common_subexprs, state);
+        let res = res?;
 
         for s in res {
             let name = s.name().to_string();
@@ -0,0 +1,19 @@
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+
+pub(crate) struct UnionExec {
+    pub(crate) inputs: Vec<Box<dyn Executor>>,
+}
+
+impl Executor for UnionExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let dfs = self
+            .inputs
+            .iter_mut()
+            .map(|input| input.execute(state))
+            .collect::<Result<Vec<_>>>()?;
+        accumulate_dataframes_vertical(dfs)
+    }
+}
@@ -1,4 +1,7 @@
 use crate::physical_plan::executors::evaluate_physical_expressions;
+use crate::physical_plan::expressions::cache::{
+    cache_common_subexprs, clear_common_subexprs, CommonSubExprs,
+};
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::prelude::*;
@@ -11,6 +14,7 @@ pub struct StandardExec {
     operation: &'static str,
     input: Box<dyn Executor>,
     expr: Vec<Arc<dyn PhysicalExpr>>,
+    common_subexprs: CommonSubExprs,
 }
 
 impl StandardExec {
@@ -18,11 +22,13 @@ impl StandardExec {
         operation: &'static str,
         input: Box<dyn Executor>,
         expr: Vec<Arc<dyn PhysicalExpr>>,
+        common_subexprs: CommonSubExprs,
     ) -> Self {
         Self {
             operation,
             input,
             expr,
+            common_subexprs,
         }
     }
 }
@@ -31,7 +37,9 @@ impl Executor for StandardExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let df = self.input.execute(state)?;
 
-        let df = evaluate_physical_expressions(&df, &self.expr, state);
+        let df = cache_common_subexprs(&df, &self.common_subexprs, state)
+            .and_then(|_| evaluate_physical_expressions(&df, &self.expr, state));
+        clear_common_subexprs(&self.common_subexprs, state);
         state.clear_expr_cache();
         df
     }
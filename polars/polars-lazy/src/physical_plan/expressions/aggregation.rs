@@ -27,7 +27,11 @@ impl PhysicalExpr for AggregationExpr {
     fn to_field(&self, input_schema: &Schema) -> Result<Field> {
         let field = self.expr.to_field(input_schema)?;
         let new_name = fmt_groupby_column(field.name(), self.agg_type);
-        Ok(Field::new(&new_name, field.data_type().clone()))
+        let dtype = match self.agg_type {
+            GroupByMethod::Skew(_) | GroupByMethod::Kurtosis(_, _) => DataType::Float64,
+            _ => field.data_type().clone(),
+        };
+        Ok(Field::new(&new_name, dtype))
     }
 
     fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
@@ -121,6 +125,14 @@ impl PhysicalAggregation for AggregationExpr {
                 let agg_s = series.agg_var(&groups);
                 Ok(rename_option_series(agg_s, &new_name))
             }
+            GroupByMethod::Skew(bias) => {
+                let agg_s = series.agg_skew(&groups, bias);
+                Ok(rename_option_series(agg_s, &new_name))
+            }
+            GroupByMethod::Kurtosis(fisher, bias) => {
+                let agg_s = series.agg_kurtosis(&groups, fisher, bias);
+                Ok(rename_option_series(agg_s, &new_name))
+            }
             GroupByMethod::Quantile(_) => {
                 // implemented explicitly in AggQuantile struct
                 unimplemented!()
@@ -221,9 +233,14 @@ impl PhysicalAggregation for AggQuantileExpr {
         groups: &GroupTuples,
         state: &ExecutionState,
     ) -> Result<Option<Series>> {
+        if !(0.0..=1.0).contains(&self.quantile) {
+            return Err(PolarsError::ValueError(
+                "quantile should be between 0.0 and 1.0".into(),
+            ));
+        }
         let series = self.expr.evaluate(df, state)?;
         let new_name = fmt_groupby_column(series.name(), GroupByMethod::Quantile(self.quantile));
-        let opt_agg = series.agg_quantile(groups, self.quantile);
+        let opt_agg = series.agg_quantile(groups, self.quantile, self.interpol);
 
         let opt_agg = opt_agg.map(|mut agg| {
             agg.rename(&new_name);
@@ -251,11 +268,20 @@ impl PhysicalAggregation for CastExpr {
 pub struct AggQuantileExpr {
     pub(crate) expr: Arc<dyn PhysicalExpr>,
     pub(crate) quantile: f64,
+    pub(crate) interpol: QuantileInterpolOptions,
 }
 
 impl AggQuantileExpr {
-    pub fn new(expr: Arc<dyn PhysicalExpr>, quantile: f64) -> Self {
-        Self { expr, quantile }
+    pub fn new(
+        expr: Arc<dyn PhysicalExpr>,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
+    ) -> Self {
+        Self {
+            expr,
+            quantile,
+            interpol,
+        }
     }
 }
 
@@ -267,7 +293,7 @@ impl PhysicalExpr for AggQuantileExpr {
     fn to_field(&self, input_schema: &Schema) -> Result<Field> {
         let field = self.expr.to_field(input_schema)?;
         let new_name = fmt_groupby_column(field.name(), GroupByMethod::Quantile(self.quantile));
-        Ok(Field::new(&new_name, field.data_type().clone()))
+        Ok(Field::new(&new_name, DataType::Float64))
     }
 
     fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
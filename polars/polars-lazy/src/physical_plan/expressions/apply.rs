@@ -9,7 +9,7 @@ use std::sync::Arc;
 pub struct ApplyExpr {
     pub inputs: Vec<Arc<dyn PhysicalExpr>>,
     pub function: NoEq<Arc<dyn SeriesUdf>>,
-    pub output_type: Option<DataType>,
+    pub output_type: GetOutput,
     pub expr: Expr,
     pub collect_groups: bool,
 }
@@ -33,13 +33,8 @@ impl PhysicalExpr for ApplyExpr {
         Ok(out)
     }
     fn to_field(&self, input_schema: &Schema) -> Result<Field> {
-        match &self.output_type {
-            Some(output_type) => {
-                let input_field = self.inputs[0].to_field(input_schema)?;
-                Ok(Field::new(input_field.name(), output_type.clone()))
-            }
-            None => self.inputs[0].to_field(input_schema),
-        }
+        let input_field = self.inputs[0].to_field(input_schema)?;
+        self.output_type.get_field(&input_field)
     }
     fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
         Ok(self)
@@ -93,15 +88,19 @@ impl PhysicalAggregation for ApplyExpr {
                     (Ok(ca), true) => {
                         let mut container = vec![Default::default()];
 
-                        let ca: ListChunked = ca
+                        let out = ca
                             .into_iter()
                             .map(|opt_s| {
-                                opt_s.and_then(|s| {
-                                    container[0] = s;
-                                    self.function.call_udf(&mut container).ok()
-                                })
+                                opt_s
+                                    .map(|s| {
+                                        container[0] = s;
+                                        self.function.call_udf(&mut container)
+                                    })
+                                    .transpose()
                             })
-                            .collect();
+                            .collect::<Result<Vec<_>>>()?;
+                        let mut ca: ListChunked = out.into_iter().collect();
+                        ca.rename(s.name());
                         Ok(Some(ca.into_series()))
                     }
                     _ => self.function.call_udf(&mut [s]).map(Some),
@@ -115,7 +114,7 @@ impl PhysicalAggregation for ApplyExpr {
                             .iter()
                             .map(|s| s.list().unwrap().take_rand())
                             .collect();
-                        let ca: ListChunked = (0..inputs[0].len())
+                        let out = (0..inputs[0].len())
                             .map(|i| {
                                 args.clear();
 
@@ -125,12 +124,14 @@ impl PhysicalAggregation for ApplyExpr {
                                     }
                                 });
                                 if args.len() == takers.len() {
-                                    self.function.call_udf(&mut args).ok()
+                                    self.function.call_udf(&mut args).map(Some)
                                 } else {
-                                    None
+                                    Ok(None)
                                 }
                             })
-                            .collect();
+                            .collect::<Result<Vec<_>>>()?;
+                        let mut ca: ListChunked = out.into_iter().collect();
+                        ca.rename(inputs[0].name());
                         Ok(Some(ca.into_series()))
                     }
                     _ => self.function.call_udf(&mut inputs).map(Some),
@@ -3,6 +3,7 @@ use crate::physical_plan::PhysicalAggregation;
 use crate::prelude::*;
 use polars_core::frame::groupby::GroupTuples;
 use polars_core::{prelude::*, POOL};
+use polars_io::{BatchStats, StatsEvaluator};
 use std::sync::Arc;
 
 pub struct BinaryExpr {
@@ -63,6 +64,91 @@ impl PhysicalExpr for BinaryExpr {
     fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
         Ok(self)
     }
+
+    fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+        Some(self)
+    }
+}
+
+impl BinaryExpr {
+    /// Check a `column <op> literal` comparison against the min/ max statistics of the column.
+    fn should_read_comparison(&self, stats: &BatchStats) -> Result<bool> {
+        let (left, right) = match &self.expr {
+            Expr::BinaryExpr { left, right, .. } => (&**left, &**right),
+            _ => return Ok(true),
+        };
+        // normalize to `column <op> literal`
+        let (name, literal, op) = match (left, right) {
+            (Expr::Column(name), Expr::Literal(_)) => (name, &self.right, self.op),
+            (Expr::Literal(_), Expr::Column(name)) => {
+                let op = match self.op {
+                    Operator::Gt => Operator::Lt,
+                    Operator::GtEq => Operator::LtEq,
+                    Operator::Lt => Operator::Gt,
+                    Operator::LtEq => Operator::GtEq,
+                    op => op,
+                };
+                (name, &self.left, op)
+            }
+            _ => return Ok(true),
+        };
+        let col_stats = match stats.get_stats(name) {
+            Some(col_stats) => col_stats,
+            None => return Ok(true),
+        };
+        // comparisons with null never pass a filter
+        if col_stats.null_count == Some(stats.num_rows) {
+            return Ok(false);
+        }
+        let (min, max) = match (&col_stats.min_value, &col_stats.max_value) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return Ok(true),
+        };
+        let literal = literal.evaluate(&DataFrame::new_no_checks(vec![]), &Default::default())?;
+        // statistics are only numeric or boolean, only compare types that can be coerced
+        use DataType::*;
+        let comparable = match (min.dtype(), literal.dtype()) {
+            (Boolean, Boolean) => true,
+            (Boolean, _) => false,
+            (_, dt) => matches!(
+                dt,
+                Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+            ),
+        };
+        if !comparable || literal.null_count() > 0 {
+            return Ok(true);
+        }
+        let holds = |mask: BooleanChunked| mask.get(0).unwrap_or(true);
+        let read = match op {
+            Operator::Gt => holds(ChunkCompare::<&Series>::gt(max, &literal)),
+            Operator::GtEq => holds(ChunkCompare::<&Series>::gt_eq(max, &literal)),
+            Operator::Lt => holds(ChunkCompare::<&Series>::lt(min, &literal)),
+            Operator::LtEq => holds(ChunkCompare::<&Series>::lt_eq(min, &literal)),
+            Operator::Eq => {
+                holds(ChunkCompare::<&Series>::lt_eq(min, &literal))
+                    && holds(ChunkCompare::<&Series>::gt_eq(max, &literal))
+            }
+            _ => true,
+        };
+        Ok(read)
+    }
+}
+
+impl StatsEvaluator for BinaryExpr {
+    fn should_read(&self, stats: &BatchStats) -> Result<bool> {
+        let should_read = |e: &Arc<dyn PhysicalExpr>| {
+            e.as_stats_evaluator()
+                .map_or(Ok(true), |e| e.should_read(stats))
+        };
+        match self.op {
+            Operator::And => Ok(should_read(&self.left)? && should_read(&self.right)?),
+            Operator::Or => Ok(should_read(&self.left)? || should_read(&self.right)?),
+            Operator::Gt | Operator::GtEq | Operator::Lt | Operator::LtEq | Operator::Eq => {
+                self.should_read_comparison(stats)
+            }
+            _ => Ok(true),
+        }
+    }
 }
 
 impl PhysicalAggregation for BinaryExpr {
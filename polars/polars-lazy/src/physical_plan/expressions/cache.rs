@@ -0,0 +1,82 @@
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use polars_core::frame::groupby::GroupTuples;
+use polars_core::prelude::*;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// The common sub-expressions of a projection and the keys under which their results are
+/// cached. A sub-expression comes after the sub-expressions it contains.
+pub(crate) type CommonSubExprs = Vec<(usize, Arc<dyn PhysicalExpr>)>;
+
+/// Evaluate the common sub-expressions of a projection on `df` and store their results in the
+/// [`ExecutionState`]. This is done once, before the expressions of the projection are evaluated
+/// in parallel, so that no sub-expression is computed more than once.
+pub(crate) fn cache_common_subexprs(
+    df: &DataFrame,
+    exprs: &[(usize, Arc<dyn PhysicalExpr>)],
+    state: &ExecutionState,
+) -> Result<()> {
+    for (key, expr) in exprs {
+        let s = expr.evaluate(df, state)?;
+        state.store_expr_cache(*key, s);
+    }
+    Ok(())
+}
+
+/// Remove the results of the common sub-expressions of a projection from the
+/// [`ExecutionState`].
+pub(crate) fn clear_common_subexprs(
+    exprs: &[(usize, Arc<dyn PhysicalExpr>)],
+    state: &ExecutionState,
+) {
+    for (key, _) in exprs {
+        state.remove_expr_cache(*key);
+    }
+}
+
+/// A sub-expression that occurs multiple times in a projection. Its result is computed by
+/// [`cache_common_subexprs`] and read from the [`ExecutionState`] by every occurrence.
+pub struct CacheExpr {
+    pub(crate) physical_expr: Arc<dyn PhysicalExpr>,
+    /// Unique within the physical plan.
+    key: usize,
+}
+
+impl CacheExpr {
+    pub fn new(physical_expr: Arc<dyn PhysicalExpr>, key: usize) -> Self {
+        Self { physical_expr, key }
+    }
+}
+
+impl PhysicalExpr for CacheExpr {
+    fn as_expression(&self) -> &Expr {
+        self.physical_expr.as_expression()
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Result<Series> {
+        match state.expr_cache_hit(self.key) {
+            Some(s) => Ok(s),
+            // the sub-expression is evaluated outside of its projection
+            None => self.physical_expr.evaluate(df, state),
+        }
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupTuples,
+        state: &ExecutionState,
+    ) -> Result<(Series, Cow<'a, GroupTuples>)> {
+        self.physical_expr.evaluate_on_groups(df, groups, state)
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> Result<Field> {
+        self.physical_expr.to_field(input_schema)
+    }
+
+    fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
+        self.physical_expr.as_agg_expr()
+    }
+}
@@ -3,6 +3,7 @@ pub(crate) mod alias;
 pub(crate) mod apply;
 pub(crate) mod binary;
 pub(crate) mod binary_function;
+pub(crate) mod cache;
 pub(crate) mod cast;
 pub(crate) mod column;
 pub(crate) mod filter;
@@ -21,7 +22,7 @@ use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::frame::groupby::GroupTuples;
 use polars_core::prelude::*;
-use polars_io::PhysicalIoExpr;
+use polars_io::{PhysicalIoExpr, StatsEvaluator};
 use std::borrow::Cow;
 
 /// Take a DataFrame and evaluate the expressions.
@@ -73,6 +74,12 @@ pub trait PhysicalExpr: Send + Sync {
             format!("{:?} is not an agg expression", e).into(),
         ))
     }
+
+    /// Get a view on the expression that can skip batches of rows based on their statistics.
+    /// This is used to prune parquet row groups during a scan.
+    fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+        None
+    }
 }
 
 /// Wrapper struct that allow us to use a PhysicalExpr in polars-io.
@@ -86,6 +93,10 @@ impl PhysicalIoExpr for PhysicalIoHelper {
     fn evaluate(&self, df: &DataFrame) -> Result<Series> {
         self.expr.evaluate(df, &Default::default())
     }
+
+    fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+        self.expr.as_stats_evaluator()
+    }
 }
 
 pub trait PhysicalAggregation: Send + Sync {
@@ -1,7 +1,7 @@
 use crate::logical_plan::Context;
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
-use polars_core::frame::groupby::GroupBy;
+use polars_core::frame::groupby::{GroupBy, GroupTuples};
 use polars_core::frame::hash_join::private_left_join_multiple_keys;
 use polars_core::prelude::*;
 use std::sync::Arc;
@@ -16,6 +16,63 @@ pub struct WindowExpr {
     pub(crate) function: Expr,
 }
 
+impl WindowExpr {
+    /// Apply the function to the values of every group and map the results back to the rows of
+    /// the DataFrame. The function should return a value for every row of the group, or a single
+    /// value for the whole group.
+    fn apply_per_group(
+        &self,
+        df: &DataFrame,
+        groups: &GroupTuples,
+        function: &dyn SeriesUdf,
+    ) -> Result<Series> {
+        let column = df.column(&self.apply_column)?;
+        // the row of every value in `out`
+        let mut rows = Vec::with_capacity(df.height());
+        let mut out: Option<Series> = None;
+        for (_, idx) in groups {
+            // Safety:
+            // the group indexes are in bounds.
+            let group = unsafe { column.take_iter_unchecked(&mut idx.iter().map(|i| *i as usize)) };
+            let mut group_out = function.call_udf(&mut [group])?;
+            if group_out.len() == 1 {
+                group_out = group_out.take_iter(&mut std::iter::repeat(0).take(idx.len()));
+            } else if group_out.len() != idx.len() {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "the function in a window expression returned {} values for a group of {} rows",
+                        group_out.len(),
+                        idx.len()
+                    )
+                    .into(),
+                ));
+            }
+            rows.extend_from_slice(idx);
+            match &mut out {
+                Some(out) => {
+                    out.append(&group_out)?;
+                }
+                None => out = Some(group_out),
+            }
+        }
+
+        let mut out = match out {
+            Some(out) => {
+                let mut positions = vec![None; df.height()];
+                for (pos, row) in rows.iter().enumerate() {
+                    positions[*row as usize] = Some(pos);
+                }
+                out.take_opt_iter(&mut positions.into_iter())
+            }
+            None => function.call_udf(&mut [column.clone()])?,
+        };
+        if let Some(name) = &self.out_name {
+            out.rename(name.as_str());
+        }
+        Ok(out)
+    }
+}
+
 impl PhysicalExpr for WindowExpr {
     // Note: this was first implemented with expression evaluation but this performed really bad.
     // Therefore we choose the groupby -> apply -> self join approach
@@ -74,6 +131,18 @@ impl PhysicalExpr for WindowExpr {
             }
         };
 
+        // a function that is applied per group returns a value per row, which doesn't need a join
+        if let Expr::Function {
+            function,
+            collect_groups: true,
+            ..
+        } = &self.function
+        {
+            let out = self.apply_per_group(df, &groups, &***function);
+            groups_lock.insert(key, groups);
+            return out;
+        }
+
         // 2. create GroupBy object and apply aggregation
         let mut gb = GroupBy::new(
             df,
@@ -98,11 +167,15 @@ impl PhysicalExpr for WindowExpr {
                 AggExpr::Last(_) => gb.last(),
                 AggExpr::Count(_) => gb.count(),
                 AggExpr::NUnique(_) => gb.n_unique(),
-                AggExpr::Quantile { quantile, .. } => gb.quantile(*quantile),
+                AggExpr::Quantile {
+                    quantile, interpol, ..
+                } => gb.quantile(*quantile, *interpol),
                 AggExpr::List(_) => gb.agg_list(),
                 AggExpr::AggGroups(_) => gb.groups(),
                 AggExpr::Std(_) => gb.std(),
                 AggExpr::Var(_) => gb.var(),
+                AggExpr::Skew { bias, .. } => gb.skew(*bias),
+                AggExpr::Kurtosis { fisher, bias, .. } => gb.kurtosis(*fisher, *bias),
             },
             _ => Err(PolarsError::Other(
                 format!(
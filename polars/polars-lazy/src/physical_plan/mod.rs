@@ -1,7 +1,11 @@
 pub mod executors;
 pub mod expressions;
+pub(crate) mod node_timer;
 pub mod planner;
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+pub mod sink;
 pub(crate) mod state;
+pub(crate) mod streaming;
 
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
@@ -0,0 +1,45 @@
+use polars_core::prelude::*;
+use std::sync::Mutex;
+use std::time::Instant;
+
+type StartInstant = Instant;
+type EndInstant = Instant;
+
+/// Records when the nodes of a physical plan start and stop executing.
+#[derive(Clone)]
+pub(crate) struct NodeTimer {
+    query_start: Instant,
+    data: Arc<Mutex<Vec<(String, StartInstant, EndInstant)>>>,
+}
+
+impl NodeTimer {
+    pub(crate) fn new() -> Self {
+        Self {
+            query_start: Instant::now(),
+            data: Arc::new(Mutex::new(Vec::with_capacity(16))),
+        }
+    }
+
+    pub(crate) fn store(&self, name: String, start: StartInstant, end: EndInstant) {
+        let mut data = self.data.lock().unwrap();
+        data.push((name, start, end));
+    }
+
+    /// Collect the timings in a DataFrame with the columns `node`, `start` and `end`.
+    /// The timestamps are in microseconds since the start of the query.
+    pub(crate) fn finish(self) -> Result<DataFrame> {
+        let mut data = self.data.lock().unwrap();
+        data.sort_by_key(|(_, start, _)| *start);
+
+        let since_start = |t: &Instant| t.duration_since(self.query_start).as_micros() as u64;
+        let names = data.iter().map(|(name, _, _)| name.as_str());
+        let start = data.iter().map(|(_, start, _)| since_start(start));
+        let end = data.iter().map(|(_, _, end)| since_start(end));
+
+        DataFrame::new(vec![
+            Utf8Chunked::new_from_iter("node", names).into_series(),
+            UInt64Chunked::new_from_iter("start", start).into_series(),
+            UInt64Chunked::new_from_iter("end", end).into_series(),
+        ])
+    }
+}
@@ -1,17 +1,21 @@
 use super::expressions as phys_expr;
 use crate::logical_plan::Context;
+use crate::physical_plan::expressions::cache::CommonSubExprs;
 use crate::prelude::*;
 use crate::utils::{aexpr_to_root_names, aexpr_to_root_nodes, agg_source_paths, has_aexpr};
 use ahash::RandomState;
 use itertools::Itertools;
 use polars_core::prelude::*;
 use polars_core::{frame::groupby::GroupByMethod, utils::parallel_op_series};
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 use polars_io::ScanAggregation;
-use std::collections::HashSet;
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 fn aggregate_expr_to_scan_agg(
     aggregate: Vec<Node>,
     expr_arena: &mut Arena<AExpr>,
@@ -55,10 +59,94 @@ fn aggregate_expr_to_scan_agg(
         .collect()
 }
 
-pub struct DefaultPlanner {}
+/// Check if a sub-expression may be computed once and shared by all its occurrences in a
+/// projection. Functions cannot be compared, so expressions that contain them are excluded.
+fn is_cse_candidate(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    use AExpr::*;
+    !matches!(
+        expr_arena.get(node),
+        Column(_) | Literal(_) | Alias(_, _) | Wildcard | Except(_)
+    ) && !has_aexpr(node, expr_arena, |e| {
+        matches!(
+            e,
+            Function { .. }
+                | BinaryFunction { .. }
+                | Window { .. }
+                | Wildcard
+                | Except(_)
+                | Literal(LiteralValue::Series(_))
+        )
+    })
+}
+
+/// Hash the structure of the expression at `node`, so that equal sub-expressions at different
+/// nodes get the same hash. Every node is hashed once, the hashes are memoized in `hashes`.
+fn hash_aexpr(node: Node, expr_arena: &Arena<AExpr>, hashes: &mut HashMap<Node, u64>) -> u64 {
+    if let Some(hash) = hashes.get(&node) {
+        return *hash;
+    }
+    let e = expr_arena.get(node);
+    let mut hasher = DefaultHasher::new();
+    std::mem::discriminant(e).hash(&mut hasher);
+    use AExpr::*;
+    match e {
+        Alias(_, name) | Column(name) => name.hash(&mut hasher),
+        Literal(value) => format!("{:?}", value).hash(&mut hasher),
+        BinaryExpr { op, .. } => format!("{:?}", op).hash(&mut hasher),
+        Cast { data_type, .. } => format!("{:?}", data_type).hash(&mut hasher),
+        Sort { reverse, .. } | SortBy { reverse, .. } => reverse.hash(&mut hasher),
+        Shift { periods, .. } => periods.hash(&mut hasher),
+        Slice { offset, length, .. } => {
+            offset.hash(&mut hasher);
+            length.hash(&mut hasher);
+        }
+        Agg(agg) => {
+            std::mem::discriminant(agg).hash(&mut hasher);
+            match agg {
+                AAggExpr::Quantile {
+                    quantile, interpol, ..
+                } => {
+                    quantile.to_bits().hash(&mut hasher);
+                    format!("{:?}", interpol).hash(&mut hasher);
+                }
+                AAggExpr::Skew { bias, .. } => bias.hash(&mut hasher),
+                AAggExpr::Kurtosis { fisher, bias, .. } => {
+                    fisher.hash(&mut hasher);
+                    bias.hash(&mut hasher);
+                }
+                _ => {}
+            }
+        }
+        // never shared, see `is_cse_candidate`
+        Function { .. } | BinaryFunction { .. } | Window { .. } | Wildcard | Except(_) => {
+            node.hash(&mut hasher)
+        }
+        _ => {}
+    }
+    let mut inputs = vec![];
+    e.nodes(&mut inputs);
+    for input in inputs {
+        hash_aexpr(input, expr_arena, hashes).hash(&mut hasher);
+    }
+    let hash = hasher.finish();
+    hashes.insert(node, hash);
+    hash
+}
+
+pub struct DefaultPlanner {
+    common_subexpr_elim: bool,
+    /// Nodes of the common sub-expressions that are currently being planned
+    /// and the key under which their result is cached.
+    cse_nodes: RefCell<HashMap<Node, usize>>,
+    /// The common sub-expressions of the projection that is currently being planned.
+    cse_exprs: RefCell<CommonSubExprs>,
+    cse_count: Cell<usize>,
+    /// Wrap every executor in a [`ProfileExec`].
+    profile: bool,
+}
 impl Default for DefaultPlanner {
     fn default() -> Self {
-        Self {}
+        Self::new(true)
     }
 }
 
@@ -74,6 +162,68 @@ impl PhysicalPlanner for DefaultPlanner {
 }
 
 impl DefaultPlanner {
+    pub fn new(common_subexpr_elim: bool) -> Self {
+        Self {
+            common_subexpr_elim,
+            cse_nodes: Default::default(),
+            cse_exprs: Default::default(),
+            cse_count: Cell::new(0),
+            profile: false,
+        }
+    }
+
+    /// Record the start and end of every node during execution, if the
+    /// [`ExecutionState`](crate::physical_plan::state::ExecutionState) has a node timer.
+    pub fn with_profile(mut self, toggle: bool) -> Self {
+        self.profile = toggle;
+        self
+    }
+
+    /// Create the physical expressions of a projection. Sub-expressions that occur more than
+    /// once are wrapped in a [`CacheExpr`] and returned separately, so that the executor can
+    /// compute them once before it evaluates the projection.
+    pub(crate) fn create_projection_expressions(
+        &self,
+        exprs: &[Node],
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<(Vec<Arc<dyn PhysicalExpr>>, CommonSubExprs)> {
+        if !self.common_subexpr_elim {
+            let phys_exprs =
+                self.create_physical_expressions(exprs, Context::Default, expr_arena)?;
+            return Ok((phys_exprs, vec![]));
+        }
+
+        let mut hashes = HashMap::new();
+        let mut occurrences: HashMap<u64, Vec<Node>> = HashMap::new();
+        let mut stack = exprs.to_vec();
+        while let Some(node) = stack.pop() {
+            let e = expr_arena.get(node);
+            // window functions are evaluated per group and have their own cache
+            if matches!(e, AExpr::Window { .. }) {
+                continue;
+            }
+            e.nodes(&mut stack);
+            if is_cse_candidate(node, expr_arena) {
+                let hash = hash_aexpr(node, expr_arena, &mut hashes);
+                occurrences.entry(hash).or_default().push(node);
+            }
+        }
+        {
+            let mut cse_nodes = self.cse_nodes.borrow_mut();
+            for (_, nodes) in occurrences {
+                if nodes.len() > 1 {
+                    let key = self.cse_count.get();
+                    self.cse_count.set(key + 1);
+                    cse_nodes.extend(nodes.into_iter().map(|node| (node, key)));
+                }
+            }
+        }
+        let out = self.create_physical_expressions(exprs, Context::Default, expr_arena);
+        self.cse_nodes.borrow_mut().clear();
+        let cse_exprs = std::mem::take(&mut *self.cse_exprs.borrow_mut());
+        Ok((out?, cse_exprs))
+    }
+
     pub fn create_physical_expressions(
         &self,
         exprs: &[Node],
@@ -90,6 +240,21 @@ impl DefaultPlanner {
         root: Node,
         lp_arena: &mut Arena<ALogicalPlan>,
         expr_arena: &mut Arena<AExpr>,
+    ) -> Result<Box<dyn Executor>> {
+        if self.profile {
+            let name = lp_arena.get(root).name();
+            let input = self.create_executor(root, lp_arena, expr_arena)?;
+            Ok(Box::new(ProfileExec { input, name }))
+        } else {
+            self.create_executor(root, lp_arena, expr_arena)
+        }
+    }
+
+    fn create_executor(
+        &self,
+        root: Node,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
     ) -> Result<Box<dyn Executor>> {
         use ALogicalPlan::*;
         let logical_plan = lp_arena.take(root);
@@ -131,6 +296,7 @@ impl DefaultPlanner {
                 aggregate,
                 cache,
                 low_memory,
+                row_count,
             } => {
                 let predicate = predicate
                     .map(|pred| self.create_physical_expr(pred, Context::Default, expr_arena))
@@ -149,6 +315,7 @@ impl DefaultPlanner {
                     aggregate,
                     cache,
                     low_memory,
+                    row_count,
                 }))
             }
             #[cfg(feature = "parquet")]
@@ -160,6 +327,7 @@ impl DefaultPlanner {
                 aggregate,
                 stop_after_n_rows,
                 cache,
+                row_count,
             } => {
                 let predicate = predicate
                     .map(|pred| self.create_physical_expr(pred, Context::Default, expr_arena))
@@ -174,19 +342,57 @@ impl DefaultPlanner {
                     aggregate,
                     stop_after_n_rows,
                     cache,
+                    row_count,
+                )))
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                with_columns,
+                predicate,
+                aggregate,
+                stop_after_n_rows,
+                cache,
+                row_count,
+            } => {
+                let predicate = predicate
+                    .map(|pred| self.create_physical_expr(pred, Context::Default, expr_arena))
+                    .map_or(Ok(None), |v| v.map(Some))?;
+
+                let aggregate = aggregate_expr_to_scan_agg(aggregate, expr_arena);
+                Ok(Box::new(IpcExec::new(
+                    path,
+                    schema,
+                    with_columns,
+                    predicate,
+                    aggregate,
+                    stop_after_n_rows,
+                    cache,
+                    row_count,
                 )))
             }
             Projection { expr, input, .. } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
-                let phys_expr =
-                    self.create_physical_expressions(&expr, Context::Default, expr_arena)?;
-                Ok(Box::new(StandardExec::new("projection", input, phys_expr)))
+                let (phys_expr, cse_exprs) =
+                    self.create_projection_expressions(&expr, expr_arena)?;
+                Ok(Box::new(StandardExec::new(
+                    "projection",
+                    input,
+                    phys_expr,
+                    cse_exprs,
+                )))
             }
             LocalProjection { expr, input, .. } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
-                let phys_expr =
-                    self.create_physical_expressions(&expr, Context::Default, expr_arena)?;
-                Ok(Box::new(StandardExec::new("projection", input, phys_expr)))
+                let (phys_expr, cse_exprs) =
+                    self.create_projection_expressions(&expr, expr_arena)?;
+                Ok(Box::new(StandardExec::new(
+                    "projection",
+                    input,
+                    phys_expr,
+                    cse_exprs,
+                )))
             }
             DataFrameScan {
                 df,
@@ -208,6 +414,7 @@ impl DefaultPlanner {
                 input,
                 by_column,
                 reverse,
+                nulls_last,
             } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
                 let by_column =
@@ -216,24 +423,15 @@ impl DefaultPlanner {
                     input,
                     by_column,
                     reverse,
+                    nulls_last,
                 }))
             }
-            Explode { input, columns } => {
+            Explode { input, columns, .. } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
                 Ok(Box::new(ExplodeExec { input, columns }))
             }
-            Cache { input } => {
-                let fields = lp_arena.get(input).schema(lp_arena).fields();
-                // todo! fix the unique constraint in the schema. Probably in projection pushdown at joins
-                let mut unique =
-                    HashSet::with_capacity_and_hasher(fields.len(), RandomState::default());
-                // assumption of 80 characters per column name
-                let mut key = String::with_capacity(fields.len() * 80);
-                for field in fields {
-                    if unique.insert(field.name()) {
-                        key.push_str(field.name())
-                    }
-                }
+            Cache { input, id } => {
+                let key = format!("cache {}", id);
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
                 Ok(Box::new(CacheExec { key, input }))
             }
@@ -241,6 +439,7 @@ impl DefaultPlanner {
                 input,
                 maintain_order,
                 subset,
+                keep,
             } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
                 let subset = Arc::try_unwrap(subset).unwrap_or_else(|subset| (*subset).clone());
@@ -248,6 +447,7 @@ impl DefaultPlanner {
                     input,
                     maintain_order,
                     subset,
+                    keep,
                 }))
             }
             Aggregate {
@@ -255,10 +455,45 @@ impl DefaultPlanner {
                 keys,
                 aggs,
                 apply,
+                #[cfg(any(feature = "dynamic_groupby", feature = "rolling_groupby"))]
+                options,
                 ..
             } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
 
+                #[cfg(any(feature = "dynamic_groupby", feature = "rolling_groupby"))]
+                if options.index_column().is_some() && apply.is_some() {
+                    return Err(PolarsError::InvalidOperation(
+                        "custom functions are not supported in a dynamic or rolling groupby".into(),
+                    ));
+                }
+                #[cfg(feature = "dynamic_groupby")]
+                if let Some(options) = options.dynamic {
+                    let keys =
+                        self.create_physical_expressions(&keys, Context::Default, expr_arena)?;
+                    let aggs =
+                        self.create_physical_expressions(&aggs, Context::Aggregation, expr_arena)?;
+                    return Ok(Box::new(GroupByDynamicExec {
+                        input,
+                        keys,
+                        aggs,
+                        options,
+                    }));
+                }
+                #[cfg(feature = "rolling_groupby")]
+                if let Some(options) = options.rolling {
+                    let keys =
+                        self.create_physical_expressions(&keys, Context::Default, expr_arena)?;
+                    let aggs =
+                        self.create_physical_expressions(&aggs, Context::Aggregation, expr_arena)?;
+                    return Ok(Box::new(GroupByRollingExec {
+                        input,
+                        keys,
+                        aggs,
+                        options,
+                    }));
+                }
+
                 // We first check if we can partition the groupby on the latest moment.
                 // TODO: fix this brittle/ buggy state and implement partitioned groupby's in eager
                 let mut partitionable = true;
@@ -313,6 +548,7 @@ impl DefaultPlanner {
                         aggs.into_iter()
                             .map(|n| node_to_exp(n, expr_arena))
                             .collect(),
+                        self.common_subexpr_elim,
                     )))
                 } else {
                     let phys_aggs =
@@ -330,6 +566,7 @@ impl DefaultPlanner {
                 right_on,
                 allow_par,
                 force_par,
+                duplicates,
                 ..
             } => {
                 let parallel = if force_par {
@@ -367,13 +604,14 @@ impl DefaultPlanner {
                     left_on,
                     right_on,
                     parallel,
+                    duplicates,
                 )))
             }
             HStack { input, exprs, .. } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
-                let phys_expr =
-                    self.create_physical_expressions(&exprs, Context::Default, expr_arena)?;
-                Ok(Box::new(StackExec::new(input, phys_expr)))
+                let (phys_expr, cse_exprs) =
+                    self.create_projection_expressions(&exprs, expr_arena)?;
+                Ok(Box::new(StackExec::new(input, phys_expr, cse_exprs)))
             }
             Udf {
                 input, function, ..
@@ -381,6 +619,13 @@ impl DefaultPlanner {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
                 Ok(Box::new(UdfExec { input, function }))
             }
+            Union { inputs } => {
+                let inputs = inputs
+                    .into_iter()
+                    .map(|node| self.create_initial_physical_plan(node, lp_arena, expr_arena))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Box::new(UnionExec { inputs }))
+            }
         }
     }
 
@@ -392,6 +637,17 @@ impl DefaultPlanner {
     ) -> Result<Arc<dyn PhysicalExpr>> {
         use AExpr::*;
 
+        let cse_key = self.cse_nodes.borrow_mut().remove(&expression);
+        if let Some(key) = cse_key {
+            let phys_expr = self.create_physical_expr(expression, ctxt, expr_arena)?;
+            // the sub-expressions it contains are planned, and thus pushed, first
+            let mut cse_exprs = self.cse_exprs.borrow_mut();
+            if cse_exprs.iter().all(|(k, _)| *k != key) {
+                cse_exprs.push((key, phys_expr.clone()));
+            }
+            return Ok(Arc::new(CacheExpr::new(phys_expr, key)));
+        }
+
         match expr_arena.get(expression).clone() {
             Window {
                 mut function,
@@ -520,7 +776,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     inputs: vec![input],
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: node_to_exp(expression, expr_arena),
                                     collect_groups: false,
                                 }))
@@ -542,7 +798,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     inputs: vec![input],
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: node_to_exp(expression, expr_arena),
                                     collect_groups: false,
                                 }))
@@ -564,7 +820,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     inputs: vec![input],
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: node_to_exp(expression, expr_arena),
                                     collect_groups: false,
                                 }))
@@ -586,7 +842,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     inputs: vec![input],
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: node_to_exp(expression, expr_arena),
                                     collect_groups: false,
                                 }))
@@ -608,7 +864,53 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     inputs: vec![input],
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
+                                }))
+                            }
+                        }
+                    }
+                    AAggExpr::Skew { expr, bias } => {
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(AggregationExpr::new(
+                                input,
+                                GroupByMethod::Skew(bias),
+                            ))),
+                            Context::Default => {
+                                let function = NoEq::new(Arc::new(move |s: &mut [Series]| {
+                                    let s = std::mem::take(&mut s[0]);
+                                    Ok(Series::new(s.name(), &[s.skew(bias)?]))
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    inputs: vec![input],
+                                    function,
+                                    output_type: GetOutput::DataType(DataType::Float64),
+                                    expr: node_to_exp(expression, expr_arena),
+                                    collect_groups: false,
+                                }))
+                            }
+                        }
+                    }
+                    AAggExpr::Kurtosis { expr, fisher, bias } => {
+                        let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
+                        match ctxt {
+                            Context::Aggregation => Ok(Arc::new(AggregationExpr::new(
+                                input,
+                                GroupByMethod::Kurtosis(fisher, bias),
+                            ))),
+                            Context::Default => {
+                                let function = NoEq::new(Arc::new(move |s: &mut [Series]| {
+                                    let s = std::mem::take(&mut s[0]);
+                                    Ok(Series::new(s.name(), &[s.kurtosis(fisher, bias)?]))
+                                })
+                                    as Arc<dyn SeriesUdf>);
+                                Ok(Arc::new(ApplyExpr {
+                                    inputs: vec![input],
+                                    function,
+                                    output_type: GetOutput::DataType(DataType::Float64),
                                     expr: node_to_exp(expression, expr_arena),
                                     collect_groups: false,
                                 }))
@@ -632,7 +934,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     inputs: vec![input],
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: node_to_exp(expression, expr_arena),
                                     collect_groups: false,
                                 }))
@@ -654,7 +956,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     inputs: vec![input],
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: node_to_exp(expression, expr_arena),
                                     collect_groups: false,
                                 }))
@@ -676,7 +978,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     inputs: vec![input],
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: node_to_exp(expression, expr_arena),
                                     collect_groups: false,
                                 }))
@@ -698,7 +1000,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     inputs: vec![input],
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: node_to_exp(expression, expr_arena),
                                     collect_groups: false,
                                 }))
@@ -737,30 +1039,34 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     inputs: vec![input],
                                     function,
-                                    output_type: Some(DataType::UInt32),
+                                    output_type: GetOutput::DataType(DataType::UInt32),
                                     expr: node_to_exp(expression, expr_arena),
                                     collect_groups: false,
                                 }))
                             }
                         }
                     }
-                    AAggExpr::Quantile { expr, quantile } => {
+                    AAggExpr::Quantile {
+                        expr,
+                        quantile,
+                        interpol,
+                    } => {
                         // todo! add schema to get correct output type
                         let input = self.create_physical_expr(expr, ctxt, expr_arena)?;
                         match ctxt {
                             Context::Aggregation => {
-                                Ok(Arc::new(AggQuantileExpr::new(input, quantile)))
+                                Ok(Arc::new(AggQuantileExpr::new(input, quantile, interpol)))
                             }
                             Context::Default => {
                                 let function = NoEq::new(Arc::new(move |s: &mut [Series]| {
                                     let s = std::mem::take(&mut s[0]);
-                                    s.quantile_as_series(quantile)
+                                    s.quantile_as_series(quantile, interpol)
                                 })
                                     as Arc<dyn SeriesUdf>);
                                 Ok(Arc::new(ApplyExpr {
                                     inputs: vec![input],
                                     function,
-                                    output_type: None,
+                                    output_type: GetOutput::SameType,
                                     expr: node_to_exp(expression, expr_arena),
                                     collect_groups: false,
                                 }))
@@ -794,7 +1100,7 @@ impl DefaultPlanner {
                                 Ok(Arc::new(ApplyExpr {
                                     inputs: vec![input],
                                     function,
-                                    output_type: Some(DataType::UInt32),
+                                    output_type: GetOutput::DataType(DataType::UInt32),
                                     expr: node_to_exp(expression, expr_arena),
                                     collect_groups: false,
                                 }))
@@ -861,7 +1167,7 @@ impl DefaultPlanner {
                 Ok(Arc::new(ApplyExpr {
                     inputs: vec![input],
                     function,
-                    output_type: None,
+                    output_type: GetOutput::SameType,
                     expr: node_to_exp(expression, expr_arena),
                     collect_groups: false,
                 }))
@@ -887,7 +1193,7 @@ impl DefaultPlanner {
                 Ok(Arc::new(ApplyExpr {
                     inputs: vec![input],
                     function,
-                    output_type: None,
+                    output_type: GetOutput::SameType,
                     expr: node_to_exp(expression, expr_arena),
                     collect_groups: false,
                 }))
@@ -901,7 +1207,7 @@ impl DefaultPlanner {
                 Ok(Arc::new(ApplyExpr {
                     inputs: vec![input],
                     function,
-                    output_type: None,
+                    output_type: GetOutput::SameType,
                     expr: node_to_exp(expression, expr_arena),
                     collect_groups: false,
                 }))
@@ -915,7 +1221,7 @@ impl DefaultPlanner {
                 Ok(Arc::new(ApplyExpr {
                     inputs: vec![input],
                     function,
-                    output_type: None,
+                    output_type: GetOutput::SameType,
                     expr: node_to_exp(expression, expr_arena),
                     collect_groups: false,
                 }))
@@ -929,7 +1235,7 @@ impl DefaultPlanner {
                 Ok(Arc::new(ApplyExpr {
                     inputs: vec![input],
                     function,
-                    output_type: None,
+                    output_type: GetOutput::SameType,
                     expr: node_to_exp(expression, expr_arena),
                     collect_groups: false,
                 }))
@@ -0,0 +1,152 @@
+//! Write the result of a query to disk in batches.
+use crate::physical_plan::state::ExecutionState;
+use crate::physical_plan::streaming::{execute_batches, streamable_source};
+use crate::prelude::*;
+use polars_core::prelude::*;
+#[cfg(feature = "csv-file")]
+use polars_io::csv::CsvWriter;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::{ParquetCompression, ParquetWriter};
+#[cfg(feature = "csv-file")]
+use polars_io::SerWriter;
+use std::fs::File;
+use std::path::Path;
+
+/// Options that influence how the result of a query is written to parquet.
+#[cfg(feature = "parquet")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
+#[derive(Copy, Clone, Debug)]
+pub struct ParquetWriteOptions {
+    /// Maximum number of rows that are written in a single row group.
+    pub row_group_size: usize,
+    /// Compression codec of the column chunks.
+    pub compression: ParquetCompression,
+}
+
+#[cfg(feature = "parquet")]
+impl Default for ParquetWriteOptions {
+    fn default() -> Self {
+        ParquetWriteOptions {
+            row_group_size: 512 * 1024,
+            compression: ParquetCompression::default(),
+        }
+    }
+}
+
+/// Options that influence how the result of a query is written to csv.
+#[cfg(feature = "csv-file")]
+#[cfg_attr(docsrs, doc(cfg(feature = "csv-file")))]
+#[derive(Copy, Clone, Debug)]
+pub struct CsvWriteOptions {
+    /// Number of rows that are computed and written at once.
+    pub batch_size: usize,
+    /// Write the column names as the first line.
+    pub has_header: bool,
+    pub delimiter: u8,
+}
+
+#[cfg(feature = "csv-file")]
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        CsvWriteOptions {
+            batch_size: 512 * 1024,
+            has_header: true,
+            delimiter: b',',
+        }
+    }
+}
+
+/// Execute the optimized plan and pass the output to `write` in batches of `batch_size` rows.
+/// Falls back to passing the full output at once if the plan cannot be executed in batches.
+fn sink<F>(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    planner: &DefaultPlanner,
+    batch_size: usize,
+    name: &str,
+    mut write: F,
+) -> Result<()>
+where
+    F: FnMut(DataFrame) -> Result<()>,
+{
+    let state = ExecutionState::new();
+
+    match streamable_source(root, lp_arena, expr_arena) {
+        Some(source) => {
+            execute_batches(
+                root, source, lp_arena, expr_arena, planner, batch_size, &state, write,
+            )?;
+        }
+        None => {
+            if state.verbose {
+                eprintln!(
+                    "{}: the query cannot be executed in batches, the full result will be materialized",
+                    name
+                )
+            }
+            let mut physical_plan = planner.create_physical_plan(root, lp_arena, expr_arena)?;
+            write(physical_plan.execute(&state)?)?;
+        }
+    }
+    Ok(())
+}
+
+/// Execute the optimized plan and write the output in row groups to a parquet file.
+#[cfg(feature = "parquet")]
+pub(crate) fn sink_parquet<P: AsRef<Path>>(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    planner: &DefaultPlanner,
+    path: P,
+    options: ParquetWriteOptions,
+) -> Result<()> {
+    let row_group_size = std::cmp::max(options.row_group_size, 1);
+    let schema = lp_arena.get(root).schema(lp_arena).clone();
+    let file = File::create(path)?;
+    let mut writer = ParquetWriter::new(file)
+        .with_compression(options.compression)
+        .with_row_group_size(Some(row_group_size))
+        .batched(&schema)?;
+
+    sink(
+        root,
+        lp_arena,
+        expr_arena,
+        planner,
+        row_group_size,
+        "sink_parquet",
+        |df| writer.write_batch(&df),
+    )?;
+    writer.finish()
+}
+
+/// Execute the optimized plan and write the output in batches to a csv file.
+#[cfg(feature = "csv-file")]
+pub(crate) fn sink_csv<P: AsRef<Path>>(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    planner: &DefaultPlanner,
+    path: P,
+    options: CsvWriteOptions,
+) -> Result<()> {
+    let schema = lp_arena.get(root).schema(lp_arena).clone();
+    let mut file = File::create(path)?;
+    let mut writer = CsvWriter::new(&mut file)
+        .has_headers(options.has_header)
+        .with_delimiter(options.delimiter)
+        .batched(&schema)?;
+
+    sink(
+        root,
+        lp_arena,
+        expr_arena,
+        planner,
+        options.batch_size,
+        "sink_csv",
+        |df| writer.write_batch(&df),
+    )?;
+    writer.finish()
+}
@@ -1,3 +1,4 @@
+use crate::physical_plan::node_timer::NodeTimer;
 use ahash::RandomState;
 use polars_core::frame::groupby::GroupTuples;
 use polars_core::prelude::*;
@@ -15,6 +16,10 @@ pub struct ExecutionState {
     pub(crate) group_tuples: GroupTuplesCache,
     /// Used by Window Expression to prevent redundant joins
     pub(crate) join_tuples: JoinTuplesCache,
+    /// Used by the common sub-expressions of a projection
+    expr_cache: Arc<Mutex<HashMap<usize, Series, RandomState>>>,
+    /// Records the timings of the nodes when the query is profiled
+    pub(crate) node_timer: Option<NodeTimer>,
     pub(crate) verbose: bool,
 }
 
@@ -24,6 +29,8 @@ impl ExecutionState {
             df_cache: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
             group_tuples: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
             join_tuples: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
+            expr_cache: Arc::new(Mutex::new(HashMap::with_hasher(RandomState::default()))),
+            node_timer: None,
             verbose: std::env::var("POLARS_VERBOSE").is_ok(),
         }
     }
@@ -40,6 +47,24 @@ impl ExecutionState {
         guard.insert(key, df);
     }
 
+    /// Check if we have the result of a common sub-expression in cache.
+    pub(crate) fn expr_cache_hit(&self, key: usize) -> Option<Series> {
+        let guard = self.expr_cache.lock().unwrap();
+        guard.get(&key).cloned()
+    }
+
+    /// Store the result of a common sub-expression in cache.
+    pub(crate) fn store_expr_cache(&self, key: usize, s: Series) {
+        let mut guard = self.expr_cache.lock().unwrap();
+        guard.insert(key, s);
+    }
+
+    /// Remove the result of a common sub-expression from the cache.
+    pub(crate) fn remove_expr_cache(&self, key: usize) {
+        let mut guard = self.expr_cache.lock().unwrap();
+        guard.remove(&key);
+    }
+
     /// Clear the cache used by the Window expressions
     pub fn clear_expr_cache(&self) {
         {
@@ -0,0 +1,575 @@
+//! Execute queries on batches of bounded size, so that the input of a query does not have to
+//! fit in memory.
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use crate::utils::is_elementwise;
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+use crate::utils::open_scan_source;
+#[cfg(feature = "csv-file")]
+use crate::utils::scan_csv_reader;
+#[cfg(feature = "csv-file")]
+use crate::utils::ScanSource;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+#[cfg(feature = "csv-file")]
+use polars_io::csv::{BatchedCsvReader, CsvEncoding};
+#[cfg(feature = "ipc")]
+use polars_io::ipc::IpcReader;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::ParquetReader;
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+use polars_io::{BatchedReader, SerReader};
+
+/// Find the scan at the source of a plan that only consists of row by row operations.
+/// Such a plan can be executed on batches of the scanned data.
+pub(crate) fn streamable_source(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<Node> {
+    use ALogicalPlan::*;
+    let elementwise = |exprs: &[Node]| exprs.iter().all(|e| is_elementwise(*e, expr_arena));
+    // a projection that does not depend on a column, such as `select([lit(1)])`, produces
+    // a single row instead of a row per input row
+    let row_wise = |exprs: &[Node]| {
+        elementwise(exprs)
+            && exprs.iter().any(|e| {
+                expr_arena
+                    .iter(*e)
+                    .any(|(_, e)| matches!(e, AExpr::Column(_)))
+            })
+    };
+
+    let mut node = root;
+    loop {
+        match lp_arena.get(node) {
+            Selection { input, predicate } if is_elementwise(*predicate, expr_arena) => {
+                node = *input
+            }
+            Projection { input, expr, .. } | LocalProjection { input, expr, .. }
+                if row_wise(expr) =>
+            {
+                node = *input
+            }
+            HStack { input, exprs, .. } if elementwise(exprs) => node = *input,
+            DataFrameScan {
+                projection,
+                selection,
+                ..
+            } => {
+                let projection_ok = projection.as_ref().map_or(true, |p| row_wise(p));
+                let selection_ok = selection.map_or(true, |s| is_elementwise(s, expr_arena));
+                return if projection_ok && selection_ok {
+                    Some(node)
+                } else {
+                    None
+                };
+            }
+            #[cfg(feature = "csv-file")]
+            CsvScan {
+                predicate,
+                aggregate,
+                row_count,
+                ..
+            } => {
+                let predicate_ok = predicate.map_or(true, |p| is_elementwise(p, expr_arena));
+                // the row count is added to the full file, not to the batches
+                return if predicate_ok && aggregate.is_empty() && row_count.is_none() {
+                    Some(node)
+                } else {
+                    None
+                };
+            }
+            #[cfg(feature = "parquet")]
+            ParquetScan {
+                predicate,
+                aggregate,
+                row_count,
+                ..
+            } => {
+                let predicate_ok = predicate.map_or(true, |p| is_elementwise(p, expr_arena));
+                // the row count is added to the full file, not to the batches
+                return if predicate_ok && aggregate.is_empty() && row_count.is_none() {
+                    Some(node)
+                } else {
+                    None
+                };
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                predicate,
+                aggregate,
+                row_count,
+                ..
+            } => {
+                let predicate_ok = predicate.map_or(true, |p| is_elementwise(p, expr_arena));
+                // the row count is added to the full file, not to the batches
+                return if predicate_ok && aggregate.is_empty() && row_count.is_none() {
+                    Some(node)
+                } else {
+                    None
+                };
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Indices of the `with_columns` of a scan in the `schema` of the file.
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+fn scan_projection(with_columns: &Option<Vec<String>>, schema: &Schema) -> Option<Vec<usize>> {
+    with_columns.as_ref().map(|with_columns| {
+        with_columns
+            .iter()
+            .map(|name| schema.column_with_name(name).unwrap().0)
+            .collect()
+    })
+}
+
+/// Produces the batches of a streamable source.
+enum Batches {
+    DataFrame {
+        df: Arc<DataFrame>,
+        offset: usize,
+        batch_size: usize,
+    },
+    #[cfg(feature = "csv-file")]
+    Csv {
+        reader: BatchedCsvReader<ScanSource>,
+        predicate: Option<Node>,
+    },
+    /// Parquet files are read in batches of `batch_size` rows and IPC files per record batch.
+    #[cfg(any(feature = "ipc", feature = "parquet"))]
+    Arrow {
+        reader: BatchedReader,
+        predicate: Option<Node>,
+    },
+}
+
+impl Batches {
+    fn new(source: Node, lp_arena: &Arena<ALogicalPlan>, batch_size: usize) -> Result<Self> {
+        use ALogicalPlan::*;
+        match lp_arena.get(source) {
+            DataFrameScan { df, .. } => Ok(Batches::DataFrame {
+                df: df.clone(),
+                offset: 0,
+                batch_size,
+            }),
+            #[cfg(feature = "csv-file")]
+            CsvScan {
+                path,
+                schema,
+                has_header,
+                delimiter,
+                ignore_errors,
+                skip_rows,
+                stop_after_n_rows,
+                with_columns,
+                predicate,
+                low_memory,
+                ..
+            } => {
+                let with_columns = with_columns.clone().filter(|columns| !columns.is_empty());
+                let reader = scan_csv_reader(path)?
+                    .has_header(*has_header)
+                    .with_schema(schema.clone())
+                    .with_delimiter(*delimiter)
+                    .with_ignore_parser_errors(*ignore_errors)
+                    .with_skip_rows(*skip_rows)
+                    .with_stop_after_n_rows(*stop_after_n_rows)
+                    .with_columns(with_columns)
+                    .low_memory(*low_memory)
+                    .with_encoding(CsvEncoding::LossyUtf8)
+                    .batched(batch_size)?;
+                Ok(Batches::Csv {
+                    reader,
+                    predicate: *predicate,
+                })
+            }
+            #[cfg(feature = "parquet")]
+            ParquetScan {
+                path,
+                schema,
+                with_columns,
+                predicate,
+                stop_after_n_rows,
+                ..
+            } => {
+                let reader = ParquetReader::new(open_scan_source(path)?)
+                    .with_stop_after_n_rows(*stop_after_n_rows)
+                    .batched(scan_projection(with_columns, schema), batch_size)?;
+                Ok(Batches::Arrow {
+                    reader,
+                    predicate: *predicate,
+                })
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                with_columns,
+                predicate,
+                stop_after_n_rows,
+                ..
+            } => {
+                let reader = IpcReader::new(open_scan_source(path)?)
+                    .with_stop_after_n_rows(*stop_after_n_rows)
+                    .with_projection(scan_projection(with_columns, schema))
+                    .batched()?;
+                Ok(Batches::Arrow {
+                    reader,
+                    predicate: *predicate,
+                })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the scan of the next batch, which replaces the source in the plan.
+    fn next_scan(
+        &mut self,
+        source: Node,
+        lp_arena: &Arena<ALogicalPlan>,
+    ) -> Result<Option<ALogicalPlan>> {
+        match self {
+            Batches::DataFrame {
+                df,
+                offset,
+                batch_size,
+            } => {
+                // an empty DataFrame still produces a single (empty) batch
+                if *offset > 0 && *offset >= df.height() {
+                    return Ok(None);
+                }
+                let batch = df.slice(*offset as i64, *batch_size);
+                *offset += *batch_size;
+
+                let mut scan = lp_arena.get(source).clone();
+                if let ALogicalPlan::DataFrameScan { df, .. } = &mut scan {
+                    *df = Arc::new(batch);
+                }
+                Ok(Some(scan))
+            }
+            #[cfg(feature = "csv-file")]
+            Batches::Csv { reader, predicate } => match reader.next().transpose()? {
+                Some(batch) => Ok(Some(ALogicalPlan::DataFrameScan {
+                    schema: Arc::new(batch.schema()),
+                    df: Arc::new(batch),
+                    projection: None,
+                    selection: *predicate,
+                })),
+                None => Ok(None),
+            },
+            #[cfg(any(feature = "ipc", feature = "parquet"))]
+            Batches::Arrow { reader, predicate } => match reader.next().transpose()? {
+                Some(batch) => Ok(Some(ALogicalPlan::DataFrameScan {
+                    schema: Arc::new(batch.schema()),
+                    df: Arc::new(batch),
+                    projection: None,
+                    selection: *predicate,
+                })),
+                None => Ok(None),
+            },
+        }
+    }
+}
+
+/// Execute the plan at `root` on every batch of the streamable `source` and pass the results
+/// to `f`. Returns the number of batches.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn execute_batches<F>(
+    root: Node,
+    source: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+    planner: &DefaultPlanner,
+    batch_size: usize,
+    state: &ExecutionState,
+    mut f: F,
+) -> Result<usize>
+where
+    F: FnMut(DataFrame) -> Result<()>,
+{
+    let mut batches = Batches::new(source, lp_arena, std::cmp::max(batch_size, 1))?;
+    let mut n_batches = 0;
+
+    while let Some(scan) = batches.next_scan(source, lp_arena)? {
+        // the planner consumes the plan, so every batch gets a fresh copy.
+        let mut lp_arena = lp_arena.clone();
+        let mut expr_arena = expr_arena.clone();
+        lp_arena.replace(source, scan);
+        let mut physical_plan =
+            planner.create_physical_plan(root, &mut lp_arena, &mut expr_arena)?;
+        let out = physical_plan.execute(state)?;
+        state.clear_expr_cache();
+        f(out)?;
+        n_batches += 1;
+    }
+    Ok(n_batches)
+}
+
+/// An aggregation that is computed per batch and combined afterwards.
+struct StreamingAgg {
+    /// Aggregations on a batch.
+    partial: Vec<Expr>,
+    /// Combine the partial aggregations of multiple batches.
+    combine: Vec<Expr>,
+    /// Compute the output from the combined partial aggregations.
+    finish: Expr,
+}
+
+fn streaming_agg(idx: usize, expr: &Expr, output_name: &str) -> Option<StreamingAgg> {
+    use AggExpr::*;
+    let expr = match expr {
+        Expr::Alias(expr, _) => &**expr,
+        expr => expr,
+    };
+    let agg = match expr {
+        Expr::Agg(agg) => agg,
+        _ => return None,
+    };
+    let input = agg.as_ref().clone();
+    if !matches!(input, Expr::Column(_)) {
+        return None;
+    }
+
+    let name_0 = format!("__POLARS_STREAMING_{}_0", idx);
+    let name_1 = format!("__POLARS_STREAMING_{}_1", idx);
+    let single = |partial: Expr, combine: Expr| StreamingAgg {
+        partial: vec![partial.alias(&name_0)],
+        combine: vec![combine.alias(&name_0)],
+        finish: col(&name_0).alias(output_name),
+    };
+
+    let agg = match agg {
+        Sum(_) => single(input.sum(), col(&name_0).sum()),
+        Min(_) => single(input.min(), col(&name_0).min()),
+        Max(_) => single(input.max(), col(&name_0).max()),
+        First(_) => single(input.first(), col(&name_0).first()),
+        Last(_) => single(input.last(), col(&name_0).last()),
+        Count(_) => single(input.count(), col(&name_0).sum()),
+        // the sum is taken in Float64, so that it cannot overflow the dtype of the column
+        Mean(_) => StreamingAgg {
+            partial: vec![
+                input.clone().cast(DataType::Float64).sum().alias(&name_0),
+                input
+                    .is_not_null()
+                    .cast(DataType::UInt32)
+                    .sum()
+                    .alias(&name_1),
+            ],
+            combine: vec![
+                col(&name_0).sum().alias(&name_0),
+                col(&name_1).sum().alias(&name_1),
+            ],
+            // the mean of a group without valid values is null
+            finish: when(col(&name_1).eq(lit(0u32)))
+                .then(lit(Null {}).cast(DataType::Float64))
+                .otherwise(col(&name_0) / col(&name_1).cast(DataType::Float64))
+                .alias(output_name),
+        },
+        _ => return None,
+    };
+    Some(agg)
+}
+
+/// A hash aggregation that can be computed on batches.
+struct StreamingGroupBy {
+    keys: Vec<Expr>,
+    aggs: Vec<StreamingAgg>,
+}
+
+impl StreamingGroupBy {
+    fn new(node: Node, lp_arena: &Arena<ALogicalPlan>, expr_arena: &Arena<AExpr>) -> Option<Self> {
+        let (keys, aggs, schema) = match lp_arena.get(node) {
+            ALogicalPlan::Aggregate {
+                keys,
+                aggs,
+                schema,
+                apply: None,
+                options,
+                ..
+            } if options.index_column().is_none() => (keys, aggs, schema),
+            _ => return None,
+        };
+
+        let keys = keys
+            .iter()
+            .map(|node| match node_to_exp(*node, expr_arena) {
+                Expr::Column(name) => Some(col(&name)),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let aggs = aggs
+            .iter()
+            .enumerate()
+            .map(|(idx, node)| {
+                let output_name = schema.field(keys.len() + idx)?.name();
+                streaming_agg(idx, &node_to_exp(*node, expr_arena), output_name)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self { keys, aggs })
+    }
+
+    fn aggregate(&self, df: DataFrame, exprs: Vec<Expr>) -> Result<DataFrame> {
+        df.lazy().groupby(self.keys.clone()).agg(exprs).collect()
+    }
+
+    fn partial(&self, df: DataFrame) -> Result<DataFrame> {
+        let exprs = self.aggs.iter().flat_map(|agg| agg.partial.clone());
+        self.aggregate(df, exprs.collect())
+    }
+
+    fn combine(&self, dfs: Vec<DataFrame>) -> Result<DataFrame> {
+        let df = accumulate_dataframes_vertical(dfs)?;
+        let exprs = self.aggs.iter().flat_map(|agg| agg.combine.clone());
+        self.aggregate(df, exprs.collect())
+    }
+
+    fn finish(&self, df: DataFrame) -> Result<DataFrame> {
+        let exprs = self
+            .keys
+            .iter()
+            .cloned()
+            .chain(self.aggs.iter().map(|agg| agg.finish.clone()))
+            .collect::<Vec<_>>();
+        df.lazy().select(exprs).collect()
+    }
+}
+
+/// Find the part of the plan that can be executed on batches: a chain of row by row
+/// operations on a scan, optionally followed by a hash aggregation. Returns the root of that
+/// part of the plan and its source.
+fn find_pipeline(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<(Node, Node)> {
+    let mut node = root;
+    loop {
+        let lp = lp_arena.get(node);
+        if let ALogicalPlan::Aggregate { input, .. } = lp {
+            if StreamingGroupBy::new(node, lp_arena, expr_arena).is_some() {
+                if let Some(source) = streamable_source(*input, lp_arena, expr_arena) {
+                    return Some((node, source));
+                }
+            }
+        }
+        if let Some(source) = streamable_source(node, lp_arena, expr_arena) {
+            return Some((node, source));
+        }
+        match lp.get_inputs().as_slice() {
+            [input] => node = *input,
+            _ => return None,
+        }
+    }
+}
+
+/// Execute the part of the plan at `pipeline` on batches. Returns `None` if the source
+/// did not produce any batch.
+fn execute_pipeline(
+    pipeline: Node,
+    source: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+    planner: &DefaultPlanner,
+    batch_size: usize,
+    state: &ExecutionState,
+) -> Result<Option<DataFrame>> {
+    let mut dfs = vec![];
+    let n_batches = match StreamingGroupBy::new(pipeline, lp_arena, expr_arena) {
+        Some(groupby) => {
+            let input = lp_arena.get(pipeline).get_inputs()[0];
+            let mut n_rows = 0;
+            let n_batches = execute_batches(
+                input,
+                source,
+                lp_arena,
+                expr_arena,
+                planner,
+                batch_size,
+                state,
+                |df| {
+                    let partial = groupby.partial(df)?;
+                    n_rows += partial.height();
+                    dfs.push(partial);
+                    // keep the number of groups that are held in memory bounded
+                    if n_rows > batch_size && dfs.len() > 1 {
+                        let combined = groupby.combine(std::mem::take(&mut dfs))?;
+                        n_rows = combined.height();
+                        dfs.push(combined);
+                    }
+                    Ok(())
+                },
+            )?;
+            if n_batches > 0 {
+                let combined = groupby.combine(std::mem::take(&mut dfs))?;
+                dfs.push(groupby.finish(combined)?);
+            }
+            n_batches
+        }
+        None => execute_batches(
+            pipeline,
+            source,
+            lp_arena,
+            expr_arena,
+            planner,
+            batch_size,
+            state,
+            |df| {
+                dfs.push(df);
+                Ok(())
+            },
+        )?,
+    };
+    if n_batches == 0 {
+        Ok(None)
+    } else {
+        accumulate_dataframes_vertical(dfs).map(Some)
+    }
+}
+
+/// Execute the optimized plan. The part of the plan that reads and transforms the input is
+/// executed on batches of `batch_size` rows. The operations on its result are executed as usual.
+pub(crate) fn collect_streaming(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    planner: &DefaultPlanner,
+    batch_size: usize,
+) -> Result<DataFrame> {
+    let state = ExecutionState::new();
+
+    match find_pipeline(root, lp_arena, expr_arena) {
+        Some((pipeline, source)) => {
+            let out = execute_pipeline(
+                pipeline, source, lp_arena, expr_arena, planner, batch_size, &state,
+            )?;
+            if let Some(df) = out {
+                if pipeline == root {
+                    return Ok(df);
+                }
+                let schema = Arc::new(lp_arena.get(pipeline).schema(lp_arena).clone());
+                lp_arena.replace(
+                    pipeline,
+                    ALogicalPlan::DataFrameScan {
+                        df: Arc::new(df),
+                        schema,
+                        projection: None,
+                        selection: None,
+                    },
+                );
+            }
+        }
+        None => {
+            if state.verbose {
+                eprintln!(
+                    "collect_streaming: the query cannot be executed in batches, the full input will be materialized"
+                )
+            }
+        }
+    }
+
+    let mut physical_plan = planner.create_physical_plan(root, lp_arena, expr_arena)?;
+    physical_plan.execute(&state)
+}
@@ -5,16 +5,30 @@ pub use crate::{
     frame::*,
     logical_plan::{
         optimizer::{type_coercion::TypeCoercionRule, Optimize, *},
-        DataFrameUdf, LiteralValue, LogicalPlan, LogicalPlanBuilder,
+        DataFrameUdf, GroupbyOptions, LiteralValue, LogicalPlan, LogicalPlanBuilder, RowCount,
     },
     physical_plan::{expressions::*, planner::DefaultPlanner, Executor, PhysicalPlanner},
 };
 
+#[cfg(feature = "csv-file")]
+pub use crate::physical_plan::sink::CsvWriteOptions;
+#[cfg(feature = "parquet")]
+pub use crate::physical_plan::sink::ParquetWriteOptions;
+#[cfg(feature = "sql")]
+pub use crate::sql::SQLContext;
+
 #[cfg(feature = "csv-file")]
 pub(crate) use crate::physical_plan::executors::scan::CsvExec;
+#[cfg(feature = "ipc")]
+pub(crate) use crate::physical_plan::executors::scan::IpcExec;
 #[cfg(feature = "parquet")]
 pub(crate) use crate::physical_plan::executors::scan::ParquetExec;
 
+#[cfg(feature = "dynamic_groupby")]
+pub(crate) use crate::physical_plan::executors::groupby_dynamic::GroupByDynamicExec;
+#[cfg(feature = "rolling_groupby")]
+pub(crate) use crate::physical_plan::executors::groupby_rolling::GroupByRollingExec;
+
 pub(crate) use crate::{
     logical_plan::{aexpr::*, alp::*, conversion::*},
     physical_plan::{
@@ -26,11 +40,13 @@ pub(crate) use crate::{
             groupby::{GroupByExec, PartitionGroupByExec},
             join::JoinExec,
             melt::MeltExec,
+            profile::ProfileExec,
             scan::DataFrameExec,
             slice::SliceExec,
             sort::SortExec,
             stack::StackExec,
             udf::UdfExec,
+            union::UnionExec,
             various::StandardExec,
         },
         expressions::{
@@ -38,6 +54,7 @@ pub(crate) use crate::{
             alias::AliasExpr,
             apply::ApplyExpr,
             binary_function::BinaryFunctionExpr,
+            cache::CacheExpr,
             cast::CastExpr,
             column::ColumnExpr,
             filter::FilterExpr,
@@ -0,0 +1,645 @@
+//! Run SQL queries on registered [LazyFrame]s.
+//!
+//! A query is parsed and translated to a lazy query, so it is optimized and executed like any
+//! other [LazyFrame]. The supported subset of SQL is:
+//!
+//! * `SELECT [DISTINCT]` expressions, `*` and aliases
+//! * `FROM` a registered table with `[INNER | LEFT | FULL] JOIN ... ON` equality conditions or `USING`
+//! * `WHERE`, `GROUP BY`, `ORDER BY`, `LIMIT` and `OFFSET`
+//! * arithmetic, comparisons, `AND`, `OR`, `NOT`, `IS [NOT] NULL`, `BETWEEN` and `CAST`
+//! * the aggregations `count`, `sum`, `avg`, `min`, `max`, `first`, `last`, `median`, `stddev`
+//!   and `variance`
+//!
+//! Columns are resolved by name only; a table qualifier such as `trades.price` is ignored except
+//! to determine the sides of a join condition.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use polars_core::prelude::*;
+//! use polars_lazy::prelude::*;
+//!
+//! fn example(trades: DataFrame) -> Result<DataFrame> {
+//!     let mut ctx = SQLContext::new();
+//!     ctx.register("trades", trades.lazy());
+//!     ctx.execute("SELECT symbol, avg(price) AS avg_price FROM trades GROUP BY symbol")?
+//!         .collect()
+//! }
+//! ```
+use crate::logical_plan::Context;
+use crate::prelude::*;
+use crate::utils::{has_expr, output_name};
+use polars_core::frame::hash_join::JoinType;
+use polars_core::prelude::*;
+use sqlparser::ast::{
+    BinaryOperator, Expr as SqlExpr, Function, FunctionArg, Ident, JoinConstraint, JoinOperator,
+    OrderByExpr, Query, Select, SelectItem, SetExpr, Statement, TableFactor, UnaryOperator, Value,
+};
+use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::Parser;
+use std::collections::HashMap;
+
+fn unsupported<T>(what: &str) -> Result<T> {
+    Err(PolarsError::InvalidOperation(
+        format!("{} is not supported in SQL queries", what).into(),
+    ))
+}
+
+/// Registry of named [LazyFrame]s that SQL queries can select from.
+#[derive(Clone, Default)]
+pub struct SQLContext {
+    tables: HashMap<String, LazyFrame>,
+}
+
+impl SQLContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make `lf` available under the table name `name`.
+    pub fn register(&mut self, name: &str, lf: LazyFrame) {
+        self.tables.insert(name.to_string(), lf);
+    }
+
+    /// Remove the table `name`.
+    pub fn unregister(&mut self, name: &str) {
+        self.tables.remove(name);
+    }
+
+    /// Translate a single `SELECT` statement to a [LazyFrame].
+    pub fn execute(&self, query: &str) -> Result<LazyFrame> {
+        let statements = Parser::parse_sql(&GenericDialect {}, query)
+            .map_err(|e| PolarsError::InvalidOperation(format!("{}", e).into()))?;
+        match statements.as_slice() {
+            [Statement::Query(query)] => self.execute_query(query),
+            [_] => unsupported("a statement other than SELECT"),
+            _ => Err(PolarsError::InvalidOperation(
+                "expected a single SQL statement".into(),
+            )),
+        }
+    }
+
+    fn execute_query(&self, query: &Query) -> Result<LazyFrame> {
+        if query.with.is_some() {
+            return unsupported("WITH");
+        }
+        let mut lf = match &query.body {
+            SetExpr::Select(select) => self.execute_select(select, &query.order_by)?,
+            _ => return unsupported("a set operation"),
+        };
+
+        let offset = match &query.offset {
+            Some(offset) => to_usize(&offset.value)?,
+            None => 0,
+        };
+        match &query.limit {
+            Some(limit) => lf = lf.slice(offset as i64, to_usize(limit)?),
+            None if offset > 0 => lf = lf.slice(offset as i64, usize::MAX),
+            None => {}
+        }
+        Ok(lf)
+    }
+
+    fn get_table(&self, relation: &TableFactor) -> Result<(String, LazyFrame)> {
+        match relation {
+            TableFactor::Table { name, alias, .. } => {
+                let table_name = name.to_string();
+                let lf = self
+                    .tables
+                    .get(&table_name)
+                    .cloned()
+                    .ok_or_else(|| PolarsError::NotFound(format!("table {}", table_name)))?;
+                let name = alias
+                    .as_ref()
+                    .map_or(table_name, |alias| alias.name.value.clone());
+                Ok((name, lf))
+            }
+            _ => unsupported("a FROM clause other than a table name"),
+        }
+    }
+
+    fn execute_select(&self, select: &Select, order_by: &[OrderByExpr]) -> Result<LazyFrame> {
+        let table = match select.from.as_slice() {
+            [table] => table,
+            [] => return unsupported("SELECT without FROM"),
+            _ => return unsupported("selecting from multiple tables without JOIN"),
+        };
+        let (_, mut lf) = self.get_table(&table.relation)?;
+        // `count(*)` counts the values of the first column
+        let first_column = lf
+            .logical_plan
+            .schema()
+            .field(0)
+            .map(|field| field.name().clone());
+
+        for join in &table.joins {
+            let (right_name, right) = self.get_table(&join.relation)?;
+            let (constraint, how) = match &join.join_operator {
+                JoinOperator::Inner(constraint) => (constraint, JoinType::Inner),
+                JoinOperator::LeftOuter(constraint) => (constraint, JoinType::Left),
+                JoinOperator::FullOuter(constraint) => (constraint, JoinType::Outer),
+                _ => return unsupported("this type of JOIN"),
+            };
+            let (left_on, right_on) = match constraint {
+                JoinConstraint::On(expr) => {
+                    let mut left_on = vec![];
+                    let mut right_on = vec![];
+                    join_keys(expr, &right_name, &mut left_on, &mut right_on)?;
+                    (left_on, right_on)
+                }
+                JoinConstraint::Using(idents) => {
+                    let on = idents
+                        .iter()
+                        .map(|ident| col(&ident.value))
+                        .collect::<Vec<_>>();
+                    (on.clone(), on)
+                }
+                _ => return unsupported("a JOIN without ON or USING"),
+            };
+            lf = lf.join(right, left_on, right_on, how);
+        }
+
+        if let Some(predicate) = &select.selection {
+            lf = lf.filter(to_expr(predicate, first_column.as_deref())?);
+        }
+        if select.having.is_some() {
+            return unsupported("HAVING");
+        }
+
+        let projection = select
+            .projection
+            .iter()
+            .map(|item| match item {
+                SelectItem::UnnamedExpr(expr) => to_expr(expr, first_column.as_deref()),
+                SelectItem::ExprWithAlias { expr, alias } => {
+                    Ok(to_expr(expr, first_column.as_deref())?.alias(&alias.value))
+                }
+                SelectItem::Wildcard | SelectItem::QualifiedWildcard(_) => Ok(col("*")),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let reverse = order_by
+            .iter()
+            .map(|ob| ob.asc == Some(false))
+            .collect::<Vec<_>>();
+        lf = if select.group_by.is_empty() {
+            // sort before the projection, so that columns that are not selected can be sorted on
+            if !order_by.is_empty() {
+                let by = order_by
+                    .iter()
+                    .map(|ob| match order_by_position(&ob.expr, projection.len())? {
+                        Some(idx) => {
+                            if projection[..=idx].contains(&Expr::Wildcard) {
+                                return unsupported("ORDER BY a position after *");
+                            }
+                            match &projection[idx] {
+                                Expr::Alias(expr, _) => Ok((**expr).clone()),
+                                expr => Ok(expr.clone()),
+                            }
+                        }
+                        None => {
+                            let expr = to_expr(&ob.expr, first_column.as_deref())?;
+                            Ok(resolve_alias(expr, &projection))
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                lf = lf.sort_by_exprs(by, reverse, false);
+            }
+            lf.select(projection)
+        } else {
+            let keys = select
+                .group_by
+                .iter()
+                .map(|expr| to_expr(expr, first_column.as_deref()))
+                .collect::<Result<Vec<_>>>()?;
+            // everything that is selected but not grouped on must be aggregated, otherwise it
+            // would silently become a list column
+            let aggs = projection
+                .iter()
+                .filter(|expr| !keys.contains(expr))
+                .cloned()
+                .collect::<Vec<_>>();
+            for expr in &aggs {
+                match expr {
+                    Expr::Alias(aliased, _) if keys.contains(&**aliased) => {
+                        return unsupported(&format!("aliasing the GROUP BY key {:?}", aliased))
+                    }
+                    _ if !has_expr(expr, |e| matches!(e, Expr::Agg(_))) => {
+                        return Err(PolarsError::InvalidOperation(
+                            format!(
+                                "{:?} must appear in the GROUP BY clause or be used in an aggregate function",
+                                expr
+                            )
+                            .into(),
+                        ))
+                    }
+                    _ => {}
+                }
+            }
+            let key_names = keys.iter().map(output_name).collect::<Result<Vec<_>>>()?;
+            // the aggregations are named after their method, e.g. `price_mean`
+            let input_schema = lf.logical_plan.schema().clone();
+            let output = projection
+                .iter()
+                .map(|expr| match keys.iter().position(|key| key == expr) {
+                    Some(idx) => Ok(col(&key_names[idx])),
+                    None => {
+                        let field = expr.to_field(&input_schema, Context::Aggregation)?;
+                        Ok(col(field.name()))
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?;
+            lf = lf.groupby(keys.clone()).agg(aggs).select(output.clone());
+            // after an aggregation only the output columns can be sorted on
+            if !order_by.is_empty() {
+                let by = order_by
+                    .iter()
+                    .map(|ob| match order_by_position(&ob.expr, output.len())? {
+                        Some(idx) => Ok(output[idx].clone()),
+                        None => to_expr(&ob.expr, first_column.as_deref()),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                lf = lf.sort_by_exprs(by, reverse, false);
+            }
+            lf
+        };
+
+        if select.distinct {
+            lf = lf.drop_duplicates(true, None, UniqueKeepStrategy::First);
+        }
+        Ok(lf)
+    }
+}
+
+/// Replace a column that refers to an alias in the projection by the aliased expression.
+fn resolve_alias(expr: Expr, projection: &[Expr]) -> Expr {
+    if let Expr::Column(name) = &expr {
+        for e in projection {
+            match e {
+                Expr::Alias(aliased, alias) if alias == name => return (**aliased).clone(),
+                _ => {}
+            }
+        }
+    }
+    expr
+}
+
+/// The index of the output column that a number in `ORDER BY` refers to, e.g. `ORDER BY 1`.
+fn order_by_position(expr: &SqlExpr, n_columns: usize) -> Result<Option<usize>> {
+    match expr {
+        SqlExpr::Value(Value::Number(s, _)) => match s.parse::<usize>() {
+            Ok(position) if position >= 1 && position <= n_columns => Ok(Some(position - 1)),
+            _ => Err(PolarsError::OutOfBounds(
+                format!("ORDER BY position {} is not in the select list", s).into(),
+            )),
+        },
+        _ => Ok(None),
+    }
+}
+
+fn to_usize(expr: &SqlExpr) -> Result<usize> {
+    match expr {
+        SqlExpr::Value(Value::Number(s, _)) => s
+            .parse()
+            .map_err(|_| PolarsError::ValueError(format!("{} is not a valid row count", s).into())),
+        _ => unsupported("a LIMIT or OFFSET that is not a number"),
+    }
+}
+
+fn column_name(expr: &SqlExpr) -> Option<(Option<&str>, &str)> {
+    match expr {
+        SqlExpr::Identifier(ident) => Some((None, &ident.value)),
+        SqlExpr::CompoundIdentifier(idents) => match idents.as_slice() {
+            [.., table, column] => Some((Some(&table.value), &column.value)),
+            [column] => Some((None, &column.value)),
+            [] => None,
+        },
+        _ => None,
+    }
+}
+
+/// Collect the columns of a join condition of the form `a.x = b.y [AND ...]`.
+fn join_keys(
+    expr: &SqlExpr,
+    right_name: &str,
+    left_on: &mut Vec<Expr>,
+    right_on: &mut Vec<Expr>,
+) -> Result<()> {
+    match expr {
+        SqlExpr::Nested(expr) => join_keys(expr, right_name, left_on, right_on),
+        SqlExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            join_keys(left, right_name, left_on, right_on)?;
+            join_keys(right, right_name, left_on, right_on)
+        }
+        SqlExpr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } => match (column_name(left), column_name(right)) {
+            (Some((left_table, left_col)), Some((_, right_col))) => {
+                if left_table == Some(right_name) {
+                    left_on.push(col(right_col));
+                    right_on.push(col(left_col));
+                } else {
+                    left_on.push(col(left_col));
+                    right_on.push(col(right_col));
+                }
+                Ok(())
+            }
+            _ => unsupported("a JOIN condition on expressions other than columns"),
+        },
+        _ => unsupported("a JOIN condition other than equality"),
+    }
+}
+
+fn to_dtype(data_type: &sqlparser::ast::DataType) -> Result<DataType> {
+    let name = data_type.to_string().to_lowercase();
+    let dtype = match name.split('(').next().unwrap_or("").trim() {
+        "boolean" | "bool" => DataType::Boolean,
+        "smallint" | "int" | "integer" => DataType::Int32,
+        "bigint" => DataType::Int64,
+        "real" | "float" => DataType::Float32,
+        "double" | "double precision" => DataType::Float64,
+        "char" | "varchar" | "text" | "string" => DataType::Utf8,
+        _ => return unsupported(&format!("casting to {}", data_type)),
+    };
+    Ok(dtype)
+}
+
+fn to_function(function: &Function, first_column: Option<&str>) -> Result<Expr> {
+    let name = function.name.to_string().to_lowercase();
+    if function.over.is_some() {
+        return unsupported("OVER");
+    }
+    let arg = match function.args.as_slice() {
+        [FunctionArg::Unnamed(SqlExpr::Wildcard)] if name == "count" => {
+            return match first_column {
+                Some(column) => Ok(col(column).count().alias("count")),
+                None => unsupported("count(*) on a table without columns"),
+            }
+        }
+        [FunctionArg::Unnamed(arg)] => to_expr(arg, first_column)?,
+        _ => return unsupported(&format!("function {} with these arguments", name)),
+    };
+    let expr = match (name.as_str(), function.distinct) {
+        // like in SQL, nulls are not counted
+        ("count", true) => arg.clone().filter(arg.is_not_null()).n_unique(),
+        ("count", false) => {
+            let name = output_name(&arg)?;
+            arg.is_not_null()
+                .cast(DataType::UInt32)
+                .sum()
+                .alias(&format!("{}_count", name))
+        }
+        ("sum", false) => arg.sum(),
+        ("avg", false) | ("mean", false) => arg.mean(),
+        ("min", false) => arg.min(),
+        ("max", false) => arg.max(),
+        ("first", false) => arg.first(),
+        ("last", false) => arg.last(),
+        ("median", false) => arg.median(),
+        ("stddev", false) | ("std", false) => arg.std(),
+        ("variance", false) | ("var", false) => arg.var(),
+        _ => return unsupported(&format!("function {}", function)),
+    };
+    Ok(expr)
+}
+
+fn to_literal(value: &Value) -> Result<Expr> {
+    let expr = match value {
+        Value::Number(s, _) => match s.parse::<i64>() {
+            Ok(v) => lit(v),
+            Err(_) => lit(s.parse::<f64>().map_err(|_| {
+                PolarsError::ValueError(format!("{} is not a valid number", s).into())
+            })?),
+        },
+        Value::SingleQuotedString(s) => lit(s.clone()),
+        Value::Boolean(b) => lit(*b),
+        Value::Null => Expr::Literal(LiteralValue::Null),
+        _ => return unsupported(&format!("literal {}", value)),
+    };
+    Ok(expr)
+}
+
+fn ident_to_col(ident: &Ident) -> Expr {
+    col(&ident.value)
+}
+
+/// Translate a SQL expression to an [Expr].
+fn to_expr(expr: &SqlExpr, first_column: Option<&str>) -> Result<Expr> {
+    let to_expr = |expr: &SqlExpr| to_expr(expr, first_column);
+    let expr = match expr {
+        SqlExpr::Identifier(ident) => ident_to_col(ident),
+        SqlExpr::CompoundIdentifier(idents) => match idents.last() {
+            Some(ident) => ident_to_col(ident),
+            None => return unsupported("an empty identifier"),
+        },
+        SqlExpr::Wildcard => col("*"),
+        SqlExpr::Value(value) => to_literal(value)?,
+        SqlExpr::Nested(expr) => to_expr(expr)?,
+        SqlExpr::IsNull(expr) => to_expr(expr)?.is_null(),
+        SqlExpr::IsNotNull(expr) => to_expr(expr)?.is_not_null(),
+        SqlExpr::Cast { expr, data_type } => to_expr(expr)?.cast(to_dtype(data_type)?),
+        SqlExpr::Between {
+            expr,
+            negated,
+            low,
+            high,
+        } => {
+            let expr = to_expr(expr)?;
+            let between = expr
+                .clone()
+                .gt_eq(to_expr(low)?)
+                .and(expr.lt_eq(to_expr(high)?));
+            if *negated {
+                between.not()
+            } else {
+                between
+            }
+        }
+        SqlExpr::UnaryOp { op, expr } => match (op, &**expr) {
+            (UnaryOperator::Minus, SqlExpr::Value(Value::Number(s, b))) => {
+                to_literal(&Value::Number(format!("-{}", s), *b))?
+            }
+            (UnaryOperator::Minus, expr) => lit(0) - to_expr(expr)?,
+            (UnaryOperator::Plus, expr) => to_expr(expr)?,
+            (UnaryOperator::Not, expr) => to_expr(expr)?.not(),
+            _ => return unsupported(&format!("operator {}", op)),
+        },
+        SqlExpr::BinaryOp { left, op, right } => {
+            let left = to_expr(left)?;
+            let right = to_expr(right)?;
+            match op {
+                BinaryOperator::Plus => left + right,
+                BinaryOperator::Minus => left - right,
+                BinaryOperator::Multiply => left * right,
+                BinaryOperator::Divide => left / right,
+                BinaryOperator::Modulus => left % right,
+                BinaryOperator::Gt => left.gt(right),
+                BinaryOperator::Lt => left.lt(right),
+                BinaryOperator::GtEq => left.gt_eq(right),
+                BinaryOperator::LtEq => left.lt_eq(right),
+                BinaryOperator::Eq => left.eq(right),
+                BinaryOperator::NotEq => left.neq(right),
+                BinaryOperator::And => left.and(right),
+                BinaryOperator::Or => left.or(right),
+                _ => return unsupported(&format!("operator {}", op)),
+            }
+        }
+        SqlExpr::Function(function) => to_function(function, first_column)?,
+        _ => return unsupported(&format!("expression {}", expr)),
+    };
+    Ok(expr)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn trades() -> Result<DataFrame> {
+        df![
+            "symbol" => ["a", "b", "a", "c", "b", "a"],
+            "price" => [1.0, 2.0, 3.0, 4.0, 6.0, 5.0],
+            "size" => [10, 20, 30, 40, 50, 60]
+        ]
+    }
+
+    #[test]
+    fn test_sql_groupby() -> Result<()> {
+        let mut ctx = SQLContext::new();
+        ctx.register("trades", trades()?.lazy());
+        let out = ctx
+            .execute(
+                "SELECT symbol, avg(price) AS avg_price, count(*) AS n FROM trades \
+                 WHERE size > 10 GROUP BY symbol ORDER BY symbol",
+            )?
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["symbol", "avg_price", "n"]);
+        assert_eq!(
+            Vec::from(out.column("symbol")?.utf8()?),
+            &[Some("a"), Some("b"), Some("c")]
+        );
+        assert_eq!(
+            Vec::from(out.column("avg_price")?.f64()?),
+            &[Some(4.0), Some(4.0), Some(4.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("n")?.u32()?),
+            &[Some(2), Some(2), Some(1)]
+        );
+
+        let out = ctx
+            .execute("SELECT max(size), symbol FROM trades GROUP BY symbol ORDER BY symbol")?
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["size_max", "symbol"]);
+        assert_eq!(
+            Vec::from(out.column("size_max")?.i32()?),
+            &[Some(60), Some(50), Some(40)]
+        );
+
+        // the outputs are found by expression, whatever their position in the aggregation
+        let out = ctx
+            .execute(
+                "SELECT min(price), symbol, sum(size) AS total, max(size) FROM trades \
+                 GROUP BY symbol ORDER BY symbol",
+            )?
+            .collect()?;
+        assert_eq!(
+            out.get_column_names(),
+            &["price_min", "symbol", "total", "size_max"]
+        );
+        assert_eq!(
+            Vec::from(out.column("total")?.i32()?),
+            &[Some(100), Some(70), Some(40)]
+        );
+
+        assert!(ctx.execute("SELECT * FROM trades GROUP BY symbol").is_err());
+        assert!(ctx
+            .execute("SELECT symbol, size FROM trades GROUP BY symbol")
+            .is_err());
+        assert!(ctx
+            .execute("SELECT symbol AS s, max(size) FROM trades GROUP BY symbol")
+            .is_err());
+        assert!(ctx
+            .execute("SELECT symbol, max(unknown) FROM trades GROUP BY symbol")
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sql_count_nulls_order_by_position() -> Result<()> {
+        let mut ctx = SQLContext::new();
+        ctx.register(
+            "t",
+            df![
+                "g" => ["a", "a", "a", "b"],
+                "v" => [Some(1), None, Some(1), None]
+            ]?
+            .lazy(),
+        );
+        let out = ctx
+            .execute(
+                "SELECT g, count(v) AS n, count(DISTINCT v) AS n_distinct, count(*) AS n_rows \
+                 FROM t GROUP BY g ORDER BY 1 DESC",
+            )?
+            .collect()?;
+        assert_eq!(Vec::from(out.column("g")?.utf8()?), &[Some("b"), Some("a")]);
+        assert_eq!(Vec::from(out.column("n")?.u32()?), &[Some(0), Some(2)]);
+        assert_eq!(
+            Vec::from(out.column("n_distinct")?.u32()?),
+            &[Some(0), Some(1)]
+        );
+        assert_eq!(Vec::from(out.column("n_rows")?.u32()?), &[Some(1), Some(3)]);
+
+        let out = ctx
+            .execute("SELECT v * 2 AS w, g FROM t ORDER BY 2 DESC, 1")?
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("g")?.utf8()?),
+            &[Some("b"), Some("a"), Some("a"), Some("a")]
+        );
+        assert!(ctx.execute("SELECT g FROM t ORDER BY 2").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sql_select_join_limit() -> Result<()> {
+        let mut ctx = SQLContext::new();
+        ctx.register("trades", trades()?.lazy());
+        ctx.register(
+            "symbols",
+            df![
+                "name" => ["a", "b"],
+                "exchange" => ["x", "y"]
+            ]?
+            .lazy(),
+        );
+        let out = ctx
+            .execute(
+                "SELECT symbol, exchange, price * 2 AS double_price FROM trades \
+                 JOIN symbols s ON s.name = trades.symbol \
+                 ORDER BY price DESC LIMIT 2 OFFSET 1",
+            )?
+            .collect()?;
+        assert_eq!(
+            out.get_column_names(),
+            &["symbol", "exchange", "double_price"]
+        );
+        assert_eq!(
+            Vec::from(out.column("double_price")?.f64()?),
+            &[Some(10.0), Some(6.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("exchange")?.utf8()?),
+            &[Some("x"), Some("x")]
+        );
+
+        assert!(ctx.execute("SELECT * FROM unknown").is_err());
+        assert!(ctx.execute("DELETE FROM trades").is_err());
+        Ok(())
+    }
+}
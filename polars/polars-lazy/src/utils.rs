@@ -78,6 +78,26 @@ where
     arena.iter(current_node).any(|(_node, e)| matches(e))
 }
 
+/// Check if an expression produces output row by row, i.e. if it can be computed on a slice of
+/// its input and concatenated afterwards.
+pub(crate) fn is_elementwise(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    expr_arena.iter(node).all(|(_, e)| {
+        use AExpr::*;
+        match e {
+            Literal(lv) => !matches!(lv, LiteralValue::Range { .. } | LiteralValue::Series(_)),
+            Alias(..)
+            | Column(_)
+            | BinaryExpr { .. }
+            | Not(_)
+            | IsNotNull(_)
+            | IsNull(_)
+            | Cast { .. }
+            | Ternary { .. } => true,
+            _ => false,
+        }
+    })
+}
+
 /// Can check if an expression tree has a matching_expr. This
 /// requires a dummy expression to be created that will be used to patter match against.
 pub(crate) fn has_expr<F>(current_expr: &Expr, matches: F) -> bool
@@ -197,7 +217,8 @@ pub(crate) fn expressions_to_schema(expr: &[Expr], schema: &Schema, ctxt: Contex
     Schema::new(fields)
 }
 
-/// Get a set of the data source paths in this LogicalPlan
+/// Get a set of the data source paths in this LogicalPlan. Cached sub plans are shared
+/// sources as well.
 pub(crate) fn agg_source_paths(
     root_lp: Node,
     paths: &mut HashSet<PathBuf, RandomState>,
@@ -212,7 +233,8 @@ pub(crate) fn agg_source_paths(
         Selection { input, .. } => {
             agg_source_paths(*input, paths, lp_arena);
         }
-        Cache { input } => {
+        Cache { input, id } => {
+            paths.insert(PathBuf::from(format!("cache {}", id)));
             agg_source_paths(*input, paths, lp_arena);
         }
         #[cfg(feature = "csv-file")]
@@ -223,6 +245,10 @@ pub(crate) fn agg_source_paths(
         ParquetScan { path, .. } => {
             paths.insert(path.clone());
         }
+        #[cfg(feature = "ipc")]
+        IpcScan { path, .. } => {
+            paths.insert(path.clone());
+        }
         DataFrameScan { .. } => (),
         Projection { input, .. } => {
             agg_source_paths(*input, paths, lp_arena);
@@ -259,9 +285,91 @@ pub(crate) fn agg_source_paths(
         Udf { input, .. } => {
             agg_source_paths(*input, paths, lp_arena);
         }
+        Union { inputs } => {
+            for input in inputs {
+                agg_source_paths(*input, paths, lp_arena);
+            }
+        }
     }
 }
 
+/// Reader of the file of a scan. With the `object-store` feature the path of a scan may also be a
+/// URI, such as `s3://bucket/data.parquet`, which is read with the registered object source.
+#[cfg(all(
+    feature = "object-store",
+    any(feature = "parquet", feature = "csv-file", feature = "ipc")
+))]
+pub(crate) type ScanSource = polars_io::object_store::ScanReader;
+#[cfg(all(
+    not(feature = "object-store"),
+    any(feature = "parquet", feature = "csv-file", feature = "ipc")
+))]
+pub(crate) type ScanSource = std::fs::File;
+
+/// Whether the path of a scan is a URI that is read from an object store.
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
+pub(crate) fn is_uri(path: &Path) -> bool {
+    #[cfg(feature = "object-store")]
+    {
+        polars_io::object_store::is_uri(path)
+    }
+    #[cfg(not(feature = "object-store"))]
+    {
+        let _ = path;
+        false
+    }
+}
+
+/// Open the file of a scan, naming the file if that fails.
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
+pub(crate) fn open_scan_source(path: &Path) -> Result<ScanSource> {
+    #[cfg(feature = "object-store")]
+    let source = ScanSource::open(path);
+    #[cfg(not(feature = "object-store"))]
+    let source = ScanSource::open(path).map_err(PolarsError::from);
+    source
+        .map_err(|e| PolarsError::Other(format!("could not open {}: {}", path.display(), e).into()))
+}
+
+/// Csv reader of the file of a scan. Local files are memory mapped by the reader.
+#[cfg(feature = "csv-file")]
+pub(crate) fn scan_csv_reader<'a>(
+    path: &Path,
+) -> Result<polars_io::prelude::CsvReader<'a, ScanSource>> {
+    use polars_io::SerReader;
+    let reader = polars_io::prelude::CsvReader::new(open_scan_source(path)?);
+    Ok(if is_uri(path) {
+        reader
+    } else {
+        reader.with_path(Some(path))
+    })
+}
+
+/// Expand a path with glob patterns (`*`, `?`, `[...]`) to the sorted paths of the matching files.
+/// Returns `None` if the path doesn't contain a glob pattern or is a URI.
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
+pub(crate) fn expand_glob(path: &Path) -> Option<Result<Vec<PathBuf>>> {
+    let pattern = path.to_string_lossy();
+    if is_uri(path) || !pattern.contains(|c| matches!(c, '*' | '?' | '[')) {
+        return None;
+    }
+    let expand = || {
+        let mut paths = glob::glob(&pattern)
+            .map_err(|e| PolarsError::ValueError(format!("{}", e).into()))?
+            .map(|path| path.map_err(|e| PolarsError::Io(e.into_error())))
+            .collect::<Result<Vec<_>>>()?;
+        if paths.is_empty() {
+            return Err(PolarsError::NotFound(format!(
+                "no files match the pattern {}",
+                pattern
+            )));
+        }
+        paths.sort();
+        Ok(paths)
+    };
+    Some(expand())
+}
+
 pub(crate) fn try_path_to_str(path: &Path) -> Result<&str> {
     path.to_str().ok_or_else(|| {
         PolarsError::Other(format!("Non-UTF8 file path: {}", path.to_string_lossy()).into())
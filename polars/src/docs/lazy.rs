@@ -30,10 +30,10 @@
 //! // scan a csv file lazily
 //! let lf: LazyFrame = LazyCsvReader::new("some_path".into())
 //!                     .has_header(true)
-//!                     .finish();
+//!                     .finish()?;
 //!
 //! // scan a parquet file lazily
-//! let lf: LazyFrame = LazyFrame::new_from_parquet("some_path".into(), None, true);
+//! let lf: LazyFrame = LazyFrame::new_from_parquet("some_path".into(), None, true)?;
 //!
 //! # Ok(())
 //! # }
@@ -84,7 +84,7 @@
 //! let reverse = vec![true, false];
 //!
 //! let sorted = df.lazy()
-//!     .sort_by_exprs(vec![col("b"), col("a")], reverse)
+//!     .sort_by_exprs(vec![col("b"), col("a")], reverse, false)
 //!     .collect()?;
 //!
 //! // sorted:
@@ -116,7 +116,7 @@
 //!  let df = LazyCsvReader::new("reddit.csv".into())
 //!     .has_header(true)
 //!     .with_delimiter(b',')
-//!     .finish()
+//!     .finish()?
 //!     .groupby(vec![col("comment_karma")])
 //!     .agg(vec![col("name").n_unique().alias("unique_names"), col("link_karma").max()])
 //!     // take only 100 rows.
@@ -94,18 +94,46 @@
 //! * `random` - Generate array's with randomly sampled values
 //! * `ndarray`- Convert from `DataFrame` to `ndarray`
 //! * `downsample` - [downsample operation](crate::frame::DataFrame::downsample) on `DataFrame`s
+//! * `upsample` - [upsample operation](crate::frame::DataFrame::upsample) on `DataFrame`s
+//! * `dynamic_groupby` - [Groupby over time windows](crate::frame::DataFrame::groupby_dynamic)
+//! * `rolling_groupby` - [Groupby over a window per row](crate::frame::DataFrame::groupby_rolling)
+//! * `ewma` - [Exponentially weighted moving aggregations](crate::series::Series::ewm_mean)
+//! * `rank` - [Rank the values](crate::series::Series::rank) of a `Series`
+//! * `interpolate` - [Fill null values by interpolation](crate::series::Series::interpolate)
+//! * `diff` - [Difference between values](crate::series::Series::diff) of a `Series`
+//! * `pct_change` - [Fractional change between values](crate::series::Series::pct_change) of a `Series`
+//! * `mode` - [Most frequent values](crate::series::Series::mode) of a `Series`
+//! * `transpose` - [Transpose](crate::frame::DataFrame::transpose) a `DataFrame`
+//! * `partition_by` - [Split a `DataFrame` per group](crate::frame::DataFrame::partition_by)
+//! * `describe` - [Summary statistics](crate::frame::DataFrame::describe) of a `DataFrame`
+//! * `search_sorted` - [Binary search](crate::series::Series::search_sorted) in a sorted `Series`
 //! * `sort_multiple` - Allow sorting a `DataFrame` on multiple columns
 //! * `is_in` - [Check for membership in `Series`](crate::chunked_array::ops::IsIn)
+//! * `is_between` - [Check if values lie in an interval](crate::series::Series::is_between)
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
+//! * `timezones` - Time zone aware `Datetime` dtype, backed by [chrono-tz](https://docs.rs/chrono-tz/)
+//! * `asof_join` - [Join on the nearest key](crate::frame::DataFrame::join_asof)
+//! * `cross_join` - [Cartesian product](crate::frame::DataFrame::cross_join) of `DataFrame`s
+//! * `semi_anti_join` - [Semi](crate::frame::DataFrame::semi_join) and [anti](crate::frame::DataFrame::anti_join) joins
 //! * `simd (nightly only)` - SIMD operations
 //! * `parquet` - Read Apache Parquet format
 //! * `json` - Json serialization
+//! * `avro` - Read and write Apache Avro format
+//! * `flight` - Arrow Flight client (`DoGet` and `DoPut`)
+//! * `xlsx` - Read Excel (.xlsx) workbooks
+//! * `sql-sqlite`, `sql-postgres`, `sql-mysql` - Read the result of SQL queries
+//! * `decompress` - Transparently read gzip and zstd compressed csv files
+//! * `object-store` - Read from (remote) object stores with pluggable sources, also in the lazy scans
 //! * `ipc` - Arrow's IPC format serialization
 //! * `lazy` - Lazy API
-//! * `strings` - Extra string utilities for `Utf8Chunked`
+//! * `sql` - Run SQL queries on `LazyFrame`s with a [`SQLContext`](crate::lazy::sql::SQLContext)
+//! * `strings` - Extra string utilities for `Utf8Chunked` and the lazy [`str` namespace](crate::lazy::dsl::Expr::str)
+//! * `list` - Operations on the values of `ListChunked` and the lazy [`arr` namespace](crate::lazy::dsl::Expr::arr)
 //! * `object` - Experimental support for generic ChunkedArray's called `ObjectChunked<T>` (generic over `T`).
 //!              These will downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization
+//! * `serde-lazy` - Serialize and deserialize `LazyFrame`s, so that a query can be executed elsewhere
+//! * `derive` - Convert between structs and `DataFrame`s with `#[derive(FromDataFrame, IntoDataFrame)]`
 //!
 //! ## Compile times and opt-in data types
 //! As mentioned above, Polars `Series` are wrappers around
@@ -130,6 +158,9 @@
 //! | UInt8Type               | dtype-u8          |
 //! | UInt16Type              | dtype-u16         |
 //! | UInt64Type              | dtype-u64         |
+//! | DecimalType             | dtype-decimal     |
+//! | StructChunked           | dtype-struct      |
+//! | BinaryChunked           | dtype-binary      |
 //!
 //!
 //! Or you can choose on of the preconfigured pre-sets.
@@ -195,3 +226,6 @@ pub use polars_core::df;
 pub use polars_io as io;
 #[cfg(feature = "lazy")]
 pub use polars_lazy as lazy;
+
+#[cfg(feature = "derive")]
+pub use polars_derive::{FromDataFrame, IntoDataFrame};
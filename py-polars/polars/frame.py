@@ -1609,17 +1609,23 @@ class DataFrame:
             .collect(no_optimization=True, string_cache=False)
         )
 
-    def is_duplicated(self) -> Series:
+    def is_duplicated(self, subset: "Optional[List[str]]" = None) -> Series:
         """
-        Get a mask of all duplicated rows in this DataFrame
+        Get a mask of all duplicated rows in this DataFrame.
+        If `subset` is given, only these columns are used to compare the rows.
         """
-        return wrap_s(self._df.is_duplicated())
+        if subset is not None and not isinstance(subset, list):
+            subset = [subset]
+        return wrap_s(self._df.is_duplicated(subset))
 
-    def is_unique(self) -> Series:
+    def is_unique(self, subset: "Optional[List[str]]" = None) -> Series:
         """
-        Get a mask of all unique rows in this DataFrame
+        Get a mask of all unique rows in this DataFrame.
+        If `subset` is given, only these columns are used to compare the rows.
         """
-        return wrap_s(self._df.is_unique())
+        if subset is not None and not isinstance(subset, list):
+            subset = [subset]
+        return wrap_s(self._df.is_unique(subset))
 
     def lazy(self) -> "LazyFrame":
         """
@@ -1746,15 +1752,27 @@ class DataFrame:
         return wrap_df(self._df.to_dummies())
 
     def drop_duplicates(
-        self, maintain_order=True, subset: "Optional[List[str]]" = None
+        self,
+        maintain_order=True,
+        subset: "Optional[List[str]]" = None,
+        keep: str = "first",
     ) -> "DataFrame":
         """
         Drop duplicate rows from this DataFrame.
         Note that this fails if there is a column of type `List` in the DataFrame.
+
+        Parameters
+        ----------
+        maintain_order
+            Keep the same order as the original DataFrame.
+        subset
+            Only use these columns to determine the duplicates.
+        keep
+            Which of the duplicated rows to keep: one of {"first", "last", "none"}.
         """
         if subset is not None and not isinstance(subset, list):
             subset = [subset]
-        return wrap_df(self._df.drop_duplicates(maintain_order, subset))
+        return wrap_df(self._df.drop_duplicates(maintain_order, subset, keep))
 
     def rechunk(self) -> "DataFrame":
         """
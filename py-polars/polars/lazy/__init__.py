@@ -711,14 +711,24 @@ class LazyFrame:
         self,
         maintain_order: bool = False,
         subset: "Optional[Union[List[str], str]]" = None,
+        keep: str = "first",
     ) -> "LazyFrame":
         """
         Drop duplicate rows from this DataFrame.
         Note that this fails if there is a column of type `List` in the DataFrame.
+
+        Parameters
+        ----------
+        maintain_order
+            Keep the same order as the original DataFrame.
+        subset
+            Only use these columns to determine the duplicates.
+        keep
+            Which of the duplicated rows to keep: one of {"first", "last", "none"}.
         """
         if subset is not None and not isinstance(subset, List):
             subset = [subset]
-        return wrap_ldf(self._ldf.drop_duplicates(maintain_order, subset))
+        return wrap_ldf(self._ldf.drop_duplicates(maintain_order, subset, keep))
 
     def drop_nulls(
         self, subset: "Optional[Union[List[str], str]]" = None
@@ -16,7 +16,7 @@ use crate::conversion::{ObjectValue, Wrap};
 use crate::datatypes::PyDataType;
 use crate::file::FileLike;
 use crate::lazy::dataframe::PyLazyFrame;
-use crate::utils::str_to_polarstype;
+use crate::utils::{str_to_keep_strategy, str_to_polarstype};
 use crate::{
     arrow_interop,
     error::PyPolarsEr,
@@ -336,8 +336,8 @@ impl PyDataFrame {
 
     pub fn fill_none(&self, strategy: &str) -> PyResult<Self> {
         let strat = match strategy {
-            "backward" => FillNoneStrategy::Backward,
-            "forward" => FillNoneStrategy::Forward,
+            "backward" => FillNoneStrategy::Backward(None),
+            "forward" => FillNoneStrategy::Forward(None),
             "min" => FillNoneStrategy::Min,
             "max" => FillNoneStrategy::Max,
             "mean" => FillNoneStrategy::Mean,
@@ -451,7 +451,7 @@ impl PyDataFrame {
     pub fn drop_nulls(&self, subset: Option<Vec<String>>) -> PyResult<Self> {
         let df = self
             .df
-            .drop_nulls(subset.as_ref().map(|s| s.as_ref()))
+            .drop_nulls(subset.as_ref().map(|s| s.as_slice()))
             .map_err(PyPolarsEr::from)?;
         Ok(df.into())
     }
@@ -557,13 +557,19 @@ impl PyDataFrame {
         PyDataFrame::new(df)
     }
 
-    pub fn is_unique(&self) -> PyResult<PySeries> {
-        let mask = self.df.is_unique().map_err(PyPolarsEr::from)?;
+    pub fn is_unique(&self, subset: Option<Vec<String>>) -> PyResult<PySeries> {
+        let mask = self
+            .df
+            .is_unique(subset.as_ref().map(|v| v.as_ref()))
+            .map_err(PyPolarsEr::from)?;
         Ok(mask.into_series().into())
     }
 
-    pub fn is_duplicated(&self) -> PyResult<PySeries> {
-        let mask = self.df.is_unique().map_err(PyPolarsEr::from)?;
+    pub fn is_duplicated(&self, subset: Option<Vec<String>>) -> PyResult<PySeries> {
+        let mask = self
+            .df
+            .is_duplicated(subset.as_ref().map(|v| v.as_ref()))
+            .map_err(PyPolarsEr::from)?;
         Ok(mask.into_series().into())
     }
 
@@ -679,7 +685,7 @@ impl PyDataFrame {
     ) -> PyResult<Self> {
         let gb = self.df.groupby(&by).map_err(PyPolarsEr::from)?;
         let selection = gb.select(&select);
-        let df = selection.quantile(quantile);
+        let df = selection.quantile(quantile, QuantileInterpolOptions::default());
         let df = df.map_err(PyPolarsEr::from)?;
         Ok(PyDataFrame::new(df))
     }
@@ -736,10 +742,15 @@ impl PyDataFrame {
         &self,
         maintain_order: bool,
         subset: Option<Vec<String>>,
+        keep: &str,
     ) -> PyResult<Self> {
         let df = self
             .df
-            .drop_duplicates(maintain_order, subset.as_ref().map(|v| v.as_ref()))
+            .drop_duplicates(
+                maintain_order,
+                subset.as_ref().map(|v| v.as_ref()),
+                str_to_keep_strategy(keep)?,
+            )
             .map_err(PyPolarsEr::from)?;
         Ok(df.into())
     }
@@ -797,7 +808,10 @@ impl PyDataFrame {
     }
 
     pub fn quantile(&self, quantile: f64) -> PyResult<Self> {
-        let df = self.df.quantile(quantile).map_err(PyPolarsEr::from)?;
+        let df = self
+            .df
+            .quantile(quantile, QuantileInterpolOptions::default())
+            .map_err(PyPolarsEr::from)?;
         Ok(df.into())
     }
 
@@ -1,10 +1,10 @@
 use crate::dataframe::PyDataFrame;
 use crate::error::PyPolarsEr;
 use crate::lazy::{dsl::PyExpr, utils::py_exprs_to_exprs};
-use crate::utils::str_to_polarstype;
+use crate::utils::{str_to_keep_strategy, str_to_polarstype};
 use polars::lazy::frame::{AllowedOptimizations, LazyCsvReader, LazyFrame, LazyGroupBy};
 use polars::lazy::prelude::col;
-use polars::prelude::{DataFrame, Field, JoinType, Schema};
+use polars::prelude::{DataFrame, Field, JoinType, QuantileInterpolOptions, Schema};
 use pyo3::prelude::*;
 
 #[pyclass]
@@ -84,7 +84,7 @@ impl PyLazyFrame {
         cache: bool,
         overwrite_dtype: Option<Vec<(&str, &PyAny)>>,
         low_memory: bool,
-    ) -> Self {
+    ) -> PyResult<Self> {
         let delimiter = sep.as_bytes()[0];
 
         let overwrite_dtype = overwrite_dtype.map(|overwrite_dtype| {
@@ -99,7 +99,7 @@ impl PyLazyFrame {
             Schema::new(fields)
         });
 
-        LazyCsvReader::new(path)
+        let lf = LazyCsvReader::new(path)
             .with_delimiter(delimiter)
             .has_header(has_header)
             .with_ignore_parser_errors(ignore_errors)
@@ -109,13 +109,20 @@ impl PyLazyFrame {
             .with_dtype_overwrite(overwrite_dtype.as_ref())
             .low_memory(low_memory)
             .finish()
-            .into()
+            .map_err(PyPolarsEr::from)?;
+        Ok(lf.into())
     }
 
     #[staticmethod]
     #[cfg(feature = "parquet")]
-    pub fn new_from_parquet(path: String, stop_after_n_rows: Option<usize>, cache: bool) -> Self {
-        LazyFrame::new_from_parquet(path, stop_after_n_rows, cache).into()
+    pub fn new_from_parquet(
+        path: String,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+    ) -> PyResult<Self> {
+        let lf = LazyFrame::new_from_parquet(path, stop_after_n_rows, cache)
+            .map_err(PyPolarsEr::from)?;
+        Ok(lf.into())
     }
 
     pub fn describe_plan(&self) -> String {
@@ -160,7 +167,7 @@ impl PyLazyFrame {
     pub fn sort_by_exprs(&self, by_column: Vec<PyExpr>, reverse: Vec<bool>) -> PyLazyFrame {
         let ldf = self.ldf.clone();
         let exprs = py_exprs_to_exprs(by_column);
-        ldf.sort_by_exprs(exprs, reverse).into()
+        ldf.sort_by_exprs(exprs, reverse, false).into()
     }
     pub fn cache(&self) -> PyLazyFrame {
         let ldf = self.ldf.clone();
@@ -308,7 +315,8 @@ impl PyLazyFrame {
 
     pub fn quantile(&self, quantile: f64) -> Self {
         let ldf = self.ldf.clone();
-        ldf.quantile(quantile).into()
+        ldf.quantile(quantile, QuantileInterpolOptions::default())
+            .into()
     }
 
     pub fn explode(&self, column: Vec<String>) -> Self {
@@ -317,9 +325,16 @@ impl PyLazyFrame {
         ldf.explode(&column).into()
     }
 
-    pub fn drop_duplicates(&self, maintain_order: bool, subset: Option<Vec<String>>) -> Self {
-        let ldf = self.ldf.clone();
-        ldf.drop_duplicates(maintain_order, subset).into()
+    pub fn drop_duplicates(
+        &self,
+        maintain_order: bool,
+        subset: Option<Vec<String>>,
+        keep: &str,
+    ) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        Ok(ldf
+            .drop_duplicates(maintain_order, subset, str_to_keep_strategy(keep)?)
+            .into())
     }
 
     pub fn drop_nulls(&self, subset: Option<Vec<String>>) -> Self {
@@ -127,7 +127,10 @@ impl PyExpr {
         self.clone().inner.list().into()
     }
     pub fn quantile(&self, quantile: f64) -> PyExpr {
-        self.clone().inner.quantile(quantile).into()
+        self.clone()
+            .inner
+            .quantile(quantile, QuantileInterpolOptions::default())
+            .into()
     }
     pub fn agg_groups(&self) -> PyExpr {
         self.clone().inner.agg_groups().into()
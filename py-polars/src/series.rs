@@ -340,7 +340,7 @@ impl PySeries {
     }
 
     pub fn value_counts(&self) -> PyResult<PyDataFrame> {
-        let df = self.series.value_counts().map_err(PyPolarsEr::from)?;
+        let df = self.series.value_counts(true).map_err(PyPolarsEr::from)?;
         Ok(df.into())
     }
 
@@ -542,7 +542,7 @@ impl PySeries {
         let py = gil.python();
         Wrap(
             self.series
-                .quantile_as_series(quantile)
+                .quantile_as_series(quantile, QuantileInterpolOptions::default())
                 .expect("invalid quantile")
                 .get(0),
         )
@@ -562,8 +562,8 @@ impl PySeries {
 
     pub fn fill_none(&self, strategy: &str) -> PyResult<Self> {
         let strat = match strategy {
-            "backward" => FillNoneStrategy::Backward,
-            "forward" => FillNoneStrategy::Forward,
+            "backward" => FillNoneStrategy::Backward(None),
+            "forward" => FillNoneStrategy::Forward(None),
             "min" => FillNoneStrategy::Min,
             "max" => FillNoneStrategy::Max,
             "mean" => FillNoneStrategy::Mean,
@@ -1,4 +1,6 @@
 use polars::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
 
 pub fn str_to_polarstype(s: &str) -> DataType {
     match s {
@@ -22,3 +24,15 @@ pub fn str_to_polarstype(s: &str) -> DataType {
         tp => panic!("Type {} not implemented in str_to_polarstype", tp),
     }
 }
+
+pub fn str_to_keep_strategy(s: &str) -> PyResult<UniqueKeepStrategy> {
+    match s {
+        "first" => Ok(UniqueKeepStrategy::First),
+        "last" => Ok(UniqueKeepStrategy::Last),
+        "none" => Ok(UniqueKeepStrategy::None),
+        keep => Err(PyErr::new::<PyValueError, _>(format!(
+            "keep strategy {} not supported, expected one of 'first', 'last' or 'none'",
+            keep
+        ))),
+    }
+}